@@ -0,0 +1,110 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use lexical_sort::{
+    lexical_cmp, natural_lexical_cmp, CachedCollator, ParStringSort, SortKey, SortMode, StringSort,
+};
+
+mod corpora;
+
+use corpora::generate_strings;
+
+pub fn sort_million(c: &mut Criterion) {
+    let strings = generate_strings(1_000_000);
+    let mut group = c.benchmark_group("Sorting 1M strings");
+    group.sample_size(10);
+
+    group.bench_function("serial", |b| {
+        b.iter_batched_ref(
+            || strings.clone(),
+            |v| v.string_sort_unstable(natural_lexical_cmp),
+            BatchSize::LargeInput,
+        );
+    });
+    group.bench_function("parallel", |b| {
+        b.iter_batched_ref(
+            || strings.clone(),
+            |v| v.par_string_sort_unstable(natural_lexical_cmp),
+            BatchSize::LargeInput,
+        );
+    });
+    group.bench_function("serial, cached keys", |b| {
+        b.iter_batched_ref(
+            || strings.clone(),
+            |v| v.sort_by_cached_key(|s| SortKey::natural_lexical(s)),
+            BatchSize::LargeInput,
+        );
+    });
+    group.bench_function("parallel, cached keys", |b| {
+        b.iter_batched_ref(
+            || strings.clone(),
+            |v| {
+                use rayon::slice::ParallelSliceMut;
+                v.par_sort_by_cached_key(|s| SortKey::natural_lexical(s))
+            },
+            BatchSize::LargeInput,
+        );
+    });
+    group.finish();
+
+    black_box(strings);
+}
+
+pub fn sort_100k(c: &mut Criterion) {
+    let strings = generate_strings(100_000);
+    let mut group = c.benchmark_group("Sorting 100k strings");
+
+    group.bench_function("lexical", |b| {
+        b.iter_batched_ref(
+            || strings.clone(),
+            |v| v.string_sort_unstable(lexical_cmp),
+            BatchSize::LargeInput,
+        );
+    });
+    group.bench_function("lexical, prefix keys", |b| {
+        b.iter_batched_ref(
+            || strings.clone(),
+            |v| v.string_sort_fast(),
+            BatchSize::LargeInput,
+        );
+    });
+    group.finish();
+
+    black_box(strings);
+}
+
+// Sorts the same strings 10 times, like a table that is sorted by another column each time
+pub fn sort_repeatedly(c: &mut Criterion) {
+    let strings = generate_strings(10_000);
+    let mut group = c.benchmark_group("Sorting 10k strings 10 times");
+
+    group.bench_function("without cache", |b| {
+        b.iter_batched_ref(
+            || strings.clone(),
+            |v| {
+                for _ in 0..10 {
+                    v.reverse();
+                    v.string_sort_unstable(natural_lexical_cmp);
+                }
+            },
+            BatchSize::LargeInput,
+        );
+    });
+    group.bench_function("CachedCollator", |b| {
+        b.iter_batched_ref(
+            || strings.clone(),
+            |v| {
+                let collator = CachedCollator::new(SortMode::NaturalLexical);
+                for _ in 0..10 {
+                    v.reverse();
+                    v.string_sort_unstable_with(&collator);
+                }
+            },
+            BatchSize::LargeInput,
+        );
+    });
+    group.finish();
+
+    black_box(strings);
+}
+
+criterion_group!(sorting, sort_million, sort_100k, sort_repeatedly);
+criterion_main!(sorting);
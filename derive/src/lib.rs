@@ -0,0 +1,284 @@
+//! The derive macro of the `lexical-sort` crate. It is re-exported by `lexical-sort` when its
+//! `derive` feature is enabled, so this crate shouldn't be used directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{
+    parse_macro_input, Data, DeriveInput, Error, Field, Ident, Index, LitInt, Member, Meta, Path,
+};
+
+/// Derives `PartialEq`, `Eq`, `PartialOrd` and `Ord` for a struct, comparing string fields
+/// with a comparison function of `lexical-sort`.
+///
+/// The fields marked with `#[lexical]` are compared first, with `lexical_cmp`. Their type must
+/// implement `AsRef<str>`. The comparison function can be chosen with flags, which are the
+/// same as in `SortMode`:
+///
+/// - `#[lexical(natural)]` compares numbers by their value, i.e. with `natural_lexical_cmp`
+/// - `#[lexical(only_alnum)]` skips non-alphanumeric characters, e.g. with
+///   `lexical_only_alnum_cmp`
+/// - `#[lexical(plain)]` compares strings without transliterating them, e.g. with `cmp`
+/// - `#[lexical(cmp = path::to::function)]` uses a custom `fn(&str, &str) -> Ordering`
+/// - `#[lexical(ord)]` compares a field that isn't a string with its `Ord` implementation
+///
+/// The fields are compared in the order of their declaration, unless they have a rank, e.g.
+/// `#[lexical(natural, rank = 1)]`: Fields with a rank are compared first, in ascending order
+/// of their rank, then the other marked fields. If all marked fields are equal, the remaining
+/// fields are compared with their `Ord` implementation, in the order of their declaration, so
+/// they must implement `Ord`.
+///
+/// `Eq` is consistent with `Ord`: Two values are equal if all fields compare equal. Note that
+/// the comparison functions of this crate only consider identical strings equal, because of
+/// their tie-break.
+///
+/// This macro doesn't implement the `LexicalOrd` trait, which provides comparison methods for
+/// strings.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::LexicalOrd;
+///
+/// #[derive(Debug, LexicalOrd)]
+/// struct Track {
+///     #[lexical(natural, rank = 1)]
+///     title: String,
+///     #[lexical(rank = 0)]
+///     artist: &'static str,
+///     #[lexical(ord)]
+///     year: u16,
+///     id: u32,
+/// }
+///
+/// let mut tracks = vec![
+///     Track { title: "Track 10".into(), artist: "Zoe", year: 2001, id: 1 },
+///     Track { title: "Track 9".into(), artist: "Zoe", year: 2001, id: 2 },
+///     Track { title: "Track 9".into(), artist: "Zoe", year: 1999, id: 3 },
+///     Track { title: "Uber".into(), artist: "Arzte", year: 1998, id: 4 },
+/// ];
+/// tracks.sort();
+///
+/// let ids: Vec<u32> = tracks.iter().map(|track| track.id).collect();
+/// assert_eq!(ids, [4, 3, 2, 1]);
+/// ```
+#[proc_macro_derive(LexicalOrd, attributes(lexical))]
+pub fn derive_lexical_ord(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// How a field is compared
+enum Comparison {
+    /// With a comparison function, on the result of `AsRef<str>`
+    Function(Path),
+    /// With `Ord::cmp`
+    Ord,
+}
+
+/// A field with a `#[lexical]` attribute
+struct Key {
+    member: Member,
+    rank: Option<(u32, LitInt)>,
+    comparison: Comparison,
+}
+
+fn expand(input: DeriveInput) -> Result<TokenStream2, Error> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        Data::Enum(data) => {
+            return Err(Error::new(
+                data.enum_token.span,
+                "`LexicalOrd` can only be derived for structs",
+            ))
+        }
+        Data::Union(data) => {
+            return Err(Error::new(
+                data.union_token.span,
+                "`LexicalOrd` can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut keys = Vec::new();
+    let mut others = Vec::new();
+    for (index, field) in fields.iter().enumerate() {
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(index)),
+        };
+        match parse_key(field, member.clone())? {
+            Some(key) => keys.push(key),
+            None => others.push(member),
+        }
+    }
+
+    if keys.is_empty() {
+        return Err(Error::new(
+            input.ident.span(),
+            "`LexicalOrd` requires at least one field with a `#[lexical]` attribute",
+        ));
+    }
+    for (i, key) in keys.iter().enumerate() {
+        if let Some((rank, lit)) = &key.rank {
+            if keys[..i]
+                .iter()
+                .any(|k| matches!(k.rank, Some((r, _)) if r == *rank))
+            {
+                return Err(Error::new(lit.span(), format!("duplicate rank {}", rank)));
+            }
+        }
+    }
+    // stable, so fields with the same key stay in the order of their declaration
+    keys.sort_by_key(|key| key.rank.as_ref().map_or((1, 0), |&(rank, _)| (0, rank)));
+
+    let comparisons = keys
+        .iter()
+        .map(|key| {
+            let member = &key.member;
+            match &key.comparison {
+                Comparison::Function(function) => quote! {
+                    #function(
+                        ::core::convert::AsRef::<str>::as_ref(&self.#member),
+                        ::core::convert::AsRef::<str>::as_ref(&other.#member),
+                    )
+                },
+                Comparison::Ord => quote! {
+                    ::core::cmp::Ord::cmp(&self.#member, &other.#member)
+                },
+            }
+        })
+        .chain(others.iter().map(|member| {
+            quote! { ::core::cmp::Ord::cmp(&self.#member, &other.#member) }
+        }));
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::core::cmp::Ord for #name #ty_generics #where_clause {
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                #(
+                    match #comparisons {
+                        ::core::cmp::Ordering::Equal => {}
+                        ordering => return ordering,
+                    }
+                )*
+                ::core::cmp::Ordering::Equal
+            }
+        }
+
+        impl #impl_generics ::core::cmp::PartialOrd for #name #ty_generics #where_clause {
+            fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                ::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+            }
+        }
+
+        impl #impl_generics ::core::cmp::PartialEq for #name #ty_generics #where_clause {
+            fn eq(&self, other: &Self) -> bool {
+                ::core::cmp::Ord::cmp(self, other) == ::core::cmp::Ordering::Equal
+            }
+        }
+
+        impl #impl_generics ::core::cmp::Eq for #name #ty_generics #where_clause {}
+    })
+}
+
+/// Parses the `#[lexical]` attribute of a field, if it has one
+fn parse_key(field: &Field, member: Member) -> Result<Option<Key>, Error> {
+    let mut attrs = field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("lexical"));
+    let attr = match attrs.next() {
+        Some(attr) => attr,
+        None => return Ok(None),
+    };
+    if let Some(duplicate) = attrs.next() {
+        return Err(Error::new_spanned(
+            duplicate,
+            "duplicate `#[lexical]` attribute",
+        ));
+    }
+
+    let (mut plain, mut natural, mut only_alnum, mut ord) = (false, false, false, false);
+    let mut rank = None;
+    let mut function = None;
+    if !matches!(attr.meta, Meta::Path(_)) {
+        attr.parse_nested_meta(|meta| {
+            let flag = if meta.path.is_ident("plain") {
+                &mut plain
+            } else if meta.path.is_ident("natural") {
+                &mut natural
+            } else if meta.path.is_ident("only_alnum") {
+                &mut only_alnum
+            } else if meta.path.is_ident("ord") {
+                &mut ord
+            } else if meta.path.is_ident("rank") {
+                let lit: LitInt = meta.value()?.parse()?;
+                if rank.is_some() {
+                    return Err(Error::new(lit.span(), "the rank is already set"));
+                }
+                rank = Some((lit.base10_parse()?, lit));
+                return Ok(());
+            } else if meta.path.is_ident("cmp") {
+                let path: Path = meta.value()?.parse()?;
+                if function.is_some() {
+                    return Err(Error::new_spanned(
+                        path,
+                        "the comparison function is already set",
+                    ));
+                }
+                function = Some(path);
+                return Ok(());
+            } else {
+                return Err(meta.error(
+                    "expected `natural`, `only_alnum`, `plain`, `ord`, `cmp = ...` or `rank = ...`",
+                ));
+            };
+            if *flag {
+                return Err(meta.error("duplicate flag"));
+            }
+            *flag = true;
+            Ok(())
+        })?;
+    }
+
+    let flags = plain || natural || only_alnum;
+    let comparison = match function {
+        Some(_) if flags || ord => {
+            return Err(Error::new_spanned(
+                attr,
+                "`cmp` can't be combined with `natural`, `only_alnum`, `plain` or `ord`",
+            ))
+        }
+        Some(function) => Comparison::Function(function),
+        None if ord && flags => {
+            return Err(Error::new_spanned(
+                attr,
+                "`ord` can't be combined with `natural`, `only_alnum` or `plain`",
+            ))
+        }
+        None if ord => Comparison::Ord,
+        None => {
+            let name = match (!plain, natural, only_alnum) {
+                (false, false, false) => "cmp",
+                (false, false, true) => "only_alnum_cmp",
+                (true, false, false) => "lexical_cmp",
+                (true, false, true) => "lexical_only_alnum_cmp",
+                (false, true, false) => "natural_cmp",
+                (false, true, true) => "natural_only_alnum_cmp",
+                (true, true, false) => "natural_lexical_cmp",
+                (true, true, true) => "natural_lexical_only_alnum_cmp",
+            };
+            let name = Ident::new(name, Span::call_site());
+            Comparison::Function(syn::parse_quote!(::lexical_sort::#name))
+        }
+    };
+    Ok(Some(Key {
+        member,
+        rank,
+        comparison,
+    }))
+}
@@ -0,0 +1,131 @@
+use lexical_sort::LexicalOrd;
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone, LexicalOrd)]
+struct Track {
+    #[lexical(natural, rank = 1)]
+    title: String,
+    #[lexical(rank = 0)]
+    artist: &'static str,
+    #[lexical(ord, rank = 2)]
+    year: u16,
+    id: u32,
+}
+
+fn track(title: &str, artist: &'static str, year: u16, id: u32) -> Track {
+    Track {
+        title: title.to_string(),
+        artist,
+        year,
+        id,
+    }
+}
+
+#[test]
+fn test_ranks() {
+    let mut tracks = [
+        track("Track 10", "Zoe", 2001, 1),
+        track("Track 9", "Zoe", 2001, 2),
+        track("Track 9", "Zoe", 1999, 3),
+        track("Uber", "Arzte", 1998, 4),
+        track("Track 9", "ZOE", 2001, 5),
+        track("Track 9", "Zoe", 1999, 0),
+    ];
+    tracks.sort();
+    let ids: Vec<u32> = tracks.iter().map(|track| track.id).collect();
+    assert_eq!(ids, [4, 5, 0, 3, 2, 1]);
+
+    // `Eq` is consistent with `Ord`
+    for a in &tracks {
+        for b in &tracks {
+            assert_eq!(a == b, a.cmp(b) == Ordering::Equal);
+            assert_eq!(a.partial_cmp(b), Some(a.cmp(b)));
+            assert_eq!(a.cmp(b), b.cmp(a).reverse());
+        }
+    }
+    assert_eq!(tracks[2], track("Track 9", "Zoe", 1999, 0));
+    assert_ne!(tracks[2], tracks[3]);
+}
+
+fn by_len(lhs: &str, rhs: &str) -> Ordering {
+    lhs.len().cmp(&rhs.len())
+}
+
+#[derive(Debug, LexicalOrd)]
+struct Flags(
+    #[lexical] &'static str,
+    #[lexical(only_alnum)] &'static str,
+    #[lexical(plain, natural)] &'static str,
+    #[lexical(cmp = by_len)] &'static str,
+);
+
+#[test]
+fn test_flags() {
+    // fields without a rank are compared in the order of their declaration
+    let cmp = |a: Flags, b: Flags| a.cmp(&b);
+    assert_eq!(
+        cmp(Flags("a", "", "", ""), Flags("B", "", "", "")),
+        Ordering::Less
+    );
+    assert_eq!(
+        cmp(Flags("", "a-c", "", ""), Flags("", "ab", "", "")),
+        Ordering::Greater
+    );
+    assert_eq!(
+        cmp(Flags("", "", "B9", ""), Flags("", "", "a10", "")),
+        Ordering::Less
+    );
+    assert_eq!(
+        cmp(Flags("", "", "a9", ""), Flags("", "", "a10", "")),
+        Ordering::Less
+    );
+    assert_eq!(
+        cmp(Flags("", "", "", "zz"), Flags("", "", "", "aaa")),
+        Ordering::Less
+    );
+    assert_eq!(
+        cmp(Flags("", "", "", "zz"), Flags("", "", "", "aa")),
+        Ordering::Equal
+    );
+    assert_eq!(
+        cmp(Flags("b", "a", "", ""), Flags("a", "b", "", "")),
+        Ordering::Greater
+    );
+}
+
+#[derive(Debug, LexicalOrd)]
+struct Generic<S: AsRef<str>, T: Ord> {
+    #[lexical(natural)]
+    name: S,
+    value: T,
+}
+
+#[test]
+fn test_generics() {
+    let mut items = [
+        Generic {
+            name: "T-20".to_string(),
+            value: 1,
+        },
+        Generic {
+            name: "t-5".to_string(),
+            value: 2,
+        },
+        Generic {
+            name: "T-20".to_string(),
+            value: 0,
+        },
+    ];
+    items.sort();
+    let sorted: Vec<(&str, i32)> = items
+        .iter()
+        .map(|item| (item.name.as_str(), item.value))
+        .collect();
+    assert_eq!(sorted, [("t-5", 2), ("T-20", 0), ("T-20", 1)]);
+}
+
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,11 @@
+use lexical_sort::LexicalOrd;
+
+#[derive(LexicalOrd)]
+struct Track {
+    #[lexical(rank = 1)]
+    title: String,
+    #[lexical(natural, rank = 1)]
+    artist: String,
+}
+
+fn main() {}
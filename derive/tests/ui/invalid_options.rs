@@ -0,0 +1,20 @@
+use lexical_sort::LexicalOrd;
+
+#[derive(LexicalOrd)]
+struct Unknown {
+    #[lexical(reverse)]
+    title: String,
+}
+
+#[derive(LexicalOrd)]
+struct Conflicting {
+    #[lexical(ord, natural)]
+    title: String,
+}
+
+#[derive(LexicalOrd)]
+enum Enum {
+    A,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use lexical_sort::LexicalOrd;
+
+#[derive(LexicalOrd)]
+struct Track {
+    title: String,
+    artist: String,
+}
+
+fn main() {}
@@ -0,0 +1,62 @@
+//! Checks that the comparison functions and iterators never panic, and that the comparison
+//! functions are total orders.
+//!
+//! The input is split into three strings at `0xFF` bytes (which can't occur in UTF-8); the
+//! first byte selects the comparison function.
+
+#![no_main]
+
+use lexical_sort::iter::{iterate_lexical, iterate_lexical_only_alnum};
+use lexical_sort::SortMode;
+use libfuzzer_sys::fuzz_target;
+use std::cmp::Ordering;
+
+const MODES: [SortMode; 8] = [
+    SortMode::Plain,
+    SortMode::OnlyAlnum,
+    SortMode::Lexical,
+    SortMode::LexicalOnlyAlnum,
+    SortMode::Natural,
+    SortMode::NaturalOnlyAlnum,
+    SortMode::NaturalLexical,
+    SortMode::NaturalLexicalOnlyAlnum,
+];
+
+fuzz_target!(|data: &[u8]| {
+    let (&selector, data) = match data.split_first() {
+        Some(x) => x,
+        None => return,
+    };
+    let mut parts = data.splitn(3, |&b| b == 0xFF);
+    let a = String::from_utf8_lossy(parts.next().unwrap_or_default());
+    let b = String::from_utf8_lossy(parts.next().unwrap_or_default());
+    let c = String::from_utf8_lossy(parts.next().unwrap_or_default());
+
+    for s in [&a, &b, &c].iter() {
+        let forward: Vec<char> = iterate_lexical(s).collect();
+        let mut backward: Vec<char> = iterate_lexical(s).rev().collect();
+        backward.reverse();
+        assert_eq!(forward, backward);
+
+        let forward: Vec<char> = iterate_lexical_only_alnum(s).collect();
+        let mut backward: Vec<char> = iterate_lexical_only_alnum(s).rev().collect();
+        backward.reverse();
+        assert_eq!(forward, backward);
+    }
+
+    let mode = MODES[usize::from(selector) % MODES.len()];
+    let cmp = mode.function();
+
+    let ab = cmp(&a, &b);
+    let bc = cmp(&b, &c);
+    let ac = cmp(&a, &c);
+
+    assert_eq!(ab, cmp(&b, &a).reverse(), "{:?}: antisymmetry", mode);
+    assert_eq!(ab == Ordering::Equal, a == b, "{:?}: equality", mode);
+    if ab == bc {
+        assert_eq!(ac, ab, "{:?}: transitivity", mode);
+    }
+    if ab != Ordering::Greater && bc != Ordering::Greater {
+        assert_ne!(ac, Ordering::Greater, "{:?}: transitivity", mode);
+    }
+});
@@ -0,0 +1,4 @@
+//! A `no_std` crate that uses `lexical-sort` with only the `alloc` feature. The tests are in
+//! `tests/no_std.rs`.
+
+#![no_std]
@@ -0,0 +1,488 @@
+//! Calls the functions of `lexical-sort` that are available without `std`. The test harness
+//! needs `std`, but this crate doesn't import anything from it, so this doesn't compile if a
+//! function requires the `std` feature.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::collections::{BTreeSet, VecDeque};
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::hash::Hasher;
+use core::ops::Bound;
+
+use lexical_sort::iter::{
+    iterate_lexical, iterate_lexical_char, iterate_lexical_indices, iterate_lexical_only_alnum,
+    lexical_display, lexical_only_alnum_to_string, lexical_to_string, LexicalNormalizer,
+};
+use lexical_sort::prelude::*;
+use lexical_sort::*;
+
+const FILES: [&str; 6] = ["T-20", "t-5", "Apfel", "apple", "T-100", "Zebra"];
+
+/// A hasher that only counts the written bytes, since `DefaultHasher` requires `std`
+#[derive(Default)]
+struct CountingHasher(u64);
+
+impl Hasher for CountingHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.0 += bytes.len() as u64;
+    }
+}
+
+#[test]
+fn test_comparison_functions() {
+    let cmps: [fn(&str, &str) -> Ordering; 8] = [
+        cmp,
+        only_alnum_cmp,
+        lexical_cmp,
+        lexical_only_alnum_cmp,
+        natural_cmp,
+        natural_only_alnum_cmp,
+        natural_lexical_cmp,
+        natural_lexical_only_alnum_cmp,
+    ];
+    for (&c, &mode) in cmps.iter().zip(SortMode::ALL.iter()) {
+        assert_eq!(c("T-5", "T-20"), mode.function()("T-5", "T-20"));
+        assert_eq!(c("T-5", "T-20"), Collator::from(mode).cmp("T-5", "T-20"));
+    }
+
+    assert_eq!(lexical_cmp("Apfel", "apple"), Ordering::Less);
+    assert_eq!(natural_lexical_cmp("T-5", "T-20"), Ordering::Less);
+    assert_eq!(lexical_ignore_whitespace_cmp("a b", "ab"), Ordering::Less);
+    assert_eq!(
+        natural_lexical_ignore_whitespace_cmp("a 10", "a9"),
+        Ordering::Greater
+    );
+    assert_eq!(shortlex_lexical_cmp("b", "aa"), Ordering::Less);
+    assert_eq!(shortlex_natural_lexical_cmp("b", "aa"), Ordering::Less);
+    assert_eq!(wordwise_lexical_cmp("a b", "ab"), Ordering::Less);
+    assert_eq!(wordwise_natural_lexical_cmp("a 9", "a 10"), Ordering::Less);
+    assert_eq!(column_label_cmp("Z", "AA"), Ordering::Less);
+    assert_eq!(
+        multiline_cmp("a\nb", "a\nc", SortMode::Lexical),
+        Ordering::Less
+    );
+    assert_eq!(casefold_cmp("STRASSE", "strasse"), Ordering::Less);
+    assert_eq!(natural_casefold_cmp("T-5", "t-20"), Ordering::Less);
+    assert_eq!(version_cmp("1.9.0", "1.10.0"), Ordering::Less);
+    assert_eq!(version_lexical_cmp("1.9.0", "1.10.0"), Ordering::Less);
+    assert_eq!(gnu_version_cmp("1.9", "1.10"), Ordering::Less);
+    assert_eq!(
+        cmp_by_reading(
+            ("日本", Some("nihon")),
+            ("中国", Some("chuugoku")),
+            lexical_cmp
+        ),
+        Ordering::Greater
+    );
+
+    assert_eq!(lexical_cmp_prefix("Apfel", "apfelmus", 5), Ordering::Equal);
+    assert_eq!(
+        natural_lexical_cmp_prefix("T-05", "t-5x", 3),
+        Ordering::Equal
+    );
+    assert_eq!(natural_cmp_prefix("T-05", "T-5x", 3), Ordering::Equal);
+    assert_eq!(cmp_digit_runs("12a", "9b").0, Ordering::Greater);
+    assert_eq!(
+        cmp_digit_runs_iter(&mut "12".chars().peekable(), &mut "9".chars().peekable()),
+        Ordering::Greater
+    );
+    assert_eq!(lexical_char_cmp('A', 'b'), Ordering::Less);
+    assert_eq!(lexical_char_key('A').len(), 1);
+
+    assert_eq!(ascii_lexical_cmp("Apple", "banana"), Ordering::Less);
+    assert_eq!(ascii_natural_lexical_cmp("T-5", "T-20"), Ordering::Less);
+    const ORDERING: Ordering = const_ascii_lexical_cmp("Apple", "banana");
+    assert_eq!(ORDERING, Ordering::Less);
+    assert!(is_sorted_ascii(&["apple", "Banana", "cherry"]));
+}
+
+#[test]
+fn test_eq_and_hash() {
+    let eqs: [fn(&str, &str) -> bool; 7] = [
+        only_alnum_eq,
+        lexical_eq,
+        lexical_only_alnum_eq,
+        natural_eq,
+        natural_only_alnum_eq,
+        natural_lexical_eq,
+        natural_lexical_only_alnum_eq,
+    ];
+    for eq in eqs.iter() {
+        assert!(eq("T-5", "T-5"));
+        assert!(!eq("T-5", "T-6"));
+    }
+    assert!(lexical_eq("Foo", "foo"));
+
+    let hashes: [fn(&str, &mut CountingHasher); 7] = [
+        only_alnum_hash,
+        lexical_hash,
+        lexical_only_alnum_hash,
+        natural_hash,
+        natural_only_alnum_hash,
+        natural_lexical_hash,
+        natural_lexical_only_alnum_hash,
+    ];
+    for hash in hashes.iter() {
+        let (mut lhs, mut rhs) = (CountingHasher::default(), CountingHasher::default());
+        hash("T-5", &mut lhs);
+        hash("T-5", &mut rhs);
+        assert_eq!(lhs.finish(), rhs.finish());
+    }
+}
+
+#[test]
+fn test_bytes() {
+    assert_eq!(lexical_cmp_bytes(b"Apfel", b"apple"), Ordering::Less);
+    assert_eq!(natural_lexical_cmp_bytes(b"T-5", b"T-20"), Ordering::Less);
+    assert_eq!(natural_cmp_bytes(b"T-5", b"T-20"), Ordering::Less);
+    assert_eq!(
+        cmp_bytes(b"T-5", b"\xFF", SortMode::NaturalLexical),
+        Ordering::Less
+    );
+
+    let mut names: [&[u8]; 3] = [b"T-20", b"\xFF", b"t-5"];
+    names.byte_sort(natural_lexical_cmp_bytes);
+    assert_eq!(names, [&b"t-5"[..], b"T-20", b"\xFF"]);
+    names.byte_sort_unstable(lexical_cmp_bytes);
+    assert_eq!(names, [&b"T-20"[..], b"t-5", b"\xFF"]);
+}
+
+#[test]
+fn test_collator() {
+    let collator = Collator::new()
+        .natural(true)
+        .symbols_last(true)
+        .ties(TieBreak::LowercaseFirst)
+        .tailoring(Tailoring::GERMAN_PHONEBOOK);
+    assert_eq!(collator.cmp("T-5", "T-20"), Ordering::Less);
+    assert_eq!(collator.sort_mode(), SortMode::NaturalLexical);
+    assert_eq!(
+        Tailoring::from_locale("de-DE-u-co-phonebk"),
+        Some(Tailoring::GERMAN_PHONEBOOK)
+    );
+
+    let (ordering, decided_by) = cmp_with_position("T-5", "T-20", SortMode::NaturalLexical);
+    assert_eq!(ordering, Ordering::Less);
+    assert_eq!(decided_by.position(), Some((2, 2)));
+    assert_eq!(collator.cmp_with_position("T-5", "T-20").0, Ordering::Less);
+    assert_eq!(
+        lexical_cmp_at("Apfel", "apple"),
+        (Ordering::Less, Some((2, 2)))
+    );
+    assert_eq!(natural_lexical_cmp_at("T-5", "T-20").0, Ordering::Less);
+    assert_eq!(
+        common_lexical_prefix("Apfel", "apfelmus", SortMode::Lexical),
+        (5, 5)
+    );
+    assert_eq!(
+        common_lexical_prefix_of(&["Photo 1", "photo 2"], SortMode::Lexical),
+        "Photo "
+    );
+
+    assert_eq!(Script::of('Ж'), Some(Script::Cyrillic));
+    assert_eq!(
+        first_lexical_unit("Apfel", SortMode::Lexical),
+        IndexKey::Letter("a")
+    );
+}
+
+#[test]
+fn test_sort_modes() {
+    for &mode in SortMode::ALL.iter() {
+        assert_eq!(mode.name().parse(), Ok(mode));
+        assert_eq!(mode.to_string(), mode.name());
+    }
+    assert_eq!("lexical-plain".parse::<SortMode>(), Err(ParseSortModeError));
+
+    let boxed: alloc::boxed::Box<dyn Compare> = alloc::boxed::Box::new(NaturalLexical);
+    let shared = alloc::sync::Arc::new(Lexical);
+    assert_eq!(boxed.compare("T-5", "T-20"), Ordering::Less);
+    assert_eq!(shared.compare("T-5", "T-20"), Ordering::Greater);
+    for cmp in [
+        &Plain as &dyn Compare,
+        &OnlyAlnum,
+        &LexicalOnlyAlnum,
+        &Natural,
+    ]
+    .iter()
+    .chain(&[&NaturalOnlyAlnum as &dyn Compare, &NaturalLexicalOnlyAlnum])
+    {
+        assert_eq!(cmp.compare("a", "b"), Ordering::Less);
+    }
+}
+
+#[test]
+fn test_string_sort() {
+    let mut files = FILES;
+    files.string_sort(natural_lexical_cmp);
+    assert_eq!(files, ["Apfel", "apple", "t-5", "T-20", "T-100", "Zebra"]);
+    assert!(files.string_is_sorted(natural_lexical_cmp));
+    assert_eq!(files.string_first_unsorted(cmp), Some(3));
+    assert!(files.string_is_sorted_by(natural_lexical_cmp, |s| s));
+
+    let expected = files;
+    let mut files = FILES;
+    files.string_sort_unstable(natural_lexical_cmp);
+    assert_eq!(files, expected);
+    let mut files = FILES;
+    files.string_sort_by(natural_lexical_cmp, str::trim);
+    assert_eq!(files, expected);
+    let mut files = FILES;
+    files.string_sort_unstable_by(natural_lexical_cmp, str::trim);
+    assert_eq!(files, expected);
+    let mut files = FILES;
+    files.string_sort_with(&NaturalLexical);
+    assert_eq!(files, expected);
+    let mut files = FILES;
+    files.string_sort_unstable_with(&Collator::from(SortMode::NaturalLexical));
+    assert_eq!(files, expected);
+    let mut files = FILES;
+    files.string_sort_by_cached_key(natural_lexical_cmp, |s| s.to_string());
+    assert_eq!(files, expected);
+    let mut files = FILES;
+    files.string_sort_unstable_by_cached_key(natural_lexical_cmp, |s| s.to_string());
+    assert_eq!(files, expected);
+    let mut files = FILES;
+    files.string_sort_preserving(SortMode::NaturalLexical);
+    assert_eq!(files, expected);
+
+    let indices = FILES.string_argsort(natural_lexical_cmp);
+    assert_eq!(indices, [2, 3, 1, 0, 4, 5]);
+    assert_eq!(FILES.string_argsort_unstable(natural_lexical_cmp), indices);
+    assert_eq!(
+        FILES.string_smallest_k(2, natural_lexical_cmp),
+        ["Apfel", "apple"]
+    );
+    let mut files = FILES;
+    files.string_select_nth_unstable(2, natural_lexical_cmp);
+    assert_eq!(files[2], "t-5");
+
+    let mut deque: VecDeque<&str> = FILES.iter().copied().collect();
+    deque.string_sort(natural_lexical_cmp);
+    assert!(deque.iter().eq(expected.iter()));
+
+    let mut vec = vec![String::from("apple"), String::from("T-20")];
+    string_insert_sorted(&mut vec, String::from("t-5"), natural_lexical_cmp);
+    assert_eq!(vec, ["apple", "t-5", "T-20"]);
+}
+
+#[test]
+fn test_other_traits() {
+    let mut options = [Some("b"), None, Some("Apfel")];
+    options.string_sort(lexical_cmp);
+    assert_eq!(options, [Some("Apfel"), Some("b"), None]);
+    options.string_sort_unstable_none_first(lexical_cmp);
+    assert_eq!(options, [None, Some("Apfel"), Some("b")]);
+    assert_eq!(none_first(lexical_cmp)(None, Some("a")), Ordering::Less);
+    assert_eq!(none_last(lexical_cmp)(None, Some("a")), Ordering::Greater);
+    assert_eq!(empty_last(lexical_cmp)("", "a"), Ordering::Greater);
+
+    let mut words = [("日本", Some("nihon")), ("中国", Some("chuugoku"))];
+    words.sort_by_reading(lexical_cmp);
+    assert_eq!(words[0].0, "中国");
+
+    let mut people = [("Zoe", 30), ("anna", 25), ("Emile", 41)];
+    people.sort_by_str_key(lexical_cmp, |p| p.0);
+    assert_eq!(people, [("anna", 25), ("Emile", 41), ("Zoe", 30)]);
+    people.sort_unstable_by_cached_str_key(lexical_cmp, |p| p.0.to_string());
+    assert_eq!(people[2], ("Zoe", 30));
+
+    let mut sorted = FILES;
+    sorted.string_sort(lexical_cmp);
+    assert_eq!(sorted.prefix_range("t", SortMode::Lexical), 2..5);
+    assert_eq!(sorted.string_binary_search("apple", lexical_cmp), Ok(1));
+    assert_eq!(
+        sorted.string_partition_point(|s| lexical_cmp(s, "b") == Ordering::Less),
+        2
+    );
+
+    assert!("Apfel".lexical_lt("apple"));
+    assert!("T-5".natural_lexical_lt("T-20"));
+    assert_eq!(LexicalKey("Foo"), LexicalKey("foo"));
+    assert!(ByOrder::<_, NaturalLexical>::new("T-5") < ByOrder::new("T-20"));
+}
+
+#[test]
+fn test_iterators() {
+    let folded: String = iterate_lexical("Creme Brulee").collect();
+    assert_eq!(folded, "creme brulee");
+    let folded: String = iterate_lexical_only_alnum("Creme Brulee!").collect();
+    assert_eq!(folded, "cremebrulee");
+    assert_eq!(iterate_lexical_char('A').len(), 1);
+    assert_eq!(iterate_lexical_indices("ab").count(), 2);
+    assert_eq!(lexical_display("Apfel").to_string(), "apfel");
+    assert_eq!(lexical_to_string("STRASSE"), "strasse");
+    assert_eq!(lexical_only_alnum_to_string("T-5"), "t5");
+
+    let mut normalizer = LexicalNormalizer::new();
+    let mut out = String::new();
+    normalizer.push_str("Creme Bru", &mut out);
+    normalizer.push_char('l', &mut out);
+    normalizer.push_str("ee", &mut out);
+    assert_eq!(out, "creme brulee");
+
+    let sorted: Vec<&str> = FILES.iter().copied().sorted_lexically().collect();
+    assert_eq!(sorted, ["Apfel", "apple", "T-100", "T-20", "t-5", "Zebra"]);
+    let sorted: Vec<&str> = FILES
+        .iter()
+        .copied()
+        .sorted_by_cmp(natural_lexical_cmp)
+        .collect();
+    assert_eq!(sorted[2], "t-5");
+    let sorted: Vec<&str> = FILES
+        .iter()
+        .copied()
+        .sorted_unstable_by_cmp(natural_lexical_cmp)
+        .collect();
+    assert_eq!(sorted[2], "t-5");
+
+    assert_eq!(lexical_min(FILES.iter(), lexical_cmp), Some(&"Apfel"));
+    assert_eq!(lexical_max(FILES.iter(), lexical_cmp), Some(&"Zebra"));
+    assert_eq!(
+        lexical_min_max(FILES.iter(), lexical_cmp),
+        Some((&"Apfel", &"Zebra"))
+    );
+}
+
+#[test]
+fn test_sorted_slices() {
+    let old = ["apple", "b", "T-5"];
+    let new = ["Apfel", "b", "T-20", "T-5"];
+    let diff: Vec<DiffItem> = diff_sorted(&old, &new, lexical_cmp).collect();
+    assert_eq!(
+        diff,
+        [
+            DiffItem::Added(0),
+            DiffItem::Removed(0),
+            DiffItem::Kept(1, 1),
+            DiffItem::Added(2),
+            DiffItem::Kept(2, 3),
+        ]
+    );
+    assert_eq!(sorted_intersection(&old, &new, lexical_cmp).count(), 2);
+    assert_eq!(sorted_difference(&old, &new, lexical_cmp).count(), 1);
+    assert_eq!(sorted_union(&old, &new, lexical_cmp).count(), 5);
+
+    let merged: Vec<&str> = merge_sorted(["a", "c"], ["B"], lexical_cmp).collect();
+    assert_eq!(merged, ["a", "B", "c"]);
+    let merged: Vec<&str> = kmerge_sorted(vec![vec!["a", "c"], vec!["B"]], lexical_cmp).collect();
+    assert_eq!(merged, ["a", "B", "c"]);
+
+    assert_eq!(count_lexical_duplicates(["a", "A", "b"], lexical_eq), 1);
+    let mut vec = vec!["a", "A", "b"];
+    string_dedup(&mut vec, lexical_eq);
+    assert_eq!(vec, ["a", "b"]);
+    let mut vec = vec![("a", 1), ("A", 2), ("b", 3)];
+    string_dedup_by_key(&mut vec, |p| p.0, lexical_eq);
+    assert_eq!(vec, [("a", 1), ("b", 3)]);
+
+    assert!(verify_sorted(&["Apfel", "b"], lexical_cmp).is_ok());
+    let violation = verify_sorted_exhaustive(&["b", "Apfel"], lexical_cmp).unwrap_err();
+    assert_eq!(violation.index, 0);
+    assert_eq!(sortedness(&["a", "c", "b"], lexical_cmp).runs, 2);
+    assert_eq!(
+        rank_strings(&["b", "a", "b"], SortMode::Lexical, TieBreak::Bytes),
+        [1, 0, 1]
+    );
+}
+
+#[test]
+fn test_search() {
+    assert!(lexical_starts_with("Apfel", "apf", SortMode::Lexical));
+    assert_eq!(
+        lexical_strip_prefix("Apfel", "ap", SortMode::Lexical),
+        Some(2)
+    );
+    assert_eq!(
+        lexical_find("GRUSSE", "usse", SortMode::Lexical),
+        Some(2..6)
+    );
+    assert!(lexical_contains(
+        "Creme Brulee",
+        "brulee",
+        SortMode::Lexical
+    ));
+    assert!(lexically_between(
+        "Apfel",
+        Bound::Included("a"),
+        Bound::Excluded("b"),
+        SortMode::Lexical
+    ));
+    assert_eq!(
+        prefix_successor("ap", SortMode::Lexical).as_deref(),
+        Some("aq")
+    );
+    assert_eq!(autocomplete(&FILES, "ap", SortMode::Lexical, 5), [2, 3]);
+}
+
+#[test]
+fn test_owned_strings() {
+    let set: BTreeSet<LexicalString> = FILES.iter().map(|&s| s.into()).collect();
+    assert!(set.contains(LexicalStr::new("Apfel")));
+    assert_eq!(set.iter().next().map(|s| s.as_str()), Some("Apfel"));
+
+    assert_eq!(sort_lines("b\nApfel\n", lexical_cmp), "Apfel\nb\n");
+    assert_eq!(sort_unique_lines("b\nb\na", lexical_cmp), "a\nb");
+    let mut text = String::from("b\na");
+    sort_lines_in_place(&mut text, lexical_cmp);
+    assert_eq!(text, "a\nb");
+    sort_unique_lines_in_place(&mut text, lexical_cmp);
+    assert_eq!(text, "a\nb");
+
+    let (lhs, rhs) = (SortKey::lexical("Apfel"), SortKey::lexical("apple"));
+    assert!(lhs < rhs);
+    assert_eq!(
+        SortKey::new("T-5", SortMode::NaturalLexical),
+        SortKey::natural_lexical("T-5")
+    );
+    let mut buf = Vec::new();
+    write_collation_key(&mut buf, "T-5", SortMode::NaturalLexical);
+    assert_eq!(buf, collation_key("T-5", SortMode::NaturalLexical));
+
+    let explanation = explain_cmp("T-5", "T-20", SortMode::NaturalLexical);
+    assert_eq!(explanation.ordering, Ordering::Less);
+    assert!(!explanation.to_string().is_empty());
+}
+
+#[test]
+#[cfg(feature = "unicode")]
+fn test_transliteration() {
+    assert_eq!(lexical_cmp("Äpfel", "apple"), Ordering::Less);
+    assert_eq!(casefold_cmp("Straße", "strasse"), Ordering::Less);
+    assert!(lexical_eq("Foo", "fóò"));
+    assert_eq!(lexical_cmp_bytes(b"\xC3\x84pfel", b"apple"), Ordering::Less);
+    assert_eq!(lexical_char_cmp('Ä', 'b'), Ordering::Less);
+    assert_eq!(
+        Collator::new()
+            .tailoring(Tailoring::GERMAN_PHONEBOOK)
+            .compare("Müller", "Mufti"),
+        Ordering::Less
+    );
+    assert_eq!(
+        first_lexical_unit("Äpfel", SortMode::Lexical),
+        IndexKey::Letter("a")
+    );
+
+    assert_eq!(iterate_lexical_char('ß').len(), 2);
+    assert_eq!(lexical_to_string("Crème Brûlée"), "creme brulee");
+    let mut normalizer = LexicalNormalizer::new();
+    let mut out = String::new();
+    normalizer.push_str("Bru", &mut out);
+    normalizer.push_char('\u{302}', &mut out);
+    normalizer.push_str("lée", &mut out);
+    assert_eq!(out, "brulee");
+
+    assert_eq!(lexical_find("Grüße", "usse", SortMode::Lexical), Some(2..7));
+    let mut people = [("Zoë", 30), ("anna", 25), ("Émile", 41)];
+    people.sort_by_str_key(lexical_cmp, |p| p.0);
+    assert_eq!(people, [("anna", 25), ("Émile", 41), ("Zoë", 30)]);
+}
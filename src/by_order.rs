@@ -0,0 +1,358 @@
+//! Wrappers that are ordered by a comparator that is chosen with a type parameter.
+
+use crate::compare::Compare;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::Deref;
+#[cfg(feature = "std")]
+use std::path::Path;
+
+/// A string that is ordered by the comparator `C`, so it can be used as a key in a `BTreeMap`
+/// or `BTreeSet`
+///
+/// The comparator is one of the unit structs implementing `Compare`, e.g. `NaturalLexical` for
+/// `natural_lexical_cmp`, so different maps can use different orders without declaring a
+/// newtype for each of them. The string can be any type that implements `AsRef<str>`.
+///
+/// Two values are equal if the comparator returns `Ordering::Equal`. They are hashed by their
+/// string, which is consistent with `Eq` if the comparator only returns `Ordering::Equal` for
+/// identical strings. This is the case for all comparators of this crate, because of their
+/// tie-break.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::{ByOrder, NaturalLexical};
+/// use std::collections::BTreeSet;
+///
+/// let mut set = BTreeSet::new();
+/// set.insert(ByOrder::<_, NaturalLexical>::new("T-20"));
+/// set.insert(ByOrder::new("Äpfel"));
+/// set.insert(ByOrder::new("t-5"));
+///
+/// let sorted: Vec<&str> = set.into_iter().map(ByOrder::into_inner).collect();
+/// assert_eq!(sorted, ["Äpfel", "t-5", "T-20"]);
+/// # }
+/// ```
+pub struct ByOrder<T, C> {
+    value: T,
+    comparator: PhantomData<C>,
+}
+
+impl<T, C> ByOrder<T, C> {
+    /// Wraps a value
+    pub const fn new(value: T) -> Self {
+        ByOrder {
+            value,
+            comparator: PhantomData,
+        }
+    }
+
+    /// Returns the wrapped value
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T, C> From<T> for ByOrder<T, C> {
+    fn from(value: T) -> Self {
+        ByOrder::new(value)
+    }
+}
+
+impl<T, C> Deref for ByOrder<T, C> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T: Clone, C> Clone for ByOrder<T, C> {
+    fn clone(&self) -> Self {
+        ByOrder::new(self.value.clone())
+    }
+}
+
+impl<T: Copy, C> Copy for ByOrder<T, C> {}
+
+impl<T: Default, C> Default for ByOrder<T, C> {
+    fn default() -> Self {
+        ByOrder::new(T::default())
+    }
+}
+
+impl<T: fmt::Debug, C> fmt::Debug for ByOrder<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.value, f)
+    }
+}
+
+impl<T: AsRef<str>, C: Compare + Default> PartialEq for ByOrder<T, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: AsRef<str>, C: Compare + Default> Eq for ByOrder<T, C> {}
+
+impl<T: AsRef<str>, C: Compare + Default> PartialOrd for ByOrder<T, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: AsRef<str>, C: Compare + Default> Ord for ByOrder<T, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        C::default().compare(self.value.as_ref(), other.value.as_ref())
+    }
+}
+
+impl<T: AsRef<str>, C: Compare + Default> Hash for ByOrder<T, C> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.as_ref().hash(state);
+    }
+}
+
+/// Serializes the wrapped value
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize, C> serde::Serialize for ByOrder<T, C> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value.serialize(serializer)
+    }
+}
+
+/// Deserializes the wrapped value
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>, C> serde::Deserialize<'de> for ByOrder<T, C> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(ByOrder::new)
+    }
+}
+
+/// A path that is ordered by the comparator `C`, like `ByOrder`
+///
+/// The path is converted with `to_string_lossy()` in each comparison, like in `PathSort`, and
+/// it is hashed as this string, so `Hash` is consistent with `Eq`.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::{ByPathOrder, NaturalLexical};
+/// use std::collections::BTreeSet;
+/// use std::path::PathBuf;
+///
+/// let set: BTreeSet<ByPathOrder<PathBuf, NaturalLexical>> = ["img/10.png", "Img/3.png"]
+///     .iter()
+///     .map(|&s| PathBuf::from(s).into())
+///     .collect();
+///
+/// let first = set.iter().next().unwrap();
+/// assert_eq!(first.to_str(), Some("Img/3.png"));
+/// ```
+#[cfg(feature = "std")]
+pub struct ByPathOrder<T, C> {
+    value: T,
+    comparator: PhantomData<C>,
+}
+
+#[cfg(feature = "std")]
+impl<T, C> ByPathOrder<T, C> {
+    /// Wraps a value
+    pub const fn new(value: T) -> Self {
+        ByPathOrder {
+            value,
+            comparator: PhantomData,
+        }
+    }
+
+    /// Returns the wrapped value
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, C> From<T> for ByPathOrder<T, C> {
+    fn from(value: T) -> Self {
+        ByPathOrder::new(value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, C> Deref for ByPathOrder<T, C> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Clone, C> Clone for ByPathOrder<T, C> {
+    fn clone(&self) -> Self {
+        ByPathOrder::new(self.value.clone())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Copy, C> Copy for ByPathOrder<T, C> {}
+
+#[cfg(feature = "std")]
+impl<T: Default, C> Default for ByPathOrder<T, C> {
+    fn default() -> Self {
+        ByPathOrder::new(T::default())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug, C> fmt::Debug for ByPathOrder<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.value, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: AsRef<Path>, C: Compare + Default> PartialEq for ByPathOrder<T, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: AsRef<Path>, C: Compare + Default> Eq for ByPathOrder<T, C> {}
+
+#[cfg(feature = "std")]
+impl<T: AsRef<Path>, C: Compare + Default> PartialOrd for ByPathOrder<T, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: AsRef<Path>, C: Compare + Default> Ord for ByPathOrder<T, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        C::default().compare(
+            &self.value.as_ref().to_string_lossy(),
+            &other.value.as_ref().to_string_lossy(),
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: AsRef<Path>, C: Compare + Default> Hash for ByPathOrder<T, C> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.as_ref().to_string_lossy().hash(state);
+    }
+}
+
+/// Serializes the wrapped value
+#[cfg(all(feature = "serde", feature = "std"))]
+impl<T: serde::Serialize, C> serde::Serialize for ByPathOrder<T, C> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value.serialize(serializer)
+    }
+}
+
+/// Deserializes the wrapped value
+#[cfg(all(feature = "serde", feature = "std"))]
+impl<'de, T: serde::Deserialize<'de>, C> serde::Deserialize<'de> for ByPathOrder<T, C> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(ByPathOrder::new)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{LexicalOnlyAlnum, NaturalLexical, Plain};
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::{BTreeSet, HashSet};
+    use std::path::PathBuf;
+
+    fn hash(value: &impl Hash) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_by_order() {
+        let strings = ["T-20", "t-5", "Äpfel", "T-100", "a-c", "ab", "T-5", "t-5"];
+
+        let natural: BTreeSet<ByOrder<String, NaturalLexical>> = strings
+            .iter()
+            .map(|&s| ByOrder::new(s.to_string()))
+            .collect();
+        let only_alnum: BTreeSet<ByOrder<String, LexicalOnlyAlnum>> = strings
+            .iter()
+            .map(|&s| ByOrder::new(s.to_string()))
+            .collect();
+        let plain: BTreeSet<ByOrder<&str, Plain>> =
+            strings.iter().copied().map(From::from).collect();
+
+        let natural: Vec<String> = natural.into_iter().map(ByOrder::into_inner).collect();
+        let only_alnum: Vec<&str> = only_alnum.iter().map(|s| s.as_str()).collect();
+        let plain: Vec<&str> = plain.iter().map(|s| **s).collect();
+        assert_eq!(
+            natural,
+            ["a-c", "ab", "Äpfel", "T-5", "t-5", "T-20", "T-100"]
+        );
+        assert_eq!(
+            only_alnum,
+            ["ab", "a-c", "Äpfel", "T-100", "T-20", "T-5", "t-5"]
+        );
+        assert_eq!(plain, ["T-100", "T-20", "T-5", "a-c", "ab", "t-5", "Äpfel"]);
+
+        // `Eq` and `Hash` are consistent
+        for &a in &strings {
+            for &b in &strings {
+                let (x, y) = (ByOrder::<_, NaturalLexical>::new(a), ByOrder::new(b));
+                assert_eq!(x == y, a == b);
+                assert_eq!(x == y, x.cmp(&y) == Ordering::Equal);
+                if x == y {
+                    assert_eq!(hash(&x), hash(&y));
+                }
+                let (x, y) = (ByOrder::<_, LexicalOnlyAlnum>::new(a), ByOrder::new(b));
+                assert_eq!(x == y, a == b);
+                if x == y {
+                    assert_eq!(hash(&x), hash(&y));
+                }
+            }
+        }
+        let set: HashSet<ByOrder<&str, NaturalLexical>> =
+            strings.iter().copied().map(ByOrder::new).collect();
+        assert_eq!(set.len(), strings.len() - 1);
+    }
+
+    #[test]
+    fn test_by_path_order() {
+        let paths = ["img/10.png", "Img/3.png", "img/2.png", "img/2.png"];
+        let natural: BTreeSet<ByPathOrder<PathBuf, NaturalLexical>> =
+            paths.iter().map(|&s| PathBuf::from(s).into()).collect();
+        let plain: BTreeSet<ByPathOrder<&str, Plain>> =
+            paths.iter().copied().map(ByPathOrder::new).collect();
+
+        let natural: Vec<PathBuf> = natural.into_iter().map(ByPathOrder::into_inner).collect();
+        let expected = ["img/2.png", "Img/3.png", "img/10.png"];
+        assert!(natural.iter().eq(expected
+            .iter()
+            .map(PathBuf::from)
+            .collect::<Vec<_>>()
+            .iter()));
+        let plain: Vec<&str> = plain.iter().map(|p| **p).collect();
+        assert_eq!(plain, ["Img/3.png", "img/10.png", "img/2.png"]);
+
+        let (x, y) = (
+            ByPathOrder::<_, NaturalLexical>::new(Path::new("a/b")),
+            ByPathOrder::new(Path::new("a/b")),
+        );
+        assert_eq!(x, y);
+        assert_eq!(hash(&x), hash(&y));
+        assert_ne!(x, ByPathOrder::new(Path::new("a//b")));
+    }
+}
@@ -0,0 +1,354 @@
+//! Comparison and sorting of byte strings that aren't guaranteed to be valid UTF-8.
+
+use crate::collator::{Collator, LeadingZeros, Step};
+use crate::compare::SortMode;
+use core::cmp::Ordering;
+use core::str;
+
+/// Compares byte strings like `lexical_cmp`, without converting them to strings first
+///
+/// The bytes are decoded as UTF-8. Valid UTF-8 is compared exactly like `lexical_cmp`
+/// compares it. Each byte that isn't part of a valid UTF-8 sequence, such as the bytes of a
+/// truncated multi-byte sequence, is compared as a character that sorts after all valid
+/// characters. Invalid bytes are compared with each other by their value.
+///
+/// Unlike `String::from_utf8_lossy`, this doesn't allocate, and it doesn't conflate invalid
+/// bytes with the replacement character `�`.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::lexical_cmp_bytes;
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(lexical_cmp_bytes("Äpfel".as_bytes(), b"apfel"), Ordering::Greater);
+/// assert_eq!(lexical_cmp_bytes(b"apfel", b"Birne"), Ordering::Less);
+/// // invalid bytes sort after all characters
+/// assert_eq!(lexical_cmp_bytes(b"a\xFF", b"az"), Ordering::Greater);
+/// assert_eq!(lexical_cmp_bytes(b"a\xFF", "a\u{FFFD}".as_bytes()), Ordering::Greater);
+/// ```
+pub fn lexical_cmp_bytes(lhs: &[u8], rhs: &[u8]) -> Ordering {
+    cmp_bytes(lhs, rhs, SortMode::Lexical)
+}
+
+/// Compares byte strings like `natural_lexical_cmp`, without converting them to strings first
+///
+/// Invalid UTF-8 is handled like in `lexical_cmp_bytes`. An invalid byte ends a number, so
+/// `b"10\xFF5"` contains the numbers `10` and `5`.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::natural_lexical_cmp_bytes;
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(natural_lexical_cmp_bytes(b"T-5", b"t-20"), Ordering::Less);
+/// assert_eq!(natural_lexical_cmp_bytes(b"T-5\xFF", b"T-20"), Ordering::Less);
+/// assert_eq!(natural_lexical_cmp_bytes(b"T-5\xFF", b"T-5a"), Ordering::Greater);
+/// ```
+pub fn natural_lexical_cmp_bytes(lhs: &[u8], rhs: &[u8]) -> Ordering {
+    cmp_bytes(lhs, rhs, SortMode::NaturalLexical)
+}
+
+/// Compares byte strings like `natural_cmp`, without converting them to strings first
+///
+/// Invalid UTF-8 is handled like in `lexical_cmp_bytes`.
+pub fn natural_cmp_bytes(lhs: &[u8], rhs: &[u8]) -> Ordering {
+    cmp_bytes(lhs, rhs, SortMode::Natural)
+}
+
+/// Compares byte strings with the given mode, without converting them to strings first
+///
+/// Valid UTF-8 is compared like the comparison function of the mode compares it. Invalid
+/// UTF-8 is handled like in `lexical_cmp_bytes`: Each invalid byte sorts after all valid
+/// characters, also in modes that skip non-alphanumeric characters.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::{cmp_bytes, SortMode};
+/// use std::cmp::Ordering;
+///
+/// let (lhs, rhs) = (b"f-5.txt", b"f5\xC3");
+/// assert_eq!(cmp_bytes(lhs, rhs, SortMode::NaturalLexicalOnlyAlnum), Ordering::Less);
+/// ```
+pub fn cmp_bytes(lhs: &[u8], rhs: &[u8], mode: SortMode) -> Ordering {
+    if let (Ok(lhs), Ok(rhs)) = (str::from_utf8(lhs), str::from_utf8(rhs)) {
+        return mode.function()(lhs, rhs);
+    }
+
+    // leading zeros only break ties, like the tie-break of the parts
+    let collator = Collator::from(mode).leading_zeros(LeadingZeros::Ignore);
+    let mut chunks1 = Utf8Chunks(lhs);
+    let mut chunks2 = Utf8Chunks(rhs);
+    // the tie-break of the first valid parts that are equal after folding, but not identical,
+    // e.g. because of their case or leading zeros
+    let mut tie = Ordering::Equal;
+    loop {
+        let (s1, invalid1) = chunks1.next_chunk();
+        let (s2, invalid2) = chunks2.next_chunk();
+        match collator.first_difference(s1, s2) {
+            // the shorter part is followed by an invalid byte, which is greater than any
+            // character, or by the end of the string
+            Step::Length(Ordering::Less) if invalid1.is_some() => return Ordering::Greater,
+            Step::Length(Ordering::Greater) if invalid2.is_some() => return Ordering::Less,
+            Step::Length(ordering) => return ordering,
+            Step::Equal { .. } if mode.is_only_alnum() => {}
+            Step::Equal { .. } => tie = tie.then_with(|| mode.function()(s1, s2)),
+            _ => return mode.function()(s1, s2),
+        }
+        match (invalid1, invalid2) {
+            (Some(b1), Some(b2)) if b1 == b2 => {}
+            (None, None) if mode.is_only_alnum() => {
+                let mode = SortMode::from_flags(mode.is_lexical(), mode.is_natural(), false);
+                return cmp_bytes(lhs, rhs, mode);
+            }
+            (invalid1, invalid2) => return invalid1.cmp(&invalid2).then(tie),
+        }
+    }
+}
+
+/// Splits a byte string into valid UTF-8 and the invalid bytes between it
+struct Utf8Chunks<'a>(&'a [u8]);
+
+impl<'a> Utf8Chunks<'a> {
+    /// Returns the longest valid UTF-8 at the start, and the invalid byte after it, if the
+    /// rest of the bytes isn't valid UTF-8. The invalid byte is consumed, so an invalid or
+    /// truncated multi-byte sequence is returned one byte at a time.
+    fn next_chunk(&mut self) -> (&'a str, Option<u8>) {
+        let valid = match str::from_utf8(self.0) {
+            Ok(s) => {
+                self.0 = &[];
+                return (s, None);
+            }
+            Err(e) => e.valid_up_to(),
+        };
+        let (s, rest) = self.0.split_at(valid);
+        self.0 = &rest[1..];
+        (str::from_utf8(s).unwrap_or_default(), Some(rest[0]))
+    }
+}
+
+/// A trait to sort byte strings that may contain invalid UTF-8, such as file names from
+/// archives. This is a convenient wrapper for the standard library sort functions, like
+/// `StringSort`.
+///
+/// This trait is implemented for all slices whose inner type implements `AsRef<[u8]>`, e.g.
+/// `[Vec<u8>]` and `[&[u8]]`.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::ByteSort;
+///
+/// let slice: &mut [&[u8]] = &mut [b"T-20", b"\xFFinvalid", b"t-5", "Äpfel".as_bytes()];
+/// slice.byte_sort_unstable(lexical_sort::natural_lexical_cmp_bytes);
+///
+/// assert_eq!(slice, &["Äpfel".as_bytes(), b"t-5", b"T-20", b"\xFFinvalid"]);
+/// # }
+/// ```
+pub trait ByteSort {
+    /// Sorts the items using the provided comparison function.
+    ///
+    /// **This is a stable sort, which is often not required**.
+    /// You can use `byte_sort_unstable` instead.
+    #[cfg(feature = "alloc")]
+    fn byte_sort(&mut self, cmp: impl FnMut(&[u8], &[u8]) -> Ordering);
+
+    /// Sorts the items using the provided comparison function.
+    ///
+    /// This sort is unstable: The original order of equal byte strings is not preserved.
+    /// It is slightly more efficient than the stable alternative.
+    fn byte_sort_unstable(&mut self, cmp: impl FnMut(&[u8], &[u8]) -> Ordering);
+
+    /// Sorts the items using the provided comparison function and another function that is
+    /// applied to each byte string before the comparison, e.g. to strip a prefix.
+    ///
+    /// **This is a stable sort, which is often not required**.
+    /// You can use `byte_sort_unstable_by` instead.
+    #[cfg(feature = "alloc")]
+    fn byte_sort_by<Cmp, Map>(&mut self, cmp: Cmp, map: Map)
+    where
+        Cmp: FnMut(&[u8], &[u8]) -> Ordering,
+        Map: FnMut(&[u8]) -> &[u8];
+
+    /// Sorts the items using the provided comparison function and another function that is
+    /// applied to each byte string before the comparison, e.g. to strip a prefix.
+    ///
+    /// This sort is unstable: The original order of equal byte strings is not preserved.
+    /// It is slightly more efficient than the stable alternative.
+    fn byte_sort_unstable_by<Cmp, Map>(&mut self, cmp: Cmp, map: Map)
+    where
+        Cmp: FnMut(&[u8], &[u8]) -> Ordering,
+        Map: FnMut(&[u8]) -> &[u8];
+}
+
+impl<A: AsRef<[u8]>> ByteSort for [A] {
+    #[cfg(feature = "alloc")]
+    fn byte_sort(&mut self, mut cmp: impl FnMut(&[u8], &[u8]) -> Ordering) {
+        self.sort_by(|lhs, rhs| cmp(lhs.as_ref(), rhs.as_ref()));
+    }
+
+    fn byte_sort_unstable(&mut self, mut cmp: impl FnMut(&[u8], &[u8]) -> Ordering) {
+        self.sort_unstable_by(|lhs, rhs| cmp(lhs.as_ref(), rhs.as_ref()));
+    }
+
+    #[cfg(feature = "alloc")]
+    fn byte_sort_by<Cmp, Map>(&mut self, mut cmp: Cmp, mut map: Map)
+    where
+        Cmp: FnMut(&[u8], &[u8]) -> Ordering,
+        Map: FnMut(&[u8]) -> &[u8],
+    {
+        self.sort_by(|lhs, rhs| cmp(map(lhs.as_ref()), map(rhs.as_ref())));
+    }
+
+    fn byte_sort_unstable_by<Cmp, Map>(&mut self, mut cmp: Cmp, mut map: Map)
+    where
+        Cmp: FnMut(&[u8], &[u8]) -> Ordering,
+        Map: FnMut(&[u8]) -> &[u8],
+    {
+        self.sort_unstable_by(|lhs, rhs| cmp(map(lhs.as_ref()), map(rhs.as_ref())));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{lexical_cmp, natural_cmp, natural_lexical_cmp};
+
+    const STRINGS: [&str; 14] = [
+        "",
+        "a",
+        "A",
+        "ä",
+        "aa",
+        "ß",
+        "ss",
+        "T-5",
+        "t-20",
+        "T-05",
+        "½",
+        "10 things",
+        "\u{FFFD}",
+        "日本",
+    ];
+
+    #[test]
+    fn test_valid_utf8_parity() {
+        for &lhs in &STRINGS {
+            for &rhs in &STRINGS {
+                let (l, r) = (lhs.as_bytes(), rhs.as_bytes());
+                assert_eq!(lexical_cmp_bytes(l, r), lexical_cmp(lhs, rhs));
+                assert_eq!(
+                    natural_lexical_cmp_bytes(l, r),
+                    natural_lexical_cmp(lhs, rhs)
+                );
+                assert_eq!(natural_cmp_bytes(l, r), natural_cmp(lhs, rhs));
+                for i in 0..8 {
+                    let mode = SortMode::from_flags(i & 1 != 0, i & 2 != 0, i & 4 != 0);
+                    assert_eq!(cmp_bytes(l, r, mode), mode.function()(lhs, rhs));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_utf8() {
+        // "€" is E2 82 AC, so these are truncated sequences
+        let sorted: [&[u8]; 12] = [
+            b"a",
+            b"a2",
+            b"a10",
+            "a\u{FFFD}".as_bytes(),
+            b"a\x82",
+            b"a\xE2",
+            b"a\xE2\x82",
+            b"a\xE2\x82b",
+            b"A\xFF",
+            b"a\xFF",
+            b"b",
+            b"\xE2",
+        ];
+        for (i, lhs) in sorted.iter().enumerate() {
+            for (j, rhs) in sorted.iter().enumerate() {
+                assert_eq!(
+                    natural_lexical_cmp_bytes(lhs, rhs),
+                    i.cmp(&j),
+                    "{:?} {:?}",
+                    lhs,
+                    rhs
+                );
+            }
+        }
+
+        assert_eq!(lexical_cmp_bytes(b"a10\xFF", b"a9"), Ordering::Less);
+        assert_eq!(natural_cmp_bytes(b"a10\xFF", b"a9"), Ordering::Greater);
+        assert_eq!(natural_cmp_bytes(b"a9\xFF", b"a9b"), Ordering::Greater);
+        assert_eq!(natural_cmp_bytes(b"a9\xFFb", b"a9\xFFc"), Ordering::Less);
+        assert_eq!(lexical_cmp_bytes(b"\xE2\x82", b"\xE2\x82"), Ordering::Equal);
+        assert_eq!(lexical_cmp_bytes(b"\xE2\x82", b"\xE2"), Ordering::Greater);
+        assert_eq!(
+            lexical_cmp_bytes(b"\xE2\x82a", b"\xE2\x82b"),
+            Ordering::Less
+        );
+        assert_eq!(lexical_cmp_bytes(b"x\xFFa", b"X\xFFa"), Ordering::Greater);
+        assert_eq!(
+            lexical_cmp_bytes(b"x\xFFa\xFE", b"X\xFFa\xFF"),
+            Ordering::Less
+        );
+        let only_alnum = SortMode::LexicalOnlyAlnum;
+        assert_eq!(cmp_bytes(b"-\xFF", b"a", only_alnum), Ordering::Greater);
+        assert_eq!(cmp_bytes(b"-a\xFF", b"a\xFF", only_alnum), Ordering::Less);
+        assert_eq!(cmp_bytes(b"a-\xFF", b"a\xFF", only_alnum), Ordering::Less);
+        assert_eq!(
+            cmp_bytes(b"a-\xFF", b"a\xFE", only_alnum),
+            Ordering::Greater
+        );
+
+        // leading zeros only break ties, so the order is consistent
+        let triples: [(SortMode, [&[u8]; 3]); 2] = [
+            (SortMode::NaturalLexical, [b"00", b"00a", b"0\xFFs"]),
+            (SortMode::Natural, [b"02", b"2 a0", b"2\xFF0"]),
+        ];
+        for &(mode, sorted) in &triples {
+            for (i, lhs) in sorted.iter().enumerate() {
+                for (j, rhs) in sorted.iter().enumerate() {
+                    assert_eq!(cmp_bytes(lhs, rhs, mode), i.cmp(&j), "{:?} {:?}", lhs, rhs);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_byte_sort() {
+        let unsorted: [&[u8]; 5] = [b"T-20", b"\xFF", "Äpfel".as_bytes(), b"t-5", b"apfel"];
+        let sorted: [&[u8]; 5] = [b"apfel", "Äpfel".as_bytes(), b"t-5", b"T-20", b"\xFF"];
+
+        let mut slice = unsorted;
+        slice.byte_sort(natural_lexical_cmp_bytes);
+        assert_eq!(slice, sorted);
+        let mut slice = unsorted;
+        slice.byte_sort_unstable(natural_lexical_cmp_bytes);
+        assert_eq!(slice, sorted);
+
+        fn strip(s: &[u8]) -> &[u8] {
+            s.strip_prefix(b"T-").unwrap_or(s)
+        }
+        let mut slice = unsorted;
+        slice.byte_sort_by(natural_lexical_cmp_bytes, strip);
+        assert_eq!(slice[..2], [&b"T-20"[..], b"apfel"]);
+        let mut slice = unsorted;
+        slice.byte_sort_unstable_by(natural_lexical_cmp_bytes, strip);
+        assert_eq!(slice[..2], [&b"T-20"[..], b"apfel"]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_byte_sort_owned() {
+        let mut vec = vec![b"b\xFF".to_vec(), b"B".to_vec(), b"a".to_vec()];
+        vec.byte_sort_unstable(lexical_cmp_bytes);
+        assert_eq!(vec, [b"a".to_vec(), b"B".to_vec(), b"b\xFF".to_vec()]);
+    }
+}
@@ -0,0 +1,243 @@
+//! A comparator that caches the sort keys of the compared strings.
+
+use crate::compare::{Compare, SortMode};
+use crate::sort_key::SortKey;
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
+
+/// The number of keys that are cached by `CachedCollator::new`
+const DEFAULT_CAPACITY: usize = 65_536;
+
+/// A comparator that remembers the `SortKey` of every string it compares, so the strings
+/// aren't transliterated again when they are compared or sorted another time
+///
+/// This is useful when the same strings are sorted repeatedly, e.g. the rows of a table
+/// whenever the user clicks on a column header. To sort a slice only once, use
+/// `[_]::sort_by_cached_key()` with a `SortKey` instead.
+///
+/// The keys are stored by the contents of the strings, so it doesn't matter where the
+/// strings are stored, or if they were modified or dropped in the meantime: A string that was
+/// changed simply gets a new key. The downside is that every string is copied into the cache.
+/// To limit the memory usage, the cache holds at most `capacity` keys. When it's full, the
+/// least recently used quarter of the keys is removed. All keys can be removed with `clear`.
+///
+/// The cache uses a `RefCell`, so a `CachedCollator` can be sent to another thread, but it
+/// can't be shared between threads; wrap it in a `Mutex` for that.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::{CachedCollator, SortMode, StringSort};
+///
+/// let collator = CachedCollator::new(SortMode::NaturalLexical);
+/// let mut rows = vec!["T-20", "t-5", "T-100"];
+///
+/// rows.string_sort_unstable(|lhs, rhs| collator.cmp(lhs, rhs));
+/// assert_eq!(rows, ["t-5", "T-20", "T-100"]);
+/// assert_eq!(collator.len(), 3);
+///
+/// // the keys are reused
+/// rows.reverse();
+/// rows.string_sort_unstable_with(&collator);
+/// assert_eq!(rows, ["t-5", "T-20", "T-100"]);
+/// assert_eq!(collator.len(), 3);
+/// ```
+pub struct CachedCollator {
+    mode: SortMode,
+    capacity: usize,
+    cache: RefCell<Cache>,
+}
+
+struct Cache {
+    keys: HashMap<String, Entry>,
+    /// Incremented whenever a key is used, to find the least recently used keys
+    clock: u64,
+}
+
+struct Entry {
+    key: SortKey,
+    used: u64,
+}
+
+impl CachedCollator {
+    /// Creates a comparator that compares strings like `mode.compare()` and caches up to
+    /// 65536 keys
+    pub fn new(mode: SortMode) -> Self {
+        CachedCollator::with_capacity(mode, DEFAULT_CAPACITY)
+    }
+
+    /// Creates a comparator that compares strings like `mode.compare()` and caches up to
+    /// `capacity` keys
+    pub fn with_capacity(mode: SortMode, capacity: usize) -> Self {
+        CachedCollator {
+            mode,
+            capacity,
+            cache: RefCell::new(Cache {
+                keys: HashMap::new(),
+                clock: 0,
+            }),
+        }
+    }
+
+    /// Compares two strings like `self.mode().compare()`, using their cached keys
+    pub fn cmp(&self, lhs: &str, rhs: &str) -> Ordering {
+        let mut cache = self.cache.borrow_mut();
+        cache.insert(lhs, self.mode, self.capacity);
+        cache.insert(rhs, self.mode, self.capacity);
+        match (cache.keys.get(lhs), cache.keys.get(rhs)) {
+            (Some(lhs), Some(rhs)) => lhs.key.cmp(&rhs.key),
+            // a key was evicted, because the capacity is tiny
+            _ => self.mode.compare(lhs, rhs),
+        }
+    }
+
+    /// Returns the sort mode
+    pub fn mode(&self) -> SortMode {
+        self.mode
+    }
+
+    /// Returns the maximum number of cached keys
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of cached keys
+    pub fn len(&self) -> usize {
+        self.cache.borrow().keys.len()
+    }
+
+    /// Returns `true` if no keys are cached
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all cached keys
+    pub fn clear(&self) {
+        self.cache.borrow_mut().keys.clear();
+    }
+}
+
+impl Cache {
+    /// Creates the key of a string if it isn't cached yet, and marks it as recently used
+    fn insert(&mut self, s: &str, mode: SortMode, capacity: usize) {
+        self.clock += 1;
+        if let Some(entry) = self.keys.get_mut(s) {
+            entry.used = self.clock;
+            return;
+        }
+        if capacity == 0 {
+            return;
+        }
+        if self.keys.len() >= capacity {
+            self.evict();
+        }
+        let entry = Entry {
+            key: SortKey::new(s, mode),
+            used: self.clock,
+        };
+        self.keys.insert(s.to_string(), entry);
+    }
+
+    /// Removes the least recently used quarter of the keys, so this is only needed once in
+    /// a while
+    fn evict(&mut self) {
+        let mut used: Vec<u64> = self.keys.values().map(|entry| entry.used).collect();
+        let n = (used.len() / 4).max(1);
+        let (_, &mut threshold, _) = used.select_nth_unstable(n - 1);
+        self.keys.retain(|_, entry| entry.used > threshold);
+    }
+}
+
+impl Compare for CachedCollator {
+    #[inline]
+    fn compare(&self, lhs: &str, rhs: &str) -> Ordering {
+        self.cmp(lhs, rhs)
+    }
+}
+
+impl fmt::Debug for CachedCollator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CachedCollator")
+            .field("mode", &self.mode)
+            .field("capacity", &self.capacity)
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StringSort;
+
+    const STRINGS: [&str; 12] = [
+        "T-20", "b", "t-5", "", "Äpfel", "T-100", "apfel", "-", "T-5", "ß", "Foo", "fóò",
+    ];
+
+    #[test]
+    fn test_cached_collator() {
+        for &mode in SortMode::ALL.iter() {
+            let collator = CachedCollator::new(mode);
+            for &a in STRINGS.iter() {
+                for &b in STRINGS.iter() {
+                    assert_eq!(
+                        collator.cmp(a, b),
+                        mode.compare(a, b),
+                        "{} {:?} {:?}",
+                        mode,
+                        a,
+                        b
+                    );
+                }
+            }
+            assert_eq!(collator.len(), STRINGS.len());
+
+            let mut sorted = STRINGS;
+            sorted.string_sort_unstable_with(&collator);
+            let mut expected = STRINGS;
+            expected.string_sort_unstable(mode.function());
+            assert_eq!(sorted, expected);
+
+            collator.clear();
+            assert!(collator.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_capacity() {
+        for &capacity in [0, 1, 2, 5].iter() {
+            let collator = CachedCollator::with_capacity(SortMode::NaturalLexical, capacity);
+            for _ in 0..3 {
+                let mut sorted = STRINGS;
+                sorted.string_sort_unstable(|a, b| collator.cmp(a, b));
+                let mut expected = STRINGS;
+                expected.string_sort_unstable(crate::natural_lexical_cmp);
+                assert_eq!(sorted, expected);
+                assert!(collator.len() <= capacity);
+            }
+        }
+
+        // the least recently used keys are evicted
+        let collator = CachedCollator::with_capacity(SortMode::Lexical, 4);
+        for s in ["a", "b", "c", "d"].iter() {
+            collator.cmp(s, s);
+        }
+        collator.cmp("a", "b");
+        collator.cmp("e", "e");
+        let cache = collator.cache.borrow();
+        let mut keys: Vec<&str> = cache.keys.keys().map(String::as_str).collect();
+        keys.sort_unstable();
+        assert_eq!(keys, ["a", "b", "d", "e"]);
+    }
+
+    #[test]
+    fn test_send() {
+        fn assert_send<T: Send>(_: &T) {}
+        fn assert_sync<T: Sync>(_: &T) {}
+        let collator = CachedCollator::new(SortMode::Lexical);
+        assert_send(&collator);
+        assert_sync(&std::sync::Mutex::new(collator));
+    }
+}
@@ -0,0 +1,137 @@
+//! Case-insensitive comparisons without transliteration.
+
+use crate::cmp::cmp_ascii_digits;
+use crate::digit::to_ascii_digit;
+use core::cmp::Ordering;
+
+/// Compares strings case-insensitively, without transliterating them
+///
+/// Unlike the lexical comparison functions, characters aren't converted to ASCII, so Greek
+/// stays Greek and Cyrillic stays Cyrillic. Instead, every character is case folded, so
+/// `"straße"` is compared like `"STRASSE"`, the final sigma `ς` like `σ`, and Cherokee
+/// letters like their uppercase forms. The folded strings are compared by their code points.
+///
+/// Characters are case folded by converting them to lowercase, then to uppercase, then to
+/// lowercase again, with the case mappings of the standard library. Strings are equal after
+/// this if and only if they are equal after Unicode full case folding, although some
+/// characters are folded to a different representative, e.g. Cherokee letters are folded to
+/// lowercase. This doesn't depend on the locale, so the Turkish dotless `ı` is only equal to
+/// itself, and `İ` is folded to `i` followed by a combining dot above.
+///
+/// If the strings are equal after case folding, they are compared with `str::cmp`, so only
+/// identical strings are equal.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::{casefold_cmp, StringSort};
+///
+/// let slice = &mut ["σοφία", "Straße", "ΣΟΦΙΑ", "strasse", "Äpfel", "apfel"];
+/// slice.string_sort_unstable(casefold_cmp);
+/// assert_eq!(slice, &["apfel", "Straße", "strasse", "Äpfel", "σοφία", "ΣΟΦΙΑ"]);
+/// ```
+pub fn casefold_cmp(s1: &str, s2: &str) -> Ordering {
+    iterate_case_folded(s1)
+        .cmp(iterate_case_folded(s2))
+        .then_with(|| s1.cmp(s2))
+}
+
+/// Compares strings case-insensitively and naturally, without transliterating them
+///
+/// This is like `casefold_cmp`, but numbers are compared by their value like in
+/// `natural_cmp`, so `"Ω50" < "ω100"`. Leading zeros of numbers are ignored, unless the
+/// strings are equal otherwise, so `"07" < "8"` and `"7" < "07"`.
+pub fn natural_casefold_cmp(s1: &str, s2: &str) -> Ordering {
+    let mut iter1 = iterate_case_folded(s1).map(to_ascii_digit).peekable();
+    let mut iter2 = iterate_case_folded(s2).map(to_ascii_digit).peekable();
+    // the ordering of the leading zeros of the first numbers that differ only in them
+    let mut zeros = Ordering::Equal;
+
+    loop {
+        match (iter1.next(), iter2.next()) {
+            (Some(lhs), Some(rhs)) => {
+                if lhs.is_ascii_digit() && rhs.is_ascii_digit() {
+                    match cmp_ascii_digits(lhs, rhs, &mut iter1, &mut iter2) {
+                        (Ordering::Equal, leading_zeros) => zeros = zeros.then(leading_zeros),
+                        (ordering, _) => return ordering,
+                    }
+                } else if lhs != rhs {
+                    return lhs.cmp(&rhs);
+                }
+            }
+            (Some(_), None) => return Ordering::Greater,
+            (None, Some(_)) => return Ordering::Less,
+            (None, None) => return zeros.then_with(|| s1.cmp(s2)),
+        }
+    }
+}
+
+/// Returns an iterator over the case folded characters of a string
+fn iterate_case_folded(s: &str) -> impl Iterator<Item = char> + '_ {
+    s.chars().flat_map(char::to_lowercase).flat_map(fold_char)
+}
+
+/// Case folds a lowercase character, e.g. `ß` is folded to `ss`, and `ς` to `σ`. The
+/// dotless `ı` isn't folded to `i`.
+fn fold_char(c: char) -> impl Iterator<Item = char> {
+    c.to_uppercase()
+        .flat_map(char::to_lowercase)
+        .map(move |folded| if c == 'ı' { c } else { folded })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_case_folding() {
+        let folded_eq = |lhs, rhs| iterate_case_folded(lhs).eq(iterate_case_folded(rhs));
+        assert!(folded_eq("straße", "STRASSE"));
+        assert!(folded_eq("STRAẞE", "strasse"));
+        assert!(folded_eq("ὀδυσσεύς", "ὈΔΥΣΣΕΎΣ"));
+        assert!(folded_eq("ᏣᎳᎩ", "ꮳꮃꭹ"));
+        assert!(folded_eq("ﬁle", "FILE"));
+        assert!(folded_eq("\u{212A}elvin", "kelvin"));
+        assert!(folded_eq("ſ", "s"));
+        assert!(folded_eq("ǅ", "ǆ"));
+        assert!(folded_eq("İ", "i\u{307}"));
+        assert!(!folded_eq("ı", "i"));
+        assert!(!folded_eq("é", "e"));
+        assert!(!folded_eq("σ", "s"));
+    }
+
+    #[test]
+    fn test_casefold_cmp() {
+        // equal after case folding, with a deterministic tie-break
+        assert_eq!(casefold_cmp("straße", "STRASSE"), Ordering::Greater);
+        assert_eq!(casefold_cmp("STRASSE", "straße"), Ordering::Less);
+        assert_eq!(casefold_cmp("straße", "straße"), Ordering::Equal);
+        assert_eq!(casefold_cmp("ΣΟΦΟΣ", "σοφος"), Ordering::Less);
+        assert_eq!(casefold_cmp("σοφος", "σοφοσ"), Ordering::Less);
+
+        assert_eq!(casefold_cmp("Strassen", "straße"), Ordering::Greater);
+        assert_eq!(casefold_cmp("B", "a"), Ordering::Greater);
+        assert_eq!(casefold_cmp("Ä", "b"), Ordering::Greater);
+        assert_eq!(casefold_cmp("Ω", "α"), Ordering::Greater);
+        assert_eq!(casefold_cmp("Ж", "я"), Ordering::Less);
+        assert_eq!(casefold_cmp("a10", "A9"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_natural_casefold_cmp() {
+        assert_eq!(natural_casefold_cmp("Ω50", "ω100"), Ordering::Less);
+        assert_eq!(natural_casefold_cmp("a10", "A9"), Ordering::Greater);
+        assert_eq!(natural_casefold_cmp("07", "8"), Ordering::Less);
+        assert_eq!(natural_casefold_cmp("7", "07"), Ordering::Less);
+        assert_eq!(
+            natural_casefold_cmp("Straße 2", "STRASSE 10"),
+            Ordering::Less
+        );
+        assert_eq!(
+            natural_casefold_cmp("STRASSE 2", "straße 2"),
+            Ordering::Less
+        );
+        assert_eq!(natural_casefold_cmp("x٣", "X١٢"), Ordering::Less);
+        assert_eq!(natural_casefold_cmp("x 1", "x 1"), Ordering::Equal);
+    }
+}
@@ -0,0 +1,473 @@
+//! General category groups of non-alphanumeric characters.
+//!
+//! The table was generated from the Unicode Character Database, version 14.0.0.
+
+use core::cmp::Ordering;
+
+/// A group of Unicode general categories
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum CategoryGroup {
+    /// Space separators (`Zs`)
+    Space,
+    /// Punctuation (`Pc`, `Pd`, `Ps`, `Pe`, `Pi`, `Pf`, `Po`)
+    Punctuation,
+    /// Symbols (`Sm`, `Sc`, `Sk`, `So`)
+    Symbol,
+    /// All other characters
+    Other,
+}
+
+use CategoryGroup::*;
+
+/// Returns the general category group of a character
+pub(crate) fn category_group(c: char) -> CategoryGroup {
+    let index = CATEGORY_GROUPS.binary_search_by(|&(start, end, _)| {
+        if end < c {
+            Ordering::Less
+        } else if start > c {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    });
+    match index {
+        Ok(i) => CATEGORY_GROUPS[i].2,
+        Err(_) => Other,
+    }
+}
+
+/// Sorted, non-overlapping ranges of characters in the `Space`, `Punctuation` and `Symbol`
+/// groups
+#[rustfmt::skip]
+const CATEGORY_GROUPS: &[(char, char, CategoryGroup)] = &[
+    ('\u{20}', '\u{20}', Space),
+    ('\u{21}', '\u{23}', Punctuation),
+    ('\u{24}', '\u{24}', Symbol),
+    ('\u{25}', '\u{2A}', Punctuation),
+    ('\u{2B}', '\u{2B}', Symbol),
+    ('\u{2C}', '\u{2F}', Punctuation),
+    ('\u{3A}', '\u{3B}', Punctuation),
+    ('\u{3C}', '\u{3E}', Symbol),
+    ('\u{3F}', '\u{40}', Punctuation),
+    ('\u{5B}', '\u{5D}', Punctuation),
+    ('\u{5E}', '\u{5E}', Symbol),
+    ('\u{5F}', '\u{5F}', Punctuation),
+    ('\u{60}', '\u{60}', Symbol),
+    ('\u{7B}', '\u{7B}', Punctuation),
+    ('\u{7C}', '\u{7C}', Symbol),
+    ('\u{7D}', '\u{7D}', Punctuation),
+    ('\u{7E}', '\u{7E}', Symbol),
+    ('\u{A0}', '\u{A0}', Space),
+    ('\u{A1}', '\u{A1}', Punctuation),
+    ('\u{A2}', '\u{A6}', Symbol),
+    ('\u{A7}', '\u{A7}', Punctuation),
+    ('\u{A8}', '\u{A9}', Symbol),
+    ('\u{AB}', '\u{AB}', Punctuation),
+    ('\u{AC}', '\u{AC}', Symbol),
+    ('\u{AE}', '\u{B1}', Symbol),
+    ('\u{B4}', '\u{B4}', Symbol),
+    ('\u{B6}', '\u{B7}', Punctuation),
+    ('\u{B8}', '\u{B8}', Symbol),
+    ('\u{BB}', '\u{BB}', Punctuation),
+    ('\u{BF}', '\u{BF}', Punctuation),
+    ('\u{D7}', '\u{D7}', Symbol),
+    ('\u{F7}', '\u{F7}', Symbol),
+    ('\u{2C2}', '\u{2C5}', Symbol),
+    ('\u{2D2}', '\u{2DF}', Symbol),
+    ('\u{2E5}', '\u{2EB}', Symbol),
+    ('\u{2ED}', '\u{2ED}', Symbol),
+    ('\u{2EF}', '\u{2FF}', Symbol),
+    ('\u{375}', '\u{375}', Symbol),
+    ('\u{37E}', '\u{37E}', Punctuation),
+    ('\u{384}', '\u{385}', Symbol),
+    ('\u{387}', '\u{387}', Punctuation),
+    ('\u{3F6}', '\u{3F6}', Symbol),
+    ('\u{482}', '\u{482}', Symbol),
+    ('\u{55A}', '\u{55F}', Punctuation),
+    ('\u{589}', '\u{58A}', Punctuation),
+    ('\u{58D}', '\u{58F}', Symbol),
+    ('\u{5BE}', '\u{5BE}', Punctuation),
+    ('\u{5C0}', '\u{5C0}', Punctuation),
+    ('\u{5C3}', '\u{5C3}', Punctuation),
+    ('\u{5C6}', '\u{5C6}', Punctuation),
+    ('\u{5F3}', '\u{5F4}', Punctuation),
+    ('\u{606}', '\u{608}', Symbol),
+    ('\u{609}', '\u{60A}', Punctuation),
+    ('\u{60B}', '\u{60B}', Symbol),
+    ('\u{60C}', '\u{60D}', Punctuation),
+    ('\u{60E}', '\u{60F}', Symbol),
+    ('\u{61B}', '\u{61B}', Punctuation),
+    ('\u{61D}', '\u{61F}', Punctuation),
+    ('\u{66A}', '\u{66D}', Punctuation),
+    ('\u{6D4}', '\u{6D4}', Punctuation),
+    ('\u{6DE}', '\u{6DE}', Symbol),
+    ('\u{6E9}', '\u{6E9}', Symbol),
+    ('\u{6FD}', '\u{6FE}', Symbol),
+    ('\u{700}', '\u{70D}', Punctuation),
+    ('\u{7F6}', '\u{7F6}', Symbol),
+    ('\u{7F7}', '\u{7F9}', Punctuation),
+    ('\u{7FE}', '\u{7FF}', Symbol),
+    ('\u{830}', '\u{83E}', Punctuation),
+    ('\u{85E}', '\u{85E}', Punctuation),
+    ('\u{888}', '\u{888}', Symbol),
+    ('\u{964}', '\u{965}', Punctuation),
+    ('\u{970}', '\u{970}', Punctuation),
+    ('\u{9F2}', '\u{9F3}', Symbol),
+    ('\u{9FA}', '\u{9FB}', Symbol),
+    ('\u{9FD}', '\u{9FD}', Punctuation),
+    ('\u{A76}', '\u{A76}', Punctuation),
+    ('\u{AF0}', '\u{AF0}', Punctuation),
+    ('\u{AF1}', '\u{AF1}', Symbol),
+    ('\u{B70}', '\u{B70}', Symbol),
+    ('\u{BF3}', '\u{BFA}', Symbol),
+    ('\u{C77}', '\u{C77}', Punctuation),
+    ('\u{C7F}', '\u{C7F}', Symbol),
+    ('\u{C84}', '\u{C84}', Punctuation),
+    ('\u{D4F}', '\u{D4F}', Symbol),
+    ('\u{D79}', '\u{D79}', Symbol),
+    ('\u{DF4}', '\u{DF4}', Punctuation),
+    ('\u{E3F}', '\u{E3F}', Symbol),
+    ('\u{E4F}', '\u{E4F}', Punctuation),
+    ('\u{E5A}', '\u{E5B}', Punctuation),
+    ('\u{F01}', '\u{F03}', Symbol),
+    ('\u{F04}', '\u{F12}', Punctuation),
+    ('\u{F13}', '\u{F13}', Symbol),
+    ('\u{F14}', '\u{F14}', Punctuation),
+    ('\u{F15}', '\u{F17}', Symbol),
+    ('\u{F1A}', '\u{F1F}', Symbol),
+    ('\u{F34}', '\u{F34}', Symbol),
+    ('\u{F36}', '\u{F36}', Symbol),
+    ('\u{F38}', '\u{F38}', Symbol),
+    ('\u{F3A}', '\u{F3D}', Punctuation),
+    ('\u{F85}', '\u{F85}', Punctuation),
+    ('\u{FBE}', '\u{FC5}', Symbol),
+    ('\u{FC7}', '\u{FCC}', Symbol),
+    ('\u{FCE}', '\u{FCF}', Symbol),
+    ('\u{FD0}', '\u{FD4}', Punctuation),
+    ('\u{FD5}', '\u{FD8}', Symbol),
+    ('\u{FD9}', '\u{FDA}', Punctuation),
+    ('\u{104A}', '\u{104F}', Punctuation),
+    ('\u{109E}', '\u{109F}', Symbol),
+    ('\u{10FB}', '\u{10FB}', Punctuation),
+    ('\u{1360}', '\u{1368}', Punctuation),
+    ('\u{1390}', '\u{1399}', Symbol),
+    ('\u{1400}', '\u{1400}', Punctuation),
+    ('\u{166D}', '\u{166D}', Symbol),
+    ('\u{166E}', '\u{166E}', Punctuation),
+    ('\u{1680}', '\u{1680}', Space),
+    ('\u{169B}', '\u{169C}', Punctuation),
+    ('\u{16EB}', '\u{16ED}', Punctuation),
+    ('\u{1735}', '\u{1736}', Punctuation),
+    ('\u{17D4}', '\u{17D6}', Punctuation),
+    ('\u{17D8}', '\u{17DA}', Punctuation),
+    ('\u{17DB}', '\u{17DB}', Symbol),
+    ('\u{1800}', '\u{180A}', Punctuation),
+    ('\u{1940}', '\u{1940}', Symbol),
+    ('\u{1944}', '\u{1945}', Punctuation),
+    ('\u{19DE}', '\u{19FF}', Symbol),
+    ('\u{1A1E}', '\u{1A1F}', Punctuation),
+    ('\u{1AA0}', '\u{1AA6}', Punctuation),
+    ('\u{1AA8}', '\u{1AAD}', Punctuation),
+    ('\u{1B5A}', '\u{1B60}', Punctuation),
+    ('\u{1B61}', '\u{1B6A}', Symbol),
+    ('\u{1B74}', '\u{1B7C}', Symbol),
+    ('\u{1B7D}', '\u{1B7E}', Punctuation),
+    ('\u{1BFC}', '\u{1BFF}', Punctuation),
+    ('\u{1C3B}', '\u{1C3F}', Punctuation),
+    ('\u{1C7E}', '\u{1C7F}', Punctuation),
+    ('\u{1CC0}', '\u{1CC7}', Punctuation),
+    ('\u{1CD3}', '\u{1CD3}', Punctuation),
+    ('\u{1FBD}', '\u{1FBD}', Symbol),
+    ('\u{1FBF}', '\u{1FC1}', Symbol),
+    ('\u{1FCD}', '\u{1FCF}', Symbol),
+    ('\u{1FDD}', '\u{1FDF}', Symbol),
+    ('\u{1FED}', '\u{1FEF}', Symbol),
+    ('\u{1FFD}', '\u{1FFE}', Symbol),
+    ('\u{2000}', '\u{200A}', Space),
+    ('\u{2010}', '\u{2027}', Punctuation),
+    ('\u{202F}', '\u{202F}', Space),
+    ('\u{2030}', '\u{2043}', Punctuation),
+    ('\u{2044}', '\u{2044}', Symbol),
+    ('\u{2045}', '\u{2051}', Punctuation),
+    ('\u{2052}', '\u{2052}', Symbol),
+    ('\u{2053}', '\u{205E}', Punctuation),
+    ('\u{205F}', '\u{205F}', Space),
+    ('\u{207A}', '\u{207C}', Symbol),
+    ('\u{207D}', '\u{207E}', Punctuation),
+    ('\u{208A}', '\u{208C}', Symbol),
+    ('\u{208D}', '\u{208E}', Punctuation),
+    ('\u{20A0}', '\u{20C0}', Symbol),
+    ('\u{2100}', '\u{2101}', Symbol),
+    ('\u{2103}', '\u{2106}', Symbol),
+    ('\u{2108}', '\u{2109}', Symbol),
+    ('\u{2114}', '\u{2114}', Symbol),
+    ('\u{2116}', '\u{2118}', Symbol),
+    ('\u{211E}', '\u{2123}', Symbol),
+    ('\u{2125}', '\u{2125}', Symbol),
+    ('\u{2127}', '\u{2127}', Symbol),
+    ('\u{2129}', '\u{2129}', Symbol),
+    ('\u{212E}', '\u{212E}', Symbol),
+    ('\u{213A}', '\u{213B}', Symbol),
+    ('\u{2140}', '\u{2144}', Symbol),
+    ('\u{214A}', '\u{214D}', Symbol),
+    ('\u{214F}', '\u{214F}', Symbol),
+    ('\u{218A}', '\u{218B}', Symbol),
+    ('\u{2190}', '\u{2307}', Symbol),
+    ('\u{2308}', '\u{230B}', Punctuation),
+    ('\u{230C}', '\u{2328}', Symbol),
+    ('\u{2329}', '\u{232A}', Punctuation),
+    ('\u{232B}', '\u{2426}', Symbol),
+    ('\u{2440}', '\u{244A}', Symbol),
+    ('\u{249C}', '\u{24E9}', Symbol),
+    ('\u{2500}', '\u{2767}', Symbol),
+    ('\u{2768}', '\u{2775}', Punctuation),
+    ('\u{2794}', '\u{27C4}', Symbol),
+    ('\u{27C5}', '\u{27C6}', Punctuation),
+    ('\u{27C7}', '\u{27E5}', Symbol),
+    ('\u{27E6}', '\u{27EF}', Punctuation),
+    ('\u{27F0}', '\u{2982}', Symbol),
+    ('\u{2983}', '\u{2998}', Punctuation),
+    ('\u{2999}', '\u{29D7}', Symbol),
+    ('\u{29D8}', '\u{29DB}', Punctuation),
+    ('\u{29DC}', '\u{29FB}', Symbol),
+    ('\u{29FC}', '\u{29FD}', Punctuation),
+    ('\u{29FE}', '\u{2B73}', Symbol),
+    ('\u{2B76}', '\u{2B95}', Symbol),
+    ('\u{2B97}', '\u{2BFF}', Symbol),
+    ('\u{2CE5}', '\u{2CEA}', Symbol),
+    ('\u{2CF9}', '\u{2CFC}', Punctuation),
+    ('\u{2CFE}', '\u{2CFF}', Punctuation),
+    ('\u{2D70}', '\u{2D70}', Punctuation),
+    ('\u{2E00}', '\u{2E2E}', Punctuation),
+    ('\u{2E30}', '\u{2E4F}', Punctuation),
+    ('\u{2E50}', '\u{2E51}', Symbol),
+    ('\u{2E52}', '\u{2E5D}', Punctuation),
+    ('\u{2E80}', '\u{2E99}', Symbol),
+    ('\u{2E9B}', '\u{2EF3}', Symbol),
+    ('\u{2F00}', '\u{2FD5}', Symbol),
+    ('\u{2FF0}', '\u{2FFB}', Symbol),
+    ('\u{3000}', '\u{3000}', Space),
+    ('\u{3001}', '\u{3003}', Punctuation),
+    ('\u{3004}', '\u{3004}', Symbol),
+    ('\u{3008}', '\u{3011}', Punctuation),
+    ('\u{3012}', '\u{3013}', Symbol),
+    ('\u{3014}', '\u{301F}', Punctuation),
+    ('\u{3020}', '\u{3020}', Symbol),
+    ('\u{3030}', '\u{3030}', Punctuation),
+    ('\u{3036}', '\u{3037}', Symbol),
+    ('\u{303D}', '\u{303D}', Punctuation),
+    ('\u{303E}', '\u{303F}', Symbol),
+    ('\u{309B}', '\u{309C}', Symbol),
+    ('\u{30A0}', '\u{30A0}', Punctuation),
+    ('\u{30FB}', '\u{30FB}', Punctuation),
+    ('\u{3190}', '\u{3191}', Symbol),
+    ('\u{3196}', '\u{319F}', Symbol),
+    ('\u{31C0}', '\u{31E3}', Symbol),
+    ('\u{3200}', '\u{321E}', Symbol),
+    ('\u{322A}', '\u{3247}', Symbol),
+    ('\u{3250}', '\u{3250}', Symbol),
+    ('\u{3260}', '\u{327F}', Symbol),
+    ('\u{328A}', '\u{32B0}', Symbol),
+    ('\u{32C0}', '\u{33FF}', Symbol),
+    ('\u{4DC0}', '\u{4DFF}', Symbol),
+    ('\u{A490}', '\u{A4C6}', Symbol),
+    ('\u{A4FE}', '\u{A4FF}', Punctuation),
+    ('\u{A60D}', '\u{A60F}', Punctuation),
+    ('\u{A673}', '\u{A673}', Punctuation),
+    ('\u{A67E}', '\u{A67E}', Punctuation),
+    ('\u{A6F2}', '\u{A6F7}', Punctuation),
+    ('\u{A700}', '\u{A716}', Symbol),
+    ('\u{A720}', '\u{A721}', Symbol),
+    ('\u{A789}', '\u{A78A}', Symbol),
+    ('\u{A828}', '\u{A82B}', Symbol),
+    ('\u{A836}', '\u{A839}', Symbol),
+    ('\u{A874}', '\u{A877}', Punctuation),
+    ('\u{A8CE}', '\u{A8CF}', Punctuation),
+    ('\u{A8F8}', '\u{A8FA}', Punctuation),
+    ('\u{A8FC}', '\u{A8FC}', Punctuation),
+    ('\u{A92E}', '\u{A92F}', Punctuation),
+    ('\u{A95F}', '\u{A95F}', Punctuation),
+    ('\u{A9C1}', '\u{A9CD}', Punctuation),
+    ('\u{A9DE}', '\u{A9DF}', Punctuation),
+    ('\u{AA5C}', '\u{AA5F}', Punctuation),
+    ('\u{AA77}', '\u{AA79}', Symbol),
+    ('\u{AADE}', '\u{AADF}', Punctuation),
+    ('\u{AAF0}', '\u{AAF1}', Punctuation),
+    ('\u{AB5B}', '\u{AB5B}', Symbol),
+    ('\u{AB6A}', '\u{AB6B}', Symbol),
+    ('\u{ABEB}', '\u{ABEB}', Punctuation),
+    ('\u{FB29}', '\u{FB29}', Symbol),
+    ('\u{FBB2}', '\u{FBC2}', Symbol),
+    ('\u{FD3E}', '\u{FD3F}', Punctuation),
+    ('\u{FD40}', '\u{FD4F}', Symbol),
+    ('\u{FDCF}', '\u{FDCF}', Symbol),
+    ('\u{FDFC}', '\u{FDFF}', Symbol),
+    ('\u{FE10}', '\u{FE19}', Punctuation),
+    ('\u{FE30}', '\u{FE52}', Punctuation),
+    ('\u{FE54}', '\u{FE61}', Punctuation),
+    ('\u{FE62}', '\u{FE62}', Symbol),
+    ('\u{FE63}', '\u{FE63}', Punctuation),
+    ('\u{FE64}', '\u{FE66}', Symbol),
+    ('\u{FE68}', '\u{FE68}', Punctuation),
+    ('\u{FE69}', '\u{FE69}', Symbol),
+    ('\u{FE6A}', '\u{FE6B}', Punctuation),
+    ('\u{FF01}', '\u{FF03}', Punctuation),
+    ('\u{FF04}', '\u{FF04}', Symbol),
+    ('\u{FF05}', '\u{FF0A}', Punctuation),
+    ('\u{FF0B}', '\u{FF0B}', Symbol),
+    ('\u{FF0C}', '\u{FF0F}', Punctuation),
+    ('\u{FF1A}', '\u{FF1B}', Punctuation),
+    ('\u{FF1C}', '\u{FF1E}', Symbol),
+    ('\u{FF1F}', '\u{FF20}', Punctuation),
+    ('\u{FF3B}', '\u{FF3D}', Punctuation),
+    ('\u{FF3E}', '\u{FF3E}', Symbol),
+    ('\u{FF3F}', '\u{FF3F}', Punctuation),
+    ('\u{FF40}', '\u{FF40}', Symbol),
+    ('\u{FF5B}', '\u{FF5B}', Punctuation),
+    ('\u{FF5C}', '\u{FF5C}', Symbol),
+    ('\u{FF5D}', '\u{FF5D}', Punctuation),
+    ('\u{FF5E}', '\u{FF5E}', Symbol),
+    ('\u{FF5F}', '\u{FF65}', Punctuation),
+    ('\u{FFE0}', '\u{FFE6}', Symbol),
+    ('\u{FFE8}', '\u{FFEE}', Symbol),
+    ('\u{FFFC}', '\u{FFFD}', Symbol),
+    ('\u{10100}', '\u{10102}', Punctuation),
+    ('\u{10137}', '\u{1013F}', Symbol),
+    ('\u{10179}', '\u{10189}', Symbol),
+    ('\u{1018C}', '\u{1018E}', Symbol),
+    ('\u{10190}', '\u{1019C}', Symbol),
+    ('\u{101A0}', '\u{101A0}', Symbol),
+    ('\u{101D0}', '\u{101FC}', Symbol),
+    ('\u{1039F}', '\u{1039F}', Punctuation),
+    ('\u{103D0}', '\u{103D0}', Punctuation),
+    ('\u{1056F}', '\u{1056F}', Punctuation),
+    ('\u{10857}', '\u{10857}', Punctuation),
+    ('\u{10877}', '\u{10878}', Symbol),
+    ('\u{1091F}', '\u{1091F}', Punctuation),
+    ('\u{1093F}', '\u{1093F}', Punctuation),
+    ('\u{10A50}', '\u{10A58}', Punctuation),
+    ('\u{10A7F}', '\u{10A7F}', Punctuation),
+    ('\u{10AC8}', '\u{10AC8}', Symbol),
+    ('\u{10AF0}', '\u{10AF6}', Punctuation),
+    ('\u{10B39}', '\u{10B3F}', Punctuation),
+    ('\u{10B99}', '\u{10B9C}', Punctuation),
+    ('\u{10EAD}', '\u{10EAD}', Punctuation),
+    ('\u{10F55}', '\u{10F59}', Punctuation),
+    ('\u{10F86}', '\u{10F89}', Punctuation),
+    ('\u{11047}', '\u{1104D}', Punctuation),
+    ('\u{110BB}', '\u{110BC}', Punctuation),
+    ('\u{110BE}', '\u{110C1}', Punctuation),
+    ('\u{11140}', '\u{11143}', Punctuation),
+    ('\u{11174}', '\u{11175}', Punctuation),
+    ('\u{111C5}', '\u{111C8}', Punctuation),
+    ('\u{111CD}', '\u{111CD}', Punctuation),
+    ('\u{111DB}', '\u{111DB}', Punctuation),
+    ('\u{111DD}', '\u{111DF}', Punctuation),
+    ('\u{11238}', '\u{1123D}', Punctuation),
+    ('\u{112A9}', '\u{112A9}', Punctuation),
+    ('\u{1144B}', '\u{1144F}', Punctuation),
+    ('\u{1145A}', '\u{1145B}', Punctuation),
+    ('\u{1145D}', '\u{1145D}', Punctuation),
+    ('\u{114C6}', '\u{114C6}', Punctuation),
+    ('\u{115C1}', '\u{115D7}', Punctuation),
+    ('\u{11641}', '\u{11643}', Punctuation),
+    ('\u{11660}', '\u{1166C}', Punctuation),
+    ('\u{116B9}', '\u{116B9}', Punctuation),
+    ('\u{1173C}', '\u{1173E}', Punctuation),
+    ('\u{1173F}', '\u{1173F}', Symbol),
+    ('\u{1183B}', '\u{1183B}', Punctuation),
+    ('\u{11944}', '\u{11946}', Punctuation),
+    ('\u{119E2}', '\u{119E2}', Punctuation),
+    ('\u{11A3F}', '\u{11A46}', Punctuation),
+    ('\u{11A9A}', '\u{11A9C}', Punctuation),
+    ('\u{11A9E}', '\u{11AA2}', Punctuation),
+    ('\u{11C41}', '\u{11C45}', Punctuation),
+    ('\u{11C70}', '\u{11C71}', Punctuation),
+    ('\u{11EF7}', '\u{11EF8}', Punctuation),
+    ('\u{11FD5}', '\u{11FF1}', Symbol),
+    ('\u{11FFF}', '\u{11FFF}', Punctuation),
+    ('\u{12470}', '\u{12474}', Punctuation),
+    ('\u{12FF1}', '\u{12FF2}', Punctuation),
+    ('\u{16A6E}', '\u{16A6F}', Punctuation),
+    ('\u{16AF5}', '\u{16AF5}', Punctuation),
+    ('\u{16B37}', '\u{16B3B}', Punctuation),
+    ('\u{16B3C}', '\u{16B3F}', Symbol),
+    ('\u{16B44}', '\u{16B44}', Punctuation),
+    ('\u{16B45}', '\u{16B45}', Symbol),
+    ('\u{16E97}', '\u{16E9A}', Punctuation),
+    ('\u{16FE2}', '\u{16FE2}', Punctuation),
+    ('\u{1BC9C}', '\u{1BC9C}', Symbol),
+    ('\u{1BC9F}', '\u{1BC9F}', Punctuation),
+    ('\u{1CF50}', '\u{1CFC3}', Symbol),
+    ('\u{1D000}', '\u{1D0F5}', Symbol),
+    ('\u{1D100}', '\u{1D126}', Symbol),
+    ('\u{1D129}', '\u{1D164}', Symbol),
+    ('\u{1D16A}', '\u{1D16C}', Symbol),
+    ('\u{1D183}', '\u{1D184}', Symbol),
+    ('\u{1D18C}', '\u{1D1A9}', Symbol),
+    ('\u{1D1AE}', '\u{1D1EA}', Symbol),
+    ('\u{1D200}', '\u{1D241}', Symbol),
+    ('\u{1D245}', '\u{1D245}', Symbol),
+    ('\u{1D300}', '\u{1D356}', Symbol),
+    ('\u{1D6C1}', '\u{1D6C1}', Symbol),
+    ('\u{1D6DB}', '\u{1D6DB}', Symbol),
+    ('\u{1D6FB}', '\u{1D6FB}', Symbol),
+    ('\u{1D715}', '\u{1D715}', Symbol),
+    ('\u{1D735}', '\u{1D735}', Symbol),
+    ('\u{1D74F}', '\u{1D74F}', Symbol),
+    ('\u{1D76F}', '\u{1D76F}', Symbol),
+    ('\u{1D789}', '\u{1D789}', Symbol),
+    ('\u{1D7A9}', '\u{1D7A9}', Symbol),
+    ('\u{1D7C3}', '\u{1D7C3}', Symbol),
+    ('\u{1D800}', '\u{1D9FF}', Symbol),
+    ('\u{1DA37}', '\u{1DA3A}', Symbol),
+    ('\u{1DA6D}', '\u{1DA74}', Symbol),
+    ('\u{1DA76}', '\u{1DA83}', Symbol),
+    ('\u{1DA85}', '\u{1DA86}', Symbol),
+    ('\u{1DA87}', '\u{1DA8B}', Punctuation),
+    ('\u{1E14F}', '\u{1E14F}', Symbol),
+    ('\u{1E2FF}', '\u{1E2FF}', Symbol),
+    ('\u{1E95E}', '\u{1E95F}', Punctuation),
+    ('\u{1ECAC}', '\u{1ECAC}', Symbol),
+    ('\u{1ECB0}', '\u{1ECB0}', Symbol),
+    ('\u{1ED2E}', '\u{1ED2E}', Symbol),
+    ('\u{1EEF0}', '\u{1EEF1}', Symbol),
+    ('\u{1F000}', '\u{1F02B}', Symbol),
+    ('\u{1F030}', '\u{1F093}', Symbol),
+    ('\u{1F0A0}', '\u{1F0AE}', Symbol),
+    ('\u{1F0B1}', '\u{1F0BF}', Symbol),
+    ('\u{1F0C1}', '\u{1F0CF}', Symbol),
+    ('\u{1F0D1}', '\u{1F0F5}', Symbol),
+    ('\u{1F10D}', '\u{1F1AD}', Symbol),
+    ('\u{1F1E6}', '\u{1F202}', Symbol),
+    ('\u{1F210}', '\u{1F23B}', Symbol),
+    ('\u{1F240}', '\u{1F248}', Symbol),
+    ('\u{1F250}', '\u{1F251}', Symbol),
+    ('\u{1F260}', '\u{1F265}', Symbol),
+    ('\u{1F300}', '\u{1F6D7}', Symbol),
+    ('\u{1F6DD}', '\u{1F6EC}', Symbol),
+    ('\u{1F6F0}', '\u{1F6FC}', Symbol),
+    ('\u{1F700}', '\u{1F773}', Symbol),
+    ('\u{1F780}', '\u{1F7D8}', Symbol),
+    ('\u{1F7E0}', '\u{1F7EB}', Symbol),
+    ('\u{1F7F0}', '\u{1F7F0}', Symbol),
+    ('\u{1F800}', '\u{1F80B}', Symbol),
+    ('\u{1F810}', '\u{1F847}', Symbol),
+    ('\u{1F850}', '\u{1F859}', Symbol),
+    ('\u{1F860}', '\u{1F887}', Symbol),
+    ('\u{1F890}', '\u{1F8AD}', Symbol),
+    ('\u{1F8B0}', '\u{1F8B1}', Symbol),
+    ('\u{1F900}', '\u{1FA53}', Symbol),
+    ('\u{1FA60}', '\u{1FA6D}', Symbol),
+    ('\u{1FA70}', '\u{1FA74}', Symbol),
+    ('\u{1FA78}', '\u{1FA7C}', Symbol),
+    ('\u{1FA80}', '\u{1FA86}', Symbol),
+    ('\u{1FA90}', '\u{1FAAC}', Symbol),
+    ('\u{1FAB0}', '\u{1FABA}', Symbol),
+    ('\u{1FAC0}', '\u{1FAC5}', Symbol),
+    ('\u{1FAD0}', '\u{1FAD9}', Symbol),
+    ('\u{1FAE0}', '\u{1FAE7}', Symbol),
+    ('\u{1FAF0}', '\u{1FAF6}', Symbol),
+    ('\u{1FB00}', '\u{1FB92}', Symbol),
+    ('\u{1FB94}', '\u{1FBCA}', Symbol),
+];
@@ -1,53 +1,477 @@
-use crate::iter::{iterate_lexical, iterate_lexical_only_alnum};
+use crate::category::category_group;
+use crate::compare::SortMode;
+use crate::digit::{digit_kind, first_digit, to_ascii_digit, DecimalDigits, DigitKind};
+#[cfg(feature = "emoji-names")]
+use crate::emoji::emoji_name;
+use crate::iter::{
+    iterate_lexical, iterate_lexical_char, iterate_lexical_char_only_alnum,
+    iterate_lexical_only_alnum, LexicalChar,
+};
+use crate::script::{script_rank, Script};
 use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::iter::Peekable;
+use core::str::CharIndices;
 
-macro_rules! cmp_ascii_digits {
-    (first_digits($lhs:ident, $rhs:ident), iterators($iter1:ident, $iter2:ident)) => {
-        let mut n1 = ascii_to_u64($lhs);
-        let mut n2 = ascii_to_u64($rhs);
-        loop {
-            match (
-                $iter1.peek().copied().filter(|c| c.is_ascii_digit()),
-                $iter2.peek().copied().filter(|c| c.is_ascii_digit()),
-            ) {
-                (Some(lhs), Some(rhs)) => {
-                    n1 = n1 * 10 + ascii_to_u64(lhs);
-                    n2 = n2 * 10 + ascii_to_u64(rhs);
-                    let _ = $iter1.next();
-                    let _ = $iter2.next();
-                }
-                (Some(_), None) => return Ordering::Greater,
-                (None, Some(_)) => return Ordering::Less,
-                (None, None) => {
-                    if n1 != n2 {
-                        return n1.cmp(&n2);
-                    } else {
-                        break;
-                    }
-                }
+/// A character in a natural comparison
+pub(crate) trait NumberChar: Copy {
+    /// Returns the character
+    fn to_char(self) -> char;
+
+    /// Returns the kind of the character it originates from. Digits of different kinds
+    /// belong to different numbers.
+    fn kind(self) -> DigitKind {
+        DigitKind::Normal
+    }
+}
+
+impl NumberChar for char {
+    fn to_char(self) -> char {
+        self
+    }
+}
+
+/// Compares two runs of ASCII digits numerically. `lhs` and `rhs` are the first digits,
+/// the remaining digits are consumed from the iterators. A run only contains digits of the
+/// same kind, so a superscript digit after an ASCII digit starts a new run.
+///
+/// Leading zeros are skipped, then the longer run is greater; runs of the same length are
+/// compared digit by digit. This never overflows, no matter how many digits the runs have.
+/// A vulgar fraction is a run of its own, which is compared by its value, e.g. `½` is between
+/// `0` and `1`. The same applies to other characters that are numbers of their own, such as
+/// the Roman numeral `Ⅻ`.
+///
+/// Returns the ordering of the numbers, and the ordering of their numbers of leading zeros,
+/// which can be used to break ties. For example, `"007"` and `"7"` are equal numbers, but
+/// `"007"` has more leading zeros.
+#[inline]
+pub(crate) fn cmp_ascii_digits<I1, I2>(
+    lhs: I1::Item,
+    rhs: I2::Item,
+    iter1: &mut Peekable<I1>,
+    iter2: &mut Peekable<I2>,
+) -> (Ordering, Ordering)
+where
+    I1: Iterator,
+    I2: Iterator,
+    I1::Item: NumberChar,
+    I2::Item: NumberChar,
+{
+    let (kind1, kind2) = (lhs.kind(), rhs.kind());
+    match (kind1.single_value(), kind2.single_value()) {
+        (Some((n1, d1)), Some((n2, d2))) => {
+            let lhs = u64::from(n1) * u64::from(d2);
+            let rhs = u64::from(n2) * u64::from(d1);
+            return (lhs.cmp(&rhs), Ordering::Equal);
+        }
+        (Some(value), None) => return cmp_value_with_digits(value, rhs, iter2),
+        (None, Some(value)) => {
+            let (ordering, zeros) = cmp_value_with_digits(value, lhs, iter1);
+            return (ordering.reverse(), zeros.reverse());
+        }
+        (None, None) => {}
+    }
+
+    let (lhs, zeros1) = skip_leading_zeros(lhs, iter1);
+    let (rhs, zeros2) = skip_leading_zeros(rhs, iter2);
+    let zeros = zeros1.cmp(&zeros2);
+
+    let mut ordering = lhs.cmp(&rhs);
+    loop {
+        match (
+            next_ascii_digit(iter1, kind1),
+            next_ascii_digit(iter2, kind2),
+        ) {
+            (Some(lhs), Some(rhs)) => {
+                ordering = ordering.then(lhs.cmp(&rhs));
+                let _ = iter1.next();
+                let _ = iter2.next();
+            }
+            (Some(_), None) => return (Ordering::Greater, zeros),
+            (None, Some(_)) => return (Ordering::Less, zeros),
+            (None, None) => return (ordering, zeros),
+        }
+    }
+}
+
+/// Compares the value of a character that is a number of its own, given as a numerator and
+/// a denominator, with a run of ASCII digits. `first` is the first digit, the remaining digits
+/// are consumed from the iterator.
+///
+/// Returns the ordering of the numbers, and the ordering of their numbers of leading zeros
+/// like `cmp_ascii_digits`. The character doesn't have leading zeros.
+#[inline]
+fn cmp_value_with_digits<I>(
+    value: (u32, u32),
+    first: I::Item,
+    iter: &mut Peekable<I>,
+) -> (Ordering, Ordering)
+where
+    I: Iterator,
+    I::Item: NumberChar,
+{
+    let kind = first.kind();
+    let (first, zeros) = skip_leading_zeros(first, iter);
+    let zeros = 0.cmp(&zeros);
+    // the run is greater than any value if it has more than 15 digits, so this can't overflow
+    let mut run = Some(u64::from(first as u8 - b'0'));
+    while let Some(c) = next_ascii_digit(iter, kind) {
+        let _ = iter.next();
+        run = run
+            .map(|run| run * 10 + u64::from(c as u8 - b'0'))
+            .filter(|&run| run < 1_000_000_000_000_000);
+    }
+    match run {
+        Some(run) => (u64::from(value.0).cmp(&(run * u64::from(value.1))), zeros),
+        None => (Ordering::Less, zeros),
+    }
+}
+
+/// Returns the next character of the iterator without consuming it, if it is an ASCII digit
+/// of the given kind
+#[inline]
+fn next_ascii_digit<I>(iter: &mut Peekable<I>, kind: DigitKind) -> Option<char>
+where
+    I: Iterator,
+    I::Item: NumberChar,
+{
+    iter.peek()
+        .filter(|c| c.kind() == kind)
+        .map(|&c| c.to_char())
+        .filter(char::is_ascii_digit)
+}
+
+/// Skips the leading zeros of a run of ASCII digits that starts with `first`, except for
+/// the last digit. Returns the first remaining digit and the number of skipped zeros.
+#[inline]
+fn skip_leading_zeros<I>(first: I::Item, iter: &mut Peekable<I>) -> (char, usize)
+where
+    I: Iterator,
+    I::Item: NumberChar,
+{
+    let kind = first.kind();
+    let mut first = first.to_char();
+    let mut zeros = 0;
+    while first == '0' {
+        match next_ascii_digit(iter, kind) {
+            Some(c) => {
+                let _ = iter.next();
+                first = c;
+                zeros += 1;
             }
+            None => break,
         }
+    }
+    (first, zeros)
+}
+
+/// Compares two runs of ASCII digits numerically, given as bytes, like `cmp_ascii_digits`.
+/// The runs must not be empty.
+///
+/// This doesn't compare the runs digit by digit: After skipping the leading zeros, the
+/// longer run is greater, and runs of the same length are compared as byte slices.
+#[inline]
+pub(crate) fn cmp_digit_bytes(run1: &[u8], run2: &[u8]) -> (Ordering, Ordering) {
+    let zeros = |run: &[u8]| {
+        run[..run.len() - 1]
+            .iter()
+            .take_while(|&&b| b == b'0')
+            .count()
     };
+    let (zeros1, zeros2) = (zeros(run1), zeros(run2));
+    let (run1, run2) = (&run1[zeros1..], &run2[zeros2..]);
+    let ordering = run1.len().cmp(&run2.len()).then_with(|| run1.cmp(run2));
+    (ordering, zeros1.cmp(&zeros2))
+}
+
+/// A string in a natural comparison, and how its characters are converted: With
+/// `iterate_lexical` if `lexical` is true. If `only_alnum` is
+/// true, non-alphanumeric characters are skipped. If `ignore_whitespace` is true, whitespace
+/// is skipped.
+#[derive(Clone, Copy)]
+pub(crate) struct Natural<'a> {
+    pub(crate) s: &'a str,
+    pub(crate) lexical: bool,
+    pub(crate) only_alnum: bool,
+    pub(crate) ignore_whitespace: bool,
+}
+
+impl<'a> Natural<'a> {
+    /// Returns an iterator over the converted characters, starting at a byte offset
+    pub(crate) fn chars_from(self, offset: usize) -> Peekable<NaturalChars<'a>> {
+        NaturalChars {
+            natural: self,
+            chars: self.s[offset..].char_indices(),
+            offset,
+            expansion: LexicalChar::empty(),
+            kind: DigitKind::Normal,
+            index: offset,
+        }
+        .peekable()
+    }
+
+    /// Returns the bytes of the number that starts with `first`, if it is a run of ASCII
+    /// digits in the string. Returns `None` if it isn't, e.g. because it continues with
+    /// non-ASCII digits.
+    fn ascii_run(self, first: NaturalChar) -> Option<&'a [u8]> {
+        if first.kind != DigitKind::Normal || self.s.as_bytes()[first.index] != first.c as u8 {
+            return None;
+        }
+        let end = first.index
+            + self.s[first.index..]
+                .bytes()
+                .take_while(u8::is_ascii_digit)
+                .count();
+        match self.chars_from(end).next() {
+            Some(next) if next.kind == first.kind && next.c.is_ascii_digit() => None,
+            _ => Some(&self.s.as_bytes()[first.index..end]),
+        }
+    }
+}
+
+/// A converted character in a natural comparison, the kind of the character it originates
+/// from, and the byte offset of that character
+#[derive(Clone, Copy)]
+pub(crate) struct NaturalChar {
+    pub(crate) c: char,
+    pub(crate) kind: DigitKind,
+    index: usize,
+}
+
+impl NumberChar for NaturalChar {
+    fn to_char(self) -> char {
+        self.c
+    }
+
+    fn kind(self) -> DigitKind {
+        self.kind
+    }
+}
+
+/// Iterator over the converted characters of a string in a natural comparison. In lexical
+/// comparisons, vulgar fractions are converted to their numerator, e.g. `½` is converted
+/// to `1`.
+#[derive(Clone)]
+pub(crate) struct NaturalChars<'a> {
+    natural: Natural<'a>,
+    chars: CharIndices<'a>,
+    offset: usize,
+    expansion: LexicalChar,
+    kind: DigitKind,
+    index: usize,
+}
+
+impl Iterator for NaturalChars<'_> {
+    type Item = NaturalChar;
+
+    fn next(&mut self) -> Option<NaturalChar> {
+        loop {
+            if let Some(c) = self.expansion.next() {
+                let (kind, index) = (self.kind, self.index);
+                return Some(NaturalChar { c, kind, index });
+            }
+            let (i, c) = self.chars.next()?;
+            if self.natural.ignore_whitespace && c.is_whitespace() {
+                continue;
+            }
+            self.index = self.offset + i;
+            if self.natural.lexical {
+                self.kind = digit_kind(c);
+                self.expansion = match self.kind {
+                    DigitKind::Fraction(n, _) => LexicalChar::from_char((b'0' + n) as char),
+                    DigitKind::Numeral(value) => LexicalChar::from_char(first_digit(value)),
+                    _ if self.natural.only_alnum => iterate_lexical_char_only_alnum(c),
+                    _ => iterate_lexical_char(c),
+                };
+            } else if !self.natural.only_alnum || c.is_alphanumeric() {
+                self.expansion = LexicalChar::from_char(to_ascii_digit(c));
+            }
+        }
+    }
+}
+
+/// Compares the numbers that start with `lhs` and `rhs` like `cmp_ascii_digits`.
+///
+/// If both numbers are runs of ASCII digits in the strings, they are compared with
+/// `cmp_digit_bytes`, and the iterators skip them without visiting every digit, so
+/// comparing very long numbers is fast.
+#[inline]
+fn cmp_numbers<'a>(
+    (natural1, lhs): (Natural<'a>, NaturalChar),
+    (natural2, rhs): (Natural<'a>, NaturalChar),
+    iter1: &mut Peekable<NaturalChars<'a>>,
+    iter2: &mut Peekable<NaturalChars<'a>>,
+) -> (Ordering, Ordering) {
+    match (natural1.ascii_run(lhs), natural2.ascii_run(rhs)) {
+        (Some(run1), Some(run2)) => {
+            // nothing was peeked after `lhs` and `rhs`, so the iterators can be replaced
+            *iter1 = natural1.chars_from(lhs.index + run1.len());
+            *iter2 = natural2.chars_from(rhs.index + run2.len());
+            cmp_digit_bytes(run1, run2)
+        }
+        _ => cmp_ascii_digits(lhs, rhs, iter1, iter2),
+    }
 }
 
+/// Compares two strings that are empty after folding, i.e. have no characters that are
+/// compared. They are ordered by their number of `char`s, then with `str::cmp`.
 #[inline]
-fn ascii_to_u64(c: char) -> u64 {
-    (c as u64) - (b'0' as u64)
+pub(crate) fn cmp_blank(lhs: &str, rhs: &str) -> Ordering {
+    lhs.chars()
+        .count()
+        .cmp(&rhs.chars().count())
+        .then_with(|| lhs.cmp(rhs))
+}
+
+/// Compares strings that are equal after transliteration: First by the script of their first
+/// alphabetic character, in the order of `Script::DEFAULT_ORDER`. Then, at the first position
+/// where only one string has a non-ASCII character, the string with the ASCII character is
+/// less. If there's no such position, the strings are compared with `str::cmp`.
+///
+/// Positions are counted after transliteration, so `"ss" < "ß"`, and `"Sss" < "sß"`, even
+/// though `'S' < 's'`.
+pub(crate) fn cmp_variants(lhs: &str, rhs: &str) -> Ordering {
+    fn variants(s: &str) -> impl Iterator<Item = bool> + '_ {
+        s.chars()
+            .flat_map(|c| iterate_lexical_char(c).map(move |_| !c.is_ascii()))
+    }
+
+    let order = Script::DEFAULT_ORDER;
+    script_rank(lhs.chars(), order)
+        .cmp(&script_rank(rhs.chars(), order))
+        .then_with(|| variants(lhs).cmp(variants(rhs)))
+        .then_with(|| lhs.cmp(rhs))
 }
 
+/// Returns the rank of a character in lexical comparisons: Non-alphanumeric characters come
+/// first, then alphanumeric characters, then the replacement character U+FFFD.
 #[inline]
-fn ret_ordering(lhs: char, rhs: char) -> Ordering {
-    let is_lhs_alnum = lhs.is_alphanumeric();
-    let is_rhs_alnum = rhs.is_alphanumeric();
-
-    let result = if is_lhs_alnum == is_rhs_alnum {
-        lhs.cmp(&rhs)
-    } else if is_lhs_alnum {
-        Ordering::Greater
+pub(crate) fn char_rank(c: char) -> u8 {
+    if c.is_alphanumeric() {
+        1
+    } else if c == '\u{FFFD}' {
+        2
     } else {
-        Ordering::Less
-    };
-    result
+        0
+    }
+}
+
+#[inline]
+pub(crate) fn ret_ordering(lhs: char, rhs: char) -> Ordering {
+    char_rank(lhs)
+        .cmp(&char_rank(rhs))
+        .then_with(|| lhs.cmp(&rhs))
+}
+
+/// Like `ret_ordering`, but non-alphanumeric characters are ordered by their general category
+/// group first: Spaces, then punctuation, then symbols, then all other characters.
+#[inline]
+pub(crate) fn ret_ordering_by_category(lhs: char, rhs: char) -> Ordering {
+    match (char_rank(lhs), char_rank(rhs)) {
+        (0, 0) => category_group(lhs)
+            .cmp(&category_group(rhs))
+            .then_with(|| lhs.cmp(&rhs)),
+        _ => ret_ordering(lhs, rhs),
+    }
+}
+
+/// Orders emojis by their short name, after all other non-alphanumeric characters. Other
+/// characters are compared with `ret_ordering`.
+#[cfg(feature = "emoji-names")]
+#[inline]
+pub(crate) fn ret_ordering_by_emoji_name(
+    lhs: char,
+    rhs: char,
+    ret_ordering: impl Fn(char, char) -> Ordering,
+) -> Ordering {
+    match (char_rank(lhs), char_rank(rhs)) {
+        (0, 0) => match (emoji_name(lhs), emoji_name(rhs)) {
+            (Some(l), Some(r)) => l.cmp(r).then_with(|| lhs.cmp(&rhs)),
+            (Some(_), None) => Ordering::Greater,
+            (None, Some(_)) => Ordering::Less,
+            (None, None) => ret_ordering(lhs, rhs),
+        },
+        _ => ret_ordering(lhs, rhs),
+    }
+}
+
+/// Returns the characters that a character is compared as in lexical comparisons, i.e. the
+/// character converted to lowercase and transliterated to ASCII, e.g. `['a', 'e']` for `'Æ'`
+///
+/// This is the same as `iter::iterate_lexical_char`. Together with `lexical_char_cmp`, it can
+/// be used to build comparison functions that are consistent with `lexical_cmp`, e.g. for
+/// custom token streams. Note that some characters, such as combining diacritical marks, are
+/// converted to no characters at all.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::{lexical_char_cmp, lexical_char_key};
+/// use std::cmp::Ordering;
+///
+/// /// Compares file names lexicographically, ignoring their extensions
+/// fn cmp_file_stems(lhs: &str, rhs: &str) -> Ordering {
+///     fn stem(s: &str) -> &str {
+///         s.rfind('.').map_or(s, |i| &s[..i])
+///     }
+///     let mut iter1 = stem(lhs).chars().flat_map(lexical_char_key);
+///     let mut iter2 = stem(rhs).chars().flat_map(lexical_char_key);
+///     loop {
+///         match (iter1.next(), iter2.next()) {
+///             (Some(l), Some(r)) => match lexical_char_cmp(l, r) {
+///                 Ordering::Equal => {}
+///                 ordering => return ordering,
+///             },
+///             (l, r) => return l.is_some().cmp(&r.is_some()),
+///         }
+///     }
+/// }
+///
+/// let mut files = ["Zebra.txt", "Äpfel.rs", "apfel-2.md", "apfel.txt"];
+/// files.sort_by(|l, r| cmp_file_stems(l, r));
+/// assert_eq!(files, ["Äpfel.rs", "apfel.txt", "apfel-2.md", "Zebra.txt"]);
+/// # }
+/// ```
+#[inline]
+pub fn lexical_char_key(c: char) -> LexicalChar {
+    iterate_lexical_char(c)
+}
+
+/// Compares two characters lexicographically, like `lexical_cmp` compares them, but without
+/// a tie-break
+///
+/// The characters are converted with `lexical_char_key` and compared at the first difference:
+/// Non-alphanumeric characters sort before alphanumeric characters, and characters of the
+/// same class are compared by their code points. Characters that are equal after the
+/// conversion, e.g. `'a'` and `'Ä'`, are `Equal`. Applied to the output of `lexical_char_key`,
+/// this is the order `lexical_cmp` uses for the transliterated strings.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::lexical_char_cmp;
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(lexical_char_cmp('B', 'a'), Ordering::Greater);
+/// assert_eq!(lexical_char_cmp('-', 'a'), Ordering::Less);
+/// assert_eq!(lexical_char_cmp('Ä', 'a'), Ordering::Equal);
+/// assert_eq!(lexical_char_cmp('æ', 'a'), Ordering::Greater);
+/// # }
+/// ```
+pub fn lexical_char_cmp(lhs: char, rhs: char) -> Ordering {
+    let mut iter1 = iterate_lexical_char(lhs);
+    let mut iter2 = iterate_lexical_char(rhs);
+    loop {
+        match (iter1.next(), iter2.next()) {
+            (Some(lhs), Some(rhs)) if lhs != rhs => return ret_ordering(lhs, rhs),
+            (Some(_), Some(_)) => {}
+            (Some(_), None) => return Ordering::Greater,
+            (None, Some(_)) => return Ordering::Less,
+            (None, None) => return Ordering::Equal,
+        }
+    }
 }
 
 /// Compares strings lexicographically
@@ -57,16 +481,19 @@ pub fn lexical_cmp(lhs: &str, rhs: &str) -> Ordering {
     let mut iter1 = iterate_lexical(lhs);
     let mut iter2 = iterate_lexical(rhs);
 
+    let mut blank = true;
     loop {
         match (iter1.next(), iter2.next()) {
             (Some(lhs), Some(rhs)) => {
+                blank = false;
                 if lhs != rhs {
                     return ret_ordering(lhs, rhs);
                 }
             }
             (Some(_), None) => return Ordering::Greater,
             (None, Some(_)) => return Ordering::Less,
-            (None, None) => return lhs.cmp(&rhs),
+            (None, None) if blank => return cmp_blank(lhs, rhs),
+            (None, None) => return cmp_variants(lhs, rhs),
         }
     }
 }
@@ -74,20 +501,106 @@ pub fn lexical_cmp(lhs: &str, rhs: &str) -> Ordering {
 /// Compares strings lexicographically, skipping non-alphanumeric characters
 ///
 /// For example, `"a" < " ä" < "ä" < "aa"`
+///
+/// Strings that are equal after skipping non-alphanumeric characters are compared with
+/// `lexical_cmp`.
 pub fn lexical_only_alnum_cmp(s1: &str, s2: &str) -> Ordering {
     let mut iter1 = iterate_lexical_only_alnum(s1);
     let mut iter2 = iterate_lexical_only_alnum(s2);
 
+    let mut blank = true;
     loop {
         match (iter1.next(), iter2.next()) {
             (Some(lhs), Some(rhs)) => {
+                blank = false;
                 if lhs != rhs {
                     return lhs.cmp(&rhs);
                 }
             }
             (Some(_), None) => return Ordering::Greater,
             (None, Some(_)) => return Ordering::Less,
-            (None, None) => return s1.cmp(&s2),
+            (None, None) if blank => return cmp_blank(s1, s2),
+            (None, None) => return lexical_cmp(s1, s2),
+        }
+    }
+}
+
+/// Compares strings lexicographically, ignoring whitespace
+///
+/// For example, `"Star Wars" < "StarWars" < "Star Wars 2"`
+///
+/// All Unicode whitespace is ignored, e.g. tabs, newlines, the no-break space and the
+/// ideographic space, but other non-alphanumeric characters are compared. Strings that are
+/// equal without whitespace are compared with `lexical_cmp`.
+pub fn lexical_ignore_whitespace_cmp(s1: &str, s2: &str) -> Ordering {
+    fn iterate(s: &str) -> impl Iterator<Item = char> + '_ {
+        s.chars()
+            .filter(|c| !c.is_whitespace())
+            .flat_map(iterate_lexical_char)
+    }
+
+    let mut iter1 = iterate(s1);
+    let mut iter2 = iterate(s2);
+
+    let mut blank = true;
+    loop {
+        match (iter1.next(), iter2.next()) {
+            (Some(lhs), Some(rhs)) => {
+                blank = false;
+                if lhs != rhs {
+                    return ret_ordering(lhs, rhs);
+                }
+            }
+            (Some(_), None) => return Ordering::Greater,
+            (None, Some(_)) => return Ordering::Less,
+            (None, None) if blank => return cmp_blank(s1, s2),
+            (None, None) => return lexical_cmp(s1, s2),
+        }
+    }
+}
+
+/// Compares strings naturally and lexicographically, ignoring whitespace
+///
+/// For example, `"Star Wars 2" < "StarWars 10"`
+///
+/// Whitespace is ignored like in `lexical_ignore_whitespace_cmp`, also within numbers, so
+/// `"1 000"` is compared like `"1000"`. Strings that are equal without whitespace are
+/// compared with `natural_lexical_cmp`.
+pub fn natural_lexical_ignore_whitespace_cmp(s1: &str, s2: &str) -> Ordering {
+    let natural1 = Natural {
+        s: s1,
+        lexical: true,
+        only_alnum: false,
+        ignore_whitespace: true,
+    };
+    let natural2 = Natural {
+        s: s2,
+        lexical: true,
+        only_alnum: false,
+        ignore_whitespace: true,
+    };
+    let mut iter1 = natural1.chars_from(0);
+    let mut iter2 = natural2.chars_from(0);
+
+    let mut blank = true;
+    loop {
+        match (iter1.next(), iter2.next()) {
+            (Some(lhs), Some(rhs)) => {
+                blank = false;
+                if lhs.c.is_ascii_digit() && rhs.c.is_ascii_digit() {
+                    let (lhs, rhs) = ((natural1, lhs), (natural2, rhs));
+                    match cmp_numbers(lhs, rhs, &mut iter1, &mut iter2) {
+                        (Ordering::Equal, _) => {}
+                        (ordering, _) => return ordering,
+                    }
+                } else if lhs.c != rhs.c {
+                    return ret_ordering(lhs.c, rhs.c);
+                }
+            }
+            (Some(_), None) => return Ordering::Greater,
+            (None, Some(_)) => return Ordering::Less,
+            (None, None) if blank => return cmp_blank(s1, s2),
+            (None, None) => return natural_lexical_cmp(s1, s2),
         }
     }
 }
@@ -95,22 +608,48 @@ pub fn lexical_only_alnum_cmp(s1: &str, s2: &str) -> Ordering {
 /// Compares strings naturally and lexicographically
 ///
 /// For example, `"a" < "ä" < "aa"`, `"50" < "100"`
+///
+/// Leading zeros of numbers are ignored, unless the strings are equal otherwise, so
+/// `"07" < "8"` and `"7" < "07"`. A vulgar fraction such as `½` is a number of its own,
+/// which sorts between `0` and `1`. Superscript and subscript digits are numbers of their
+/// own as well, so `"x1¹"` is `x`, `1`, `1` rather than `x`, `11`.
 pub fn natural_lexical_cmp(s1: &str, s2: &str) -> Ordering {
-    let mut iter1 = iterate_lexical(s1).peekable();
-    let mut iter2 = iterate_lexical(s2).peekable();
+    let natural1 = Natural {
+        s: s1,
+        lexical: true,
+        only_alnum: false,
+        ignore_whitespace: false,
+    };
+    let natural2 = Natural {
+        s: s2,
+        lexical: true,
+        only_alnum: false,
+        ignore_whitespace: false,
+    };
+    let mut iter1 = natural1.chars_from(0);
+    let mut iter2 = natural2.chars_from(0);
+    // the ordering of the leading zeros of the first numbers that differ only in them
+    let mut zeros = Ordering::Equal;
 
+    let mut blank = true;
     loop {
         match (iter1.next(), iter2.next()) {
             (Some(lhs), Some(rhs)) => {
-                if lhs.is_ascii_digit() && rhs.is_ascii_digit() {
-                    cmp_ascii_digits!(first_digits(lhs, rhs), iterators(iter1, iter2));
-                } else if lhs != rhs {
-                    return ret_ordering(lhs, rhs);
+                blank = false;
+                if lhs.c.is_ascii_digit() && rhs.c.is_ascii_digit() {
+                    let (lhs, rhs) = ((natural1, lhs), (natural2, rhs));
+                    match cmp_numbers(lhs, rhs, &mut iter1, &mut iter2) {
+                        (Ordering::Equal, leading_zeros) => zeros = zeros.then(leading_zeros),
+                        (ordering, _) => return ordering,
+                    }
+                } else if lhs.c != rhs.c {
+                    return ret_ordering(lhs.c, rhs.c);
                 }
             }
             (Some(_), None) => return Ordering::Greater,
             (None, Some(_)) => return Ordering::Less,
-            (None, None) => return s1.cmp(&s2),
+            (None, None) if blank => return cmp_blank(s1, s2),
+            (None, None) => return zeros.then_with(|| cmp_variants(s1, s2)),
         }
     }
 }
@@ -118,22 +657,44 @@ pub fn natural_lexical_cmp(s1: &str, s2: &str) -> Ordering {
 /// Compares strings naturally and lexicographically, skipping non-alphanumeric characters
 ///
 /// For example, `"a" < " ä" < "ä" < "aa"`, `"50" < "100"`
+///
+/// Strings that are equal after skipping non-alphanumeric characters are compared with
+/// `natural_lexical_cmp`.
 pub fn natural_lexical_only_alnum_cmp(s1: &str, s2: &str) -> Ordering {
-    let mut iter1 = iterate_lexical_only_alnum(s1).peekable();
-    let mut iter2 = iterate_lexical_only_alnum(s2).peekable();
+    let natural1 = Natural {
+        s: s1,
+        lexical: true,
+        only_alnum: true,
+        ignore_whitespace: false,
+    };
+    let natural2 = Natural {
+        s: s2,
+        lexical: true,
+        only_alnum: true,
+        ignore_whitespace: false,
+    };
+    let mut iter1 = natural1.chars_from(0);
+    let mut iter2 = natural2.chars_from(0);
 
+    let mut blank = true;
     loop {
         match (iter1.next(), iter2.next()) {
             (Some(lhs), Some(rhs)) => {
-                if lhs.is_ascii_digit() && rhs.is_ascii_digit() {
-                    cmp_ascii_digits!(first_digits(lhs, rhs), iterators(iter1, iter2));
-                } else if lhs != rhs {
-                    return lhs.cmp(&rhs);
+                blank = false;
+                if lhs.c.is_ascii_digit() && rhs.c.is_ascii_digit() {
+                    let (lhs, rhs) = ((natural1, lhs), (natural2, rhs));
+                    match cmp_numbers(lhs, rhs, &mut iter1, &mut iter2) {
+                        (Ordering::Equal, _) => {}
+                        (ordering, _) => return ordering,
+                    }
+                } else if lhs.c != rhs.c {
+                    return lhs.c.cmp(&rhs.c);
                 }
             }
             (Some(_), None) => return Ordering::Greater,
             (None, Some(_)) => return Ordering::Less,
-            (None, None) => return s1.cmp(&s2),
+            (None, None) if blank => return cmp_blank(s1, s2),
+            (None, None) => return natural_lexical_cmp(s1, s2),
         }
     }
 }
@@ -141,22 +702,45 @@ pub fn natural_lexical_only_alnum_cmp(s1: &str, s2: &str) -> Ordering {
 /// Compares strings naturally
 ///
 /// For example, `"50" < "100"`
+///
+/// Leading zeros of numbers are ignored, unless the strings are equal otherwise, so
+/// `"07" < "8"` and `"7" < "07"`. Decimal digits of other scripts, such as `٣` (Arabic-Indic
+/// digit three) or `３` (fullwidth digit three), are compared like ASCII digits, so
+/// `"٣" < "١٢"`. Strings that are equal otherwise are compared with `str::cmp`.
 pub fn natural_cmp(s1: &str, s2: &str) -> Ordering {
-    let mut iter1 = s1.chars().peekable();
-    let mut iter2 = s2.chars().peekable();
+    let natural1 = Natural {
+        s: s1,
+        lexical: false,
+        only_alnum: false,
+        ignore_whitespace: false,
+    };
+    let natural2 = Natural {
+        s: s2,
+        lexical: false,
+        only_alnum: false,
+        ignore_whitespace: false,
+    };
+    let mut iter1 = natural1.chars_from(0);
+    let mut iter2 = natural2.chars_from(0);
+    // the ordering of the leading zeros of the first numbers that differ only in them
+    let mut zeros = Ordering::Equal;
 
     loop {
         match (iter1.next(), iter2.next()) {
             (Some(lhs), Some(rhs)) => {
-                if lhs.is_ascii_digit() && rhs.is_ascii_digit() {
-                    cmp_ascii_digits!(first_digits(lhs, rhs), iterators(iter1, iter2));
-                } else if lhs != rhs {
-                    return lhs.cmp(&rhs);
+                if lhs.c.is_ascii_digit() && rhs.c.is_ascii_digit() {
+                    let (lhs, rhs) = ((natural1, lhs), (natural2, rhs));
+                    match cmp_numbers(lhs, rhs, &mut iter1, &mut iter2) {
+                        (Ordering::Equal, leading_zeros) => zeros = zeros.then(leading_zeros),
+                        (ordering, _) => return ordering,
+                    }
+                } else if lhs.c != rhs.c {
+                    return lhs.c.cmp(&rhs.c);
                 }
             }
             (Some(_), None) => return Ordering::Greater,
             (None, Some(_)) => return Ordering::Less,
-            (None, None) => return Ordering::Equal,
+            (None, None) => return zeros.then_with(|| s1.cmp(s2)),
         }
     }
 }
@@ -164,22 +748,55 @@ pub fn natural_cmp(s1: &str, s2: &str) -> Ordering {
 /// Compares strings naturally, skipping non-alphanumeric characters
 ///
 /// For example, `"a" < " b" < "b"`, `"50" < "100"`
+///
+/// This function is case sensitive and doesn't transliterate characters. Strings are compared
+/// in three steps, and each step is only used if the previous one considers the strings equal:
+///
+/// 1. The alphanumeric characters are compared, with numbers compared naturally.
+///    So `"a-B"` and `"aB"` are equal in this step, but `"aB"` is less than `"ab"`.
+/// 2. All characters are compared with `natural_cmp`, so `"a-B" < "aB"`.
+/// 3. The strings are compared with `str::cmp`, which only considers equal strings equal.
+///
+/// Strings without alphanumeric characters (e.g. `""`, `" "` or `"-"`) are an exception: They
+/// sort before all other strings, and are ordered by their length, then with `str::cmp`.
+///
+/// Since every step is a refinement of the previous one, this is a total order: sorting
+/// the same strings always produces the same result, regardless of their initial order.
 pub fn natural_only_alnum_cmp(s1: &str, s2: &str) -> Ordering {
-    let mut iter1 = s1.chars().filter(|c| c.is_alphanumeric()).peekable();
-    let mut iter2 = s2.chars().filter(|c| c.is_alphanumeric()).peekable();
+    let natural1 = Natural {
+        s: s1,
+        lexical: false,
+        only_alnum: true,
+        ignore_whitespace: false,
+    };
+    let natural2 = Natural {
+        s: s2,
+        lexical: false,
+        only_alnum: true,
+        ignore_whitespace: false,
+    };
+    let mut iter1 = natural1.chars_from(0);
+    let mut iter2 = natural2.chars_from(0);
 
+    let mut blank = true;
     loop {
         match (iter1.next(), iter2.next()) {
             (Some(lhs), Some(rhs)) => {
-                if lhs.is_ascii_digit() && rhs.is_ascii_digit() {
-                    cmp_ascii_digits!(first_digits(lhs, rhs), iterators(iter1, iter2));
-                } else if lhs != rhs {
-                    return lhs.cmp(&rhs);
+                blank = false;
+                if lhs.c.is_ascii_digit() && rhs.c.is_ascii_digit() {
+                    let (lhs, rhs) = ((natural1, lhs), (natural2, rhs));
+                    match cmp_numbers(lhs, rhs, &mut iter1, &mut iter2) {
+                        (Ordering::Equal, _) => {}
+                        (ordering, _) => return ordering,
+                    }
+                } else if lhs.c != rhs.c {
+                    return lhs.c.cmp(&rhs.c);
                 }
             }
             (Some(_), None) => return Ordering::Greater,
             (None, Some(_)) => return Ordering::Less,
-            (None, None) => return s1.cmp(&s2),
+            (None, None) if blank => return cmp_blank(s1, s2),
+            (None, None) => return natural_cmp(s1, s2),
         }
     }
 }
@@ -191,16 +808,19 @@ pub fn only_alnum_cmp(s1: &str, s2: &str) -> Ordering {
     let mut iter1 = s1.chars().filter(|c| c.is_alphanumeric());
     let mut iter2 = s2.chars().filter(|c| c.is_alphanumeric());
 
+    let mut blank = true;
     loop {
         match (iter1.next(), iter2.next()) {
             (Some(lhs), Some(rhs)) => {
+                blank = false;
                 if lhs != rhs {
                     return lhs.cmp(&rhs);
                 }
             }
             (Some(_), None) => return Ordering::Greater,
             (None, Some(_)) => return Ordering::Less,
-            (None, None) => return s1.cmp(&s2),
+            (None, None) if blank => return cmp_blank(s1, s2),
+            (None, None) => return s1.cmp(s2),
         }
     }
 }
@@ -226,57 +846,914 @@ pub fn cmp(s1: &str, s2: &str) -> Ordering {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Compares strings in shortlex order: Strings with fewer characters after transliteration
+/// come first, and strings with the same number of characters are compared with `lexical_cmp`
+///
+/// The characters are counted like `lexical_cmp` compares them, so `ß` counts as two
+/// characters (`ss`), and combining marks don't count. The lengths are compared without
+/// counting all characters of the longer string.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::shortlex_lexical_cmp;
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(shortlex_lexical_cmp("zz", "aaa"), Ordering::Less);
+/// assert_eq!(shortlex_lexical_cmp("ß", "sa"), Ordering::Greater);
+/// assert_eq!(shortlex_lexical_cmp("ß", "sab"), Ordering::Less);
+/// # }
+/// ```
+pub fn shortlex_lexical_cmp(lhs: &str, rhs: &str) -> Ordering {
+    cmp_lexical_len(lhs, rhs).then_with(|| lexical_cmp(lhs, rhs))
+}
 
-    fn make_test(desc: &'static str, algo: impl Fn(&str, &str) -> Ordering) -> impl Fn(&str, &str) {
-        move |lhs, rhs| {
-            let success = algo(lhs, rhs) == Ordering::Less;
-            assert!(success, "{} comparison {:?} < {:?} failed", desc, lhs, rhs);
+/// Compares strings in shortlex order, like `shortlex_lexical_cmp`, but strings with the same
+/// number of characters are compared with `natural_lexical_cmp`
+///
+/// Digits are counted as characters, so `"x9" < "x10"`, but also `"x99" < "x100"` and
+/// `"y1" < "x10"`.
+pub fn shortlex_natural_lexical_cmp(lhs: &str, rhs: &str) -> Ordering {
+    cmp_lexical_len(lhs, rhs).then_with(|| natural_lexical_cmp(lhs, rhs))
+}
 
-            let success = algo(rhs, lhs) == Ordering::Greater;
-            assert!(success, "{} comparison {:?} > {:?} failed", desc, rhs, lhs);
+/// Compares the number of characters of strings after transliteration, stopping at the end
+/// of the shorter string
+fn cmp_lexical_len(lhs: &str, rhs: &str) -> Ordering {
+    let mut iter1 = iterate_lexical(lhs);
+    let mut iter2 = iterate_lexical(rhs);
+    loop {
+        match (iter1.next(), iter2.next()) {
+            (Some(_), Some(_)) => {}
+            (l, r) => return l.is_some().cmp(&r.is_some()),
         }
     }
+}
 
-    #[test]
-    fn test_cmp() {
-        let ordered = make_test("Cmp", cmp);
-
-        ordered("aaa", "aaaa");
-        ordered("aaa", "aab");
-        ordered("AAb", "aaa");
-        ordered("aab", "äáa");
-        ordered("aaa", "äáb");
+/// Compares the first `n` characters of strings lexicographically, after transliterating them
+///
+/// This is consistent with `lexical_cmp`: If it returns `Less` or `Greater`, `lexical_cmp`
+/// returns the same. If the strings don't differ in their first `n` characters, it returns
+/// `Equal`, also if they differ later or only in their tie-break. This can be used to put
+/// strings into buckets, e.g. by their first letter, before sorting each bucket.
+///
+/// The `n` characters are counted after transliteration, so a character that is
+/// transliterated to several characters counts as several characters, e.g. `"ß"` counts as
+/// `"ss"`. The prefixes of both strings are determined independently of each other, so the
+/// strings that are equal form equivalence classes.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::lexical_cmp_prefix;
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(lexical_cmp_prefix("Apfel", "ananas", 1), Ordering::Equal);
+/// assert_eq!(lexical_cmp_prefix("Apfel", "ananas", 2), Ordering::Greater);
+/// assert_eq!(lexical_cmp_prefix("Straße", "strassen", 6), Ordering::Equal);
+/// assert_eq!(lexical_cmp_prefix("Straße", "strasze", 5), Ordering::Equal);
+/// assert_eq!(lexical_cmp_prefix("Straße", "strasze", 6), Ordering::Less);
+/// # }
+/// ```
+pub fn lexical_cmp_prefix(lhs: &str, rhs: &str, n: usize) -> Ordering {
+    let mut iter1 = iterate_lexical(lhs).take(n);
+    let mut iter2 = iterate_lexical(rhs).take(n);
 
-        ordered("T-20", "T-5");
-        ordered("T-5", "Ŧ-5");
+    loop {
+        match (iter1.next(), iter2.next()) {
+            (Some(l), Some(r)) if l != r => return ret_ordering(l, r),
+            (Some(_), Some(_)) => {}
+            (Some(_), None) => return Ordering::Greater,
+            (None, Some(_)) => return Ordering::Less,
+            (None, None) => return Ordering::Equal,
+        }
     }
+}
 
-    #[test]
-    fn test_only_alnum() {
-        let ordered = make_test("Only-alnum", only_alnum_cmp);
+/// Compares the first `n` characters of strings naturally and lexicographically, like
+/// `lexical_cmp_prefix`
+///
+/// This is consistent with `natural_lexical_cmp`. A number counts as one character, and a
+/// number that starts among the first `n` characters is compared completely. So
+/// `"T-100"` is greater than `"T-20"` with `n = 3`.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::natural_lexical_cmp_prefix;
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(natural_lexical_cmp_prefix("T-100", "t-20", 3), Ordering::Greater);
+/// assert_eq!(natural_lexical_cmp_prefix("T-100 a", "t-0100 b", 4), Ordering::Equal);
+/// ```
+pub fn natural_lexical_cmp_prefix(lhs: &str, rhs: &str, n: usize) -> Ordering {
+    natural_cmp_prefix_with(lhs, rhs, n, true)
+}
 
-        ordered("aaa", "aaaa");
-        ordered("aaa", "aab");
-        ordered("AAb", "aaa");
-        ordered("aab", "äáa");
-        ordered("aaa", "äáb");
+/// Compares the first `n` characters of strings naturally, like `natural_lexical_cmp_prefix`
+///
+/// This is consistent with `natural_cmp`. A number counts as one character, and a number that
+/// starts among the first `n` characters is compared completely.
+pub fn natural_cmp_prefix(lhs: &str, rhs: &str, n: usize) -> Ordering {
+    natural_cmp_prefix_with(lhs, rhs, n, false)
+}
 
-        ordered("_ad", "_æ");
-        ordered("_ae", "_æ");
-        ordered("_ae_", "_æ");
-        ordered("_af", "_æ");
+fn natural_cmp_prefix_with(s1: &str, s2: &str, n: usize, lexical: bool) -> Ordering {
+    let natural1 = Natural {
+        s: s1,
+        lexical,
+        only_alnum: false,
+        ignore_whitespace: false,
+    };
+    let natural2 = Natural { s: s2, ..natural1 };
+    let mut iter1 = natural1.chars_from(0);
+    let mut iter2 = natural2.chars_from(0);
 
-        ordered("T-20", "T-5");
-        ordered("T-5", "Ŧ-5");
+    // both strings advance by one character or number in each step
+    for _ in 0..n {
+        match (iter1.next(), iter2.next()) {
+            (Some(lhs), Some(rhs)) => {
+                if lhs.c.is_ascii_digit() && rhs.c.is_ascii_digit() {
+                    let (lhs, rhs) = ((natural1, lhs), (natural2, rhs));
+                    match cmp_numbers(lhs, rhs, &mut iter1, &mut iter2) {
+                        (Ordering::Equal, _) => {}
+                        (ordering, _) => return ordering,
+                    }
+                } else if lhs.c != rhs.c && lexical {
+                    return ret_ordering(lhs.c, rhs.c);
+                } else if lhs.c != rhs.c {
+                    return lhs.c.cmp(&rhs.c);
+                }
+            }
+            (Some(_), None) => return Ordering::Greater,
+            (None, Some(_)) => return Ordering::Less,
+            (None, None) => break,
+        }
     }
+    Ordering::Equal
+}
+
+/// Returns whether strings are equal in a lexical comparison, i.e. equal after
+/// transliteration, e.g. `"Foo"` and `"fóò"`
+///
+/// This is an equivalence relation that is consistent with `lexical_cmp`, except for its
+/// tie-break: Strings for which this returns `true` are only ordered by the tie-break, so they
+/// are next to each other after sorting. The comparison stops at the first difference.
+/// Strings that are equal are hashed equally by `lexical_hash`.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::lexical_eq;
+///
+/// assert!(lexical_eq("Foo", "fóò"));
+/// assert!(lexical_eq("Straße", "STRASSE"));
+/// assert!(!lexical_eq("foo", "foo bar"));
+/// # }
+/// ```
+pub fn lexical_eq(s1: &str, s2: &str) -> bool {
+    iterate_lexical(s1).eq(iterate_lexical(s2))
+}
+
+/// Returns whether strings are equal in a lexical comparison that skips non-alphanumeric
+/// characters, e.g. `"Foo-Bar"` and `"foobar"`
+///
+/// This is consistent with `lexical_only_alnum_cmp`, except for its tie-break, like
+/// `lexical_eq`.
+pub fn lexical_only_alnum_eq(s1: &str, s2: &str) -> bool {
+    iterate_lexical_only_alnum(s1).eq(iterate_lexical_only_alnum(s2))
+}
+
+/// Returns whether strings are equal in a natural lexical comparison, i.e. equal after
+/// transliteration, with numbers compared by their value. Leading zeros are ignored, so
+/// `"x01"` and `"x1"` are equal.
+///
+/// This is consistent with `natural_lexical_cmp`, except for its tie-break, like
+/// `lexical_eq`.
+pub fn natural_lexical_eq(s1: &str, s2: &str) -> bool {
+    natural_eq_with(s1, s2, true, false)
+}
+
+/// Returns whether strings are equal in a natural lexical comparison that skips
+/// non-alphanumeric characters, e.g. `"File-01"` and `"file 1"`
+///
+/// This is consistent with `natural_lexical_only_alnum_cmp`, except for its tie-break, like
+/// `lexical_eq`.
+pub fn natural_lexical_only_alnum_eq(s1: &str, s2: &str) -> bool {
+    natural_eq_with(s1, s2, true, true)
+}
+
+/// Returns whether strings are equal in a natural comparison, i.e. equal with numbers compared
+/// by their value. Leading zeros are ignored, so `"x01"` and `"x1"` are equal.
+///
+/// This is consistent with `natural_cmp`, except for its tie-break, like `lexical_eq`.
+pub fn natural_eq(s1: &str, s2: &str) -> bool {
+    natural_eq_with(s1, s2, false, false)
+}
+
+/// Returns whether strings are equal in a natural comparison that skips non-alphanumeric
+/// characters, e.g. `"x-01"` and `"x1"`
+///
+/// This is consistent with `natural_only_alnum_cmp`, except for its tie-break, like
+/// `lexical_eq`.
+pub fn natural_only_alnum_eq(s1: &str, s2: &str) -> bool {
+    natural_eq_with(s1, s2, false, true)
+}
+
+/// Returns whether strings are equal after skipping non-alphanumeric characters, e.g. `"a-b"`
+/// and `"ab"`
+///
+/// This is consistent with `only_alnum_cmp`, except for its tie-break, like `lexical_eq`.
+pub fn only_alnum_eq(s1: &str, s2: &str) -> bool {
+    let iter1 = s1.chars().filter(|c| c.is_alphanumeric());
+    let iter2 = s2.chars().filter(|c| c.is_alphanumeric());
+    iter1.eq(iter2)
+}
+
+/// Feeds a string into a hasher, so that strings are hashed equally if they are equal
+/// according to `lexical_eq`, e.g. `"Foo"` and `"fóò"`
+///
+/// Strings that aren't equal may be hashed equally as well, but this is unlikely.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::lexical_hash;
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::Hasher;
+///
+/// let hash = |s: &str| {
+///     let mut hasher = DefaultHasher::new();
+///     lexical_hash(s, &mut hasher);
+///     hasher.finish()
+/// };
+/// assert_eq!(hash("Foo"), hash("fóò"));
+/// # }
+/// ```
+pub fn lexical_hash(s: &str, hasher: &mut impl Hasher) {
+    iterate_lexical(s).for_each(|c| c.hash(hasher));
+    hasher.write_u8(0xff);
+}
+
+/// Feeds a string into a hasher, so that strings are hashed equally if they are equal
+/// according to `lexical_only_alnum_eq`, like `lexical_hash`
+pub fn lexical_only_alnum_hash(s: &str, hasher: &mut impl Hasher) {
+    iterate_lexical_only_alnum(s).for_each(|c| c.hash(hasher));
+    hasher.write_u8(0xff);
+}
+
+/// Feeds a string into a hasher, so that strings are hashed equally if they are equal
+/// according to `natural_lexical_eq`, like `lexical_hash`. Numbers are hashed by their value,
+/// so `"x01"` and `"x1"` are hashed equally.
+pub fn natural_lexical_hash(s: &str, hasher: &mut impl Hasher) {
+    natural_hash_with(s, true, false, hasher);
+}
+
+/// Feeds a string into a hasher, so that strings are hashed equally if they are equal
+/// according to `natural_lexical_only_alnum_eq`, like `natural_lexical_hash`
+pub fn natural_lexical_only_alnum_hash(s: &str, hasher: &mut impl Hasher) {
+    natural_hash_with(s, true, true, hasher);
+}
+
+/// Feeds a string into a hasher, so that strings are hashed equally if they are equal
+/// according to `natural_eq`, like `natural_lexical_hash`
+pub fn natural_hash(s: &str, hasher: &mut impl Hasher) {
+    natural_hash_with(s, false, false, hasher);
+}
+
+/// Feeds a string into a hasher, so that strings are hashed equally if they are equal
+/// according to `natural_only_alnum_eq`, like `natural_lexical_hash`
+pub fn natural_only_alnum_hash(s: &str, hasher: &mut impl Hasher) {
+    natural_hash_with(s, false, true, hasher);
+}
+
+/// Feeds a string into a hasher, so that strings are hashed equally if they are equal
+/// according to `only_alnum_eq`, like `lexical_hash`
+pub fn only_alnum_hash(s: &str, hasher: &mut impl Hasher) {
+    s.chars()
+        .filter(|c| c.is_alphanumeric())
+        .for_each(|c| c.hash(hasher));
+    hasher.write_u8(0xff);
+}
+
+/// Hashes the converted characters of a string in a natural comparison. Numbers are hashed by
+/// their value: Runs of digits without their leading zeros, and characters that are numbers
+/// of their own, e.g. `½`, as their reduced fraction.
+fn natural_hash_with(s: &str, lexical: bool, only_alnum: bool, hasher: &mut impl Hasher) {
+    let natural = Natural {
+        s,
+        lexical,
+        only_alnum,
+        ignore_whitespace: false,
+    };
+    let mut iter = natural.chars_from(0);
+
+    while let Some(first) = iter.next() {
+        if !first.c.is_ascii_digit() {
+            first.c.hash(hasher);
+            continue;
+        }
+        match first.kind.single_value() {
+            Some((numerator, denominator)) => {
+                let gcd = gcd(numerator, denominator);
+                hash_digits(numerator / gcd, hasher);
+                if denominator != gcd {
+                    '/'.hash(hasher);
+                    hash_digits(denominator / gcd, hasher);
+                }
+            }
+            None => {
+                let mut digits = Some(first.c);
+                let mut leading_zero = true;
+                while let Some(c) = digits {
+                    if !(leading_zero && c == '0') {
+                        leading_zero = false;
+                        c.hash(hasher);
+                    }
+                    digits = next_ascii_digit(&mut iter, first.kind);
+                    if digits.is_some() {
+                        let _ = iter.next();
+                    }
+                }
+            }
+        }
+    }
+    hasher.write_u8(0xff);
+}
+
+/// Hashes the decimal digits of a number, without leading zeros, so no digits are hashed
+/// for 0
+fn hash_digits(value: u32, hasher: &mut impl Hasher) {
+    if value != 0 {
+        DecimalDigits::new(value).for_each(|c| c.hash(hasher));
+    }
+}
+
+/// Returns the greatest common divisor of two numbers, or 1 if both are 0
+fn gcd(mut a: u32, mut b: u32) -> u32 {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a.max(1)
+}
+
+/// Returns whether the converted characters of strings are equal in a natural comparison,
+/// with numbers compared by their value
+fn natural_eq_with(s1: &str, s2: &str, lexical: bool, only_alnum: bool) -> bool {
+    let natural1 = Natural {
+        s: s1,
+        lexical,
+        only_alnum,
+        ignore_whitespace: false,
+    };
+    let natural2 = Natural { s: s2, ..natural1 };
+    let mut iter1 = natural1.chars_from(0);
+    let mut iter2 = natural2.chars_from(0);
+
+    loop {
+        match (iter1.next(), iter2.next()) {
+            (Some(lhs), Some(rhs)) => {
+                if lhs.c.is_ascii_digit() && rhs.c.is_ascii_digit() {
+                    let (lhs, rhs) = ((natural1, lhs), (natural2, rhs));
+                    if cmp_numbers(lhs, rhs, &mut iter1, &mut iter2).0 != Ordering::Equal {
+                        return false;
+                    }
+                } else if lhs.c != rhs.c {
+                    return false;
+                }
+            }
+            (None, None) => return true,
+            _ => return false,
+        }
+    }
+}
+
+/// Compares two entries that have a display string and an optional reading, e.g. Japanese
+/// names with their kana reading (furigana).
+///
+/// Each entry is compared by its reading, or by its display string if it has no reading.
+/// Ties are always broken by comparing the display strings. Both comparisons use the
+/// provided comparison function.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::{cmp_by_reading, lexical_cmp};
+/// use std::cmp::Ordering;
+///
+/// let tanaka = ("田中", Some("たなか"));
+/// let sato = ("佐藤", Some("さとう"));
+/// assert_eq!(cmp_by_reading(sato, tanaka, lexical_cmp), Ordering::Less);
+/// ```
+pub fn cmp_by_reading(
+    lhs: (&str, Option<&str>),
+    rhs: (&str, Option<&str>),
+    mut cmp: impl FnMut(&str, &str) -> Ordering,
+) -> Ordering {
+    let lhs_key = lhs.1.unwrap_or(lhs.0);
+    let rhs_key = rhs.1.unwrap_or(rhs.0);
+
+    match cmp(lhs_key, rhs_key) {
+        Ordering::Equal => cmp(lhs.0, rhs.0),
+        ordering => ordering,
+    }
+}
+
+/// Compares multi-line strings line by line, with the comparison function of the given mode.
+///
+/// The strings are split into lines like `str::lines`, i.e. at `\n` or `\r\n`. The first
+/// lines are compared first; if they are equal, the second lines are compared, and so on. If
+/// all lines of one string are equal to the first lines of the other string, the string with
+/// fewer lines is less.
+///
+/// A trailing newline doesn't start a new line, so `"a\nb"` and `"a\nb\n"` have the same
+/// lines. Strings with the same lines are compared with `str::cmp`, so `"a\nb" < "a\nb\n"`
+/// and `"a\nb" < "a\r\nb"`.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::{multiline_cmp, SortMode};
+/// use std::cmp::Ordering;
+///
+/// // with `lexical_cmp`, "\n" < " ", so "fn main()\n" < "fn main() {"
+/// let lhs = "fn main() {\n    a();\n}";
+/// let rhs = "fn main()\n{\n    b();\n}";
+/// assert_eq!(multiline_cmp(lhs, rhs, SortMode::Lexical), Ordering::Greater);
+/// assert_eq!(multiline_cmp("b\nc", "b\nc\nd", SortMode::Lexical), Ordering::Less);
+/// ```
+pub fn multiline_cmp(lhs: &str, rhs: &str, mode: SortMode) -> Ordering {
+    let cmp = mode.function();
+    let mut lines1 = lhs.lines();
+    let mut lines2 = rhs.lines();
+
+    loop {
+        match (lines1.next(), lines2.next()) {
+            (Some(l), Some(r)) => match cmp(l, r) {
+                Ordering::Equal => {}
+                ordering => return ordering,
+            },
+            (Some(_), None) => return Ordering::Greater,
+            (None, Some(_)) => return Ordering::Less,
+            (None, None) => return lhs.cmp(rhs),
+        }
+    }
+}
+
+/// Compares strings word by word, with `lexical_cmp`.
+///
+/// The strings are split into words at Unicode whitespace, like `str::split_whitespace`. The
+/// first words are compared first; if they are equal, the second words are compared, and so
+/// on. If all words of one string are equal to the first words of the other string, the
+/// string with fewer words is less. This means that the whitespace between words doesn't
+/// matter, so `"New  York" > "New Jersey"`, although `"  " < " J"`.
+///
+/// Leading, trailing and repeated whitespace doesn't create empty words. Strings with the
+/// same words are compared with `str::cmp`, so `"a b" < "a  b"`.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::{lexical_cmp, wordwise_lexical_cmp};
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(wordwise_lexical_cmp("New  York", "New Jersey"), Ordering::Greater);
+/// assert_eq!(lexical_cmp("New  York", "New Jersey"), Ordering::Less);
+/// assert_eq!(wordwise_lexical_cmp("New York", "Newark"), Ordering::Less);
+/// ```
+pub fn wordwise_lexical_cmp(lhs: &str, rhs: &str) -> Ordering {
+    cmp_words(lhs, rhs, lexical_cmp, lexical_eq)
+}
+
+/// Compares strings word by word, like `wordwise_lexical_cmp`, but the words are compared
+/// with `natural_lexical_cmp`
+pub fn wordwise_natural_lexical_cmp(lhs: &str, rhs: &str) -> Ordering {
+    cmp_words(lhs, rhs, natural_lexical_cmp, natural_lexical_eq)
+}
+
+/// Compares the words of strings with the given function, and strings with the same words
+/// with `str::cmp`
+///
+/// All words are compared without their tie-break (`eq`) first, so the tie-break of an
+/// earlier word can't outweigh a real difference in a later word.
+fn cmp_words(
+    lhs: &str,
+    rhs: &str,
+    cmp: fn(&str, &str) -> Ordering,
+    eq: fn(&str, &str) -> bool,
+) -> Ordering {
+    let mut words1 = lhs.split_whitespace();
+    let mut words2 = rhs.split_whitespace();
+
+    loop {
+        match (words1.next(), words2.next()) {
+            (Some(l), Some(r)) if eq(l, r) => {}
+            (Some(l), Some(r)) => return cmp(l, r),
+            (Some(_), None) => return Ordering::Greater,
+            (None, Some(_)) => return Ordering::Less,
+            (None, None) => break,
+        }
+    }
+
+    // the words only differ in their tie-breaks
+    lhs.split_whitespace()
+        .zip(rhs.split_whitespace())
+        .map(|(l, r)| cmp(l, r))
+        .find(|&ordering| ordering != Ordering::Equal)
+        .unwrap_or_else(|| lhs.cmp(rhs))
+}
+
+/// Compares strings naturally, with runs of ASCII letters compared by their length first, like
+/// spreadsheet column labels
+///
+/// For example, `"A" < "B" < "Z" < "AA" < "AZ" < "BA"`, and `"Sheet1!Z9" < "Sheet1!AA3"`.
+///
+/// A run of ASCII letters with more letters is greater; runs with the same number of letters
+/// are compared alphabetically, ignoring case. Runs of ASCII digits are compared numerically,
+/// like in `natural_cmp`. Other characters are compared by their code point, where ASCII letters
+/// count as uppercase, so `"Z" < "_" < "a_"`. Note that this is usually wrong for ordinary
+/// words, e.g. `"Zoo" < "Apple"`.
+///
+/// Strings that are equal otherwise are compared with `natural_cmp`, so `"AA" < "aa"`.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::{column_label_cmp, StringSort};
+///
+/// let slice = &mut ["AA", "B", "Z", "AZ", "A", "BA"];
+/// slice.string_sort_unstable(column_label_cmp);
+/// assert_eq!(slice, &["A", "B", "Z", "AA", "AZ", "BA"]);
+/// ```
+pub fn column_label_cmp(s1: &str, s2: &str) -> Ordering {
+    let mut iter1 = s1.chars().peekable();
+    let mut iter2 = s2.chars().peekable();
+
+    loop {
+        match (iter1.next(), iter2.next()) {
+            (Some(lhs), Some(rhs)) => {
+                let ordering = if lhs.is_ascii_digit() && rhs.is_ascii_digit() {
+                    cmp_ascii_digits(lhs, rhs, &mut iter1, &mut iter2).0
+                } else if lhs.is_ascii_alphabetic() && rhs.is_ascii_alphabetic() {
+                    cmp_ascii_letters(lhs, rhs, &mut iter1, &mut iter2)
+                } else {
+                    // letters are uppercased, so they are all less than e.g. `_`
+                    lhs.to_ascii_uppercase().cmp(&rhs.to_ascii_uppercase())
+                };
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            (Some(_), None) => return Ordering::Greater,
+            (None, Some(_)) => return Ordering::Less,
+            (None, None) => return natural_cmp(s1, s2),
+        }
+    }
+}
+
+/// Compares two runs of ASCII letters by their length, then alphabetically, ignoring case.
+/// `lhs` and `rhs` are the first letters, the remaining letters are consumed from the
+/// iterators.
+fn cmp_ascii_letters(
+    lhs: char,
+    rhs: char,
+    iter1: &mut Peekable<impl Iterator<Item = char>>,
+    iter2: &mut Peekable<impl Iterator<Item = char>>,
+) -> Ordering {
+    let letter = |c: &&char| c.is_ascii_alphabetic();
+    let mut ordering = lhs.to_ascii_uppercase().cmp(&rhs.to_ascii_uppercase());
+    loop {
+        match (iter1.peek().filter(letter), iter2.peek().filter(letter)) {
+            (Some(lhs), Some(rhs)) => {
+                ordering = ordering.then(lhs.to_ascii_uppercase().cmp(&rhs.to_ascii_uppercase()));
+                let _ = iter1.next();
+                let _ = iter2.next();
+            }
+            (Some(_), None) => return Ordering::Greater,
+            (None, Some(_)) => return Ordering::Less,
+            (None, None) => return ordering,
+        }
+    }
+}
+
+/// Compares the runs of ASCII digits at the start of two strings numerically, and returns the
+/// ordering and the byte lengths of the runs
+///
+/// Each run is the longest prefix of ASCII digits, which may be empty. An empty run is less
+/// than every other run. Leading zeros are ignored, so `"007"` and `"7"` are equal, but their
+/// lengths differ. This never overflows, no matter how many digits the runs have.
+///
+/// This is the comparison of numbers that the natural comparison functions use, so it can be
+/// used to build natural comparisons with a different tokenization. To compare runs of
+/// characters from iterators, use `cmp_digit_runs_iter`.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::cmp_digit_runs;
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(cmp_digit_runs("9 lives", "10 lives"), (Ordering::Less, 1, 2));
+/// assert_eq!(cmp_digit_runs("007", "7up"), (Ordering::Equal, 3, 1));
+/// assert_eq!(cmp_digit_runs("x1", "1"), (Ordering::Less, 0, 1));
+/// ```
+pub fn cmp_digit_runs(lhs: &str, rhs: &str) -> (Ordering, usize, usize) {
+    let run = |s: &str| s.bytes().take_while(u8::is_ascii_digit).count();
+    let (len1, len2) = (run(lhs), run(rhs));
+    let ordering = match (len1, len2) {
+        (0, _) | (_, 0) => len1.min(1).cmp(&len2.min(1)),
+        _ => cmp_digit_bytes(&lhs.as_bytes()[..len1], &rhs.as_bytes()[..len2]).0,
+    };
+    (ordering, len1, len2)
+}
+
+/// Compares the runs of ASCII digits at the start of two iterators numerically, like
+/// `cmp_digit_runs`, and consumes them
+///
+/// The digits of the runs are consumed from the iterators, so they point to the first
+/// character after the runs afterwards. Nothing else is consumed.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::cmp_digit_runs_iter;
+/// use std::cmp::Ordering;
+///
+/// let mut iter1 = "123abc".chars().peekable();
+/// let mut iter2 = "0124".chars().peekable();
+/// assert_eq!(cmp_digit_runs_iter(&mut iter1, &mut iter2), Ordering::Less);
+/// assert_eq!(iter1.next(), Some('a'));
+/// assert_eq!(iter2.next(), None);
+/// ```
+pub fn cmp_digit_runs_iter(
+    iter1: &mut Peekable<impl Iterator<Item = char>>,
+    iter2: &mut Peekable<impl Iterator<Item = char>>,
+) -> Ordering {
+    let digit = |c: &&char| c.is_ascii_digit();
+    match (iter1.peek().filter(digit), iter2.peek().filter(digit)) {
+        (Some(&lhs), Some(&rhs)) => {
+            let _ = iter1.next();
+            let _ = iter2.next();
+            cmp_ascii_digits(lhs, rhs, iter1, iter2).0
+        }
+        (Some(_), None) => {
+            while matches!(iter1.peek(), Some(c) if c.is_ascii_digit()) {
+                let _ = iter1.next();
+            }
+            Ordering::Greater
+        }
+        (None, Some(_)) => {
+            while matches!(iter2.peek(), Some(c) if c.is_ascii_digit()) {
+                let _ = iter2.next();
+            }
+            Ordering::Less
+        }
+        (None, None) => Ordering::Equal,
+    }
+}
+
+/// Compares ASCII strings lexicographically. This is a `const fn`, so it can be used to
+/// sort or check string literals at compile time.
+///
+/// For ASCII strings, this returns the same result as `lexical_cmp`. Non-ASCII strings are
+/// **not** transliterated: Their bytes are compared raw, and are sorted after all ASCII
+/// alphanumeric characters.
+///
+/// For example, `"a" < "B" < "b" < "ba"`
+pub const fn const_ascii_lexical_cmp(lhs: &str, rhs: &str) -> Ordering {
+    let lhs = lhs.as_bytes();
+    let rhs = rhs.as_bytes();
+
+    let mut i = 0;
+    while i < lhs.len() && i < rhs.len() {
+        let l = lhs[i].to_ascii_lowercase();
+        let r = rhs[i].to_ascii_lowercase();
+        if l != r {
+            let is_l_alnum = l.is_ascii_alphanumeric() || !l.is_ascii();
+            let is_r_alnum = r.is_ascii_alphanumeric() || !r.is_ascii();
+
+            return if is_l_alnum == is_r_alnum {
+                if l < r {
+                    Ordering::Less
+                } else {
+                    Ordering::Greater
+                }
+            } else if is_l_alnum {
+                Ordering::Greater
+            } else {
+                Ordering::Less
+            };
+        }
+        i += 1;
+    }
+
+    if lhs.len() != rhs.len() {
+        return if lhs.len() < rhs.len() {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        };
+    }
+
+    // Same lowercase representation, so fall back to comparing the bytes
+    let mut i = 0;
+    while i < lhs.len() {
+        if lhs[i] != rhs[i] {
+            return if lhs[i] < rhs[i] {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            };
+        }
+        i += 1;
+    }
+    Ordering::Equal
+}
+
+/// Returns `true` if the strings are sorted according to `const_ascii_lexical_cmp`.
+/// This is a `const fn`, so it can be used in a `const` assertion:
+///
+/// ```rust
+/// use lexical_sort::is_sorted_ascii;
+///
+/// const KEYWORDS: &[&str] = &["as", "Break", "const", "continue", "crate"];
+/// const _: () = assert!(is_sorted_ascii(KEYWORDS));
+/// ```
+pub const fn is_sorted_ascii(strings: &[&str]) -> bool {
+    let mut i = 1;
+    while i < strings.len() {
+        if let Ordering::Greater = const_ascii_lexical_cmp(strings[i - 1], strings[i]) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Compares ASCII strings lexicographically, like `lexical_cmp`, but faster.
+///
+/// This compares the bytes of the strings directly, without transliterating them. If both
+/// strings are ASCII, it returns the same result as `lexical_cmp`, so it can be used in hot
+/// loops that compare ASCII identifiers. As soon as a non-ASCII byte is encountered, it falls
+/// back to `lexical_cmp`, so the result is always correct, but it is slower for non-ASCII
+/// strings than `lexical_cmp` itself.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::{ascii_lexical_cmp, lexical_cmp};
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(ascii_lexical_cmp("foo_bar", "Foo2"), Ordering::Less);
+/// assert_eq!(ascii_lexical_cmp("Foo", "foo"), Ordering::Less);
+/// assert_eq!(ascii_lexical_cmp("Äpfel", "Birne"), lexical_cmp("Äpfel", "Birne"));
+/// ```
+pub fn ascii_lexical_cmp(lhs: &str, rhs: &str) -> Ordering {
+    let (b1, b2) = (lhs.as_bytes(), rhs.as_bytes());
+
+    for (&l, &r) in b1.iter().zip(b2) {
+        if !(l | r).is_ascii() {
+            return lexical_cmp(lhs, rhs);
+        }
+        let (l, r) = (l.to_ascii_lowercase(), r.to_ascii_lowercase());
+        if l != r {
+            return cmp_ascii_bytes(l, r);
+        }
+    }
+
+    let n = b1.len().min(b2.len());
+    if !(b1[n..].is_ascii() && b2[n..].is_ascii()) {
+        return lexical_cmp(lhs, rhs);
+    }
+    b1.len().cmp(&b2.len()).then_with(|| b1.cmp(b2))
+}
+
+/// Compares ASCII strings naturally and lexicographically, like `natural_lexical_cmp`, but
+/// faster.
+///
+/// This compares the bytes of the strings directly, like `ascii_lexical_cmp`. If both strings
+/// are ASCII, it returns the same result as `natural_lexical_cmp`. As soon as a non-ASCII byte
+/// is encountered, it falls back to `natural_lexical_cmp`.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::ascii_natural_lexical_cmp;
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(ascii_natural_lexical_cmp("T-5", "t-20"), Ordering::Less);
+/// assert_eq!(ascii_natural_lexical_cmp("x7", "x07"), Ordering::Less);
+/// assert_eq!(ascii_natural_lexical_cmp("x½", "x1"), Ordering::Less);
+/// ```
+pub fn ascii_natural_lexical_cmp(s1: &str, s2: &str) -> Ordering {
+    let (b1, b2) = (s1.as_bytes(), s2.as_bytes());
+    let (mut i, mut j) = (0, 0);
+    // the ordering of the leading zeros of the first numbers that differ only in them
+    let mut zeros = Ordering::Equal;
+
+    while i < b1.len() && j < b2.len() {
+        let (l, r) = (b1[i], b2[j]);
+        if !(l | r).is_ascii() {
+            return natural_lexical_cmp(s1, s2);
+        }
+        if l.is_ascii_digit() && r.is_ascii_digit() {
+            let run = |b: &[u8]| b.iter().take_while(|b| b.is_ascii_digit()).count();
+            let (end1, end2) = (i + run(&b1[i..]), j + run(&b2[j..]));
+            // a number can continue with non-ASCII digits
+            let is_ascii = |b: &[u8], end: usize| b.get(end).copied().unwrap_or(0).is_ascii();
+            if !(is_ascii(b1, end1) && is_ascii(b2, end2)) {
+                return natural_lexical_cmp(s1, s2);
+            }
+            match cmp_digit_bytes(&b1[i..end1], &b2[j..end2]) {
+                (Ordering::Equal, leading_zeros) => zeros = zeros.then(leading_zeros),
+                (ordering, _) => return ordering,
+            }
+            i = end1;
+            j = end2;
+        } else {
+            let (l, r) = (l.to_ascii_lowercase(), r.to_ascii_lowercase());
+            if l != r {
+                return cmp_ascii_bytes(l, r);
+            }
+            i += 1;
+            j += 1;
+        }
+    }
+
+    if !(b1[i..].is_ascii() && b2[j..].is_ascii()) {
+        return natural_lexical_cmp(s1, s2);
+    }
+    (b1.len() - i)
+        .cmp(&(b2.len() - j))
+        .then(zeros)
+        .then_with(|| b1.cmp(b2))
+}
+
+/// Compares two different lowercase ASCII bytes like `ret_ordering`
+#[inline]
+fn cmp_ascii_bytes(l: u8, r: u8) -> Ordering {
+    let (rank1, rank2) = (l.is_ascii_alphanumeric(), r.is_ascii_alphanumeric());
+    rank1.cmp(&rank2).then(l.cmp(&r))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_test(desc: &'static str, algo: impl Fn(&str, &str) -> Ordering) -> impl Fn(&str, &str) {
+        move |lhs, rhs| {
+            let success = algo(lhs, rhs) == Ordering::Less;
+            assert!(success, "{} comparison {:?} < {:?} failed", desc, lhs, rhs);
+
+            let success = algo(rhs, lhs) == Ordering::Greater;
+            assert!(success, "{} comparison {:?} > {:?} failed", desc, rhs, lhs);
+        }
+    }
+
+    #[test]
+    fn test_cmp() {
+        let ordered = make_test("Cmp", cmp);
+
+        ordered("aaa", "aaaa");
+        ordered("aaa", "aab");
+        ordered("AAb", "aaa");
+        ordered("aab", "äáa");
+        ordered("aaa", "äáb");
+
+        ordered("T-20", "T-5");
+        ordered("T-5", "Ŧ-5");
+    }
+
+    #[test]
+    fn test_only_alnum() {
+        let ordered = make_test("Only-alnum", only_alnum_cmp);
+
+        ordered("aaa", "aaaa");
+        ordered("aaa", "aab");
+        ordered("AAb", "aaa");
+        ordered("aab", "äáa");
+        ordered("aaa", "äáb");
+
+        ordered("_ad", "_æ");
+        ordered("_ae", "_æ");
+        ordered("_ae_", "_æ");
+        ordered("_af", "_æ");
+
+        ordered("T-20", "T-5");
+        ordered("T-5", "Ŧ-5");
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_lexical() {
+        let ordered = make_test("Lexical", lexical_cmp);
 
-    #[test]
-    fn test_lexical() {
-        let ordered = make_test("Lexical", lexical_cmp);
-
         ordered("aaa", "aaaa");
         ordered("aaa", "aab");
         ordered("aaa", "AAb");
@@ -293,6 +1770,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "unicode")]
     fn test_lexical_only_alnum() {
         let ordered = make_test("Lexical, only-alnum", lexical_only_alnum_cmp);
 
@@ -302,14 +1780,71 @@ mod tests {
         ordered("äáa", "aab");
         ordered("aaa", "äáb");
 
-        ordered("_ad", "_æ");
-        ordered("_ae", "_æ");
-        ordered("_ae_", "_æ");
-        ordered("_æ", "_af");
+        ordered("_ad", "_æ");
+        ordered("_ae", "_æ");
+        ordered("_æ", "_ae_");
+        ordered("_æ", "_af");
+
+        ordered("T20", "T-21");
+        ordered("T-21", "T22");
+        ordered("T-21", "T3");
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_lexical_ignore_whitespace() {
+        let ordered = make_test("Lexical, ignore whitespace", lexical_ignore_whitespace_cmp);
+
+        ordered("Star  Wars", "Star Wars");
+        ordered("Star Wars", "StarWars");
+        ordered("StarWars", "Star Warz");
+        ordered("Star-Wars", "Star Wars");
+        ordered("Star Wars", "Star Wars 2");
+        ordered("aaa", "a a b");
+        ordered("ä a", "aab");
+
+        for s in &["a\u{A0}b", "a\tb", "a \u{3000}b", "\ta b\n", "\u{2003}ab"] {
+            assert_eq!(lexical_ignore_whitespace_cmp(s, "aa"), Ordering::Greater);
+            assert_eq!(lexical_ignore_whitespace_cmp(s, "aba"), Ordering::Less);
+            let ordering = lexical_ignore_whitespace_cmp(s, "ab");
+            assert_eq!(ordering, lexical_cmp(s, "ab"));
+            assert_ne!(ordering, Ordering::Equal);
+        }
+        ordered("a\tb", "a b");
+        ordered(" ", "  ");
+        ordered("  ", "a");
+    }
+
+    #[test]
+    fn test_natural_lexical_ignore_whitespace() {
+        let ordered = make_test(
+            "Natural lexical, ignore whitespace",
+            natural_lexical_ignore_whitespace_cmp,
+        );
+
+        ordered("Star Wars 2", "StarWars 10");
+        ordered("Star Wars", "StarWars");
+        ordered("Star Wars 9", "Star Wars 10");
+        ordered("Star-Wars", "Star Wars");
+        ordered("999", "1 000");
+        ordered("1 000", "1000");
+        ordered("1\u{A0}000", "1001");
+        ordered("a\t01", "a 1");
+        ordered("a 1", "a\t2");
 
-        ordered("T20", "T-21");
-        ordered("T-21", "T22");
-        ordered("T-21", "T3");
+        for s in &["a\u{A0}2", "a\t2", "a \u{3000}2", "\ta 2\n", "\u{2003}a2"] {
+            assert_eq!(
+                natural_lexical_ignore_whitespace_cmp(s, "a1"),
+                Ordering::Greater
+            );
+            assert_eq!(
+                natural_lexical_ignore_whitespace_cmp(s, "a10"),
+                Ordering::Less
+            );
+            let ordering = natural_lexical_ignore_whitespace_cmp(s, "a2");
+            assert_eq!(ordering, natural_lexical_cmp(s, "a2"));
+            assert_ne!(ordering, Ordering::Equal);
+        }
     }
 
     #[test]
@@ -331,6 +1866,449 @@ mod tests {
         ordered("T-5", "Ŧ-5");
     }
 
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_natural_unicode_digits() {
+        fn check(ordered: impl Fn(&str, &str)) {
+            ordered("٣", "١٢");
+            ordered("file ٣", "file ١٢");
+            ordered("३", "१२");
+            ordered("３", "１２");
+            ordered("x３y", "x１２y");
+
+            // digits of different scripts in the same number
+            ordered("3", "1٢");
+            ordered("1٢", "13");
+            ordered("１2", "13");
+            ordered("ファイル９", "ファイル１0");
+            ordered("ファイル1０", "ファイル１１");
+            ordered("ファイル１０", "ファイル１1");
+
+            // equal numbers are ordered by the other characters first
+            ordered("١٢a", "12b");
+            ordered("12", "١٢");
+        }
+        check(make_test("Natural", natural_cmp));
+        check(make_test("Natural, only-alnum", natural_only_alnum_cmp));
+        check(make_test("Natural, lexical", natural_lexical_cmp));
+        check(make_test(
+            "Natural, lexical, only-alnum",
+            natural_lexical_only_alnum_cmp,
+        ));
+    }
+
+    #[test]
+    fn test_enclosed_alphanumerics() {
+        let sorted = [
+            "1intro",
+            "①intro",
+            "⑴intro",
+            "⒈intro",
+            "2 setup",
+            "⑵ setup",
+            "9 usage",
+            "⑨ usage",
+            "10 faq",
+            "⑩ faq",
+            "⑽ faq",
+            "a",
+            "Ⓐ",
+            "ⓐ",
+            "🅰",
+            "b",
+            "⒝",
+        ];
+        for (i, lhs) in sorted.iter().enumerate() {
+            for (j, rhs) in sorted.iter().enumerate() {
+                let ordering = natural_lexical_cmp(lhs, rhs);
+                assert_eq!(ordering, i.cmp(&j), "{:?} {:?}", lhs, rhs);
+            }
+        }
+
+        // an enclosed number is a number of its own
+        let ordered = make_test("Natural, lexical", natural_lexical_cmp);
+        ordered("⑩", "⑪");
+        ordered("⑴", "⑽");
+        ordered("⑨0", "⑩");
+        ordered("item ⑵", "item 10");
+        ordered("⑴", "⒜");
+
+        let ordered = make_test("Lexical", lexical_cmp);
+        ordered("⑩", "2");
+        ordered("①intro", "1outro");
+        ordered("Ⓐpple", "banana");
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_eq() {
+        assert!(lexical_eq("Foo", "fóò"));
+        assert!(lexical_eq("Æther", "aether"));
+        assert!(lexical_eq("", ""));
+        assert!(!lexical_eq("foo", "foo "));
+        assert!(!lexical_eq("foo-bar", "foobar"));
+        assert!(!lexical_eq("x01", "x1"));
+
+        assert!(lexical_only_alnum_eq("Foo-Bar", "foobar"));
+        assert!(lexical_only_alnum_eq("Ça va?", "ca va"));
+        assert!(!lexical_only_alnum_eq("foo1", "foo2"));
+
+        assert!(natural_lexical_eq("x01", "x1"));
+        assert!(natural_lexical_eq("File 007", "file 7"));
+        assert!(natural_lexical_eq("x١٢", "X12"));
+        assert!(!natural_lexical_eq("x01", "x10"));
+        assert!(!natural_lexical_eq("x 1", "x1"));
+        assert!(natural_lexical_only_alnum_eq("File-01", "file 1"));
+        assert!(!natural_lexical_only_alnum_eq("File-01", "file 1a"));
+
+        assert!(natural_eq("x01", "x1"));
+        assert!(!natural_eq("X01", "x1"));
+        assert!(natural_only_alnum_eq("x-01", "x1"));
+        assert!(!natural_only_alnum_eq("x-01", "x1 y"));
+
+        assert!(only_alnum_eq("a-b", "ab"));
+        assert!(!only_alnum_eq("a-b", "aB"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", feature = "unicode"))]
+    fn test_hash() {
+        use std::collections::hash_map::DefaultHasher;
+
+        fn hash(f: fn(&str, &mut DefaultHasher), s: &str) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            f(s, &mut hasher);
+            hasher.finish()
+        }
+
+        assert_eq!(hash(lexical_hash, "Foo"), hash(lexical_hash, "fóò"));
+        assert_eq!(hash(lexical_hash, "Straße"), hash(lexical_hash, "STRASSE"));
+        assert_ne!(hash(lexical_hash, "foo"), hash(lexical_hash, "foo "));
+        assert_ne!(hash(lexical_hash, "x01"), hash(lexical_hash, "x1"));
+        assert_eq!(
+            hash(lexical_only_alnum_hash, "Foo-Bar"),
+            hash(lexical_only_alnum_hash, "foobar")
+        );
+
+        let f = natural_lexical_hash;
+        assert_eq!(hash(f, "x01"), hash(f, "x1"));
+        assert_eq!(hash(f, "x0"), hash(f, "x000"));
+        assert_eq!(hash(f, "x١٢"), hash(f, "X12"));
+        assert_eq!(hash(f, "chapter ⑩"), hash(f, "Chapter 10"));
+        assert_eq!(hash(f, "x↉"), hash(f, "x0"));
+        assert_ne!(hash(f, "x½"), hash(f, "x1"));
+        assert_ne!(hash(f, "x10"), hash(f, "x1"));
+        assert_eq!(
+            hash(natural_lexical_only_alnum_hash, "File-01"),
+            hash(natural_lexical_only_alnum_hash, "file 1")
+        );
+
+        assert_eq!(hash(natural_hash, "x01"), hash(natural_hash, "x1"));
+        assert_ne!(hash(natural_hash, "X1"), hash(natural_hash, "x1"));
+        assert_eq!(
+            hash(natural_only_alnum_hash, "x-01"),
+            hash(natural_only_alnum_hash, "x1")
+        );
+        assert_eq!(hash(only_alnum_hash, "a-b"), hash(only_alnum_hash, "ab"));
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_shortlex() {
+        let sorted = [
+            "", "\u{301}", "A", "a", "e\u{301}", "é", "z", "æ", "ss", "ß", "st", "aaa", "ßa", "ßß",
+        ];
+        for (i, lhs) in sorted.iter().enumerate() {
+            for (j, rhs) in sorted.iter().enumerate() {
+                assert_eq!(
+                    shortlex_lexical_cmp(lhs, rhs),
+                    i.cmp(&j),
+                    "{:?} {:?}",
+                    lhs,
+                    rhs
+                );
+            }
+        }
+
+        // byte lengths disagree with lexical lengths
+        assert_eq!(shortlex_lexical_cmp("ß", "abc"), Ordering::Less);
+        assert_eq!(shortlex_lexical_cmp("ß", "a"), Ordering::Greater);
+        assert_eq!(
+            shortlex_lexical_cmp("e\u{301}\u{302}", "ab"),
+            Ordering::Less
+        );
+        assert_eq!(shortlex_lexical_cmp("ǆ", "dzz"), Ordering::Less);
+
+        assert_eq!(shortlex_natural_lexical_cmp("x10", "x9"), Ordering::Greater);
+        assert_eq!(shortlex_natural_lexical_cmp("x10", "y9"), Ordering::Greater);
+        assert_eq!(shortlex_natural_lexical_cmp("x010", "x100"), Ordering::Less);
+        assert_eq!(shortlex_natural_lexical_cmp("T-5", "t-5"), Ordering::Less);
+        assert_eq!(shortlex_lexical_cmp("x010", "x100"), Ordering::Less);
+        assert_eq!(shortlex_natural_lexical_cmp("x20", "x100"), Ordering::Less);
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_cmp_prefix() {
+        // the first difference is exactly at position `n`
+        assert_eq!(lexical_cmp_prefix("abcx", "abcy", 3), Ordering::Equal);
+        assert_eq!(lexical_cmp_prefix("abcx", "abcy", 4), Ordering::Less);
+        assert_eq!(lexical_cmp_prefix("abc", "abcd", 3), Ordering::Equal);
+        assert_eq!(lexical_cmp_prefix("abc", "abcd", 4), Ordering::Less);
+        assert_eq!(lexical_cmp_prefix("Abc", "abc", 10), Ordering::Equal);
+        assert_eq!(lexical_cmp_prefix("b", "a", 0), Ordering::Equal);
+        assert_eq!(lexical_cmp_prefix("", "", 1), Ordering::Equal);
+
+        // expansions count as several characters
+        assert_eq!(lexical_cmp_prefix("ß", "sa", 1), Ordering::Equal);
+        assert_eq!(lexical_cmp_prefix("ß", "sa", 2), Ordering::Greater);
+        assert_eq!(lexical_cmp_prefix("aß", "asa", 2), Ordering::Equal);
+        assert_eq!(lexical_cmp_prefix("aß", "asa", 3), Ordering::Greater);
+        assert_eq!(lexical_cmp_prefix("aßt", "asst", 2), Ordering::Equal);
+        assert_eq!(lexical_cmp_prefix("Æx", "aey", 1), Ordering::Equal);
+        assert_eq!(lexical_cmp_prefix("ßß", "sßs", 1), Ordering::Equal);
+        assert_eq!(lexical_cmp_prefix("ßßa", "sßsb", 1), Ordering::Equal);
+        assert_eq!(lexical_cmp_prefix("ßßa", "sßsb", 5), Ordering::Less);
+
+        // numbers are compared completely in natural comparisons
+        let cmp_prefix = natural_lexical_cmp_prefix;
+        assert_eq!(cmp_prefix("x100", "x20", 2), Ordering::Greater);
+        assert_eq!(cmp_prefix("x100", "x20", 1), Ordering::Equal);
+        assert_eq!(lexical_cmp_prefix("x100", "x20", 2), Ordering::Less);
+        assert_eq!(cmp_prefix("x10a", "x010b", 2), Ordering::Equal);
+        assert_eq!(cmp_prefix("x10a", "x010b", 3), Ordering::Less);
+        assert_eq!(cmp_prefix("Ä1", "a1", 2), Ordering::Equal);
+        assert_eq!(natural_cmp_prefix("Ä1", "a1", 2), Ordering::Greater);
+        assert_eq!(natural_cmp_prefix("a10b", "a9c", 2), Ordering::Greater);
+        assert_eq!(natural_cmp_prefix("a10b", "a10c", 2), Ordering::Equal);
+
+        // the prefixes are determined independently, so the order is transitive
+        for &cmp_prefix in &[lexical_cmp_prefix, natural_lexical_cmp_prefix] {
+            assert_eq!(cmp_prefix("s", "SS", 1), Ordering::Equal);
+            assert_eq!(cmp_prefix("SS", "ß", 1), Ordering::Equal);
+            assert_eq!(cmp_prefix("s", "ß", 1), Ordering::Equal);
+            assert_eq!(cmp_prefix("s", "SS", 2), Ordering::Less);
+            assert_eq!(cmp_prefix("SS", "ß", 2), Ordering::Equal);
+            assert_eq!(cmp_prefix("s", "ß", 2), Ordering::Less);
+        }
+
+        // consistent with the comparison functions
+        let strings = [
+            "", "a", "A", "ä", "aa", "ß", "ss", "SS", "s", "sß", "sz", "æ", "ae", "aex", "T-5",
+            "t-20", "T-05", "½", "x1¹", "10",
+        ];
+        let functions: [(PrefixFn, CmpFn); 3] = [
+            (lexical_cmp_prefix, lexical_cmp),
+            (natural_lexical_cmp_prefix, natural_lexical_cmp),
+            (natural_cmp_prefix, natural_cmp),
+        ];
+        for &(cmp_prefix, cmp) in &functions {
+            for lhs in &strings {
+                for rhs in &strings {
+                    for n in 0..5 {
+                        let ordering = cmp_prefix(lhs, rhs, n);
+                        if ordering != Ordering::Equal {
+                            assert_eq!(ordering, cmp(lhs, rhs), "{:?} {:?} {}", lhs, rhs, n);
+                        }
+                        for other in &strings {
+                            if cmp_prefix(rhs, other, n) == ordering {
+                                let msg = (lhs, rhs, other, n);
+                                assert_eq!(cmp_prefix(lhs, other, n), ordering, "{:?}", msg);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_lexical_char_cmp() {
+        assert_eq!(lexical_char_cmp('a', 'b'), Ordering::Less);
+        assert_eq!(lexical_char_cmp('A', 'b'), Ordering::Less);
+        assert_eq!(lexical_char_cmp('a', 'B'), Ordering::Less);
+        assert_eq!(lexical_char_cmp('z', '0'), Ordering::Greater);
+        assert_eq!(lexical_char_cmp('~', '0'), Ordering::Less);
+        assert_eq!(lexical_char_cmp('\u{2019}', '\''), Ordering::Equal);
+        assert_eq!(lexical_char_cmp('ß', 's'), Ordering::Greater);
+        assert_eq!(lexical_char_cmp('ß', 't'), Ordering::Less);
+        assert_eq!(lexical_char_cmp('\u{300}', 'a'), Ordering::Less);
+        assert_eq!(lexical_char_cmp('\u{300}', '\u{301}'), Ordering::Equal);
+        assert!(lexical_char_key('Æ').eq(['a', 'e']));
+
+        // consistent with `lexical_cmp`, except for its tie-break
+        let chars = [
+            'a', 'A', 'ä', 'æ', 'b', 'ß', 's', '0', '½', '-', ' ', '~', '→', '\u{300}', 'Ω', '中',
+        ];
+        let mut buf1 = [0; 4];
+        let mut buf2 = [0; 4];
+        for &l in &chars {
+            for &r in &chars {
+                let ordering = lexical_char_cmp(l, r);
+                let (s1, s2) = (l.encode_utf8(&mut buf1), r.encode_utf8(&mut buf2));
+                if ordering != Ordering::Equal {
+                    assert_eq!(ordering, lexical_cmp(s1, s2), "{:?} {:?}", l, r);
+                } else {
+                    assert!(lexical_eq(s1, s2), "{:?} {:?}", l, r);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_natural_huge_numbers() {
+        let ordered = make_test("Natural", natural_cmp);
+
+        ordered("18446744073709551615", "18446744073709551616");
+        ordered("99999999999999999999", "100000000000000000000");
+        ordered(
+            "x123456789012345678901234567890",
+            "x123456789012345678901234567891",
+        );
+        ordered(
+            "x000000000000000000000000000001",
+            "x1000000000000000000000000000000",
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_natural_long_digit_runs() {
+        let nines = "9".repeat(1 << 20);
+        let zeros = "0".repeat(1 << 20);
+        let long = format!("x{}1", nines);
+        let longer = format!("x1{}0", zeros);
+        let padded = format!("x{}{}1", zeros, nines);
+
+        let natural: [fn(&str, &str) -> Ordering; 4] = [
+            natural_cmp,
+            natural_only_alnum_cmp,
+            natural_lexical_cmp,
+            natural_lexical_only_alnum_cmp,
+        ];
+        for cmp in natural.iter() {
+            assert_eq!(cmp(&long, &longer), Ordering::Less);
+            assert_eq!(cmp(&longer, &long), Ordering::Greater);
+            assert_eq!(cmp(&long, &padded), Ordering::Less);
+            assert_eq!(cmp(&padded, &format!("{}a", long)), Ordering::Less);
+            assert_eq!(
+                cmp(&format!("{}b", padded), &format!("{}a", long)),
+                Ordering::Greater
+            );
+        }
+
+        // runs that continue with non-ASCII digits or after skipped characters
+        assert_eq!(natural_cmp("1٢", "13"), Ordering::Less);
+        assert_eq!(natural_cmp("13", "1٢"), Ordering::Greater);
+        assert_eq!(natural_cmp("12", "1٢"), Ordering::Less);
+        assert_eq!(natural_only_alnum_cmp("1-3", "12"), Ordering::Greater);
+        assert_eq!(natural_only_alnum_cmp("12", "1-3"), Ordering::Less);
+        assert_eq!(natural_lexical_cmp("1²", "12"), Ordering::Less);
+        assert_eq!(natural_lexical_cmp("01", "1"), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_cmp_digit_runs() {
+        assert_eq!(cmp_digit_runs("", ""), (Ordering::Equal, 0, 0));
+        assert_eq!(cmp_digit_runs("a", "b"), (Ordering::Equal, 0, 0));
+        assert_eq!(cmp_digit_runs("", "0"), (Ordering::Less, 0, 1));
+        assert_eq!(cmp_digit_runs("12a", "x"), (Ordering::Greater, 2, 0));
+        assert_eq!(cmp_digit_runs("2", "10"), (Ordering::Less, 1, 2));
+        assert_eq!(cmp_digit_runs("20", "19"), (Ordering::Greater, 2, 2));
+        assert_eq!(cmp_digit_runs("0", "000"), (Ordering::Equal, 1, 3));
+        assert_eq!(cmp_digit_runs("0010", "9"), (Ordering::Greater, 4, 1));
+        assert_eq!(cmp_digit_runs("1.5", "1.4"), (Ordering::Equal, 1, 1));
+        assert_eq!(cmp_digit_runs("1²", "12"), (Ordering::Less, 1, 2));
+        assert_eq!(cmp_digit_runs("١٢", "1"), (Ordering::Less, 0, 1));
+
+        // huge numbers don't overflow
+        let huge = |lhs, rhs| cmp_digit_runs(lhs, rhs).0;
+        assert_eq!(
+            huge("18446744073709551615", "18446744073709551616"),
+            Ordering::Less
+        );
+        assert_eq!(
+            huge("99999999999999999999", "100000000000000000000"),
+            Ordering::Less
+        );
+        assert_eq!(
+            huge(
+                "123456789012345678901234567891x",
+                "123456789012345678901234567890y"
+            ),
+            Ordering::Greater
+        );
+        assert_eq!(
+            huge(
+                "000000000000000000000000000001",
+                "1000000000000000000000000000000"
+            ),
+            Ordering::Less
+        );
+        assert_eq!(
+            huge(
+                "0000000000000000000000000000018446744073709551616",
+                "18446744073709551616"
+            ),
+            Ordering::Equal
+        );
+
+        // only the runs are consumed from the iterators
+        let mut iter1 = "0042-x".chars().peekable();
+        let mut iter2 = "42".chars().peekable();
+        assert_eq!(cmp_digit_runs_iter(&mut iter1, &mut iter2), Ordering::Equal);
+        assert!(iter1.eq("-x".chars()));
+        assert_eq!(iter2.next(), None);
+
+        let mut iter1 = "123".chars().peekable();
+        let mut iter2 = "abc".chars().peekable();
+        assert_eq!(
+            cmp_digit_runs_iter(&mut iter1, &mut iter2),
+            Ordering::Greater
+        );
+        assert_eq!(iter1.next(), None);
+        assert_eq!(iter2.next(), Some('a'));
+        assert_eq!(cmp_digit_runs_iter(&mut iter2, &mut iter1), Ordering::Equal);
+        assert_eq!(iter2.next(), Some('b'));
+
+        let mut iter1 = "9 10".chars().peekable();
+        let mut iter2 = "9 9".chars().peekable();
+        assert_eq!(cmp_digit_runs_iter(&mut iter1, &mut iter2), Ordering::Equal);
+        assert_eq!((iter1.next(), iter2.next()), (Some(' '), Some(' ')));
+        assert_eq!(
+            cmp_digit_runs_iter(&mut iter1, &mut iter2),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn test_natural_leading_zeros() {
+        fn check(ordered: impl Fn(&str, &str)) {
+            ordered("01", "2");
+            ordered("B-007", "B-7a");
+            ordered("999", "0001000");
+            ordered("x09", "x10");
+            ordered("0", "1");
+            ordered("00", "1");
+
+            // leading zeros only break ties
+            ordered("7", "07");
+            ordered("07", "007");
+            ordered("x7", "x07");
+            ordered("x7y", "x07y");
+            ordered("a7b01", "a07b1");
+            ordered("0", "00");
+        }
+        check(make_test("Natural", natural_cmp));
+        check(make_test("Natural, only-alnum", natural_only_alnum_cmp));
+        check(make_test("Natural, lexical", natural_lexical_cmp));
+        check(make_test(
+            "Natural, lexical, only-alnum",
+            natural_lexical_only_alnum_cmp,
+        ));
+    }
+
     #[test]
     fn test_natural_only_alnum() {
         let ordered = make_test("Natural, only-alnum", natural_only_alnum_cmp);
@@ -352,6 +2330,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "unicode")]
     fn test_natural_lexical() {
         let ordered = make_test("Natural, lexical", natural_lexical_cmp);
 
@@ -371,6 +2350,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "unicode")]
     fn test_natural_lexical_only_alnum() {
         let ordered = make_test(
             "Natural, lexical, only-alnum",
@@ -391,4 +2371,790 @@ mod tests {
         ordered("T-5", "Ŧ-27");
         ordered("T-5", "Ŧ-5");
     }
+
+    #[test]
+    fn test_const_ascii_lexical() {
+        let strings = [
+            "", " ", "!", "-", "-$", "-a", "0", "100", "50", "A", "a", "aa", "AB", "Ab", "ab",
+            "a b", "a-b", "a_b", "ab!", "B", "b", "Z", "z", "zz", "~",
+        ];
+        for &lhs in &strings {
+            for &rhs in &strings {
+                assert_eq!(
+                    const_ascii_lexical_cmp(lhs, rhs),
+                    lexical_cmp(lhs, rhs),
+                    "comparison of {:?} and {:?} differs",
+                    lhs,
+                    rhs,
+                );
+            }
+        }
+
+        const SORTED: [&str; 7] = ["-", "1", "a", "B", "b", "ba", "c"];
+        const _: () = assert!(is_sorted_ascii(&SORTED));
+        const _: () = assert!(!is_sorted_ascii(&["b", "a"]));
+        const CMP: Ordering = const_ascii_lexical_cmp("A", "a");
+        assert_eq!(CMP, Ordering::Less);
+        assert!(is_sorted_ascii(&SORTED));
+        assert!(is_sorted_ascii(&[]));
+    }
+
+    #[test]
+    fn test_ascii_lexical() {
+        let strings = [
+            "",
+            " ",
+            "\0",
+            "\t",
+            "!",
+            "-",
+            "-$",
+            "-a",
+            "0",
+            "00",
+            "007",
+            "01",
+            "1",
+            "1a",
+            "7",
+            "100",
+            "50",
+            "A",
+            "a",
+            "aa",
+            "AB",
+            "Ab",
+            "ab",
+            "a b",
+            "a-b",
+            "a_b",
+            "ab!",
+            "B",
+            "b",
+            "Z",
+            "z",
+            "zz",
+            "~",
+            "x1",
+            "x01",
+            "X1",
+            "x1y",
+            "x01y",
+            "x9",
+            "x10",
+            "x010",
+            "x1-2",
+            "x1.5",
+            "T-5",
+            "t-20",
+            "\"usZj9;",
+            "\"uo%m",
+            "f Yu|u4",
+            "f Yu|us 0oZH\"0 (\"3a",
+            "G*rMiue",
+            "G*rMwN<f<5",
+            "/l>Wvr<QV |dv",
+            "/l>Wvr<QV oaR",
+            "k4bt\\JJnz]Ncp/Tef",
+            "k4Wna1.UnUA",
+            "55N ]j|o2P0#}H",
+            "62#lw{5o",
+            "01Sneh%KQ`|",
+            // non-ASCII strings fall back to the general functions
+            "ä",
+            "Ä",
+            "ae",
+            "ß",
+            "ss",
+            "a\u{301}",
+            "x²",
+            "x1²",
+            "x1٣",
+            "x13",
+            "x½",
+            "Ⅻ",
+        ];
+        for &lhs in &strings {
+            for &rhs in &strings {
+                assert_eq!(
+                    ascii_lexical_cmp(lhs, rhs),
+                    lexical_cmp(lhs, rhs),
+                    "comparison of {:?} and {:?} differs",
+                    lhs,
+                    rhs,
+                );
+                assert_eq!(
+                    ascii_natural_lexical_cmp(lhs, rhs),
+                    natural_lexical_cmp(lhs, rhs),
+                    "natural comparison of {:?} and {:?} differs",
+                    lhs,
+                    rhs,
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_wordwise() {
+        let sorted = [
+            "",
+            "New York",
+            " new  york ",
+            "New York City",
+            "New\u{3000}York\tCity",
+            "New-York",
+            "Newark",
+            "Newark Liberty",
+            "Newport",
+        ];
+        for (i, lhs) in sorted.iter().enumerate() {
+            for (j, rhs) in sorted.iter().enumerate() {
+                assert_eq!(
+                    wordwise_lexical_cmp(lhs, rhs),
+                    i.cmp(&j),
+                    "{:?} {:?}",
+                    lhs,
+                    rhs
+                );
+            }
+        }
+
+        // word by word vs. character by character
+        assert_eq!(wordwise_lexical_cmp("Ab  Cd", "Ab Bd"), Ordering::Greater);
+        assert_eq!(lexical_cmp("Ab  Cd", "Ab Bd"), Ordering::Less);
+        assert_eq!(wordwise_lexical_cmp("a\tz", "a y"), Ordering::Greater);
+        assert_eq!(lexical_cmp("a\tz", "a y"), Ordering::Less);
+        assert_eq!(wordwise_lexical_cmp(" b", "a"), Ordering::Greater);
+        assert_eq!(lexical_cmp(" b", "a"), Ordering::Less);
+
+        // the tie-break of a word doesn't outweigh a later word
+        assert_eq!(wordwise_lexical_cmp("Foo b", "foo a"), Ordering::Greater);
+        assert_eq!(wordwise_lexical_cmp("foo a", "Foo b"), Ordering::Less);
+        assert_eq!(
+            wordwise_lexical_cmp("Foo a", "foo a"),
+            lexical_cmp("Foo", "foo")
+        );
+        assert_eq!(
+            wordwise_natural_lexical_cmp("x01 b", "x1 a"),
+            Ordering::Greater
+        );
+
+        // whitespace only
+        assert_eq!(wordwise_lexical_cmp("", "  "), Ordering::Less);
+        assert_eq!(wordwise_lexical_cmp("  ", "\t"), Ordering::Greater);
+        assert_eq!(wordwise_lexical_cmp("\n", "a"), Ordering::Less);
+
+        assert_eq!(
+            wordwise_natural_lexical_cmp("Track 10 b", "Track 9 a"),
+            Ordering::Greater
+        );
+        assert_eq!(
+            wordwise_natural_lexical_cmp("Track 9  b", "Track 10 a"),
+            Ordering::Less
+        );
+        assert_eq!(
+            wordwise_lexical_cmp("Track 9 b", "Track 10 a"),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn test_multiline() {
+        let cmp = |lhs, rhs| multiline_cmp(lhs, rhs, SortMode::NaturalLexical);
+
+        // differing only after the first newline
+        assert_eq!(cmp("title\nB", "title\na"), Ordering::Greater);
+        assert_eq!(cmp("title\nitem 10", "title\nitem 9"), Ordering::Greater);
+        assert_eq!(cmp("title\r\nb", "title\na"), Ordering::Greater);
+        // the first line decides, unlike with a plain comparison where "\t" < "\n"
+        assert_eq!(cmp("a\tb\nz", "a\nzz"), Ordering::Greater);
+        assert_eq!(natural_lexical_cmp("a\tb\nz", "a\nzz"), Ordering::Less);
+
+        // different line counts
+        assert_eq!(cmp("a\nb", "a\nb\nc"), Ordering::Less);
+        assert_eq!(cmp("a\nb\n\n", "a\nb"), Ordering::Greater);
+        assert_eq!(cmp("", "\n"), Ordering::Less);
+
+        // same lines
+        assert_eq!(cmp("a\nb", "a\nb\n"), Ordering::Less);
+        assert_eq!(cmp("a\nb", "a\r\nb"), Ordering::Less);
+        assert_eq!(cmp("a\nb", "a\nb"), Ordering::Equal);
+
+        // lines are compared with the function of the mode
+        assert_eq!(
+            multiline_cmp("a\nB", "a\nb", SortMode::Plain),
+            Ordering::Less
+        );
+        assert_eq!(
+            multiline_cmp("a\n-b", "a\nc", SortMode::LexicalOnlyAlnum),
+            Ordering::Less
+        );
+        assert_eq!(
+            multiline_cmp("a\n-b", "a\nc", SortMode::Plain),
+            Ordering::Less
+        );
+        assert_eq!(
+            multiline_cmp("a\nd", "a\n-c", SortMode::OnlyAlnum),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn test_cmp_by_reading() {
+        let cmp = |lhs, rhs| cmp_by_reading(lhs, rhs, lexical_cmp);
+
+        // readings decide when both are present
+        assert_eq!(cmp(("b", Some("a")), ("a", Some("b"))), Ordering::Less);
+        // an entry without reading is compared by its display string
+        assert_eq!(cmp(("c", None), ("a", Some("b"))), Ordering::Greater);
+        assert_eq!(cmp(("a", Some("c")), ("b", None)), Ordering::Greater);
+        assert_eq!(cmp(("a", None), ("b", None)), Ordering::Less);
+        // ties are broken by the display string
+        assert_eq!(cmp(("b", Some("x")), ("a", Some("x"))), Ordering::Greater);
+        assert_eq!(cmp(("x", None), ("y", Some("x"))), Ordering::Less);
+        assert_eq!(cmp(("x", Some("r")), ("x", Some("r"))), Ordering::Equal);
+    }
+
+    type CmpFn = fn(&str, &str) -> Ordering;
+    #[cfg(feature = "unicode")]
+    type PrefixFn = fn(&str, &str, usize) -> Ordering;
+    #[cfg(feature = "std")]
+    type EqFn = fn(&str, &str) -> bool;
+    #[cfg(feature = "std")]
+    type HashFn = fn(&str, &mut std::collections::hash_map::DefaultHasher);
+
+    const ALL: [(&str, CmpFn); 8] = [
+        ("Cmp", cmp),
+        ("Only-alnum", only_alnum_cmp),
+        ("Lexical", lexical_cmp),
+        ("Lexical, only-alnum", lexical_only_alnum_cmp),
+        ("Natural", natural_cmp),
+        ("Natural, only-alnum", natural_only_alnum_cmp),
+        ("Natural lexical", natural_lexical_cmp),
+        (
+            "Natural lexical, only-alnum",
+            natural_lexical_only_alnum_cmp,
+        ),
+    ];
+
+    /// The comparison functions with their equality and hash functions
+    #[cfg(feature = "std")]
+    const ALL_EQ: [(&str, CmpFn, EqFn, HashFn); 7] = [
+        ("Only-alnum", only_alnum_cmp, only_alnum_eq, only_alnum_hash),
+        ("Lexical", lexical_cmp, lexical_eq, lexical_hash),
+        (
+            "Lexical, only-alnum",
+            lexical_only_alnum_cmp,
+            lexical_only_alnum_eq,
+            lexical_only_alnum_hash,
+        ),
+        ("Natural", natural_cmp, natural_eq, natural_hash),
+        (
+            "Natural, only-alnum",
+            natural_only_alnum_cmp,
+            natural_only_alnum_eq,
+            natural_only_alnum_hash,
+        ),
+        (
+            "Natural lexical",
+            natural_lexical_cmp,
+            natural_lexical_eq,
+            natural_lexical_hash,
+        ),
+        (
+            "Natural lexical, only-alnum",
+            natural_lexical_only_alnum_cmp,
+            natural_lexical_only_alnum_eq,
+            natural_lexical_only_alnum_hash,
+        ),
+    ];
+
+    /// Checks that `algo` is a total order on `strings`, i.e. it is antisymmetric, transitive,
+    /// and only returns `Equal` for equal strings
+    fn assert_total_order(desc: &str, algo: CmpFn, strings: &[&str]) {
+        for a in strings {
+            for b in strings {
+                let ab = algo(a, b);
+                assert_eq!(ab, algo(b, a).reverse(), "{}: {:?} vs {:?}", desc, a, b);
+                assert_eq!(
+                    ab == Ordering::Equal,
+                    a == b,
+                    "{}: {:?} vs {:?}",
+                    desc,
+                    a,
+                    b
+                );
+                if ab != Ordering::Less {
+                    continue;
+                }
+                for c in strings {
+                    if algo(b, c) == Ordering::Less {
+                        let ac = algo(a, c);
+                        assert_eq!(
+                            ac,
+                            Ordering::Less,
+                            "{}: {:?} < {:?} < {:?}, but {:?} vs {:?} is {:?}",
+                            desc,
+                            a,
+                            b,
+                            c,
+                            a,
+                            c,
+                            ac
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_total_order() {
+        let strings = [
+            "", "b", "-c", "c", "-b", "b-", "a-B", "aB", "ab", "a b", "A-b", "ä", "-ä", "a", "ae",
+            "æ", "-æ", "ß", "ss", "s-s", "1", "01", "-1", "1-", "001", "10", "1-0", "9", "0", "½",
+            "1/2", "12", "1 2", "T-5", "T5", "T-05", "t5", "T-20", "T20", "x1y", "x01y", "x1-y",
+            "x-1y", ".", "-", "--", " ", "!a", "a!",
+        ];
+        for &(desc, algo) in ALL.iter() {
+            assert_total_order(desc, algo, &strings);
+        }
+        let ignore_whitespace: [(&str, CmpFn); 2] = [
+            ("Lexical, ignore whitespace", lexical_ignore_whitespace_cmp),
+            (
+                "Natural lexical, ignore whitespace",
+                natural_lexical_ignore_whitespace_cmp,
+            ),
+        ];
+        let whitespace = ["a\tb", "a\u{A0}b", "1 0", "1\t0", " 1", "\u{3000}", "\t"];
+        for &(desc, algo) in ignore_whitespace.iter() {
+            assert_total_order(desc, algo, &strings);
+            assert_total_order(desc, algo, &whitespace);
+        }
+    }
+
+    #[test]
+    fn test_total_order_adversarial() {
+        let strings = [
+            // combining marks
+            "a\u{300}",
+            "à",
+            "a",
+            "\u{300}",
+            "\u{300}a",
+            "a\u{300}\u{301}",
+            "e\u{301}",
+            "é",
+            // characters that are transliterated to several characters
+            "ß",
+            "ss",
+            "SS",
+            "sß",
+            "ßs",
+            "s-ß",
+            "st",
+            // strings that are empty after skipping non-alphanumeric characters
+            "",
+            " ",
+            "  ",
+            "-",
+            "!!",
+            "\u{300}\u{300}",
+            "🎉",
+            // huge digit runs
+            "99999999999999999999",
+            "100000000000000000000",
+            "18446744073709551615",
+            "18446744073709551616",
+            "x18446744073709551616y",
+            "x18446744073709551617",
+            "123456789012345678901234567890",
+            "123456789012345678901234567891",
+            // equal values with different padding
+            "7",
+            "07",
+            "007",
+            "0007a",
+            "a7",
+            "a07",
+            "a-07",
+            "a0-7",
+            "0",
+            "00",
+            "000000000000000000000000",
+            // other digits
+            "²",
+            "2",
+            "٣",
+            "3",
+        ];
+        for &(desc, algo) in ALL.iter() {
+            assert_total_order(desc, algo, &strings);
+        }
+    }
+
+    #[test]
+    fn test_blank_strings() {
+        let strings = ["a", "!!", " a", "-", "\u{300}", "", "  ", " ", "-a", "\t"];
+
+        let plain = ["", "\t", " ", "  ", " a", "!!", "-", "-a", "a", "\u{300}"];
+        let lexical = ["", "\u{300}", "\t", " ", "  ", " a", "!!", "-", "-a", "a"];
+        let only_alnum = ["", "\t", " ", "-", "\u{300}", "  ", "!!", " a", "-a", "a"];
+
+        let expected = [
+            plain, only_alnum, lexical, only_alnum, plain, only_alnum, lexical, only_alnum,
+        ];
+        for (&(desc, algo), expected) in ALL.iter().zip(expected.iter()) {
+            let mut sorted = strings;
+            sorted.sort_by(|a, b| algo(a, b));
+            assert_eq!(&sorted, expected, "{}", desc);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_replacement_character() {
+        let invalid: [&[u8]; 3] = [b"file\xFF", b"file\xFF\xFE", b"file\xFF\xFE\xFD"];
+        let invalid: Vec<String> = invalid
+            .iter()
+            .map(|&s| String::from_utf8_lossy(s).into_owned())
+            .collect();
+        assert_eq!(invalid[2], "file\u{FFFD}\u{FFFD}\u{FFFD}");
+
+        let ordered = make_test("Lexical", lexical_cmp);
+        for s in &invalid {
+            ordered("file", s);
+            ordered("file-", s);
+            ordered("file~", s);
+            ordered("file🎉", s);
+            ordered("filez", s);
+            ordered("fileß", s);
+            ordered("file北", s);
+        }
+        ordered(&invalid[0], &invalid[1]);
+        ordered(&invalid[1], &invalid[2]);
+        ordered("file\u{FFFD}a", "file\u{FFFD}\u{FFFD}");
+
+        let strings = [
+            "file\u{FFFD}",
+            "file-\u{FFFD}",
+            "file\u{FFFD}1",
+            "filea",
+            "file1\u{FFFD}",
+        ];
+        for &(desc, algo) in ALL.iter() {
+            assert_total_order(desc, algo, &strings);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_sharp_s() {
+        let expected = [
+            "s", "SS", "Ss", "ss", "ß", "ẞ", "SSa", "ssa", "ßa", "sss", "Sß", "sß", "ßs", "Ssx",
+            "ßx", "ßz", "sszz", "sz",
+        ];
+        let functions: [CmpFn; 4] = [
+            lexical_cmp,
+            lexical_only_alnum_cmp,
+            natural_lexical_cmp,
+            natural_lexical_only_alnum_cmp,
+        ];
+        for &algo in &functions {
+            let mut sorted = expected;
+            sorted.reverse();
+            sorted.sort_by(|a, b| algo(a, b));
+            assert_eq!(sorted, expected);
+        }
+
+        let ordered = make_test("Lexical", lexical_cmp);
+        ordered("ss", "ß");
+        ordered("ssx", "ßx");
+        ordered("Sss", "sß");
+        ordered("sßx", "ßsx");
+        ordered("Foo", "foo");
+        ordered("foo", "fóò");
+        ordered("FOO", "fóò");
+        ordered("fÓÒ", "fóò");
+    }
+
+    #[test]
+    fn test_scripts() {
+        // Latin, Greek, Cyrillic, Armenian
+        let expected = ["ANNA", "Anna", "anna", "Ánna", "Άννα", "Анна", "Աննա"];
+        let functions: [CmpFn; 4] = [
+            lexical_cmp,
+            lexical_only_alnum_cmp,
+            natural_lexical_cmp,
+            natural_lexical_only_alnum_cmp,
+        ];
+        for &algo in &functions {
+            let mut sorted = expected;
+            sorted.reverse();
+            sorted.sort_by(|a, b| algo(a, b));
+            assert_eq!(sorted, expected);
+        }
+
+        let ordered = make_test("Lexical", lexical_cmp);
+        ordered("Beijing", "北亰");
+        ordered("1 Άννα", "1 Анна");
+        ordered("北", "베이");
+    }
+
+    #[test]
+    fn test_only_alnum_fallback() {
+        // strings that are equal after skipping non-alphanumeric characters are compared
+        // with the corresponding function that doesn't skip them
+        assert_eq!(lexical_only_alnum_cmp("a-B", "ab"), Ordering::Less);
+        assert_eq!(lexical_only_alnum_cmp("a-B", "aB"), Ordering::Less);
+        assert_eq!(lexical_only_alnum_cmp("aB", "ab"), Ordering::Less);
+        assert_eq!(natural_lexical_only_alnum_cmp("T-5", "T5"), Ordering::Less);
+        assert_eq!(natural_only_alnum_cmp("x-1y", "x1-y"), Ordering::Less);
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_only_alnum_fractions() {
+        // in only-alnum mode, the `/` of the transliteration of `½` is skipped like a literal `/`
+        let strings = ["½", "1/2", "12", "1 2"];
+        for &lhs in &strings {
+            for &rhs in &strings {
+                assert!(iterate_lexical_only_alnum(lhs).eq(iterate_lexical_only_alnum(rhs)));
+            }
+        }
+
+        let mut sorted = strings;
+        sorted.sort_by(|a, b| lexical_only_alnum_cmp(a, b));
+        assert_eq!(sorted, ["1 2", "1/2", "½", "12"]);
+        // in natural comparisons, `½` is a single number between 0 and 1
+        sorted.sort_by(|a, b| natural_lexical_only_alnum_cmp(a, b));
+        assert_eq!(sorted, ["½", "1 2", "1/2", "12"]);
+
+        let ordered = make_test(
+            "Natural lexical, only-alnum",
+            natural_lexical_only_alnum_cmp,
+        );
+        ordered("½", "13");
+        ordered("½", "11");
+        ordered("½", "1/3");
+    }
+
+    #[test]
+    fn test_column_label_cmp() {
+        let ordered = make_test("Column label", column_label_cmp);
+
+        ordered("A", "B");
+        ordered("B", "Z");
+        ordered("Z", "AA");
+        ordered("AA", "AZ");
+        ordered("AZ", "BA");
+        ordered("ZZ", "AAA");
+        ordered("z", "AA");
+        ordered("AA", "aa");
+        ordered("ab", "AC");
+
+        // letter runs and digit runs
+        ordered("Sheet1!Z9", "Sheet1!AA3");
+        ordered("Sheet1!AA3", "Sheet1!AA10");
+        ordered("Sheet2!AA3", "Sheet10!A1");
+        ordered("A9", "A10");
+        ordered("Z10", "AA1");
+        ordered("A1", "A01");
+        ordered("A-1", "A1");
+
+        // letters are less than the characters between `Z` and `a`
+        ordered("Z", "_");
+        ordered("a", "_");
+        ordered("a", "Z");
+        ordered("z", "[");
+        ordered("A_1", "A`1");
+        ordered("A[", "AA");
+
+        assert_eq!(column_label_cmp("Sheet1!B2", "Sheet1!B2"), Ordering::Equal);
+
+        // the order is consistent
+        let strings = [
+            "",
+            "A",
+            "a",
+            "B",
+            "Z",
+            "AA",
+            "Aa",
+            "AZ",
+            "BA",
+            "ZZ",
+            "AAA",
+            "A1",
+            "A01",
+            "A10",
+            "A9",
+            "AA1",
+            "Z10",
+            "A-1",
+            "A!",
+            "!A",
+            "1A",
+            "Sheet1!Z9",
+            "Sheet1!AA3",
+            "_",
+            "a_",
+            "_A",
+            "[",
+            "A[",
+            "`",
+            "z`",
+            "Z_1",
+            "ß",
+            "Ä",
+        ];
+        let mut sorted = strings;
+        sorted.sort_by(|a, b| column_label_cmp(a, b));
+        for (i, lhs) in sorted.iter().enumerate() {
+            for (j, rhs) in sorted.iter().enumerate() {
+                assert_eq!(column_label_cmp(lhs, rhs), i.cmp(&j), "{} {}", lhs, rhs);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_natural_fractions() {
+        fn check(ordered: impl Fn(&str, &str)) {
+            ordered("Chapter 0", "Chapter ½");
+            ordered("Chapter ½", "Chapter 1");
+            ordered("Chapter 1", "Chapter 2");
+            ordered("Chapter 00", "Chapter ½");
+            ordered("⅓", "½");
+            ordered("½", "⅔");
+            ordered("⅛", "⅐");
+            ordered("↉", "⅒");
+            ordered("0", "↉");
+
+            // a fraction after a number is a number of its own
+            ordered("1", "1½");
+            ordered("1½", "2");
+            ordered("1½", "1⅔");
+            ordered("1½", "11");
+            ordered("½1", "½2");
+
+            // superscript digits aren't part of an adjacent number
+            ordered("x1", "x1¹");
+            ordered("x1¹", "x2");
+            ordered("x1¹", "x11");
+            ordered("x¹", "x¹¹");
+            ordered("x²", "x¹²");
+            ordered("x¹", "x2");
+        }
+        check(make_test("Natural lexical", natural_lexical_cmp));
+        check(make_test(
+            "Natural lexical, only-alnum",
+            natural_lexical_only_alnum_cmp,
+        ));
+    }
+
+    #[test]
+    fn test_natural_only_alnum_triples() {
+        let ordered = make_test("Natural, only-alnum", natural_only_alnum_cmp);
+
+        ordered("a-B", "aB");
+        ordered("aB", "ab");
+        ordered("a-B", "ab");
+        ordered("a-b", "ab");
+        ordered("aB", "a-b");
+        ordered("a-1-0", "a10");
+        ordered("a-10-", "a10");
+        ordered("a-9", "a10");
+    }
+
+    #[cfg(feature = "std")]
+    mod proptests {
+        use super::{ALL, ALL_EQ};
+        use core::cmp::Ordering;
+        use proptest::prelude::*;
+
+        proptest! {
+            #[test]
+            fn total_order(a in "[-aAbä1 0ß.]{0,6}", b in "[-aAbä1 0ß.]{0,6}", c in "[-aAbä1 0ß.]{0,6}") {
+                for &(desc, algo) in ALL.iter() {
+                    let ab = algo(&a, &b);
+                    prop_assert_eq!(ab, algo(&b, &a).reverse(), "{}", desc);
+                    prop_assert_eq!(ab == Ordering::Equal, a == b, "{}", desc);
+                    if ab != Ordering::Greater && algo(&b, &c) != Ordering::Greater {
+                        prop_assert_ne!(algo(&a, &c), Ordering::Greater, "{}", desc);
+                    }
+                }
+            }
+
+            #[test]
+            fn total_order_adversarial(
+                a in "([0-9]{1,25}|[-sSaäß\u{300}½ !])*",
+                b in "([0-9]{1,25}|[-sSaäß\u{300}½ !])*",
+                c in "([0-9]{1,25}|[-sSaäß\u{300}½ !])*",
+            ) {
+                for &(desc, algo) in ALL.iter() {
+                    let ab = algo(&a, &b);
+                    prop_assert_eq!(ab, algo(&b, &a).reverse(), "{}", desc);
+                    prop_assert_eq!(ab == Ordering::Equal, a == b, "{}", desc);
+                    if ab != Ordering::Greater && algo(&b, &c) != Ordering::Greater {
+                        prop_assert_ne!(algo(&a, &c), Ordering::Greater, "{}", desc);
+                    }
+                }
+            }
+
+            #[test]
+            fn eq_consistent_with_cmp(
+                a in "[-aAbä1 0ß.]{0,6}",
+                b in "[-aAbä1 0ß.]{0,6}",
+                c in "[-aAbä1 0ß.]{0,6}",
+            ) {
+                for &(desc, algo, eq, _) in ALL_EQ.iter() {
+                    prop_assert!(eq(&a, &a), "{}", desc);
+                    prop_assert_eq!(eq(&a, &b), eq(&b, &a), "{}", desc);
+                    // equal strings are only ordered by the tie-break, so they are in the
+                    // same position relative to all other strings
+                    if eq(&a, &b) && !eq(&a, &c) {
+                        prop_assert_eq!(algo(&a, &c), algo(&b, &c), "{}", desc);
+                    }
+                }
+            }
+
+            #[test]
+            fn hash_consistent_with_eq(
+                a in "([0-9]{1,4}|[-aAä ß½¹⑩↉])*",
+                b in "([0-9]{1,4}|[-aAä ß½¹⑩↉])*",
+            ) {
+                use std::collections::hash_map::DefaultHasher;
+                use std::hash::Hasher;
+
+                let hash = |f: fn(&str, &mut DefaultHasher), s: &str| {
+                    let mut hasher = DefaultHasher::new();
+                    f(s, &mut hasher);
+                    hasher.finish()
+                };
+                for &(desc, _, eq, hash_fn) in ALL_EQ.iter() {
+                    // numbers with and without leading zeros are equal in natural comparisons
+                    let padded = a.replace('1', "01");
+                    for other in &[&b, &padded] {
+                        if eq(&a, other) {
+                            prop_assert_eq!(hash(hash_fn, &a), hash(hash_fn, other), "{}", desc);
+                        }
+                    }
+                }
+            }
+
+            #[test]
+            fn shuffle_invariant(
+                strings in proptest::collection::vec("[-aAbB1 0]{0,4}", 0..12)
+                    .prop_flat_map(|v| (Just(v.clone()), Just(v).prop_shuffle()))
+            ) {
+                let (mut a, mut b) = strings;
+                for &(desc, algo) in ALL.iter() {
+                    a.sort_unstable_by(|l, r| algo(l, r));
+                    b.sort_unstable_by(|l, r| algo(l, r));
+                    prop_assert_eq!(&a, &b, "{}", desc);
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,6546 @@
+//! A configurable comparison function.
+
+use crate::category::{category_group, CategoryGroup};
+#[cfg(feature = "emoji-names")]
+use crate::cmp::ret_ordering_by_emoji_name;
+use crate::cmp::{
+    cmp_ascii_digits, cmp_blank, cmp_digit_bytes, ret_ordering, ret_ordering_by_category,
+    NumberChar,
+};
+use crate::compare::{Compare, SortMode};
+use crate::digit::{
+    byte_size, cjk_digit, digit_kind, duration, first_digit, ipv4_address, month_name,
+    ordinal_suffix, parse_cjk_numeral, roman_numeral, to_ascii_digit, DecimalDigits, DigitKind,
+};
+use crate::hangul::{is_jamo, Jamo};
+use crate::iter::{
+    combining_diacritical, iterate_lexical_char, iterate_lexical_char_only_alnum,
+    iterate_lexical_char_only_alnum_with, iterate_lexical_char_with, iterate_lexical_symbol,
+    LexicalChar, Transliterate,
+};
+use crate::kana::fold_kana;
+use crate::script::{is_cjk, script_rank, Script};
+use crate::tailoring::Tailoring;
+use core::cmp::Ordering;
+use core::iter::Peekable;
+#[cfg(feature = "unicode-normalization")]
+use core::iter::{Chain, Once};
+use core::str::{CharIndices, Chars};
+#[cfg(feature = "unicode-normalization")]
+use unicode_normalization::char::{
+    canonical_combining_class, decompose_canonical, decompose_compatible,
+};
+#[cfg(feature = "unicode-normalization")]
+use unicode_normalization::{Decompositions, UnicodeNormalization};
+#[cfg(feature = "unicode-segmentation")]
+use unicode_segmentation::UnicodeSegmentation;
+
+/// A configurable comparison function.
+///
+/// A `Collator` is created with `Collator::new()`, which compares strings like `lexical_cmp`.
+/// It can then be configured with builder methods. The comparison is done with the `cmp`
+/// method, or via the `Compare` trait.
+///
+/// `Collator::from` creates a collator from a `SortMode`, which compares strings exactly like
+/// the corresponding comparison function, e.g. `natural_lexical_cmp`. The comparison
+/// functions don't use a `Collator`: A `Collator` checks its options for every character, which
+/// makes it 3 to 15 times slower than the functions in the `comparing` benchmark. Instead, the
+/// functions are tested to agree with their collators on the strings of the benchmarks.
+/// A `Collator` is `Copy`, `Send` and `Sync`, so it can be stored in other types and shared
+/// between threads. With the optional `serde` feature, its options can be serialized, e.g. to
+/// store user preferences. Options that borrow data or contain functions, such as
+/// `separators`, can't be serialized; only the predefined tailorings can be serialized.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::{Collator, StringSort};
+///
+/// let collator = Collator::new()
+///     .natural(true)
+///     .token_replacements(&[("St", "Saint"), ("Mt", "Mount")]);
+///
+/// let slice = &mut ["Saint Nicholas", "St. Mary", "Mt. Everest", "Saint Louis 10", "Saint Louis 9"];
+/// slice.string_sort_unstable_with(&collator);
+///
+/// assert_eq!(
+///     slice,
+///     &["Mt. Everest", "Saint Louis 9", "Saint Louis 10", "St. Mary", "Saint Nicholas"],
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Collator<'a> {
+    pub(crate) lexical: bool,
+    pub(crate) natural: bool,
+    pub(crate) signed_numbers: bool,
+    pub(crate) decimal_numbers: bool,
+    pub(crate) decimal_separator: char,
+    digit_group_separators: &'a [char],
+    pub(crate) radix_prefixes: bool,
+    cjk_numerals: bool,
+    roman_numerals: bool,
+    month_names: bool,
+    ordinal_suffixes: bool,
+    units: bool,
+    dotted_quads: bool,
+    durations: bool,
+    pub(crate) only_alnum: bool,
+    skipped_last: bool,
+    ignore_chars: Option<fn(char) -> bool>,
+    separators: &'a [char],
+    collapse_whitespace: bool,
+    group_non_alnum: bool,
+    numbers_last: bool,
+    symbols_last: bool,
+    emoji_last: bool,
+    identifier_words: bool,
+    transliterate_symbols: bool,
+    transliterate_cjk: bool,
+    transliterator: Option<&'a (dyn Transliterate + Sync)>,
+    overrides: &'a [(char, &'a str)],
+    fold_kana: bool,
+    decompose_hangul: bool,
+    controls: Placement,
+    control_policy: ControlPolicy,
+    pub(crate) nul_policy: NulPolicy,
+    pub(crate) ties: TieBreak,
+    leading_zeros: LeadingZeros,
+    scripts: &'a [Script],
+    pub(crate) group_by_script: bool,
+    accents: AccentOrder,
+    tailoring: Tailoring<'a>,
+    #[cfg(feature = "unicode-normalization")]
+    normalization: Normalization,
+    #[cfg(feature = "unicode-segmentation")]
+    graphemes: bool,
+    #[cfg(feature = "emoji-names")]
+    emoji: EmojiOrder,
+    token_replacements: &'a [(&'a str, &'a str)],
+    pub(crate) prefixes: &'a [&'a str],
+}
+
+/// Returns the locale that is used for collation
+#[cfg(all(feature = "sys-locale", feature = "std"))]
+fn system_locale() -> Option<String> {
+    #[cfg(unix)]
+    for var in &["LC_ALL", "LC_COLLATE", "LANG"] {
+        match std::env::var(var) {
+            Ok(locale) if !locale.is_empty() => return Some(locale),
+            _ => {}
+        }
+    }
+    sys_locale::get_locale()
+}
+
+/// Where a group of characters is sorted, relative to all other characters
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum Placement {
+    /// The characters are sorted before all other characters
+    First,
+    /// The characters are sorted after all other characters
+    Last,
+    /// The characters are sorted like any other character. This is the default.
+    #[default]
+    AsIs,
+}
+
+/// How NUL characters (`'\0'`) are handled
+///
+/// NUL characters are valid in Rust strings, but most operating system APIs treat them as
+/// the end of the string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum NulPolicy {
+    /// NUL characters are compared like any other control character. This is the default.
+    #[default]
+    Compare,
+    /// Strings are compared as if they ended at the first NUL character. Strings that are
+    /// equal up to the first NUL character are then compared with `str::cmp`.
+    Truncate,
+    /// NUL characters are sorted after all other characters, including control characters
+    /// placed with `Placement::Last`
+    SortLast,
+}
+
+/// How control characters that aren't whitespace are handled, e.g. NUL, BEL, ESC, or C1
+/// control characters such as U+0090
+///
+/// Whitespace control characters, such as tab, newline or U+0085, are always compared. NUL
+/// characters are only affected if the `NulPolicy` is `NulPolicy::Compare`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum ControlPolicy {
+    /// Control characters are compared by their code point, like other non-alphanumeric
+    /// characters. This is the default.
+    #[default]
+    Compare,
+    /// Control characters are skipped, like combining diacritical marks, so `"a\u{1b}b"` is
+    /// equal to `"ab"` (except for the tie-break)
+    Ignore,
+    /// Control characters are all compared like U+001A SUBSTITUTE, so they are equal to each
+    /// other, and sorted after tab and newline and before the space. They can be moved with
+    /// `Collator::controls`.
+    Placeholder,
+}
+
+/// How strings that are equal after folding (e.g. `"Foo"` and `"fóò"`) are ordered
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum TieBreak {
+    /// The strings are compared like `lexical_cmp` does if they are equal after folding, so
+    /// the order is total and only identical strings are equal. This is the default.
+    #[default]
+    Bytes,
+    /// The strings are considered equal, so a stable sort preserves their input order. This
+    /// is also useful to find strings that are the same for sorting purposes, e.g. with
+    /// `dedup_by`.
+    ///
+    /// Note that this is only a weak ordering: Different strings can be equal, so an unstable
+    /// sort orders them arbitrarily, and a binary search may find any of them.
+    InputOrder,
+    /// Like `Bytes`, but at the first character where only one string has an uppercase
+    /// letter, that string comes first, so `"Foo" < "foo"` and `"fOO" < "foo"`. Accents are
+    /// compared before the case in lexical comparisons, so `"foo" < "Föo"`.
+    UppercaseFirst,
+    /// Like `Bytes`, but at the first character where only one string has a lowercase
+    /// letter, that string comes first, so `"foo" < "Foo"` and `"foo" < "fOO"`. Accents are
+    /// compared before the case in lexical comparisons, so `"Foo" < "föo"`.
+    LowercaseFirst,
+}
+
+/// How numbers that are equal, but have different numbers of leading zeros (e.g. `"01"` and
+/// `"1"`), are ordered in natural comparisons
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum LeadingZeros {
+    /// The number with fewer leading zeros comes first, if the strings are equal otherwise,
+    /// so `"IMG_1" < "IMG_01" < "IMG_2"`. This is the default.
+    #[default]
+    FewerFirst,
+    /// The number with more leading zeros comes first, if the strings are equal otherwise,
+    /// so `"IMG_01" < "IMG_1" < "IMG_2"`
+    MoreFirst,
+    /// Leading zeros are ignored, so the numbers are equal, and strings that are equal
+    /// otherwise are ordered by the tie-break
+    Ignore,
+}
+
+/// How strings that only differ in accents (e.g. `"cote"` and `"côte"`) are ordered in
+/// lexical comparisons
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum AccentOrder {
+    /// At the first position where only one string has a non-ASCII character, the string with
+    /// the ASCII character comes first. Strings with accents at the same positions are ordered
+    /// by their code points, so `"à" < "á" < "â"`. This is the default.
+    #[default]
+    Codepoint,
+    /// At the first position where the accents differ, they are compared in this order:
+    /// unaccented, acute, grave, circumflex, diaeresis, then all other accents and non-ASCII
+    /// letters. This is similar to the secondary weights of the Unicode Collation Algorithm,
+    /// so `"a" < "á" < "à" < "â" < "ä"`. Combining marks are compared like the accents of
+    /// precomposed letters.
+    Weighted,
+}
+
+/// Which strings are considered equivalent before comparing them
+///
+/// This requires the `unicode-normalization` feature.
+#[cfg(feature = "unicode-normalization")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum Normalization {
+    /// Strings aren't normalized. This is the default.
+    #[default]
+    None,
+    /// Canonically equivalent strings, such as `"é"` (U+E9) and `"é"` (`e` followed by
+    /// U+301), are equal, and characters are decomposed before they are compared.
+    Nfc,
+    /// Like `Nfc`, but compatibility equivalent strings, such as `"ﬁle"` and `"file"`, or
+    /// `"①"` and `"1"`, are equal as well, and characters are replaced with their
+    /// compatibility decomposition before they are compared, e.g. `"㎒"` is compared as
+    /// `"MHz"`.
+    Nfkc,
+}
+
+/// How emojis are ordered in lexical comparisons
+///
+/// This requires the `emoji-names` feature.
+#[cfg(feature = "emoji-names")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum EmojiOrder {
+    /// Emojis are ordered by their code point, like other non-alphanumeric characters. This is
+    /// the default.
+    #[default]
+    ByCodepoint,
+    /// Emojis are ordered by their CLDR short name (e.g. `"party popper"` for `🎉`), after all
+    /// other non-alphanumeric characters.
+    ///
+    /// This applies to emojis that consist of a single code point and are displayed as emojis
+    /// by default. Other characters of emoji sequences (e.g. skin tone modifiers) are ordered
+    /// like other non-alphanumeric characters.
+    ByName,
+}
+
+impl Default for Collator<'_> {
+    fn default() -> Self {
+        Collator::new()
+    }
+}
+
+impl From<SortMode> for Collator<'_> {
+    fn from(mode: SortMode) -> Self {
+        Collator::new().mode(mode)
+    }
+}
+
+impl<'a> Collator<'a> {
+    /// Creates a new collator, which compares strings like `lexical_cmp`
+    pub const fn new() -> Self {
+        Collator {
+            lexical: true,
+            natural: false,
+            signed_numbers: false,
+            decimal_numbers: false,
+            decimal_separator: '.',
+            digit_group_separators: &[],
+            radix_prefixes: false,
+            cjk_numerals: false,
+            roman_numerals: false,
+            month_names: false,
+            ordinal_suffixes: false,
+            units: false,
+            dotted_quads: false,
+            durations: false,
+            only_alnum: false,
+            skipped_last: false,
+            ignore_chars: None,
+            separators: &[],
+            collapse_whitespace: false,
+            group_non_alnum: false,
+            numbers_last: false,
+            symbols_last: false,
+            emoji_last: false,
+            identifier_words: false,
+            transliterate_symbols: false,
+            transliterate_cjk: true,
+            transliterator: None,
+            overrides: &[],
+            fold_kana: false,
+            decompose_hangul: false,
+            controls: Placement::AsIs,
+            control_policy: ControlPolicy::Compare,
+            nul_policy: NulPolicy::Compare,
+            ties: TieBreak::Bytes,
+            leading_zeros: LeadingZeros::FewerFirst,
+            scripts: Script::DEFAULT_ORDER,
+            group_by_script: false,
+            accents: AccentOrder::Codepoint,
+            tailoring: Tailoring::new(&[], &[]),
+            #[cfg(feature = "unicode-normalization")]
+            normalization: Normalization::None,
+            #[cfg(feature = "unicode-segmentation")]
+            graphemes: false,
+            #[cfg(feature = "emoji-names")]
+            emoji: EmojiOrder::ByCodepoint,
+            token_replacements: &[],
+            prefixes: &[],
+        }
+    }
+
+    /// Creates a new collator with the tailoring for the locale of the system, which is
+    /// selected with `Tailoring::from_locale`. If the locale has no tailoring or can't be
+    /// determined, this is the same as `Collator::new()`.
+    ///
+    /// On Unix, the locale is read from the `LC_ALL`, `LC_COLLATE` and `LANG` environment
+    /// variables, in this order. Otherwise, and if none of them is set, the locale is
+    /// determined with the `sys-locale` crate.
+    ///
+    /// This requires the `sys-locale` and `std` features.
+    #[cfg(all(feature = "sys-locale", feature = "std"))]
+    pub fn from_system_locale() -> Self {
+        let tailoring = system_locale()
+            .as_deref()
+            .and_then(Tailoring::from_locale)
+            .unwrap_or_default();
+        Collator::new().tailoring(tailoring)
+    }
+
+    /// Sets the lexical, natural and only-alnum flags to the ones of the given mode
+    pub const fn mode(self, mode: SortMode) -> Self {
+        self.lexical(mode.is_lexical())
+            .natural(mode.is_natural())
+            .only_alnum(mode.is_only_alnum())
+    }
+
+    /// Returns the mode with the lexical, natural and only-alnum flags of this collator
+    pub const fn sort_mode(&self) -> SortMode {
+        SortMode::from_flags(self.lexical, self.natural, self.only_alnum)
+    }
+
+    /// Sets whether strings are compared lexicographically, i.e. transliterated to lowercase
+    /// ASCII. The default is `true`.
+    pub const fn lexical(mut self, lexical: bool) -> Self {
+        self.lexical = lexical;
+        self
+    }
+
+    /// Sets whether numbers are compared naturally. The default is `false`.
+    pub const fn natural(mut self, natural: bool) -> Self {
+        self.natural = natural;
+        self
+    }
+
+    /// Sets whether a minus sign before a number makes it negative in natural comparisons.
+    /// The default is `false`.
+    ///
+    /// If enabled, a `-` or `−` (U+2212) immediately before a number is a minus sign, so
+    /// `"-12" < "-5" < "3"`. A minus sign is only recognized at the start of the string or
+    /// after a character that isn't alphanumeric, such as `_` or a space, so the `-` in
+    /// `"a-5"` or `"T-10"` is still a separator, and a `-` without a number after it is
+    /// compared like any other punctuation. This has no effect if numbers aren't compared
+    /// naturally.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::{Collator, StringSort};
+    ///
+    /// let collator = Collator::new().natural(true).signed_numbers(true);
+    /// let slice = &mut ["temp_3.csv", "temp_-5.csv", "temp_-12.csv", "temp_0.csv"];
+    /// slice.string_sort_with(&collator);
+    /// assert_eq!(slice, &["temp_-12.csv", "temp_-5.csv", "temp_0.csv", "temp_3.csv"]);
+    /// ```
+    pub const fn signed_numbers(mut self, signed_numbers: bool) -> Self {
+        self.signed_numbers = signed_numbers;
+        self
+    }
+
+    /// Sets whether decimal fractions are recognized in natural comparisons. The default is
+    /// `false`.
+    ///
+    /// If enabled, a number followed by a `.` and more digits is compared as a decimal
+    /// number: First the integer parts, then the fractional parts digit by digit, as if the
+    /// shorter one was padded with zeros. So `"1.25" < "1.5"`, and `"1.5"` and `"1.50"` are
+    /// equal, so the tie-break decides. A number without a fractional part is compared as if
+    /// its fractional part was zero, and a `.` without digits after it isn't a decimal point.
+    ///
+    /// Numbers with several dots, such as `1.2.10`, are version numbers, so their parts are
+    /// compared as integers as usual. Note that this doesn't apply to version numbers with
+    /// a single dot, so `"v1.10" < "v1.9"` if this is enabled. A version number has no
+    /// fractional part, so it isn't equal to a decimal number: `"1.2.0" < "1.2"`, and even
+    /// `"1.3.0" < "1.2"`, because the fraction `.2` is compared with zero. This has no effect if numbers
+    /// aren't compared naturally, or if non-alphanumeric characters are skipped.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::{Collator, StringSort};
+    ///
+    /// let collator = Collator::new().natural(true).decimal_numbers(true);
+    /// let slice = &mut ["spacer 1.5mm.stl", "spacer 1.25mm.stl", "spacer 2mm.stl", "spacer 1mm.stl"];
+    /// slice.string_sort_with(&collator);
+    /// assert_eq!(
+    ///     slice,
+    ///     &["spacer 1mm.stl", "spacer 1.25mm.stl", "spacer 1.5mm.stl", "spacer 2mm.stl"],
+    /// );
+    /// ```
+    pub const fn decimal_numbers(mut self, decimal_numbers: bool) -> Self {
+        self.decimal_numbers = decimal_numbers;
+        self
+    }
+
+    /// Sets the decimal separator of decimal numbers. The default is `'.'`.
+    ///
+    /// This has no effect unless `decimal_numbers` is enabled. Other characters, including
+    /// `.` if the separator is changed, are compared like any other punctuation.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::{Collator, StringSort};
+    ///
+    /// let collator = Collator::new()
+    ///     .natural(true)
+    ///     .decimal_numbers(true)
+    ///     .decimal_separator(',');
+    /// let slice = &mut ["10,25 kg", "3,75 kg", "3,5 kg", "3 kg"];
+    /// slice.string_sort_with(&collator);
+    /// assert_eq!(slice, &["3 kg", "3,5 kg", "3,75 kg", "10,25 kg"]);
+    /// ```
+    pub const fn decimal_separator(mut self, decimal_separator: char) -> Self {
+        self.decimal_separator = decimal_separator;
+        self
+    }
+
+    /// Sets characters that group the digits of a number in natural comparisons, e.g. `,` in
+    /// `1,000`, or `_` in `1_000`. The default is none.
+    ///
+    /// These characters are skipped if they are between two digits, so `"1,000"` is compared
+    /// as `"1000"`. Otherwise they are compared like any other character, so `"1, 2"` still
+    /// contains two numbers. Other useful separators are the no-break space (U+00A0), the
+    /// thin space (U+2009) and `'` (e.g. in `1'000`). This has no effect if numbers aren't
+    /// compared naturally. The decimal separator shouldn't be a group separator.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::{Collator, StringSort};
+    ///
+    /// let collator = Collator::new().natural(true).digit_group_separators(&[',', '_']);
+    /// let slice = &mut ["1,000 items", "500 items", "1_000_001 items", "2, 3 items"];
+    /// slice.string_sort_with(&collator);
+    /// assert_eq!(slice, &["2, 3 items", "500 items", "1,000 items", "1_000_001 items"]);
+    /// ```
+    pub const fn digit_group_separators(mut self, separators: &'a [char]) -> Self {
+        self.digit_group_separators = separators;
+        self
+    }
+
+    /// Sets whether numbers with the prefix `0x`, `0o` or `0b` are parsed as hexadecimal,
+    /// octal or binary numbers in natural comparisons. The default is `false`.
+    ///
+    /// If enabled, the prefixes are case-insensitive, and hexadecimal numbers contain the
+    /// letters `a` to `f` as well. A prefix must be followed by a digit of its radix, so
+    /// `"0xg"` doesn't contain a hexadecimal number. Numbers are compared by their value, even
+    /// if their radixes differ, so `"0x10" < "20"`. Numbers with the same radix are compared
+    /// digit by digit, so they can't overflow. Numbers with different radixes are compared
+    /// exactly if they are less than 2^4096; larger numbers come after them and are ordered by
+    /// their radix first (binary, octal, decimal, hexadecimal), then by their value.
+    ///
+    /// This has no effect if numbers aren't compared naturally.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::{Collator, StringSort};
+    ///
+    /// let collator = Collator::new().natural(true).radix_prefixes(true);
+    /// let slice = &mut ["reg_0x10", "reg_0xA", "reg_0x9", "reg_12"];
+    /// slice.string_sort_with(&collator);
+    /// assert_eq!(slice, &["reg_0x9", "reg_0xA", "reg_12", "reg_0x10"]);
+    /// ```
+    pub const fn radix_prefixes(mut self, radix_prefixes: bool) -> Self {
+        self.radix_prefixes = radix_prefixes;
+        self
+    }
+
+    /// Sets whether CJK numerals are compared as numbers in natural comparisons. The default
+    /// is `false`, because these characters are also used in ordinary words.
+    ///
+    /// If enabled, a numeral with the multipliers `十`, `百` and `千` is compared like the
+    /// number of its value, e.g. `二十三` like `23`. The digits `〇` and `一` to `九` are
+    /// compared like ASCII digits, so `二〇二三` is compared like `2023`. Otherwise, CJK
+    /// numerals are compared like other characters, which are transliterated in lexical
+    /// comparisons, so `十` (`shi`) would sort after `二` (`er`).
+    ///
+    /// This has no effect if numbers aren't compared naturally.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::{Collator, StringSort};
+    ///
+    /// let collator = Collator::new().natural(true).cjk_numerals(true);
+    /// let slice = &mut ["第十巻", "第二十三巻", "第二巻", "第3巻"];
+    /// slice.string_sort_with(&collator);
+    /// assert_eq!(slice, &["第二巻", "第3巻", "第十巻", "第二十三巻"]);
+    /// ```
+    pub const fn cjk_numerals(mut self, cjk_numerals: bool) -> Self {
+        self.cjk_numerals = cjk_numerals;
+        self
+    }
+
+    /// Sets whether Roman numeral characters (U+2160 to U+2188), such as `Ⅻ` or `ⅳ`, are
+    /// compared as numbers in natural comparisons. The default is `false`.
+    ///
+    /// If enabled, every Roman numeral character is a number of its own, which is compared by
+    /// its value, so `"Ⅱ" < "Ⅹ" < "Ⅻ"` and `"9" < "Ⅻ" < "13"`. Roman numerals that consist of
+    /// ASCII letters, such as `XII`, are still compared like words. Otherwise, Roman numeral
+    /// characters are transliterated in lexical comparisons, so `Ⅻ` is compared like `xii`.
+    ///
+    /// This has no effect if numbers aren't compared naturally.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::{Collator, StringSort};
+    ///
+    /// let collator = Collator::new().natural(true).roman_numerals(true);
+    /// let slice = &mut ["Part Ⅻ", "Part Ⅱ", "Part 9", "Part Ⅹ"];
+    /// slice.string_sort_with(&collator);
+    /// assert_eq!(slice, &["Part Ⅱ", "Part 9", "Part Ⅹ", "Part Ⅻ"]);
+    /// ```
+    pub const fn roman_numerals(mut self, roman_numerals: bool) -> Self {
+        self.roman_numerals = roman_numerals;
+        self
+    }
+
+    /// Sets whether English month names and their abbreviations are compared as the numbers
+    /// of the months in natural comparisons. The default is `false`.
+    ///
+    /// If enabled, the names `January` to `December` and the abbreviations `Jan` to `Dec`
+    /// (and `Sept`) are recognized, ignoring case, if they aren't preceded or followed by a
+    /// letter. So `"Feb" < "Mar" < "Nov"`, but `Janet` doesn't contain a month. A month is
+    /// a number of its own, which is compared with other numbers at the same position, so
+    /// `"Feb" < "3"`, and `"Jan-2021" < "Dec-2020"`. To sort by year first, the year must
+    /// come first.
+    ///
+    /// This has no effect if numbers aren't compared naturally.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::{Collator, StringSort};
+    ///
+    /// let collator = Collator::new().natural(true).month_names(true);
+    /// let slice = &mut ["report-Apr-2021", "report-Feb-2021", "report-Dec-2020", "report-2021"];
+    /// slice.string_sort_with(&collator);
+    /// assert_eq!(
+    ///     slice,
+    ///     &["report-Feb-2021", "report-Apr-2021", "report-Dec-2020", "report-2021"],
+    /// );
+    /// ```
+    pub const fn month_names(mut self, month_names: bool) -> Self {
+        self.month_names = month_names;
+        self
+    }
+
+    /// Sets whether ordinal suffixes after numbers are ignored in natural comparisons. The
+    /// default is `false`.
+    ///
+    /// If enabled, the suffixes `st`, `nd`, `rd` and `th` (ignoring case) are skipped if they
+    /// directly follow a number and aren't followed by a letter, so `"2nd draft"` is compared
+    /// like `"2 draft"`, and the rest of the string decides. Strings that are equal otherwise
+    /// are ordered by the tie-break.
+    ///
+    /// This has no effect if numbers aren't compared naturally.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::{Collator, StringSort};
+    ///
+    /// let collator = Collator::new().natural(true).ordinal_suffixes(true);
+    /// let slice = &mut ["No. 2nd b", "No. 2 a", "No. 10th a", "No. 1st c"];
+    /// slice.string_sort_with(&collator);
+    /// assert_eq!(slice, &["No. 1st c", "No. 2 a", "No. 2nd b", "No. 10th a"]);
+    /// ```
+    pub const fn ordinal_suffixes(mut self, ordinal_suffixes: bool) -> Self {
+        self.ordinal_suffixes = ordinal_suffixes;
+        self
+    }
+
+    /// Sets whether sizes with a byte-size unit are compared by their value in natural
+    /// comparisons. The default is `false`.
+    ///
+    /// A size is a number with an optional decimal part, an optional space and one of the
+    /// units `B`, `KB`, `MB`, `GB`, `TB`, `KiB`, `MiB`, `GiB` or `TiB`, ignoring case. `KB` is
+    /// 1000 bytes, and `KiB` is 1024 bytes. The decimal separator is the one set with
+    /// `decimal_separator`. Numbers without a unit are less than sizes, no matter their value,
+    /// so `"900" < "2 GB"`.
+    ///
+    /// Sizes with the same value, such as `1.5 GB` and `1500 MB`, are equal, so they are
+    /// ordered by the tie-break if the rest of the strings is equal.
+    ///
+    /// This has no effect if numbers aren't compared naturally.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::{Collator, StringSort};
+    ///
+    /// let collator = Collator::new().natural(true).units(true);
+    /// let slice = &mut ["backup 2 GB", "backup 900 MB", "backup 1.5GB", "backup 1 TiB"];
+    /// slice.string_sort_with(&collator);
+    /// assert_eq!(slice, &["backup 900 MB", "backup 1.5GB", "backup 2 GB", "backup 1 TiB"]);
+    /// ```
+    pub const fn units(mut self, units: bool) -> Self {
+        self.units = units;
+        self
+    }
+
+    /// Sets whether IPv4 addresses are compared as a whole in natural comparisons. The
+    /// default is `false`.
+    ///
+    /// An IPv4 address is a dotted quad such as `192.168.1.10`, i.e. four numbers from 0 to 255
+    /// separated by dots, which isn't followed by another dot and a digit. If both numbers at
+    /// the same position start an address, the addresses are compared as a tuple of four
+    /// numbers, so the comparison never continues after the end of the shorter address, even
+    /// if dots are skipped. Otherwise, e.g. if an address is incomplete, the numbers are
+    /// compared as usual. Addresses that only differ in leading zeros are equal.
+    ///
+    /// This has no effect if numbers aren't compared naturally.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::{Collator, StringSort};
+    ///
+    /// let collator = Collator::new().natural(true).only_alnum(true).dotted_quads(true);
+    /// let slice = &mut ["web-10.0.0.1", "web-9.99.0.1", "web-10.0.0.10", "web-10.0.0.9"];
+    /// slice.string_sort_with(&collator);
+    /// assert_eq!(slice, &["web-9.99.0.1", "web-10.0.0.1", "web-10.0.0.9", "web-10.0.0.10"]);
+    /// ```
+    pub const fn dotted_quads(mut self, dotted_quads: bool) -> Self {
+        self.dotted_quads = dotted_quads;
+        self
+    }
+
+    /// Sets whether durations and clock times such as `1:30` or `1:02:45` are compared as a
+    /// whole in natural comparisons. The default is `false`.
+    ///
+    /// A duration consists of two or three fields separated by colons. The first field has 1
+    /// to 3 digits, the others have exactly 2 digits, as in `61:00` or `1:05:09`. If both
+    /// numbers at the same position start a duration, a duration with more fields is greater,
+    /// so `1:00:00` is greater than `61:00`. Durations with the same number of fields are
+    /// compared field by field. Other numbers, e.g. in `12:monkeys`, count as a single field,
+    /// so they are less than every duration, and they are compared with each other as usual.
+    /// Durations that only differ in leading zeros are equal.
+    ///
+    /// This has no effect if numbers aren't compared naturally.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::{Collator, StringSort};
+    ///
+    /// let collator = Collator::new().natural(true).durations(true);
+    /// let slice = &mut ["1:02:45 Finale", "59:59 Suite", "9:59 Prelude"];
+    /// slice.string_sort_with(&collator);
+    /// assert_eq!(slice, &["9:59 Prelude", "59:59 Suite", "1:02:45 Finale"]);
+    /// ```
+    pub const fn durations(mut self, durations: bool) -> Self {
+        self.durations = durations;
+        self
+    }
+
+    /// Sets whether non-alphanumeric characters are skipped. The default is `false`.
+    pub const fn only_alnum(mut self, only_alnum: bool) -> Self {
+        self.only_alnum = only_alnum;
+        self
+    }
+
+    /// Sets whether the characters skipped with `only_alnum` are only compared as the last
+    /// resort. The default is `false`.
+    ///
+    /// Strings that are equal after skipping non-alphanumeric characters, such as `"foo-bar"`
+    /// and `"Foo.bar"`, are ordered by the tie-break. By default, the strings are compared
+    /// again with the skipped characters, so `"foo-bar" < "Foo.bar"`, because `-` is less
+    /// than `.`. If this is `true`, the tie-break is applied to the alphanumeric characters
+    /// first: Their scripts and accents are compared, then the characters case-sensitively,
+    /// so `"Foo.bar" < "foo-bar"`. Only if all alphanumeric characters are identical, the
+    /// strings are compared with the skipped characters.
+    ///
+    /// This has no effect if `only_alnum` is `false`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::{Collator, StringSort};
+    ///
+    /// let collator = Collator::new().only_alnum(true);
+    /// let slice = &mut ["foo-bar", "Foo.bar"];
+    /// slice.string_sort_with(&collator);
+    /// assert_eq!(slice, &["foo-bar", "Foo.bar"]);
+    ///
+    /// slice.string_sort_with(&collator.skipped_last(true));
+    /// assert_eq!(slice, &["Foo.bar", "foo-bar"]);
+    /// ```
+    pub const fn skipped_last(mut self, skipped_last: bool) -> Self {
+        self.skipped_last = skipped_last;
+        self
+    }
+
+    /// Sets a predicate for characters that are skipped. The default is to skip no characters.
+    ///
+    /// This is a finer-grained alternative to `only_alnum`: the characters are skipped before
+    /// they are transliterated, so the other characters are compared as if they weren't
+    /// there. In natural comparisons, digits separated by skipped characters are part of the
+    /// same number.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::{Collator, StringSort};
+    ///
+    /// let collator = Collator::new()
+    ///     .natural(true)
+    ///     .ignore_chars(|c| c == '_' || c == '\'');
+    /// let slice = &mut ["f10", "f_5", "f-5", "f5", "f 5"];
+    /// slice.string_sort_with(&collator);
+    /// assert_eq!(slice, &["f 5", "f-5", "f5", "f_5", "f10"]);
+    /// ```
+    pub const fn ignore_chars(mut self, ignore: fn(char) -> bool) -> Self {
+        self.ignore_chars = Some(ignore);
+        self
+    }
+
+    /// Sets characters that are compared as the same separator, e.g. `&[' ', '-', '_']`. The
+    /// default is none.
+    ///
+    /// All of these characters are compared like the first one, so `"my-crate"`, `"my_crate"`
+    /// and `"my crate"` are only ordered by the tie-breaks. Unlike with `only_alnum`, the
+    /// separators aren't skipped, so `"ab"` and `"a b"` are still different. Each separator in
+    /// a run of separators is compared on its own. Digit group separators are matched before
+    /// the separators are folded.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::{Collator, StringSort};
+    ///
+    /// let slice = &mut ["my_crate", "my-crates", "my crate", "my.crate", "myc", "my-crate"];
+    /// slice.string_sort_with(&Collator::new());
+    /// assert_eq!(slice, &["my crate", "my-crate", "my-crates", "my.crate", "my_crate", "myc"]);
+    ///
+    /// slice.string_sort_with(&Collator::new().separators(&[' ', '-', '_']));
+    /// assert_eq!(slice, &["my crate", "my-crate", "my_crate", "my-crates", "my.crate", "myc"]);
+    /// ```
+    pub const fn separators(mut self, separators: &'a [char]) -> Self {
+        self.separators = separators;
+        self
+    }
+
+    /// Sets whether a run of whitespace is compared as a single space. The default is `false`.
+    ///
+    /// If enabled, any run of Unicode whitespace, e.g. two spaces, a tab or a newline, is
+    /// compared like one space, also at the start and end of the string. So `"foo  bar"` and
+    /// `"foo\tbar"` are next to `"foo bar"`, but still different from `"foobar"`. Strings that
+    /// only differ in their whitespace are then ordered by the tie-breaks.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::{Collator, StringSort};
+    ///
+    /// let slice = &mut ["foo b", "foo\td", "foob", "foo  c"];
+    /// slice.string_sort_with(&Collator::new());
+    /// assert_eq!(slice, &["foo\td", "foo  c", "foo b", "foob"]);
+    ///
+    /// slice.string_sort_with(&Collator::new().collapse_whitespace(true));
+    /// assert_eq!(slice, &["foo b", "foo  c", "foo\td", "foob"]);
+    /// ```
+    pub const fn collapse_whitespace(mut self, collapse_whitespace: bool) -> Self {
+        self.collapse_whitespace = collapse_whitespace;
+        self
+    }
+
+    /// Sets whether non-alphanumeric characters are ordered by their Unicode general category
+    /// in lexical comparisons. The default is `false`.
+    ///
+    /// If enabled, spaces (`Zs`) come first, then punctuation (`P*`), then symbols (`S*`,
+    /// including most emojis), then all other characters. Characters in the same group are
+    /// ordered by their code point. Otherwise, all non-alphanumeric characters are ordered by
+    /// their code point, e.g. `'+' < ','` and `'€' < '‽'`.
+    pub const fn group_non_alnum(mut self, group_non_alnum: bool) -> Self {
+        self.group_non_alnum = group_non_alnum;
+        self
+    }
+
+    /// Sets whether numbers sort after letters in lexical comparisons. The default is `false`.
+    ///
+    /// By default, digits sort before letters, like in ASCII. If enabled, all letters sort
+    /// before all digits and other numeric characters, so entries that start with a number
+    /// come after all entries that start with a letter, like in many contact lists. The order
+    /// of the letters and the order of the numbers don't change, so `2` still sorts before
+    /// `10` in natural comparisons, and non-alphanumeric characters still sort first.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::{Collator, StringSort};
+    ///
+    /// let collator = Collator::new().natural(true).numbers_last(true);
+    /// let slice = &mut ["1st Choice Plumbing", "Zoë", "10 Pin Bowling", "2 Brothers", "Anna"];
+    /// slice.string_sort_with(&collator);
+    /// assert_eq!(
+    ///     slice,
+    ///     &["Anna", "Zoë", "1st Choice Plumbing", "2 Brothers", "10 Pin Bowling"],
+    /// );
+    /// ```
+    pub const fn numbers_last(mut self, numbers_last: bool) -> Self {
+        self.numbers_last = numbers_last;
+        self
+    }
+
+    /// Sets whether non-alphanumeric characters sort after alphanumeric characters in lexical
+    /// comparisons. The default is `false`.
+    ///
+    /// By default, punctuation, whitespace, symbols and emojis sort before letters and digits,
+    /// at every position of the string. If enabled, they sort after them instead, so
+    /// `"readme"` comes before `".hidden"`, and `"a1"` before `"a-1"`. Among themselves, the
+    /// non-alphanumeric characters are ordered as usual, and the replacement character
+    /// U+FFFD still sorts last. This has no effect if non-alphanumeric characters are skipped.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::{Collator, StringSort};
+    ///
+    /// let slice = &mut ["_scratch", "readme", ".hidden", "Cargo.toml", "src"];
+    /// slice.string_sort_with(&Collator::new());
+    /// assert_eq!(slice, &[".hidden", "_scratch", "Cargo.toml", "readme", "src"]);
+    ///
+    /// slice.string_sort_with(&Collator::new().symbols_last(true));
+    /// assert_eq!(slice, &["Cargo.toml", "readme", "src", ".hidden", "_scratch"]);
+    /// ```
+    pub const fn symbols_last(mut self, symbols_last: bool) -> Self {
+        self.symbols_last = symbols_last;
+        self
+    }
+
+    /// Sets whether non-ASCII symbols, such as emojis, dingbats, arrows and box-drawing
+    /// characters, sort after alphanumeric characters in lexical comparisons. The default is
+    /// `false`.
+    ///
+    /// If enabled, there are three classes of characters: Whitespace, punctuation and ASCII
+    /// symbols come first, then alphanumeric characters, then non-ASCII characters in the
+    /// Unicode general categories `Sm`, `Sc`, `Sk` and `So`. So strings that start with an
+    /// emoji come after all strings that start with a letter, while ordinary punctuation keeps
+    /// its position. Within a class, characters are ordered as usual.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::{Collator, StringSort};
+    ///
+    /// let slice = &mut ["party", "\u{1F389}party", "!party", "zebra"];
+    /// slice.string_sort_with(&Collator::new());
+    /// assert_eq!(slice, &["!party", "\u{1F389}party", "party", "zebra"]);
+    ///
+    /// slice.string_sort_with(&Collator::new().emoji_last(true));
+    /// assert_eq!(slice, &["!party", "party", "zebra", "\u{1F389}party"]);
+    /// ```
+    pub const fn emoji_last(mut self, emoji_last: bool) -> Self {
+        self.emoji_last = emoji_last;
+        self
+    }
+
+    /// Sets whether strings are compared as identifiers, i.e. split into words, which are
+    /// compared one after the other. The default is `false`.
+    ///
+    /// Words are separated by non-alphanumeric characters (e.g. in `snake_case` or
+    /// `kebab-case`), which are otherwise ignored, and by case transitions (e.g. in `camelCase`
+    /// or `PascalCase`). A run of uppercase letters is an acronym, so `"HTTPServer"` consists
+    /// of the words `"HTTP"` and `"Server"`. Words are compared with the other options of
+    /// the collator, so they are case-insensitive in lexical comparisons.
+    ///
+    /// Identifiers with the same words are then compared with the usual tie-breaks.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::{Collator, StringSort};
+    ///
+    /// let collator = Collator::new().identifier_words(true);
+    /// let slice = &mut ["parse_http_response", "parser", "parseHTTPResponse", "parse_json"];
+    /// slice.string_sort_with(&collator);
+    /// assert_eq!(slice, &["parseHTTPResponse", "parse_http_response", "parse_json", "parser"]);
+    /// ```
+    pub const fn identifier_words(mut self, identifier_words: bool) -> Self {
+        self.identifier_words = identifier_words;
+        self
+    }
+
+    /// Sets whether non-alphanumeric characters are transliterated in lexical comparisons, if
+    /// possible. The default is `false`.
+    ///
+    /// If enabled, symbols such as `©`, `™` or `°` are transliterated like alphanumeric
+    /// characters (to `(c)`, `tm` and `deg`), and `&` is transliterated to `and`. If
+    /// non-alphanumeric characters are skipped, they are skipped in the transliteration as
+    /// well, so `©` is compared as `c`. Emojis aren't transliterated.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::{Collator, StringSort};
+    ///
+    /// let collator = Collator::new().transliterate_symbols(true);
+    /// let slice = &mut ["Tom and Jerry", "Tom & Jerry", "(c)2020", "Tom Bombadil", "©2020"];
+    /// slice.string_sort_with(&collator);
+    /// assert_eq!(slice, &["(c)2020", "©2020", "Tom & Jerry", "Tom and Jerry", "Tom Bombadil"]);
+    /// ```
+    pub const fn transliterate_symbols(mut self, transliterate_symbols: bool) -> Self {
+        self.transliterate_symbols = transliterate_symbols;
+        self
+    }
+
+    /// Sets whether Han characters, hiragana and katakana are transliterated in lexical
+    /// comparisons. The default is `true`.
+    ///
+    /// Han characters are transliterated with their Mandarin reading in pinyin, e.g. `北京` is
+    /// compared as `beijing`, which doesn't fit Japanese text. If disabled, these characters
+    /// aren't transliterated, so they sort after Latin letters by their code point, like
+    /// letters of other scripts with `lexical(false)`. Other scripts are still transliterated.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "unicode")] {
+    /// use lexical_sort::{Collator, StringSort};
+    ///
+    /// let slice = &mut ["北京", "zebra", "東京", "bear", "とうきょう"];
+    /// slice.string_sort_with(&Collator::new());
+    /// assert_eq!(slice, &["bear", "北京", "東京", "とうきょう", "zebra"]);
+    ///
+    /// slice.string_sort_with(&Collator::new().transliterate_cjk(false));
+    /// assert_eq!(slice, &["bear", "zebra", "とうきょう", "北京", "東京"]);
+    /// # }
+    /// ```
+    pub const fn transliterate_cjk(mut self, transliterate_cjk: bool) -> Self {
+        self.transliterate_cjk = transliterate_cjk;
+        self
+    }
+
+    /// Sets the backend that transliterates non-ASCII alphanumeric characters in lexical
+    /// comparisons. The default is `iter::AnyAscii`, which uses the `any_ascii` crate.
+    ///
+    /// This can be used to plug in another transliteration crate or an in-house table. The
+    /// other lexical options still apply; for example, tailorings and `transliterate_cjk(false)`
+    /// take precedence over the backend. The backend must be `Sync`, so the `Collator` can
+    /// still be shared between threads.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "unicode")] {
+    /// use lexical_sort::iter::{AnyAscii, Mapped, Transliterate};
+    /// use lexical_sort::{Collator, StringSort};
+    ///
+    /// /// Compares `ü` like `ue`, and transliterates everything else with `any_ascii`
+    /// struct Umlauts;
+    ///
+    /// impl Transliterate for Umlauts {
+    ///     fn transliterate(&self, c: char) -> Mapped {
+    ///         match c {
+    ///             'ü' | 'Ü' => Mapped::Str("ue"),
+    ///             _ => AnyAscii.transliterate(c),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let slice = &mut ["Uhr", "Über", "Udo", "Ubbo"];
+    /// slice.string_sort_with(&Collator::new());
+    /// assert_eq!(slice, &["Ubbo", "Über", "Udo", "Uhr"]);
+    ///
+    /// slice.string_sort_with(&Collator::new().transliterator(&Umlauts));
+    /// assert_eq!(slice, &["Ubbo", "Udo", "Über", "Uhr"]);
+    /// # }
+    /// ```
+    pub const fn transliterator(mut self, transliterator: &'a (dyn Transliterate + Sync)) -> Self {
+        self.transliterator = Some(transliterator);
+        self
+    }
+
+    /// Sets characters that are compared like a string in lexical comparisons, e.g.
+    /// `('ø', "oe")`. The default is no overrides.
+    ///
+    /// Overrides are consulted before the character is transliterated, and before the
+    /// expansions of the tailoring. The string is then compared like the rest of the string,
+    /// so digits in it are part of numbers in natural comparisons. A character that is
+    /// overridden with an empty string is ignored. Characters are matched exactly, so
+    /// uppercase and lowercase characters need separate overrides.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "unicode")] {
+    /// use lexical_sort::{Collator, StringSort};
+    ///
+    /// let slice = &mut ["Oxford", "Øresund", "Ohio", "Odense"];
+    /// slice.string_sort_with(&Collator::new());
+    /// assert_eq!(slice, &["Odense", "Ohio", "Øresund", "Oxford"]);
+    ///
+    /// slice.string_sort_with(&Collator::new().overrides(&[('ø', "oe"), ('Ø', "Oe")]));
+    /// assert_eq!(slice, &["Odense", "Øresund", "Ohio", "Oxford"]);
+    /// # }
+    /// ```
+    pub const fn overrides(mut self, overrides: &'a [(char, &'a str)]) -> Self {
+        self.overrides = overrides;
+        self
+    }
+
+    /// Sets whether Japanese kana are folded in lexical comparisons, like in the gojūon order.
+    /// The default is `false`.
+    ///
+    /// If enabled, katakana are compared like hiragana, so `カ` is equal to `か`, and small
+    /// kana like full-size kana, so `ゃ` is compared like `や`. The prolonged sound mark `ー`
+    /// is compared like the vowel of the kana before it, so `"カード"` is compared like
+    /// `"かあど"`. This is done before the kana are transliterated, and also applies if they
+    /// aren't transliterated with `transliterate_cjk(false)`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "unicode")] {
+    /// use lexical_sort::{Collator, StringSort};
+    ///
+    /// let slice = &mut ["カド", "かーど", "カタ", "カード"];
+    /// slice.string_sort_with(&Collator::new().fold_kana(true));
+    /// assert_eq!(slice, &["かーど", "カード", "カド", "カタ"]);
+    /// # }
+    /// ```
+    pub const fn fold_kana(mut self, fold_kana: bool) -> Self {
+        self.fold_kana = fold_kana;
+        self
+    }
+
+    /// Sets whether Hangul is compared by its jamo in lexical comparisons, like in the ganada
+    /// order. The default is `false`.
+    ///
+    /// If enabled, Hangul syllables are decomposed into their conjoining jamo, and
+    /// compatibility jamo such as `ㄱ` are compared like the conjoining jamo. The jamo aren't
+    /// transliterated, so they sort after Latin letters, in the order of the Korean alphabet.
+    /// Since the syllables are decomposed, precomposed syllables are equal to the same
+    /// syllables spelled with conjoining jamo when they are compared, even without
+    /// normalization.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "unicode")] {
+    /// use lexical_sort::{Collator, StringSort};
+    ///
+    /// let slice = &mut ["하늘", "아이", "가방", "나무", "zebra"];
+    /// slice.string_sort_with(&Collator::new());
+    /// assert_eq!(slice, &["아이", "가방", "하늘", "나무", "zebra"]);
+    ///
+    /// slice.string_sort_with(&Collator::new().decompose_hangul(true));
+    /// assert_eq!(slice, &["zebra", "가방", "나무", "아이", "하늘"]);
+    /// # }
+    /// ```
+    pub const fn decompose_hangul(mut self, decompose_hangul: bool) -> Self {
+        self.decompose_hangul = decompose_hangul;
+        self
+    }
+
+    /// Sets where control characters (e.g. tab, newline, ESC or U+0085) are sorted. The
+    /// default is `Placement::AsIs`, which sorts them by their code point, like other
+    /// non-alphanumeric characters.
+    ///
+    /// This has no effect on strings that are equal after skipping non-alphanumeric
+    /// characters, if `only_alnum` is enabled, since control characters are skipped.
+    pub const fn controls(mut self, placement: Placement) -> Self {
+        self.controls = placement;
+        self
+    }
+
+    /// Sets how control characters that aren't whitespace, e.g. NUL, BEL or ESC, are handled.
+    /// The default is `ControlPolicy::Compare`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::{Collator, ControlPolicy, StringSort};
+    ///
+    /// let slice = &mut ["report-b", "\u{7}report-c", "report-a", "\u{1b}[1mreport-d"];
+    /// slice.string_sort_with(&Collator::new());
+    /// assert_eq!(slice, &["\u{7}report-c", "\u{1b}[1mreport-d", "report-a", "report-b"]);
+    ///
+    /// slice.string_sort_with(&Collator::new().control_policy(ControlPolicy::Ignore));
+    /// assert_eq!(slice, &["\u{1b}[1mreport-d", "report-a", "report-b", "\u{7}report-c"]);
+    /// ```
+    pub const fn control_policy(mut self, policy: ControlPolicy) -> Self {
+        self.control_policy = policy;
+        self
+    }
+
+    /// Sets how emojis are ordered in lexical comparisons. The default is
+    /// `EmojiOrder::ByCodepoint`.
+    ///
+    /// This requires the `emoji-names` feature.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::{Collator, EmojiOrder, StringSort};
+    ///
+    /// let slice = &mut ["🦄 magic", "🎉 party", "☕ break", "🍕 lunch"];
+    /// slice.string_sort_with(&Collator::new());
+    /// assert_eq!(slice, &["☕ break", "🍕 lunch", "🎉 party", "🦄 magic"]);
+    ///
+    /// // hot beverage < party popper < pizza < unicorn
+    /// slice.string_sort_with(&Collator::new().emoji(EmojiOrder::ByName));
+    /// assert_eq!(slice, &["☕ break", "🎉 party", "🍕 lunch", "🦄 magic"]);
+    /// ```
+    #[cfg(feature = "emoji-names")]
+    pub const fn emoji(mut self, order: EmojiOrder) -> Self {
+        self.emoji = order;
+        self
+    }
+
+    /// Sets how NUL characters are handled. The default is `NulPolicy::Compare`.
+    pub const fn nul_policy(mut self, policy: NulPolicy) -> Self {
+        self.nul_policy = policy;
+        self
+    }
+
+    /// Sets how strings that are equal after folding are ordered. The default is
+    /// `TieBreak::Bytes`.
+    ///
+    /// With `TieBreak::InputOrder`, strings such as `"Foo"` and `"fóò"` are equal, so a
+    /// stable sort keeps them in their input order. This must only be used with stable sorts
+    /// (such as `string_sort_with`), or when equal strings are expected, e.g. for `dedup_by`.
+    ///
+    /// `TieBreak::UppercaseFirst` and `TieBreak::LowercaseFirst` only change the order of
+    /// strings that differ in case, and are total orders like `TieBreak::Bytes`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "unicode")] {
+    /// use lexical_sort::{Collator, StringSort, TieBreak};
+    ///
+    /// let collator = Collator::new().ties(TieBreak::InputOrder);
+    /// let slice = &mut ["fóò", "bar", "Foo"];
+    /// slice.string_sort_with(&collator);
+    /// assert_eq!(slice, &["bar", "fóò", "Foo"]);
+    ///
+    /// let collator = Collator::new().ties(TieBreak::LowercaseFirst);
+    /// let slice = &mut ["Foo", "bar", "foo", "fóò"];
+    /// slice.string_sort_with(&collator);
+    /// assert_eq!(slice, &["bar", "foo", "Foo", "fóò"]);
+    /// # }
+    /// ```
+    pub const fn ties(mut self, ties: TieBreak) -> Self {
+        self.ties = ties;
+        self
+    }
+
+    /// Sets how numbers that only differ in their leading zeros are ordered in natural
+    /// comparisons. The default is `LeadingZeros::FewerFirst`.
+    ///
+    /// Leading zeros only matter if the strings are equal otherwise, so `"IMG_01b"` comes
+    /// after `"IMG_1a"` either way. If several numbers differ in their leading zeros, the
+    /// first one decides. If non-alphanumeric characters are skipped, leading zeros are
+    /// compared after the skipped characters.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::{Collator, LeadingZeros, StringSort};
+    ///
+    /// let slice = &mut ["IMG_01", "IMG_2", "IMG_1", "IMG_001"];
+    /// slice.string_sort_with(&Collator::new().natural(true));
+    /// assert_eq!(slice, &["IMG_1", "IMG_01", "IMG_001", "IMG_2"]);
+    ///
+    /// let collator = Collator::new().natural(true).leading_zeros(LeadingZeros::MoreFirst);
+    /// slice.string_sort_with(&collator);
+    /// assert_eq!(slice, &["IMG_001", "IMG_01", "IMG_1", "IMG_2"]);
+    /// ```
+    pub const fn leading_zeros(mut self, leading_zeros: LeadingZeros) -> Self {
+        self.leading_zeros = leading_zeros;
+        self
+    }
+
+    /// Sets the order of scripts, which orders strings that are equal after folding by the
+    /// script of their first alphabetic character. Scripts that aren't in the list, and
+    /// strings without alphabetic characters, come last. The default is
+    /// `Script::DEFAULT_ORDER`, i.e. Latin, Greek, Cyrillic, Han, then all other scripts.
+    ///
+    /// This only affects lexical comparisons, and is applied before the other tie-breaks.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "unicode")] {
+    /// use lexical_sort::{Collator, Script, StringSort};
+    ///
+    /// let slice = &mut ["Анна", "Anna", "Άννα"];
+    /// slice.string_sort_with(&Collator::new());
+    /// assert_eq!(slice, &["Anna", "Άννα", "Анна"]);
+    ///
+    /// let collator = Collator::new().script_order(&[Script::Cyrillic, Script::Greek]);
+    /// slice.string_sort_with(&collator);
+    /// assert_eq!(slice, &["Анна", "Άννα", "Anna"]);
+    /// # }
+    /// ```
+    pub const fn script_order(mut self, scripts: &'a [Script]) -> Self {
+        self.scripts = scripts;
+        self
+    }
+
+    /// Sets whether strings are grouped by the script of their first alphabetic character,
+    /// instead of transliterating other scripts. The default is `false`.
+    ///
+    /// If enabled, the scripts are compared first, in the order set with `script_order`, so
+    /// e.g. all Latin strings come before all Greek strings. Strings of scripts that aren't
+    /// in the list come after them, grouped by their script, and strings without alphabetic
+    /// characters come last. Within a group, Latin strings and strings without alphabetic
+    /// characters are compared as usual, and strings of other scripts are compared by their
+    /// code points, like with `lexical(false)`. Numbers are still compared naturally if
+    /// enabled.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "unicode")] {
+    /// use lexical_sort::{Collator, StringSort};
+    ///
+    /// let slice = &mut ["Ωμέγα", "Zeus", "Анна", "Άλφα", "apple", "Борис"];
+    /// slice.string_sort_with(&Collator::new());
+    /// assert_eq!(slice, &["Άλφα", "Анна", "apple", "Борис", "Ωμέγα", "Zeus"]);
+    ///
+    /// slice.string_sort_with(&Collator::new().group_by_script(true));
+    /// assert_eq!(slice, &["apple", "Zeus", "Άλφα", "Ωμέγα", "Анна", "Борис"]);
+    /// # }
+    /// ```
+    pub const fn group_by_script(mut self, group_by_script: bool) -> Self {
+        self.group_by_script = group_by_script;
+        self
+    }
+
+    /// Sets how strings that only differ in accents are ordered. The default is
+    /// `AccentOrder::Codepoint`.
+    ///
+    /// This only affects lexical comparisons, and is applied after the script order, but
+    /// before the other tie-breaks.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "unicode")] {
+    /// use lexical_sort::{AccentOrder, Collator, StringSort};
+    ///
+    /// let slice = &mut ["à", "â", "a", "ä", "á"];
+    /// slice.string_sort_with(&Collator::new());
+    /// assert_eq!(slice, &["a", "à", "á", "â", "ä"]);
+    ///
+    /// let collator = Collator::new().accent_order(AccentOrder::Weighted);
+    /// slice.string_sort_with(&collator);
+    /// assert_eq!(slice, &["a", "á", "à", "â", "ä"]);
+    /// # }
+    /// ```
+    pub const fn accent_order(mut self, accents: AccentOrder) -> Self {
+        self.accents = accents;
+        self
+    }
+
+    /// Sets the tailoring of the lexical order for a language, e.g.
+    /// `Tailoring::GERMAN_PHONEBOOK`. The default is no tailoring.
+    ///
+    /// This only affects lexical comparisons. See `Tailoring` for details.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::{Collator, StringSort, Tailoring};
+    ///
+    /// let slice = &mut ["Müller", "Muller", "Mueller", "Mulder"];
+    /// slice.string_sort_with(&Collator::new());
+    /// assert_eq!(slice, &["Mueller", "Mulder", "Muller", "Müller"]);
+    ///
+    /// slice.string_sort_with(&Collator::new().tailoring(Tailoring::GERMAN_PHONEBOOK));
+    /// assert_eq!(slice, &["Mueller", "Müller", "Mulder", "Muller"]);
+    ///
+    /// let slice = &mut ["ñu", "nz", "o", "na"];
+    /// slice.string_sort_with(&Collator::new().tailoring(Tailoring::SPANISH));
+    /// assert_eq!(slice, &["na", "nz", "ñu", "o"]);
+    /// ```
+    pub const fn tailoring(mut self, tailoring: Tailoring<'a>) -> Self {
+        self.tailoring = tailoring;
+        self
+    }
+
+    /// Sets whether strings are normalized before they are compared. The default is
+    /// `Normalization::None`.
+    ///
+    /// With `Normalization::Nfc`, canonically equivalent strings are equal, and every
+    /// character is replaced with its canonical decomposition before it is compared, e.g.
+    /// `'ǘ'` is compared as `'u'` followed by two combining marks. This is done on the fly;
+    /// memory is only allocated for long sequences of combining marks. `Normalization::Nfkc`
+    /// uses the compatibility decomposition instead, so ligatures such as `'ﬁ'` and characters
+    /// such as `'①'` are compared like their plain equivalents.
+    ///
+    /// This requires the `unicode-normalization` feature.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::{Collator, Normalization, SortMode};
+    /// use std::cmp::Ordering;
+    ///
+    /// let collator = Collator::from(SortMode::Plain);
+    /// assert_eq!(collator.cmp("caf\u{E9}", "cafe\u{301}"), Ordering::Greater);
+    ///
+    /// let collator = collator.normalize(Normalization::Nfc);
+    /// assert_eq!(collator.cmp("caf\u{E9}", "cafe\u{301}"), Ordering::Equal);
+    ///
+    /// let collator = collator.normalize(Normalization::Nfkc);
+    /// assert_eq!(collator.cmp("\u{FB01}le \u{2460}", "file 1"), Ordering::Equal);
+    /// ```
+    #[cfg(feature = "unicode-normalization")]
+    pub const fn normalize(mut self, normalization: Normalization) -> Self {
+        self.normalization = normalization;
+        self
+    }
+
+    /// Returns `true` if the strings are canonically (or compatibility) equivalent and should
+    /// be equal
+    #[cfg(feature = "unicode-normalization")]
+    #[inline]
+    pub(crate) fn equivalent(&self, lhs: &str, rhs: &str) -> bool {
+        match self.normalization {
+            Normalization::None => false,
+            Normalization::Nfc => lhs.nfd().eq(rhs.nfd()),
+            Normalization::Nfkc => lhs.nfkd().eq(rhs.nfkd()),
+        }
+    }
+
+    #[cfg(not(feature = "unicode-normalization"))]
+    #[inline]
+    pub(crate) fn equivalent(&self, _: &str, _: &str) -> bool {
+        false
+    }
+
+    /// Sets whether grapheme clusters are compared as units. The default is `false`.
+    ///
+    /// If enabled, an extended grapheme cluster that starts with a non-alphanumeric character
+    /// and consists of several code points, such as a flag (`"🇩🇪"`), an emoji with a skin
+    /// tone modifier (`"👍🏽"`), or a sequence joined with zero-width joiners (`"👨‍👩‍👧"`), is
+    /// compared as an opaque unit: Clusters are compared by their first character, like
+    /// other characters, then by the rest of their code points, and a cluster is less than
+    /// any longer cluster that starts with it. So the code points inside a cluster are never
+    /// compared with the characters after a different cluster, e.g. `"👨 x"` and `"👨x"` both
+    /// sort before `"👨‍👩‍👧"`. Other clusters, such as letters with combining marks, are
+    /// transliterated as usual. This has no effect if only alphanumeric characters are
+    /// compared, since the clusters are skipped.
+    ///
+    /// This requires the `unicode-segmentation` feature.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::{Collator, StringSort};
+    ///
+    /// let slice = &mut ["\u{1F468}\u{200D}\u{1F467}", "\u{1F468}z", "\u{1F468}"];
+    /// slice.string_sort_with(&Collator::new());
+    /// assert_eq!(slice, &["\u{1F468}", "\u{1F468}\u{200D}\u{1F467}", "\u{1F468}z"]);
+    ///
+    /// slice.string_sort_with(&Collator::new().graphemes(true));
+    /// assert_eq!(slice, &["\u{1F468}", "\u{1F468}z", "\u{1F468}\u{200D}\u{1F467}"]);
+    /// ```
+    #[cfg(feature = "unicode-segmentation")]
+    pub const fn graphemes(mut self, graphemes: bool) -> Self {
+        self.graphemes = graphemes;
+        self
+    }
+
+    /// Sets tokens that are replaced during the comparison, e.g. `("St", "Saint")`.
+    ///
+    /// A token only matches at the start of a word (i.e. at the start of the string or after
+    /// a non-alphanumeric character), and must be followed by a `.`, a space, or the end of
+    /// the string. The token is matched case-insensitively. If the token is followed by a `.`,
+    /// the `.` is replaced as well. If several tokens match, the longest one is used.
+    ///
+    /// For example, with `("St", "Saint")`, `"St. Mary"` and `"st Mary"` are compared as
+    /// `"Saint Mary"`, but `"Street"` and `"Best. Mary"` are unaffected.
+    ///
+    /// The strings aren't modified, and no memory is allocated.
+    pub const fn token_replacements(mut self, replacements: &'a [(&'a str, &'a str)]) -> Self {
+        self.token_replacements = replacements;
+        self
+    }
+
+    /// Sets prefixes that are ignored during the comparison, e.g. `"IMG_"`.
+    ///
+    /// The longest prefix that matches at the start of a string is removed, once, before the
+    /// string is compared. Prefixes are matched case-insensitively. Strings that are equal
+    /// without their prefixes are then compared with their prefixes, so the prefixes only
+    /// break ties. A string that consists only of a prefix is compared as an empty string.
+    ///
+    /// The strings aren't modified, and no memory is allocated.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::{Collator, StringSort};
+    ///
+    /// let collator = Collator::new().natural(true).strip_prefixes(&["IMG_", "DSC_"]);
+    /// let slice = &mut ["IMG_20.jpg", "DSC_3.jpg", "img_3.jpg", "beach.jpg", "IMG_100.jpg"];
+    /// slice.string_sort_with(&collator);
+    /// assert_eq!(slice, &["DSC_3.jpg", "img_3.jpg", "IMG_20.jpg", "IMG_100.jpg", "beach.jpg"]);
+    /// ```
+    pub const fn strip_prefixes(mut self, prefixes: &'a [&'a str]) -> Self {
+        self.prefixes = prefixes;
+        self
+    }
+
+    /// Compares two strings
+    pub fn cmp(&self, lhs: &str, rhs: &str) -> Ordering {
+        self.cmp_with_position(lhs, rhs).0
+    }
+
+    /// Compares two strings, and returns what decided the result. If the strings differ
+    /// after folding, this includes the byte offsets of the first differing characters.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "unicode")] {
+    /// use lexical_sort::{Collator, DecidedBy};
+    /// use std::cmp::Ordering;
+    ///
+    /// let collator = Collator::new();
+    /// assert_eq!(
+    ///     collator.cmp_with_position("Straße", "Strasze"),
+    ///     (Ordering::Less, DecidedBy::Difference { lhs: 4, rhs: 5 }),
+    /// );
+    /// assert_eq!(
+    ///     collator.cmp_with_position("Foo", "foo"),
+    ///     (Ordering::Less, DecidedBy::TieBreak),
+    /// );
+    /// # }
+    /// ```
+    pub fn cmp_with_position(&self, lhs: &str, rhs: &str) -> (Ordering, DecidedBy) {
+        if self.equivalent(lhs, rhs) {
+            return (Ordering::Equal, DecidedBy::Equal);
+        }
+        if let NulPolicy::Truncate = self.nul_policy {
+            fn truncate(s: &str) -> &str {
+                s.find('\0').map_or(s, |i| &s[..i])
+            }
+            return match self
+                .nul_policy(NulPolicy::Compare)
+                .cmp_with_position(truncate(lhs), truncate(rhs))
+            {
+                (Ordering::Equal, _) if self.ties != TieBreak::InputOrder => {
+                    tie_break(self.cmp_raw(lhs, rhs))
+                }
+                result => result,
+            };
+        }
+        if !self.prefixes.is_empty() {
+            let (i, j) = (self.prefix_len(lhs), self.prefix_len(rhs));
+            let collator = self.strip_prefixes(&[]);
+            return match collator.cmp_with_position(&lhs[i..], &rhs[j..]) {
+                (Ordering::Equal, _) if i != 0 || j != 0 => collator.cmp_with_position(lhs, rhs),
+                (ordering, decided) => (ordering, decided.offset(i, j)),
+            };
+        }
+        if self.group_by_script {
+            let (l, r) = (self.script_group(lhs), self.script_group(rhs));
+            if l != r {
+                let position = |s: &str| s.find(char::is_alphabetic).unwrap_or(s.len());
+                return (
+                    l.cmp(&r),
+                    DecidedBy::Difference {
+                        lhs: position(lhs),
+                        rhs: position(rhs),
+                    },
+                );
+            }
+            return self.in_script_group(l.1).cmp_with_position(lhs, rhs);
+        }
+
+        match self.first_difference(lhs, rhs) {
+            Step::Chars(l, r) => (self.cmp_chars(l.c, r.c), DecidedBy::at(l, r)),
+            Step::Numbers(l, r, ordering) => (ordering, DecidedBy::at(l, r)),
+            #[cfg(feature = "unicode-segmentation")]
+            Step::Clusters(l, r, ordering) => (ordering, DecidedBy::at(l, r)),
+            Step::Length(ordering) => (ordering, DecidedBy::Length),
+            Step::Equal { .. } if self.ties == TieBreak::InputOrder => {
+                (Ordering::Equal, DecidedBy::Equal)
+            }
+            Step::Equal { blank: true } => tie_break(self.cmp_blank(lhs, rhs)),
+            Step::Equal { .. } if self.only_alnum && self.skipped_last => tie_break(
+                self.cmp_alnum_variants(lhs, rhs)
+                    .then_with(|| self.only_alnum(false).cmp(lhs, rhs)),
+            ),
+            Step::Equal { .. } if self.only_alnum => {
+                self.only_alnum(false).cmp_with_position(lhs, rhs)
+            }
+            Step::Equal { .. } if self.lexical => tie_break(self.cmp_variants(lhs, rhs)),
+            Step::Equal { .. } => tie_break(self.cmp_raw(lhs, rhs)),
+        }
+    }
+
+    /// Compares the folded strings, and returns the step where they first differ
+    pub(crate) fn first_difference(&self, lhs: &str, rhs: &str) -> Step {
+        if self.identifier_words {
+            return self.first_word_difference(lhs, rhs);
+        }
+
+        let mut iter1 = self.fold(lhs).peekable();
+        let mut iter2 = self.fold(rhs).peekable();
+
+        let mut blank = true;
+        // whether the previous characters were a number and the decimal separator, as in a
+        // version number
+        let (mut after_number, mut after_separator) = (false, false);
+        // the first numbers that only differ in their leading zeros, to break ties
+        let mut zeros = None;
+        loop {
+            match (iter1.next(), iter2.next()) {
+                (Some(mut l), Some(mut r)) => {
+                    blank = false;
+                    if self.natural && self.signed_numbers {
+                        let l_sign = is_minus_sign(lhs, l, iter1.peek());
+                        let r_sign = is_minus_sign(rhs, r, iter2.peek());
+                        match (l_sign, r_sign) {
+                            (true, true) => continue,
+                            (true, false) if r.c.is_ascii_digit() => {
+                                return Step::Numbers(l, r, Ordering::Less)
+                            }
+                            (false, true) if l.c.is_ascii_digit() => {
+                                return Step::Numbers(l, r, Ordering::Greater)
+                            }
+                            // otherwise, a minus sign is compared like a hyphen
+                            _ if l_sign => l.c = '-',
+                            _ if r_sign => r.c = '-',
+                            _ => {}
+                        }
+                    }
+                    if self.natural && l.c.is_ascii_digit() && r.c.is_ascii_digit() {
+                        if !after_separator {
+                            let tokens = self.cmp_number_tokens((lhs, l), (rhs, r));
+                            if let Some((ordering, end1, end2)) = tokens {
+                                if ordering != Ordering::Equal {
+                                    return Step::Numbers(l, r, ordering);
+                                }
+                                while matches!(iter1.peek(), Some(f) if f.index < end1) {
+                                    iter1.next();
+                                }
+                                while matches!(iter2.peek(), Some(f) if f.index < end2) {
+                                    iter2.next();
+                                }
+                                after_number = false;
+                                continue;
+                            }
+                        }
+                        let (radix1, radix2) = if self.radix_prefixes {
+                            (
+                                radix_prefix(l, iter1.clone()),
+                                radix_prefix(r, iter2.clone()),
+                            )
+                        } else {
+                            (None, None)
+                        };
+                        let (mut ordering, leading_zeros) = if radix1.is_some() || radix2.is_some()
+                        {
+                            let ordering =
+                                cmp_radix_numbers((l, r), (radix1, radix2), &mut iter1, &mut iter2);
+                            (ordering, Ordering::Equal)
+                        } else if let Some((ordering, zeros, end1, end2)) =
+                            self.cmp_long_digit_runs((lhs, l), (rhs, r))
+                        {
+                            iter1 = self.fold_after_digits(lhs, end1).peekable();
+                            iter2 = self.fold_after_digits(rhs, end2).peekable();
+                            (ordering, zeros)
+                        } else {
+                            cmp_ascii_digits(l, r, &mut iter1, &mut iter2)
+                        };
+                        if self.decimal_numbers
+                            && !after_separator
+                            && radix1.is_none()
+                            && radix2.is_none()
+                            && ordering == Ordering::Equal
+                        {
+                            let separator = self.decimal_separator;
+                            ordering = cmp_fractions(&mut iter1, &mut iter2, separator);
+                        }
+                        let ordering = if self.signed_numbers {
+                            // the minus signs were skipped if only alphanumeric characters
+                            // are compared
+                            match (is_negative(lhs, l.index), is_negative(rhs, r.index)) {
+                                (true, true) => ordering.reverse(),
+                                (true, false) => Ordering::Less,
+                                (false, true) => Ordering::Greater,
+                                (false, false) => ordering,
+                            }
+                        } else {
+                            ordering
+                        };
+                        if ordering != Ordering::Equal {
+                            return Step::Numbers(l, r, ordering);
+                        }
+                        let leading_zeros = match self.leading_zeros {
+                            LeadingZeros::FewerFirst => leading_zeros,
+                            LeadingZeros::MoreFirst => leading_zeros.reverse(),
+                            LeadingZeros::Ignore => Ordering::Equal,
+                        };
+                        if zeros.is_none() && leading_zeros != Ordering::Equal {
+                            zeros = Some((l, r, leading_zeros));
+                        }
+                        after_number = true;
+                        after_separator = false;
+                        continue;
+                    }
+                    #[cfg(feature = "unicode-segmentation")]
+                    if l.cluster || r.cluster {
+                        match self.cmp_clusters((lhs, l), (rhs, r)) {
+                            Ordering::Equal => {}
+                            ordering => return Step::Clusters(l, r, ordering),
+                        }
+                    }
+                    if l.c != r.c {
+                        return Step::Chars(l, r);
+                    }
+                    after_separator = after_number && l.c == self.decimal_separator;
+                    after_number = false;
+                }
+                (Some(_), None) => return Step::Length(Ordering::Greater),
+                (None, Some(_)) => return Step::Length(Ordering::Less),
+                (None, None) => {
+                    // if only alphanumeric characters are compared, leading zeros are compared
+                    // in the tie-break, like the skipped characters
+                    return match zeros {
+                        Some((l, r, ordering)) if !self.only_alnum => Step::Numbers(l, r, ordering),
+                        _ => Step::Equal { blank },
+                    };
+                }
+            }
+        }
+    }
+
+    /// Compares the words of two identifiers, and returns the step where they first differ
+    fn first_word_difference(&self, lhs: &str, rhs: &str) -> Step {
+        let collator = self.identifier_words(false);
+        let mut words1 = Words::new(lhs);
+        let mut words2 = Words::new(rhs);
+
+        let mut blank = true;
+        loop {
+            match (words1.next(), words2.next()) {
+                (Some((i, w1)), Some((j, w2))) => {
+                    blank = false;
+                    let offset = |mut l: Folded, mut r: Folded| {
+                        l.index += i;
+                        l.end += i;
+                        r.index += j;
+                        r.end += j;
+                        (l, r)
+                    };
+                    match collator.first_difference(w1, w2) {
+                        Step::Chars(l, r) => {
+                            let (l, r) = offset(l, r);
+                            return Step::Chars(l, r);
+                        }
+                        Step::Numbers(l, r, ordering) => {
+                            let (l, r) = offset(l, r);
+                            return Step::Numbers(l, r, ordering);
+                        }
+                        #[cfg(feature = "unicode-segmentation")]
+                        Step::Clusters(l, r, ordering) => {
+                            let (l, r) = offset(l, r);
+                            return Step::Clusters(l, r, ordering);
+                        }
+                        Step::Length(ordering) => return Step::Length(ordering),
+                        Step::Equal { .. } => {}
+                    }
+                }
+                (Some(_), None) => return Step::Length(Ordering::Greater),
+                (None, Some(_)) => return Step::Length(Ordering::Less),
+                (None, None) => return Step::Equal { blank },
+            }
+        }
+    }
+
+    /// Compares two folded characters, if at least one of them is a grapheme cluster. They
+    /// are compared by their first character, then by the code points of the clusters.
+    #[cfg(feature = "unicode-segmentation")]
+    fn cmp_clusters(&self, (lhs, l): (&str, Folded), (rhs, r): (&str, Folded)) -> Ordering {
+        fn rest(s: &str, f: Folded) -> Chars<'_> {
+            match f.cluster {
+                true => s[f.index + f.c.len_utf8()..f.end].chars(),
+                false => "".chars(),
+            }
+        }
+        match l.c == r.c {
+            true => rest(lhs, l).cmp(rest(rhs, r)),
+            false => self.cmp_chars(l.c, r.c),
+        }
+    }
+
+    /// Compares two different characters
+    #[inline]
+    pub(crate) fn cmp_chars(&self, lhs: char, rhs: char) -> Ordering {
+        // separate letters of the tailoring are compared after the letter they follow
+        let (lhs, rhs) = if self.lexical && !self.tailoring.letters().is_empty() {
+            let (l, r) = (
+                self.tailoring.letter_key(lhs),
+                self.tailoring.letter_key(rhs),
+            );
+            if l.0 == r.0 {
+                return l.1.cmp(&r.1);
+            }
+            (l.0, r.0)
+        } else {
+            (lhs, rhs)
+        };
+        let rank = |c: char| match self.controls {
+            _ if c == '\0' && self.nul_policy == NulPolicy::SortLast => 3,
+            Placement::First if c.is_control() => 0,
+            Placement::Last if c.is_control() => 2,
+            _ => 1,
+        };
+
+        let letters_first = match (lhs.is_alphanumeric(), rhs.is_alphanumeric()) {
+            (true, true) if self.lexical && self.numbers_last => {
+                lhs.is_numeric().cmp(&rhs.is_numeric())
+            }
+            _ if self.lexical && (self.symbols_last || self.emoji_last) => {
+                self.char_class(lhs).cmp(&self.char_class(rhs))
+            }
+            _ => Ordering::Equal,
+        };
+
+        rank(lhs).cmp(&rank(rhs)).then(letters_first).then_with(|| {
+            #[cfg(feature = "emoji-names")]
+            if self.lexical && self.emoji == EmojiOrder::ByName {
+                return if self.group_non_alnum {
+                    ret_ordering_by_emoji_name(lhs, rhs, ret_ordering_by_category)
+                } else {
+                    ret_ordering_by_emoji_name(lhs, rhs, ret_ordering)
+                };
+            }
+            if self.lexical && self.group_non_alnum {
+                ret_ordering_by_category(lhs, rhs)
+            } else if self.lexical {
+                ret_ordering(lhs, rhs)
+            } else {
+                lhs.cmp(&rhs)
+            }
+        })
+    }
+
+    /// Returns whether a character is affected by the `control_policy`: a control character
+    /// that isn't whitespace, and not a NUL character handled by the `nul_policy`
+    fn is_stray_control(&self, c: char) -> bool {
+        c.is_control() && !c.is_whitespace() && (c != '\0' || self.nul_policy == NulPolicy::Compare)
+    }
+
+    /// Returns the class of a character with `symbols_last` or `emoji_last`: 0 for characters
+    /// that sort before alphanumeric characters, 1 for alphanumeric characters, and 2 for
+    /// characters that sort after them
+    fn char_class(&self, c: char) -> u8 {
+        if c.is_alphanumeric() {
+            1
+        } else if self.symbols_last
+            || (self.emoji_last && !c.is_ascii() && category_group(c) == CategoryGroup::Symbol)
+        {
+            2
+        } else {
+            0
+        }
+    }
+
+    /// Compares strings that are equal after folding, like `lexical_cmp` does: First by the
+    /// script of their first alphabetic character. Then, at the first position where only one
+    /// string has a non-ASCII character, the string with the ASCII character is less, or the
+    /// accents are compared with `AccentOrder::Weighted`. If there's no such position, the
+    /// strings are compared with `str::cmp`.
+    pub(crate) fn cmp_variants(&self, lhs: &str, rhs: &str) -> Ordering {
+        self.cmp_accents(lhs, rhs)
+            .then_with(|| self.cmp_raw(lhs, rhs))
+    }
+
+    /// Compares strings that are equal after folding with `only_alnum`, like `cmp_variants`,
+    /// but only their alphanumeric characters are compared with `str::cmp`
+    fn cmp_alnum_variants(&self, lhs: &str, rhs: &str) -> Ordering {
+        let alnum = |s| self.chars(s).filter(|c| c.is_alphanumeric());
+        let accents = match self.lexical {
+            true => self.cmp_accents(lhs, rhs),
+            false => Ordering::Equal,
+        };
+        accents
+            .then_with(|| self.cmp_case_first(alnum(lhs), alnum(rhs)))
+            .then_with(|| alnum(lhs).cmp(alnum(rhs)))
+    }
+
+    /// Compares the scripts and accents of strings that are equal after folding, like
+    /// `cmp_variants`
+    fn cmp_accents(&self, lhs: &str, rhs: &str) -> Ordering {
+        script_rank(self.chars(lhs), self.scripts)
+            .cmp(&script_rank(self.chars(rhs), self.scripts))
+            .then_with(|| match self.accents {
+                AccentOrder::Codepoint => {
+                    let variants1 = self.fold(lhs).map(|f| f.non_ascii);
+                    let variants2 = self.fold(rhs).map(|f| f.non_ascii);
+                    variants1.cmp(variants2)
+                }
+                AccentOrder::Weighted => {
+                    let weights1 = self.fold(lhs).map(|f| accent_weight(&lhs[f.index..]));
+                    let weights2 = self.fold(rhs).map(|f| accent_weight(&rhs[f.index..]));
+                    weights1.cmp(weights2)
+                }
+            })
+    }
+
+    /// Compares two strings that are empty after folding, like `cmp_blank`, but normalized
+    pub(crate) fn cmp_blank(&self, lhs: &str, rhs: &str) -> Ordering {
+        if self.normalized() {
+            let (len1, len2) = (self.chars(lhs).count(), self.chars(rhs).count());
+            len1.cmp(&len2).then_with(|| self.cmp_raw(lhs, rhs))
+        } else {
+            cmp_blank(lhs, rhs)
+        }
+    }
+
+    /// Compares the case of characters with `TieBreak::UppercaseFirst` or
+    /// `TieBreak::LowercaseFirst`. Otherwise, returns `Ordering::Equal`.
+    fn cmp_case_first(
+        &self,
+        lhs: impl Iterator<Item = char>,
+        rhs: impl Iterator<Item = char>,
+    ) -> Ordering {
+        match self.ties {
+            TieBreak::UppercaseFirst => cmp_case(lhs, rhs, false),
+            TieBreak::LowercaseFirst => cmp_case(lhs, rhs, true),
+            TieBreak::Bytes | TieBreak::InputOrder => Ordering::Equal,
+        }
+    }
+
+    /// Compares the code points of two strings, after normalizing them. With a case-first
+    /// tie-break, their case is compared first.
+    pub(crate) fn cmp_raw(&self, lhs: &str, rhs: &str) -> Ordering {
+        let case = self.cmp_case_first(self.chars(lhs), self.chars(rhs));
+        case.then_with(|| {
+            if self.normalized() {
+                self.chars(lhs).cmp(self.chars(rhs))
+            } else {
+                lhs.cmp(rhs)
+            }
+        })
+    }
+
+    /// Returns the group of a string with `group_by_script`: the position of the script of
+    /// its first alphabetic character in the script order, and the script
+    pub(crate) fn script_group(&self, s: &str) -> (usize, Option<Script>) {
+        // scripts that aren't in the list come before strings without alphabetic characters,
+        // and are grouped by their script
+        match self.chars(s).find_map(Script::of) {
+            Some(script) => match self.scripts.iter().position(|&s| s == script) {
+                Some(rank) => (rank, Some(script)),
+                None => (self.scripts.len(), Some(script)),
+            },
+            None => (self.scripts.len() + 1, None),
+        }
+    }
+
+    /// Returns the collator that compares strings in the same script group
+    pub(crate) fn in_script_group(&self, script: Option<Script>) -> Self {
+        match script {
+            None | Some(Script::Latin) => self.group_by_script(false),
+            Some(_) => self.group_by_script(false).lexical(false),
+        }
+    }
+
+    /// Returns an iterator over the characters of a string, after decomposing them if it is
+    /// normalized
+    fn chars<'s>(&self, s: &'s str) -> Normalized<'s> {
+        #[cfg(feature = "unicode-normalization")]
+        match self.normalization {
+            Normalization::None => {}
+            Normalization::Nfc => return Normalized::Nfd(s.nfd()),
+            Normalization::Nfkc => return Normalized::Nfd(s.nfkd()),
+        }
+        Normalized::Chars(s.chars())
+    }
+
+    #[inline]
+    fn normalized(&self) -> bool {
+        #[cfg(feature = "unicode-normalization")]
+        return self.normalization != Normalization::None;
+        #[cfg(not(feature = "unicode-normalization"))]
+        return false;
+    }
+
+    /// Returns an iterator over the characters that are compared
+    pub(crate) fn fold<'s>(&'s self, s: &'s str) -> Fold<'s> {
+        Fold {
+            collator: self,
+            source: s,
+            chars: s.char_indices(),
+            offset: 0,
+            index: 0,
+            end: 0,
+            replacement: "".chars(),
+            expansion: LexicalChar::empty(),
+            numeral: DecimalDigits::default(),
+            #[cfg(feature = "unicode-normalization")]
+            decomposition: None,
+            jamo: Jamo::default(),
+            non_ascii: false,
+            kind: DigitKind::Normal,
+            #[cfg(feature = "unicode-segmentation")]
+            cluster: false,
+            at_word_start: true,
+            after_digit: false,
+        }
+    }
+
+    /// Returns an iterator over the characters that are compared, starting at the byte offset
+    /// after a run of ASCII digits
+    fn fold_after_digits<'s>(&'s self, s: &'s str, offset: usize) -> Fold<'s> {
+        Fold {
+            chars: s[offset..].char_indices(),
+            offset,
+            index: offset,
+            end: offset,
+            at_word_start: false,
+            after_digit: true,
+            ..self.fold(s)
+        }
+    }
+
+    /// Returns `true` if every ASCII digit is compared as itself
+    fn folds_digits_to_themselves(&self) -> bool {
+        let digits = "0123456789";
+        (0..10).all(|i| {
+            let mut fold = self.fold(&digits[i..i + 1]);
+            let first = fold.next();
+            let expected = digits.as_bytes()[i] as char;
+            let digit = |f: Folded| f.c == expected && f.end == 1 && f.kind == DigitKind::Normal;
+            first.is_some_and(digit) && fold.next().is_none()
+        })
+    }
+
+    /// Compares long runs of ASCII digits that start with the folded digits `l` and `r` as
+    /// byte slices with `cmp_digit_bytes`, which is much faster than comparing the folded
+    /// digits one by one. Returns the orderings like `cmp_ascii_digits` and the byte offsets
+    /// after the runs, or `None` if the runs are short or aren't compared as they are.
+    fn cmp_long_digit_runs(
+        &self,
+        (lhs, l): (&str, Folded),
+        (rhs, r): (&str, Folded),
+    ) -> Option<(Ordering, Ordering, usize, usize)> {
+        fn run(s: &str, f: Folded) -> Option<&[u8]> {
+            let bytes = &s.as_bytes()[f.index..];
+            let len = bytes.iter().take_while(|b| b.is_ascii_digit()).count();
+            let plain = f.kind == DigitKind::Normal && f.end == f.index + 1 && len > 0;
+            Some(&bytes[..len]).filter(|_| plain && bytes[0] == f.c as u8)
+        }
+        let (run1, run2) = (run(lhs, l)?, run(rhs, r)?);
+        if run1.len().max(run2.len()) < LONG_DIGIT_RUN || !self.folds_digits_to_themselves() {
+            return None;
+        }
+        let (end1, end2) = (l.index + run1.len(), r.index + run2.len());
+        // the number continues, e.g. after a digit group separator
+        let continues = |s, end| {
+            let next = self.fold_after_digits(s, end).next();
+            matches!(next, Some(f) if f.c.is_ascii_digit())
+        };
+        if continues(lhs, end1) || continues(rhs, end2) {
+            return None;
+        }
+        let (ordering, zeros) = cmp_digit_bytes(run1, run2);
+        Some((ordering, zeros, end1, end2))
+    }
+
+    /// Maps a single character, depending on the lexical and only-alnum flags
+    #[inline]
+    fn map_char(&self, c: char) -> LexicalChar {
+        if self.lexical {
+            if let Some(letter) = self.tailoring.letter(c) {
+                return LexicalChar::from_char(letter);
+            }
+        }
+        if self.lexical && self.transliterate_symbols && !c.is_alphanumeric() {
+            if let Some(chars) = iterate_lexical_symbol(c, self.only_alnum) {
+                return chars;
+            }
+        }
+        if self.natural && self.cjk_numerals {
+            if let Some(digit) = cjk_digit(c) {
+                return LexicalChar::from_char((b'0' + digit as u8) as char);
+            }
+        }
+        if self.lexical && !self.transliterate_cjk && c.is_alphanumeric() && is_cjk(c) {
+            return LexicalChar::from_char(c);
+        }
+        if self.lexical && self.decompose_hangul && is_jamo(c) {
+            return LexicalChar::from_char(c);
+        }
+        match (self.lexical, self.only_alnum) {
+            (true, false) => match self.transliterator {
+                Some(backend) => iterate_lexical_char_with(c, backend),
+                None => iterate_lexical_char(c),
+            },
+            (true, true) => match self.transliterator {
+                Some(backend) => iterate_lexical_char_only_alnum_with(c, backend),
+                None => iterate_lexical_char_only_alnum(c),
+            },
+            (false, true) if !c.is_alphanumeric() => LexicalChar::empty(),
+            (false, _) if self.natural => LexicalChar::from_char(to_ascii_digit(c)),
+            (false, _) => LexicalChar::from_char(c),
+        }
+    }
+
+    /// Returns the replacement and the length of the matched token (including a trailing `.`)
+    /// if one of the tokens matches at the start of `s`.
+    fn match_token(&self, s: &str) -> Option<(&'a str, usize)> {
+        let mut result: Option<(&'a str, usize)> = None;
+
+        for &(token, replacement) in self.token_replacements {
+            let token_len = match match_prefix(token, s) {
+                Some(len) => len,
+                None => continue,
+            };
+            let mut rest = s[token_len..].chars();
+            let len = match rest.next() {
+                None | Some(' ') => token_len,
+                Some('.') => token_len + 1,
+                Some(_) => continue,
+            };
+            if result.map(|(_, l)| l) < Some(len) {
+                result = Some((replacement, len));
+            }
+        }
+        result
+    }
+
+    /// Compares the IPv4 addresses, durations or sizes with a byte-size unit that start with
+    /// the folded digits `l` and `r`, if both are the same kind of token, and returns the
+    /// ordering and the byte offsets after them
+    fn cmp_number_tokens(
+        &self,
+        (lhs, l): (&str, Folded),
+        (rhs, r): (&str, Folded),
+    ) -> Option<(Ordering, usize, usize)> {
+        let (start1, start2) = (self.number_start(lhs, l), self.number_start(rhs, r));
+        if self.durations {
+            // other numbers are like durations with a single field, so they're less than
+            // durations, no matter their value
+            match (start1.and_then(duration), start2.and_then(duration)) {
+                (Some(_), None) => return Some((Ordering::Greater, l.end, r.end)),
+                (None, Some(_)) => return Some((Ordering::Less, l.end, r.end)),
+                _ => {}
+            }
+        }
+        if self.units {
+            // likewise, numbers without a unit are less than sizes
+            let size = |s: &str| byte_size(s, self.decimal_separator).is_some();
+            match (start1.is_some_and(size), start2.is_some_and(size)) {
+                (true, false) => return Some((Ordering::Greater, l.end, r.end)),
+                (false, true) => return Some((Ordering::Less, l.end, r.end)),
+                _ => {}
+            }
+        }
+        let (s1, s2) = (start1?, start2?);
+        let (offset1, offset2) = (lhs.len() - s1.len(), rhs.len() - s2.len());
+        if self.dotted_quads {
+            if let (Some((a1, len1)), Some((a2, len2))) = (ipv4_address(s1), ipv4_address(s2)) {
+                return Some((a1.cmp(&a2), offset1 + len1, offset2 + len2));
+            }
+        }
+        if self.durations {
+            if let (Some((f1, d1, len1)), Some((f2, d2, len2))) = (duration(s1), duration(s2)) {
+                return Some(((f1, d1).cmp(&(f2, d2)), offset1 + len1, offset2 + len2));
+            }
+        }
+        if self.units {
+            let separator = self.decimal_separator;
+            let sizes = (byte_size(s1, separator), byte_size(s2, separator));
+            if let (Some((size1, len1)), Some((size2, len2))) = sizes {
+                return Some((size1.cmp(&size2), offset1 + len1, offset2 + len2));
+            }
+        }
+        None
+    }
+
+    /// Returns the rest of `s` from the folded digit `f`, if it starts a number in `s`
+    fn number_start<'s>(&self, s: &'s str, f: Folded) -> Option<&'s str> {
+        let after_digit = matches!(s[..f.index].bytes().next_back(), Some(b) if b.is_ascii_digit());
+        if f.kind != DigitKind::Normal
+            || after_digit
+            || (self.signed_numbers && is_negative(s, f.index))
+        {
+            return None;
+        }
+        Some(&s[f.index..])
+    }
+
+    /// Returns the byte lengths of the longest common prefix of two strings after folding,
+    /// which ends after a whole character of both strings. See `common_lexical_prefix`.
+    pub(crate) fn common_prefix(&self, lhs: &str, rhs: &str) -> (usize, usize) {
+        let mut iter1 = self.fold(lhs).peekable();
+        let mut iter2 = self.fold(rhs).peekable();
+        let mut prefix = (0, 0);
+        while let (Some(mut l), Some(mut r)) = (iter1.next(), iter2.next()) {
+            if self.natural && l.c.is_ascii_digit() && r.c.is_ascii_digit() {
+                let mut equal = l.c == r.c && l.kind.single_value() == r.kind.single_value();
+                // a number only contains digits of the same kind, and a vulgar fraction is a
+                // number of its own
+                let digit = |first: Folded| {
+                    move |f: &&Folded| {
+                        f.c.is_ascii_digit()
+                            && f.kind == first.kind
+                            && f.kind.single_value().is_none()
+                    }
+                };
+                let (digit1, digit2) = (digit(l), digit(r));
+                loop {
+                    match (iter1.peek().filter(digit1), iter2.peek().filter(digit2)) {
+                        (Some(_), Some(_)) => {
+                            l = iter1.next().unwrap();
+                            r = iter2.next().unwrap();
+                            equal &= l.c == r.c;
+                        }
+                        (None, None) => break,
+                        _ => return prefix,
+                    }
+                }
+                if !equal {
+                    return prefix;
+                }
+            } else if l.c != r.c {
+                return prefix;
+            }
+
+            let at_boundary =
+                |next: Option<&Folded>, f: Folded| !matches!(next, Some(n) if n.index == f.index);
+            if at_boundary(iter1.peek(), l) && at_boundary(iter2.peek(), r) {
+                prefix = (l.end, r.end);
+            }
+        }
+        prefix
+    }
+
+    /// Returns the length of the longest prefix that matches at the start of `s`, or 0
+    pub(crate) fn prefix_len(&self, s: &str) -> usize {
+        self.prefixes
+            .iter()
+            .filter_map(|prefix| match_prefix(prefix, s))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// Returns the number of digits of the decimal fraction at the start of the iterator, i.e.
+/// after the decimal separator, or 0 if there is none. If the digits are followed by another
+/// separator and a digit, they are part of a version number such as `1.2.10`, so they aren't
+/// a fraction.
+pub(crate) fn fraction_len(mut iter: impl Iterator<Item = Folded>, separator: char) -> usize {
+    if !matches!(iter.next(), Some(f) if f.c == separator) {
+        return 0;
+    }
+    let mut len = 0;
+    loop {
+        match iter.next() {
+            Some(f) if f.c.is_ascii_digit() => len += 1,
+            Some(f) if f.c == separator && len > 0 => {
+                let version = matches!(iter.next(), Some(f) if f.c.is_ascii_digit());
+                return if version { 0 } else { len };
+            }
+            _ => return len,
+        }
+    }
+}
+
+/// Compares the decimal fractions at the start of the iterators digit by digit, as if the
+/// shorter one was padded with zeros. A missing fraction is zero. If the fractions are equal,
+/// they are consumed from the iterators.
+fn cmp_fractions<'a>(
+    iter1: &mut Peekable<Fold<'a>>,
+    iter2: &mut Peekable<Fold<'a>>,
+    separator: char,
+) -> Ordering {
+    let len1 = fraction_len(iter1.clone(), separator);
+    let len2 = fraction_len(iter2.clone(), separator);
+    let digits = |iter: &Peekable<Fold<'a>>, len| {
+        let digits = iter.clone().skip(1).take(len).map(|f| f.c);
+        digits.chain(core::iter::repeat('0'))
+    };
+    let ordering = digits(iter1, len1)
+        .zip(digits(iter2, len2))
+        .take(len1.max(len2))
+        .map(|(l, r)| l.cmp(&r))
+        .find(|&ordering| ordering != Ordering::Equal)
+        .unwrap_or(Ordering::Equal);
+
+    if ordering == Ordering::Equal {
+        if len1 > 0 {
+            iter1.nth(len1);
+        }
+        if len2 > 0 {
+            iter2.nth(len2);
+        }
+    }
+    ordering
+}
+
+/// Returns the radix of the number that starts with `first`, if it is a normal `0` followed by
+/// a radix prefix such as `x` and a digit of this radix in `iter`
+pub(crate) fn radix_prefix(first: Folded, mut iter: impl Iterator<Item = Folded>) -> Option<u32> {
+    if first.c != '0' || first.kind != DigitKind::Normal {
+        return None;
+    }
+    let radix = match iter.next()?.c {
+        'x' | 'X' => 16,
+        'o' | 'O' => 8,
+        'b' | 'B' => 2,
+        _ => return None,
+    };
+    match iter.next() {
+        Some(f) if f.c.is_digit(radix) && f.kind == DigitKind::Normal => Some(radix),
+        _ => None,
+    }
+}
+
+/// Returns the digit values of the number that starts with `first`, with the radix returned
+/// by `radix_prefix`, or a decimal number if it is `None`. Like in `cmp_ascii_digits`, the
+/// number only contains digits of the same kind, and a character that is a number of its
+/// own, such as `Ⅻ`, is a number with a single digit.
+fn radix_digits<'a>(
+    first: Folded,
+    radix: Option<u32>,
+    iter: &Peekable<Fold<'a>>,
+) -> impl Iterator<Item = u32> + Clone + 'a {
+    // the first digit of a number with a prefix is the `0` of the prefix
+    let (digit, skip) = match radix {
+        Some(_) => (None, 1),
+        None => (first.c.to_digit(10), 0),
+    };
+    let single = first.kind.single_value().is_some();
+    let radix = radix.unwrap_or(10);
+    let rest = iter.clone().skip(skip).map(move |f| {
+        f.c.to_digit(radix)
+            .filter(|_| f.kind == first.kind && !single)
+    });
+    digit
+        .into_iter()
+        .chain(rest.take_while(Option::is_some).flatten())
+}
+
+/// The minimum number of digits of a run that is compared with `cmp_digit_bytes`
+const LONG_DIGIT_RUN: usize = 32;
+
+/// The number of 32-bit limbs of a `RadixValue`
+const RADIX_VALUE_LIMBS: usize = 128;
+
+/// The value of a number with a radix prefix, if it is less than 2^4096. It is stored on the
+/// stack, so no memory is allocated.
+#[derive(PartialEq, Eq)]
+struct RadixValue {
+    /// The limbs, starting with the least significant one
+    limbs: [u32; RADIX_VALUE_LIMBS],
+    /// The number of limbs without leading zeros
+    len: usize,
+}
+
+impl RadixValue {
+    /// Returns the value of the digits in the given radix, or `None` if it overflows
+    fn new(digits: impl Iterator<Item = u32>, radix: u32) -> Option<Self> {
+        let mut value = RadixValue {
+            limbs: [0; RADIX_VALUE_LIMBS],
+            len: 0,
+        };
+        for d in digits {
+            let mut carry = u64::from(d);
+            for limb in &mut value.limbs[..value.len] {
+                let product = u64::from(*limb) * u64::from(radix) + carry;
+                *limb = product as u32;
+                carry = product >> 32;
+            }
+            if carry != 0 {
+                *value.limbs.get_mut(value.len)? = carry as u32;
+                value.len += 1;
+            }
+        }
+        Some(value)
+    }
+
+    /// Compares the value with a fraction, given as a numerator and a denominator
+    fn cmp_fraction(&self, (n, d): (u32, u32)) -> Ordering {
+        if self.len > 2 {
+            return Ordering::Greater;
+        }
+        let limbs = self.limbs[..self.len].iter().rev();
+        let value = limbs.fold(0, |value, &limb| value << 32 | u128::from(limb));
+        (value * u128::from(d)).cmp(&u128::from(n))
+    }
+}
+
+impl Ord for RadixValue {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let limbs1 = self.limbs[..self.len].iter().rev();
+        let limbs2 = other.limbs[..other.len].iter().rev();
+        self.len.cmp(&other.len).then_with(|| limbs1.cmp(limbs2))
+    }
+}
+
+impl PartialOrd for RadixValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Compares two numbers, at least one of which has a radix prefix, by their value. The first
+/// characters of the numbers were already consumed, the rest is consumed from the iterators.
+fn cmp_radix_numbers<'a>(
+    (l, r): (Folded, Folded),
+    (radix1, radix2): (Option<u32>, Option<u32>),
+    iter1: &mut Peekable<Fold<'a>>,
+    iter2: &mut Peekable<Fold<'a>>,
+) -> Ordering {
+    let digits1 = radix_digits(l, radix1, iter1);
+    let digits2 = radix_digits(r, radix2, iter2);
+    let (len1, len2) = (digits1.clone().count(), digits2.clone().count());
+
+    // a character that is a number of its own is compared by its value, like in
+    // `cmp_ascii_digits`; only the other number can have a prefix
+    let cmp_value =
+        |value, digits, radix: Option<u32>| match RadixValue::new(digits, radix.unwrap_or(10)) {
+            Some(number) => number.cmp_fraction(value).reverse(),
+            None => Ordering::Less,
+        };
+    let ordering = if let Some(value) = l.kind.single_value() {
+        cmp_value(value, digits2, radix2)
+    } else if let Some(value) = r.kind.single_value() {
+        cmp_value(value, digits1, radix1).reverse()
+    } else if radix1 == radix2 {
+        let digits1 = digits1.skip_while(|&d| d == 0);
+        let digits2 = digits2.skip_while(|&d| d == 0);
+        let len1 = digits1.clone().count();
+        len1.cmp(&digits2.clone().count())
+            .then_with(|| digits1.cmp(digits2))
+    } else {
+        let (radix1, radix2) = (radix1.unwrap_or(10), radix2.unwrap_or(10));
+        match (
+            RadixValue::new(digits1, radix1),
+            RadixValue::new(digits2, radix2),
+        ) {
+            (Some(lhs), Some(rhs)) => lhs.cmp(&rhs),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            // huge numbers are grouped by their radix, so the order is still consistent
+            (None, None) => radix1.cmp(&radix2),
+        }
+    };
+
+    // consume the prefixes and the digits after the first one
+    let consumed = |radix: Option<u32>, len: usize| if radix.is_some() { len + 1 } else { len - 1 };
+    for _ in 0..consumed(radix1, len1) {
+        iter1.next();
+    }
+    for _ in 0..consumed(radix2, len2) {
+        iter2.next();
+    }
+    ordering
+}
+
+/// Returns whether the folded character is a minus sign before the following number
+fn is_minus_sign(s: &str, f: Folded, next: Option<&Folded>) -> bool {
+    matches!(f.c, '-' | '\u{2212}')
+        && matches!(next, Some(n) if n.c.is_ascii_digit() && is_negative(s, n.index))
+}
+
+/// Returns whether the number starting at the given byte offset is preceded by a minus sign
+/// at the start of the string or after a non-alphanumeric character
+pub(crate) fn is_negative(s: &str, index: usize) -> bool {
+    let mut before = s[..index].chars().rev();
+    matches!(before.next(), Some('-') | Some('\u{2212}'))
+        && !matches!(before.next(), Some(c) if c.is_alphanumeric())
+}
+
+/// Returns the length of the non-empty `prefix` in `s`, if `s` starts with it, ignoring case
+fn match_prefix(prefix: &str, s: &str) -> Option<usize> {
+    if prefix.is_empty() {
+        return None;
+    }
+    let mut rest = s.chars();
+    let matches = prefix.chars().all(|p| match rest.next() {
+        Some(c) => c == p || c.to_lowercase().eq(p.to_lowercase()),
+        None => false,
+    });
+    if matches {
+        Some(s.len() - rest.as_str().len())
+    } else {
+        None
+    }
+}
+
+impl Compare for Collator<'_> {
+    #[inline]
+    fn compare(&self, lhs: &str, rhs: &str) -> Ordering {
+        self.cmp(lhs, rhs)
+    }
+}
+
+/// What decided the result of a comparison
+///
+/// This is returned by `Collator::cmp_with_position` and `cmp_with_position`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DecidedBy {
+    /// The strings differ after folding. The fields are the byte offsets of the first
+    /// differing characters in `lhs` and `rhs`.
+    ///
+    /// If the differing characters are part of a transliteration (e.g. the second `s` of
+    /// `ß`), this is the offset of the transliterated character. If numbers are compared
+    /// naturally and differ, these are the offsets of the numbers.
+    Difference {
+        /// The byte offset in the left string
+        lhs: usize,
+        /// The byte offset in the right string
+        rhs: usize,
+    },
+    /// One string is a prefix of the other after folding
+    Length,
+    /// The strings are equal after folding, and the tie-break decided the result
+    TieBreak,
+    /// The strings are equal
+    Equal,
+}
+
+impl DecidedBy {
+    fn at(lhs: Folded, rhs: Folded) -> Self {
+        DecidedBy::Difference {
+            lhs: lhs.index,
+            rhs: rhs.index,
+        }
+    }
+
+    /// Adds byte offsets to the position of the first differing characters
+    pub(crate) fn offset(self, i: usize, j: usize) -> Self {
+        match self {
+            DecidedBy::Difference { lhs, rhs } => DecidedBy::Difference {
+                lhs: lhs + i,
+                rhs: rhs + j,
+            },
+            decided => decided,
+        }
+    }
+
+    /// Returns the byte offsets of the first differing characters, if the strings differ
+    /// after folding
+    pub fn position(self) -> Option<(usize, usize)> {
+        match self {
+            DecidedBy::Difference { lhs, rhs } => Some((lhs, rhs)),
+            _ => None,
+        }
+    }
+}
+
+pub(crate) fn tie_break(ordering: Ordering) -> (Ordering, DecidedBy) {
+    match ordering {
+        Ordering::Equal => (ordering, DecidedBy::Equal),
+        _ => (ordering, DecidedBy::TieBreak),
+    }
+}
+
+/// Latin letters with an acute accent
+const ACUTE: &str = "áéíóúýćĺńŕśźǵḱḿṕẃǽǿÁÉÍÓÚÝĆĹŃŔŚŹǴḰḾṔẂǼǾ";
+/// Latin letters with a grave accent
+const GRAVE: &str = "àèìòùǹẁỳÀÈÌÒÙǸẀỲ";
+/// Latin letters with a circumflex
+const CIRCUMFLEX: &str = "âêîôûĉĝĥĵŝŵŷẑÂÊÎÔÛĈĜĤĴŜŴŶẐ";
+/// Latin letters with a diaeresis
+const DIAERESIS: &str = "äëïöüÿḧẅẍẗÄËÏÖÜŸḦẄẌ";
+
+/// Returns the weight of the accents of the first character of a string and the combining
+/// marks after it, in the order of `AccentOrder::Weighted`
+fn accent_weight(s: &str) -> u8 {
+    let mut chars = s.chars();
+    let mut weight = match chars.next() {
+        Some(c) if c.is_ascii() => 0,
+        Some(c) if ACUTE.contains(c) => 1,
+        Some(c) if GRAVE.contains(c) => 2,
+        Some(c) if CIRCUMFLEX.contains(c) => 3,
+        Some(c) if DIAERESIS.contains(c) => 4,
+        _ => 5,
+    };
+    for mark in chars.take_while(combining_diacritical) {
+        weight = match (weight, mark) {
+            (0, '\u{301}') => 1,
+            (0, '\u{300}') => 2,
+            (0, '\u{302}') => 3,
+            (0, '\u{308}') => 4,
+            _ => 5,
+        };
+    }
+    weight
+}
+
+/// Compares the case of two strings character by character: Uppercase letters come first,
+/// then characters without case, then lowercase letters. If `lowercase_first` is true,
+/// lowercase letters come first instead.
+fn cmp_case(
+    lhs: impl Iterator<Item = char>,
+    rhs: impl Iterator<Item = char>,
+    lowercase_first: bool,
+) -> Ordering {
+    let case = |c: char| match (c.is_uppercase(), c.is_lowercase()) {
+        (true, _) if lowercase_first => 2,
+        (true, _) => 0,
+        (_, true) if lowercase_first => 0,
+        (_, true) => 2,
+        _ => 1,
+    };
+    lhs.map(case).cmp(rhs.map(case))
+}
+
+/// Compares two strings with the given mode, and returns what decided the result.
+///
+/// This is equivalent to `Collator::from(mode).cmp_with_position(lhs, rhs)`.
+pub fn cmp_with_position(lhs: &str, rhs: &str, mode: SortMode) -> (Ordering, DecidedBy) {
+    Collator::from(mode).cmp_with_position(lhs, rhs)
+}
+
+/// Compares strings like `lexical_cmp`, and returns the byte offsets of the first characters
+/// in `lhs` and `rhs` whose transliterations differ
+///
+/// If a character is transliterated to several characters, such as `ß` to `ss`, the offset
+/// of that character is returned. If one string is a prefix of the other after
+/// transliteration, the offset in the shorter string is its length, and the offset in the
+/// longer string is that of its first character after the common prefix. If the strings are equal after
+/// transliteration, so the tie-break decides the result, `None` is returned.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::lexical_cmp_at;
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(lexical_cmp_at("Straße", "strasze"), (Ordering::Less, Some((4, 5))));
+/// assert_eq!(lexical_cmp_at("Straße", "strassen"), (Ordering::Less, Some((7, 7))));
+/// assert_eq!(lexical_cmp_at("Straße", "strasse"), (Ordering::Greater, None));
+/// # }
+/// ```
+pub fn lexical_cmp_at(lhs: &str, rhs: &str) -> (Ordering, Option<(usize, usize)>) {
+    cmp_at(lhs, rhs, SortMode::Lexical)
+}
+
+/// Compares strings like `natural_lexical_cmp`, and returns the byte offsets of the first
+/// characters in `lhs` and `rhs` whose transliterations differ, like `lexical_cmp_at`
+///
+/// If two numbers differ, also if only in their leading zeros, the offsets of the numbers
+/// are returned.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::natural_lexical_cmp_at;
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(natural_lexical_cmp_at("T-20", "t-5"), (Ordering::Greater, Some((2, 2))));
+/// assert_eq!(natural_lexical_cmp_at("T-5", "t-5"), (Ordering::Less, None));
+/// ```
+pub fn natural_lexical_cmp_at(lhs: &str, rhs: &str) -> (Ordering, Option<(usize, usize)>) {
+    cmp_at(lhs, rhs, SortMode::NaturalLexical)
+}
+
+fn cmp_at(lhs: &str, rhs: &str, mode: SortMode) -> (Ordering, Option<(usize, usize)>) {
+    let collator = Collator::from(mode);
+    match collator.cmp_with_position(lhs, rhs) {
+        (ordering, DecidedBy::Length) => {
+            // the first character of the longer string after the common prefix
+            let (i, j) = collator.common_prefix(lhs, rhs);
+            let next = |s: &str, i| collator.fold(s).find(|f| f.index >= i).map(|f| f.index);
+            match ordering {
+                Ordering::Less => (ordering, Some((lhs.len(), next(rhs, j).unwrap_or(j)))),
+                _ => (ordering, Some((next(lhs, i).unwrap_or(i), rhs.len()))),
+            }
+        }
+        (ordering, decided) => (ordering, decided.position()),
+    }
+}
+
+/// Returns the byte lengths of the longest common prefix of two strings, after folding them
+/// with the given mode. The lengths can differ, e.g. the common prefix of `"Maße"` and
+/// `"masse"` is `"Maß"` (4 bytes) and `"mass"` (4 bytes), and the common prefix of
+/// `"Maße"` and `"masz"` is `"Ma"` and `"ma"`.
+///
+/// The prefix always ends after a whole character of both strings: If only a part of a
+/// transliteration matches (such as one `s` of `ß`), that character isn't part of the prefix.
+/// Likewise, numbers are only part of the prefix if they are equal in natural modes. Skipped
+/// non-alphanumeric characters after the prefix aren't part of it.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::{common_lexical_prefix, SortMode};
+///
+/// let (a, b) = ("Résumé 2020.pdf", "resume 2021.pdf");
+/// let (i, j) = common_lexical_prefix(a, b, SortMode::Lexical);
+/// assert_eq!((&a[..i], &b[..j]), ("Résumé 202", "resume 202"));
+///
+/// let (i, j) = common_lexical_prefix(a, b, SortMode::NaturalLexical);
+/// assert_eq!((&a[..i], &b[..j]), ("Résumé ", "resume "));
+/// # }
+/// ```
+pub fn common_lexical_prefix(lhs: &str, rhs: &str, mode: SortMode) -> (usize, usize) {
+    Collator::from(mode).common_prefix(lhs, rhs)
+}
+
+/// Returns the longest prefix of the first string that all strings have in common after
+/// folding them with the given mode, like `common_lexical_prefix` does for two strings.
+/// Returns an empty string if the slice is empty.
+///
+/// The returned prefix is part of the first string. The length of the prefix in another
+/// string can be found by calling `common_lexical_prefix` with the prefix and that string.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::{common_lexical_prefix_of, SortMode};
+///
+/// let names = ["Straße 1", "STRASSE 12", "strasse 2"];
+/// assert_eq!(common_lexical_prefix_of(&names, SortMode::Lexical), "Straße ");
+///
+/// let names = ["Straße", "STRAND"];
+/// assert_eq!(common_lexical_prefix_of(&names, SortMode::Lexical), "Stra");
+/// # }
+/// ```
+pub fn common_lexical_prefix_of<S: AsRef<str>>(strings: &[S], mode: SortMode) -> &str {
+    let collator = Collator::from(mode);
+    let mut strings = strings.iter().map(AsRef::as_ref);
+    let first = strings.next().unwrap_or_default();
+    strings.fold(first, |prefix, s| {
+        let (len, _) = collator.common_prefix(prefix, s);
+        &prefix[..len]
+    })
+}
+
+/// The step where two folded strings first differ
+pub(crate) enum Step {
+    /// The characters differ
+    Chars(Folded, Folded),
+    /// The numbers starting with these characters differ
+    Numbers(Folded, Folded, Ordering),
+    /// The grapheme clusters starting with these characters differ
+    #[cfg(feature = "unicode-segmentation")]
+    Clusters(Folded, Folded, Ordering),
+    /// One string is a prefix of the other
+    Length(Ordering),
+    /// The strings are equal. `blank` is `true` if they are both empty.
+    Equal { blank: bool },
+}
+
+/// A character as it is compared by a `Collator`
+#[derive(Clone, Copy)]
+pub(crate) struct Folded {
+    pub(crate) c: char,
+    /// Whether the character originates from a non-ASCII character
+    pub(crate) non_ascii: bool,
+    /// The byte offset of the character it originates from
+    pub(crate) index: usize,
+    /// The byte offset after the character it originates from
+    pub(crate) end: usize,
+    /// The kind of digit the character originates from, in natural lexical comparisons
+    pub(crate) kind: DigitKind,
+    /// Whether the character is the first character of a grapheme cluster that is compared
+    /// as a unit. `index` and `end` are the byte offsets of the cluster.
+    #[cfg(feature = "unicode-segmentation")]
+    pub(crate) cluster: bool,
+}
+
+impl From<Folded> for char {
+    fn from(folded: Folded) -> char {
+        folded.c
+    }
+}
+
+impl NumberChar for Folded {
+    fn to_char(self) -> char {
+        self.c
+    }
+
+    fn kind(self) -> DigitKind {
+        self.kind
+    }
+}
+
+/// Iterator over the characters of a string as they are compared by a `Collator`
+#[derive(Clone)]
+pub(crate) struct Fold<'s> {
+    collator: &'s Collator<'s>,
+    source: &'s str,
+    chars: CharIndices<'s>,
+    /// The offset of `chars` in `source`
+    offset: usize,
+    replacement: Chars<'s>,
+    expansion: LexicalChar,
+    /// The digits of a CJK numeral with multipliers
+    numeral: DecimalDigits,
+    #[cfg(feature = "unicode-normalization")]
+    decomposition: Option<Decomposition<'s>>,
+    jamo: Jamo,
+    non_ascii: bool,
+    kind: DigitKind,
+    #[cfg(feature = "unicode-segmentation")]
+    cluster: bool,
+    index: usize,
+    end: usize,
+    at_word_start: bool,
+    /// Whether the last folded character was a digit
+    after_digit: bool,
+}
+
+/// Iterator over the words of an identifier and their byte offsets
+struct Words<'s> {
+    source: &'s str,
+    pos: usize,
+}
+
+impl<'s> Words<'s> {
+    fn new(source: &'s str) -> Self {
+        Words { source, pos: 0 }
+    }
+}
+
+impl<'s> Iterator for Words<'s> {
+    type Item = (usize, &'s str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.pos + self.source[self.pos..].find(char::is_alphanumeric)?;
+        let mut chars = self.source[start..].char_indices().peekable();
+        let mut end = self.source.len();
+        let mut prev = None;
+
+        while let Some((i, c)) = chars.next() {
+            let next_is_lower = matches!(chars.peek(), Some(&(_, n)) if n.is_lowercase());
+            let boundary = match prev {
+                _ if !c.is_alphanumeric() => true,
+                Some(p) if c.is_uppercase() => !char::is_uppercase(p) || next_is_lower,
+                _ => false,
+            };
+            if boundary {
+                end = start + i;
+                break;
+            }
+            prev = Some(c);
+        }
+        self.pos = end;
+        Some((start, &self.source[start..end]))
+    }
+}
+
+/// Iterator over the characters of a string, which may be normalized
+enum Normalized<'s> {
+    Chars(Chars<'s>),
+    /// The canonical or compatibility decomposition of the string
+    #[cfg(feature = "unicode-normalization")]
+    Nfd(Decompositions<Chars<'s>>),
+}
+
+impl Iterator for Normalized<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        match self {
+            Normalized::Chars(chars) => chars.next(),
+            #[cfg(feature = "unicode-normalization")]
+            Normalized::Nfd(chars) => chars.next(),
+        }
+    }
+}
+
+/// The canonical or compatibility decomposition of a character and the combining marks that
+/// follow it, which are reordered like in the decomposition of the whole string
+#[cfg(feature = "unicode-normalization")]
+type Decomposition<'s> = Decompositions<Chain<Once<char>, Chars<'s>>>;
+
+/// Returns the length of the combining marks at the start of a string, i.e. the characters
+/// whose decomposition starts with a character with a non-zero canonical combining class
+#[cfg(feature = "unicode-normalization")]
+fn combining_marks_len(s: &str, normalization: Normalization) -> usize {
+    let is_mark = |c: char| {
+        let mut first = None;
+        let push = |d| {
+            first.get_or_insert(d);
+        };
+        match normalization {
+            Normalization::Nfkc => decompose_compatible(c, push),
+            _ => decompose_canonical(c, push),
+        }
+        first.is_some_and(|d| canonical_combining_class(d) != 0)
+    };
+    s.char_indices()
+        .find(|&(_, c)| c.is_ascii() || !is_mark(c))
+        .map_or(s.len(), |(i, _)| i)
+}
+
+impl Fold<'_> {
+    /// Returns the next folded character, without skipping digit group separators
+    fn next_folded(&mut self) -> Option<Folded> {
+        loop {
+            if let Some(c) = self.expansion.next().or_else(|| self.numeral.next()) {
+                return Some(Folded {
+                    c,
+                    non_ascii: self.non_ascii,
+                    index: self.index,
+                    end: self.end,
+                    kind: self.kind,
+                    #[cfg(feature = "unicode-segmentation")]
+                    cluster: self.cluster,
+                });
+            }
+            if let Some(c) = self.replacement.next() {
+                self.expand(c);
+                continue;
+            }
+            #[cfg(feature = "unicode-normalization")]
+            if let Some(c) = self.decomposition.as_mut().and_then(Iterator::next) {
+                self.expand(c);
+                continue;
+            }
+            if let Some(c) = self.jamo.next() {
+                self.expand(c);
+                continue;
+            }
+
+            let (i, c) = self.chars.next()?;
+            if matches!(self.collator.ignore_chars, Some(ignore) if ignore(c)) {
+                continue;
+            }
+            if self.collator.control_policy == ControlPolicy::Ignore
+                && self.collator.is_stray_control(c)
+            {
+                continue;
+            }
+            let i = self.offset + i;
+            if self.at_word_start && !self.collator.token_replacements.is_empty() {
+                if let Some((replacement, len)) = self.collator.match_token(&self.source[i..]) {
+                    self.replacement = replacement.chars();
+                    self.skip_token(i, len);
+                    continue;
+                }
+            }
+            if self.collator.natural && self.collator.cjk_numerals {
+                if let Some((value, len)) = parse_cjk_numeral(&self.source[i..]) {
+                    self.numeral = DecimalDigits::new(value);
+                    self.non_ascii = true;
+                    self.kind = DigitKind::Normal;
+                    self.skip_token(i, len);
+                    continue;
+                }
+            }
+            if self.collator.natural && self.collator.month_names {
+                let after_letter =
+                    matches!(self.source[..i].chars().next_back(), Some(p) if p.is_alphabetic());
+                if let Some((month, len)) = month_name(&self.source[i..]).filter(|_| !after_letter)
+                {
+                    self.expansion = LexicalChar::from_char(first_digit(month));
+                    self.non_ascii = false;
+                    self.kind = DigitKind::Numeral(month);
+                    self.skip_token(i, len);
+                    continue;
+                }
+            }
+            if self.collator.natural && self.collator.ordinal_suffixes && self.after_digit {
+                if let Some(len) = ordinal_suffix(&self.source[i..]) {
+                    self.skip_token(i, len);
+                    continue;
+                }
+            }
+            if self.collator.lexical && !self.collator.overrides.is_empty() {
+                if let Some(&(_, s)) = self.collator.overrides.iter().find(|o| o.0 == c) {
+                    self.replacement = s.chars();
+                    self.skip_token(i, c.len_utf8());
+                    continue;
+                }
+            }
+            if self.collator.lexical {
+                if let Some(expansion) = self.collator.tailoring.expansion(c) {
+                    self.replacement = expansion.chars();
+                    self.skip_token(i, c.len_utf8());
+                    continue;
+                }
+            }
+            #[cfg(feature = "unicode-segmentation")]
+            if self.collator.graphemes && !self.collator.only_alnum && !c.is_alphanumeric() {
+                let len = self.source[i..].graphemes(true).next().map_or(0, str::len);
+                if len > c.len_utf8() {
+                    self.expansion = LexicalChar::from_char(c);
+                    self.non_ascii = true;
+                    self.kind = DigitKind::Normal;
+                    self.skip_token(i, len);
+                    self.cluster = true;
+                    self.at_word_start = true;
+                    continue;
+                }
+            }
+            self.at_word_start = !c.is_alphanumeric();
+            self.index = i;
+            self.end = i + c.len_utf8();
+            let c = match self.collator.lexical && self.collator.fold_kana {
+                true => fold_kana(c, self.source[..i].chars().rev()),
+                false => c,
+            };
+            if self.collator.lexical && self.collator.decompose_hangul {
+                if let Some(jamo) = Jamo::new(c) {
+                    self.jamo = jamo;
+                    continue;
+                }
+            }
+            #[cfg(feature = "unicode-normalization")]
+            if self.collator.normalized() {
+                // the marks after the character are decomposed with it, so they are sorted by
+                // their combining class
+                let end = self.end
+                    + combining_marks_len(&self.source[self.end..], self.collator.normalization);
+                let chars = core::iter::once(c).chain(self.source[self.end..end].chars());
+                self.decomposition = Some(match self.collator.normalization {
+                    Normalization::Nfkc => chars.nfkd(),
+                    _ => chars.nfd(),
+                });
+                self.offset = end;
+                self.chars = self.source[end..].char_indices();
+                self.end = end;
+                continue;
+            }
+            self.expand(c);
+        }
+    }
+
+    /// Continues after a token of `len` bytes at the byte offset `i`, which was replaced
+    fn skip_token(&mut self, i: usize, len: usize) {
+        self.offset = i + len;
+        self.chars = self.source[self.offset..].char_indices();
+        self.index = i;
+        self.end = self.offset;
+        self.at_word_start = false;
+        #[cfg(feature = "unicode-segmentation")]
+        {
+            self.cluster = false;
+        }
+    }
+
+    /// Sets the expansion of a character. In natural comparisons, a Roman numeral is expanded
+    /// to the first digit of its value if enabled, and in natural lexical comparisons, a vulgar
+    /// fraction is expanded to its numerator. Their values are compared via `kind`.
+    fn expand(&mut self, c: char) {
+        let c = match self.collator.control_policy {
+            ControlPolicy::Placeholder if self.collator.is_stray_control(c) => '\u{1A}',
+            _ => c,
+        };
+        let numeral = match roman_numeral(c) {
+            Some(value) if self.collator.natural && self.collator.roman_numerals => Some(value),
+            _ => None,
+        };
+        self.kind = match numeral {
+            Some(value) => DigitKind::Numeral(value),
+            None if self.collator.natural && self.collator.lexical => digit_kind(c),
+            None => DigitKind::Normal,
+        };
+        self.expansion = match self.kind {
+            DigitKind::Fraction(n, _) => LexicalChar::from_char((b'0' + n) as char),
+            DigitKind::Numeral(value) => LexicalChar::from_char(first_digit(value)),
+            _ => self.collator.map_char(c),
+        };
+        self.non_ascii = !c.is_ascii();
+        #[cfg(feature = "unicode-segmentation")]
+        {
+            self.cluster = false;
+        }
+    }
+
+    /// Returns whether the character is a digit group separator
+    fn is_group_separator(&self, c: char) -> bool {
+        self.collator.natural && self.collator.digit_group_separators.contains(&c)
+    }
+}
+
+impl Iterator for Fold<'_> {
+    type Item = Folded;
+
+    fn next(&mut self) -> Option<Folded> {
+        loop {
+            let mut folded = self.next_folded()?;
+            if self.after_digit && self.is_group_separator(folded.c) {
+                let next = self.clone().next_folded();
+                if matches!(next, Some(f) if f.c.is_ascii_digit()) {
+                    continue;
+                }
+            }
+            self.after_digit = folded.c.is_ascii_digit();
+            if self.collator.collapse_whitespace && folded.c.is_whitespace() {
+                let mut next = self.clone();
+                while matches!(next.next_folded(), Some(f) if f.c.is_whitespace()) {
+                    *self = next.clone();
+                }
+                folded.c = ' ';
+            }
+            if let Some(&separator) = self.collator.separators.first() {
+                if self.collator.separators.contains(&folded.c) {
+                    return Some(Folded {
+                        c: separator,
+                        ..folded
+                    });
+                }
+            }
+            return Some(folded);
+        }
+    }
+}
+
+/// `Serialize` and `Deserialize` implementations for `Collator`, which require the `serde`
+/// feature
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::*;
+    use serde::de::{Deserialize, Deserializer};
+    use serde::ser::{Error, Serialize, Serializer};
+
+    /// The tailorings that can be serialized. German dictionary order is the same as no
+    /// tailoring, and Norwegian is the same as Danish.
+    #[derive(Clone, Copy, serde::Serialize, serde::Deserialize)]
+    #[serde(rename_all = "kebab-case")]
+    enum TailoringName {
+        GermanPhonebook,
+        Spanish,
+        Swedish,
+        Danish,
+    }
+
+    const TAILORINGS: [(TailoringName, Tailoring<'static>); 4] = [
+        (TailoringName::GermanPhonebook, Tailoring::GERMAN_PHONEBOOK),
+        (TailoringName::Spanish, Tailoring::SPANISH),
+        (TailoringName::Swedish, Tailoring::SWEDISH),
+        (TailoringName::Danish, Tailoring::DANISH),
+    ];
+
+    /// The serializable options of a `Collator`, named like the builder methods. Missing
+    /// options have their default value, and unknown options are ignored, so configurations
+    /// can be read by other versions of this crate.
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(default)]
+    struct Options {
+        lexical: bool,
+        natural: bool,
+        signed_numbers: bool,
+        decimal_numbers: bool,
+        decimal_separator: char,
+        radix_prefixes: bool,
+        cjk_numerals: bool,
+        roman_numerals: bool,
+        month_names: bool,
+        ordinal_suffixes: bool,
+        units: bool,
+        dotted_quads: bool,
+        durations: bool,
+        only_alnum: bool,
+        skipped_last: bool,
+        collapse_whitespace: bool,
+        group_non_alnum: bool,
+        numbers_last: bool,
+        symbols_last: bool,
+        emoji_last: bool,
+        identifier_words: bool,
+        transliterate_symbols: bool,
+        transliterate_cjk: bool,
+        fold_kana: bool,
+        decompose_hangul: bool,
+        controls: Placement,
+        control_policy: ControlPolicy,
+        nul_policy: NulPolicy,
+        ties: TieBreak,
+        leading_zeros: LeadingZeros,
+        group_by_script: bool,
+        accent_order: AccentOrder,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        tailoring: Option<TailoringName>,
+        #[cfg(feature = "unicode-normalization")]
+        normalize: Normalization,
+        #[cfg(feature = "unicode-segmentation")]
+        graphemes: bool,
+        #[cfg(feature = "emoji-names")]
+        emoji: EmojiOrder,
+    }
+
+    impl Default for Options {
+        fn default() -> Self {
+            Options::from(&Collator::new())
+        }
+    }
+
+    impl From<&Collator<'_>> for Options {
+        fn from(c: &Collator<'_>) -> Self {
+            Options {
+                lexical: c.lexical,
+                natural: c.natural,
+                signed_numbers: c.signed_numbers,
+                decimal_numbers: c.decimal_numbers,
+                decimal_separator: c.decimal_separator,
+                radix_prefixes: c.radix_prefixes,
+                cjk_numerals: c.cjk_numerals,
+                roman_numerals: c.roman_numerals,
+                month_names: c.month_names,
+                ordinal_suffixes: c.ordinal_suffixes,
+                units: c.units,
+                dotted_quads: c.dotted_quads,
+                durations: c.durations,
+                only_alnum: c.only_alnum,
+                skipped_last: c.skipped_last,
+                collapse_whitespace: c.collapse_whitespace,
+                group_non_alnum: c.group_non_alnum,
+                numbers_last: c.numbers_last,
+                symbols_last: c.symbols_last,
+                emoji_last: c.emoji_last,
+                identifier_words: c.identifier_words,
+                transliterate_symbols: c.transliterate_symbols,
+                transliterate_cjk: c.transliterate_cjk,
+                fold_kana: c.fold_kana,
+                decompose_hangul: c.decompose_hangul,
+                controls: c.controls,
+                control_policy: c.control_policy,
+                nul_policy: c.nul_policy,
+                ties: c.ties,
+                leading_zeros: c.leading_zeros,
+                group_by_script: c.group_by_script,
+                accent_order: c.accents,
+                tailoring: TAILORINGS
+                    .iter()
+                    .find(|(_, tailoring)| *tailoring == c.tailoring)
+                    .map(|&(name, _)| name),
+                #[cfg(feature = "unicode-normalization")]
+                normalize: c.normalization,
+                #[cfg(feature = "unicode-segmentation")]
+                graphemes: c.graphemes,
+                #[cfg(feature = "emoji-names")]
+                emoji: c.emoji,
+            }
+        }
+    }
+
+    impl From<Options> for Collator<'_> {
+        fn from(o: Options) -> Self {
+            let mut c = Collator::new();
+            c.lexical = o.lexical;
+            c.natural = o.natural;
+            c.signed_numbers = o.signed_numbers;
+            c.decimal_numbers = o.decimal_numbers;
+            c.decimal_separator = o.decimal_separator;
+            c.radix_prefixes = o.radix_prefixes;
+            c.cjk_numerals = o.cjk_numerals;
+            c.roman_numerals = o.roman_numerals;
+            c.month_names = o.month_names;
+            c.ordinal_suffixes = o.ordinal_suffixes;
+            c.units = o.units;
+            c.dotted_quads = o.dotted_quads;
+            c.durations = o.durations;
+            c.only_alnum = o.only_alnum;
+            c.skipped_last = o.skipped_last;
+            c.collapse_whitespace = o.collapse_whitespace;
+            c.group_non_alnum = o.group_non_alnum;
+            c.numbers_last = o.numbers_last;
+            c.symbols_last = o.symbols_last;
+            c.emoji_last = o.emoji_last;
+            c.identifier_words = o.identifier_words;
+            c.transliterate_symbols = o.transliterate_symbols;
+            c.transliterate_cjk = o.transliterate_cjk;
+            c.fold_kana = o.fold_kana;
+            c.decompose_hangul = o.decompose_hangul;
+            c.controls = o.controls;
+            c.control_policy = o.control_policy;
+            c.nul_policy = o.nul_policy;
+            c.ties = o.ties;
+            c.leading_zeros = o.leading_zeros;
+            c.group_by_script = o.group_by_script;
+            c.accents = o.accent_order;
+            if let Some(name) = o.tailoring {
+                c.tailoring = TAILORINGS[name as usize].1;
+            }
+            #[cfg(feature = "unicode-normalization")]
+            {
+                c.normalization = o.normalize;
+            }
+            #[cfg(feature = "unicode-segmentation")]
+            {
+                c.graphemes = o.graphemes;
+            }
+            #[cfg(feature = "emoji-names")]
+            {
+                c.emoji = o.emoji;
+            }
+            c
+        }
+    }
+
+    impl Collator<'_> {
+        /// Returns the name of the first option that is set, but can't be serialized
+        fn unserializable_option(&self) -> Option<&'static str> {
+            let tailoring = self.tailoring == Tailoring::GERMAN_DICTIONARY
+                || TAILORINGS.iter().any(|(_, t)| *t == self.tailoring);
+            [
+                (
+                    self.digit_group_separators.is_empty(),
+                    "digit_group_separators",
+                ),
+                (self.ignore_chars.is_none(), "ignore_chars"),
+                (self.separators.is_empty(), "separators"),
+                (self.transliterator.is_none(), "transliterator"),
+                (self.overrides.is_empty(), "overrides"),
+                (self.scripts == Script::DEFAULT_ORDER, "script_order"),
+                (tailoring, "tailoring"),
+                (self.token_replacements.is_empty(), "token_replacements"),
+                (self.prefixes.is_empty(), "strip_prefixes"),
+            ]
+            .iter()
+            .find(|(is_default, _)| !is_default)
+            .map(|&(_, name)| name)
+        }
+    }
+
+    /// Serializes the options as a struct. Options that borrow data or contain functions,
+    /// such as `separators` or `transliterator`, can't be serialized, so serialization fails if
+    /// one of them is set. Of the tailorings, only the predefined ones can be serialized.
+    impl Serialize for Collator<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            if let Some(option) = self.unserializable_option() {
+                return Err(S::Error::custom(format_args!(
+                    "the `{}` option of a `Collator` can't be serialized",
+                    option
+                )));
+            }
+            Options::from(self).serialize(serializer)
+        }
+    }
+
+    /// Deserializes the options written by the `Serialize` implementation. Missing options
+    /// have their default value, and unknown options are ignored.
+    impl<'de> Deserialize<'de> for Collator<'_> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            Options::deserialize(deserializer).map(Collator::from)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_modes() {
+        let strings = [
+            "", "a", "ä", "A", "-a", "10", "9", "a b", "ab", "T-5", "T5", "T-20", "ß", "ss", "09",
+            "009", "B-007", "B-7a", "B7", "٣", "١٢", "１２", "1٢", "x३", "½", "1½", "1⅔", "x1¹",
+            "x¹²",
+        ];
+        let modes = [
+            SortMode::Plain,
+            SortMode::OnlyAlnum,
+            SortMode::Lexical,
+            SortMode::LexicalOnlyAlnum,
+            SortMode::Natural,
+            SortMode::NaturalOnlyAlnum,
+            SortMode::NaturalLexical,
+            SortMode::NaturalLexicalOnlyAlnum,
+        ];
+        for &mode in &modes {
+            let collator = Collator::from(mode);
+            assert_eq!(collator.sort_mode(), mode);
+            for lhs in &strings {
+                for rhs in &strings {
+                    assert_eq!(
+                        collator.cmp(lhs, rhs),
+                        mode.function()(lhs, rhs),
+                        "{:?}: {:?} vs {:?}",
+                        mode,
+                        lhs,
+                        rhs
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_send_sync() {
+        fn assert_send_sync<T: Send + Sync + Copy>(_: &T) {}
+
+        struct Toy;
+        impl Transliterate for Toy {
+            fn transliterate(&self, c: char) -> crate::iter::Mapped {
+                crate::iter::Mapped::Char(c)
+            }
+        }
+        assert_send_sync(&Collator::new());
+        assert_send_sync(&Collator::new().transliterator(&Toy).separators(&['-']));
+    }
+
+    #[test]
+    fn test_token_replacements() {
+        let replacements = [("St", "Saint"), ("Dr", "Doctor"), ("Mt", "Mount")];
+        let collator = Collator::new().token_replacements(&replacements);
+        let ordered = |lhs: &str, rhs: &str| {
+            assert_eq!(
+                collator.cmp(lhs, rhs),
+                Ordering::Less,
+                "{:?} < {:?}",
+                lhs,
+                rhs
+            );
+            assert_eq!(
+                collator.cmp(rhs, lhs),
+                Ordering::Greater,
+                "{:?} > {:?}",
+                rhs,
+                lhs
+            );
+        };
+
+        ordered("Saint Mark", "St. Mary");
+        ordered("St. Mary", "Saint Nicholas");
+        ordered("Saint Mary", "St. Mary");
+        ordered("Saint Mary", "st Mary");
+        ordered("Saint", "St");
+        ordered("Doctor No", "Dr. Who");
+        ordered("Dr. Who", "Dune");
+        ordered("Mount Everest", "Mt. Fuji");
+        ordered("Mt. Fuji", "Mountain");
+
+        // tokens only match at word boundaries
+        ordered("Saint Z", "Street");
+        ordered("Street", "Stz");
+        ordered("Best. Mary", "Bf");
+        ordered("Dr.Who", "Drum");
+        ordered("Stdout", "Stout");
+        ordered("The Saint", "The St.");
+        ordered("The St.", "The Saints");
+
+        // without replacements
+        let collator = Collator::new();
+        assert_eq!(
+            collator.cmp("St. Mary", "Saint Nicholas"),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn test_longest_token() {
+        let replacements = [("S", "South"), ("St", "Saint")];
+        let collator = Collator::new().token_replacements(&replacements);
+
+        assert_eq!(collator.cmp("St. X", "Saint X"), Ordering::Greater);
+        assert_eq!(collator.cmp("St. X", "Saint Y"), Ordering::Less);
+        assert_eq!(collator.cmp("S. X", "South X"), Ordering::Less);
+        assert_eq!(collator.cmp("S. X", "South Y"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_strip_prefixes() {
+        let prefixes = ["IMG_", "DSC_", "IMG", "DSC"];
+        let collator = Collator::new().natural(true).strip_prefixes(&prefixes);
+
+        let mut names = [
+            "IMG_1234.jpg",
+            "Beach.jpg",
+            "dsc_42.jpg",
+            "IMG_99.jpg",
+            "IMG1234.jpg",
+            "DSC_99.jpg",
+            "1000.jpg",
+            "img_1234.jpg",
+            "Imagine.jpg",
+        ];
+        names.sort_unstable_by(|lhs, rhs| collator.cmp(lhs, rhs));
+        assert_eq!(
+            names,
+            [
+                "dsc_42.jpg",
+                "DSC_99.jpg",
+                "IMG_99.jpg",
+                "1000.jpg",
+                "IMG_1234.jpg",
+                "img_1234.jpg",
+                "IMG1234.jpg",
+                "Beach.jpg",
+                "Imagine.jpg",
+            ]
+        );
+
+        // the longest prefix is stripped, only once
+        assert_eq!(collator.prefix_len("IMG_IMG_1"), 4);
+        assert_eq!(collator.prefix_len("img1"), 3);
+        assert_eq!(collator.prefix_len("IM"), 0);
+        assert_eq!(collator.cmp("IMG_IMG_1", "IMG_2"), Ordering::Greater);
+
+        // a string that consists only of a prefix is compared as an empty string
+        assert_eq!(collator.cmp("IMG_", "0"), Ordering::Less);
+        assert_eq!(collator.cmp("DSC_", "IMG_"), Ordering::Less);
+        assert_eq!(collator.cmp("IMG_", "IMG_"), Ordering::Equal);
+
+        // positions refer to the original strings
+        assert_eq!(
+            collator.cmp_with_position("IMG_12", "DSC_9"),
+            (Ordering::Greater, DecidedBy::Difference { lhs: 4, rhs: 4 }),
+        );
+        assert_eq!(
+            collator.cmp_with_position("IMG_1a", "1b"),
+            (Ordering::Less, DecidedBy::Difference { lhs: 5, rhs: 1 }),
+        );
+
+        // empty prefixes are ignored
+        let collator = Collator::new().strip_prefixes(&[""]);
+        assert_eq!(collator.cmp("b", "a"), Ordering::Greater);
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_common_prefix() {
+        let prefix = |lhs: &'static str, rhs: &'static str, mode| {
+            let (i, j) = common_lexical_prefix(lhs, rhs, mode);
+            (&lhs[..i], &rhs[..j])
+        };
+
+        // accents
+        assert_eq!(
+            prefix("Café au lait", "cafe noir", SortMode::Lexical),
+            ("Café ", "cafe ")
+        );
+        assert_eq!(prefix("Café", "cafe", SortMode::Plain), ("", ""));
+        assert_eq!(prefix("café", "cafe", SortMode::Plain), ("caf", "caf"));
+        assert_eq!(
+            prefix("Ärger", "argument", SortMode::Lexical),
+            ("Ärg", "arg")
+        );
+
+        // expansions only count if they match completely
+        assert_eq!(
+            prefix("Straße", "strasse", SortMode::Lexical),
+            ("Straße", "strasse")
+        );
+        assert_eq!(
+            prefix("Straße", "Strasbourg", SortMode::Lexical),
+            ("Stra", "Stra")
+        );
+        assert_eq!(prefix("ßa", "ssb", SortMode::Lexical), ("ß", "ss"));
+        assert_eq!(prefix("æon", "aeons", SortMode::Lexical), ("æon", "aeon"));
+        assert_eq!(prefix("æ", "a", SortMode::Lexical), ("", ""));
+
+        // digit runs
+        assert_eq!(
+            prefix("v1.2.10", "v1.2.11", SortMode::Lexical),
+            ("v1.2.1", "v1.2.1")
+        );
+        assert_eq!(
+            prefix("v1.2.10", "v1.2.11", SortMode::NaturalLexical),
+            ("v1.2.", "v1.2.")
+        );
+        assert_eq!(prefix("x12y", "x12z", SortMode::Natural), ("x12", "x12"));
+        assert_eq!(prefix("x12", "x123", SortMode::Natural), ("x", "x"));
+        assert_eq!(prefix("x12", "x012", SortMode::Natural), ("x", "x"));
+        assert_eq!(
+            prefix("x¹²a", "x12b", SortMode::NaturalLexical),
+            ("x¹²", "x12")
+        );
+        assert_eq!(prefix("x1¹", "x11", SortMode::NaturalLexical), ("x", "x"));
+        assert_eq!(prefix("x½a", "x1a", SortMode::NaturalLexical), ("x", "x"));
+
+        // skipped characters
+        assert_eq!(
+            prefix("a-b-c", "a_b_d", SortMode::LexicalOnlyAlnum),
+            ("a-b", "a_b")
+        );
+        assert_eq!(prefix("a-b", "ab", SortMode::OnlyAlnum), ("a-b", "ab"));
+
+        // disjoint and empty strings
+        assert_eq!(prefix("apple", "banana", SortMode::Lexical), ("", ""));
+        assert_eq!(prefix("", "banana", SortMode::Lexical), ("", ""));
+        assert_eq!(prefix("same", "same", SortMode::Plain), ("same", "same"));
+
+        // combining marks are folded to nothing, so they only count before a matching char
+        let (cafe, cafe_acute) = ("cafes", "cafe\u{301}s");
+        assert_eq!(
+            prefix(cafe_acute, cafe, SortMode::Lexical),
+            (cafe_acute, cafe)
+        );
+        assert_eq!(
+            prefix("cafe\u{301}", "cafe\u{300}", SortMode::Lexical),
+            ("cafe", "cafe")
+        );
+        assert_eq!(
+            prefix("cafe\u{301}", "cafe\u{300}", SortMode::Plain),
+            ("cafe", "cafe")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_common_prefix_of() {
+        assert_eq!(common_lexical_prefix_of::<&str>(&[], SortMode::Lexical), "");
+        assert_eq!(
+            common_lexical_prefix_of(&["Straße"], SortMode::Lexical),
+            "Straße"
+        );
+        assert_eq!(
+            common_lexical_prefix_of(&["Straße", "STRAND"], SortMode::Lexical),
+            "Stra"
+        );
+        assert_eq!(
+            common_lexical_prefix_of(&["Straße 1", "strasse 2", "STRASSE 3"], SortMode::Lexical),
+            "Straße "
+        );
+        assert_eq!(
+            common_lexical_prefix_of(&["ßa", "ssb", "sz"], SortMode::Lexical),
+            ""
+        );
+        assert_eq!(
+            common_lexical_prefix_of(
+                &["Café au lait", "cafe noir", "CAFE\u{301}"],
+                SortMode::Lexical
+            ),
+            "Café"
+        );
+        assert_eq!(
+            common_lexical_prefix_of(&["v1.2.10", "v1.2.11", "v1.2.1"], SortMode::Lexical),
+            "v1.2.1"
+        );
+        assert_eq!(
+            common_lexical_prefix_of(&["v1.2.10", "v1.2.11", "v1.2.1"], SortMode::NaturalLexical),
+            "v1.2."
+        );
+        assert_eq!(
+            common_lexical_prefix_of(&["a-b-c", "a_b_d", "ab"], SortMode::LexicalOnlyAlnum),
+            "a-b"
+        );
+    }
+
+    #[test]
+    fn test_controls() {
+        let strings = [
+            "a\tb", "a\nb", "a\u{1b}b", "a\u{85}b", "a b", "a-b", "a~b", "ab", "a→b",
+        ];
+        let sorted = |collator: Collator<'_>| {
+            let mut sorted = strings;
+            sorted.sort_by(|a, b| collator.cmp(a, b));
+            sorted
+        };
+
+        assert_eq!(
+            sorted(Collator::new()),
+            ["a\tb", "a\nb", "a\u{1b}b", "a b", "a-b", "a~b", "a\u{85}b", "a→b", "ab"],
+        );
+        assert_eq!(
+            sorted(Collator::new().controls(Placement::First)),
+            ["a\tb", "a\nb", "a\u{1b}b", "a\u{85}b", "a b", "a-b", "a~b", "a→b", "ab"],
+        );
+        assert_eq!(
+            sorted(Collator::new().controls(Placement::Last)),
+            ["a b", "a-b", "a~b", "a→b", "ab", "a\tb", "a\nb", "a\u{1b}b", "a\u{85}b"],
+        );
+        assert_eq!(
+            sorted(Collator::new().lexical(false).controls(Placement::Last)),
+            ["a b", "a-b", "ab", "a~b", "a→b", "a\tb", "a\nb", "a\u{1b}b", "a\u{85}b"],
+        );
+        assert_eq!(
+            sorted(Collator::new().natural(true).controls(Placement::First)),
+            sorted(Collator::new().controls(Placement::First)),
+        );
+
+        // leading control characters
+        let collator = Collator::new().controls(Placement::Last);
+        assert_eq!(collator.cmp("\u{1b}[1mfoo", "zzz"), Ordering::Greater);
+        assert_eq!(
+            collator.cmp("\u{1b}[1mfoo", "\u{1b}[1mbar"),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn test_control_policy() {
+        let ignore = Collator::new().control_policy(ControlPolicy::Ignore);
+        let placeholder = Collator::new().control_policy(ControlPolicy::Placeholder);
+        let ties = |collator: Collator<'_>, lhs, rhs| {
+            collator.cmp_with_position(lhs, rhs).1 == DecidedBy::TieBreak
+        };
+        for &collator in &[ignore, ignore.natural(true), ignore.lexical(false)] {
+            assert!(ties(collator, "foo\0bar", "foobar"));
+            assert!(ties(collator, "foo\u{1b}bar", "foobar"));
+            assert!(ties(collator, "\u{7}foo\u{90}bar\u{9f}", "foo\0bar"));
+            assert!(!ties(collator, "foo\tbar", "foobar"));
+            assert!(!ties(collator, "foo\u{85}bar", "foobar"));
+        }
+
+        let sorted = [
+            "a\u{1b}b",
+            "ab",
+            "a\u{90}b",
+            "a\0\u{7}bc",
+            "abc",
+            "a\u{1b}bd",
+            "b",
+        ];
+        for (i, lhs) in sorted.iter().enumerate() {
+            for (j, rhs) in sorted.iter().enumerate() {
+                assert_eq!(ignore.cmp(lhs, rhs), i.cmp(&j), "{:?} {:?}", lhs, rhs);
+            }
+        }
+
+        // all control characters are equal, and sorted between newline and space
+        assert!(ties(placeholder, "foo\0bar", "foo\u{1b}bar"));
+        assert!(ties(placeholder, "foo\u{7}bar", "foo\u{90}bar"));
+        assert!(!ties(placeholder, "foo\u{1b}bar", "foobar"));
+        let sorted = [
+            "a\tz", "a\nz", "a\u{90}b", "a\0c", "a\u{1b}d", "a b", "a-b", "ab",
+        ];
+        for (i, lhs) in sorted.iter().enumerate() {
+            for (j, rhs) in sorted.iter().enumerate() {
+                assert_eq!(placeholder.cmp(lhs, rhs), i.cmp(&j), "{:?} {:?}", lhs, rhs);
+            }
+        }
+        let last = placeholder.controls(Placement::Last);
+        assert_eq!(last.cmp("a\u{90}b", "a~b"), Ordering::Greater);
+        assert_eq!(last.cmp("a\u{90}b", "a\u{1b}c"), Ordering::Less);
+
+        // NUL characters are handled by the NUL policy
+        let truncate = ignore.nul_policy(NulPolicy::Truncate);
+        assert_eq!(truncate.cmp("foo\0zzz", "foo\u{1b}a"), Ordering::Less);
+        assert_eq!(truncate.cmp("a\u{1b}c\0a", "ab\0z"), Ordering::Greater);
+        let sort_last = ignore.nul_policy(NulPolicy::SortLast);
+        assert_eq!(sort_last.cmp("foo\0", "foo\u{1b}bar"), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_nul_policy() {
+        let strings = [
+            "foo\0bar", "foo\0", "foo", "foo\tbar", "foo bar", "foobar", "foo\0abc",
+        ];
+        let sorted = |collator: Collator<'_>| {
+            let mut sorted = strings;
+            sorted.sort_by(|a, b| collator.cmp(a, b));
+            sorted
+        };
+
+        assert_eq!(
+            sorted(Collator::new()),
+            ["foo", "foo\0", "foo\0abc", "foo\0bar", "foo\tbar", "foo bar", "foobar"],
+        );
+        assert_eq!(
+            sorted(Collator::new().nul_policy(NulPolicy::Truncate)),
+            ["foo", "foo\0", "foo\0abc", "foo\0bar", "foo\tbar", "foo bar", "foobar"],
+        );
+        assert_eq!(
+            sorted(Collator::new().nul_policy(NulPolicy::SortLast)),
+            ["foo", "foo\tbar", "foo bar", "foobar", "foo\0", "foo\0abc", "foo\0bar"],
+        );
+        assert_eq!(
+            sorted(
+                Collator::new()
+                    .controls(Placement::Last)
+                    .nul_policy(NulPolicy::SortLast)
+            ),
+            ["foo", "foo bar", "foobar", "foo\tbar", "foo\0", "foo\0abc", "foo\0bar"],
+        );
+
+        // strings that are identical up to a NUL character
+        let collator = Collator::new().nul_policy(NulPolicy::Truncate);
+        assert_eq!(collator.cmp("foo\0bar", "foo\0baz"), Ordering::Less);
+        assert_eq!(collator.cmp("foo\0zzz", "fop"), Ordering::Less);
+        assert_eq!(collator.cmp("foo\0zzz", "foo!"), Ordering::Less);
+        assert_eq!(collator.cmp("foo\0zzz", "foo"), Ordering::Greater);
+        assert_eq!(collator.cmp("\0zzz", ""), Ordering::Greater);
+        assert_eq!(collator.cmp("\0zzz", "-"), Ordering::Less);
+
+        assert_eq!(collator.cmp("a\0~", "a\0b"), Ordering::Greater);
+        assert_eq!(Collator::new().cmp("a\0~", "a\0b"), Ordering::Less);
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_ties() {
+        let modes = [
+            SortMode::Plain,
+            SortMode::OnlyAlnum,
+            SortMode::Lexical,
+            SortMode::LexicalOnlyAlnum,
+            SortMode::Natural,
+            SortMode::NaturalOnlyAlnum,
+            SortMode::NaturalLexical,
+            SortMode::NaturalLexicalOnlyAlnum,
+        ];
+        let strings = [
+            "", "-", "--", "Foo", "fóò", "foo", "a-b", "ab", "x01", "x1", "x2",
+        ];
+        for &mode in &modes {
+            let collator = Collator::from(mode).ties(TieBreak::InputOrder);
+            for lhs in &strings {
+                for rhs in &strings {
+                    // only strings that are equal after folding are affected
+                    let expected = match collator.first_difference(lhs, rhs) {
+                        Step::Equal { .. } => Ordering::Equal,
+                        _ => mode.function()(lhs, rhs),
+                    };
+                    assert_eq!(collator.cmp(lhs, rhs), expected, "{:?}", mode);
+                }
+            }
+        }
+
+        let collator = Collator::new().ties(TieBreak::InputOrder);
+        assert_eq!(collator.cmp("Foo", "fóò"), Ordering::Equal);
+        assert_eq!(collator.cmp("½", "1/2"), Ordering::Equal);
+        assert_eq!(collator.cmp("Foo", "fop"), Ordering::Less);
+        let collator = collator.only_alnum(true);
+        assert_eq!(collator.cmp("a-b", "AB"), Ordering::Equal);
+        assert_eq!(collator.cmp("-", "--"), Ordering::Equal);
+        let collator = collator.nul_policy(NulPolicy::Truncate);
+        assert_eq!(collator.cmp("foo\0bar", "foo\0baz"), Ordering::Equal);
+        assert_eq!(
+            collator.cmp_with_position("foo", "Foo"),
+            (Ordering::Equal, DecidedBy::Equal)
+        );
+    }
+
+    #[test]
+    fn test_skipped_last() {
+        let collator = Collator::new().only_alnum(true);
+        assert_eq!(collator.cmp("foo-bar", "Foo.bar"), Ordering::Less);
+        assert_eq!(collator.cmp("Foo.bar", "foo-bar"), Ordering::Greater);
+        assert_eq!(collator.cmp("a-b", "a.B"), Ordering::Less);
+
+        let collator = collator.skipped_last(true);
+        assert_eq!(collator.cmp("foo-bar", "Foo.bar"), Ordering::Greater);
+        assert_eq!(collator.cmp("Foo.bar", "foo-bar"), Ordering::Less);
+        assert_eq!(collator.cmp("a-b", "a.B"), Ordering::Greater);
+        // the skipped characters only decide if everything else is equal
+        assert_eq!(collator.cmp("a-b", "a.b"), Ordering::Less);
+        assert_eq!(collator.cmp("a.b", "a-b"), Ordering::Greater);
+        assert_eq!(collator.cmp("ab", "a-b"), Ordering::Greater);
+        assert_eq!(collator.cmp("a-b", "a-b"), Ordering::Equal);
+        // accents are compared before case
+        assert_eq!(collator.cmp("A-e", "a.é"), Ordering::Less);
+        assert_eq!(collator.cmp("a-é", "A.e"), Ordering::Greater);
+        assert_eq!(collator.cmp("a-b", "a.c"), Ordering::Less);
+
+        let collator = Collator::from(SortMode::OnlyAlnum).skipped_last(true);
+        assert_eq!(collator.cmp("b-a", "B.a"), Ordering::Greater);
+        assert_eq!(collator.cmp("B-a", "b.a"), Ordering::Less);
+        assert_eq!(collator.cmp("b_a", "b.a"), Ordering::Greater);
+
+        // without only_alnum, the option has no effect
+        let collator = Collator::new().skipped_last(true);
+        assert_eq!(collator.cmp("foo-bar", "Foo.bar"), Ordering::Less);
+        assert_eq!(collator.cmp("a.b", "a-B"), Ordering::Greater);
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_accent_order() {
+        let collator = Collator::new().accent_order(AccentOrder::Weighted);
+        let sorted = [
+            "cote",
+            "cote\u{301}",
+            "cot\u{E9}",
+            "co\u{302}te",
+            "c\u{F4}te",
+            "co\u{302}te\u{301}",
+            "c\u{F4}t\u{E9}",
+        ];
+        for (i, lhs) in sorted.iter().enumerate() {
+            for (j, rhs) in sorted.iter().enumerate() {
+                assert_eq!(collator.cmp(lhs, rhs), i.cmp(&j), "{:?} vs {:?}", lhs, rhs);
+            }
+        }
+
+        let sorted = [
+            "a",
+            "a\u{301}",
+            "á",
+            "a\u{300}",
+            "à",
+            "â",
+            "a\u{308}",
+            "ä",
+            "a\u{308}\u{301}",
+            "a\u{30C}",
+            "ǎ",
+            "æ",
+        ];
+        for (i, lhs) in sorted.iter().enumerate() {
+            for (j, rhs) in sorted.iter().enumerate() {
+                assert_eq!(collator.cmp(lhs, rhs), i.cmp(&j), "{:?} vs {:?}", lhs, rhs);
+            }
+        }
+        assert_eq!(collator.cmp("résumé", "resumes"), Ordering::Less);
+        assert_eq!(collator.cmp("Ébène", "ébène"), Ordering::Less);
+        assert_eq!(collator.cmp("ébène", "Ebène"), Ordering::Greater);
+
+        // the default order, by code point
+        let collator = Collator::new();
+        assert_eq!(collator.cmp("à", "á"), Ordering::Less);
+        assert_eq!(collator.cmp("coté", "côte"), Ordering::Less);
+        assert_eq!(collator.cmp("a\u{301}", "à"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_case_first() {
+        let upper = Collator::new().ties(TieBreak::UppercaseFirst);
+        let lower = Collator::new().ties(TieBreak::LowercaseFirst);
+        assert_eq!(upper.cmp("Foo", "foo"), Ordering::Less);
+        assert_eq!(lower.cmp("Foo", "foo"), Ordering::Greater);
+        assert_eq!(upper.cmp("fOO", "Foo"), Ordering::Greater);
+        assert_eq!(lower.cmp("fOO", "Foo"), Ordering::Less);
+        assert_eq!(upper.cmp("Éclair", "éclair"), Ordering::Less);
+        assert_eq!(lower.cmp("Éclair", "éclair"), Ordering::Greater);
+        assert_eq!(lower.cmp("foo", "foo"), Ordering::Equal);
+
+        // accents and scripts are compared before the case
+        assert_eq!(lower.cmp("Arger", "ärger"), Ordering::Less);
+        assert_eq!(upper.cmp("arger", "Ärger"), Ordering::Less);
+        // strings that differ after folding are unaffected
+        assert_eq!(lower.cmp("Bar", "foo"), Ordering::Less);
+        assert_eq!(upper.cmp("bar", "Foo"), Ordering::Less);
+
+        let natural = lower.natural(true);
+        assert_eq!(natural.cmp("img_1", "IMG_1"), Ordering::Less);
+        assert_eq!(natural.cmp("IMG_1", "img_01"), Ordering::Less);
+        let only_alnum = lower.only_alnum(true);
+        assert_eq!(only_alnum.cmp("a-b", "A-B"), Ordering::Less);
+        assert_eq!(only_alnum.cmp("A-B", "ab"), Ordering::Less);
+        let truncate = upper.nul_policy(NulPolicy::Truncate);
+        assert_eq!(truncate.cmp("foo\0x", "foo\0X"), Ordering::Greater);
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_script_order() {
+        let strings = ["Анна", "Anna", "Аnna", "Άννα", "Աննա", "anna"];
+        let sorted = |collator: Collator<'_>| {
+            let mut sorted = strings;
+            sorted.sort_by(|a, b| collator.cmp(a, b));
+            sorted
+        };
+
+        assert_eq!(
+            sorted(Collator::new()),
+            ["Anna", "anna", "Άννα", "Аnna", "Анна", "Աննա"],
+        );
+        assert_eq!(
+            sorted(Collator::new().script_order(&[Script::Cyrillic, Script::Greek, Script::Latin])),
+            ["Аnna", "Анна", "Άννα", "Anna", "anna", "Աննա"],
+        );
+        assert_eq!(
+            sorted(Collator::new().script_order(&[Script::Other, Script::Greek])),
+            ["Աննա", "Άννα", "Anna", "anna", "Аnna", "Анна"],
+        );
+        assert_eq!(
+            sorted(Collator::new().script_order(&[])),
+            ["Anna", "anna", "Аnna", "Άννα", "Анна", "Աննա"],
+        );
+
+        assert_eq!(Script::of('ß'), Some(Script::Latin));
+        assert_eq!(Script::of('ς'), Some(Script::Greek));
+        assert_eq!(Script::of('ж'), Some(Script::Cyrillic));
+        assert_eq!(Script::of('北'), Some(Script::Han));
+        assert_eq!(Script::of('あ'), Some(Script::Other));
+        assert_eq!(Script::of('1'), None);
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_group_by_script() {
+        // Latin, Greek, Cyrillic and Han blocks, then strings of other scripts and strings
+        // without alphabetic characters
+        let sorted = [
+            "2 things",
+            "10 things",
+            "apple",
+            "\u{C9}clair",
+            "Zeus",
+            "\u{386}\u{3BB}\u{3C6}\u{3B1}",
+            "\u{392}\u{3AE}\u{3C4}\u{3B1} 2",
+            "\u{392}\u{3AE}\u{3C4}\u{3B1} 10",
+            "\u{3A9}\u{3BC}\u{3AD}\u{3B3}\u{3B1}",
+            "\u{3B1}\u{3BB}\u{3C6}\u{3B1}",
+            "\u{410}\u{43D}\u{43D}\u{430}",
+            "\u{42F}\u{43A}\u{43E}\u{432}",
+            "\u{430}\u{431}\u{432}",
+            "1 \u{4E0A}\u{6D77}",
+            "\u{5317}\u{4EAC}",
+            "\u{5D0}",
+            "\u{3042}\u{3044}",
+            "",
+            "7",
+            "42",
+        ];
+        let collator = Collator::new().natural(true).group_by_script(true);
+        for (i, lhs) in sorted.iter().enumerate() {
+            for (j, rhs) in sorted.iter().enumerate() {
+                assert_eq!(collator.cmp(lhs, rhs), i.cmp(&j), "{:?} vs {:?}", lhs, rhs);
+            }
+        }
+
+        assert_eq!(
+            collator.cmp_with_position("10 things", "\u{3B1}"),
+            (Ordering::Less, DecidedBy::Difference { lhs: 3, rhs: 0 })
+        );
+        // the script order applies to the groups
+        let collator = collator.script_order(&[Script::Cyrillic, Script::Latin]);
+        assert_eq!(collator.cmp("\u{42F}", "a"), Ordering::Less);
+        assert_eq!(collator.cmp("\u{3B1}", "a"), Ordering::Greater);
+        assert_eq!(collator.cmp("\u{3B1}", "\u{5D0}"), Ordering::Less);
+        assert_eq!(collator.cmp("\u{3B1}", "1"), Ordering::Less);
+    }
+
+    #[test]
+    #[cfg(feature = "unicode-normalization")]
+    fn test_normalization() {
+        let modes = [
+            SortMode::Plain,
+            SortMode::OnlyAlnum,
+            SortMode::Lexical,
+            SortMode::LexicalOnlyAlnum,
+            SortMode::Natural,
+            SortMode::NaturalOnlyAlnum,
+            SortMode::NaturalLexical,
+            SortMode::NaturalLexicalOnlyAlnum,
+        ];
+        // NFC and NFD forms
+        let pairs = [
+            ("caf\u{E9}", "cafe\u{301}"),
+            ("\u{C5}ngstr\u{F6}m", "A\u{30A}ngstro\u{308}m"),
+            ("\u{212B}", "\u{C5}"),
+            ("\u{1E69}", "s\u{323}\u{307}"),
+            ("\u{1E69}", "s\u{307}\u{323}"),
+            (
+                "\u{D55C}\u{AE00}",
+                "\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}",
+            ),
+            (
+                "\u{3AC}\u{3BB}\u{3C6}\u{3B1}",
+                "\u{3B1}\u{301}\u{3BB}\u{3C6}\u{3B1}",
+            ),
+            ("\u{1F71}", "\u{3AC}"),
+        ];
+        let others = [
+            "", "cafe", "cafes", "Angstrom", "s", "t", "\u{D55C}", "\u{D55D}", "\u{3B1}",
+        ];
+
+        for &mode in &modes {
+            let collator = Collator::from(mode).normalize(Normalization::Nfc);
+            for &(nfc, nfd) in &pairs {
+                assert_eq!(collator.cmp(nfc, nfd), Ordering::Equal, "{:?}", mode);
+                assert_ne!(Collator::from(mode).cmp(nfc, nfd), Ordering::Equal);
+                for other in &others {
+                    assert_eq!(
+                        collator.cmp(nfc, other),
+                        collator.cmp(nfd, other),
+                        "{:?} {:?} {:?}",
+                        nfc,
+                        other,
+                        mode,
+                    );
+                }
+            }
+        }
+
+        let collator = Collator::from(SortMode::Plain).normalize(Normalization::Nfc);
+        assert_eq!(collator.cmp("e\u{301}", "f"), Ordering::Less);
+        assert_eq!(collator.cmp("\u{E9}", "e\u{300}"), Ordering::Greater);
+        assert_eq!(collator.cmp("\u{E9}", "e"), Ordering::Greater);
+    }
+
+    #[test]
+    #[cfg(feature = "unicode-normalization")]
+    fn test_normalization_transitive() {
+        // combining marks in non-canonical order, also after a precomposed character
+        let strings = [
+            "\u{1E69}",
+            "s\u{307}\u{323}",
+            "s\u{323}\u{307}",
+            "s\u{307}\u{324}",
+            "s\u{324}\u{307}",
+            "\u{1E63}\u{307}",
+            "\u{1E61}\u{323}",
+            "\u{1E61}\u{324}",
+            "\u{1E69}\u{324}",
+            "s\u{307}\u{324}\u{323}",
+            "s\u{307}\u{323}\u{324}x",
+            "\u{1E69}x",
+            "sx",
+        ];
+        for &mode in SortMode::ALL.iter() {
+            for &normalization in &[Normalization::Nfc, Normalization::Nfkc] {
+                let collator = Collator::from(mode).normalize(normalization);
+                for a in &strings {
+                    for b in &strings {
+                        let ab = collator.cmp(a, b);
+                        assert_eq!(
+                            ab == Ordering::Equal,
+                            a.nfd().eq(b.nfd()),
+                            "{:?} {:?}",
+                            a,
+                            b
+                        );
+                        for c in &strings {
+                            let bc = collator.cmp(b, c);
+                            if ab == bc {
+                                assert_eq!(collator.cmp(a, c), ab, "{:?} {:?} {:?}", a, b, c);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "unicode-normalization")]
+    fn test_compatibility_normalization() {
+        let modes = [
+            SortMode::Plain,
+            SortMode::OnlyAlnum,
+            SortMode::Lexical,
+            SortMode::LexicalOnlyAlnum,
+            SortMode::Natural,
+            SortMode::NaturalOnlyAlnum,
+            SortMode::NaturalLexical,
+            SortMode::NaturalLexicalOnlyAlnum,
+        ];
+        // compatibility characters and their plain spellings, and NFC and NFD forms
+        let pairs = [
+            ("\u{FB01}le", "file"),
+            ("e\u{FB00}ort", "effort"),
+            ("\u{2460}", "1"),
+            ("\u{338F}", "kg"),
+            ("5\u{3392}", "5MHz"),
+            ("x\u{B2}", "x2"),
+            ("\u{FF26}\u{FF55}\u{FF4C}\u{FF4C}", "Full"),
+            ("\u{2168}", "IX"),
+            ("caf\u{E9}", "cafe\u{301}"),
+            ("\u{1E9B}\u{323}", "s\u{323}\u{307}"),
+        ];
+        let others = [
+            "", "1", "2", "10", "effort", "fil", "filf", "kg", "x1", "x3", "Fulm",
+        ];
+
+        for &mode in &modes {
+            let collator = Collator::from(mode).normalize(Normalization::Nfkc);
+            for &(compatible, plain) in &pairs {
+                assert_eq!(
+                    collator.cmp(compatible, plain),
+                    Ordering::Equal,
+                    "{:?}",
+                    mode
+                );
+                for other in &others {
+                    assert_eq!(
+                        collator.cmp(compatible, other),
+                        collator.cmp(plain, other),
+                        "{:?} {:?} {:?}",
+                        compatible,
+                        other,
+                        mode,
+                    );
+                }
+            }
+        }
+
+        // only canonically equivalent strings are equal with NFC
+        let collator = Collator::new().normalize(Normalization::Nfc);
+        assert_ne!(collator.cmp("\u{FB01}le", "file"), Ordering::Equal);
+        assert_eq!(collator.cmp("caf\u{E9}", "cafe\u{301}"), Ordering::Equal);
+
+        let natural = Collator::new().natural(true).normalize(Normalization::Nfkc);
+        assert_eq!(natural.cmp("\u{2468} Uhr", "10 Uhr"), Ordering::Less);
+        assert_eq!(natural.cmp("\u{2469}", "9"), Ordering::Greater);
+    }
+
+    #[test]
+    #[cfg(feature = "unicode-segmentation")]
+    fn test_graphemes() {
+        let modes = [
+            SortMode::Plain,
+            SortMode::Lexical,
+            SortMode::Natural,
+            SortMode::NaturalLexical,
+        ];
+        // flags, emoji sequences with zero-width joiners and skin tone modifiers, and the
+        // same emojis followed by plain text
+        let sorted = [
+            "\u{1F1E9}\u{1F1EA}",
+            "\u{1F1E9}\u{1F1EA} Berlin",
+            "\u{1F1E9}\u{1F1F0}",
+            "\u{1F1EB}\u{1F1F7}",
+            "\u{1F1EB}\u{1F1F7}\u{1F1E9}\u{1F1EA}",
+            "\u{1F468}",
+            "\u{1F468} x",
+            "\u{1F468}x",
+            "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F466}",
+            "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F466} x",
+            "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}",
+            "\u{1F468}\u{1F3FD}",
+            "\u{1F468}\u{1F3FD}\u{200D}\u{1F4BB}",
+        ];
+        for &mode in &modes {
+            let collator = Collator::from(mode).graphemes(true);
+            for (i, lhs) in sorted.iter().enumerate() {
+                for (j, rhs) in sorted.iter().enumerate() {
+                    assert_eq!(
+                        collator.cmp(lhs, rhs),
+                        i.cmp(&j),
+                        "{:?} vs {:?} {:?}",
+                        lhs,
+                        rhs,
+                        mode
+                    );
+                }
+            }
+        }
+
+        let collator = Collator::new().graphemes(true);
+        // the code points of a cluster aren't compared with the text after another cluster
+        assert_eq!(
+            Collator::new().cmp("a\u{1F468}b", "a\u{1F468}\u{200D}\u{1F469}"),
+            Ordering::Greater
+        );
+        assert_eq!(
+            collator.cmp("a\u{1F468}b", "a\u{1F468}\u{200D}\u{1F469}"),
+            Ordering::Less
+        );
+        assert_eq!(
+            collator.cmp_with_position("a\u{1F468}b", "a\u{1F468}\u{200D}\u{1F469}"),
+            (Ordering::Less, DecidedBy::Difference { lhs: 1, rhs: 1 })
+        );
+        // letters with combining marks are still transliterated
+        assert_eq!(
+            collator.cmp("e\u{301}t\u{E9}", "\u{E9}te\u{301}"),
+            Ordering::Less
+        );
+        assert_eq!(collator.cmp("e\u{301}tz", "\u{E9}ta"), Ordering::Greater);
+        // clusters are skipped if only alphanumeric characters are compared
+        let collator = collator.only_alnum(true);
+        assert_eq!(
+            collator.cmp("\u{1F1E9}\u{1F1EA}b", "\u{1F1EB}\u{1F1F7}a"),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "emoji-names")]
+    fn test_emoji_order() {
+        let strings = [
+            "🦄 unicorn",
+            "🎉🎉",
+            "🎉 party",
+            "☣ biohazard",
+            "👍🏽 ok",
+            "👍 ok",
+            "- dash",
+            "☕",
+            "🍕 pizza",
+            "abc",
+        ];
+        let sorted = |collator: Collator<'_>| {
+            let mut sorted = strings;
+            sorted.sort_by(|a, b| collator.cmp(a, b));
+            sorted
+        };
+
+        assert_eq!(
+            sorted(Collator::new()),
+            [
+                "- dash",
+                "☕",
+                "☣ biohazard",
+                "🍕 pizza",
+                "🎉 party",
+                "🎉🎉",
+                "👍 ok",
+                "👍🏽 ok",
+                "🦄 unicorn",
+                "abc",
+            ],
+        );
+        // hot beverage < party popper < pizza < thumbs up < unicorn
+        assert_eq!(
+            sorted(Collator::new().emoji(EmojiOrder::ByName)),
+            [
+                "- dash",
+                "☣ biohazard",
+                "☕",
+                "🎉 party",
+                "🎉🎉",
+                "🍕 pizza",
+                "👍 ok",
+                "👍🏽 ok",
+                "🦄 unicorn",
+                "abc",
+            ],
+        );
+        // emojis still come after all other non-alphanumeric characters
+        assert_eq!(
+            sorted(
+                Collator::new()
+                    .emoji(EmojiOrder::ByName)
+                    .group_non_alnum(true)
+            )[..3],
+            ["- dash", "☣ biohazard", "☕"],
+        );
+        // no effect on comparisons that aren't lexical
+        let collator = Collator::from(SortMode::Plain).emoji(EmojiOrder::ByName);
+        assert_eq!(collator.cmp("🦄", "🎉"), Ordering::Greater);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_identifier_words() {
+        let words = |s| Words::new(s).map(|(_, w)| w).collect::<Vec<_>>();
+        assert_eq!(words("parseHTTPResponse"), ["parse", "HTTP", "Response"]);
+        assert_eq!(words("HTTPServer"), ["HTTP", "Server"]);
+        assert_eq!(words("__init__"), ["init"]);
+        assert_eq!(words("utf8Decode"), ["utf8", "Decode"]);
+        assert_eq!(words("kebab-case--id"), ["kebab", "case", "id"]);
+        assert_eq!(words("ÄpfelÜber"), ["Äpfel", "Über"]);
+        assert_eq!(words("--"), Vec::<&str>::new());
+
+        let strings = [
+            "ParseHttpResponse",
+            "parse_json",
+            "parse-http-response",
+            "parser",
+            "parse",
+            "HTTPServer",
+            "parseHTTPResponse",
+            "http_server",
+            "parse_http_response",
+            "HttpServer",
+            "http-server",
+            "parse_http",
+            "httpServer",
+        ];
+        let collator = Collator::new().identifier_words(true);
+        let mut sorted = strings;
+        sorted.sort_by(|a, b| collator.cmp(a, b));
+        assert_eq!(
+            sorted,
+            [
+                "HTTPServer",
+                "HttpServer",
+                "httpServer",
+                "http-server",
+                "http_server",
+                "parse",
+                "parse_http",
+                "ParseHttpResponse",
+                "parseHTTPResponse",
+                "parse-http-response",
+                "parse_http_response",
+                "parse_json",
+                "parser",
+            ],
+        );
+
+        // without the option, the naming conventions aren't adjacent
+        sorted.sort_by(|a, b| Collator::new().cmp(a, b));
+        assert_eq!(sorted[..3], ["http-server", "http_server", "HTTPServer"]);
+
+        let collator = collator.natural(true);
+        assert_eq!(collator.cmp("item10Name", "item9_name"), Ordering::Greater);
+        assert_eq!(collator.cmp("item9Name", "item9_name"), Ordering::Less);
+        assert_eq!(
+            collator.cmp_with_position("get_user_id", "getUserName"),
+            (Ordering::Less, DecidedBy::Difference { lhs: 9, rhs: 7 }),
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_decompose_hangul() {
+        // given names in the order of a Korean dictionary
+        let sorted = [
+            "가영",
+            "가은",
+            "강민",
+            "건우",
+            "김민준",
+            "김서연",
+            "나리",
+            "다은",
+            "민수",
+            "민준",
+            "박지훈",
+            "서연",
+            "서윤",
+            "아린",
+            "예준",
+            "이서준",
+            "정민",
+            "조은",
+            "지호",
+            "최유진",
+            "하은",
+            "현우",
+        ];
+        for &mode in &[SortMode::Lexical, SortMode::NaturalLexicalOnlyAlnum] {
+            let collator = Collator::from(mode).decompose_hangul(true);
+            for (i, lhs) in sorted.iter().enumerate() {
+                for (j, rhs) in sorted.iter().enumerate() {
+                    let ordering = collator.cmp(lhs, rhs);
+                    assert_eq!(ordering, i.cmp(&j), "{:?} vs {:?} {:?}", lhs, rhs, mode);
+                }
+            }
+        }
+
+        // precomposed syllables, conjoining jamo and compatibility jamo are folded the same
+        let collator = Collator::new().decompose_hangul(true);
+        let folded = |s| collator.fold(s).map(char::from);
+        assert!(folded("한글").eq(folded("\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}")));
+        assert!(folded("한").eq(['\u{1112}', '\u{1161}', '\u{11AB}'].iter().copied()));
+        assert!(folded("ㄱㅏ").eq(folded("가")));
+        assert_eq!(collator.cmp("ㄱ", "가"), Ordering::Less);
+        assert_eq!(collator.cmp("ㄴ", "가"), Ordering::Greater);
+        assert_eq!(collator.cmp("가", "zebra"), Ordering::Greater);
+        assert_eq!(collator.cmp("가 10", "가 9"), Ordering::Less);
+        assert_eq!(
+            collator.natural(true).cmp("가 10", "가 9"),
+            Ordering::Greater
+        );
+        // with transliteration, initial ㅇ is silent
+        assert_eq!(Collator::new().cmp("아", "가"), Ordering::Less);
+        assert_eq!(collator.cmp("아", "가"), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_fold_kana() {
+        // in the same order with and without transliteration, and strings that are equal
+        // after folding are ordered by the tie-break
+        let sorted = [
+            "かあど",
+            "かーど",
+            "カアド",
+            "カード",
+            "カド",
+            "かな",
+            "きやく",
+            "キャク",
+            "キヤク",
+            "ｷｬｸ",
+        ];
+        for &collator in &[
+            Collator::new().fold_kana(true),
+            Collator::new().fold_kana(true).transliterate_cjk(false),
+            Collator::new().natural(true).fold_kana(true),
+        ] {
+            for (i, lhs) in sorted.iter().enumerate() {
+                for (j, rhs) in sorted.iter().enumerate() {
+                    assert_eq!(collator.cmp(lhs, rhs), i.cmp(&j), "{:?} vs {:?}", lhs, rhs);
+                }
+            }
+        }
+
+        let collator = Collator::new().fold_kana(true).transliterate_cjk(false);
+        // the prolonged sound mark is compared like the vowel of the kana before it
+        assert_eq!(
+            collator.cmp("ルール", "るうる"),
+            collator.cmp("ルウル", "るうる")
+        );
+        assert_eq!(collator.cmp("ルール", "るうるa"), Ordering::Less);
+        assert_eq!(collator.cmp("ルール", "るうら"), Ordering::Greater);
+        assert_eq!(collator.cmp("ラーメン", "らあめん"), Ordering::Greater);
+        assert_eq!(collator.cmp("ラーメン", "らあめんa"), Ordering::Less);
+        assert_eq!(collator.cmp("コーヒー", "こおひい"), Ordering::Greater);
+        assert_eq!(collator.cmp("コーヒー", "こおひいa"), Ordering::Less);
+        assert_eq!(collator.cmp("ンー", "んん"), Ordering::Greater);
+        assert_eq!(collator.cmp("ンー", "んんa"), Ordering::Less);
+        // without a kana before it, it is compared as itself
+        assert_eq!(collator.cmp("ー", "あ"), Ordering::Greater);
+        assert_eq!(collator.cmp("aー", "aあ"), Ordering::Greater);
+        // only lexical comparisons fold kana
+        let collator = collator.lexical(false);
+        assert_eq!(collator.cmp("カ", "き"), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_tailoring_german() {
+        let strings = [
+            "Mueller", "Müller", "Muller", "Mulder", "Götz", "Goetz", "Gotz", "Straße",
+        ];
+        let sorted = |collator: Collator<'_>| {
+            let mut sorted = strings;
+            sorted.sort_by(|a, b| collator.cmp(a, b));
+            sorted
+        };
+
+        let dictionary = [
+            "Goetz", "Gotz", "Götz", "Mueller", "Mulder", "Muller", "Müller", "Straße",
+        ];
+        assert_eq!(sorted(Collator::new()), dictionary);
+        assert_eq!(
+            sorted(Collator::new().tailoring(Tailoring::GERMAN_DICTIONARY)),
+            dictionary
+        );
+        assert_eq!(
+            sorted(Collator::new().tailoring(Tailoring::GERMAN_PHONEBOOK)),
+            ["Goetz", "Götz", "Gotz", "Mueller", "Müller", "Mulder", "Muller", "Straße"],
+        );
+
+        let collator = Collator::new().tailoring(Tailoring::GERMAN_PHONEBOOK);
+        assert_eq!(collator.cmp("ÄRGER", "aerger"), Ordering::Greater);
+        assert_eq!(collator.cmp("Ärger", "Aerger"), Ordering::Greater);
+        assert_eq!(collator.cmp("Ärger", "Aergez"), Ordering::Less);
+        assert_eq!(collator.cmp("Ärger", "Af"), Ordering::Less);
+        assert_eq!(
+            collator.cmp_with_position("xÖy", "xof"),
+            (Ordering::Less, DecidedBy::Difference { lhs: 1, rhs: 2 })
+        );
+        // only lexical comparisons are tailored
+        let collator = collator.lexical(false);
+        assert_eq!(collator.cmp("Ärger", "Af"), Ordering::Greater);
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_tailoring_spanish() {
+        let sorted = [
+            "cana", "canal", "canon", "caña", "cañon", "Cañón", "cañón", "cao", "nz", "Ñandú",
+            "ñu", "o",
+        ];
+        for &mode in &[SortMode::Lexical, SortMode::NaturalLexicalOnlyAlnum] {
+            let collator = Collator::from(mode).tailoring(Tailoring::SPANISH);
+            for (i, lhs) in sorted.iter().enumerate() {
+                for (j, rhs) in sorted.iter().enumerate() {
+                    let ordering = collator.cmp(lhs, rhs);
+                    assert_eq!(ordering, i.cmp(&j), "{:?} vs {:?} {:?}", lhs, rhs, mode);
+                }
+            }
+        }
+        assert_eq!(Collator::new().cmp("caña", "canon"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_tailoring_swedish() {
+        let sorted = [
+            "Ahlin",
+            "Berg",
+            "Vik",
+            "Wall",
+            "Zetterberg",
+            "Åberg",
+            "Åsa",
+            "Ängel",
+            "Ärlig",
+            "Öberg",
+            "Östlund",
+        ];
+        let collator = Collator::new().tailoring(Tailoring::SWEDISH);
+        for (i, lhs) in sorted.iter().enumerate() {
+            for (j, rhs) in sorted.iter().enumerate() {
+                assert_eq!(collator.cmp(lhs, rhs), i.cmp(&j), "{:?} vs {:?}", lhs, rhs);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_tailoring_nordic() {
+        let strings = [
+            "öl", "ärta", "zebra", "ål", "Ørsted", "æble", "Åland", "Arne", "ål 10",
+        ];
+        let sorted = |collator: Collator<'_>| {
+            let mut sorted = strings;
+            sorted.sort_by(|a, b| collator.cmp(a, b));
+            sorted
+        };
+        assert_eq!(
+            sorted(Collator::new()),
+            ["æble", "ål", "ål 10", "Åland", "Arne", "ärta", "öl", "Ørsted", "zebra"],
+        );
+        assert_eq!(
+            sorted(Collator::new().tailoring(Tailoring::SWEDISH)),
+            ["Arne", "zebra", "ål", "ål 10", "Åland", "æble", "ärta", "öl", "Ørsted"],
+        );
+        assert_eq!(
+            sorted(Collator::new().tailoring(Tailoring::DANISH)),
+            ["Arne", "zebra", "æble", "ärta", "öl", "Ørsted", "ål", "ål 10", "Åland"],
+        );
+        assert_eq!(Tailoring::NORWEGIAN, Tailoring::DANISH);
+
+        // the letters are still compared naturally and case-insensitively
+        let collator = Collator::new().natural(true).tailoring(Tailoring::SWEDISH);
+        let sorted = [
+            "zebra 10", "ål 2", "Ål 10", "ål 10", "ålder", "ÄRTA 1", "ärta 2", "öl",
+        ];
+        for (i, lhs) in sorted.iter().enumerate() {
+            for (j, rhs) in sorted.iter().enumerate() {
+                assert_eq!(collator.cmp(lhs, rhs), i.cmp(&j), "{:?} vs {:?}", lhs, rhs);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_tailoring_custom() {
+        // Polish and Danish letters that are compared like ASCII letters, and Croatian letters
+        // that are separate letters
+        let tailoring = Tailoring::new(&[('ł', "l"), ('ø', "oe")], &[('č', 'c'), ('ć', 'c')]);
+        let collator = Collator::new().tailoring(tailoring);
+        assert_eq!(collator.cmp("Łódź", "Lodz"), Ordering::Greater);
+        assert_eq!(collator.cmp("Łódź", "Lodza"), Ordering::Less);
+        assert_eq!(collator.cmp("Ørsted", "Oersted"), Ordering::Greater);
+        assert_eq!(collator.cmp("Ørsted", "Oerz"), Ordering::Less);
+        assert_eq!(collator.cmp("čaj", "cz"), Ordering::Greater);
+        assert_eq!(collator.cmp("čaj", "ćaj"), Ordering::Less);
+        assert_eq!(collator.cmp("Ćaj", "da"), Ordering::Less);
+        assert_eq!(tailoring.letters(), &[('č', 'c'), ('ć', 'c')]);
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_transliterate_cjk() {
+        let modes = [
+            SortMode::Lexical,
+            SortMode::LexicalOnlyAlnum,
+            SortMode::NaturalLexical,
+            SortMode::NaturalLexicalOnlyAlnum,
+        ];
+        for &mode in &modes {
+            // Han characters and kana sort after Latin letters by their code point
+            let sorted = [
+                "alpha",
+                "bear",
+                "bird",
+                "zebra",
+                "\u{3072}\u{3089}\u{304C}\u{306A}",
+                "\u{30AB}\u{30BF}\u{30AB}\u{30CA}",
+                "\u{5317}\u{4EAC}",
+                "\u{6771}\u{4EAC}",
+                "\u{FF76}\u{FF80}\u{FF76}\u{FF85}",
+            ];
+            let collator = Collator::from(mode).transliterate_cjk(false);
+            for (i, lhs) in sorted.iter().enumerate() {
+                for (j, rhs) in sorted.iter().enumerate() {
+                    let ordering = collator.cmp(lhs, rhs);
+                    assert_eq!(ordering, i.cmp(&j), "{:?} vs {:?} {:?}", lhs, rhs, mode);
+                }
+            }
+
+            let collator = Collator::from(mode);
+            assert_eq!(collator.cmp("\u{5317}\u{4EAC}", "bear"), Ordering::Greater);
+            assert_eq!(collator.cmp("\u{5317}\u{4EAC}", "bird"), Ordering::Less);
+        }
+
+        // other scripts are still transliterated
+        let collator = Collator::new().transliterate_cjk(false);
+        assert_eq!(
+            collator.cmp("\u{3B1}\u{3BB}\u{3C6}\u{3B1}", "alfb"),
+            Ordering::Less
+        );
+        assert_eq!(collator.cmp("\u{416}", "zh"), Ordering::Greater);
+        assert_eq!(collator.cmp("\u{416}", "zi"), Ordering::Less);
+        assert_eq!(collator.cmp("\u{D55C}", "han"), Ordering::Greater);
+        assert_eq!(collator.cmp("\u{D55C}", "hao"), Ordering::Less);
+        // as are CJK numerals in natural comparisons
+        let collator = collator.natural(true).cjk_numerals(true);
+        assert_eq!(
+            collator.cmp("\u{7B2C}\u{5341}\u{5DFB}", "\u{7B2C}9\u{5DFB}"),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn test_separators() {
+        let modes = [
+            SortMode::Plain,
+            SortMode::Natural,
+            SortMode::Lexical,
+            SortMode::NaturalLexical,
+        ];
+        for &mode in &modes {
+            let sorted = [
+                "a",
+                "a -_b",
+                "a-_ b",
+                "a_ -b",
+                "a b",
+                "a-b",
+                "a_b",
+                "a-b-c",
+                "a.b",
+                "ab",
+                "my crate",
+                "my-crate",
+                "my_crate",
+                "my-crates",
+                "my_crates",
+                "my.crate",
+                "myc",
+            ];
+            let collator = Collator::from(mode).separators(&[' ', '-', '_']);
+            for (i, lhs) in sorted.iter().enumerate() {
+                for (j, rhs) in sorted.iter().enumerate() {
+                    let ordering = collator.cmp(lhs, rhs);
+                    assert_eq!(ordering, i.cmp(&j), "{:?} vs {:?} {:?}", lhs, rhs, mode);
+                }
+            }
+        }
+
+        // the first separator is the canonical one
+        let collator = Collator::new().separators(&['_', '-']);
+        assert_eq!(collator.cmp("a-b", "a b"), Ordering::Greater);
+        assert_eq!(collator.cmp("a-b", "a^b"), Ordering::Greater);
+        assert_eq!(collator.cmp("a-b", "a`b"), Ordering::Less);
+
+        // digit group separators still apply
+        let collator = Collator::new()
+            .natural(true)
+            .digit_group_separators(&['_'])
+            .separators(&[' ', '_']);
+        assert_eq!(collator.cmp("1_000", "999"), Ordering::Greater);
+        assert_eq!(collator.cmp("a_b", "a b"), Ordering::Greater);
+        assert_eq!(collator.cmp("a_b", "a c"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_collapse_whitespace() {
+        let modes = [
+            SortMode::Plain,
+            SortMode::Natural,
+            SortMode::Lexical,
+            SortMode::NaturalLexical,
+        ];
+        for &mode in &modes {
+            let sorted = [
+                "",
+                "\t",
+                " ",
+                "  ",
+                " \u{A0}\n",
+                "\t a",
+                "\ta",
+                " a",
+                "  a ",
+                "a",
+                "a\t",
+                "a ",
+                "a \t",
+                "a -b",
+                "a\tb",
+                "a  b",
+                "a b",
+                "a \u{3000}b",
+                "a  b ",
+                "a-b",
+                "ab",
+            ];
+            let collator = Collator::from(mode).collapse_whitespace(true);
+            for (i, lhs) in sorted.iter().enumerate() {
+                for (j, rhs) in sorted.iter().enumerate() {
+                    let ordering = collator.cmp(lhs, rhs);
+                    assert_eq!(ordering, i.cmp(&j), "{:?} vs {:?} {:?}", lhs, rhs, mode);
+                }
+            }
+        }
+
+        let collator = Collator::from(SortMode::NaturalLexical).collapse_whitespace(true);
+        assert_eq!(collator.cmp("a \t 10", "a 9"), Ordering::Greater);
+        assert_eq!(collator.cmp("a \t 10", "a 11"), Ordering::Less);
+        // whitespace is still skipped with `only_alnum`
+        let collator = collator.only_alnum(true);
+        assert_eq!(collator.cmp("a \t b", "aa"), Ordering::Greater);
+        assert_eq!(collator.cmp("a \t b", "ac"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_ignore_chars() {
+        let modes = [
+            SortMode::Plain,
+            SortMode::Natural,
+            SortMode::Lexical,
+            SortMode::NaturalLexical,
+            SortMode::LexicalOnlyAlnum,
+            SortMode::NaturalLexicalOnlyAlnum,
+        ];
+        let strings = [
+            "",
+            "f5",
+            "f_5",
+            "f 5",
+            "f-5",
+            "f10",
+            "F5",
+            "f\u{E9}",
+            "fe",
+            "f\u{2469}",
+            "a'b",
+        ];
+        for &mode in &modes {
+            // skipping nothing is the same as the default
+            let collator = Collator::from(mode);
+            let nothing = collator.ignore_chars(|_| false);
+            for lhs in &strings {
+                for rhs in &strings {
+                    let ordering = collator.cmp(lhs, rhs);
+                    assert_eq!(
+                        nothing.cmp(lhs, rhs),
+                        ordering,
+                        "{:?} {:?} {:?}",
+                        lhs,
+                        rhs,
+                        mode
+                    );
+                }
+            }
+
+            // skipping everything non-ASCII
+            let ascii = collator.ignore_chars(|c| !c.is_ascii());
+            assert_eq!(ascii.cmp("a\u{E9}b", "ab"), Ordering::Greater, "{:?}", mode);
+            assert_eq!(ascii.cmp("a\u{E9}b", "ac"), Ordering::Less, "{:?}", mode);
+            assert_eq!(
+                ascii.cmp("\u{5317}\u{4EAC}", ""),
+                Ordering::Greater,
+                "{:?}",
+                mode
+            );
+            assert_eq!(
+                ascii.cmp("\u{5317}\u{4EAC}", "a"),
+                Ordering::Less,
+                "{:?}",
+                mode
+            );
+        }
+
+        let collator = Collator::new()
+            .natural(true)
+            .ignore_chars(|c| c == '_' || c == '\'');
+        assert_eq!(collator.cmp("f_5", "f5"), Ordering::Greater);
+        assert_eq!(collator.cmp("f_5", "f6"), Ordering::Less);
+        assert_eq!(collator.cmp("f_5", "f 5"), Ordering::Greater);
+        assert_eq!(collator.cmp("f 5", "f-5"), Ordering::Less);
+        assert_eq!(collator.cmp("f 5", "f10"), Ordering::Less);
+        assert_eq!(collator.cmp("f1_0", "f9"), Ordering::Greater);
+        assert_eq!(collator.cmp("it's", "its"), Ordering::Less);
+        assert_eq!(collator.cmp("it's", "ita"), Ordering::Greater);
+        assert_eq!(collator.cmp("it's", "itt"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_overrides() {
+        let overrides = [
+            ('\u{2116}', "no"),
+            ('\u{F8}', "oe"),
+            ('&', "and"),
+            ('\u{2469}', "10"),
+            ('\u{2461}', "2"),
+            ('_', ""),
+        ];
+        let collator = Collator::new().overrides(&overrides);
+        assert_eq!(collator.cmp("\u{2116} 5", "no 5"), Ordering::Greater);
+        assert_eq!(collator.cmp("\u{2116} 5", "no 6"), Ordering::Less);
+        assert_eq!(collator.cmp("\u{F8}re", "odense"), Ordering::Greater);
+        assert_eq!(collator.cmp("\u{F8}re", "ohio"), Ordering::Less);
+        assert_eq!(collator.cmp("Rock & Roll", "Rock and Roll"), Ordering::Less);
+        assert_eq!(
+            collator.cmp("Rock & Roll", "Rock and Rol"),
+            Ordering::Greater
+        );
+        assert_eq!(collator.cmp("Rock & Roll", "Rock anda"), Ordering::Less);
+        assert_eq!(collator.cmp("a_c", "ab"), Ordering::Greater);
+        assert_eq!(collator.cmp("a_b", "ac"), Ordering::Less);
+        // overrides are matched exactly
+        assert_eq!(collator.cmp("\u{D8}re", "ohio"), Ordering::Greater);
+        // overrides don't apply in non-lexical comparisons
+        let plain = Collator::from(SortMode::Plain).overrides(&overrides);
+        assert_eq!(plain.cmp("\u{F8}", "z"), Ordering::Greater);
+
+        // overrides that expand to digits are part of numbers
+        let collator = collator.natural(true);
+        assert_eq!(collator.cmp("track \u{2469}", "track 9"), Ordering::Greater);
+        assert_eq!(collator.cmp("track \u{2469}", "track 11"), Ordering::Less);
+        assert_eq!(
+            collator.cmp("track \u{2469}", "track 10"),
+            Ordering::Greater
+        );
+        assert_eq!(collator.cmp("x\u{2461}3", "x22"), Ordering::Greater);
+        assert_eq!(collator.cmp("x\u{2461}3", "x24"), Ordering::Less);
+        assert_eq!(collator.cmp("x3\u{2461}", "x4"), Ordering::Greater);
+        assert_eq!(collator.cmp("x1_0", "x9"), Ordering::Greater);
+
+        let only_alnum = Collator::from(SortMode::NaturalLexicalOnlyAlnum).overrides(&overrides);
+        assert_eq!(
+            only_alnum.cmp("Rock & Roll", "Rockandrol"),
+            Ordering::Greater
+        );
+        assert_eq!(only_alnum.cmp("Rock & Roll", "rockandrolm"), Ordering::Less);
+        assert_eq!(only_alnum.cmp("\u{2469}", "9"), Ordering::Greater);
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_transliterator() {
+        use crate::iter::Mapped;
+
+        struct Toy;
+
+        impl Transliterate for Toy {
+            fn transliterate(&self, _: char) -> Mapped {
+                Mapped::Str("x")
+            }
+        }
+
+        let modes = [
+            SortMode::Lexical,
+            SortMode::LexicalOnlyAlnum,
+            SortMode::NaturalLexical,
+            SortMode::NaturalLexicalOnlyAlnum,
+        ];
+        for &mode in &modes {
+            let collator = Collator::from(mode).transliterator(&Toy);
+            assert_eq!(collator.cmp("\u{E9}a", "xb"), Ordering::Less, "{:?}", mode);
+            assert_eq!(
+                collator.cmp("\u{E9}c", "xb"),
+                Ordering::Greater,
+                "{:?}",
+                mode
+            );
+            assert_eq!(
+                collator.cmp("\u{416}", "w"),
+                Ordering::Greater,
+                "{:?}",
+                mode
+            );
+            assert_eq!(collator.cmp("\u{416}", "y"), Ordering::Less, "{:?}", mode);
+            assert_eq!(collator.cmp("\u{5317}", "\u{4EAC}"), Ordering::Greater);
+            // ASCII characters aren't passed to the backend
+            assert_eq!(collator.cmp("ab", "ac"), Ordering::Less, "{:?}", mode);
+
+            let collator = Collator::from(mode);
+            assert_eq!(collator.cmp("\u{E9}c", "xb"), Ordering::Less, "{:?}", mode);
+        }
+
+        let collator = Collator::from(SortMode::NaturalLexical).transliterator(&Toy);
+        assert_eq!(collator.cmp("\u{E4}10", "x9"), Ordering::Greater);
+        assert_eq!(collator.cmp("\u{E4}10", "y9"), Ordering::Less);
+        // other options still take precedence
+        let collator = collator.transliterate_cjk(false);
+        assert_eq!(collator.cmp("\u{5317}", "z"), Ordering::Greater);
+
+        struct Skip;
+
+        impl Transliterate for Skip {
+            fn transliterate(&self, _: char) -> Mapped {
+                Mapped::Skip
+            }
+        }
+
+        let collator = Collator::new().transliterator(&Skip);
+        assert_eq!(collator.cmp("b\u{E4}c", "bd"), Ordering::Less);
+        assert_eq!(collator.cmp("\u{E4}b", "a"), Ordering::Greater);
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_transliterate_symbols() {
+        let modes = [
+            SortMode::Plain,
+            SortMode::OnlyAlnum,
+            SortMode::Lexical,
+            SortMode::LexicalOnlyAlnum,
+            SortMode::Natural,
+            SortMode::NaturalOnlyAlnum,
+            SortMode::NaturalLexical,
+            SortMode::NaturalLexicalOnlyAlnum,
+        ];
+        let pairs = [
+            ("Tom & Jerry", "Tom and Jerry", "Tom andJerry"),
+            ("©2020", "(c)2020", "c2020"),
+            ("Foo™", "FooTM", "Footm"),
+            ("Bar℠", "BarSM", "Barsm"),
+            ("90°", "90deg", "90deg"),
+        ];
+        for &mode in &modes {
+            let collator = Collator::from(mode).transliterate_symbols(true);
+            for &(symbol, spelled, spelled_only_alnum) in &pairs {
+                let folded = |s| {
+                    collator
+                        .fold(s)
+                        .map(char::from)
+                        .eq(collator.fold(spelled).map(char::from))
+                };
+                let folded_only_alnum = |s| {
+                    collator
+                        .fold(s)
+                        .map(char::from)
+                        .eq(collator.fold(spelled_only_alnum).map(char::from))
+                };
+                match (mode.is_lexical(), mode.is_only_alnum()) {
+                    (true, false) => assert!(folded(symbol), "{:?} {:?}", symbol, mode),
+                    (true, true) => assert!(folded_only_alnum(symbol), "{:?} {:?}", symbol, mode),
+                    (false, _) => assert_eq!(
+                        collator.cmp(symbol, spelled),
+                        Collator::from(mode).cmp(symbol, spelled),
+                    ),
+                }
+            }
+        }
+
+        let collator = Collator::new().transliterate_symbols(true);
+        assert_eq!(collator.cmp("Tom & Jerry", "Tom Bombadil"), Ordering::Less);
+        assert_eq!(
+            Collator::new().cmp("Tom & Jerry", "Tom Bombadil"),
+            Ordering::Less
+        );
+        assert_eq!(collator.cmp("Tom & Jerry", "Tom Ancona"), Ordering::Greater);
+        assert_eq!(
+            Collator::new().cmp("Tom & Jerry", "Tom Ancona"),
+            Ordering::Less
+        );
+        // emojis and other ASCII characters aren't transliterated
+        assert_eq!(collator.cmp("🎉", ":tada:"), Ordering::Greater);
+        assert_eq!(collator.cmp("a+b", "a-b"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_group_non_alnum() {
+        let strings = [
+            "a→",
+            "a€",
+            "a+",
+            "a,",
+            "a 🎉",
+            "a🎉",
+            "a\u{3000}",
+            "a ",
+            "a«",
+            "a\t",
+            "ab",
+        ];
+        let sorted = |collator: Collator<'_>| {
+            let mut sorted = strings;
+            sorted.sort_by(|a, b| collator.cmp(a, b));
+            sorted
+        };
+
+        assert_eq!(
+            sorted(Collator::new()),
+            [
+                "a\t",
+                "a ",
+                "a 🎉",
+                "a+",
+                "a,",
+                "a«",
+                "a€",
+                "a→",
+                "a\u{3000}",
+                "a🎉",
+                "ab"
+            ],
+        );
+        assert_eq!(
+            sorted(Collator::new().group_non_alnum(true)),
+            [
+                "a ",
+                "a 🎉",
+                "a\u{3000}",
+                "a,",
+                "a«",
+                "a+",
+                "a€",
+                "a→",
+                "a🎉",
+                "a\t",
+                "ab"
+            ],
+        );
+        assert_eq!(
+            sorted(
+                Collator::new()
+                    .group_non_alnum(true)
+                    .controls(Placement::First)
+            ),
+            [
+                "a\t",
+                "a ",
+                "a 🎉",
+                "a\u{3000}",
+                "a,",
+                "a«",
+                "a+",
+                "a€",
+                "a→",
+                "a🎉",
+                "ab"
+            ],
+        );
+    }
+
+    #[test]
+    fn test_signed_numbers() {
+        let collator = Collator::new().natural(true).signed_numbers(true);
+        let sorted = |collator: Collator<'_>, strings: &[&'static str]| {
+            let mut strings = strings.to_vec();
+            strings.sort_by(|l, r| collator.cmp(l, r));
+            strings
+        };
+
+        assert_eq!(
+            sorted(
+                collator,
+                &["temp_3.csv", "temp_-5.csv", "temp_-12.csv", "temp_0.csv"]
+            ),
+            ["temp_-12.csv", "temp_-5.csv", "temp_0.csv", "temp_3.csv"],
+        );
+        assert_eq!(collator.cmp("-12", "-5"), Ordering::Less);
+        assert_eq!(collator.cmp("-5", "3"), Ordering::Less);
+        assert_eq!(collator.cmp("\u{2212}12", "-5"), Ordering::Less);
+        assert_eq!(collator.cmp("\u{2212}5", "3"), Ordering::Less);
+
+        // mixed numbers inside longer strings
+        assert_eq!(collator.cmp("delta_-3_x", "delta_2_x"), Ordering::Less);
+        assert_eq!(collator.cmp("delta_-3_x", "delta_-3_y"), Ordering::Less);
+        assert_eq!(collator.cmp("delta_-3_x", "delta_-20_x"), Ordering::Greater);
+        assert_eq!(collator.cmp("x -1 -2", "x -1 -10"), Ordering::Greater);
+
+        // a lone minus sign is punctuation
+        assert_eq!(collator.cmp("a_-", "a_-_"), Ordering::Less);
+        assert_eq!(collator.cmp("a_-b", "a_-5"), Ordering::Greater);
+        assert_eq!(collator.cmp("a_-x", "a_5"), Ordering::Less);
+
+        // a dash after an alphanumeric character is a separator
+        assert_eq!(collator.cmp("T-20", "T-5"), Ordering::Greater);
+        assert_eq!(collator.cmp("a-5", "a5"), Ordering::Less);
+        assert_eq!(collator.cmp("2-1", "2-3"), Ordering::Less);
+
+        // the minus signs are also recognized if they are skipped
+        let only_alnum = collator.only_alnum(true);
+        assert_eq!(only_alnum.cmp("t_-12", "t 5"), Ordering::Less);
+        assert_eq!(only_alnum.cmp("t_-12", "t -5"), Ordering::Less);
+        assert_eq!(only_alnum.cmp("t-12", "t 5"), Ordering::Greater);
+
+        // no effect without natural comparisons
+        let lexical = Collator::new().signed_numbers(true);
+        assert_eq!(lexical.cmp("-12", "-5"), Ordering::Less);
+        assert_eq!(lexical.cmp("-2", "-12"), Ordering::Greater);
+        assert_eq!(
+            Collator::new().natural(true).cmp("-12", "-5"),
+            Ordering::Greater
+        );
+
+        // the order is consistent in all modes
+        let strings = [
+            "",
+            "-",
+            "--",
+            "-0",
+            "0",
+            "-1",
+            "1",
+            "-10",
+            "10",
+            "\u{2212}1",
+            "\u{2212}10",
+            "a-1",
+            "a-10",
+            "a_-1",
+            "a_-10",
+            "a_1",
+            "a_",
+            "a_-",
+            "a_-x",
+            "a.1",
+            "a/1",
+            "ax",
+        ];
+        for &mode in &[
+            SortMode::Natural,
+            SortMode::NaturalOnlyAlnum,
+            SortMode::NaturalLexical,
+            SortMode::NaturalLexicalOnlyAlnum,
+        ] {
+            let collator = Collator::from(mode).signed_numbers(true);
+            let sorted = sorted(collator, &strings);
+            for (i, lhs) in sorted.iter().enumerate() {
+                for (j, rhs) in sorted.iter().enumerate() {
+                    let expected = i.cmp(&j);
+                    assert_eq!(
+                        collator.cmp(lhs, rhs),
+                        expected,
+                        "{:?} {} {}",
+                        mode,
+                        lhs,
+                        rhs
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_decimal_numbers() {
+        let collator = Collator::new().natural(true).decimal_numbers(true);
+
+        assert_eq!(collator.cmp("1.25", "1.5"), Ordering::Less);
+        assert_eq!(
+            Collator::new().natural(true).cmp("1.25", "1.5"),
+            Ordering::Greater
+        );
+        assert_eq!(
+            collator.cmp("spacer 1.5mm", "spacer 1.25mm"),
+            Ordering::Greater
+        );
+        assert_eq!(collator.cmp("1.05", "1.5"), Ordering::Less);
+        assert_eq!(collator.cmp("2.1", "10.01"), Ordering::Less);
+
+        // a missing fraction is zero
+        assert_eq!(collator.cmp("1mm", "1.5mm"), Ordering::Less);
+        assert_eq!(collator.cmp("2mm", "1.5mm"), Ordering::Greater);
+
+        // equal numbers are ordered by the tie-break
+        assert_eq!(collator.cmp("1.5", "1.50"), Ordering::Less);
+        assert!(matches!(
+            collator.first_difference("1.5", "1.50"),
+            Step::Equal { blank: false }
+        ));
+        assert!(matches!(
+            collator.first_difference("1.0mm", "1mm"),
+            Step::Equal { blank: false }
+        ));
+        let ties = collator.ties(TieBreak::InputOrder);
+        assert_eq!(ties.cmp("x 1.5", "x 1.50"), Ordering::Equal);
+
+        // a dot without digits isn't a decimal point
+        assert_eq!(collator.cmp("1.", "1"), Ordering::Greater);
+        assert_eq!(collator.cmp("1.", "1.0"), Ordering::Greater);
+        assert_eq!(collator.cmp("1.x", "1.5"), Ordering::Less);
+
+        // version numbers
+        assert_eq!(collator.cmp("1.2.10", "1.2.9"), Ordering::Greater);
+        assert_eq!(collator.cmp("1.10.2", "1.9.2"), Ordering::Greater);
+        assert_eq!(collator.cmp("v1.2.10.tar", "v1.2.9.tar"), Ordering::Greater);
+        assert_eq!(collator.cmp("1.2.3", "1.2.3.4"), Ordering::Less);
+        assert_eq!(collator.cmp("v1.10", "v1.9"), Ordering::Less);
+
+        // version numbers have no fractional part, so they aren't equal to decimal numbers
+        assert_eq!(collator.cmp("1.2", "1.2.0"), Ordering::Greater);
+        assert_eq!(collator.cmp("1.2", "1.3.0"), Ordering::Greater);
+        assert_eq!(collator.cmp("1", "1.2.0"), Ordering::Less);
+        assert_eq!(collator.cmp("1.0", "1.2.0"), Ordering::Less);
+        assert_eq!(collator.cmp("2", "1.2.0"), Ordering::Greater);
+
+        // negative decimal numbers
+        let signed = collator.signed_numbers(true);
+        assert_eq!(signed.cmp("-1.5", "-1.25"), Ordering::Less);
+        assert_eq!(signed.cmp("-1.5", "1.25"), Ordering::Less);
+
+        // no effect if the dots are skipped
+        assert_eq!(
+            collator.only_alnum(true).cmp("1.25", "1.5"),
+            Ordering::Greater
+        );
+
+        // the order is consistent
+        let strings = [
+            "", ".", "1", "1.", "1.0", "1.00", "1.5", "1.50", "1.25", "1.2.10", "1.2.9", "1.2.",
+            "1..5", "1.5.", "1.5x", "1x", "-1.5", "-1", "2", "10.01", "1.2.3.4", "x1.5", "1.2",
+            "1.2.0", "1.3.0", "1.20",
+        ];
+        for &collator in &[collator, signed, collator.lexical(false)] {
+            let mut sorted = strings.to_vec();
+            sorted.sort_by(|l, r| collator.cmp(l, r));
+            for (i, lhs) in sorted.iter().enumerate() {
+                for (j, rhs) in sorted.iter().enumerate() {
+                    assert_eq!(collator.cmp(lhs, rhs), i.cmp(&j), "{} {}", lhs, rhs);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_decimal_separator() {
+        // the cases are written with `.`, which is replaced by the separator
+        let cases = [
+            ("3.5 kg", "10.25 kg", Ordering::Less),
+            ("3.5 kg", "3.75 kg", Ordering::Less),
+            ("3.5 kg", "3.25 kg", Ordering::Greater),
+            ("3.5 kg", "3.50 kg", Ordering::Less),
+            ("3 kg", "3.5 kg", Ordering::Less),
+            ("1.2.10", "1.2.9", Ordering::Greater),
+        ];
+        for &(sep, other) in &[('.', ','), (',', '.')] {
+            let collator = Collator::new()
+                .natural(true)
+                .decimal_numbers(true)
+                .decimal_separator(sep);
+            let natural = Collator::new().natural(true);
+            for &(lhs, rhs, expected) in &cases {
+                let (lhs1, rhs1) = (
+                    lhs.replace('.', &sep.to_string()),
+                    rhs.replace('.', &sep.to_string()),
+                );
+                assert_eq!(collator.cmp(&lhs1, &rhs1), expected, "{} {}", lhs1, rhs1);
+
+                // the other character is ordinary punctuation
+                let lhs2 = lhs.replace('.', &other.to_string());
+                let rhs2 = rhs.replace('.', &other.to_string());
+                assert_eq!(
+                    collator.cmp(&lhs2, &rhs2),
+                    natural.cmp(&lhs2, &rhs2),
+                    "{} {}",
+                    lhs2,
+                    rhs2
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_digit_group_separators() {
+        let collator = Collator::new()
+            .natural(true)
+            .digit_group_separators(&[',', '_', '\u{a0}', '\u{2009}']);
+
+        assert_eq!(collator.cmp("1,000 items", "500 items"), Ordering::Greater);
+        assert_eq!(collator.cmp("1_000", "999"), Ordering::Greater);
+        assert_eq!(collator.cmp("1\u{a0}000", "999"), Ordering::Greater);
+        assert_eq!(collator.cmp("1\u{2009}000 kg", "999 kg"), Ordering::Greater);
+        assert_eq!(
+            Collator::new().natural(true).cmp("1,000", "500"),
+            Ordering::Less
+        );
+
+        // mixed separators
+        assert_eq!(collator.cmp("1,000_000", "999_999"), Ordering::Greater);
+        assert_eq!(collator.cmp("1,000_000", "1_000,001"), Ordering::Less);
+        assert!(matches!(
+            collator.first_difference("1,000,000", "1_000\u{2009}000"),
+            Step::Equal { blank: false }
+        ));
+        assert!(matches!(
+            collator.first_difference("x 1,000", "x 1000"),
+            Step::Equal { blank: false }
+        ));
+
+        // separators that aren't between two digits
+        assert_eq!(collator.cmp("1, 2", "1 2"), Ordering::Greater);
+        assert_eq!(collator.cmp("1, 2", "12"), Ordering::Less);
+        assert_eq!(collator.cmp("1,", "1"), Ordering::Greater);
+        assert_eq!(collator.cmp("1,,000", "2"), Ordering::Less);
+        assert_eq!(collator.cmp(",1", "1"), Ordering::Less);
+        assert_eq!(collator.cmp("a_1", "a1"), Ordering::Less);
+
+        // other characters aren't separators
+        assert_eq!(collator.cmp("1.000", "999"), Ordering::Less);
+
+        // no effect without natural comparisons
+        let lexical = Collator::new().digit_group_separators(&[',']);
+        assert_eq!(lexical.cmp("1,000", "500"), Ordering::Less);
+
+        // decimal numbers
+        let decimal = collator.decimal_numbers(true);
+        assert_eq!(decimal.cmp("1,000.5", "1000.25"), Ordering::Greater);
+        assert_eq!(decimal.cmp("1,000.5", "999.9"), Ordering::Greater);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_long_digit_runs() {
+        let nines = "9".repeat(1 << 20);
+        let zeros = "0".repeat(1 << 20);
+        let long = format!("x{}1", nines);
+        let longer = format!("x1{}0", zeros);
+        let padded = format!("x{}{}1", zeros, nines);
+
+        for &mode in &SortMode::ALL {
+            let collator = Collator::from(mode).natural(true);
+            let cmp = |lhs: &str, rhs: &str| collator.cmp(lhs, rhs);
+            assert_eq!(cmp(&long, &longer), Ordering::Less);
+            assert_eq!(cmp(&longer, &long), Ordering::Greater);
+            assert_eq!(cmp(&long, &padded), Ordering::Less);
+            assert_eq!(cmp(&padded, &format!("{}a", long)), Ordering::Less);
+            assert_eq!(
+                cmp(&format!("{}b", padded), &format!("{}a", long)),
+                Ordering::Greater
+            );
+        }
+
+        // runs that are compared like shorter runs
+        let digits = "1234567890".repeat(4);
+        let strings = [
+            digits.clone(),
+            format!("{}1", digits),
+            format!("0{}", digits),
+            format!("{}.5", digits),
+            format!("{}.25", digits),
+            format!("{},000", digits),
+            format!("{}_1", digits),
+            format!("{}\u{B2}", digits),
+            format!("-{}", digits),
+            format!("x{}a", digits),
+            format!("x{}B", digits),
+            "1".to_string(),
+            "x1".to_string(),
+        ];
+        let collators = [
+            Collator::new().natural(true),
+            Collator::new().natural(true).only_alnum(true),
+            Collator::new()
+                .natural(true)
+                .leading_zeros(LeadingZeros::MoreFirst),
+            Collator::new().natural(true).decimal_numbers(true),
+            Collator::new().natural(true).signed_numbers(true),
+            Collator::new().natural(true).digit_group_separators(&[',']),
+            Collator::new().natural(true).ignore_chars(|c| c == '_'),
+            Collator::new().natural(true).ignore_chars(|c| c == '0'),
+            Collator::new().natural(true).overrides(&[('1', "one")]),
+        ];
+        for collator in &collators {
+            let mut sorted = strings.to_vec();
+            sorted.sort_by(|l, r| collator.cmp(l, r));
+            for (i, lhs) in sorted.iter().enumerate() {
+                for (j, rhs) in sorted.iter().enumerate() {
+                    let expected = i.cmp(&j);
+                    assert_eq!(collator.cmp(lhs, rhs), expected, "{} {}", lhs, rhs);
+                    // the folded digits are compared one by one in short runs
+                    let (lhs, rhs) = (lhs.replace(&digits, "1234"), rhs.replace(&digits, "1234"));
+                    if lhs != rhs {
+                        assert_eq!(collator.cmp(&lhs, &rhs), expected, "{} {}", lhs, rhs);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_huge_radix_numbers() {
+        let collator = Collator::new().natural(true).radix_prefixes(true);
+
+        // numbers greater than 2^4096 are ordered by their radix
+        let huge_hex = format!("0x1{}", "0".repeat(1024));
+        let huge_decimal = format!("1{}", "0".repeat(1300));
+        let bigger_decimal = format!("2{}", "0".repeat(1300));
+        assert_eq!(collator.cmp(&huge_hex, &huge_decimal), Ordering::Greater);
+        assert_eq!(collator.cmp(&huge_decimal, &bigger_decimal), Ordering::Less);
+        assert_eq!(collator.cmp(&huge_hex, &bigger_decimal), Ordering::Greater);
+        assert_eq!(collator.cmp(&huge_hex, "0b1"), Ordering::Greater);
+        assert_eq!(collator.cmp(&huge_decimal, "0xffff"), Ordering::Greater);
+
+        // the largest numbers that are compared exactly
+        let max_hex = format!("0x{}", "f".repeat(1024));
+        let max_decimal = format!("1{}", "0".repeat(1232));
+        assert_eq!(collator.cmp(&max_hex, &max_decimal), Ordering::Greater);
+        assert_eq!(collator.cmp(&max_hex, &huge_decimal), Ordering::Less);
+    }
+
+    #[test]
+    fn test_radix_prefixes() {
+        let collator = Collator::new().natural(true).radix_prefixes(true);
+
+        assert_eq!(collator.cmp("reg_0x9", "reg_0xA"), Ordering::Less);
+        assert_eq!(collator.cmp("reg_0xA", "reg_0x10"), Ordering::Less);
+        assert_eq!(collator.cmp("reg_0xff", "reg_0x100"), Ordering::Less);
+        assert_eq!(collator.cmp("0XFF", "0x100"), Ordering::Less);
+        assert_eq!(collator.cmp("0o7", "0o10"), Ordering::Less);
+        assert_eq!(collator.cmp("0b11", "0b100"), Ordering::Less);
+        assert_eq!(collator.cmp("0x0010", "0x9"), Ordering::Greater);
+        assert_eq!(
+            Collator::new().natural(true).cmp("reg_0xA", "reg_0x10"),
+            Ordering::Greater
+        );
+
+        // mixed radixes are compared by their value
+        assert_eq!(collator.cmp("0x10", "20"), Ordering::Less);
+        assert_eq!(collator.cmp("0x10", "15"), Ordering::Greater);
+        assert_eq!(collator.cmp("0b101", "0o4"), Ordering::Greater);
+        assert_eq!(collator.cmp("0x10 a", "16 b"), Ordering::Less);
+        assert_eq!(collator.cmp("0x10 b", "16 a"), Ordering::Greater);
+        assert!(matches!(
+            collator.first_difference("0x10", "16"),
+            Step::Equal { blank: false }
+        ));
+
+        // no overflow
+        let big = "0x10000000000000000000000000000000000000000";
+        let bigger = "0x10000000000000000000000000000000000000001";
+        assert_eq!(collator.cmp(big, bigger), Ordering::Less);
+        assert_eq!(
+            collator.cmp(big, "0xfffffffffffffffffffffffffffffff"),
+            Ordering::Greater
+        );
+        assert_eq!(collator.cmp(big, "999999999"), Ordering::Greater);
+        assert_eq!(
+            collator.cmp(big, "99999999999999999999999999999999999999999999999999"),
+            Ordering::Less
+        );
+
+        // numbers that don't fit in a `u128` are compared exactly
+        let cycle = [
+            "0x10000000000003000000000000000000000000000 b",
+            "1461501637330902918203684832716283019655932542976 c",
+            "1461501637330902918203684832716283019655932542977 a",
+        ];
+        assert_eq!(collator.cmp(cycle[0], cycle[1]), Ordering::Greater);
+        assert_eq!(collator.cmp(cycle[1], cycle[2]), Ordering::Less);
+        assert_eq!(collator.cmp(cycle[2], cycle[0]), Ordering::Less);
+        assert!(matches!(
+            collator.first_difference(
+                "0x10000000000000000000000000000000000000000",
+                "1461501637330902918203684832716283019655932542976"
+            ),
+            Step::Equal { blank: false }
+        ));
+
+        // a prefix without a digit isn't a prefix, so the number is 0
+        assert_eq!(collator.cmp("0xg", "0x1"), Ordering::Less);
+        assert_eq!(collator.cmp("0xg", "0x0"), Ordering::Greater);
+        assert_eq!(collator.cmp("0b2", "0b1"), Ordering::Less);
+        assert_eq!(collator.cmp("0x", "1"), Ordering::Less);
+        assert_eq!(collator.cmp("1x10", "1x9"), Ordering::Greater);
+
+        // characters that are numbers of their own are compared by their value
+        assert_eq!(collator.cmp("0x9", "9"), Ordering::Less);
+        assert_eq!(collator.cmp("9", "⑽"), Ordering::Less);
+        assert_eq!(collator.cmp("0x9", "⑽"), Ordering::Less);
+        assert_eq!(collator.cmp("0x9", "½"), Ordering::Greater);
+        assert_eq!(collator.cmp("0x0", "½"), Ordering::Less);
+        assert_eq!(collator.cmp("0x10", "⑽"), Ordering::Greater);
+        assert_eq!(collator.cmp("⓪x1", "0x1"), Ordering::Less);
+        let numerals = collator.roman_numerals(true).cjk_numerals(true);
+        assert_eq!(numerals.cmp("0xA", "十"), Ordering::Less);
+        assert_eq!(numerals.cmp("十", "Ⅻ"), Ordering::Less);
+        assert_eq!(numerals.cmp("0xA", "Ⅻ"), Ordering::Less);
+        assert_eq!(numerals.cmp("0xD", "Ⅻ"), Ordering::Greater);
+        assert_eq!(numerals.cmp("0xC a", "Ⅻ b"), Ordering::Less);
+
+        // the order is consistent
+        let strings = [
+            "", "0", "0x", "0x0", "0x1", "0x9", "0xA", "0xa", "0x10", "0xg", "0b", "0b1", "0b10",
+            "0o17", "1", "2", "10", "16", "0x10a", "0x10 a", "16a", "a0x1", "9", "⑽", "½", "十",
+            "Ⅻ", "ⅫⅫ", "0xⅫ", "0x1²", "⓪x1",
+        ];
+        for &collator in &[
+            collator,
+            collator.lexical(false),
+            collator.only_alnum(true),
+            numerals,
+        ] {
+            let mut sorted = strings.to_vec();
+            sorted.sort_by(|l, r| collator.cmp(l, r));
+            for (i, lhs) in sorted.iter().enumerate() {
+                for (j, rhs) in sorted.iter().enumerate() {
+                    assert_eq!(collator.cmp(lhs, rhs), i.cmp(&j), "{} {}", lhs, rhs);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_cjk_numerals() {
+        let collator = Collator::new().natural(true).cjk_numerals(true);
+
+        assert_eq!(collator.cmp("第二巻", "第十巻"), Ordering::Less);
+        assert_eq!(collator.cmp("第十巻", "第十一巻"), Ordering::Less);
+        assert_eq!(collator.cmp("第十九巻", "第二十巻"), Ordering::Less);
+        assert_eq!(collator.cmp("第二十三巻", "第百巻"), Ordering::Less);
+        assert_eq!(collator.cmp("第九十九巻", "第百巻"), Ordering::Less);
+        assert_eq!(collator.cmp("二〇二三年", "二〇二四年"), Ordering::Less);
+        assert_eq!(collator.cmp("第二十三巻", "第23巻"), Ordering::Greater);
+        assert!(matches!(
+            collator.first_difference("第二十三巻", "第23巻"),
+            Step::Equal { blank: false }
+        ));
+        assert!(matches!(
+            collator.first_difference("二〇二三", "2023"),
+            Step::Equal { blank: false }
+        ));
+        assert_eq!(collator.cmp("第十巻", "第9巻"), Ordering::Greater);
+
+        // off by default, and without natural comparisons
+        assert_eq!(
+            Collator::new().natural(true).cmp("第二巻", "第十巻"),
+            Ordering::Less
+        );
+        assert_eq!(
+            Collator::new().natural(true).cmp("第四巻", "第十巻"),
+            Ordering::Greater
+        );
+        assert_eq!(
+            Collator::new().cjk_numerals(true).cmp("第四巻", "第十巻"),
+            Ordering::Greater
+        );
+
+        // the order is consistent
+        let strings = [
+            "",
+            "一",
+            "二",
+            "十",
+            "十一",
+            "二十",
+            "二十三",
+            "23",
+            "百",
+            "千",
+            "〇",
+            "0",
+            "二〇",
+            "十十",
+            "十二三",
+            "第三巻",
+            "第3巻",
+            "x",
+        ];
+        for &collator in &[collator, collator.lexical(false), collator.only_alnum(true)] {
+            let mut sorted = strings.to_vec();
+            sorted.sort_by(|l, r| collator.cmp(l, r));
+            for (i, lhs) in sorted.iter().enumerate() {
+                for (j, rhs) in sorted.iter().enumerate() {
+                    assert_eq!(collator.cmp(lhs, rhs), i.cmp(&j), "{} {}", lhs, rhs);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_roman_numerals() {
+        let collator = Collator::new().natural(true).roman_numerals(true);
+
+        assert_eq!(collator.cmp("Ⅱ", "Ⅹ"), Ordering::Less);
+        assert_eq!(collator.cmp("Ⅹ", "Ⅻ"), Ordering::Less);
+        assert_eq!(collator.cmp("ⅳ", "Ⅴ"), Ordering::Less);
+        assert_eq!(collator.cmp("Ⅻ", "Ⅼ"), Ordering::Less);
+        assert_eq!(collator.cmp("Ⅿ", "ↂ"), Ordering::Less);
+        assert_eq!(collator.cmp("Part Ⅻ", "Part 9"), Ordering::Greater);
+        assert_eq!(collator.cmp("Part Ⅻ", "Part 13"), Ordering::Less);
+        assert_eq!(collator.cmp("Part Ⅻ", "Part 012"), Ordering::Less);
+        assert!(matches!(
+            collator.first_difference("Part Ⅻ", "Part 12"),
+            Step::Equal { blank: false }
+        ));
+
+        // every character is a number of its own
+        assert_eq!(collator.cmp("ⅩⅡ", "Ⅻ"), Ordering::Less);
+        assert_eq!(collator.cmp("Ⅻ1", "Ⅻ2"), Ordering::Less);
+        assert_eq!(collator.cmp("1Ⅻ", "13"), Ordering::Less);
+        assert_eq!(collator.cmp("Ⅻ", "99999999999999999999"), Ordering::Less);
+
+        // ASCII letters aren't parsed
+        assert_eq!(collator.cmp("XII", "IX"), Ordering::Greater);
+
+        // off by default
+        assert_eq!(
+            Collator::new().natural(true).cmp("Ⅻ", "9"),
+            Ordering::Greater
+        );
+        assert_eq!(
+            Collator::new().natural(true).cmp("Ⅻ", "Ⅹ"),
+            Ordering::Greater
+        );
+        assert_eq!(
+            Collator::new().natural(true).cmp("Ⅻ", "Ⅼ"),
+            Ordering::Greater
+        );
+
+        // the order is consistent
+        let strings = [
+            "", "0", "1", "Ⅰ", "ⅰ", "Ⅱ", "9", "Ⅻ", "ⅻ", "12", "012", "13", "ⅩⅡ", "Ⅻ1", "1Ⅻ", "½",
+            "Ⅼ", "ↈ", "XII", "x",
+        ];
+        for &collator in &[collator, collator.lexical(false), collator.only_alnum(true)] {
+            let mut sorted = strings.to_vec();
+            sorted.sort_by(|l, r| collator.cmp(l, r));
+            for (i, lhs) in sorted.iter().enumerate() {
+                for (j, rhs) in sorted.iter().enumerate() {
+                    assert_eq!(collator.cmp(lhs, rhs), i.cmp(&j), "{} {}", lhs, rhs);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_month_names() {
+        let collator = Collator::new().natural(true).month_names(true);
+
+        assert_eq!(collator.cmp("Feb", "Mar"), Ordering::Less);
+        assert_eq!(collator.cmp("Mar", "Nov"), Ordering::Less);
+        assert_eq!(collator.cmp("Apr", "Aug"), Ordering::Less);
+        assert_eq!(collator.cmp("september", "OCT"), Ordering::Less);
+        assert_eq!(collator.cmp("Sept", "Oct"), Ordering::Less);
+        assert_eq!(
+            collator.cmp("report-Dec-2021.pdf", "report-Feb-2021.pdf"),
+            Ordering::Greater
+        );
+        assert_eq!(collator.cmp("Jan-2021", "Dec-2020"), Ordering::Less);
+        assert_eq!(collator.cmp("Jan 2021", "Jan 2020"), Ordering::Greater);
+        assert_eq!(collator.cmp("2021 Jan", "2020 Dec"), Ordering::Greater);
+        assert!(matches!(
+            collator.first_difference("Jan", "January"),
+            Step::Equal { blank: false }
+        ));
+
+        // a month is a number of its own
+        assert_eq!(collator.cmp("Feb", "3"), Ordering::Less);
+        assert_eq!(collator.cmp("Dec", "3"), Ordering::Greater);
+        assert_eq!(collator.cmp("May2", "May10"), Ordering::Less);
+        assert_eq!(collator.cmp("Oct1", "Oct10"), Ordering::Less);
+
+        // only whole words
+        assert_eq!(collator.cmp("Janet", "Feb"), Ordering::Greater);
+        assert_eq!(collator.cmp("Janet", "Jane"), Ordering::Greater);
+        assert_eq!(collator.cmp("xmay", "xjun"), Ordering::Greater);
+        assert_eq!(collator.cmp("Maybe", "Mar"), Ordering::Greater);
+
+        // off by default
+        let natural = Collator::new().natural(true);
+        assert_eq!(natural.cmp("Feb", "Apr"), Ordering::Greater);
+        assert_eq!(
+            Collator::new().month_names(true).cmp("Feb", "Apr"),
+            Ordering::Greater
+        );
+
+        // the order is consistent
+        let strings = [
+            "", "Jan", "jan", "January", "Feb", "Mar", "Dec", "Sept", "Sep", "1", "2", "12", "13",
+            "Janet", "Jan-2021", "Dec-2020", "May2", "May10", "x", "Mayx",
+        ];
+        for &collator in &[collator, collator.lexical(false), collator.only_alnum(true)] {
+            let mut sorted = strings.to_vec();
+            sorted.sort_by(|l, r| collator.cmp(l, r));
+            for (i, lhs) in sorted.iter().enumerate() {
+                for (j, rhs) in sorted.iter().enumerate() {
+                    assert_eq!(collator.cmp(lhs, rhs), i.cmp(&j), "{} {}", lhs, rhs);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_ordinal_suffixes() {
+        let collator = Collator::new().natural(true).ordinal_suffixes(true);
+
+        assert_eq!(collator.cmp("1st draft", "2nd draft"), Ordering::Less);
+        assert_eq!(collator.cmp("2nd draft", "10th draft"), Ordering::Less);
+        assert_eq!(collator.cmp("3rd draft", "21st draft"), Ordering::Less);
+        assert_eq!(collator.cmp("No.2nd b", "No.2 a"), Ordering::Greater);
+        assert_eq!(collator.cmp("No.2nd a", "No.2 b"), Ordering::Less);
+        assert_eq!(collator.cmp("2nd 5", "2 10"), Ordering::Less);
+        assert!(matches!(
+            collator.first_difference("2nd draft", "2 draft"),
+            Step::Equal { blank: false }
+        ));
+        assert!(matches!(
+            collator.first_difference("2ND", "2nd"),
+            Step::Equal { blank: false }
+        ));
+
+        // only directly after a number, and not followed by a letter
+        assert_eq!(collator.cmp("10thousand", "10 a"), Ordering::Greater);
+        assert_eq!(collator.cmp("2 nd", "2"), Ordering::Greater);
+        assert_eq!(collator.cmp("x-st", "x-"), Ordering::Greater);
+
+        // off by default
+        let natural = Collator::new().natural(true);
+        assert_eq!(natural.cmp("2nd b", "2 c"), Ordering::Greater);
+        assert_eq!(collator.cmp("2nd b", "2 c"), Ordering::Less);
+
+        // the order is consistent
+        let strings = [
+            "",
+            "1",
+            "1st",
+            "1 st",
+            "2",
+            "2nd",
+            "2ND",
+            "2nd draft",
+            "2 draft",
+            "3rd",
+            "10th",
+            "10thousand",
+            "21st",
+            "st",
+            "x1st",
+            "x1",
+            "x1 a",
+            "x1st b",
+        ];
+        for &collator in &[collator, collator.lexical(false), collator.only_alnum(true)] {
+            let mut sorted = strings.to_vec();
+            sorted.sort_by(|l, r| collator.cmp(l, r));
+            for (i, lhs) in sorted.iter().enumerate() {
+                for (j, rhs) in sorted.iter().enumerate() {
+                    assert_eq!(collator.cmp(lhs, rhs), i.cmp(&j), "{} {}", lhs, rhs);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_units() {
+        let collator = Collator::new().natural(true).units(true);
+
+        assert_eq!(collator.cmp("backup 900 MB", "backup 2 GB"), Ordering::Less);
+        assert_eq!(collator.cmp("1 KiB", "1000 B"), Ordering::Greater);
+        assert_eq!(collator.cmp("1 KiB", "1 kb"), Ordering::Greater);
+        assert_eq!(collator.cmp("0.5 TB", "499 gb"), Ordering::Greater);
+        assert_eq!(collator.cmp("2 GB a", "2 GB b"), Ordering::Less);
+        assert_eq!(collator.cmp("1 GB 900 MB", "1 GB 2 GB"), Ordering::Less);
+        assert_eq!(
+            collator.cmp("disk 1 TB, 10 GB", "disk 1 TB, 9 GB"),
+            Ordering::Greater
+        );
+
+        // equal sizes are ordered by the rest of the string, then by the tie-break
+        assert!(matches!(
+            collator.first_difference("1.5 GB", "1500 MB"),
+            Step::Equal { blank: false }
+        ));
+        assert!(matches!(
+            collator.first_difference("1 KiB", "1024B"),
+            Step::Equal { blank: false }
+        ));
+        assert_eq!(collator.cmp("1.5 GB", "1500 MB"), Ordering::Less);
+        assert_eq!(collator.cmp("1500 MB", "1.5 GB"), Ordering::Greater);
+        assert_eq!(collator.cmp("1500 MB b", "1.5 GB a"), Ordering::Greater);
+        assert_eq!(collator.cmp("1500 MB a", "1.5 GB b"), Ordering::Less);
+
+        // numbers without a unit are less than sizes, no matter their value
+        assert_eq!(collator.cmp("2 GB", "900"), Ordering::Greater);
+        assert_eq!(collator.cmp("2 GB", "2"), Ordering::Greater);
+        assert_eq!(collator.cmp("0 B", "900"), Ordering::Greater);
+        assert_eq!(collator.cmp("10 Bytes", "9 B"), Ordering::Less);
+        assert_eq!(collator.cmp("20 GBit", "900 MB"), Ordering::Less);
+
+        // the last part of a version number isn't a size
+        assert_eq!(collator.cmp("v1.2 GB", "v1.10 MB"), Ordering::Greater);
+        assert_eq!(collator.cmp("v1.2.30 MB", "v1.2.4 GB"), Ordering::Greater);
+        let decimal = collator.decimal_numbers(true);
+        assert_eq!(decimal.cmp("1.20 GB", "1.3 GB"), Ordering::Less);
+        assert_eq!(decimal.cmp("v1.2.30 MB", "v1.2.4 GB"), Ordering::Greater);
+
+        // off by default
+        let natural = Collator::new().natural(true);
+        assert_eq!(
+            natural.cmp("backup 900 MB", "backup 2 GB"),
+            Ordering::Greater
+        );
+
+        // the order is consistent, also when mixing numbers with and without units
+        let strings = [
+            "",
+            "0 B",
+            "1 B",
+            "1B",
+            "999 B",
+            "1 kb",
+            "1 KB",
+            "1.0 KB",
+            "1000 B",
+            "1 KiB",
+            "1024B",
+            "1.5 GB",
+            "1500 MB",
+            "1500MB",
+            "2 GB",
+            "900 MB",
+            "1 TB",
+            "1 TiB",
+            "backup 2 GB",
+            "backup 900 MB",
+            "backup",
+            "x",
+            "1 GB x",
+            "1 GB y",
+            "1000MB z",
+            "2",
+            "900",
+            "1500",
+            "10 Bytes",
+            "backup 5",
+            "backup 5000",
+            "1 GB 2",
+            "1 GB 3 MB",
+        ];
+        for &collator in &[collator, collator.lexical(false), collator.only_alnum(true)] {
+            let mut sorted = strings.to_vec();
+            sorted.sort_by(|l, r| collator.cmp(l, r));
+            for (i, lhs) in sorted.iter().enumerate() {
+                for (j, rhs) in sorted.iter().enumerate() {
+                    assert_eq!(collator.cmp(lhs, rhs), i.cmp(&j), "{} {}", lhs, rhs);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_dotted_quads() {
+        let collator = Collator::new().natural(true).dotted_quads(true);
+
+        assert_eq!(collator.cmp("10.0.0.2", "10.0.0.10"), Ordering::Less);
+        assert_eq!(collator.cmp("10.2.3.4", "10.10.1.1"), Ordering::Less);
+        assert_eq!(
+            collator.cmp("web-192.168.1.10-prod", "web-192.168.1.9-prod"),
+            Ordering::Greater
+        );
+        assert_eq!(
+            collator.cmp("web-192.168.1.10-prod", "web-192.168.1.10-dev"),
+            Ordering::Greater
+        );
+        assert_eq!(
+            collator.cmp("host 10.0.0.1:8080", "host 10.0.0.1:443"),
+            Ordering::Greater
+        );
+        assert_eq!(collator.cmp("10.0.0.1 2", "10.0.0.1 10"), Ordering::Less);
+        assert!(matches!(
+            collator.first_difference("010.0.0.1", "10.000.0.01"),
+            Step::Equal { blank: false }
+        ));
+
+        // the addresses are compared as a whole, even if dots are skipped
+        let alnum = collator.only_alnum(true);
+        assert_eq!(alnum.cmp("10.0.0.1", "9.99.0.1"), Ordering::Greater);
+        assert_eq!(alnum.cmp("1.2.3.45 a", "1.2.34.5 b"), Ordering::Less);
+        assert_eq!(alnum.cmp("1.2.3.4 5", "1.2.3.45"), Ordering::Less);
+        let natural = Collator::new().natural(true).only_alnum(true);
+        assert_eq!(natural.cmp("10.0.0.1", "9.99.0.1"), Ordering::Less);
+
+        // incomplete addresses are compared as usual
+        assert_eq!(collator.cmp("10.0.0abc", "10.0.0.1"), Ordering::Greater);
+        assert_eq!(collator.cmp("10.0.0", "10.0.0.1"), Ordering::Less);
+        assert_eq!(collator.cmp("1.2.3.4.5", "1.2.3.4"), Ordering::Greater);
+        assert_eq!(alnum.cmp("1.2.3.45.6", "1.2.3.4"), Ordering::Greater);
+        assert_eq!(collator.cmp("1.2.3.300", "1.2.3.4"), Ordering::Greater);
+        assert_eq!(alnum.cmp("v2 10.0.0", "v2 9.99.0.1"), Ordering::Less);
+
+        // the order is consistent
+        let strings = [
+            "",
+            "0.0.0.0",
+            "1.2.3.4",
+            "1.2.3.4 5",
+            "1.2.3.45",
+            "1.2.34.5",
+            "10.0.0.1",
+            "10.0.0.2",
+            "10.0.0.10",
+            "010.0.0.10",
+            "10.2.3.4",
+            "10.10.1.1",
+            "9.99.0.1",
+            "web-192.168.1.10-prod",
+            "web-192.168.1.9-prod",
+            "web-192.168.1.10-dev",
+            "web-",
+            "255.255.255.255",
+            "x",
+        ];
+        for &collator in &[collator, collator.lexical(false), alnum] {
+            let mut sorted = strings.to_vec();
+            sorted.sort_by(|l, r| collator.cmp(l, r));
+            for (i, lhs) in sorted.iter().enumerate() {
+                for (j, rhs) in sorted.iter().enumerate() {
+                    assert_eq!(collator.cmp(lhs, rhs), i.cmp(&j), "{} {}", lhs, rhs);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_durations() {
+        let collator = Collator::new().natural(true).durations(true);
+
+        assert_eq!(collator.cmp("9:59", "10:00"), Ordering::Less);
+        assert_eq!(collator.cmp("1:00:00", "61:00"), Ordering::Greater);
+        assert_eq!(collator.cmp("1:02:45", "59:59"), Ordering::Greater);
+        assert_eq!(collator.cmp("12:05", "1:30"), Ordering::Greater);
+        assert_eq!(collator.cmp("log 1:30 b", "log 1:30 a"), Ordering::Greater);
+        assert_eq!(collator.cmp("1:30 2", "1:30 10"), Ordering::Less);
+        assert!(matches!(
+            collator.first_difference("01:05", "1:05"),
+            Step::Equal { blank: false }
+        ));
+        assert_eq!(collator.cmp("01:05", "1:05"), Ordering::Greater);
+
+        // the fields are compared as a whole, even if colons are skipped
+        let alnum = collator.only_alnum(true);
+        assert_eq!(alnum.cmp("1:02:45", "59:59"), Ordering::Greater);
+        assert_eq!(alnum.cmp("1:30 5", "1:31"), Ordering::Less);
+        // `1:305` isn't a duration
+        assert_eq!(alnum.cmp("1:30 5", "1:305"), Ordering::Greater);
+        let natural = Collator::new().natural(true);
+        assert_eq!(natural.cmp("1:00:00", "61:00"), Ordering::Less);
+
+        // other numbers are less than durations, and are compared as usual
+        assert_eq!(collator.cmp("12:monkeys", "9:59"), Ordering::Less);
+        assert_eq!(collator.cmp("12:monkeys", "9:monkeys"), Ordering::Greater);
+        assert_eq!(collator.cmp("12:5", "12:05"), Ordering::Less);
+        assert_eq!(collator.cmp("1:00:00:00", "2:00:00"), Ordering::Less);
+        assert_eq!(collator.cmp("3:00:00:00", "2:00:00"), Ordering::Less);
+        assert_eq!(collator.cmp("1000:00", "1:00:00"), Ordering::Less);
+        assert_eq!(collator.cmp("1000:00", "999"), Ordering::Greater);
+
+        // a plain number is less than a duration, so this isn't a cycle
+        let (hours, minutes, number) = ("1:00:00", "61:00", "2");
+        assert_eq!(collator.cmp(hours, minutes), Ordering::Greater);
+        assert_eq!(collator.cmp(minutes, number), Ordering::Greater);
+        assert_eq!(collator.cmp(hours, number), Ordering::Greater);
+
+        // the order is consistent
+        let strings = [
+            "",
+            "0:00",
+            "0:59",
+            "1:00",
+            "01:00",
+            "1:30",
+            "9:59",
+            "10:00",
+            "12:05",
+            "59:59",
+            "61:00",
+            "100:00",
+            "0:00:00",
+            "1:00:00",
+            "1:02:45",
+            "01:02:45",
+            "1:30 a",
+            "1:30 b",
+            "log 1:30",
+            "log 12:05",
+            "log",
+            "x",
+            "2",
+            "61",
+            "1000:00",
+            "12:monkeys",
+            "3:00:00:00",
+        ];
+        for &collator in &[collator, collator.lexical(false), alnum] {
+            let mut sorted = strings.to_vec();
+            sorted.sort_by(|l, r| collator.cmp(l, r));
+            for (i, lhs) in sorted.iter().enumerate() {
+                for (j, rhs) in sorted.iter().enumerate() {
+                    assert_eq!(collator.cmp(lhs, rhs), i.cmp(&j), "{} {}", lhs, rhs);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_numbers_last() {
+        let collator = Collator::new().natural(true).numbers_last(true);
+
+        assert_eq!(collator.cmp("1st Choice", "Zoë"), Ordering::Greater);
+        assert_eq!(collator.cmp("2 Brothers", "10 Pin"), Ordering::Less);
+        assert_eq!(collator.cmp("x2", "x10"), Ordering::Less);
+        assert_eq!(collator.cmp("x10", "xa"), Ordering::Greater);
+        assert_eq!(collator.cmp("x½", "xa"), Ordering::Greater);
+        assert_eq!(collator.cmp("#1", "a"), Ordering::Less);
+        assert_eq!(collator.cmp("a", "b"), Ordering::Less);
+        assert_eq!(collator.lexical(false).cmp("1", "a"), Ordering::Less);
+
+        // off by default
+        assert_eq!(Collator::new().cmp("1st Choice", "Zoë"), Ordering::Less);
+
+        // the order is consistent
+        let strings = [
+            "",
+            "-",
+            "#1",
+            "1",
+            "1st Choice",
+            "2",
+            "2 Brothers",
+            "10",
+            "10 Pin",
+            "٣",
+            "½",
+            "a",
+            "A",
+            "a1",
+            "a2",
+            "a10",
+            "ab",
+            "a-b",
+            "Zoë",
+            "ß",
+            "x",
+            "x1",
+            "x-",
+            "\u{FFFD}",
+        ];
+        for &collator in &[
+            collator,
+            collator.natural(false),
+            collator.only_alnum(true),
+            collator.group_non_alnum(true),
+        ] {
+            let mut sorted = strings.to_vec();
+            sorted.sort_by(|l, r| collator.cmp(l, r));
+            for (i, lhs) in sorted.iter().enumerate() {
+                for (j, rhs) in sorted.iter().enumerate() {
+                    assert_eq!(collator.cmp(lhs, rhs), i.cmp(&j), "{} {}", lhs, rhs);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_emoji_last() {
+        let sorted = [
+            "",
+            " party",
+            "!party",
+            "#1",
+            "$5",
+            "(party)",
+            "\u{AB}party\u{BB}",
+            "1 party",
+            "party",
+            "party!",
+            "party2",
+            "party\u{1F389}",
+            "zebra",
+            "\u{A9} 2022",
+            "\u{20AC}5",
+            "\u{2192} next",
+            "\u{2500}\u{2500}",
+            "\u{2764}",
+            "\u{1F389}party",
+            "\u{1F600}",
+            "\u{FFFD}",
+        ];
+        let collator = Collator::new().emoji_last(true);
+        for &collator in &[collator, collator.natural(true)] {
+            for (i, lhs) in sorted.iter().enumerate() {
+                for (j, rhs) in sorted.iter().enumerate() {
+                    assert_eq!(collator.cmp(lhs, rhs), i.cmp(&j), "{:?} {:?}", lhs, rhs);
+                }
+            }
+        }
+
+        // off by default
+        let collator = Collator::new();
+        assert_eq!(collator.cmp("\u{1F389}party", "party"), Ordering::Less);
+        assert_eq!(collator.cmp("\u{1F389}party", "!party"), Ordering::Greater);
+        // no effect in non-lexical comparisons
+        let collator = collator.emoji_last(true).lexical(false);
+        assert_eq!(collator.cmp("\u{A9}", "\u{2018}"), Ordering::Less);
+        // with `symbols_last`, all non-alphanumeric characters sort last
+        let collator = Collator::new().emoji_last(true).symbols_last(true);
+        assert_eq!(collator.cmp("!party", "party"), Ordering::Greater);
+        assert_eq!(collator.cmp("\u{1F389}party", "!party"), Ordering::Greater);
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_symbols_last() {
+        let sorted = [
+            "",
+            "A",
+            "a",
+            "ä",
+            "ab",
+            "a1",
+            "a2",
+            "a10",
+            "a b",
+            "a-b",
+            "a-1",
+            "a_",
+            "ß",
+            "x",
+            "x\u{1F389}",
+            "z",
+            "\u{BD}",
+            "1",
+            "2",
+            "10",
+            " leading",
+            "!party",
+            "#1",
+            ".hidden",
+            "_scratch",
+            "\u{1F389}party",
+            "\u{FFFD}",
+        ];
+        let collator = Collator::from(SortMode::NaturalLexical)
+            .numbers_last(true)
+            .symbols_last(true);
+        for &collator in &[collator, collator.group_non_alnum(true)] {
+            for (i, lhs) in sorted.iter().enumerate() {
+                for (j, rhs) in sorted.iter().enumerate() {
+                    assert_eq!(collator.cmp(lhs, rhs), i.cmp(&j), "{:?} {:?}", lhs, rhs);
+                }
+            }
+        }
+
+        let collator = Collator::new().symbols_last(true);
+        assert_eq!(collator.cmp("readme", ".hidden"), Ordering::Less);
+        assert_eq!(collator.cmp("a1", "a-1"), Ordering::Less);
+        assert_eq!(collator.cmp("a 1", "a-1"), Ordering::Less);
+        assert_eq!(collator.cmp("1", "z"), Ordering::Less);
+        // off by default
+        assert_eq!(Collator::new().cmp("readme", ".hidden"), Ordering::Greater);
+        // no effect in non-lexical comparisons or if symbols are skipped
+        assert_eq!(collator.lexical(false).cmp("a", "."), Ordering::Greater);
+        assert_eq!(collator.only_alnum(true).cmp("_b", "a"), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_leading_zeros() {
+        let strings = [
+            "IMG_01", "IMG_2", "IMG_1", "IMG_001", "IMG_01a", "IMG_1b", "IMG_10",
+        ];
+        let sorted = |collator: Collator<'_>| {
+            let mut strings = strings;
+            strings.sort_by(|l, r| collator.cmp(l, r));
+            strings
+        };
+        let natural = Collator::new().natural(true);
+
+        assert_eq!(
+            sorted(natural),
+            ["IMG_1", "IMG_01", "IMG_001", "IMG_01a", "IMG_1b", "IMG_2", "IMG_10"],
+        );
+        assert_eq!(
+            sorted(natural.leading_zeros(LeadingZeros::MoreFirst)),
+            ["IMG_001", "IMG_01", "IMG_1", "IMG_01a", "IMG_1b", "IMG_2", "IMG_10"],
+        );
+        assert_eq!(
+            sorted(natural.lexical(false).leading_zeros(LeadingZeros::Ignore)),
+            ["IMG_001", "IMG_01", "IMG_1", "IMG_01a", "IMG_1b", "IMG_2", "IMG_10"],
+        );
+        let ignore = natural
+            .leading_zeros(LeadingZeros::Ignore)
+            .ties(TieBreak::InputOrder);
+        assert_eq!(ignore.cmp("IMG_01", "IMG_1"), Ordering::Equal);
+        assert_eq!(
+            natural.ties(TieBreak::InputOrder).cmp("IMG_01", "IMG_1"),
+            Ordering::Greater
+        );
+
+        // the first number that differs in its leading zeros decides
+        let more_first = natural.leading_zeros(LeadingZeros::MoreFirst);
+        assert_eq!(natural.cmp("a01-2", "a1-002"), Ordering::Greater);
+        assert_eq!(more_first.cmp("a01-2", "a1-002"), Ordering::Less);
+
+        // skipped characters are compared first
+        let only_alnum = natural.only_alnum(true);
+        assert_eq!(only_alnum.cmp("a-01", "a1"), Ordering::Less);
+        assert_eq!(only_alnum.cmp("a01", "a1"), Ordering::Greater);
+        assert_eq!(
+            only_alnum
+                .leading_zeros(LeadingZeros::MoreFirst)
+                .cmp("a01", "a1"),
+            Ordering::Less
+        );
+
+        // no effect without natural comparisons
+        let lexical = Collator::new().leading_zeros(LeadingZeros::MoreFirst);
+        assert_eq!(lexical.cmp("01", "1"), Ordering::Less);
+        assert_eq!(lexical.cmp("01", "2"), Ordering::Less);
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_cmp_at() {
+        // differences, also inside a transliteration
+        assert_eq!(lexical_cmp_at("abc", "abd"), (Ordering::Less, Some((2, 2))));
+        assert_eq!(lexical_cmp_at("aß", "asz"), (Ordering::Less, Some((1, 2))));
+        assert_eq!(
+            lexical_cmp_at("asz", "aß"),
+            (Ordering::Greater, Some((2, 1)))
+        );
+        assert_eq!(
+            lexical_cmp_at("Ärger", "arm"),
+            (Ordering::Less, Some((3, 2)))
+        );
+        assert_eq!(lexical_cmp_at("b", "a"), (Ordering::Greater, Some((0, 0))));
+
+        // one string is a prefix of the other
+        assert_eq!(lexical_cmp_at("ab", "abc"), (Ordering::Less, Some((2, 2))));
+        assert_eq!(lexical_cmp_at("ÄB", "ab-"), (Ordering::Less, Some((3, 2))));
+        assert_eq!(lexical_cmp_at("ß", "s"), (Ordering::Greater, Some((0, 1))));
+        assert_eq!(lexical_cmp_at("", "a"), (Ordering::Less, Some((0, 0))));
+        assert_eq!(
+            lexical_cmp_at("café", "cafe\u{301}s"),
+            (Ordering::Less, Some((5, 6)))
+        );
+
+        // tie-breaks
+        assert_eq!(lexical_cmp_at("Foo", "foo"), (Ordering::Less, None));
+        assert_eq!(lexical_cmp_at("ss", "ß"), (Ordering::Less, None));
+        assert_eq!(lexical_cmp_at("foo", "foo"), (Ordering::Equal, None));
+
+        // natural comparisons
+        let cmp_at = natural_lexical_cmp_at;
+        assert_eq!(cmp_at("x10", "x9"), (Ordering::Greater, Some((1, 1))));
+        assert_eq!(cmp_at("x10a", "x10b"), (Ordering::Less, Some((3, 3))));
+        assert_eq!(cmp_at("x10", "x10a"), (Ordering::Less, Some((3, 3))));
+        assert_eq!(cmp_at("Maße 2", "masse 10"), (Ordering::Less, Some((6, 6))));
+        assert_eq!(cmp_at("x010", "x10"), (Ordering::Greater, Some((1, 1))));
+        assert_eq!(cmp_at("X10", "x10"), (Ordering::Less, None));
+        assert_eq!(lexical_cmp_at("x10", "x9"), (Ordering::Less, Some((1, 1))));
+
+        for (lhs, rhs) in [("a", "B"), ("x10", "x9"), ("ß", "s"), ("É", "e")] {
+            assert_eq!(lexical_cmp_at(lhs, rhs).0, crate::lexical_cmp(lhs, rhs));
+            assert_eq!(
+                natural_lexical_cmp_at(lhs, rhs).0,
+                crate::natural_lexical_cmp(lhs, rhs)
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_cmp_with_position() {
+        let collator = Collator::new();
+        let position = |lhs: &str, rhs: &str| collator.cmp_with_position(lhs, rhs);
+
+        // differences inside a transliteration
+        assert_eq!(
+            position("aß", "asz"),
+            (Ordering::Less, DecidedBy::Difference { lhs: 1, rhs: 2 }),
+        );
+        assert_eq!(
+            position("xæb", "xaec"),
+            (Ordering::Less, DecidedBy::Difference { lhs: 3, rhs: 3 }),
+        );
+        assert_eq!(
+            position("ﬀo", "ffi"),
+            (Ordering::Greater, DecidedBy::Difference { lhs: 3, rhs: 2 }),
+        );
+
+        // differences decided by the length
+        assert_eq!(position("ab", "abc"), (Ordering::Less, DecidedBy::Length));
+        assert_eq!(position("ß", "s"), (Ordering::Greater, DecidedBy::Length));
+
+        // differences decided by the tie-break
+        assert_eq!(
+            position("Foo", "foo"),
+            (Ordering::Less, DecidedBy::TieBreak)
+        );
+        assert_eq!(position("ss", "ß"), (Ordering::Less, DecidedBy::TieBreak));
+        assert_eq!(
+            position("", "\u{300}"),
+            (Ordering::Less, DecidedBy::TieBreak)
+        );
+        assert_eq!(position("foo", "foo"), (Ordering::Equal, DecidedBy::Equal));
+        assert_eq!(DecidedBy::TieBreak.position(), None);
+
+        // natural comparisons
+        let collator = Collator::new().natural(true);
+        assert_eq!(
+            collator.cmp_with_position("x10", "x9"),
+            (Ordering::Greater, DecidedBy::Difference { lhs: 1, rhs: 1 }),
+        );
+        assert_eq!(
+            collator.cmp_with_position("x10a", "x10b"),
+            (Ordering::Less, DecidedBy::Difference { lhs: 3, rhs: 3 }),
+        );
+
+        // skipped characters
+        assert_eq!(
+            cmp_with_position("-a-b", "ac", SortMode::LexicalOnlyAlnum),
+            (Ordering::Less, DecidedBy::Difference { lhs: 3, rhs: 1 }),
+        );
+        assert_eq!(
+            cmp_with_position("a-b", "ab", SortMode::LexicalOnlyAlnum),
+            (Ordering::Less, DecidedBy::Difference { lhs: 1, rhs: 1 }),
+        );
+
+        // token replacements
+        let replacements = [("St", "Saint")];
+        let collator = Collator::new().token_replacements(&replacements);
+        assert_eq!(
+            collator.cmp_with_position("St. Mary", "Saint Mark"),
+            (Ordering::Greater, DecidedBy::Difference { lhs: 7, rhs: 9 }),
+        );
+        assert_eq!(
+            collator.cmp_with_position("St. Mary", "Saints"),
+            (Ordering::Less, DecidedBy::Difference { lhs: 3, rhs: 5 }),
+        );
+        assert_eq!(
+            collator.cmp_with_position("St. Mary", "Sainz"),
+            (Ordering::Less, DecidedBy::Difference { lhs: 0, rhs: 4 }),
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "std"))]
+    fn test_serde() {
+        let strings = [
+            "T-20", "t-5", "Äpfel", "apfel", "Mueller", "Müller", "ñu", "nz", "\u{1}a", "Foo",
+            "fóò", "x 1/2", "x½", "",
+        ];
+        let collators = [
+            Collator::new(),
+            Collator::new()
+                .natural(true)
+                .ties(TieBreak::UppercaseFirst)
+                .decimal_separator(','),
+            Collator::new()
+                .only_alnum(true)
+                .symbols_last(true)
+                .controls(Placement::Last)
+                .tailoring(Tailoring::GERMAN_PHONEBOOK),
+            Collator::from(SortMode::Plain).tailoring(Tailoring::NORWEGIAN),
+            Collator::new()
+                .tailoring(Tailoring::SPANISH)
+                .accent_order(AccentOrder::Weighted),
+        ];
+        for collator in &collators {
+            let json = serde_json::to_string(collator).unwrap();
+            let deserialized: Collator = serde_json::from_str(&json).unwrap();
+            assert_eq!(serde_json::to_string(&deserialized).unwrap(), json);
+            for lhs in &strings {
+                for rhs in &strings {
+                    assert_eq!(deserialized.cmp(lhs, rhs), collator.cmp(lhs, rhs));
+                }
+            }
+        }
+        let json = serde_json::to_string(&collators[3]).unwrap();
+        assert!(json.starts_with(r#"{"lexical":false,"natural":false,"#));
+        assert!(json.contains(r#""ties":"bytes","#));
+        assert!(json.contains(r#""tailoring":"danish""#));
+
+        // missing options have their default value, unknown options are ignored
+        let old = r#"{"natural": true, "ties": "lowercase-first", "removed_option": [1, 2]}"#;
+        let deserialized: Collator = serde_json::from_str(old).unwrap();
+        assert_eq!(
+            serde_json::to_string(&deserialized).unwrap(),
+            serde_json::to_string(&Collator::new().natural(true).ties(TieBreak::LowercaseFirst))
+                .unwrap()
+        );
+        let deserialized: Collator = serde_json::from_str("{}").unwrap();
+        assert_eq!(
+            serde_json::to_string(&deserialized).unwrap(),
+            serde_json::to_string(&Collator::new()).unwrap()
+        );
+        assert!(serde_json::from_str::<Collator>(r#"{"ties": "random"}"#).is_err());
+        assert!(serde_json::from_str::<Collator>(r#"{"tailoring": "klingon"}"#).is_err());
+
+        let error = serde_json::to_string(&Collator::new().separators(&['-'])).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "the `separators` option of a `Collator` can't be serialized"
+        );
+        let custom = Tailoring::new(&[('ä', "ae")], &[]);
+        assert!(serde_json::to_string(&Collator::new().tailoring(custom)).is_err());
+        assert!(serde_json::to_string(&Collator::new().script_order(&[Script::Greek])).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    mod proptests {
+        use super::*;
+        use proptest::prelude::*;
+
+        const MODES: [SortMode; 8] = [
+            SortMode::Plain,
+            SortMode::OnlyAlnum,
+            SortMode::Lexical,
+            SortMode::LexicalOnlyAlnum,
+            SortMode::Natural,
+            SortMode::NaturalOnlyAlnum,
+            SortMode::NaturalLexical,
+            SortMode::NaturalLexicalOnlyAlnum,
+        ];
+
+        proptest! {
+            #[test]
+            fn modes_agree_with_functions(
+                a in "[-_aAbBäÄ1 09.ßẞæ½²٣ \t🎉Ωω]{0,8}",
+                b in "[-_aAbBäÄ1 09.ßẞæ½²٣ \t🎉Ωω]{0,8}",
+            ) {
+                for &mode in MODES.iter() {
+                    let collator = Collator::from(mode);
+                    prop_assert_eq!(collator.cmp(&a, &b), mode.function()(&a, &b), "{:?}", mode);
+                }
+            }
+
+            #[test]
+            fn case_first_total_order(
+                a in "[-aAbBäÄ1 0ßẞ]{0,6}",
+                b in "[-aAbBäÄ1 0ßẞ]{0,6}",
+                c in "[-aAbBäÄ1 0ßẞ]{0,6}",
+            ) {
+                for &mode in MODES.iter() {
+                    for &ties in [TieBreak::UppercaseFirst, TieBreak::LowercaseFirst].iter() {
+                        let collator = Collator::from(mode).ties(ties);
+                        let ab = collator.cmp(&a, &b);
+                        prop_assert_eq!(ab, collator.cmp(&b, &a).reverse(), "{:?}", mode);
+                        prop_assert_eq!(ab == Ordering::Equal, a == b, "{:?}", mode);
+                        if ab != Ordering::Greater && collator.cmp(&b, &c) != Ordering::Greater {
+                            prop_assert_ne!(collator.cmp(&a, &c), Ordering::Greater, "{:?}", mode);
+                        }
+                    }
+                }
+            }
+
+            #[test]
+            fn symbols_last_total_order(
+                a in "[-_aAä1 0.!ß🎉]{0,6}",
+                b in "[-_aAä1 0.!ß🎉]{0,6}",
+                c in "[-_aAä1 0.!ß🎉]{0,6}",
+            ) {
+                for &mode in MODES.iter() {
+                    for &symbols_last in [false, true].iter() {
+                        let collator = Collator::from(mode).symbols_last(symbols_last);
+                        let ab = collator.cmp(&a, &b);
+                        prop_assert_eq!(ab, collator.cmp(&b, &a).reverse(), "{:?}", mode);
+                        prop_assert_eq!(ab == Ordering::Equal, a == b, "{:?}", mode);
+                        if ab != Ordering::Greater && collator.cmp(&b, &c) != Ordering::Greater {
+                            prop_assert_ne!(collator.cmp(&a, &c), Ordering::Greater, "{:?}", mode);
+                        }
+                    }
+                }
+            }
+
+            #[test]
+            fn radix_prefixes_total_order(
+                strings in proptest::collection::vec(
+                    proptest::collection::vec(
+                        proptest::sample::select(
+                            vec!["0x9", "9", "⑽", "½", "0xA", "十", "Ⅻ", "0x1", "12", "x", " "],
+                        ),
+                        0..3,
+                    )
+                    .prop_map(|tokens| tokens.concat()),
+                    0..12,
+                ),
+            ) {
+                let collator = Collator::new()
+                    .natural(true)
+                    .radix_prefixes(true)
+                    .roman_numerals(true)
+                    .cjk_numerals(true);
+                for a in &strings {
+                    for b in &strings {
+                        let ab = collator.cmp(a, b);
+                        prop_assert_eq!(collator.cmp(b, a), ab.reverse());
+                        for c in &strings {
+                            if ab != Ordering::Greater && collator.cmp(b, c) != Ordering::Greater {
+                                prop_assert_ne!(
+                                    collator.cmp(a, c),
+                                    Ordering::Greater,
+                                    "{:?} {:?} {:?}",
+                                    a,
+                                    b,
+                                    c
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+
+            #[test]
+            fn case_first_only_breaks_ties(
+                a in "[-aAbBäÄ1 0ßẞ]{0,6}",
+                b in "[-aAbBäÄ1 0ßẞ]{0,6}",
+            ) {
+                for &mode in MODES.iter() {
+                    let collator = Collator::from(mode);
+                    let ties = collator.ties(TieBreak::InputOrder);
+                    if ties.cmp(&a, &b) != Ordering::Equal {
+                        let expected = collator.cmp(&a, &b);
+                        let upper = collator.ties(TieBreak::UppercaseFirst);
+                        let lower = collator.ties(TieBreak::LowercaseFirst);
+                        prop_assert_eq!(upper.cmp(&a, &b), expected, "{:?}", mode);
+                        prop_assert_eq!(lower.cmp(&a, &b), expected, "{:?}", mode);
+                    }
+                }
+            }
+        }
+    }
+}
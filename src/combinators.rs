@@ -0,0 +1,255 @@
+//! Functions to combine comparison functions.
+//!
+//! These functions take comparison functions (such as `natural_lexical_cmp`) and return new
+//! ones, which can be passed to the methods of `StringSort`, `PathSort` or to
+//! `slice::sort_by`. They are generic over the compared type, so the same functions work for
+//! strings and for other items. They return closures, so they don't have any runtime overhead
+//! compared to writing the closures by hand.
+//!
+//! ## Example
+//!
+//! Sort files by their directory, then by their name in descending natural order:
+//!
+//! ```rust
+//! use lexical_sort::combinators::{by_key, reverse, then};
+//! use lexical_sort::{lexical_cmp, natural_lexical_cmp};
+//!
+//! struct File {
+//!     dir: &'static str,
+//!     name: &'static str,
+//! }
+//!
+//! let mut files = [
+//!     File { dir: "src", name: "T-5" },
+//!     File { dir: "Docs", name: "T-20" },
+//!     File { dir: "src", name: "T-100" },
+//!     File { dir: "docs", name: "t-5" },
+//!     File { dir: "Docs", name: "T-100" },
+//! ];
+//!
+//! files.sort_by(then(
+//!     by_key(|f: &File| f.dir, lexical_cmp),
+//!     reverse(by_key(|f: &File| f.name, natural_lexical_cmp)),
+//! ));
+//!
+//! let sorted: Vec<_> = files.iter().map(|f| (f.dir, f.name)).collect();
+//! assert_eq!(
+//!     sorted,
+//!     [
+//!         ("Docs", "T-100"),
+//!         ("Docs", "T-20"),
+//!         ("docs", "t-5"),
+//!         ("src", "T-100"),
+//!         ("src", "T-5"),
+//!     ]
+//! );
+//! ```
+
+use core::cmp::Ordering;
+
+/// Returns a comparison function that compares items that implement `AsRef<str>`, such as
+/// `&str`, `String` or `Cow<str>`, using `cmp`.
+///
+/// The comparison functions of this crate take `&str` arguments, so they can't be passed to
+/// `slice::sort_by` or `Iterator::max_by` directly, which pass references to the items, e.g.
+/// `&&str` or `&String`. This function is also available as `lexical_sort::by`.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::{by, natural_lexical_cmp};
+/// use std::borrow::Cow;
+///
+/// let mut strs = vec!["T-20", "T-100", "t-5"];
+/// strs.sort_by(by(natural_lexical_cmp));
+/// assert_eq!(strs, ["t-5", "T-20", "T-100"]);
+///
+/// let mut strings: Vec<String> = vec!["T-20".into(), "T-100".into(), "t-5".into()];
+/// strings.sort_by(by(natural_lexical_cmp));
+/// assert_eq!(strings, ["t-5", "T-20", "T-100"]);
+///
+/// let cows: Vec<Cow<str>> = vec!["T-20".into(), String::from("T-100").into(), "t-5".into()];
+/// let max = cows.iter().max_by(by(natural_lexical_cmp));
+/// assert_eq!(max.map(|s| s.as_ref()), Some("T-100"));
+/// ```
+pub fn by<T: AsRef<str> + ?Sized>(
+    cmp: impl Fn(&str, &str) -> Ordering,
+) -> impl Fn(&T, &T) -> Ordering {
+    move |lhs, rhs| cmp(lhs.as_ref(), rhs.as_ref())
+}
+
+/// Returns a comparison function that orders items in the reverse order of `cmp`, i.e. in
+/// descending order.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::{combinators::reverse, natural_lexical_cmp, StringSort};
+///
+/// let slice = &mut ["T-20", "T-100", "T-5"];
+/// slice.string_sort_unstable(reverse(natural_lexical_cmp));
+/// assert_eq!(slice, &["T-100", "T-20", "T-5"]);
+/// ```
+pub fn reverse<T: ?Sized>(cmp: impl Fn(&T, &T) -> Ordering) -> impl Fn(&T, &T) -> Ordering {
+    move |lhs, rhs| cmp(rhs, lhs)
+}
+
+/// Returns a comparison function that compares items with `first`, and with `second` if
+/// `first` considers them equal.
+///
+/// Note that the comparison functions of this crate only consider strings equal if they are
+/// identical, so `second` is only useful after a comparator that ignores some differences,
+/// e.g. one created with `by_key`.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::{combinators::then, natural_lexical_cmp, StringSort};
+///
+/// let by_len = |lhs: &str, rhs: &str| lhs.len().cmp(&rhs.len());
+///
+/// let slice = &mut ["T-20", "T-100", "a", "T-5"];
+/// slice.string_sort_unstable(then(by_len, natural_lexical_cmp));
+/// assert_eq!(slice, &["a", "T-5", "T-20", "T-100"]);
+/// ```
+pub fn then<T: ?Sized>(
+    first: impl Fn(&T, &T) -> Ordering,
+    second: impl Fn(&T, &T) -> Ordering,
+) -> impl Fn(&T, &T) -> Ordering {
+    move |lhs, rhs| first(lhs, rhs).then_with(|| second(lhs, rhs))
+}
+
+/// Returns a comparison function that compares items by the string returned by `key`, using
+/// `cmp`.
+///
+/// The key is borrowed from the item, so no strings are allocated. If computing the key is
+/// expensive, use `SortByStrKey::sort_by_cached_str_key` instead.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::{combinators::by_key, lexical_cmp};
+///
+/// let mut people = [("Zoë", 30), ("anna", 25), ("Émile", 41)];
+/// people.sort_by(by_key(|p: &(&str, u32)| p.0, lexical_cmp));
+/// assert_eq!(people, [("anna", 25), ("Émile", 41), ("Zoë", 30)]);
+/// # }
+/// ```
+pub fn by_key<T: ?Sized>(
+    key: impl Fn(&T) -> &str,
+    cmp: impl Fn(&str, &str) -> Ordering,
+) -> impl Fn(&T, &T) -> Ordering {
+    move |lhs, rhs| cmp(key(lhs), key(rhs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cmp, lexical_cmp, lexical_only_alnum_cmp, natural_lexical_cmp, StringSort};
+
+    const STRINGS: [&str; 14] = [
+        "T-20", "b", "t-5", "", "Äpfel", "T-100", "apfel", "-", "T-5", "b", "ß", "ss", "Foo", "fóò",
+    ];
+
+    #[test]
+    fn test_reverse() {
+        let cmps: [fn(&str, &str) -> Ordering; 4] = [
+            cmp,
+            lexical_cmp,
+            lexical_only_alnum_cmp,
+            natural_lexical_cmp,
+        ];
+        for &c in cmps.iter() {
+            let reversed = reverse(c);
+            let twice = reverse(reverse(c));
+            for &a in STRINGS.iter() {
+                for &b in STRINGS.iter() {
+                    assert_eq!(reversed(a, b), c(a, b).reverse());
+                    assert_eq!(twice(a, b), c(a, b));
+                }
+            }
+
+            let mut sorted = STRINGS;
+            sorted.string_sort_unstable(c);
+            let mut descending = STRINGS;
+            descending.string_sort_unstable(reverse(c));
+            descending.reverse();
+            assert_eq!(descending, sorted);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_by() {
+        use std::borrow::Cow;
+
+        let mut strs = STRINGS;
+        strs.sort_by(by(natural_lexical_cmp));
+        let mut expected = STRINGS;
+        expected.string_sort(natural_lexical_cmp);
+        assert_eq!(strs, expected);
+
+        let mut strings: Vec<String> = STRINGS.iter().map(|&s| s.to_string()).collect();
+        strings.sort_by(by(natural_lexical_cmp));
+        assert_eq!(strings, expected);
+
+        let mut cows: Vec<Cow<str>> = STRINGS.iter().map(|&s| Cow::Borrowed(s)).collect();
+        cows.sort_unstable_by(reverse(by(lexical_cmp)));
+        cows.reverse();
+        expected.string_sort_unstable(lexical_cmp);
+        assert_eq!(cows, expected);
+
+        assert_eq!(
+            STRINGS.iter().max_by(by(lexical_only_alnum_cmp)),
+            STRINGS.iter().max_by(|a, b| lexical_only_alnum_cmp(a, b))
+        );
+        assert_eq!(
+            strings.iter().min_by(by(cmp)).map(String::as_str),
+            STRINGS.iter().copied().min()
+        );
+    }
+
+    #[cfg(feature = "unicode")]
+    fn first_char(s: &str) -> &str {
+        &s[..s.chars().next().map_or(0, char::len_utf8)]
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_then_and_by_key() {
+        let len = |a: &str, b: &str| a.len().cmp(&b.len());
+        let by_first = || by_key(first_char, lexical_cmp);
+
+        for &a in STRINGS.iter() {
+            for &b in STRINGS.iter() {
+                assert_eq!(by_first()(a, b), lexical_cmp(first_char(a), first_char(b)));
+
+                // `then` is associative, and an equal comparator is its identity
+                let equal = |_: &str, _: &str| Ordering::Equal;
+                let left = then(then(by_first(), len), natural_lexical_cmp);
+                let right = then(by_first(), then(len, natural_lexical_cmp));
+                assert_eq!(left(a, b), right(a, b));
+                assert_eq!(then(equal, lexical_cmp)(a, b), lexical_cmp(a, b));
+                assert_eq!(then(lexical_cmp, equal)(a, b), lexical_cmp(a, b));
+
+                // `cmp` only returns `Equal` for identical strings
+                assert_eq!(then(cmp, lexical_cmp)(a, b), cmp(a, b));
+                assert_eq!(
+                    reverse(then(by_first(), len))(a, b),
+                    then(reverse(by_first()), reverse(len))(a, b)
+                );
+            }
+        }
+
+        let mut sorted = STRINGS;
+        sorted.string_sort(then(by_first(), reverse(natural_lexical_cmp)));
+        assert_eq!(
+            sorted,
+            [
+                "", "-", "apfel", "Äpfel", "b", "b", "Foo", "fóò", "ss", "ß", "T-100", "T-20",
+                "T-5", "t-5"
+            ]
+        );
+    }
+}
@@ -0,0 +1,672 @@
+//! A trait for comparison functions that can be stored and selected at runtime.
+
+use crate::cmp::{
+    cmp, lexical_cmp, lexical_only_alnum_cmp, natural_cmp, natural_lexical_cmp,
+    natural_lexical_only_alnum_cmp, natural_only_alnum_cmp, only_alnum_cmp,
+};
+use core::cmp::Ordering;
+use core::fmt;
+use core::str::FromStr;
+
+/// A comparison function for strings.
+///
+/// This trait is object safe, so a comparator can be chosen at runtime and stored as a
+/// `Box<dyn Compare>` or `&dyn Compare`. It is implemented for unit structs representing the
+/// eight comparison functions of this crate (e.g. `NaturalLexical` for `natural_lexical_cmp`),
+/// and for function pointers. The unit structs are `Copy`, `Send` and `Sync`, so they can be
+/// stored in other types and shared between threads; a comparator chosen at runtime can be
+/// shared as an `Arc<dyn Compare + Send + Sync>`.
+///
+/// To sort with a `Compare`, use the `_with` methods of `StringSort` and `PathSort`, such as
+/// `string_sort_with`. The other methods take closures, which can call `Compare::compare`.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::{Compare, Lexical, NaturalLexical, StringSort};
+///
+/// let natural = true;
+/// let comparator: Box<dyn Compare> = if natural {
+///     Box::new(NaturalLexical)
+/// } else {
+///     Box::new(Lexical)
+/// };
+///
+/// let slice = &mut ["T-100", "T-5", "T-20"];
+/// slice.string_sort_unstable_with(&*comparator);
+/// assert_eq!(slice, &["T-5", "T-20", "T-100"]);
+/// ```
+pub trait Compare {
+    /// Compares two strings.
+    fn compare(&self, lhs: &str, rhs: &str) -> Ordering;
+}
+
+impl Compare for fn(&str, &str) -> Ordering {
+    #[inline]
+    fn compare(&self, lhs: &str, rhs: &str) -> Ordering {
+        self(lhs, rhs)
+    }
+}
+
+impl<C: Compare + ?Sized> Compare for &C {
+    #[inline]
+    fn compare(&self, lhs: &str, rhs: &str) -> Ordering {
+        (**self).compare(lhs, rhs)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<C: Compare + ?Sized> Compare for alloc::boxed::Box<C> {
+    #[inline]
+    fn compare(&self, lhs: &str, rhs: &str) -> Ordering {
+        (**self).compare(lhs, rhs)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<C: Compare + ?Sized> Compare for alloc::sync::Arc<C> {
+    #[inline]
+    fn compare(&self, lhs: &str, rhs: &str) -> Ordering {
+        (**self).compare(lhs, rhs)
+    }
+}
+
+/// The comparison modes of this crate, one for each of the eight comparison functions.
+///
+/// This is useful to inspect which flags a comparator has. It also implements `Compare`,
+/// so it can be used to select a comparison function at runtime, and `function` returns the
+/// comparison function as a function pointer.
+///
+/// A mode can be parsed from a string, e.g. a command-line argument, with `FromStr`. The
+/// canonical names are the names of the comparison functions without `_cmp`, in kebab case:
+/// `plain`, `only-alnum`, `lexical`, `lexical-only-alnum`, `natural`, `natural-only-alnum`,
+/// `natural-lexical` and `natural-lexical-only-alnum`. They are returned by `name` and by the
+/// `Display` implementation. When parsing, case is ignored, words can also be separated with
+/// `_`, and `nat`, `lex` and `alnum` are accepted as abbreviations of `natural`, `lexical` and
+/// `only-alnum`, so `"nat-lex"` is parsed as `NaturalLexical`.
+///
+/// With the optional `clap` feature, this type implements `clap::ValueEnum`, so it can be
+/// used as a command-line argument directly. With the optional `serde` feature, it is
+/// serialized as its canonical name.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::{SortMode, StringSort};
+///
+/// let mode: SortMode = "natural-lexical".parse().unwrap();
+/// assert_eq!(mode, SortMode::NaturalLexical);
+/// assert_eq!("NAT_LEX".parse(), Ok(SortMode::NaturalLexical));
+/// assert_eq!(mode.to_string(), "natural-lexical");
+/// assert!("naturally".parse::<SortMode>().is_err());
+///
+/// let slice = &mut ["T-20", "T-5", "t-100"];
+/// slice.string_sort_unstable(mode.function());
+/// assert_eq!(slice, &["T-5", "T-20", "t-100"]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SortMode {
+    /// Compares strings like `cmp`
+    Plain,
+    /// Compares strings like `only_alnum_cmp`
+    OnlyAlnum,
+    /// Compares strings like `lexical_cmp`
+    Lexical,
+    /// Compares strings like `lexical_only_alnum_cmp`
+    LexicalOnlyAlnum,
+    /// Compares strings like `natural_cmp`
+    Natural,
+    /// Compares strings like `natural_only_alnum_cmp`
+    NaturalOnlyAlnum,
+    /// Compares strings like `natural_lexical_cmp`
+    NaturalLexical,
+    /// Compares strings like `natural_lexical_only_alnum_cmp`
+    NaturalLexicalOnlyAlnum,
+}
+
+impl SortMode {
+    /// All modes, in the order of their declaration
+    pub const ALL: [SortMode; 8] = [
+        SortMode::Plain,
+        SortMode::OnlyAlnum,
+        SortMode::Lexical,
+        SortMode::LexicalOnlyAlnum,
+        SortMode::Natural,
+        SortMode::NaturalOnlyAlnum,
+        SortMode::NaturalLexical,
+        SortMode::NaturalLexicalOnlyAlnum,
+    ];
+
+    /// Returns the mode with the given flags
+    pub const fn from_flags(lexical: bool, natural: bool, only_alnum: bool) -> Self {
+        match (lexical, natural, only_alnum) {
+            (false, false, false) => SortMode::Plain,
+            (false, false, true) => SortMode::OnlyAlnum,
+            (true, false, false) => SortMode::Lexical,
+            (true, false, true) => SortMode::LexicalOnlyAlnum,
+            (false, true, false) => SortMode::Natural,
+            (false, true, true) => SortMode::NaturalOnlyAlnum,
+            (true, true, false) => SortMode::NaturalLexical,
+            (true, true, true) => SortMode::NaturalLexicalOnlyAlnum,
+        }
+    }
+
+    /// Returns `true` if strings are compared lexicographically, i.e. transliterated to
+    /// lowercase ASCII
+    pub const fn is_lexical(self) -> bool {
+        matches!(
+            self,
+            SortMode::Lexical
+                | SortMode::LexicalOnlyAlnum
+                | SortMode::NaturalLexical
+                | SortMode::NaturalLexicalOnlyAlnum
+        )
+    }
+
+    /// Returns `true` if numbers are compared naturally
+    pub const fn is_natural(self) -> bool {
+        matches!(
+            self,
+            SortMode::Natural
+                | SortMode::NaturalOnlyAlnum
+                | SortMode::NaturalLexical
+                | SortMode::NaturalLexicalOnlyAlnum
+        )
+    }
+
+    /// Returns `true` if non-alphanumeric characters are skipped
+    pub const fn is_only_alnum(self) -> bool {
+        matches!(
+            self,
+            SortMode::OnlyAlnum
+                | SortMode::LexicalOnlyAlnum
+                | SortMode::NaturalOnlyAlnum
+                | SortMode::NaturalLexicalOnlyAlnum
+        )
+    }
+
+    /// Returns the comparison function of this mode
+    pub fn function(self) -> fn(&str, &str) -> Ordering {
+        match self {
+            SortMode::Plain => cmp,
+            SortMode::OnlyAlnum => only_alnum_cmp,
+            SortMode::Lexical => lexical_cmp,
+            SortMode::LexicalOnlyAlnum => lexical_only_alnum_cmp,
+            SortMode::Natural => natural_cmp,
+            SortMode::NaturalOnlyAlnum => natural_only_alnum_cmp,
+            SortMode::NaturalLexical => natural_lexical_cmp,
+            SortMode::NaturalLexicalOnlyAlnum => natural_lexical_only_alnum_cmp,
+        }
+    }
+}
+
+impl Compare for SortMode {
+    #[inline]
+    fn compare(&self, lhs: &str, rhs: &str) -> Ordering {
+        self.function()(lhs, rhs)
+    }
+}
+
+impl SortMode {
+    /// Returns the canonical name of this mode, e.g. `"natural-lexical"`
+    pub const fn name(self) -> &'static str {
+        match self {
+            SortMode::Plain => "plain",
+            SortMode::OnlyAlnum => "only-alnum",
+            SortMode::Lexical => "lexical",
+            SortMode::LexicalOnlyAlnum => "lexical-only-alnum",
+            SortMode::Natural => "natural",
+            SortMode::NaturalOnlyAlnum => "natural-only-alnum",
+            SortMode::NaturalLexical => "natural-lexical",
+            SortMode::NaturalLexicalOnlyAlnum => "natural-lexical-only-alnum",
+        }
+    }
+}
+
+impl fmt::Display for SortMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The error returned when parsing a `SortMode` fails
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseSortModeError;
+
+impl fmt::Display for ParseSortModeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid sort mode, expected e.g. `lexical` or `natural-lexical`")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseSortModeError {}
+
+impl FromStr for SortMode {
+    type Err = ParseSortModeError;
+
+    fn from_str(s: &str) -> Result<Self, ParseSortModeError> {
+        let mut words = s.split(&['-', '_'][..]);
+        let (mut lexical, mut natural, mut only_alnum) = (false, false, false);
+        let mut plain = false;
+        while let Some(word) = words.next() {
+            let flag = if word.eq_ignore_ascii_case("lexical") || word.eq_ignore_ascii_case("lex") {
+                &mut lexical
+            } else if word.eq_ignore_ascii_case("natural") || word.eq_ignore_ascii_case("nat") {
+                &mut natural
+            } else if word.eq_ignore_ascii_case("alnum") {
+                &mut only_alnum
+            } else if word.eq_ignore_ascii_case("only") {
+                match words.next() {
+                    Some(next) if next.eq_ignore_ascii_case("alnum") => &mut only_alnum,
+                    _ => return Err(ParseSortModeError),
+                }
+            } else if word.eq_ignore_ascii_case("plain") {
+                &mut plain
+            } else {
+                return Err(ParseSortModeError);
+            };
+            if *flag {
+                return Err(ParseSortModeError);
+            }
+            *flag = true;
+        }
+        if plain == (lexical || natural || only_alnum) {
+            return Err(ParseSortModeError);
+        }
+        Ok(SortMode::from_flags(lexical, natural, only_alnum))
+    }
+}
+
+/// Serializes the mode as its canonical name, e.g. `"natural-lexical"`
+#[cfg(feature = "serde")]
+impl serde::Serialize for SortMode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+/// Deserializes a mode from a string, which is parsed like with `FromStr`
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SortMode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = SortMode;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a sort mode such as `natural-lexical`")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<SortMode, E> {
+                v.parse()
+                    .map_err(|_| E::invalid_value(serde::de::Unexpected::Str(v), &self))
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+#[cfg(all(feature = "clap", feature = "std"))]
+impl clap::ValueEnum for SortMode {
+    fn value_variants<'a>() -> &'a [Self] {
+        &SortMode::ALL
+    }
+
+    fn to_possible_value(&self) -> Option<clap::builder::PossibleValue> {
+        let value = clap::builder::PossibleValue::new(self.name());
+        Some(match self {
+            SortMode::OnlyAlnum => value.alias("alnum"),
+            SortMode::Lexical => value.alias("lex"),
+            SortMode::Natural => value.alias("nat"),
+            SortMode::NaturalLexical => value.alias("nat-lex"),
+            _ => value,
+        })
+    }
+}
+
+macro_rules! comparators {
+    ($( $(#[$attr:meta])* $name:ident => $function:ident, )*) => {
+        $(
+            $(#[$attr])*
+            ///
+            /// This is a zero-sized type, so it can be used as a type parameter without any
+            /// runtime storage. It can be converted into a function pointer with `From`/`Into`.
+            #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+            pub struct $name;
+
+            impl $name {
+                /// The flags of this comparator
+                pub const MODE: SortMode = SortMode::$name;
+
+                /// Compares two strings
+                #[inline]
+                pub fn call(self, lhs: &str, rhs: &str) -> Ordering {
+                    $function(lhs, rhs)
+                }
+            }
+
+            impl Compare for $name {
+                #[inline]
+                fn compare(&self, lhs: &str, rhs: &str) -> Ordering {
+                    $function(lhs, rhs)
+                }
+            }
+
+            impl From<$name> for fn(&str, &str) -> Ordering {
+                #[inline]
+                fn from(_: $name) -> Self {
+                    $function
+                }
+            }
+        )*
+    };
+}
+
+comparators! {
+    /// Compares strings like `cmp`
+    Plain => cmp,
+    /// Compares strings like `only_alnum_cmp`
+    OnlyAlnum => only_alnum_cmp,
+    /// Compares strings like `lexical_cmp`
+    Lexical => lexical_cmp,
+    /// Compares strings like `lexical_only_alnum_cmp`
+    LexicalOnlyAlnum => lexical_only_alnum_cmp,
+    /// Compares strings like `natural_cmp`
+    Natural => natural_cmp,
+    /// Compares strings like `natural_only_alnum_cmp`
+    NaturalOnlyAlnum => natural_only_alnum_cmp,
+    /// Compares strings like `natural_lexical_cmp`
+    NaturalLexical => natural_lexical_cmp,
+    /// Compares strings like `natural_lexical_only_alnum_cmp`
+    NaturalLexicalOnlyAlnum => natural_lexical_only_alnum_cmp,
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+    use super::*;
+    use crate::StringSort;
+
+    type CmpFn = fn(&str, &str) -> Ordering;
+
+    fn choose(name: &str) -> Box<dyn Compare> {
+        match name {
+            "plain" => Box::new(Plain),
+            "lexical" => Box::new(Lexical),
+            "natural" => Box::new(NaturalLexical),
+            _ => Box::new(lexical_only_alnum_cmp as CmpFn),
+        }
+    }
+
+    #[test]
+    fn test_dyn_compare() {
+        let strings = ["b", "T-5", "a", "T-20", "_c", "B"];
+
+        let mut plain = strings;
+        plain.string_sort_with(&*choose("plain"));
+        assert_eq!(plain, ["B", "T-20", "T-5", "_c", "a", "b"]);
+
+        let mut lexical = strings;
+        lexical.string_sort_unstable_with(&*choose("lexical"));
+        assert_eq!(lexical, ["_c", "a", "B", "b", "T-20", "T-5"]);
+
+        let mut natural = strings;
+        natural.string_sort_with(&choose("natural"));
+        assert_eq!(natural, ["_c", "a", "B", "b", "T-5", "T-20"]);
+
+        let mut only_alnum = strings;
+        only_alnum.string_sort_with(&choose("only alnum"));
+        assert_eq!(only_alnum, ["a", "B", "b", "_c", "T-20", "T-5"]);
+    }
+
+    #[test]
+    fn test_compare_across_threads() {
+        use crate::PathSort;
+        use std::path::Path;
+        use std::sync::Arc;
+
+        fn sort_in_thread<C: Compare + Send + 'static>(comparator: C) -> Vec<&'static str> {
+            std::thread::spawn(move || {
+                let mut strings = ["T-20", "b", "T-5", "A"];
+                strings.string_sort_with(&comparator);
+                strings.to_vec()
+            })
+            .join()
+            .unwrap()
+        }
+
+        // generic, with a unit struct or a function pointer
+        assert_eq!(sort_in_thread(NaturalLexical), ["A", "b", "T-5", "T-20"]);
+        assert_eq!(sort_in_thread(Plain), ["A", "T-20", "T-5", "b"]);
+        let function: CmpFn = NaturalLexical.into();
+        assert_eq!(sort_in_thread(function), ["A", "b", "T-5", "T-20"]);
+
+        // dynamic, chosen at runtime
+        for &(natural, expected) in [
+            (true, ["A", "b", "T-5", "T-20"]),
+            (false, ["A", "b", "T-20", "T-5"]),
+        ]
+        .iter()
+        {
+            let comparator: Arc<dyn Compare + Send + Sync> = if natural {
+                Arc::new(NaturalLexical)
+            } else {
+                Arc::new(Lexical)
+            };
+            assert_eq!(sort_in_thread(Arc::clone(&comparator)), expected);
+
+            let mut paths: Vec<&Path> = ["T-20", "b", "T-5", "A"].iter().map(Path::new).collect();
+            paths.path_sort_unstable_with(&comparator);
+            assert!(paths.iter().copied().eq(expected.iter().map(Path::new)));
+            let mut strings = ["T-20", "b", "T-5", "A"];
+            strings.string_sort_unstable(|lhs, rhs| comparator.compare(lhs, rhs));
+            assert_eq!(strings, expected);
+        }
+    }
+
+    #[test]
+    fn test_unit_structs() {
+        let strings = ["", "a", "ä", "A", "-a", "10", "9", "a b", "ab", "T-5", "T5"];
+        let pairs: [(&dyn Compare, CmpFn); 8] = [
+            (&Plain, cmp),
+            (&OnlyAlnum, only_alnum_cmp),
+            (&Lexical, lexical_cmp),
+            (&LexicalOnlyAlnum, lexical_only_alnum_cmp),
+            (&Natural, natural_cmp),
+            (&NaturalOnlyAlnum, natural_only_alnum_cmp),
+            (&NaturalLexical, natural_lexical_cmp),
+            (&NaturalLexicalOnlyAlnum, natural_lexical_only_alnum_cmp),
+        ];
+        for (comparator, function) in pairs.iter() {
+            for lhs in &strings {
+                for rhs in &strings {
+                    assert_eq!(comparator.compare(lhs, rhs), function(lhs, rhs));
+                }
+            }
+        }
+    }
+
+    /// A toy container that keeps its items sorted using the comparator type `C`
+    struct SortedList<C> {
+        items: Vec<String>,
+        comparator: C,
+    }
+
+    impl<C: Compare + Default> SortedList<C> {
+        fn new() -> Self {
+            SortedList {
+                items: Vec::new(),
+                comparator: C::default(),
+            }
+        }
+
+        fn insert(&mut self, item: &str) {
+            let index = self
+                .items
+                .binary_search_by(|probe| self.comparator.compare(probe, item))
+                .unwrap_or_else(|i| i);
+            self.items.insert(index, item.to_string());
+        }
+    }
+
+    #[test]
+    fn test_type_parameter() {
+        // the comparator doesn't take up space
+        assert_eq!(std::mem::size_of::<NaturalLexical>(), 0);
+        assert_eq!(
+            std::mem::size_of::<SortedList<NaturalLexical>>(),
+            std::mem::size_of::<Vec<String>>()
+        );
+
+        let strings = ["T-20", "b", "T-5", "ä", "A", "_c", "T-100"];
+        let mut list = SortedList::<NaturalLexical>::new();
+        let mut expected = strings;
+        for s in &strings {
+            list.insert(s);
+        }
+        expected.sort_by(|a, b| natural_lexical_cmp(a, b));
+        assert_eq!(list.items, expected);
+
+        let mut list = SortedList::<Plain>::new();
+        let mut expected = strings;
+        for s in &strings {
+            list.insert(s);
+        }
+        expected.sort_by(|a, b| cmp(a, b));
+        assert_eq!(list.items, expected);
+    }
+
+    #[test]
+    fn test_modes() {
+        assert_eq!(NaturalLexical::MODE, SortMode::NaturalLexical);
+        assert!(NaturalLexical::MODE.is_natural());
+        assert!(NaturalLexical::MODE.is_lexical());
+        assert!(!NaturalLexical::MODE.is_only_alnum());
+        assert!(!Plain::MODE.is_natural());
+        assert!(OnlyAlnum::MODE.is_only_alnum());
+
+        let modes = [
+            SortMode::Plain,
+            SortMode::OnlyAlnum,
+            SortMode::Lexical,
+            SortMode::LexicalOnlyAlnum,
+            SortMode::Natural,
+            SortMode::NaturalOnlyAlnum,
+            SortMode::NaturalLexical,
+            SortMode::NaturalLexicalOnlyAlnum,
+        ];
+        for &mode in &modes {
+            let flags = (mode.is_lexical(), mode.is_natural(), mode.is_only_alnum());
+            assert_eq!(SortMode::from_flags(flags.0, flags.1, flags.2), mode);
+        }
+
+        let function: CmpFn = LexicalOnlyAlnum.into();
+        assert_eq!(function("a-c", "ab"), Ordering::Greater);
+        assert_eq!(LexicalOnlyAlnum.call("a-c", "ab"), Ordering::Greater);
+        assert_eq!(
+            SortMode::LexicalOnlyAlnum.compare("a-c", "ab"),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn test_parse_modes() {
+        let strings = ["", "a", "ä", "A", "-a", "10", "9", "a b", "ab", "T-5", "T5"];
+        let functions: [CmpFn; 8] = [
+            cmp,
+            only_alnum_cmp,
+            lexical_cmp,
+            lexical_only_alnum_cmp,
+            natural_cmp,
+            natural_only_alnum_cmp,
+            natural_lexical_cmp,
+            natural_lexical_only_alnum_cmp,
+        ];
+        for (&mode, function) in SortMode::ALL.iter().zip(functions.iter()) {
+            assert_eq!(mode.to_string().parse(), Ok(mode));
+            assert_eq!(
+                mode.name().to_uppercase().replace('-', "_").parse(),
+                Ok(mode)
+            );
+            for lhs in &strings {
+                for rhs in &strings {
+                    assert_eq!(mode.function()(lhs, rhs), function(lhs, rhs));
+                }
+            }
+        }
+
+        assert_eq!("nat".parse(), Ok(SortMode::Natural));
+        assert_eq!("lex".parse(), Ok(SortMode::Lexical));
+        assert_eq!("alnum".parse(), Ok(SortMode::OnlyAlnum));
+        assert_eq!(
+            "Nat-Lex_alnum".parse(),
+            Ok(SortMode::NaturalLexicalOnlyAlnum)
+        );
+        assert_eq!("only-alnum-natural".parse(), Ok(SortMode::NaturalOnlyAlnum));
+        for invalid in &[
+            "",
+            "-",
+            "natural-",
+            "naturally",
+            "only",
+            "natural-only",
+            "natural-natural",
+            "plain-lexical",
+            "plain-plain",
+            "natural lexical",
+        ] {
+            assert_eq!(
+                invalid.parse::<SortMode>(),
+                Err(ParseSortModeError),
+                "{:?}",
+                invalid
+            );
+        }
+        assert_eq!(
+            ParseSortModeError.to_string(),
+            "invalid sort mode, expected e.g. `lexical` or `natural-lexical`"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_sort_mode() {
+        for &mode in SortMode::ALL.iter() {
+            let json = serde_json::to_string(&mode).unwrap();
+            assert_eq!(json, format!("{:?}", mode.name()));
+            assert_eq!(serde_json::from_str::<SortMode>(&json).unwrap(), mode);
+        }
+        let mode: SortMode = serde_json::from_str("\"nat-lex\"").unwrap();
+        assert_eq!(mode, SortMode::NaturalLexical);
+        let error = serde_json::from_str::<SortMode>("\"naturally\"").unwrap_err();
+        assert!(error.to_string().starts_with(
+            "invalid value: string \"naturally\", expected a sort mode such as `natural-lexical`"
+        ));
+        assert!(serde_json::from_str::<SortMode>("3").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "clap")]
+    fn test_clap_value_enum() {
+        use clap::ValueEnum;
+
+        for &mode in SortMode::ALL.iter() {
+            assert_eq!(
+                <SortMode as ValueEnum>::from_str(mode.name(), false),
+                Ok(mode)
+            );
+        }
+        assert_eq!(
+            <SortMode as ValueEnum>::from_str("nat-lex", false),
+            Ok(SortMode::NaturalLexical)
+        );
+        assert_eq!(
+            <SortMode as ValueEnum>::from_str("NAT", true),
+            Ok(SortMode::Natural)
+        );
+        assert!(<SortMode as ValueEnum>::from_str("nat_lex", false).is_err());
+    }
+}
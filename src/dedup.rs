@@ -0,0 +1,192 @@
+//! Removal of consecutive strings that are equal up to case, diacritics, etc.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::path::Path;
+
+/// Removes consecutive strings that are equal according to the given equality function,
+/// keeping the first of them.
+///
+/// The comparison functions of this crate only return `Ordering::Equal` for identical strings,
+/// because of their tie-break, so they can't be used to find duplicates. Instead, use one of
+/// the equality functions that are consistent with them, e.g. `lexical_eq` for `lexical_cmp`
+/// or `natural_lexical_eq` for `natural_lexical_cmp`. After sorting, strings that are equal
+/// according to these functions are next to each other, so all duplicates are removed.
+///
+/// Each string is compared with the first string of its run of duplicates, which is kept, not
+/// with the previous one. This works on a `Vec` of any type that implements `AsRef<str>`; for
+/// paths, use `path_dedup`.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::{lexical_cmp, lexical_eq, string_dedup, StringSort};
+///
+/// let mut names = vec!["fóò", "Bar", "foo", "Foo", "bar", "baz"];
+/// names.string_sort(lexical_cmp);
+/// assert_eq!(names, ["Bar", "bar", "baz", "Foo", "foo", "fóò"]);
+///
+/// string_dedup(&mut names, lexical_eq);
+/// assert_eq!(names, ["Bar", "baz", "Foo"]);
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+pub fn string_dedup<A: AsRef<str>>(vec: &mut Vec<A>, mut eq: impl FnMut(&str, &str) -> bool) {
+    vec.dedup_by(|rhs, lhs| eq(lhs.as_ref(), rhs.as_ref()));
+}
+
+/// Removes consecutive items whose keys are equal according to the given equality function,
+/// keeping the first of them, like `string_dedup`.
+///
+/// The key function returns the string that is compared, e.g. a field of a struct. It is
+/// called twice in each comparison, so it should be cheap.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::{natural_lexical_eq, string_dedup_by_key};
+///
+/// let mut files = vec![("File-01.txt", 3), ("file-1.txt", 5), ("file-2.txt", 8)];
+/// string_dedup_by_key(&mut files, |&(name, _)| name, natural_lexical_eq);
+/// assert_eq!(files, [("File-01.txt", 3), ("file-2.txt", 8)]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn string_dedup_by_key<T, K: AsRef<str>>(
+    vec: &mut Vec<T>,
+    mut key: impl FnMut(&T) -> K,
+    mut eq: impl FnMut(&str, &str) -> bool,
+) {
+    vec.dedup_by(|rhs, lhs| eq(key(lhs).as_ref(), key(rhs).as_ref()));
+}
+
+/// Removes consecutive paths that are equal according to the given equality function, keeping
+/// the first of them, like `string_dedup`.
+///
+/// The paths are converted with `to_string_lossy()` in each comparison.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::{lexical_eq, path_dedup};
+/// use std::path::PathBuf;
+///
+/// let mut paths: Vec<PathBuf> = vec!["a/Foo".into(), "a/fóò".into(), "b/foo".into()];
+/// path_dedup(&mut paths, lexical_eq);
+/// assert_eq!(paths, [PathBuf::from("a/Foo"), PathBuf::from("b/foo")]);
+/// # }
+/// ```
+#[cfg(feature = "std")]
+pub fn path_dedup<A: AsRef<Path>>(vec: &mut Vec<A>, mut eq: impl FnMut(&str, &str) -> bool) {
+    vec.dedup_by(|rhs, lhs| {
+        eq(
+            &lhs.as_ref().to_string_lossy(),
+            &rhs.as_ref().to_string_lossy(),
+        )
+    });
+}
+
+/// Returns the number of strings that `string_dedup` would remove, without removing them.
+///
+/// A string is a duplicate if it is equal to the first string of its run of consecutive
+/// strings according to the given equality function, e.g. `lexical_eq`.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::{count_lexical_duplicates, lexical_eq};
+///
+/// let names = ["Bar", "bar", "baz", "Foo", "foo", "fóò"];
+/// assert_eq!(count_lexical_duplicates(names, lexical_eq), 3);
+/// # }
+/// ```
+pub fn count_lexical_duplicates<I>(iter: I, mut eq: impl FnMut(&str, &str) -> bool) -> usize
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut iter = iter.into_iter();
+    let mut kept = match iter.next() {
+        Some(item) => item,
+        None => return 0,
+    };
+    let mut count = 0;
+    for item in iter {
+        if eq(kept.as_ref(), item.as_ref()) {
+            count += 1;
+        } else {
+            kept = item;
+        }
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{lexical_eq, natural_lexical_eq};
+
+    #[test]
+    fn test_count_duplicates() {
+        let empty: [&str; 0] = [];
+        assert_eq!(count_lexical_duplicates(empty, lexical_eq), 0);
+        assert_eq!(count_lexical_duplicates(["a"], lexical_eq), 0);
+        assert_eq!(
+            count_lexical_duplicates(["a", "A", "b", "a"], lexical_eq),
+            1
+        );
+        let files = ["T-5", "t-05", "T-20", "t-020", "T-020"];
+        assert_eq!(count_lexical_duplicates(files, natural_lexical_eq), 3);
+        assert_eq!(count_lexical_duplicates(files, lexical_eq), 1);
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", feature = "unicode"))]
+    fn test_dedup() {
+        use crate::{lexical_cmp, natural_lexical_cmp, only_alnum_eq, StringSort};
+        use std::path::PathBuf;
+
+        let mut names = vec![
+            "Straße", "foo", "Bär", "STRASSE", "fóò", "bar", "Foo", "Øre", "ore", "bar", "",
+        ];
+        names.string_sort(lexical_cmp);
+        let sorted = names.clone();
+        let count = count_lexical_duplicates(&names, lexical_eq);
+        string_dedup(&mut names, lexical_eq);
+        assert_eq!(names, ["", "bar", "Foo", "ore", "STRASSE"]);
+        assert_eq!(names.len() + count, sorted.len());
+
+        // the same for owned strings and paths
+        let mut strings: Vec<String> = sorted.iter().map(|&s| s.to_string()).collect();
+        string_dedup(&mut strings, lexical_eq);
+        assert_eq!(strings, names);
+        let mut paths: Vec<PathBuf> = sorted.iter().map(PathBuf::from).collect();
+        path_dedup(&mut paths, lexical_eq);
+        assert!(paths
+            .iter()
+            .map(PathBuf::as_path)
+            .eq(names.iter().map(Path::new)));
+
+        // each string is compared with the first one of the run, which is kept
+        let mut strings = vec!["a-b", "ab", "a b", "abc", "a-b-c", "abd"];
+        string_dedup(&mut strings, only_alnum_eq);
+        assert_eq!(strings, ["a-b", "abc", "abd"]);
+        let mut strings = vec!["a", "a-", "a--", "a---"];
+        string_dedup(&mut strings, |lhs, rhs| lhs.len() + 1 == rhs.len());
+        assert_eq!(strings, ["a", "a--"]);
+
+        let mut files = vec![
+            ("T-020", 1),
+            ("T-5", 2),
+            ("t-05", 3),
+            ("T-20", 4),
+            ("t-5", 5),
+        ];
+        files.sort_by(|lhs, rhs| natural_lexical_cmp(lhs.0, rhs.0));
+        string_dedup_by_key(&mut files, |&(name, _)| name, natural_lexical_eq);
+        assert_eq!(files, [("T-5", 2), ("T-20", 4)]);
+    }
+}
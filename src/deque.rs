@@ -0,0 +1,302 @@
+//! Implementations of `StringSort` and `PathSort` for `VecDeque`.
+
+#[cfg(feature = "std")]
+use crate::PathSort;
+use crate::{smallest_k, StringSort};
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+#[cfg(feature = "std")]
+use std::path::Path;
+
+/// The items of a `VecDeque` may be split in two slices, so the sorting methods call
+/// `VecDeque::make_contiguous` first. The other methods don't reorder the items.
+impl<A: AsRef<str>> StringSort for VecDeque<A> {
+    fn string_sort(&mut self, cmp: impl FnMut(&str, &str) -> Ordering) {
+        self.make_contiguous().string_sort(cmp);
+    }
+
+    fn string_sort_unstable(&mut self, cmp: impl FnMut(&str, &str) -> Ordering) {
+        self.make_contiguous().string_sort_unstable(cmp);
+    }
+
+    fn string_sort_by<Cmp, Map>(&mut self, cmp: Cmp, map: Map)
+    where
+        Cmp: FnMut(&str, &str) -> Ordering,
+        Map: FnMut(&str) -> &str,
+    {
+        self.make_contiguous().string_sort_by(cmp, map);
+    }
+
+    fn string_sort_unstable_by<Cmp, Map>(&mut self, cmp: Cmp, map: Map)
+    where
+        Cmp: FnMut(&str, &str) -> Ordering,
+        Map: FnMut(&str) -> &str,
+    {
+        self.make_contiguous().string_sort_unstable_by(cmp, map);
+    }
+
+    fn string_sort_by_cached_key<K, Cmp, Key>(&mut self, cmp: Cmp, key: Key)
+    where
+        K: AsRef<str>,
+        Cmp: FnMut(&str, &str) -> Ordering,
+        Key: FnMut(&str) -> K,
+    {
+        self.make_contiguous().string_sort_by_cached_key(cmp, key);
+    }
+
+    fn string_sort_unstable_by_cached_key<K, Cmp, Key>(&mut self, cmp: Cmp, key: Key)
+    where
+        K: AsRef<str>,
+        Cmp: FnMut(&str, &str) -> Ordering,
+        Key: FnMut(&str) -> K,
+    {
+        self.make_contiguous()
+            .string_sort_unstable_by_cached_key(cmp, key);
+    }
+
+    fn string_sort_fast(&mut self) {
+        self.make_contiguous().string_sort_fast();
+    }
+
+    fn string_is_sorted_by<Cmp, Map>(&self, mut cmp: Cmp, mut map: Map) -> bool
+    where
+        Cmp: FnMut(&str, &str) -> Ordering,
+        Map: FnMut(&str) -> &str,
+    {
+        self.iter()
+            .zip(self.iter().skip(1))
+            .all(|(lhs, rhs)| cmp(map(lhs.as_ref()), map(rhs.as_ref())) != Ordering::Greater)
+    }
+
+    fn string_first_unsorted(&self, mut cmp: impl FnMut(&str, &str) -> Ordering) -> Option<usize> {
+        self.iter()
+            .zip(self.iter().skip(1))
+            .position(|(lhs, rhs)| cmp(lhs.as_ref(), rhs.as_ref()) == Ordering::Greater)
+            .map(|i| i + 1)
+    }
+
+    fn string_argsort(&self, mut cmp: impl FnMut(&str, &str) -> Ordering) -> Vec<usize> {
+        let mut indices: Vec<usize> = (0..self.len()).collect();
+        indices.sort_by(|&i, &j| cmp(self[i].as_ref(), self[j].as_ref()));
+        indices
+    }
+
+    fn string_argsort_unstable(&self, mut cmp: impl FnMut(&str, &str) -> Ordering) -> Vec<usize> {
+        let mut indices: Vec<usize> = (0..self.len()).collect();
+        indices.sort_unstable_by(|&i, &j| cmp(self[i].as_ref(), self[j].as_ref()));
+        indices
+    }
+
+    fn string_select_nth_unstable(&mut self, n: usize, cmp: impl FnMut(&str, &str) -> Ordering) {
+        self.make_contiguous().string_select_nth_unstable(n, cmp);
+    }
+
+    fn string_smallest_k(
+        &self,
+        k: usize,
+        mut cmp: impl FnMut(&str, &str) -> Ordering,
+    ) -> Vec<&str> {
+        smallest_k(self.len(), k, |i, j| {
+            cmp(self[i].as_ref(), self[j].as_ref())
+        })
+        .into_iter()
+        .map(|i| self[i].as_ref())
+        .collect()
+    }
+}
+
+/// Like the `StringSort` implementation, the sorting methods call `VecDeque::make_contiguous`.
+#[cfg(feature = "std")]
+impl<A: AsRef<Path>> PathSort for VecDeque<A> {
+    fn path_sort(&mut self, cmp: impl FnMut(&str, &str) -> Ordering) {
+        self.make_contiguous().path_sort(cmp);
+    }
+
+    fn path_sort_unstable(&mut self, cmp: impl FnMut(&str, &str) -> Ordering) {
+        self.make_contiguous().path_sort_unstable(cmp);
+    }
+
+    fn path_sort_by<Cmp, Map>(&mut self, cmp: Cmp, map: Map)
+    where
+        Cmp: FnMut(&str, &str) -> Ordering,
+        Map: FnMut(&str) -> &str,
+    {
+        self.make_contiguous().path_sort_by(cmp, map);
+    }
+
+    fn path_sort_unstable_by<Cmp, Map>(&mut self, cmp: Cmp, map: Map)
+    where
+        Cmp: FnMut(&str, &str) -> Ordering,
+        Map: FnMut(&str) -> &str,
+    {
+        self.make_contiguous().path_sort_unstable_by(cmp, map);
+    }
+
+    fn path_sort_by_cached_key<K, Cmp, Key>(&mut self, cmp: Cmp, key: Key)
+    where
+        K: AsRef<str>,
+        Cmp: FnMut(&str, &str) -> Ordering,
+        Key: FnMut(&str) -> K,
+    {
+        self.make_contiguous().path_sort_by_cached_key(cmp, key);
+    }
+
+    fn path_sort_unstable_by_cached_key<K, Cmp, Key>(&mut self, cmp: Cmp, key: Key)
+    where
+        K: AsRef<str>,
+        Cmp: FnMut(&str, &str) -> Ordering,
+        Key: FnMut(&str) -> K,
+    {
+        self.make_contiguous()
+            .path_sort_unstable_by_cached_key(cmp, key);
+    }
+
+    fn path_is_sorted_by<Cmp, Map>(&self, mut cmp: Cmp, mut map: Map) -> bool
+    where
+        Cmp: FnMut(&str, &str) -> Ordering,
+        Map: FnMut(&str) -> &str,
+    {
+        self.iter().zip(self.iter().skip(1)).all(|(lhs, rhs)| {
+            cmp(
+                map(&lhs.as_ref().to_string_lossy()),
+                map(&rhs.as_ref().to_string_lossy()),
+            ) != Ordering::Greater
+        })
+    }
+
+    fn path_first_unsorted(&self, mut cmp: impl FnMut(&str, &str) -> Ordering) -> Option<usize> {
+        self.iter()
+            .zip(self.iter().skip(1))
+            .position(|(lhs, rhs)| {
+                cmp(
+                    &lhs.as_ref().to_string_lossy(),
+                    &rhs.as_ref().to_string_lossy(),
+                ) == Ordering::Greater
+            })
+            .map(|i| i + 1)
+    }
+
+    fn path_argsort(&self, mut cmp: impl FnMut(&str, &str) -> Ordering) -> Vec<usize> {
+        let mut indices: Vec<usize> = (0..self.len()).collect();
+        indices.sort_by(|&i, &j| {
+            cmp(
+                &self[i].as_ref().to_string_lossy(),
+                &self[j].as_ref().to_string_lossy(),
+            )
+        });
+        indices
+    }
+
+    fn path_argsort_unstable(&self, mut cmp: impl FnMut(&str, &str) -> Ordering) -> Vec<usize> {
+        let mut indices: Vec<usize> = (0..self.len()).collect();
+        indices.sort_unstable_by(|&i, &j| {
+            cmp(
+                &self[i].as_ref().to_string_lossy(),
+                &self[j].as_ref().to_string_lossy(),
+            )
+        });
+        indices
+    }
+
+    fn path_select_nth_unstable(&mut self, n: usize, cmp: impl FnMut(&str, &str) -> Ordering) {
+        self.make_contiguous().path_select_nth_unstable(n, cmp);
+    }
+
+    fn path_smallest_k(&self, k: usize, mut cmp: impl FnMut(&str, &str) -> Ordering) -> Vec<&Path> {
+        smallest_k(self.len(), k, |i, j| {
+            cmp(
+                &self[i].as_ref().to_string_lossy(),
+                &self[j].as_ref().to_string_lossy(),
+            )
+        })
+        .into_iter()
+        .map(|i| self[i].as_ref())
+        .collect()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{lexical_cmp, natural_lexical_cmp, NaturalLexical};
+
+    /// Returns a deque with the items, which wrap around the end of the ring buffer
+    fn wrapped<T: Copy>(items: &[T]) -> VecDeque<T> {
+        let mut deque = VecDeque::with_capacity(items.len());
+        let (front, back) = items.split_at(items.len() / 2);
+        for &item in front.iter().rev() {
+            deque.push_front(item);
+        }
+        for &item in back {
+            deque.push_back(item);
+        }
+        deque
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_sort_deque() {
+        let strings = [
+            "T-20", "b", "t-5", "", "Äpfel", "T-100", "apfel", "-", "T-5", "b", "ß", "ss",
+        ];
+        let mut sorted = strings;
+        sorted.string_sort(natural_lexical_cmp);
+
+        let deque = wrapped(&strings);
+        assert!(!deque.as_slices().1.is_empty());
+        assert!(deque.iter().eq(strings.iter()));
+        assert_eq!(deque.string_first_unsorted(natural_lexical_cmp), Some(1));
+        assert_eq!(
+            deque.string_argsort(natural_lexical_cmp),
+            strings.string_argsort(natural_lexical_cmp)
+        );
+        assert_eq!(deque.string_smallest_k(5, natural_lexical_cmp), sorted[..5]);
+
+        let mut deque = wrapped(&strings);
+        deque.string_sort(natural_lexical_cmp);
+        assert!(deque.iter().eq(sorted.iter()));
+        assert!(deque.string_is_sorted(natural_lexical_cmp));
+
+        let mut deque = wrapped(&strings);
+        deque.string_sort_unstable_with(&NaturalLexical);
+        assert!(deque.iter().eq(sorted.iter()));
+
+        let mut deque = wrapped(&strings);
+        deque.string_select_nth_unstable(4, natural_lexical_cmp);
+        assert_eq!(deque[4], sorted[4]);
+
+        // the `_by` variants
+        let mut deque = wrapped(&[" b", "a", "  c", "d"]);
+        deque.string_sort_by(lexical_cmp, str::trim_start);
+        assert!(deque.iter().eq([&"a", &" b", &"  c", &"d"]));
+        assert!(deque.string_is_sorted_by(lexical_cmp, str::trim_start));
+        assert!(!deque.string_is_sorted(lexical_cmp));
+        let mut deque = wrapped(&["T-20", "t-5", "T-100", "Äpfel"]);
+        deque.string_sort_unstable_by_cached_key(lexical_cmp, |s| s.to_lowercase());
+        assert!(deque.iter().eq([&"Äpfel", &"T-100", &"T-20", &"t-5"]));
+    }
+
+    #[test]
+    fn test_path_sort_deque() {
+        let paths: Vec<&Path> = ["img/10.png", "Img/3.png", "img/2.png", "  a", " b"]
+            .iter()
+            .map(Path::new)
+            .collect();
+        let mut sorted = paths.clone();
+        sorted.path_sort(natural_lexical_cmp);
+
+        let mut deque = wrapped(&paths);
+        assert!(!deque.as_slices().1.is_empty());
+        assert_eq!(deque.path_first_unsorted(natural_lexical_cmp), Some(1));
+        assert_eq!(deque.path_smallest_k(2, natural_lexical_cmp), sorted[..2]);
+        deque.path_sort(natural_lexical_cmp);
+        assert!(deque.iter().eq(sorted.iter()));
+        assert!(deque.path_is_sorted(natural_lexical_cmp));
+
+        let mut deque = wrapped(&paths);
+        deque.path_sort_unstable_by(natural_lexical_cmp, str::trim_start);
+        assert!(deque.path_is_sorted_by(natural_lexical_cmp, str::trim_start));
+        assert_eq!(deque[0], Path::new("  a"));
+    }
+}
@@ -0,0 +1,287 @@
+//! Differences between two sorted slices of strings, and set operations on them.
+
+use core::cmp::Ordering;
+
+/// An item of the edit script returned by `diff_sorted`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiffItem {
+    /// The string at this index of the new slice was added
+    Added(usize),
+    /// The string at this index of the old slice was removed
+    Removed(usize),
+    /// The string at the first index of the old slice is equal to the string at the second
+    /// index of the new slice
+    Kept(usize, usize),
+}
+
+/// Returns the differences between two slices that are sorted with the same comparison
+/// function, in a single pass over both slices.
+///
+/// The items are returned in sorted order. Two strings are the same if the comparison
+/// function returns `Ordering::Equal`, so with the comparison functions of this crate, only
+/// identical strings are the same, and e.g. `"Foo"` and `"foo"` are reported as a removal and
+/// an addition. To treat strings as the same if they are equal after folding, use a `Collator`
+/// with `TieBreak::InputOrder`. Duplicates are matched in order, so if a string occurs
+/// twice in the old slice and once in the new slice, the second occurrence is removed.
+///
+/// If one of the slices isn't sorted, the result is unspecified, but it doesn't panic.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::{diff_sorted, lexical_cmp, DiffItem};
+///
+/// let old = ["apple", "Banana", "cherry"];
+/// let new = ["Banana", "banana", "cherry", "date"];
+/// let diff: Vec<_> = diff_sorted(&old, &new, lexical_cmp).collect();
+///
+/// assert_eq!(
+///     diff,
+///     &[
+///         DiffItem::Removed(0),
+///         DiffItem::Kept(1, 0),
+///         DiffItem::Added(1),
+///         DiffItem::Kept(2, 2),
+///         DiffItem::Added(3),
+///     ],
+/// );
+/// ```
+pub fn diff_sorted<'a, A: AsRef<str>, B: AsRef<str>>(
+    old: &'a [A],
+    new: &'a [B],
+    mut cmp: impl FnMut(&str, &str) -> Ordering + 'a,
+) -> impl Iterator<Item = DiffItem> + 'a {
+    let (mut i, mut j) = (0, 0);
+    core::iter::from_fn(move || {
+        let item = match (old.get(i), new.get(j)) {
+            (Some(lhs), Some(rhs)) => match cmp(lhs.as_ref(), rhs.as_ref()) {
+                Ordering::Less => DiffItem::Removed(i),
+                Ordering::Greater => DiffItem::Added(j),
+                Ordering::Equal => DiffItem::Kept(i, j),
+            },
+            (Some(_), None) => DiffItem::Removed(i),
+            (None, Some(_)) => DiffItem::Added(j),
+            (None, None) => return None,
+        };
+        match item {
+            DiffItem::Removed(_) => i += 1,
+            DiffItem::Added(_) => j += 1,
+            DiffItem::Kept(..) => {
+                i += 1;
+                j += 1;
+            }
+        }
+        Some(item)
+    })
+}
+
+/// Returns the strings of `a` that are also in `b`, in sorted order. Both slices must be
+/// sorted with the same comparison function.
+///
+/// This walks both slices once, like `diff_sorted`, so it doesn't allocate or hash the
+/// strings. Strings are the same if the comparison function returns `Ordering::Equal`. With
+/// the comparison functions of this crate, only identical strings are the same; to treat
+/// strings as the same if they are equal after folding, e.g. `"Foo"` and `"fóò"`, use a
+/// `Collator` with `TieBreak::InputOrder`.
+///
+/// The slices are treated as multisets: Duplicates are matched in order, so a string that
+/// occurs 3 times in `a` and twice in `b` occurs twice in the result. In debug builds, this
+/// panics if one of the slices isn't sorted.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::{natural_lexical_cmp, sorted_intersection, Collator, TieBreak};
+///
+/// let a = ["Foo", "T-5", "T-20"];
+/// let b = ["fóò", "T-20", "T-100"];
+/// let both: Vec<_> = sorted_intersection(&a, &b, natural_lexical_cmp).collect();
+/// assert_eq!(both, ["T-20"]);
+///
+/// let collator = Collator::from(lexical_sort::SortMode::NaturalLexical)
+///     .ties(TieBreak::InputOrder);
+/// let both: Vec<_> = sorted_intersection(&a, &b, |l, r| collator.cmp(l, r)).collect();
+/// assert_eq!(both, ["Foo", "T-20"]);
+/// # }
+/// ```
+pub fn sorted_intersection<'a, A: AsRef<str>, B: AsRef<str>>(
+    a: &'a [A],
+    b: &'a [B],
+    cmp: impl FnMut(&str, &str) -> Ordering + 'a,
+) -> impl Iterator<Item = &'a str> + 'a {
+    checked_diff_sorted(a, b, cmp).filter_map(move |item| match item {
+        DiffItem::Kept(i, _) => Some(a[i].as_ref()),
+        _ => None,
+    })
+}
+
+/// Returns the strings of `a` that are not in `b`, in sorted order. Both slices must be sorted
+/// with the same comparison function.
+///
+/// This works like `sorted_intersection`. Duplicates are matched in order, so a string that
+/// occurs 3 times in `a` and once in `b` occurs twice in the result.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::{lexical_cmp, sorted_difference};
+///
+/// let a = ["apple", "Banana", "banana", "cherry"];
+/// let b = ["Banana", "cherry"];
+/// let diff: Vec<_> = sorted_difference(&a, &b, lexical_cmp).collect();
+/// assert_eq!(diff, ["apple", "banana"]);
+/// ```
+pub fn sorted_difference<'a, A: AsRef<str>, B: AsRef<str>>(
+    a: &'a [A],
+    b: &'a [B],
+    cmp: impl FnMut(&str, &str) -> Ordering + 'a,
+) -> impl Iterator<Item = &'a str> + 'a {
+    checked_diff_sorted(a, b, cmp).filter_map(move |item| match item {
+        DiffItem::Removed(i) => Some(a[i].as_ref()),
+        _ => None,
+    })
+}
+
+/// Returns the strings that are in `a` or `b`, in sorted order. Both slices must be sorted
+/// with the same comparison function.
+///
+/// This works like `sorted_intersection`. Strings that are in both slices are returned once,
+/// from `a`. Duplicates are matched in order, so a string that occurs 3 times in `a` and twice
+/// in `b` occurs 3 times in the result.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::{lexical_cmp, sorted_union};
+///
+/// let a = ["apple", "cherry"];
+/// let b = ["Banana", "cherry", "date"];
+/// let union: Vec<_> = sorted_union(&a, &b, lexical_cmp).collect();
+/// assert_eq!(union, ["apple", "Banana", "cherry", "date"]);
+/// ```
+pub fn sorted_union<'a, A: AsRef<str>, B: AsRef<str>>(
+    a: &'a [A],
+    b: &'a [B],
+    cmp: impl FnMut(&str, &str) -> Ordering + 'a,
+) -> impl Iterator<Item = &'a str> + 'a {
+    checked_diff_sorted(a, b, cmp).map(move |item| match item {
+        DiffItem::Kept(i, _) | DiffItem::Removed(i) => a[i].as_ref(),
+        DiffItem::Added(j) => b[j].as_ref(),
+    })
+}
+
+/// Returns `diff_sorted(a, b, cmp)`, after checking that the slices are sorted in debug builds
+fn checked_diff_sorted<'a, A: AsRef<str>, B: AsRef<str>>(
+    a: &'a [A],
+    b: &'a [B],
+    mut cmp: impl FnMut(&str, &str) -> Ordering + 'a,
+) -> impl Iterator<Item = DiffItem> + 'a {
+    debug_assert!(is_sorted(a, &mut cmp), "the first slice isn't sorted");
+    debug_assert!(is_sorted(b, &mut cmp), "the second slice isn't sorted");
+    diff_sorted(a, b, cmp)
+}
+
+fn is_sorted<A: AsRef<str>>(s: &[A], cmp: &mut impl FnMut(&str, &str) -> Ordering) -> bool {
+    s.windows(2)
+        .all(|w| cmp(w[0].as_ref(), w[1].as_ref()) != Ordering::Greater)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{lexical_cmp, natural_lexical_cmp, Collator, TieBreak};
+    use DiffItem::{Added, Kept, Removed};
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_diff_sorted() {
+        let diff = |old: &[&str], new: &[&str]| -> Vec<DiffItem> {
+            diff_sorted(old, new, natural_lexical_cmp).collect()
+        };
+
+        // additions and removals
+        assert_eq!(diff(&[], &[]), []);
+        assert_eq!(diff(&["a", "b"], &[]), [Removed(0), Removed(1)]);
+        assert_eq!(diff(&[], &["a", "b"]), [Added(0), Added(1)]);
+        assert_eq!(
+            diff(&["T-2", "T-10", "x"], &["T-1", "T-2", "T-10", "y"]),
+            [Added(0), Kept(0, 1), Kept(1, 2), Removed(2), Added(3)]
+        );
+
+        // duplicates are matched in order
+        assert_eq!(
+            diff(&["a", "a", "a", "b"], &["a", "b", "b"]),
+            [Kept(0, 0), Removed(1), Removed(2), Kept(3, 1), Added(2)]
+        );
+
+        // strings that are equal after folding, but not identical
+        assert_eq!(
+            diff(&["Foo", "fóò"], &["foo", "fóò"]),
+            [Removed(0), Added(0), Kept(1, 1)]
+        );
+        let collator = Collator::new().ties(TieBreak::InputOrder);
+        let diff: Vec<_> = diff_sorted(&["Foo", "fóò"], &["foo", "FOO"], |l, r| {
+            collator.cmp(l, r)
+        })
+        .collect();
+        assert_eq!(diff, [Kept(0, 0), Kept(1, 1)]);
+
+        // the items are in sorted order
+        let old = ["ä", "b", "ß", "z"];
+        let new = ["a", "ä", "ss", "ß"];
+        let merged: Vec<&str> = diff_sorted(&old, &new, lexical_cmp)
+            .map(|item| match item {
+                Added(j) | Kept(_, j) => new[j],
+                Removed(i) => old[i],
+            })
+            .collect();
+        assert_eq!(merged, ["a", "ä", "b", "ss", "ß", "z"]);
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_set_operations() {
+        let a = ["a", "a", "a", "b", "T-5", "T-20", "x"];
+        let b = ["a", "b", "b", "T-10", "T-20", "y"];
+        let cmp = natural_lexical_cmp;
+
+        let both: Vec<_> = sorted_intersection(&a, &b, cmp).collect();
+        assert_eq!(both, ["a", "b", "T-20"]);
+        let diff: Vec<_> = sorted_difference(&a, &b, cmp).collect();
+        assert_eq!(diff, ["a", "a", "T-5", "x"]);
+        let diff: Vec<_> = sorted_difference(&b, &a, cmp).collect();
+        assert_eq!(diff, ["b", "T-10", "y"]);
+        let union: Vec<_> = sorted_union(&a, &b, cmp).collect();
+        assert_eq!(
+            union,
+            ["a", "a", "a", "b", "b", "T-5", "T-10", "T-20", "x", "y"]
+        );
+
+        let empty: [&str; 0] = [];
+        assert_eq!(sorted_intersection(&a, &empty, cmp).count(), 0);
+        assert_eq!(sorted_difference(&a, &empty, cmp).count(), a.len());
+        assert_eq!(sorted_difference(&empty, &a, cmp).count(), 0);
+        assert_eq!(sorted_union(&empty, &b, cmp).count(), b.len());
+
+        // strings that are equal after folding
+        let a = [String::from("Foo"), String::from("Straße")];
+        let b = ["fóò", "STRASSE", "Zürich"];
+        assert_eq!(sorted_intersection(&a, &b, lexical_cmp).count(), 0);
+        let collator = Collator::new().ties(TieBreak::InputOrder);
+        let cmp = |l: &str, r: &str| collator.cmp(l, r);
+        let both: Vec<_> = sorted_intersection(&a, &b, cmp).collect();
+        assert_eq!(both, ["Foo", "Straße"]);
+        let union: Vec<_> = sorted_union(&a, &b, cmp).collect();
+        assert_eq!(union, ["Foo", "Straße", "Zürich"]);
+        let diff: Vec<_> = sorted_difference(&b, &a, cmp).collect();
+        assert_eq!(diff, ["Zürich"]);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "the second slice isn't sorted")]
+    fn test_set_operations_unsorted() {
+        let _ = sorted_union(&["a", "b"], &["b", "a"], lexical_cmp);
+    }
+}
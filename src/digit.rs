@@ -0,0 +1,687 @@
+//! Decimal digits of all scripts, e.g. `٣` (Arabic-Indic digit three) or `３` (fullwidth digit
+//! three), and other characters and words that are compared as numbers, e.g. `¹`, `½`, the
+//! CJK numeral `二十三` or the month `Feb`.
+//!
+//! The table was generated from the Unicode Character Database, version 14.0.0.
+
+/// The kind of a character that is transliterated to digits, e.g. in natural comparisons.
+/// Digits of different kinds belong to different numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DigitKind {
+    /// A digit on the baseline, e.g. `1`, `١` or `１`, or any other character
+    Normal,
+    /// A superscript digit, e.g. `¹`
+    Superscript,
+    /// A subscript digit, e.g. `₁`
+    Subscript,
+    /// A vulgar fraction, e.g. `½`, with its numerator and denominator
+    Fraction(u8, u8),
+    /// A character that is a number of its own, e.g. the Roman numeral `Ⅻ` or the circled
+    /// number `⑩`, with its value
+    Numeral(u32),
+}
+
+impl DigitKind {
+    /// Returns the value of a character that is a number of its own as a numerator and
+    /// a denominator, or `None` if the character can be part of a longer number
+    pub(crate) fn single_value(self) -> Option<(u32, u32)> {
+        match self {
+            DigitKind::Fraction(n, d) => Some((n.into(), d.into())),
+            DigitKind::Numeral(value) => Some((value, 1)),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the kind of a character that is transliterated to digits
+pub(crate) fn digit_kind(c: char) -> DigitKind {
+    match c {
+        '¹' | '²' | '³' | '\u{2070}' | '\u{2074}'..='\u{2079}' => DigitKind::Superscript,
+        '\u{2080}'..='\u{2089}' => DigitKind::Subscript,
+        '¼' => DigitKind::Fraction(1, 4),
+        '½' => DigitKind::Fraction(1, 2),
+        '¾' => DigitKind::Fraction(3, 4),
+        '⅐' => DigitKind::Fraction(1, 7),
+        '⅑' => DigitKind::Fraction(1, 9),
+        '⅒' => DigitKind::Fraction(1, 10),
+        '⅓' => DigitKind::Fraction(1, 3),
+        '⅔' => DigitKind::Fraction(2, 3),
+        '⅕' => DigitKind::Fraction(1, 5),
+        '⅖' => DigitKind::Fraction(2, 5),
+        '⅗' => DigitKind::Fraction(3, 5),
+        '⅘' => DigitKind::Fraction(4, 5),
+        '⅙' => DigitKind::Fraction(1, 6),
+        '⅚' => DigitKind::Fraction(5, 6),
+        '⅛' => DigitKind::Fraction(1, 8),
+        '⅜' => DigitKind::Fraction(3, 8),
+        '⅝' => DigitKind::Fraction(5, 8),
+        '⅞' => DigitKind::Fraction(7, 8),
+        '↉' => DigitKind::Fraction(0, 3),
+        _ => match enclosed_number(c) {
+            Some(value) => DigitKind::Numeral(value),
+            None => DigitKind::Normal,
+        },
+    }
+}
+
+/// Returns the ASCII digit with the same value if `c` is a decimal digit (general category
+/// `Nd`), otherwise `c`
+pub(crate) fn to_ascii_digit(c: char) -> char {
+    if c.is_ascii() {
+        return c;
+    }
+    let i = match DIGIT_ZEROS.binary_search(&c) {
+        Ok(i) => i,
+        Err(0) => return c,
+        Err(i) => i - 1,
+    };
+    let value = c as u32 - DIGIT_ZEROS[i] as u32;
+    if value < 10 {
+        (b'0' + value as u8) as char
+    } else {
+        c
+    }
+}
+
+/// Returns the value of a Roman numeral character (U+2160 to U+2188), e.g. 12 for `Ⅻ`
+pub(crate) fn roman_numeral(c: char) -> Option<u32> {
+    Some(match c {
+        '\u{2160}'..='\u{216b}' => c as u32 - 0x215f,
+        '\u{2170}'..='\u{217b}' => c as u32 - 0x216f,
+        'Ⅼ' | 'ⅼ' | 'ↆ' => 50,
+        'Ⅽ' | 'ⅽ' => 100,
+        'Ⅾ' | 'ⅾ' => 500,
+        'Ⅿ' | 'ⅿ' | 'ↀ' => 1000,
+        'ↁ' => 5000,
+        'ↂ' => 10_000,
+        'ↅ' => 6,
+        'ↇ' => 50_000,
+        'ↈ' => 100_000,
+        _ => return None,
+    })
+}
+
+/// Returns the value of an enclosed number of the Enclosed Alphanumerics and Enclosed
+/// Alphanumeric Supplement blocks, e.g. 10 for `⑩`, `⑽`, `⒑` or `⓾`, and 0 for `🄀`
+pub(crate) fn enclosed_number(c: char) -> Option<u32> {
+    let code = c as u32;
+    Some(match c {
+        '\u{2460}'..='\u{2473}' => code - 0x245f,
+        '\u{2474}'..='\u{2487}' => code - 0x2473,
+        '\u{2488}'..='\u{249b}' => code - 0x2487,
+        '⓪' | '⓿' | '🄀' | '🄋' | '🄌' => 0,
+        '\u{24eb}'..='\u{24f4}' => code - 0x24eb + 11,
+        '\u{24f5}'..='\u{24fe}' => code - 0x24f4,
+        '\u{1f101}'..='\u{1f10a}' => code - 0x1f101,
+        _ => return None,
+    })
+}
+
+/// The English month names and abbreviations, with the numbers of the months
+const MONTH_NAMES: &[(&str, u32)] = &[
+    ("january", 1),
+    ("february", 2),
+    ("march", 3),
+    ("april", 4),
+    ("may", 5),
+    ("june", 6),
+    ("july", 7),
+    ("august", 8),
+    ("september", 9),
+    ("october", 10),
+    ("november", 11),
+    ("december", 12),
+    ("jan", 1),
+    ("feb", 2),
+    ("mar", 3),
+    ("apr", 4),
+    ("jun", 6),
+    ("jul", 7),
+    ("aug", 8),
+    ("sept", 9),
+    ("sep", 9),
+    ("oct", 10),
+    ("nov", 11),
+    ("dec", 12),
+];
+
+/// Returns the number of the month and the byte length of the name if `s` starts with an
+/// English month name or abbreviation (ignoring case) that isn't followed by a letter
+pub(crate) fn month_name(s: &str) -> Option<(u32, usize)> {
+    MONTH_NAMES.iter().find_map(|&(name, month)| {
+        let prefix = s.get(..name.len())?;
+        let followed_by_letter =
+            matches!(s[name.len()..].chars().next(), Some(c) if c.is_alphabetic());
+        if prefix.eq_ignore_ascii_case(name) && !followed_by_letter {
+            Some((month, name.len()))
+        } else {
+            None
+        }
+    })
+}
+
+/// Returns the byte length of the ordinal suffix (`st`, `nd`, `rd` or `th`, ignoring case) at
+/// the start of `s`, if it isn't followed by a letter
+pub(crate) fn ordinal_suffix(s: &str) -> Option<usize> {
+    let suffix = s.get(..2)?;
+    let followed_by_letter = matches!(s[2..].chars().next(), Some(c) if c.is_alphabetic());
+    let is_suffix = ["st", "nd", "rd", "th"]
+        .iter()
+        .any(|ordinal| suffix.eq_ignore_ascii_case(ordinal));
+    if is_suffix && !followed_by_letter {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+/// Byte-size units and their multipliers. Units with a common prefix are listed longest first.
+const BYTE_UNITS: [(&str, u64); 9] = [
+    ("KiB", 1 << 10),
+    ("MiB", 1 << 20),
+    ("GiB", 1 << 30),
+    ("TiB", 1 << 40),
+    ("KB", 1_000),
+    ("MB", 1_000_000),
+    ("GB", 1_000_000_000),
+    ("TB", 1_000_000_000_000),
+    ("B", 1),
+];
+
+/// Returns the value in millionths of a byte and the byte length of the size at the start of
+/// `s`, i.e. a number with an optional decimal part after `separator`, an optional space and a
+/// byte-size unit such as `KB` or `GiB` (ignoring case) that isn't followed by a letter or digit.
+///
+/// Numbers with more than 12 integer digits or more than 6 fractional digits aren't sizes.
+pub(crate) fn byte_size(s: &str, separator: char) -> Option<(u128, usize)> {
+    let int_len = s.bytes().take_while(u8::is_ascii_digit).count();
+    if int_len == 0 || int_len > 12 {
+        return None;
+    }
+    let mut value: u128 = s[..int_len].parse().ok()?;
+    let mut len = int_len;
+    let mut frac_len = 0;
+    if s[len..].starts_with(separator) {
+        let digits = &s[len + separator.len_utf8()..];
+        frac_len = digits.bytes().take_while(u8::is_ascii_digit).count();
+        if frac_len > 6 {
+            return None;
+        } else if frac_len > 0 {
+            value =
+                value * 10u128.pow(frac_len as u32) + digits[..frac_len].parse::<u128>().ok()?;
+            len += separator.len_utf8() + frac_len;
+        }
+    }
+    value *= 10u128.pow(6 - frac_len as u32);
+
+    if s[len..].starts_with(' ') {
+        len += 1;
+    }
+    let rest = &s[len..];
+    let &(unit, multiplier) = BYTE_UNITS.iter().find(
+        |(unit, _)| matches!(rest.get(..unit.len()), Some(u) if u.eq_ignore_ascii_case(unit)),
+    )?;
+    if matches!(rest[unit.len()..].chars().next(), Some(c) if c.is_alphanumeric()) {
+        return None;
+    }
+    Some((value * u128::from(multiplier), len + unit.len()))
+}
+
+/// Returns the IPv4 address and the byte length of the dotted quad at the start of `s`, e.g.
+/// `192.168.0.1`, i.e. four numbers from 0 to 255 with at most 3 digits, separated by dots. The
+/// last number must not be followed by another dot and a digit.
+pub(crate) fn ipv4_address(s: &str) -> Option<(u32, usize)> {
+    let bytes = s.as_bytes();
+    let mut address = 0;
+    let mut len = 0;
+    for i in 0..4 {
+        if i > 0 {
+            if bytes.get(len) != Some(&b'.') {
+                return None;
+            }
+            len += 1;
+        }
+        let digits = bytes[len..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+        if digits == 0 || digits > 3 {
+            return None;
+        }
+        let octet: u32 = s[len..len + digits].parse().ok()?;
+        if octet > 255 {
+            return None;
+        }
+        address = address << 8 | octet;
+        len += digits;
+    }
+    if bytes.get(len) == Some(&b'.') && matches!(bytes.get(len + 1), Some(b) if b.is_ascii_digit())
+    {
+        return None;
+    }
+    Some((address, len))
+}
+
+/// Returns the number of fields, the value and the byte length of the duration or clock time at
+/// the start of `s`, e.g. `1:30` or `1:02:45`, i.e. two or three fields separated by colons. The
+/// first field has 1 to 3 digits, the others have exactly 2 digits, and the last field must not
+/// be followed by a digit or by another colon and a digit.
+///
+/// The value has 2 decimal digits per field after the first, so durations with the same number
+/// of fields are compared field by field when comparing their values.
+pub(crate) fn duration(s: &str) -> Option<(usize, u32, usize)> {
+    let bytes = s.as_bytes();
+    let digits = |start: usize| {
+        bytes[start..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count()
+    };
+    let len = digits(0);
+    if len == 0 || len > 3 {
+        return None;
+    }
+    let mut value: u32 = s[..len].parse().ok()?;
+    let mut len = len;
+    let mut fields = 1;
+    while fields < 3 && bytes.get(len) == Some(&b':') && digits(len + 1) == 2 {
+        value = value * 100 + s[len + 1..len + 3].parse::<u32>().ok()?;
+        len += 3;
+        fields += 1;
+    }
+    let followed_by_number = bytes.get(len) == Some(&b':')
+        && matches!(bytes.get(len + 1), Some(b) if b.is_ascii_digit());
+    if fields == 1 || followed_by_number {
+        return None;
+    }
+    Some((fields, value, len))
+}
+
+/// Returns the first decimal digit of a number
+pub(crate) fn first_digit(mut value: u32) -> char {
+    while value >= 10 {
+        value /= 10;
+    }
+    (b'0' + value as u8) as char
+}
+
+/// Returns the value of a CJK numeral digit, e.g. 2 for `二`
+pub(crate) fn cjk_digit(c: char) -> Option<u32> {
+    Some(match c {
+        '〇' => 0,
+        '一' => 1,
+        '二' => 2,
+        '三' => 3,
+        '四' => 4,
+        '五' => 5,
+        '六' => 6,
+        '七' => 7,
+        '八' => 8,
+        '九' => 9,
+        _ => return None,
+    })
+}
+
+/// Returns the value of a CJK numeral multiplier, e.g. 10 for `十`
+fn cjk_multiplier(c: char) -> Option<u32> {
+    Some(match c {
+        '十' => 10,
+        '百' => 100,
+        '千' => 1000,
+        _ => return None,
+    })
+}
+
+/// Parses a CJK numeral with multipliers at the start of `s`, e.g. `十` (10) or `二十三` (23).
+/// Returns the value and the byte length of the numeral.
+///
+/// Multipliers must decrease, and a digit can only follow a multiplier or `〇`, so the numeral
+/// ends before the first character that doesn't fit, e.g. `十十` is parsed as `十`. Returns
+/// `None` if `s` doesn't start with a numeral that contains a multiplier; digits without a
+/// multiplier, as in `二〇二三` (2023), are positional and can be converted one by one.
+pub(crate) fn parse_cjk_numeral(s: &str) -> Option<(u32, usize)> {
+    let mut total = 0;
+    let mut digit = None;
+    let mut last_multiplier = None;
+    let mut len = 0;
+    for c in s.chars() {
+        if let Some(d) = cjk_digit(c) {
+            if matches!(digit, Some(prev) if prev != 0) {
+                break;
+            }
+            digit = Some(d);
+        } else if let Some(m) = cjk_multiplier(c) {
+            if matches!(last_multiplier, Some(last) if last <= m) {
+                break;
+            }
+            total += digit.unwrap_or(1) * m;
+            digit = None;
+            last_multiplier = Some(m);
+        } else {
+            break;
+        }
+        len += c.len_utf8();
+    }
+    last_multiplier?;
+    Some((total + digit.unwrap_or(0), len))
+}
+
+/// Iterator over the ASCII digits of a number
+#[derive(Clone, Default)]
+pub(crate) struct DecimalDigits {
+    value: u32,
+    /// The place value of the next digit, or 0 if there are no digits left
+    divisor: u32,
+}
+
+impl DecimalDigits {
+    pub(crate) fn new(value: u32) -> Self {
+        let mut divisor = 1;
+        while divisor <= value / 10 {
+            divisor *= 10;
+        }
+        DecimalDigits { value, divisor }
+    }
+}
+
+impl Iterator for DecimalDigits {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if self.divisor == 0 {
+            return None;
+        }
+        let digit = self.value / self.divisor % 10;
+        self.divisor /= 10;
+        Some((b'0' + digit as u8) as char)
+    }
+}
+
+/// The digit zero of every script. The other digits follow their zero, so every decimal digit
+/// is in the range of its zero and the nine characters after it.
+const DIGIT_ZEROS: &[char] = &[
+    '\u{30}',
+    '\u{660}',
+    '\u{6f0}',
+    '\u{7c0}',
+    '\u{966}',
+    '\u{9e6}',
+    '\u{a66}',
+    '\u{ae6}',
+    '\u{b66}',
+    '\u{be6}',
+    '\u{c66}',
+    '\u{ce6}',
+    '\u{d66}',
+    '\u{de6}',
+    '\u{e50}',
+    '\u{ed0}',
+    '\u{f20}',
+    '\u{1040}',
+    '\u{1090}',
+    '\u{17e0}',
+    '\u{1810}',
+    '\u{1946}',
+    '\u{19d0}',
+    '\u{1a80}',
+    '\u{1a90}',
+    '\u{1b50}',
+    '\u{1bb0}',
+    '\u{1c40}',
+    '\u{1c50}',
+    '\u{a620}',
+    '\u{a8d0}',
+    '\u{a900}',
+    '\u{a9d0}',
+    '\u{a9f0}',
+    '\u{aa50}',
+    '\u{abf0}',
+    '\u{ff10}',
+    '\u{104a0}',
+    '\u{10d30}',
+    '\u{11066}',
+    '\u{110f0}',
+    '\u{11136}',
+    '\u{111d0}',
+    '\u{112f0}',
+    '\u{11450}',
+    '\u{114d0}',
+    '\u{11650}',
+    '\u{116c0}',
+    '\u{11730}',
+    '\u{118e0}',
+    '\u{11950}',
+    '\u{11c50}',
+    '\u{11d50}',
+    '\u{11da0}',
+    '\u{16a60}',
+    '\u{16ac0}',
+    '\u{16b50}',
+    '\u{1d7ce}',
+    '\u{1d7d8}',
+    '\u{1d7e2}',
+    '\u{1d7ec}',
+    '\u{1d7f6}',
+    '\u{1e140}',
+    '\u{1e2f0}',
+    '\u{1e950}',
+    '\u{1fbf0}',
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_ascii_digit() {
+        assert_eq!(to_ascii_digit('7'), '7');
+        assert_eq!(to_ascii_digit('a'), 'a');
+        assert_eq!(to_ascii_digit('٣'), '3');
+        assert_eq!(to_ascii_digit('١'), '1');
+        assert_eq!(to_ascii_digit('९'), '9');
+        assert_eq!(to_ascii_digit('０'), '0');
+        assert_eq!(to_ascii_digit('９'), '9');
+        assert_eq!(to_ascii_digit('𝟗'), '9');
+        assert_eq!(to_ascii_digit('𝟘'), '0');
+
+        // not decimal digits
+        assert_eq!(to_ascii_digit('²'), '²');
+        assert_eq!(to_ascii_digit('½'), '½');
+        assert_eq!(to_ascii_digit('Ⅻ'), 'Ⅻ');
+        assert_eq!(to_ascii_digit('十'), '十');
+        assert_eq!(to_ascii_digit('\u{65f}'), '\u{65f}');
+
+        assert!(DIGIT_ZEROS
+            .windows(2)
+            .all(|w| w[0] as u32 + 10 <= w[1] as u32));
+    }
+
+    #[test]
+    fn test_roman_numeral() {
+        assert_eq!(roman_numeral('Ⅰ'), Some(1));
+        assert_eq!(roman_numeral('Ⅳ'), Some(4));
+        assert_eq!(roman_numeral('Ⅻ'), Some(12));
+        assert_eq!(roman_numeral('ⅰ'), Some(1));
+        assert_eq!(roman_numeral('ⅳ'), Some(4));
+        assert_eq!(roman_numeral('ⅻ'), Some(12));
+        assert_eq!(roman_numeral('Ⅼ'), Some(50));
+        assert_eq!(roman_numeral('ⅿ'), Some(1000));
+        assert_eq!(roman_numeral('ↈ'), Some(100_000));
+
+        // the reversed C is a letter without a numeric value
+        assert_eq!(roman_numeral('Ↄ'), None);
+        assert_eq!(roman_numeral('ↄ'), None);
+        assert_eq!(roman_numeral('X'), None);
+        assert_eq!(roman_numeral('1'), None);
+    }
+
+    #[test]
+    fn test_enclosed_number() {
+        assert_eq!(enclosed_number('①'), Some(1));
+        assert_eq!(enclosed_number('⑳'), Some(20));
+        assert_eq!(enclosed_number('⑴'), Some(1));
+        assert_eq!(enclosed_number('⑽'), Some(10));
+        assert_eq!(enclosed_number('⒇'), Some(20));
+        assert_eq!(enclosed_number('⒈'), Some(1));
+        assert_eq!(enclosed_number('⒛'), Some(20));
+        assert_eq!(enclosed_number('⓪'), Some(0));
+        assert_eq!(enclosed_number('⓫'), Some(11));
+        assert_eq!(enclosed_number('⓴'), Some(20));
+        assert_eq!(enclosed_number('⓵'), Some(1));
+        assert_eq!(enclosed_number('⓾'), Some(10));
+        assert_eq!(enclosed_number('⓿'), Some(0));
+        assert_eq!(enclosed_number('🄀'), Some(0));
+        assert_eq!(enclosed_number('🄁'), Some(0));
+        assert_eq!(enclosed_number('🄊'), Some(9));
+        assert_eq!(enclosed_number('🄌'), Some(0));
+
+        assert_eq!(enclosed_number('⒜'), None);
+        assert_eq!(enclosed_number('Ⓐ'), None);
+        assert_eq!(enclosed_number('1'), None);
+        assert_eq!(digit_kind('⑩'), DigitKind::Numeral(10));
+    }
+
+    #[test]
+    fn test_month_name() {
+        assert_eq!(month_name("Jan"), Some((1, 3)));
+        assert_eq!(month_name("january"), Some((1, 7)));
+        assert_eq!(month_name("FEB-2021"), Some((2, 3)));
+        assert_eq!(month_name("Sept."), Some((9, 4)));
+        assert_eq!(month_name("Sep 1"), Some((9, 3)));
+        assert_eq!(month_name("May2021"), Some((5, 3)));
+        assert_eq!(month_name("December"), Some((12, 8)));
+
+        assert_eq!(month_name("Janet"), None);
+        assert_eq!(month_name("Janu"), None);
+        assert_eq!(month_name("Ja"), None);
+        assert_eq!(month_name("Marché"), None);
+        assert_eq!(month_name("Maybe"), None);
+        assert_eq!(month_name(""), None);
+    }
+
+    #[test]
+    fn test_ordinal_suffix() {
+        assert_eq!(ordinal_suffix("st"), Some(2));
+        assert_eq!(ordinal_suffix("nd draft"), Some(2));
+        assert_eq!(ordinal_suffix("RD"), Some(2));
+        assert_eq!(ordinal_suffix("th-3"), Some(2));
+        assert_eq!(ordinal_suffix("th3"), Some(2));
+
+        assert_eq!(ordinal_suffix("thousand"), None);
+        assert_eq!(ordinal_suffix("s"), None);
+        assert_eq!(ordinal_suffix("nt"), None);
+        assert_eq!(ordinal_suffix("ß"), None);
+        assert_eq!(ordinal_suffix(""), None);
+    }
+
+    #[test]
+    fn test_byte_size() {
+        const MB: u128 = 1_000_000 * 1_000_000;
+        assert_eq!(byte_size("1 B", '.'), Some((1_000_000, 3)));
+        assert_eq!(byte_size("900 MB", '.'), Some((900 * MB, 6)));
+        assert_eq!(byte_size("900MB", '.'), Some((900 * MB, 5)));
+        assert_eq!(byte_size("1.5 GB", '.'), Some((1500 * MB, 6)));
+        assert_eq!(byte_size("1,5 gb", ','), Some((1500 * MB, 6)));
+        assert_eq!(byte_size("1.5 GB", ','), None);
+        assert_eq!(byte_size("0.000001 kb.", '.'), Some((1_000, 11)));
+        assert_eq!(byte_size("2 KiB, 3 KiB", '.'), Some((2048 * 1_000_000, 5)));
+        assert_eq!(byte_size("1 TiB", '.'), Some(((1 << 40) * 1_000_000, 5)));
+        assert_eq!(
+            byte_size("999999999999.999999 TB", '.'),
+            Some((999_999_999_999_999_999 * MB, 22))
+        );
+
+        assert_eq!(byte_size("10 Bytes", '.'), None);
+        assert_eq!(byte_size("10 GB2", '.'), None);
+        assert_eq!(byte_size("10  GB", '.'), None);
+        assert_eq!(byte_size("10 KiBi", '.'), None);
+        assert_eq!(byte_size("1. GB", '.'), None);
+        assert_eq!(byte_size("1.0000001 GB", '.'), None);
+        assert_eq!(byte_size("1000000000000 B", '.'), None);
+        assert_eq!(byte_size("GB", '.'), None);
+        assert_eq!(byte_size("", '.'), None);
+    }
+
+    #[test]
+    fn test_ipv4_address() {
+        assert_eq!(ipv4_address("10.0.0.2"), Some((0x0A00_0002, 8)));
+        assert_eq!(ipv4_address("192.168.1.10-prod"), Some((0xC0A8_010A, 12)));
+        assert_eq!(ipv4_address("255.255.255.255."), Some((0xFFFF_FFFF, 15)));
+        assert_eq!(ipv4_address("010.000.0.1 x"), Some((0x0A00_0001, 11)));
+        assert_eq!(ipv4_address("1.2.3.4.x"), Some((0x0102_0304, 7)));
+        assert_eq!(ipv4_address("1.2.3.4a"), Some((0x0102_0304, 7)));
+
+        assert_eq!(ipv4_address("10.0.0"), None);
+        assert_eq!(ipv4_address("10.0.0abc"), None);
+        assert_eq!(ipv4_address("10.0.0.x"), None);
+        assert_eq!(ipv4_address("1.2.3.4.5"), None);
+        assert_eq!(ipv4_address("1.2.3.256"), None);
+        assert_eq!(ipv4_address("1.2.3.0001"), None);
+        assert_eq!(ipv4_address("1..2.3.4"), None);
+        assert_eq!(ipv4_address(".1.2.3.4"), None);
+        assert_eq!(ipv4_address(""), None);
+    }
+
+    #[test]
+    fn test_duration() {
+        assert_eq!(duration("1:30"), Some((2, 130, 4)));
+        assert_eq!(duration("12:05 Track"), Some((2, 1205, 5)));
+        assert_eq!(duration("1:02:45"), Some((3, 10245, 7)));
+        assert_eq!(duration("01:02:45"), Some((3, 10245, 8)));
+        assert_eq!(duration("100:00:00"), Some((3, 1_000_000, 9)));
+        assert_eq!(duration("9:59pm"), Some((2, 959, 4)));
+        assert_eq!(duration("9:59:"), Some((2, 959, 4)));
+        assert_eq!(duration("9:59.5"), Some((2, 959, 4)));
+
+        assert_eq!(duration("12:monkeys"), None);
+        assert_eq!(duration("12"), None);
+        assert_eq!(duration("12:"), None);
+        assert_eq!(duration("12:5"), None);
+        assert_eq!(duration("12:050"), None);
+        assert_eq!(duration("1000:00"), None);
+        assert_eq!(duration("1:00:00:00"), None);
+        assert_eq!(duration("1:00:00:0"), None);
+        assert_eq!(duration(":30"), None);
+        assert_eq!(duration(""), None);
+    }
+
+    #[test]
+    fn test_first_digit() {
+        assert_eq!(first_digit(0), '0');
+        assert_eq!(first_digit(7), '7');
+        assert_eq!(first_digit(12), '1');
+        assert_eq!(first_digit(90210), '9');
+    }
+
+    #[test]
+    fn test_parse_cjk_numeral() {
+        assert_eq!(parse_cjk_numeral("十"), Some((10, 3)));
+        assert_eq!(parse_cjk_numeral("十一"), Some((11, 6)));
+        assert_eq!(parse_cjk_numeral("二十"), Some((20, 6)));
+        assert_eq!(parse_cjk_numeral("二十三巻"), Some((23, 9)));
+        assert_eq!(parse_cjk_numeral("百二十"), Some((120, 9)));
+        assert_eq!(parse_cjk_numeral("三千〇五"), Some((3005, 12)));
+        assert_eq!(parse_cjk_numeral("九千九百九十九"), Some((9999, 21)));
+
+        // the numeral ends before a character that doesn't fit
+        assert_eq!(parse_cjk_numeral("十十"), Some((10, 3)));
+        assert_eq!(parse_cjk_numeral("十百"), Some((10, 3)));
+        assert_eq!(parse_cjk_numeral("十二三"), Some((12, 6)));
+
+        // no multiplier
+        assert_eq!(parse_cjk_numeral("二"), None);
+        assert_eq!(parse_cjk_numeral("二〇二三"), None);
+        assert_eq!(parse_cjk_numeral("x十"), None);
+        assert_eq!(parse_cjk_numeral(""), None);
+    }
+
+    #[test]
+    fn test_decimal_digits() {
+        assert!(DecimalDigits::default().eq("".chars()));
+        assert!(DecimalDigits::new(0).eq("0".chars()));
+        assert!(DecimalDigits::new(7).eq("7".chars()));
+        assert!(DecimalDigits::new(10).eq("10".chars()));
+        assert!(DecimalDigits::new(3005).eq("3005".chars()));
+        assert!(DecimalDigits::new(u32::MAX).eq("4294967295".chars()));
+    }
+}
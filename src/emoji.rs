@@ -0,0 +1,1188 @@
+//! Short names of emojis, to order emojis by name instead of by code point.
+//!
+//! The table was generated from `emoji-test.txt` of Unicode Emoji, version 15.1. It contains
+//! the CLDR short names of all emojis that consist of a single code point and are displayed as
+//! emojis by default (i.e. have the `Emoji_Presentation` property).
+
+/// Returns the CLDR short name of an emoji, e.g. `"party popper"` for `'🎉'`
+pub(crate) fn emoji_name(c: char) -> Option<&'static str> {
+    EMOJI_NAMES
+        .binary_search_by_key(&c, |&(e, _)| e)
+        .ok()
+        .map(|i| EMOJI_NAMES[i].1)
+}
+
+/// Emojis and their short names, sorted by code point
+#[rustfmt::skip]
+const EMOJI_NAMES: &[(char, &str)] = &[
+    ('\u{231A}', "watch"),
+    ('\u{231B}', "hourglass done"),
+    ('\u{23E9}', "fast-forward button"),
+    ('\u{23EA}', "fast reverse button"),
+    ('\u{23EB}', "fast up button"),
+    ('\u{23EC}', "fast down button"),
+    ('\u{23F0}', "alarm clock"),
+    ('\u{23F3}', "hourglass not done"),
+    ('\u{25FD}', "white medium-small square"),
+    ('\u{25FE}', "black medium-small square"),
+    ('\u{2614}', "umbrella with rain drops"),
+    ('\u{2615}', "hot beverage"),
+    ('\u{2648}', "Aries"),
+    ('\u{2649}', "Taurus"),
+    ('\u{264A}', "Gemini"),
+    ('\u{264B}', "Cancer"),
+    ('\u{264C}', "Leo"),
+    ('\u{264D}', "Virgo"),
+    ('\u{264E}', "Libra"),
+    ('\u{264F}', "Scorpio"),
+    ('\u{2650}', "Sagittarius"),
+    ('\u{2651}', "Capricorn"),
+    ('\u{2652}', "Aquarius"),
+    ('\u{2653}', "Pisces"),
+    ('\u{267F}', "wheelchair symbol"),
+    ('\u{2693}', "anchor"),
+    ('\u{26A1}', "high voltage"),
+    ('\u{26AA}', "white circle"),
+    ('\u{26AB}', "black circle"),
+    ('\u{26BD}', "soccer ball"),
+    ('\u{26BE}', "baseball"),
+    ('\u{26C4}', "snowman without snow"),
+    ('\u{26C5}', "sun behind cloud"),
+    ('\u{26CE}', "Ophiuchus"),
+    ('\u{26D4}', "no entry"),
+    ('\u{26EA}', "church"),
+    ('\u{26F2}', "fountain"),
+    ('\u{26F3}', "flag in hole"),
+    ('\u{26F5}', "sailboat"),
+    ('\u{26FA}', "tent"),
+    ('\u{26FD}', "fuel pump"),
+    ('\u{2705}', "check mark button"),
+    ('\u{270A}', "raised fist"),
+    ('\u{270B}', "raised hand"),
+    ('\u{2728}', "sparkles"),
+    ('\u{274C}', "cross mark"),
+    ('\u{274E}', "cross mark button"),
+    ('\u{2753}', "red question mark"),
+    ('\u{2754}', "white question mark"),
+    ('\u{2755}', "white exclamation mark"),
+    ('\u{2757}', "red exclamation mark"),
+    ('\u{2795}', "plus"),
+    ('\u{2796}', "minus"),
+    ('\u{2797}', "divide"),
+    ('\u{27B0}', "curly loop"),
+    ('\u{27BF}', "double curly loop"),
+    ('\u{2B1B}', "black large square"),
+    ('\u{2B1C}', "white large square"),
+    ('\u{2B50}', "star"),
+    ('\u{2B55}', "hollow red circle"),
+    ('\u{1F004}', "mahjong red dragon"),
+    ('\u{1F0CF}', "joker"),
+    ('\u{1F18E}', "AB button (blood type)"),
+    ('\u{1F191}', "CL button"),
+    ('\u{1F192}', "COOL button"),
+    ('\u{1F193}', "FREE button"),
+    ('\u{1F194}', "ID button"),
+    ('\u{1F195}', "NEW button"),
+    ('\u{1F196}', "NG button"),
+    ('\u{1F197}', "OK button"),
+    ('\u{1F198}', "SOS button"),
+    ('\u{1F199}', "UP! button"),
+    ('\u{1F19A}', "VS button"),
+    ('\u{1F201}', "Japanese “here” button"),
+    ('\u{1F21A}', "Japanese “free of charge” button"),
+    ('\u{1F22F}', "Japanese “reserved” button"),
+    ('\u{1F232}', "Japanese “prohibited” button"),
+    ('\u{1F233}', "Japanese “vacancy” button"),
+    ('\u{1F234}', "Japanese “passing grade” button"),
+    ('\u{1F235}', "Japanese “no vacancy” button"),
+    ('\u{1F236}', "Japanese “not free of charge” button"),
+    ('\u{1F238}', "Japanese “application” button"),
+    ('\u{1F239}', "Japanese “discount” button"),
+    ('\u{1F23A}', "Japanese “open for business” button"),
+    ('\u{1F250}', "Japanese “bargain” button"),
+    ('\u{1F251}', "Japanese “acceptable” button"),
+    ('\u{1F300}', "cyclone"),
+    ('\u{1F301}', "foggy"),
+    ('\u{1F302}', "closed umbrella"),
+    ('\u{1F303}', "night with stars"),
+    ('\u{1F304}', "sunrise over mountains"),
+    ('\u{1F305}', "sunrise"),
+    ('\u{1F306}', "cityscape at dusk"),
+    ('\u{1F307}', "sunset"),
+    ('\u{1F308}', "rainbow"),
+    ('\u{1F309}', "bridge at night"),
+    ('\u{1F30A}', "water wave"),
+    ('\u{1F30B}', "volcano"),
+    ('\u{1F30C}', "milky way"),
+    ('\u{1F30D}', "globe showing Europe-Africa"),
+    ('\u{1F30E}', "globe showing Americas"),
+    ('\u{1F30F}', "globe showing Asia-Australia"),
+    ('\u{1F310}', "globe with meridians"),
+    ('\u{1F311}', "new moon"),
+    ('\u{1F312}', "waxing crescent moon"),
+    ('\u{1F313}', "first quarter moon"),
+    ('\u{1F314}', "waxing gibbous moon"),
+    ('\u{1F315}', "full moon"),
+    ('\u{1F316}', "waning gibbous moon"),
+    ('\u{1F317}', "last quarter moon"),
+    ('\u{1F318}', "waning crescent moon"),
+    ('\u{1F319}', "crescent moon"),
+    ('\u{1F31A}', "new moon face"),
+    ('\u{1F31B}', "first quarter moon face"),
+    ('\u{1F31C}', "last quarter moon face"),
+    ('\u{1F31D}', "full moon face"),
+    ('\u{1F31E}', "sun with face"),
+    ('\u{1F31F}', "glowing star"),
+    ('\u{1F320}', "shooting star"),
+    ('\u{1F32D}', "hot dog"),
+    ('\u{1F32E}', "taco"),
+    ('\u{1F32F}', "burrito"),
+    ('\u{1F330}', "chestnut"),
+    ('\u{1F331}', "seedling"),
+    ('\u{1F332}', "evergreen tree"),
+    ('\u{1F333}', "deciduous tree"),
+    ('\u{1F334}', "palm tree"),
+    ('\u{1F335}', "cactus"),
+    ('\u{1F337}', "tulip"),
+    ('\u{1F338}', "cherry blossom"),
+    ('\u{1F339}', "rose"),
+    ('\u{1F33A}', "hibiscus"),
+    ('\u{1F33B}', "sunflower"),
+    ('\u{1F33C}', "blossom"),
+    ('\u{1F33D}', "ear of corn"),
+    ('\u{1F33E}', "sheaf of rice"),
+    ('\u{1F33F}', "herb"),
+    ('\u{1F340}', "four leaf clover"),
+    ('\u{1F341}', "maple leaf"),
+    ('\u{1F342}', "fallen leaf"),
+    ('\u{1F343}', "leaf fluttering in wind"),
+    ('\u{1F344}', "mushroom"),
+    ('\u{1F345}', "tomato"),
+    ('\u{1F346}', "eggplant"),
+    ('\u{1F347}', "grapes"),
+    ('\u{1F348}', "melon"),
+    ('\u{1F349}', "watermelon"),
+    ('\u{1F34A}', "tangerine"),
+    ('\u{1F34B}', "lemon"),
+    ('\u{1F34C}', "banana"),
+    ('\u{1F34D}', "pineapple"),
+    ('\u{1F34E}', "red apple"),
+    ('\u{1F34F}', "green apple"),
+    ('\u{1F350}', "pear"),
+    ('\u{1F351}', "peach"),
+    ('\u{1F352}', "cherries"),
+    ('\u{1F353}', "strawberry"),
+    ('\u{1F354}', "hamburger"),
+    ('\u{1F355}', "pizza"),
+    ('\u{1F356}', "meat on bone"),
+    ('\u{1F357}', "poultry leg"),
+    ('\u{1F358}', "rice cracker"),
+    ('\u{1F359}', "rice ball"),
+    ('\u{1F35A}', "cooked rice"),
+    ('\u{1F35B}', "curry rice"),
+    ('\u{1F35C}', "steaming bowl"),
+    ('\u{1F35D}', "spaghetti"),
+    ('\u{1F35E}', "bread"),
+    ('\u{1F35F}', "french fries"),
+    ('\u{1F360}', "roasted sweet potato"),
+    ('\u{1F361}', "dango"),
+    ('\u{1F362}', "oden"),
+    ('\u{1F363}', "sushi"),
+    ('\u{1F364}', "fried shrimp"),
+    ('\u{1F365}', "fish cake with swirl"),
+    ('\u{1F366}', "soft ice cream"),
+    ('\u{1F367}', "shaved ice"),
+    ('\u{1F368}', "ice cream"),
+    ('\u{1F369}', "doughnut"),
+    ('\u{1F36A}', "cookie"),
+    ('\u{1F36B}', "chocolate bar"),
+    ('\u{1F36C}', "candy"),
+    ('\u{1F36D}', "lollipop"),
+    ('\u{1F36E}', "custard"),
+    ('\u{1F36F}', "honey pot"),
+    ('\u{1F370}', "shortcake"),
+    ('\u{1F371}', "bento box"),
+    ('\u{1F372}', "pot of food"),
+    ('\u{1F373}', "cooking"),
+    ('\u{1F374}', "fork and knife"),
+    ('\u{1F375}', "teacup without handle"),
+    ('\u{1F376}', "sake"),
+    ('\u{1F377}', "wine glass"),
+    ('\u{1F378}', "cocktail glass"),
+    ('\u{1F379}', "tropical drink"),
+    ('\u{1F37A}', "beer mug"),
+    ('\u{1F37B}', "clinking beer mugs"),
+    ('\u{1F37C}', "baby bottle"),
+    ('\u{1F37E}', "bottle with popping cork"),
+    ('\u{1F37F}', "popcorn"),
+    ('\u{1F380}', "ribbon"),
+    ('\u{1F381}', "wrapped gift"),
+    ('\u{1F382}', "birthday cake"),
+    ('\u{1F383}', "jack-o-lantern"),
+    ('\u{1F384}', "Christmas tree"),
+    ('\u{1F385}', "Santa Claus"),
+    ('\u{1F386}', "fireworks"),
+    ('\u{1F387}', "sparkler"),
+    ('\u{1F388}', "balloon"),
+    ('\u{1F389}', "party popper"),
+    ('\u{1F38A}', "confetti ball"),
+    ('\u{1F38B}', "tanabata tree"),
+    ('\u{1F38C}', "crossed flags"),
+    ('\u{1F38D}', "pine decoration"),
+    ('\u{1F38E}', "Japanese dolls"),
+    ('\u{1F38F}', "carp streamer"),
+    ('\u{1F390}', "wind chime"),
+    ('\u{1F391}', "moon viewing ceremony"),
+    ('\u{1F392}', "backpack"),
+    ('\u{1F393}', "graduation cap"),
+    ('\u{1F3A0}', "carousel horse"),
+    ('\u{1F3A1}', "ferris wheel"),
+    ('\u{1F3A2}', "roller coaster"),
+    ('\u{1F3A3}', "fishing pole"),
+    ('\u{1F3A4}', "microphone"),
+    ('\u{1F3A5}', "movie camera"),
+    ('\u{1F3A6}', "cinema"),
+    ('\u{1F3A7}', "headphone"),
+    ('\u{1F3A8}', "artist palette"),
+    ('\u{1F3A9}', "top hat"),
+    ('\u{1F3AA}', "circus tent"),
+    ('\u{1F3AB}', "ticket"),
+    ('\u{1F3AC}', "clapper board"),
+    ('\u{1F3AD}', "performing arts"),
+    ('\u{1F3AE}', "video game"),
+    ('\u{1F3AF}', "bullseye"),
+    ('\u{1F3B0}', "slot machine"),
+    ('\u{1F3B1}', "pool 8 ball"),
+    ('\u{1F3B2}', "game die"),
+    ('\u{1F3B3}', "bowling"),
+    ('\u{1F3B4}', "flower playing cards"),
+    ('\u{1F3B5}', "musical note"),
+    ('\u{1F3B6}', "musical notes"),
+    ('\u{1F3B7}', "saxophone"),
+    ('\u{1F3B8}', "guitar"),
+    ('\u{1F3B9}', "musical keyboard"),
+    ('\u{1F3BA}', "trumpet"),
+    ('\u{1F3BB}', "violin"),
+    ('\u{1F3BC}', "musical score"),
+    ('\u{1F3BD}', "running shirt"),
+    ('\u{1F3BE}', "tennis"),
+    ('\u{1F3BF}', "skis"),
+    ('\u{1F3C0}', "basketball"),
+    ('\u{1F3C1}', "chequered flag"),
+    ('\u{1F3C2}', "snowboarder"),
+    ('\u{1F3C3}', "person running"),
+    ('\u{1F3C4}', "person surfing"),
+    ('\u{1F3C5}', "sports medal"),
+    ('\u{1F3C6}', "trophy"),
+    ('\u{1F3C7}', "horse racing"),
+    ('\u{1F3C8}', "american football"),
+    ('\u{1F3C9}', "rugby football"),
+    ('\u{1F3CA}', "person swimming"),
+    ('\u{1F3CF}', "cricket game"),
+    ('\u{1F3D0}', "volleyball"),
+    ('\u{1F3D1}', "field hockey"),
+    ('\u{1F3D2}', "ice hockey"),
+    ('\u{1F3D3}', "ping pong"),
+    ('\u{1F3E0}', "house"),
+    ('\u{1F3E1}', "house with garden"),
+    ('\u{1F3E2}', "office building"),
+    ('\u{1F3E3}', "Japanese post office"),
+    ('\u{1F3E4}', "post office"),
+    ('\u{1F3E5}', "hospital"),
+    ('\u{1F3E6}', "bank"),
+    ('\u{1F3E7}', "ATM sign"),
+    ('\u{1F3E8}', "hotel"),
+    ('\u{1F3E9}', "love hotel"),
+    ('\u{1F3EA}', "convenience store"),
+    ('\u{1F3EB}', "school"),
+    ('\u{1F3EC}', "department store"),
+    ('\u{1F3ED}', "factory"),
+    ('\u{1F3EE}', "red paper lantern"),
+    ('\u{1F3EF}', "Japanese castle"),
+    ('\u{1F3F0}', "castle"),
+    ('\u{1F3F4}', "black flag"),
+    ('\u{1F3F8}', "badminton"),
+    ('\u{1F3F9}', "bow and arrow"),
+    ('\u{1F3FA}', "amphora"),
+    ('\u{1F400}', "rat"),
+    ('\u{1F401}', "mouse"),
+    ('\u{1F402}', "ox"),
+    ('\u{1F403}', "water buffalo"),
+    ('\u{1F404}', "cow"),
+    ('\u{1F405}', "tiger"),
+    ('\u{1F406}', "leopard"),
+    ('\u{1F407}', "rabbit"),
+    ('\u{1F408}', "cat"),
+    ('\u{1F409}', "dragon"),
+    ('\u{1F40A}', "crocodile"),
+    ('\u{1F40B}', "whale"),
+    ('\u{1F40C}', "snail"),
+    ('\u{1F40D}', "snake"),
+    ('\u{1F40E}', "horse"),
+    ('\u{1F40F}', "ram"),
+    ('\u{1F410}', "goat"),
+    ('\u{1F411}', "ewe"),
+    ('\u{1F412}', "monkey"),
+    ('\u{1F413}', "rooster"),
+    ('\u{1F414}', "chicken"),
+    ('\u{1F415}', "dog"),
+    ('\u{1F416}', "pig"),
+    ('\u{1F417}', "boar"),
+    ('\u{1F418}', "elephant"),
+    ('\u{1F419}', "octopus"),
+    ('\u{1F41A}', "spiral shell"),
+    ('\u{1F41B}', "bug"),
+    ('\u{1F41C}', "ant"),
+    ('\u{1F41D}', "honeybee"),
+    ('\u{1F41E}', "lady beetle"),
+    ('\u{1F41F}', "fish"),
+    ('\u{1F420}', "tropical fish"),
+    ('\u{1F421}', "blowfish"),
+    ('\u{1F422}', "turtle"),
+    ('\u{1F423}', "hatching chick"),
+    ('\u{1F424}', "baby chick"),
+    ('\u{1F425}', "front-facing baby chick"),
+    ('\u{1F426}', "bird"),
+    ('\u{1F427}', "penguin"),
+    ('\u{1F428}', "koala"),
+    ('\u{1F429}', "poodle"),
+    ('\u{1F42A}', "camel"),
+    ('\u{1F42B}', "two-hump camel"),
+    ('\u{1F42C}', "dolphin"),
+    ('\u{1F42D}', "mouse face"),
+    ('\u{1F42E}', "cow face"),
+    ('\u{1F42F}', "tiger face"),
+    ('\u{1F430}', "rabbit face"),
+    ('\u{1F431}', "cat face"),
+    ('\u{1F432}', "dragon face"),
+    ('\u{1F433}', "spouting whale"),
+    ('\u{1F434}', "horse face"),
+    ('\u{1F435}', "monkey face"),
+    ('\u{1F436}', "dog face"),
+    ('\u{1F437}', "pig face"),
+    ('\u{1F438}', "frog"),
+    ('\u{1F439}', "hamster"),
+    ('\u{1F43A}', "wolf"),
+    ('\u{1F43B}', "bear"),
+    ('\u{1F43C}', "panda"),
+    ('\u{1F43D}', "pig nose"),
+    ('\u{1F43E}', "paw prints"),
+    ('\u{1F440}', "eyes"),
+    ('\u{1F442}', "ear"),
+    ('\u{1F443}', "nose"),
+    ('\u{1F444}', "mouth"),
+    ('\u{1F445}', "tongue"),
+    ('\u{1F446}', "backhand index pointing up"),
+    ('\u{1F447}', "backhand index pointing down"),
+    ('\u{1F448}', "backhand index pointing left"),
+    ('\u{1F449}', "backhand index pointing right"),
+    ('\u{1F44A}', "oncoming fist"),
+    ('\u{1F44B}', "waving hand"),
+    ('\u{1F44C}', "OK hand"),
+    ('\u{1F44D}', "thumbs up"),
+    ('\u{1F44E}', "thumbs down"),
+    ('\u{1F44F}', "clapping hands"),
+    ('\u{1F450}', "open hands"),
+    ('\u{1F451}', "crown"),
+    ('\u{1F452}', "woman’s hat"),
+    ('\u{1F453}', "glasses"),
+    ('\u{1F454}', "necktie"),
+    ('\u{1F455}', "t-shirt"),
+    ('\u{1F456}', "jeans"),
+    ('\u{1F457}', "dress"),
+    ('\u{1F458}', "kimono"),
+    ('\u{1F459}', "bikini"),
+    ('\u{1F45A}', "woman’s clothes"),
+    ('\u{1F45B}', "purse"),
+    ('\u{1F45C}', "handbag"),
+    ('\u{1F45D}', "clutch bag"),
+    ('\u{1F45E}', "man’s shoe"),
+    ('\u{1F45F}', "running shoe"),
+    ('\u{1F460}', "high-heeled shoe"),
+    ('\u{1F461}', "woman’s sandal"),
+    ('\u{1F462}', "woman’s boot"),
+    ('\u{1F463}', "footprints"),
+    ('\u{1F464}', "bust in silhouette"),
+    ('\u{1F465}', "busts in silhouette"),
+    ('\u{1F466}', "boy"),
+    ('\u{1F467}', "girl"),
+    ('\u{1F468}', "man"),
+    ('\u{1F469}', "woman"),
+    ('\u{1F46A}', "family"),
+    ('\u{1F46B}', "woman and man holding hands"),
+    ('\u{1F46C}', "men holding hands"),
+    ('\u{1F46D}', "women holding hands"),
+    ('\u{1F46E}', "police officer"),
+    ('\u{1F46F}', "people with bunny ears"),
+    ('\u{1F470}', "person with veil"),
+    ('\u{1F471}', "person: blond hair"),
+    ('\u{1F472}', "person with skullcap"),
+    ('\u{1F473}', "person wearing turban"),
+    ('\u{1F474}', "old man"),
+    ('\u{1F475}', "old woman"),
+    ('\u{1F476}', "baby"),
+    ('\u{1F477}', "construction worker"),
+    ('\u{1F478}', "princess"),
+    ('\u{1F479}', "ogre"),
+    ('\u{1F47A}', "goblin"),
+    ('\u{1F47B}', "ghost"),
+    ('\u{1F47C}', "baby angel"),
+    ('\u{1F47D}', "alien"),
+    ('\u{1F47E}', "alien monster"),
+    ('\u{1F47F}', "angry face with horns"),
+    ('\u{1F480}', "skull"),
+    ('\u{1F481}', "person tipping hand"),
+    ('\u{1F482}', "guard"),
+    ('\u{1F483}', "woman dancing"),
+    ('\u{1F484}', "lipstick"),
+    ('\u{1F485}', "nail polish"),
+    ('\u{1F486}', "person getting massage"),
+    ('\u{1F487}', "person getting haircut"),
+    ('\u{1F488}', "barber pole"),
+    ('\u{1F489}', "syringe"),
+    ('\u{1F48A}', "pill"),
+    ('\u{1F48B}', "kiss mark"),
+    ('\u{1F48C}', "love letter"),
+    ('\u{1F48D}', "ring"),
+    ('\u{1F48E}', "gem stone"),
+    ('\u{1F48F}', "kiss"),
+    ('\u{1F490}', "bouquet"),
+    ('\u{1F491}', "couple with heart"),
+    ('\u{1F492}', "wedding"),
+    ('\u{1F493}', "beating heart"),
+    ('\u{1F494}', "broken heart"),
+    ('\u{1F495}', "two hearts"),
+    ('\u{1F496}', "sparkling heart"),
+    ('\u{1F497}', "growing heart"),
+    ('\u{1F498}', "heart with arrow"),
+    ('\u{1F499}', "blue heart"),
+    ('\u{1F49A}', "green heart"),
+    ('\u{1F49B}', "yellow heart"),
+    ('\u{1F49C}', "purple heart"),
+    ('\u{1F49D}', "heart with ribbon"),
+    ('\u{1F49E}', "revolving hearts"),
+    ('\u{1F49F}', "heart decoration"),
+    ('\u{1F4A0}', "diamond with a dot"),
+    ('\u{1F4A1}', "light bulb"),
+    ('\u{1F4A2}', "anger symbol"),
+    ('\u{1F4A3}', "bomb"),
+    ('\u{1F4A4}', "ZZZ"),
+    ('\u{1F4A5}', "collision"),
+    ('\u{1F4A6}', "sweat droplets"),
+    ('\u{1F4A7}', "droplet"),
+    ('\u{1F4A8}', "dashing away"),
+    ('\u{1F4A9}', "pile of poo"),
+    ('\u{1F4AA}', "flexed biceps"),
+    ('\u{1F4AB}', "dizzy"),
+    ('\u{1F4AC}', "speech balloon"),
+    ('\u{1F4AD}', "thought balloon"),
+    ('\u{1F4AE}', "white flower"),
+    ('\u{1F4AF}', "hundred points"),
+    ('\u{1F4B0}', "money bag"),
+    ('\u{1F4B1}', "currency exchange"),
+    ('\u{1F4B2}', "heavy dollar sign"),
+    ('\u{1F4B3}', "credit card"),
+    ('\u{1F4B4}', "yen banknote"),
+    ('\u{1F4B5}', "dollar banknote"),
+    ('\u{1F4B6}', "euro banknote"),
+    ('\u{1F4B7}', "pound banknote"),
+    ('\u{1F4B8}', "money with wings"),
+    ('\u{1F4B9}', "chart increasing with yen"),
+    ('\u{1F4BA}', "seat"),
+    ('\u{1F4BB}', "laptop"),
+    ('\u{1F4BC}', "briefcase"),
+    ('\u{1F4BD}', "computer disk"),
+    ('\u{1F4BE}', "floppy disk"),
+    ('\u{1F4BF}', "optical disk"),
+    ('\u{1F4C0}', "dvd"),
+    ('\u{1F4C1}', "file folder"),
+    ('\u{1F4C2}', "open file folder"),
+    ('\u{1F4C3}', "page with curl"),
+    ('\u{1F4C4}', "page facing up"),
+    ('\u{1F4C5}', "calendar"),
+    ('\u{1F4C6}', "tear-off calendar"),
+    ('\u{1F4C7}', "card index"),
+    ('\u{1F4C8}', "chart increasing"),
+    ('\u{1F4C9}', "chart decreasing"),
+    ('\u{1F4CA}', "bar chart"),
+    ('\u{1F4CB}', "clipboard"),
+    ('\u{1F4CC}', "pushpin"),
+    ('\u{1F4CD}', "round pushpin"),
+    ('\u{1F4CE}', "paperclip"),
+    ('\u{1F4CF}', "straight ruler"),
+    ('\u{1F4D0}', "triangular ruler"),
+    ('\u{1F4D1}', "bookmark tabs"),
+    ('\u{1F4D2}', "ledger"),
+    ('\u{1F4D3}', "notebook"),
+    ('\u{1F4D4}', "notebook with decorative cover"),
+    ('\u{1F4D5}', "closed book"),
+    ('\u{1F4D6}', "open book"),
+    ('\u{1F4D7}', "green book"),
+    ('\u{1F4D8}', "blue book"),
+    ('\u{1F4D9}', "orange book"),
+    ('\u{1F4DA}', "books"),
+    ('\u{1F4DB}', "name badge"),
+    ('\u{1F4DC}', "scroll"),
+    ('\u{1F4DD}', "memo"),
+    ('\u{1F4DE}', "telephone receiver"),
+    ('\u{1F4DF}', "pager"),
+    ('\u{1F4E0}', "fax machine"),
+    ('\u{1F4E1}', "satellite antenna"),
+    ('\u{1F4E2}', "loudspeaker"),
+    ('\u{1F4E3}', "megaphone"),
+    ('\u{1F4E4}', "outbox tray"),
+    ('\u{1F4E5}', "inbox tray"),
+    ('\u{1F4E6}', "package"),
+    ('\u{1F4E7}', "e-mail"),
+    ('\u{1F4E8}', "incoming envelope"),
+    ('\u{1F4E9}', "envelope with arrow"),
+    ('\u{1F4EA}', "closed mailbox with lowered flag"),
+    ('\u{1F4EB}', "closed mailbox with raised flag"),
+    ('\u{1F4EC}', "open mailbox with raised flag"),
+    ('\u{1F4ED}', "open mailbox with lowered flag"),
+    ('\u{1F4EE}', "postbox"),
+    ('\u{1F4EF}', "postal horn"),
+    ('\u{1F4F0}', "newspaper"),
+    ('\u{1F4F1}', "mobile phone"),
+    ('\u{1F4F2}', "mobile phone with arrow"),
+    ('\u{1F4F3}', "vibration mode"),
+    ('\u{1F4F4}', "mobile phone off"),
+    ('\u{1F4F5}', "no mobile phones"),
+    ('\u{1F4F6}', "antenna bars"),
+    ('\u{1F4F7}', "camera"),
+    ('\u{1F4F8}', "camera with flash"),
+    ('\u{1F4F9}', "video camera"),
+    ('\u{1F4FA}', "television"),
+    ('\u{1F4FB}', "radio"),
+    ('\u{1F4FC}', "videocassette"),
+    ('\u{1F4FF}', "prayer beads"),
+    ('\u{1F500}', "shuffle tracks button"),
+    ('\u{1F501}', "repeat button"),
+    ('\u{1F502}', "repeat single button"),
+    ('\u{1F503}', "clockwise vertical arrows"),
+    ('\u{1F504}', "counterclockwise arrows button"),
+    ('\u{1F505}', "dim button"),
+    ('\u{1F506}', "bright button"),
+    ('\u{1F507}', "muted speaker"),
+    ('\u{1F508}', "speaker low volume"),
+    ('\u{1F509}', "speaker medium volume"),
+    ('\u{1F50A}', "speaker high volume"),
+    ('\u{1F50B}', "battery"),
+    ('\u{1F50C}', "electric plug"),
+    ('\u{1F50D}', "magnifying glass tilted left"),
+    ('\u{1F50E}', "magnifying glass tilted right"),
+    ('\u{1F50F}', "locked with pen"),
+    ('\u{1F510}', "locked with key"),
+    ('\u{1F511}', "key"),
+    ('\u{1F512}', "locked"),
+    ('\u{1F513}', "unlocked"),
+    ('\u{1F514}', "bell"),
+    ('\u{1F515}', "bell with slash"),
+    ('\u{1F516}', "bookmark"),
+    ('\u{1F517}', "link"),
+    ('\u{1F518}', "radio button"),
+    ('\u{1F519}', "BACK arrow"),
+    ('\u{1F51A}', "END arrow"),
+    ('\u{1F51B}', "ON! arrow"),
+    ('\u{1F51C}', "SOON arrow"),
+    ('\u{1F51D}', "TOP arrow"),
+    ('\u{1F51E}', "no one under eighteen"),
+    ('\u{1F51F}', "keycap: 10"),
+    ('\u{1F520}', "input latin uppercase"),
+    ('\u{1F521}', "input latin lowercase"),
+    ('\u{1F522}', "input numbers"),
+    ('\u{1F523}', "input symbols"),
+    ('\u{1F524}', "input latin letters"),
+    ('\u{1F525}', "fire"),
+    ('\u{1F526}', "flashlight"),
+    ('\u{1F527}', "wrench"),
+    ('\u{1F528}', "hammer"),
+    ('\u{1F529}', "nut and bolt"),
+    ('\u{1F52A}', "kitchen knife"),
+    ('\u{1F52B}', "water pistol"),
+    ('\u{1F52C}', "microscope"),
+    ('\u{1F52D}', "telescope"),
+    ('\u{1F52E}', "crystal ball"),
+    ('\u{1F52F}', "dotted six-pointed star"),
+    ('\u{1F530}', "Japanese symbol for beginner"),
+    ('\u{1F531}', "trident emblem"),
+    ('\u{1F532}', "black square button"),
+    ('\u{1F533}', "white square button"),
+    ('\u{1F534}', "red circle"),
+    ('\u{1F535}', "blue circle"),
+    ('\u{1F536}', "large orange diamond"),
+    ('\u{1F537}', "large blue diamond"),
+    ('\u{1F538}', "small orange diamond"),
+    ('\u{1F539}', "small blue diamond"),
+    ('\u{1F53A}', "red triangle pointed up"),
+    ('\u{1F53B}', "red triangle pointed down"),
+    ('\u{1F53C}', "upwards button"),
+    ('\u{1F53D}', "downwards button"),
+    ('\u{1F54B}', "kaaba"),
+    ('\u{1F54C}', "mosque"),
+    ('\u{1F54D}', "synagogue"),
+    ('\u{1F54E}', "menorah"),
+    ('\u{1F550}', "one o’clock"),
+    ('\u{1F551}', "two o’clock"),
+    ('\u{1F552}', "three o’clock"),
+    ('\u{1F553}', "four o’clock"),
+    ('\u{1F554}', "five o’clock"),
+    ('\u{1F555}', "six o’clock"),
+    ('\u{1F556}', "seven o’clock"),
+    ('\u{1F557}', "eight o’clock"),
+    ('\u{1F558}', "nine o’clock"),
+    ('\u{1F559}', "ten o’clock"),
+    ('\u{1F55A}', "eleven o’clock"),
+    ('\u{1F55B}', "twelve o’clock"),
+    ('\u{1F55C}', "one-thirty"),
+    ('\u{1F55D}', "two-thirty"),
+    ('\u{1F55E}', "three-thirty"),
+    ('\u{1F55F}', "four-thirty"),
+    ('\u{1F560}', "five-thirty"),
+    ('\u{1F561}', "six-thirty"),
+    ('\u{1F562}', "seven-thirty"),
+    ('\u{1F563}', "eight-thirty"),
+    ('\u{1F564}', "nine-thirty"),
+    ('\u{1F565}', "ten-thirty"),
+    ('\u{1F566}', "eleven-thirty"),
+    ('\u{1F567}', "twelve-thirty"),
+    ('\u{1F57A}', "man dancing"),
+    ('\u{1F595}', "middle finger"),
+    ('\u{1F596}', "vulcan salute"),
+    ('\u{1F5A4}', "black heart"),
+    ('\u{1F5FB}', "mount fuji"),
+    ('\u{1F5FC}', "Tokyo tower"),
+    ('\u{1F5FD}', "Statue of Liberty"),
+    ('\u{1F5FE}', "map of Japan"),
+    ('\u{1F5FF}', "moai"),
+    ('\u{1F600}', "grinning face"),
+    ('\u{1F601}', "beaming face with smiling eyes"),
+    ('\u{1F602}', "face with tears of joy"),
+    ('\u{1F603}', "grinning face with big eyes"),
+    ('\u{1F604}', "grinning face with smiling eyes"),
+    ('\u{1F605}', "grinning face with sweat"),
+    ('\u{1F606}', "grinning squinting face"),
+    ('\u{1F607}', "smiling face with halo"),
+    ('\u{1F608}', "smiling face with horns"),
+    ('\u{1F609}', "winking face"),
+    ('\u{1F60A}', "smiling face with smiling eyes"),
+    ('\u{1F60B}', "face savoring food"),
+    ('\u{1F60C}', "relieved face"),
+    ('\u{1F60D}', "smiling face with heart-eyes"),
+    ('\u{1F60E}', "smiling face with sunglasses"),
+    ('\u{1F60F}', "smirking face"),
+    ('\u{1F610}', "neutral face"),
+    ('\u{1F611}', "expressionless face"),
+    ('\u{1F612}', "unamused face"),
+    ('\u{1F613}', "downcast face with sweat"),
+    ('\u{1F614}', "pensive face"),
+    ('\u{1F615}', "confused face"),
+    ('\u{1F616}', "confounded face"),
+    ('\u{1F617}', "kissing face"),
+    ('\u{1F618}', "face blowing a kiss"),
+    ('\u{1F619}', "kissing face with smiling eyes"),
+    ('\u{1F61A}', "kissing face with closed eyes"),
+    ('\u{1F61B}', "face with tongue"),
+    ('\u{1F61C}', "winking face with tongue"),
+    ('\u{1F61D}', "squinting face with tongue"),
+    ('\u{1F61E}', "disappointed face"),
+    ('\u{1F61F}', "worried face"),
+    ('\u{1F620}', "angry face"),
+    ('\u{1F621}', "enraged face"),
+    ('\u{1F622}', "crying face"),
+    ('\u{1F623}', "persevering face"),
+    ('\u{1F624}', "face with steam from nose"),
+    ('\u{1F625}', "sad but relieved face"),
+    ('\u{1F626}', "frowning face with open mouth"),
+    ('\u{1F627}', "anguished face"),
+    ('\u{1F628}', "fearful face"),
+    ('\u{1F629}', "weary face"),
+    ('\u{1F62A}', "sleepy face"),
+    ('\u{1F62B}', "tired face"),
+    ('\u{1F62C}', "grimacing face"),
+    ('\u{1F62D}', "loudly crying face"),
+    ('\u{1F62E}', "face with open mouth"),
+    ('\u{1F62F}', "hushed face"),
+    ('\u{1F630}', "anxious face with sweat"),
+    ('\u{1F631}', "face screaming in fear"),
+    ('\u{1F632}', "astonished face"),
+    ('\u{1F633}', "flushed face"),
+    ('\u{1F634}', "sleeping face"),
+    ('\u{1F635}', "face with crossed-out eyes"),
+    ('\u{1F636}', "face without mouth"),
+    ('\u{1F637}', "face with medical mask"),
+    ('\u{1F638}', "grinning cat with smiling eyes"),
+    ('\u{1F639}', "cat with tears of joy"),
+    ('\u{1F63A}', "grinning cat"),
+    ('\u{1F63B}', "smiling cat with heart-eyes"),
+    ('\u{1F63C}', "cat with wry smile"),
+    ('\u{1F63D}', "kissing cat"),
+    ('\u{1F63E}', "pouting cat"),
+    ('\u{1F63F}', "crying cat"),
+    ('\u{1F640}', "weary cat"),
+    ('\u{1F641}', "slightly frowning face"),
+    ('\u{1F642}', "slightly smiling face"),
+    ('\u{1F643}', "upside-down face"),
+    ('\u{1F644}', "face with rolling eyes"),
+    ('\u{1F645}', "person gesturing NO"),
+    ('\u{1F646}', "person gesturing OK"),
+    ('\u{1F647}', "person bowing"),
+    ('\u{1F648}', "see-no-evil monkey"),
+    ('\u{1F649}', "hear-no-evil monkey"),
+    ('\u{1F64A}', "speak-no-evil monkey"),
+    ('\u{1F64B}', "person raising hand"),
+    ('\u{1F64C}', "raising hands"),
+    ('\u{1F64D}', "person frowning"),
+    ('\u{1F64E}', "person pouting"),
+    ('\u{1F64F}', "folded hands"),
+    ('\u{1F680}', "rocket"),
+    ('\u{1F681}', "helicopter"),
+    ('\u{1F682}', "locomotive"),
+    ('\u{1F683}', "railway car"),
+    ('\u{1F684}', "high-speed train"),
+    ('\u{1F685}', "bullet train"),
+    ('\u{1F686}', "train"),
+    ('\u{1F687}', "metro"),
+    ('\u{1F688}', "light rail"),
+    ('\u{1F689}', "station"),
+    ('\u{1F68A}', "tram"),
+    ('\u{1F68B}', "tram car"),
+    ('\u{1F68C}', "bus"),
+    ('\u{1F68D}', "oncoming bus"),
+    ('\u{1F68E}', "trolleybus"),
+    ('\u{1F68F}', "bus stop"),
+    ('\u{1F690}', "minibus"),
+    ('\u{1F691}', "ambulance"),
+    ('\u{1F692}', "fire engine"),
+    ('\u{1F693}', "police car"),
+    ('\u{1F694}', "oncoming police car"),
+    ('\u{1F695}', "taxi"),
+    ('\u{1F696}', "oncoming taxi"),
+    ('\u{1F697}', "automobile"),
+    ('\u{1F698}', "oncoming automobile"),
+    ('\u{1F699}', "sport utility vehicle"),
+    ('\u{1F69A}', "delivery truck"),
+    ('\u{1F69B}', "articulated lorry"),
+    ('\u{1F69C}', "tractor"),
+    ('\u{1F69D}', "monorail"),
+    ('\u{1F69E}', "mountain railway"),
+    ('\u{1F69F}', "suspension railway"),
+    ('\u{1F6A0}', "mountain cableway"),
+    ('\u{1F6A1}', "aerial tramway"),
+    ('\u{1F6A2}', "ship"),
+    ('\u{1F6A3}', "person rowing boat"),
+    ('\u{1F6A4}', "speedboat"),
+    ('\u{1F6A5}', "horizontal traffic light"),
+    ('\u{1F6A6}', "vertical traffic light"),
+    ('\u{1F6A7}', "construction"),
+    ('\u{1F6A8}', "police car light"),
+    ('\u{1F6A9}', "triangular flag"),
+    ('\u{1F6AA}', "door"),
+    ('\u{1F6AB}', "prohibited"),
+    ('\u{1F6AC}', "cigarette"),
+    ('\u{1F6AD}', "no smoking"),
+    ('\u{1F6AE}', "litter in bin sign"),
+    ('\u{1F6AF}', "no littering"),
+    ('\u{1F6B0}', "potable water"),
+    ('\u{1F6B1}', "non-potable water"),
+    ('\u{1F6B2}', "bicycle"),
+    ('\u{1F6B3}', "no bicycles"),
+    ('\u{1F6B4}', "person biking"),
+    ('\u{1F6B5}', "person mountain biking"),
+    ('\u{1F6B6}', "person walking"),
+    ('\u{1F6B7}', "no pedestrians"),
+    ('\u{1F6B8}', "children crossing"),
+    ('\u{1F6B9}', "men’s room"),
+    ('\u{1F6BA}', "women’s room"),
+    ('\u{1F6BB}', "restroom"),
+    ('\u{1F6BC}', "baby symbol"),
+    ('\u{1F6BD}', "toilet"),
+    ('\u{1F6BE}', "water closet"),
+    ('\u{1F6BF}', "shower"),
+    ('\u{1F6C0}', "person taking bath"),
+    ('\u{1F6C1}', "bathtub"),
+    ('\u{1F6C2}', "passport control"),
+    ('\u{1F6C3}', "customs"),
+    ('\u{1F6C4}', "baggage claim"),
+    ('\u{1F6C5}', "left luggage"),
+    ('\u{1F6CC}', "person in bed"),
+    ('\u{1F6D0}', "place of worship"),
+    ('\u{1F6D1}', "stop sign"),
+    ('\u{1F6D2}', "shopping cart"),
+    ('\u{1F6D5}', "hindu temple"),
+    ('\u{1F6D6}', "hut"),
+    ('\u{1F6D7}', "elevator"),
+    ('\u{1F6DC}', "wireless"),
+    ('\u{1F6DD}', "playground slide"),
+    ('\u{1F6DE}', "wheel"),
+    ('\u{1F6DF}', "ring buoy"),
+    ('\u{1F6EB}', "airplane departure"),
+    ('\u{1F6EC}', "airplane arrival"),
+    ('\u{1F6F4}', "kick scooter"),
+    ('\u{1F6F5}', "motor scooter"),
+    ('\u{1F6F6}', "canoe"),
+    ('\u{1F6F7}', "sled"),
+    ('\u{1F6F8}', "flying saucer"),
+    ('\u{1F6F9}', "skateboard"),
+    ('\u{1F6FA}', "auto rickshaw"),
+    ('\u{1F6FB}', "pickup truck"),
+    ('\u{1F6FC}', "roller skate"),
+    ('\u{1F7E0}', "orange circle"),
+    ('\u{1F7E1}', "yellow circle"),
+    ('\u{1F7E2}', "green circle"),
+    ('\u{1F7E3}', "purple circle"),
+    ('\u{1F7E4}', "brown circle"),
+    ('\u{1F7E5}', "red square"),
+    ('\u{1F7E6}', "blue square"),
+    ('\u{1F7E7}', "orange square"),
+    ('\u{1F7E8}', "yellow square"),
+    ('\u{1F7E9}', "green square"),
+    ('\u{1F7EA}', "purple square"),
+    ('\u{1F7EB}', "brown square"),
+    ('\u{1F7F0}', "heavy equals sign"),
+    ('\u{1F90C}', "pinched fingers"),
+    ('\u{1F90D}', "white heart"),
+    ('\u{1F90E}', "brown heart"),
+    ('\u{1F90F}', "pinching hand"),
+    ('\u{1F910}', "zipper-mouth face"),
+    ('\u{1F911}', "money-mouth face"),
+    ('\u{1F912}', "face with thermometer"),
+    ('\u{1F913}', "nerd face"),
+    ('\u{1F914}', "thinking face"),
+    ('\u{1F915}', "face with head-bandage"),
+    ('\u{1F916}', "robot"),
+    ('\u{1F917}', "smiling face with open hands"),
+    ('\u{1F918}', "sign of the horns"),
+    ('\u{1F919}', "call me hand"),
+    ('\u{1F91A}', "raised back of hand"),
+    ('\u{1F91B}', "left-facing fist"),
+    ('\u{1F91C}', "right-facing fist"),
+    ('\u{1F91D}', "handshake"),
+    ('\u{1F91E}', "crossed fingers"),
+    ('\u{1F91F}', "love-you gesture"),
+    ('\u{1F920}', "cowboy hat face"),
+    ('\u{1F921}', "clown face"),
+    ('\u{1F922}', "nauseated face"),
+    ('\u{1F923}', "rolling on the floor laughing"),
+    ('\u{1F924}', "drooling face"),
+    ('\u{1F925}', "lying face"),
+    ('\u{1F926}', "person facepalming"),
+    ('\u{1F927}', "sneezing face"),
+    ('\u{1F928}', "face with raised eyebrow"),
+    ('\u{1F929}', "star-struck"),
+    ('\u{1F92A}', "zany face"),
+    ('\u{1F92B}', "shushing face"),
+    ('\u{1F92C}', "face with symbols on mouth"),
+    ('\u{1F92D}', "face with hand over mouth"),
+    ('\u{1F92E}', "face vomiting"),
+    ('\u{1F92F}', "exploding head"),
+    ('\u{1F930}', "pregnant woman"),
+    ('\u{1F931}', "breast-feeding"),
+    ('\u{1F932}', "palms up together"),
+    ('\u{1F933}', "selfie"),
+    ('\u{1F934}', "prince"),
+    ('\u{1F935}', "person in tuxedo"),
+    ('\u{1F936}', "Mrs. Claus"),
+    ('\u{1F937}', "person shrugging"),
+    ('\u{1F938}', "person cartwheeling"),
+    ('\u{1F939}', "person juggling"),
+    ('\u{1F93A}', "person fencing"),
+    ('\u{1F93C}', "people wrestling"),
+    ('\u{1F93D}', "person playing water polo"),
+    ('\u{1F93E}', "person playing handball"),
+    ('\u{1F93F}', "diving mask"),
+    ('\u{1F940}', "wilted flower"),
+    ('\u{1F941}', "drum"),
+    ('\u{1F942}', "clinking glasses"),
+    ('\u{1F943}', "tumbler glass"),
+    ('\u{1F944}', "spoon"),
+    ('\u{1F945}', "goal net"),
+    ('\u{1F947}', "1st place medal"),
+    ('\u{1F948}', "2nd place medal"),
+    ('\u{1F949}', "3rd place medal"),
+    ('\u{1F94A}', "boxing glove"),
+    ('\u{1F94B}', "martial arts uniform"),
+    ('\u{1F94C}', "curling stone"),
+    ('\u{1F94D}', "lacrosse"),
+    ('\u{1F94E}', "softball"),
+    ('\u{1F94F}', "flying disc"),
+    ('\u{1F950}', "croissant"),
+    ('\u{1F951}', "avocado"),
+    ('\u{1F952}', "cucumber"),
+    ('\u{1F953}', "bacon"),
+    ('\u{1F954}', "potato"),
+    ('\u{1F955}', "carrot"),
+    ('\u{1F956}', "baguette bread"),
+    ('\u{1F957}', "green salad"),
+    ('\u{1F958}', "shallow pan of food"),
+    ('\u{1F959}', "stuffed flatbread"),
+    ('\u{1F95A}', "egg"),
+    ('\u{1F95B}', "glass of milk"),
+    ('\u{1F95C}', "peanuts"),
+    ('\u{1F95D}', "kiwi fruit"),
+    ('\u{1F95E}', "pancakes"),
+    ('\u{1F95F}', "dumpling"),
+    ('\u{1F960}', "fortune cookie"),
+    ('\u{1F961}', "takeout box"),
+    ('\u{1F962}', "chopsticks"),
+    ('\u{1F963}', "bowl with spoon"),
+    ('\u{1F964}', "cup with straw"),
+    ('\u{1F965}', "coconut"),
+    ('\u{1F966}', "broccoli"),
+    ('\u{1F967}', "pie"),
+    ('\u{1F968}', "pretzel"),
+    ('\u{1F969}', "cut of meat"),
+    ('\u{1F96A}', "sandwich"),
+    ('\u{1F96B}', "canned food"),
+    ('\u{1F96C}', "leafy green"),
+    ('\u{1F96D}', "mango"),
+    ('\u{1F96E}', "moon cake"),
+    ('\u{1F96F}', "bagel"),
+    ('\u{1F970}', "smiling face with hearts"),
+    ('\u{1F971}', "yawning face"),
+    ('\u{1F972}', "smiling face with tear"),
+    ('\u{1F973}', "partying face"),
+    ('\u{1F974}', "woozy face"),
+    ('\u{1F975}', "hot face"),
+    ('\u{1F976}', "cold face"),
+    ('\u{1F977}', "ninja"),
+    ('\u{1F978}', "disguised face"),
+    ('\u{1F979}', "face holding back tears"),
+    ('\u{1F97A}', "pleading face"),
+    ('\u{1F97B}', "sari"),
+    ('\u{1F97C}', "lab coat"),
+    ('\u{1F97D}', "goggles"),
+    ('\u{1F97E}', "hiking boot"),
+    ('\u{1F97F}', "flat shoe"),
+    ('\u{1F980}', "crab"),
+    ('\u{1F981}', "lion"),
+    ('\u{1F982}', "scorpion"),
+    ('\u{1F983}', "turkey"),
+    ('\u{1F984}', "unicorn"),
+    ('\u{1F985}', "eagle"),
+    ('\u{1F986}', "duck"),
+    ('\u{1F987}', "bat"),
+    ('\u{1F988}', "shark"),
+    ('\u{1F989}', "owl"),
+    ('\u{1F98A}', "fox"),
+    ('\u{1F98B}', "butterfly"),
+    ('\u{1F98C}', "deer"),
+    ('\u{1F98D}', "gorilla"),
+    ('\u{1F98E}', "lizard"),
+    ('\u{1F98F}', "rhinoceros"),
+    ('\u{1F990}', "shrimp"),
+    ('\u{1F991}', "squid"),
+    ('\u{1F992}', "giraffe"),
+    ('\u{1F993}', "zebra"),
+    ('\u{1F994}', "hedgehog"),
+    ('\u{1F995}', "sauropod"),
+    ('\u{1F996}', "T-Rex"),
+    ('\u{1F997}', "cricket"),
+    ('\u{1F998}', "kangaroo"),
+    ('\u{1F999}', "llama"),
+    ('\u{1F99A}', "peacock"),
+    ('\u{1F99B}', "hippopotamus"),
+    ('\u{1F99C}', "parrot"),
+    ('\u{1F99D}', "raccoon"),
+    ('\u{1F99E}', "lobster"),
+    ('\u{1F99F}', "mosquito"),
+    ('\u{1F9A0}', "microbe"),
+    ('\u{1F9A1}', "badger"),
+    ('\u{1F9A2}', "swan"),
+    ('\u{1F9A3}', "mammoth"),
+    ('\u{1F9A4}', "dodo"),
+    ('\u{1F9A5}', "sloth"),
+    ('\u{1F9A6}', "otter"),
+    ('\u{1F9A7}', "orangutan"),
+    ('\u{1F9A8}', "skunk"),
+    ('\u{1F9A9}', "flamingo"),
+    ('\u{1F9AA}', "oyster"),
+    ('\u{1F9AB}', "beaver"),
+    ('\u{1F9AC}', "bison"),
+    ('\u{1F9AD}', "seal"),
+    ('\u{1F9AE}', "guide dog"),
+    ('\u{1F9AF}', "white cane"),
+    ('\u{1F9B4}', "bone"),
+    ('\u{1F9B5}', "leg"),
+    ('\u{1F9B6}', "foot"),
+    ('\u{1F9B7}', "tooth"),
+    ('\u{1F9B8}', "superhero"),
+    ('\u{1F9B9}', "supervillain"),
+    ('\u{1F9BA}', "safety vest"),
+    ('\u{1F9BB}', "ear with hearing aid"),
+    ('\u{1F9BC}', "motorized wheelchair"),
+    ('\u{1F9BD}', "manual wheelchair"),
+    ('\u{1F9BE}', "mechanical arm"),
+    ('\u{1F9BF}', "mechanical leg"),
+    ('\u{1F9C0}', "cheese wedge"),
+    ('\u{1F9C1}', "cupcake"),
+    ('\u{1F9C2}', "salt"),
+    ('\u{1F9C3}', "beverage box"),
+    ('\u{1F9C4}', "garlic"),
+    ('\u{1F9C5}', "onion"),
+    ('\u{1F9C6}', "falafel"),
+    ('\u{1F9C7}', "waffle"),
+    ('\u{1F9C8}', "butter"),
+    ('\u{1F9C9}', "mate"),
+    ('\u{1F9CA}', "ice"),
+    ('\u{1F9CB}', "bubble tea"),
+    ('\u{1F9CC}', "troll"),
+    ('\u{1F9CD}', "person standing"),
+    ('\u{1F9CE}', "person kneeling"),
+    ('\u{1F9CF}', "deaf person"),
+    ('\u{1F9D0}', "face with monocle"),
+    ('\u{1F9D1}', "person"),
+    ('\u{1F9D2}', "child"),
+    ('\u{1F9D3}', "older person"),
+    ('\u{1F9D4}', "person: beard"),
+    ('\u{1F9D5}', "woman with headscarf"),
+    ('\u{1F9D6}', "person in steamy room"),
+    ('\u{1F9D7}', "person climbing"),
+    ('\u{1F9D8}', "person in lotus position"),
+    ('\u{1F9D9}', "mage"),
+    ('\u{1F9DA}', "fairy"),
+    ('\u{1F9DB}', "vampire"),
+    ('\u{1F9DC}', "merperson"),
+    ('\u{1F9DD}', "elf"),
+    ('\u{1F9DE}', "genie"),
+    ('\u{1F9DF}', "zombie"),
+    ('\u{1F9E0}', "brain"),
+    ('\u{1F9E1}', "orange heart"),
+    ('\u{1F9E2}', "billed cap"),
+    ('\u{1F9E3}', "scarf"),
+    ('\u{1F9E4}', "gloves"),
+    ('\u{1F9E5}', "coat"),
+    ('\u{1F9E6}', "socks"),
+    ('\u{1F9E7}', "red envelope"),
+    ('\u{1F9E8}', "firecracker"),
+    ('\u{1F9E9}', "puzzle piece"),
+    ('\u{1F9EA}', "test tube"),
+    ('\u{1F9EB}', "petri dish"),
+    ('\u{1F9EC}', "dna"),
+    ('\u{1F9ED}', "compass"),
+    ('\u{1F9EE}', "abacus"),
+    ('\u{1F9EF}', "fire extinguisher"),
+    ('\u{1F9F0}', "toolbox"),
+    ('\u{1F9F1}', "brick"),
+    ('\u{1F9F2}', "magnet"),
+    ('\u{1F9F3}', "luggage"),
+    ('\u{1F9F4}', "lotion bottle"),
+    ('\u{1F9F5}', "thread"),
+    ('\u{1F9F6}', "yarn"),
+    ('\u{1F9F7}', "safety pin"),
+    ('\u{1F9F8}', "teddy bear"),
+    ('\u{1F9F9}', "broom"),
+    ('\u{1F9FA}', "basket"),
+    ('\u{1F9FB}', "roll of paper"),
+    ('\u{1F9FC}', "soap"),
+    ('\u{1F9FD}', "sponge"),
+    ('\u{1F9FE}', "receipt"),
+    ('\u{1F9FF}', "nazar amulet"),
+    ('\u{1FA70}', "ballet shoes"),
+    ('\u{1FA71}', "one-piece swimsuit"),
+    ('\u{1FA72}', "briefs"),
+    ('\u{1FA73}', "shorts"),
+    ('\u{1FA74}', "thong sandal"),
+    ('\u{1FA75}', "light blue heart"),
+    ('\u{1FA76}', "grey heart"),
+    ('\u{1FA77}', "pink heart"),
+    ('\u{1FA78}', "drop of blood"),
+    ('\u{1FA79}', "adhesive bandage"),
+    ('\u{1FA7A}', "stethoscope"),
+    ('\u{1FA7B}', "x-ray"),
+    ('\u{1FA7C}', "crutch"),
+    ('\u{1FA80}', "yo-yo"),
+    ('\u{1FA81}', "kite"),
+    ('\u{1FA82}', "parachute"),
+    ('\u{1FA83}', "boomerang"),
+    ('\u{1FA84}', "magic wand"),
+    ('\u{1FA85}', "piñata"),
+    ('\u{1FA86}', "nesting dolls"),
+    ('\u{1FA87}', "maracas"),
+    ('\u{1FA88}', "flute"),
+    ('\u{1FA90}', "ringed planet"),
+    ('\u{1FA91}', "chair"),
+    ('\u{1FA92}', "razor"),
+    ('\u{1FA93}', "axe"),
+    ('\u{1FA94}', "diya lamp"),
+    ('\u{1FA95}', "banjo"),
+    ('\u{1FA96}', "military helmet"),
+    ('\u{1FA97}', "accordion"),
+    ('\u{1FA98}', "long drum"),
+    ('\u{1FA99}', "coin"),
+    ('\u{1FA9A}', "carpentry saw"),
+    ('\u{1FA9B}', "screwdriver"),
+    ('\u{1FA9C}', "ladder"),
+    ('\u{1FA9D}', "hook"),
+    ('\u{1FA9E}', "mirror"),
+    ('\u{1FA9F}', "window"),
+    ('\u{1FAA0}', "plunger"),
+    ('\u{1FAA1}', "sewing needle"),
+    ('\u{1FAA2}', "knot"),
+    ('\u{1FAA3}', "bucket"),
+    ('\u{1FAA4}', "mouse trap"),
+    ('\u{1FAA5}', "toothbrush"),
+    ('\u{1FAA6}', "headstone"),
+    ('\u{1FAA7}', "placard"),
+    ('\u{1FAA8}', "rock"),
+    ('\u{1FAA9}', "mirror ball"),
+    ('\u{1FAAA}', "identification card"),
+    ('\u{1FAAB}', "low battery"),
+    ('\u{1FAAC}', "hamsa"),
+    ('\u{1FAAD}', "folding hand fan"),
+    ('\u{1FAAE}', "hair pick"),
+    ('\u{1FAAF}', "khanda"),
+    ('\u{1FAB0}', "fly"),
+    ('\u{1FAB1}', "worm"),
+    ('\u{1FAB2}', "beetle"),
+    ('\u{1FAB3}', "cockroach"),
+    ('\u{1FAB4}', "potted plant"),
+    ('\u{1FAB5}', "wood"),
+    ('\u{1FAB6}', "feather"),
+    ('\u{1FAB7}', "lotus"),
+    ('\u{1FAB8}', "coral"),
+    ('\u{1FAB9}', "empty nest"),
+    ('\u{1FABA}', "nest with eggs"),
+    ('\u{1FABB}', "hyacinth"),
+    ('\u{1FABC}', "jellyfish"),
+    ('\u{1FABD}', "wing"),
+    ('\u{1FABF}', "goose"),
+    ('\u{1FAC0}', "anatomical heart"),
+    ('\u{1FAC1}', "lungs"),
+    ('\u{1FAC2}', "people hugging"),
+    ('\u{1FAC3}', "pregnant man"),
+    ('\u{1FAC4}', "pregnant person"),
+    ('\u{1FAC5}', "person with crown"),
+    ('\u{1FACE}', "moose"),
+    ('\u{1FACF}', "donkey"),
+    ('\u{1FAD0}', "blueberries"),
+    ('\u{1FAD1}', "bell pepper"),
+    ('\u{1FAD2}', "olive"),
+    ('\u{1FAD3}', "flatbread"),
+    ('\u{1FAD4}', "tamale"),
+    ('\u{1FAD5}', "fondue"),
+    ('\u{1FAD6}', "teapot"),
+    ('\u{1FAD7}', "pouring liquid"),
+    ('\u{1FAD8}', "beans"),
+    ('\u{1FAD9}', "jar"),
+    ('\u{1FADA}', "ginger root"),
+    ('\u{1FADB}', "pea pod"),
+    ('\u{1FAE0}', "melting face"),
+    ('\u{1FAE1}', "saluting face"),
+    ('\u{1FAE2}', "face with open eyes and hand over mouth"),
+    ('\u{1FAE3}', "face with peeking eye"),
+    ('\u{1FAE4}', "face with diagonal mouth"),
+    ('\u{1FAE5}', "dotted line face"),
+    ('\u{1FAE6}', "biting lip"),
+    ('\u{1FAE7}', "bubbles"),
+    ('\u{1FAE8}', "shaking face"),
+    ('\u{1FAF0}', "hand with index finger and thumb crossed"),
+    ('\u{1FAF1}', "rightwards hand"),
+    ('\u{1FAF2}', "leftwards hand"),
+    ('\u{1FAF3}', "palm down hand"),
+    ('\u{1FAF4}', "palm up hand"),
+    ('\u{1FAF5}', "index pointing at the viewer"),
+    ('\u{1FAF6}', "heart hands"),
+    ('\u{1FAF7}', "leftwards pushing hand"),
+    ('\u{1FAF8}', "rightwards pushing hand"),
+];
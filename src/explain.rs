@@ -0,0 +1,541 @@
+//! Explanations of comparisons, to debug unexpected sort orders.
+
+use crate::collator::{
+    fraction_len, is_negative, radix_prefix, tie_break, Collator, DecidedBy, NulPolicy, Step,
+    TieBreak,
+};
+use crate::compare::SortMode;
+use crate::digit::DigitKind;
+use crate::script::Script;
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::cmp::Ordering;
+use core::fmt;
+
+/// An explanation why a string is less than, equal to or greater than another string
+///
+/// This is returned by `Collator::explain` and `explain_cmp`. It implements `Display`, which
+/// produces a short sentence.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::{explain_cmp, SortMode};
+///
+/// let explanation = explain_cmp("T-20", "T-5", SortMode::NaturalLexical);
+/// assert_eq!(
+///     explanation.to_string(),
+///     r#""T-20" > "T-5", because the number 20 > 5 (at byte 2 and 2)"#,
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    /// The left string
+    pub lhs: String,
+    /// The right string
+    pub rhs: String,
+    /// The result of the comparison
+    pub ordering: Ordering,
+    /// What decided the result
+    pub reason: Reason,
+    /// Whether non-alphanumeric characters were skipped in the comparison that decided the
+    /// result. This is `false` if the strings were equal after skipping them and then
+    /// compared again without skipping them.
+    pub skipped_chars: bool,
+}
+
+/// What decided the result of a comparison, with details
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reason {
+    /// The strings differ after folding
+    Chars {
+        /// The first differing character of the left string, after folding
+        lhs: char,
+        /// The first differing character of the right string, after folding
+        rhs: char,
+        /// The byte offsets of the original characters in the strings
+        position: (usize, usize),
+    },
+    /// The strings contain different numbers, which were compared naturally
+    Numbers {
+        /// The number in the left string
+        lhs: String,
+        /// The number in the right string
+        rhs: String,
+        /// The byte offsets of the numbers in the strings
+        position: (usize, usize),
+    },
+    /// The strings contain different grapheme clusters, which were compared as units with
+    /// `Collator::graphemes`
+    Graphemes {
+        /// The grapheme cluster of the left string. This is a single character if only the
+        /// right character is a cluster.
+        lhs: String,
+        /// The grapheme cluster of the right string
+        rhs: String,
+        /// The byte offsets of the clusters in the strings
+        position: (usize, usize),
+    },
+    /// The strings are in different script groups with `Collator::group_by_script`
+    Script {
+        /// The script of the first alphabetic character of the left string, if it has one
+        lhs: Option<Script>,
+        /// The script of the first alphabetic character of the right string, if it has one
+        rhs: Option<Script>,
+    },
+    /// One string is a prefix of the other after folding
+    Length,
+    /// Both strings are empty after folding, so they were ordered by their length, then by
+    /// their code points
+    Blank,
+    /// The strings are equal after folding, so the tie-break decided
+    TieBreak,
+    /// The strings are equal, equivalent with `Normalization::Nfc` or `Normalization::Nfkc`,
+    /// or equal after folding with `TieBreak::InputOrder`
+    Equal,
+}
+
+impl Collator<'_> {
+    /// Compares two strings and explains the result.
+    ///
+    /// This is much slower than `cmp` and allocates, so it should only be used for debugging.
+    /// The result always agrees with `cmp`.
+    pub fn explain(&self, lhs: &str, rhs: &str) -> Explanation {
+        let (ordering, reason, skipped_chars) = self.explain_inner(lhs, rhs);
+        debug_assert_eq!(ordering, self.cmp(lhs, rhs));
+
+        Explanation {
+            lhs: lhs.to_string(),
+            rhs: rhs.to_string(),
+            ordering,
+            reason,
+            skipped_chars,
+        }
+    }
+
+    fn explain_inner(&self, lhs: &str, rhs: &str) -> (Ordering, Reason, bool) {
+        if self.equivalent(lhs, rhs) {
+            return (Ordering::Equal, Reason::Equal, false);
+        }
+        if let NulPolicy::Truncate = self.nul_policy {
+            fn truncate(s: &str) -> &str {
+                s.find('\0').map_or(s, |i| &s[..i])
+            }
+            return match self
+                .nul_policy(NulPolicy::Compare)
+                .explain_inner(truncate(lhs), truncate(rhs))
+            {
+                (Ordering::Equal, _, skipped) if self.ties != TieBreak::InputOrder => {
+                    let (ordering, decided) = tie_break(self.cmp_raw(lhs, rhs));
+                    (ordering, Reason::from_tie_break(decided), skipped)
+                }
+                result => result,
+            };
+        }
+        if !self.prefixes.is_empty() {
+            let (i, j) = (self.prefix_len(lhs), self.prefix_len(rhs));
+            let collator = self.strip_prefixes(&[]);
+            return match collator.explain_inner(&lhs[i..], &rhs[j..]) {
+                (Ordering::Equal, ..) if i != 0 || j != 0 => collator.explain_inner(lhs, rhs),
+                (ordering, reason, skipped) => (ordering, reason.offset(i, j), skipped),
+            };
+        }
+        if self.group_by_script {
+            let (l, r) = (self.script_group(lhs), self.script_group(rhs));
+            if l != r {
+                let reason = Reason::Script { lhs: l.1, rhs: r.1 };
+                return (l.cmp(&r), reason, false);
+            }
+            return self.in_script_group(l.1).explain_inner(lhs, rhs);
+        }
+
+        let only_alnum = self.only_alnum;
+        match self.first_difference(lhs, rhs) {
+            Step::Chars(l, r) => {
+                let reason = Reason::Chars {
+                    lhs: l.c,
+                    rhs: r.c,
+                    position: (l.index, r.index),
+                };
+                (self.cmp_chars(l.c, r.c), reason, only_alnum)
+            }
+            Step::Numbers(l, r, ordering) => {
+                let reason = Reason::Numbers {
+                    lhs: self.number_at(lhs, l.index),
+                    rhs: self.number_at(rhs, r.index),
+                    position: (l.index, r.index),
+                };
+                (ordering, reason, only_alnum)
+            }
+            #[cfg(feature = "unicode-segmentation")]
+            Step::Clusters(l, r, ordering) => {
+                let reason = Reason::Graphemes {
+                    lhs: lhs[l.index..l.end].to_string(),
+                    rhs: rhs[r.index..r.end].to_string(),
+                    position: (l.index, r.index),
+                };
+                (ordering, reason, only_alnum)
+            }
+            Step::Length(ordering) => (ordering, Reason::Length, only_alnum),
+            Step::Equal { .. } if self.ties == TieBreak::InputOrder => {
+                (Ordering::Equal, Reason::Equal, only_alnum)
+            }
+            Step::Equal { blank: true } => {
+                let (ordering, decided) = tie_break(self.cmp_blank(lhs, rhs));
+                let reason = match decided {
+                    DecidedBy::Equal => Reason::Equal,
+                    _ => Reason::Blank,
+                };
+                (ordering, reason, only_alnum)
+            }
+            Step::Equal { .. } if only_alnum => self.only_alnum(false).explain_inner(lhs, rhs),
+            Step::Equal { .. } => {
+                let (ordering, decided) = if self.lexical {
+                    tie_break(self.cmp_variants(lhs, rhs))
+                } else {
+                    tie_break(self.cmp_raw(lhs, rhs))
+                };
+                (ordering, Reason::from_tie_break(decided), false)
+            }
+        }
+    }
+
+    /// Returns the folded digits of the number at the given byte offset, with a minus sign if
+    /// it is negative, and with its radix prefix or fractional part if it has one
+    fn number_at(&self, s: &str, index: usize) -> String {
+        let mut fold = self
+            .fold(s)
+            .skip_while(|f| f.index < index || !f.c.is_ascii_digit())
+            .peekable();
+        let mut number = String::new();
+        match fold.peek() {
+            Some(f) if self.signed_numbers && is_negative(s, f.index) => number.push('-'),
+            _ => {}
+        }
+        if self.radix_prefixes {
+            let mut rest = fold.clone();
+            rest.next();
+            if let Some(radix) = fold.peek().and_then(|&f| radix_prefix(f, rest)) {
+                number.extend(fold.by_ref().take(2).map(|f| f.c));
+                while let Some(f) = fold
+                    .peek()
+                    .filter(|f| f.c.is_digit(radix) && f.kind == DigitKind::Normal)
+                {
+                    number.push(f.c);
+                    fold.next();
+                }
+                return number;
+            }
+        }
+        let kind = match fold.peek() {
+            Some(f) if f.kind.single_value().is_some() => {
+                number.push_str(&s[f.index..f.end]);
+                return number;
+            }
+            Some(f) => f.kind,
+            None => DigitKind::Normal,
+        };
+        while let Some(f) = fold
+            .peek()
+            .filter(|f| f.c.is_ascii_digit() && f.kind == kind)
+        {
+            number.push(f.c);
+            fold.next();
+        }
+        if self.decimal_numbers && !self.follows_number_separator(s, index) {
+            let len = fraction_len(fold.clone(), self.decimal_separator);
+            if len > 0 {
+                number.extend(fold.take(len + 1).map(|f| f.c));
+            }
+        }
+        number
+    }
+
+    /// Returns whether the folded characters before the given byte offset end with a digit
+    /// and the decimal separator, as in a version number
+    fn follows_number_separator(&self, s: &str, index: usize) -> bool {
+        let mut last = (None, None);
+        for f in self.fold(s).take_while(|f| f.index < index) {
+            last = (last.1, Some(f.c));
+        }
+        matches!(last, (Some(c), Some(sep)) if c.is_ascii_digit() && sep == self.decimal_separator)
+    }
+}
+
+impl Reason {
+    /// Adds byte offsets to the position of the differing characters or numbers
+    fn offset(self, i: usize, j: usize) -> Self {
+        match self {
+            Reason::Chars { lhs, rhs, position } => Reason::Chars {
+                lhs,
+                rhs,
+                position: (position.0 + i, position.1 + j),
+            },
+            Reason::Numbers { lhs, rhs, position } => Reason::Numbers {
+                lhs,
+                rhs,
+                position: (position.0 + i, position.1 + j),
+            },
+            Reason::Graphemes { lhs, rhs, position } => Reason::Graphemes {
+                lhs,
+                rhs,
+                position: (position.0 + i, position.1 + j),
+            },
+            reason => reason,
+        }
+    }
+
+    fn from_tie_break(decided: DecidedBy) -> Self {
+        match decided {
+            DecidedBy::Equal => Reason::Equal,
+            _ => Reason::TieBreak,
+        }
+    }
+}
+
+/// Compares two strings with the given mode and explains the result.
+///
+/// This is equivalent to `Collator::from(mode).explain(lhs, rhs)`.
+pub fn explain_cmp(lhs: &str, rhs: &str, mode: SortMode) -> Explanation {
+    Collator::from(mode).explain(lhs, rhs)
+}
+
+fn symbol(ordering: Ordering) -> &'static str {
+    match ordering {
+        Ordering::Less => "<",
+        Ordering::Equal => "=",
+        Ordering::Greater => ">",
+    }
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let op = symbol(self.ordering);
+        write!(f, "{:?} {} {:?}", self.lhs, op, self.rhs)?;
+
+        match &self.reason {
+            Reason::Chars { lhs, rhs, position } => write!(
+                f,
+                ", because {:?} {} {:?} (at byte {} and {})",
+                lhs, op, rhs, position.0, position.1
+            )?,
+            Reason::Numbers { lhs, rhs, position } => write!(
+                f,
+                ", because the number {} {} {} (at byte {} and {})",
+                lhs, op, rhs, position.0, position.1
+            )?,
+            Reason::Graphemes { lhs, rhs, position } => write!(
+                f,
+                ", because the grapheme cluster {:?} {} {:?} (at byte {} and {})",
+                lhs, op, rhs, position.0, position.1
+            )?,
+            Reason::Script { lhs, rhs } => {
+                let name = |script: &Option<Script>| match script {
+                    Some(script) => format!("{:?}", script),
+                    None => "no script".to_string(),
+                };
+                write!(
+                    f,
+                    ", because {} {} {} in the script order",
+                    name(lhs),
+                    op,
+                    name(rhs)
+                )?
+            }
+            Reason::Length => {
+                let (shorter, longer) = match self.ordering {
+                    Ordering::Less => (&self.lhs, &self.rhs),
+                    _ => (&self.rhs, &self.lhs),
+                };
+                write!(f, ", because {:?} is a prefix of {:?}", shorter, longer)?
+            }
+            Reason::Blank => write!(
+                f,
+                ", because both are empty after folding, so they are ordered by length"
+            )?,
+            Reason::TieBreak => write!(
+                f,
+                ", because they are equal after folding, so the tie-break decides"
+            )?,
+            Reason::Equal => {}
+        }
+
+        if self.skipped_chars && self.reason != Reason::Equal {
+            write!(f, " after skipping non-alphanumeric characters")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_agrees_with_cmp() {
+        let strings = [
+            "", " ", "-", "a", "A", "ä", "ab", "a-b", "a b", "ß", "ss", "T-5", "T-20", "T5", "x01",
+            "x1", "½", "1/2", "Foo", "fóò", "a\0b", "a\0c", "-5", "_-12", "_−12", "_-", "1.5",
+            "1.25", "1.2.10", "1.", "1.50", "0x10", "0xA", "0b", "0b11", "16", "1½", "⅓", "x1¹",
+            "x11",
+        ];
+        let modes = [
+            SortMode::Plain,
+            SortMode::OnlyAlnum,
+            SortMode::Lexical,
+            SortMode::LexicalOnlyAlnum,
+            SortMode::Natural,
+            SortMode::NaturalOnlyAlnum,
+            SortMode::NaturalLexical,
+            SortMode::NaturalLexicalOnlyAlnum,
+        ];
+        for &mode in &modes {
+            for &collator in &[
+                Collator::from(mode),
+                Collator::from(mode).nul_policy(NulPolicy::Truncate),
+                Collator::from(mode).strip_prefixes(&["a", "T-"]),
+                Collator::from(mode).signed_numbers(true),
+                Collator::from(mode).decimal_numbers(true),
+                Collator::from(mode).radix_prefixes(true),
+                Collator::from(mode).group_by_script(true),
+            ] {
+                for lhs in &strings {
+                    for rhs in &strings {
+                        let explanation = collator.explain(lhs, rhs);
+                        assert_eq!(explanation.ordering, collator.cmp(lhs, rhs));
+                    }
+                }
+            }
+            for lhs in &strings {
+                for rhs in &strings {
+                    let explanation = explain_cmp(lhs, rhs, mode);
+                    assert_eq!(explanation.ordering, mode.function()(lhs, rhs));
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_explanations() {
+        let explain = |lhs, rhs, mode| explain_cmp(lhs, rhs, mode).to_string();
+
+        assert_eq!(
+            explain("ab", "ä", SortMode::Lexical),
+            r#""ab" > "ä", because "ä" is a prefix of "ab""#,
+        );
+        assert_eq!(
+            explain("aß", "asz", SortMode::Lexical),
+            r#""aß" < "asz", because 's' < 'z' (at byte 1 and 2)"#,
+        );
+        assert_eq!(
+            explain("T-20", "T-5", SortMode::Lexical),
+            r#""T-20" < "T-5", because '2' < '5' (at byte 2 and 2)"#,
+        );
+        assert_eq!(
+            explain("x-10", "x9", SortMode::NaturalLexicalOnlyAlnum),
+            r#""x-10" > "x9", because the number 10 > 9 (at byte 2 and 1) after skipping non-alphanumeric characters"#,
+        );
+        assert_eq!(
+            explain("a-b", "ab", SortMode::LexicalOnlyAlnum),
+            r#""a-b" < "ab", because '-' < 'b' (at byte 1 and 1)"#,
+        );
+        assert_eq!(
+            explain("Foo", "fóò", SortMode::Lexical),
+            r#""Foo" < "fóò", because they are equal after folding, so the tie-break decides"#,
+        );
+        assert_eq!(
+            explain("--", "-", SortMode::LexicalOnlyAlnum),
+            r#""--" > "-", because both are empty after folding, so they are ordered by length after skipping non-alphanumeric characters"#,
+        );
+        assert_eq!(explain("foo", "foo", SortMode::Natural), r#""foo" = "foo""#,);
+        assert_eq!(
+            explain("Chapter ½", "Chapter 1", SortMode::NaturalLexical),
+            r#""Chapter ½" < "Chapter 1", because the number ½ < 1 (at byte 8 and 8)"#,
+        );
+
+        let collator = Collator::new().natural(true).signed_numbers(true);
+        assert_eq!(
+            collator.explain("temp_-12", "temp_-5").to_string(),
+            r#""temp_-12" < "temp_-5", because the number -12 < -5 (at byte 6 and 6)"#,
+        );
+        assert_eq!(
+            collator.explain("temp_-12", "temp_3").to_string(),
+            r#""temp_-12" < "temp_3", because the number -12 < 3 (at byte 5 and 5)"#,
+        );
+
+        let collator = collator.decimal_numbers(true);
+        assert_eq!(
+            collator.explain("x -1.5", "x -1.25").to_string(),
+            r#""x -1.5" < "x -1.25", because the number -1.5 < -1.25 (at byte 3 and 3)"#,
+        );
+        assert_eq!(
+            collator.explain("v1.2.10", "v1.2.9").to_string(),
+            r#""v1.2.10" > "v1.2.9", because the number 10 > 9 (at byte 5 and 5)"#,
+        );
+        let collator = Collator::new().natural(true).radix_prefixes(true);
+        assert_eq!(
+            collator.explain("reg_0x10", "reg_12").to_string(),
+            r#""reg_0x10" > "reg_12", because the number 0x10 > 12 (at byte 4 and 4)"#,
+        );
+        let collator = Collator::new().natural(true).month_names(true);
+        assert_eq!(
+            collator
+                .explain("report-Feb-2021", "report-Jan-2022")
+                .to_string(),
+            r#""report-Feb-2021" > "report-Jan-2022", because the number Feb > Jan (at byte 7 and 7)"#,
+        );
+
+        let explanation = explain_cmp("x¹²", "x3", SortMode::NaturalLexical);
+        assert_eq!(
+            explanation.reason,
+            Reason::Numbers {
+                lhs: "12".to_string(),
+                rhs: "3".to_string(),
+                position: (1, 1)
+            }
+        );
+        assert!(!explanation.skipped_chars);
+    }
+
+    #[test]
+    #[cfg(feature = "unicode-segmentation")]
+    fn test_explain_graphemes() {
+        let collator = Collator::new().graphemes(true);
+        let explanation = collator.explain("x \u{1F1E9}\u{1F1F0}", "x \u{1F1E9}\u{1F1EA}");
+        assert_eq!(
+            explanation.reason,
+            Reason::Graphemes {
+                lhs: "\u{1F1E9}\u{1F1F0}".to_string(),
+                rhs: "\u{1F1E9}\u{1F1EA}".to_string(),
+                position: (2, 2),
+            }
+        );
+        assert_eq!(
+            explanation.to_string(),
+            "\"x \u{1F1E9}\u{1F1F0}\" > \"x \u{1F1E9}\u{1F1EA}\", because the grapheme cluster \
+             \"\u{1F1E9}\u{1F1F0}\" > \"\u{1F1E9}\u{1F1EA}\" (at byte 2 and 2)",
+        );
+    }
+
+    #[test]
+    fn test_explain_script() {
+        let collator = Collator::new().group_by_script(true);
+        let explanation = collator.explain("Zeus", "\u{391}\u{3B8}\u{3AE}\u{3BD}\u{3B1}");
+        assert_eq!(
+            explanation.reason,
+            Reason::Script {
+                lhs: Some(Script::Latin),
+                rhs: Some(Script::Greek),
+            }
+        );
+        assert_eq!(
+            explanation.to_string(),
+            "\"Zeus\" < \"\u{391}\u{3B8}\u{3AE}\u{3BD}\u{3B1}\", because Latin < Greek in the script order",
+        );
+        assert_eq!(
+            collator.explain("42", "\u{5D0}").to_string(),
+            "\"42\" > \"\u{5D0}\", because no script > Other in the script order",
+        );
+    }
+}
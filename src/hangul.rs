@@ -0,0 +1,85 @@
+//! Decomposition of Hangul, used with `Collator::decompose_hangul`.
+
+/// The conjoining jamo of the compatibility consonants from U+3131 to U+314E. Consonants are
+/// mapped to leading consonants, and consonant clusters to trailing consonants.
+const CONSONANTS: [char; 30] = [
+    '\u{1100}', '\u{1101}', '\u{11AA}', '\u{1102}', '\u{11AC}', '\u{11AD}', '\u{1103}', '\u{1104}',
+    '\u{1105}', '\u{11B0}', '\u{11B1}', '\u{11B2}', '\u{11B3}', '\u{11B4}', '\u{11B5}', '\u{11B6}',
+    '\u{1106}', '\u{1107}', '\u{1108}', '\u{11B9}', '\u{1109}', '\u{110A}', '\u{110B}', '\u{110C}',
+    '\u{110D}', '\u{110E}', '\u{110F}', '\u{1110}', '\u{1111}', '\u{1112}',
+];
+
+/// The conjoining jamo of a Hangul syllable or compatibility jamo, which has at most 3
+/// characters
+#[derive(Clone, Default)]
+pub(crate) struct Jamo {
+    chars: [char; 3],
+    pos: usize,
+    len: usize,
+}
+
+impl Jamo {
+    /// Decomposes a precomposed Hangul syllable into its leading consonant, vowel and
+    /// trailing consonant, if it has one, and maps a compatibility jamo to the conjoining
+    /// jamo. Returns `None` for other characters.
+    pub(crate) fn new(c: char) -> Option<Self> {
+        let jamo = |chars: &[u32]| {
+            let mut jamo = Jamo::default();
+            for &c in chars {
+                jamo.chars[jamo.len] = core::char::from_u32(c)?;
+                jamo.len += 1;
+            }
+            Some(jamo)
+        };
+        let code = c as u32;
+        match c {
+            '\u{AC00}'..='\u{D7A3}' => {
+                let s = code - 0xAC00;
+                let (l, v, t) = (0x1100 + s / 588, 0x1161 + s % 588 / 28, s % 28);
+                match t {
+                    0 => jamo(&[l, v]),
+                    _ => jamo(&[l, v, 0x11A7 + t]),
+                }
+            }
+            '\u{3131}'..='\u{314E}' => jamo(&[CONSONANTS[(code - 0x3131) as usize] as u32]),
+            '\u{314F}'..='\u{3163}' => jamo(&[code - 0x314F + 0x1161]),
+            _ => None,
+        }
+    }
+}
+
+impl Iterator for Jamo {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.chars[..self.len].get(self.pos).copied()?;
+        self.pos += 1;
+        Some(c)
+    }
+}
+
+/// Returns whether a character is a conjoining Hangul jamo
+pub(crate) fn is_jamo(c: char) -> bool {
+    matches!(c, '\u{1100}'..='\u{11FF}' | '\u{A960}'..='\u{A97F}' | '\u{D7B0}'..='\u{D7FF}')
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jamo() {
+        let jamo = |c| Jamo::new(c).map(|j| j.collect::<Vec<char>>());
+        assert_eq!(jamo('가'), Some(vec!['\u{1100}', '\u{1161}']));
+        assert_eq!(jamo('한'), Some(vec!['\u{1112}', '\u{1161}', '\u{11AB}']));
+        assert_eq!(jamo('힣'), Some(vec!['\u{1112}', '\u{1175}', '\u{11C2}']));
+        assert_eq!(jamo('ㄱ'), Some(vec!['\u{1100}']));
+        assert_eq!(jamo('ㅎ'), Some(vec!['\u{1112}']));
+        assert_eq!(jamo('ㄳ'), Some(vec!['\u{11AA}']));
+        assert_eq!(jamo('ㅏ'), Some(vec!['\u{1161}']));
+        assert_eq!(jamo('ㅣ'), Some(vec!['\u{1175}']));
+        assert_eq!(jamo('\u{1100}'), None);
+        assert_eq!(jamo('a'), None);
+        assert!(is_jamo('\u{1100}') && is_jamo('\u{11AB}') && !is_jamo('ㄱ'));
+    }
+}
@@ -0,0 +1,154 @@
+//! Keys for index headers (e.g. `A`, `B`, `#`) of sorted lists.
+
+use crate::collator::Collator;
+use crate::compare::SortMode;
+
+/// The first unit of a string as it is compared, e.g. to group a sorted list under index
+/// headers
+///
+/// This is returned by `Collator::index_key` and `first_lexical_unit`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IndexKey<'a> {
+    /// The string starts with a letter. In lexical comparisons, this is a lowercase ASCII
+    /// letter such as `"a"`, otherwise it is an ASCII letter with its original case.
+    ///
+    /// This is a string slice, so that a unit of several characters can be represented.
+    Letter(&'a str),
+    /// The string starts with an ASCII digit
+    Digit,
+    /// The string starts with a character that isn't an ASCII letter or digit, e.g. a space,
+    /// a symbol, or a letter that isn't transliterated. This is also the key of strings that
+    /// aren't empty, but are empty after folding, e.g. `"--"` in modes that skip
+    /// non-alphanumeric characters.
+    Other,
+    /// The string is empty
+    Empty,
+}
+
+const LETTERS: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+impl<'a> Collator<'a> {
+    /// Returns the first unit of a string after folding it, e.g. `IndexKey::Letter("a")` for
+    /// `"Ángel"` in lexical comparisons. See `first_lexical_unit` for details.
+    pub fn index_key(&self, s: &str) -> IndexKey<'a> {
+        match self.fold(s).next() {
+            None if s.is_empty() => IndexKey::Empty,
+            None => IndexKey::Other,
+            Some(f) if f.c.is_ascii_alphabetic() => {
+                let i = LETTERS.find(f.c).unwrap_or(0);
+                IndexKey::Letter(&LETTERS[i..i + 1])
+            }
+            Some(f) if f.c.is_ascii_digit() => IndexKey::Digit,
+            Some(_) => IndexKey::Other,
+        }
+    }
+}
+
+/// Returns the first unit of a string after folding it with the given mode, e.g. to group
+/// a sorted list under index headers.
+///
+/// In lexical modes, the result is consistent with the sort order: Strings that are sorted
+/// with the same mode have contiguous keys, and `"Ángel"` and `"angel"` both have the key
+/// `IndexKey::Letter("a")`. In modes that skip non-alphanumeric characters, these characters
+/// are skipped here as well, so `" 42nd"` has the key `IndexKey::Digit`, but `"--"` has the
+/// key `IndexKey::Other`, since it isn't empty.
+///
+/// This is equivalent to `Collator::from(mode).index_key(s)`.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::{first_lexical_unit, IndexKey, SortMode};
+///
+/// assert_eq!(first_lexical_unit("Ángel", SortMode::Lexical), IndexKey::Letter("a"));
+/// assert_eq!(first_lexical_unit("→x", SortMode::Lexical), IndexKey::Other);
+/// assert_eq!(first_lexical_unit("→x", SortMode::LexicalOnlyAlnum), IndexKey::Letter("x"));
+/// assert_eq!(first_lexical_unit(" 42nd", SortMode::LexicalOnlyAlnum), IndexKey::Digit);
+/// assert_eq!(first_lexical_unit("--", SortMode::LexicalOnlyAlnum), IndexKey::Other);
+/// assert_eq!(first_lexical_unit("", SortMode::LexicalOnlyAlnum), IndexKey::Empty);
+/// # }
+/// ```
+pub fn first_lexical_unit(s: &str, mode: SortMode) -> IndexKey<'static> {
+    Collator::from(mode).index_key(s)
+}
+
+#[cfg(all(test, feature = "unicode"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index_key() {
+        use IndexKey::{Digit, Empty, Letter, Other};
+
+        let cases = [
+            ("Ángel", Letter("a"), Letter("a"), Other),
+            ("angel", Letter("a"), Letter("a"), Letter("a")),
+            ("Zoë", Letter("z"), Letter("z"), Letter("Z")),
+            ("ßtraße", Letter("s"), Letter("s"), Other),
+            ("Æon", Letter("a"), Letter("a"), Other),
+            ("½ cup", Digit, Digit, Other),
+            ("42nd", Digit, Digit, Digit),
+            (" 42nd", Other, Digit, Other),
+            ("→x", Other, Letter("x"), Other),
+            ("Жук", Letter("z"), Letter("z"), Other),
+            ("北京", Letter("b"), Letter("b"), Other),
+            ("🎉", Other, Other, Other),
+            ("--", Other, Other, Other),
+            ("\u{301}", Other, Other, Other),
+            ("", Empty, Empty, Empty),
+        ];
+        for &(s, lexical, lexical_only_alnum, plain) in &cases {
+            assert_eq!(first_lexical_unit(s, SortMode::Lexical), lexical, "{:?}", s);
+            assert_eq!(
+                first_lexical_unit(s, SortMode::NaturalLexical),
+                lexical,
+                "{:?}",
+                s
+            );
+            assert_eq!(
+                first_lexical_unit(s, SortMode::LexicalOnlyAlnum),
+                lexical_only_alnum,
+                "{:?}",
+                s
+            );
+            assert_eq!(first_lexical_unit(s, SortMode::Plain), plain, "{:?}", s);
+        }
+        assert_eq!(first_lexical_unit("-Zoë", SortMode::OnlyAlnum), Letter("Z"));
+        assert_eq!(first_lexical_unit("-Ángel", SortMode::OnlyAlnum), Other);
+
+        let collator = Collator::new().transliterate_symbols(true);
+        assert_eq!(collator.index_key("& more"), Letter("a"));
+        assert_eq!(Collator::new().index_key("& more"), Other);
+    }
+
+    #[test]
+    fn test_index_key_contiguous() {
+        let modes = [
+            SortMode::Lexical,
+            SortMode::LexicalOnlyAlnum,
+            SortMode::NaturalLexical,
+            SortMode::NaturalLexicalOnlyAlnum,
+        ];
+        let mut strings = [
+            "", "--", "\u{301}", " 42nd", "42nd", "½ cup", "Éric", "eric", "Emma", "-Emma", "北京",
+            "Beijing", "bar", "Ängel", "angel", "Zoë", "zebra", "Æon", "ßtraße", "Straße", "🎉",
+            "→x", "x", "_x", "Жук", "~", "9", "Z9",
+        ];
+        for &mode in &modes {
+            strings.sort_unstable_by(|l, r| mode.function()(l, r));
+            let keys = strings.map(|s| first_lexical_unit(s, mode));
+            // every key occurs in one contiguous run, so index headers are never repeated
+            for (i, key) in keys.iter().enumerate() {
+                let end = keys.iter().rposition(|k| k == key).unwrap();
+                assert!(
+                    keys[i..=end].iter().all(|k| k == key),
+                    "{:?}: {:?} at {:?}",
+                    mode,
+                    key,
+                    &strings[i..=end]
+                );
+            }
+        }
+    }
+}
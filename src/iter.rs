@@ -7,19 +7,27 @@
 //! The iterators don't allocate memory on the heap. I haven't benchmarked it,
 //! but I believe that it's quite efficient.
 
+use crate::digit::enclosed_number;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "unicode")]
 use any_ascii::any_ascii_char;
+use core::fmt;
 use core::iter::FusedIterator;
+use core::ops::Range;
+use core::str::Chars;
 
 /// An iterator over one `char`, converted to lowercase
 /// and transliterated to ASCII, if it is an alphanumeric character
 ///
 /// This iterator can be created by calling `iterate_lexical_char()` or
 /// `iterate_lexical_char_only_alnum()`
+#[derive(Clone, Debug)]
 pub struct LexicalChar(CharOrSlice);
 
 impl LexicalChar {
     #[inline]
-    fn from_char(c: char) -> Self {
+    pub(crate) fn from_char(c: char) -> Self {
         LexicalChar(CharOrSlice::Char(c))
     }
 
@@ -29,7 +37,23 @@ impl LexicalChar {
     }
 
     #[inline]
-    fn empty() -> Self {
+    fn from_alnum_slice(s: &'static [u8]) -> Self {
+        LexicalChar(CharOrSlice::AlnumSlice(s))
+    }
+
+    /// Returns the decimal digits of a number from 0 to 20
+    #[inline]
+    fn from_small_number(value: u32) -> Self {
+        const NUMBERS: &[u8] = b"01234567891011121314151617181920";
+        let (start, len) = match value {
+            0..=9 => (value as usize, 1),
+            _ => (10 + (value as usize - 10) * 2, 2),
+        };
+        LexicalChar::from_slice(NUMBERS.get(start..start + len).unwrap_or(&[]))
+    }
+
+    #[inline]
+    pub(crate) fn empty() -> Self {
         LexicalChar(CharOrSlice::Slice(&[]))
     }
 
@@ -44,9 +68,12 @@ impl LexicalChar {
     }
 }
 
+#[derive(Clone, Debug)]
 enum CharOrSlice {
     Char(char),
     Slice(&'static [u8]),
+    /// Like `Slice`, but non-alphanumeric bytes are skipped
+    AlnumSlice(&'static [u8]),
 }
 
 impl Iterator for LexicalChar {
@@ -59,13 +86,19 @@ impl Iterator for LexicalChar {
                 *self = LexicalChar::empty();
                 Some(c)
             }
-            CharOrSlice::Slice(slice) => match slice.get(0_usize) {
+            CharOrSlice::Slice(slice) => match slice.first() {
                 Some(&next) => {
                     *slice = &slice[1..];
                     Some((next as char).to_ascii_lowercase())
                 }
                 None => None,
             },
+            CharOrSlice::AlnumSlice(slice) => {
+                let ix = slice.iter().position(u8::is_ascii_alphanumeric)?;
+                let next = slice[ix];
+                *slice = &slice[ix + 1..];
+                Some((next as char).to_ascii_lowercase())
+            }
         }
     }
 
@@ -74,23 +107,36 @@ impl Iterator for LexicalChar {
         match self.inner() {
             CharOrSlice::Char(_) => (1, Some(1)),
             CharOrSlice::Slice(s) => (s.len(), Some(s.len())),
+            CharOrSlice::AlnumSlice(s) => {
+                let len = s.iter().filter(|b| b.is_ascii_alphanumeric()).count();
+                (len, Some(len))
+            }
         }
     }
 
     #[inline]
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        if n == 0 {
-            self.next()
-        } else if let CharOrSlice::Slice(slice) = self.inner_mut() {
-            match slice.get(n) {
+        match self.inner_mut() {
+            CharOrSlice::Slice(slice) => match slice.get(n) {
                 Some(&next) => {
-                    *slice = &slice[1..];
+                    *slice = &slice[n + 1..];
                     Some((next as char).to_ascii_lowercase())
                 }
-                None => None,
+                None => {
+                    *slice = &[];
+                    None
+                }
+            },
+            CharOrSlice::Char(_) if n > 0 => {
+                *self = LexicalChar::empty();
+                None
+            }
+            _ => {
+                for _ in 0..n {
+                    self.next()?;
+                }
+                self.next()
             }
-        } else {
-            None
         }
     }
 }
@@ -108,76 +154,672 @@ impl DoubleEndedIterator for LexicalChar {
                 Some(c)
             }
             CharOrSlice::Slice(slice) => {
-                if slice.len() > 0 {
-                    let ix = slice.len() - 1;
-                    *slice = &slice[..ix];
-                    Some((slice[ix] as char).to_ascii_lowercase())
+                if let Some((&last, rest)) = slice.split_last() {
+                    *slice = rest;
+                    Some((last as char).to_ascii_lowercase())
                 } else {
                     None
                 }
             }
+            CharOrSlice::AlnumSlice(slice) => {
+                let ix = slice.iter().rposition(u8::is_ascii_alphanumeric)?;
+                let next = slice[ix];
+                *slice = &slice[..ix];
+                Some((next as char).to_ascii_lowercase())
+            }
         }
     }
 }
 
 /// Returns an iterator over one `char`, converted to lowercase
 /// and transliterated to ASCII, if it is alphanumeric
+///
+/// Combining diacritical marks and invisible formatting characters, such as the zero-width
+/// space, are skipped. Typographic punctuation is converted to its ASCII equivalent: curly
+/// quotes to `'` and `"`, dashes to `-`, and the ellipsis `…` to `...`. Characters of the
+/// Halfwidth and Fullwidth Forms block are converted to their normal width first, so `！` is
+/// converted to `!`, and `１` to `1`. Likewise, the styled Latin letters and digits of the
+/// Mathematical Alphanumeric Symbols block, such as `𝐀` or `𝟐`, are converted to plain ASCII,
+/// and enclosed letters and numbers, such as `Ⓐ`, `🅰` or `⑽`, to the bare letter or number.
+///
+/// Without the `unicode` feature, other non-ASCII alphanumeric characters are only converted
+/// to lowercase, not transliterated.
 #[inline]
 pub fn iterate_lexical_char(c: char) -> LexicalChar {
-    if c.is_ascii() {
+    iterate_lexical_char_with(c, &DefaultBackend)
+}
+
+/// Like `iterate_lexical_char()`, but non-ASCII alphanumeric characters are transliterated
+/// with the given backend
+#[inline]
+pub fn iterate_lexical_char_with<T: Transliterate + ?Sized>(c: char, backend: &T) -> LexicalChar {
+    let c = compatibility_char(c);
+    if let Some(value) = enclosed_number(c) {
+        LexicalChar::from_small_number(value)
+    } else if c.is_ascii() {
         LexicalChar::from_char(c.to_ascii_lowercase())
+    } else if combining_diacritical(&c) || invisible(c) {
+        LexicalChar::empty()
     } else if c.is_alphanumeric() {
-        match any_ascii_char(c) {
-            s if s.is_empty() => LexicalChar::from_char(c),
-            s => LexicalChar::from_slice(s.as_bytes()),
+        match backend.transliterate(c) {
+            Mapped::Str(s) if s.is_ascii() => LexicalChar::from_slice(s.as_bytes()),
+            Mapped::Skip => LexicalChar::empty(),
+            Mapped::Char(c) => LexicalChar::from_char(c),
+            Mapped::Str(_) => LexicalChar::from_char(c),
         }
-    } else if combining_diacritical(&c) {
-        LexicalChar::empty()
     } else {
-        LexicalChar::from_char(c)
+        ascii_punctuation(c).unwrap_or_else(|| LexicalChar::from_char(c))
     }
 }
 
 /// Returns an iterator over one `char`, converted to lowercase
 /// and transliterated to ASCII, if it is alphanumeric
+///
+/// Non-alphanumeric characters in the transliteration are skipped as well, so `½` is
+/// converted to `12`, just like `1/2`.
 #[inline]
 pub fn iterate_lexical_char_only_alnum(c: char) -> LexicalChar {
-    if c.is_ascii() {
+    iterate_lexical_char_only_alnum_with(c, &DefaultBackend)
+}
+
+/// Like `iterate_lexical_char_only_alnum()`, but non-ASCII alphanumeric characters are
+/// transliterated with the given backend
+#[inline]
+pub fn iterate_lexical_char_only_alnum_with<T: Transliterate + ?Sized>(
+    c: char,
+    backend: &T,
+) -> LexicalChar {
+    let c = compatibility_char(c);
+    if let Some(value) = enclosed_number(c) {
+        LexicalChar::from_small_number(value)
+    } else if c.is_ascii() {
         if c.is_ascii_alphanumeric() {
             LexicalChar::from_char(c.to_ascii_lowercase())
         } else {
             LexicalChar::empty()
         }
-    } else if c.is_alphanumeric() {
-        match any_ascii_char(c) {
-            s if s.is_empty() => LexicalChar::from_char(c),
-            s => LexicalChar::from_slice(s.as_bytes()),
+    } else if c.is_alphanumeric() && !combining_diacritical(&c) {
+        match backend.transliterate(c) {
+            Mapped::Str(s) if s.is_ascii() => LexicalChar::from_alnum_slice(s.as_bytes()),
+            Mapped::Skip => LexicalChar::empty(),
+            Mapped::Char(c) => LexicalChar::from_char(c),
+            Mapped::Str(_) => LexicalChar::from_char(c),
         }
     } else {
         LexicalChar::empty()
     }
 }
 
-/// returns `true` for combining diacritical marks
+/// A transliteration backend, which maps non-ASCII alphanumeric characters to the characters
+/// they are compared as in lexical comparisons
+///
+/// The default backend is `AnyAscii`. ASCII characters, combining diacritical marks and
+/// non-alphanumeric characters are never passed to the backend.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::iter::{iterate_lexical_char_with, Mapped, Transliterate};
+///
+/// /// Keeps Greek letters, and transliterates everything else with `any_ascii`
+/// struct KeepGreek;
+///
+/// impl Transliterate for KeepGreek {
+///     fn transliterate(&self, c: char) -> Mapped {
+///         match c {
+///             'α'..='ω' => Mapped::Char(c),
+///             'Α'..='Ω' => Mapped::Char(c.to_lowercase().next().unwrap_or(c)),
+///             _ => lexical_sort::iter::AnyAscii.transliterate(c),
+///         }
+///     }
+/// }
+///
+/// assert!(iterate_lexical_char_with('Ω', &KeepGreek).eq(['ω']));
+/// assert!(iterate_lexical_char_with('æ', &KeepGreek).eq(['a', 'e']));
+/// # }
+/// ```
+pub trait Transliterate {
+    /// Returns what a non-ASCII alphanumeric character is compared as
+    fn transliterate(&self, c: char) -> Mapped;
+}
+
+impl fmt::Debug for dyn Transliterate + '_ {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Transliterate")
+    }
+}
+
+impl fmt::Debug for dyn Transliterate + Sync + '_ {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Transliterate")
+    }
+}
+
+/// What a character is mapped to by a `Transliterate` backend
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Mapped {
+    /// The character is compared as this character, which is used as is, so it should be
+    /// lowercase
+    Char(char),
+    /// The character is compared as this string, which is converted to lowercase. The string
+    /// must only contain ASCII characters; otherwise the character is compared as itself.
+    Str(&'static str),
+    /// The character is ignored
+    Skip,
+}
+
+/// The default transliteration backend, which uses the `any_ascii` crate
+///
+/// Characters that can't be transliterated are converted to lowercase instead, if they have a
+/// single lowercase character.
+///
+/// This requires the `unicode` feature.
+#[cfg(feature = "unicode")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct AnyAscii;
+
+#[cfg(feature = "unicode")]
+impl Transliterate for AnyAscii {
+    #[inline]
+    fn transliterate(&self, c: char) -> Mapped {
+        DefaultBackend.transliterate(c)
+    }
+}
+
+/// The backend of `iterate_lexical_char()`, which is `AnyAscii` with the `unicode` feature.
+/// Without it, characters are only converted to lowercase.
+struct DefaultBackend;
+
+impl Transliterate for DefaultBackend {
+    #[inline]
+    fn transliterate(&self, c: char) -> Mapped {
+        match transliterate(c) {
+            Ok(s) => Mapped::Str(s),
+            Err(c) => Mapped::Char(c),
+        }
+    }
+}
+
+/// Transliterates a non-ASCII alphanumeric character to ASCII. If that isn't possible, the
+/// character is converted to lowercase instead (if it has a single lowercase character).
+///
+/// A few transliterations of `any_ascii` are overridden, so that uppercase and lowercase
+/// variants of a character are transliterated the same way. Without the `unicode` feature,
+/// characters are never transliterated.
+#[inline]
+fn transliterate(c: char) -> Result<&'static str, char> {
+    #[cfg(feature = "unicode")]
+    {
+        let s = match c {
+            'µ' => "m",        // MICRO SIGN, like 'Μ'
+            'ϐ' => "v",        // GREEK BETA SYMBOL, like 'Β'
+            'ϕ' => "f",        // GREEK PHI SYMBOL, like 'Φ'
+            '\u{2126}' => "o", // OHM SIGN, like 'ω'
+            _ => any_ascii_char(c),
+        };
+        if !s.is_empty() {
+            return Ok(s);
+        }
+    }
+
+    let mut lower = c.to_lowercase();
+    match (lower.next(), lower.next()) {
+        (Some(l), None) if l != c => transliterate(l),
+        _ => Err(c),
+    }
+}
+
+/// Returns an iterator over a non-alphanumeric character transliterated to lowercase ASCII,
+/// e.g. `©` is converted to `(c)`, and `&` is converted to `and`. If `only_alnum` is `true`,
+/// non-alphanumeric characters in the transliteration are skipped, so `©` is converted to `c`.
+///
+/// Returns `None` if the character can't be transliterated. Emojis aren't transliterated.
 #[inline]
-fn combining_diacritical(&c: &char) -> bool {
-    c >= '\u{300}' && c <= '\u{36F}'
+pub(crate) fn iterate_lexical_symbol(c: char, only_alnum: bool) -> Option<LexicalChar> {
+    let s = match c {
+        '&' => "and",
+        _ if c.is_ascii() => return None,
+        #[cfg(feature = "unicode")]
+        _ => any_ascii_char(c),
+        #[cfg(not(feature = "unicode"))]
+        _ => return None,
+    };
+    // emojis are transliterated to shortcodes such as `:tada:`
+    if s.is_empty() || s.starts_with(':') {
+        return None;
+    }
+    Some(if only_alnum {
+        LexicalChar::from_alnum_slice(s.as_bytes())
+    } else {
+        LexicalChar::from_slice(s.as_bytes())
+    })
 }
 
+/// Returns the ASCII equivalent of typographic punctuation: quotation marks, dashes and the
+/// ellipsis
+#[inline]
+fn ascii_punctuation(c: char) -> Option<LexicalChar> {
+    let s: &'static str = match c {
+        '\u{2018}' | '\u{2019}' | '\u{201A}' | '\u{201B}' => "'",
+        '\u{201C}' | '\u{201D}' | '\u{201E}' | '\u{201F}' => "\"",
+        '\u{2010}'..='\u{2015}' | '\u{2212}' => "-",
+        '\u{2026}' => "...",
+        _ => return None,
+    };
+    Some(LexicalChar::from_slice(s.as_bytes()))
+}
+
+/// Converts a character of the Halfwidth and Fullwidth Forms block to its compatibility
+/// equivalent, e.g. `Ａ` to `A`, `１` to `1`, `（` to `(` and `￥` to `¥`, and a Latin letter
+/// or digit of the Mathematical Alphanumeric Symbols block to ASCII, e.g. `𝐀` or `𝔄` to `A`.
+/// Enclosed letters, e.g. `Ⓐ`, `⒜` or `🅰`, are converted to the ASCII letter.
+///
+/// Other characters, including enclosed numbers, are returned unchanged. This includes the halfwidth katakana and Hangul
+/// letters and the mathematical Greek letters, which are transliterated like their plain forms.
+#[inline]
+fn compatibility_char(c: char) -> char {
+    let code = match c {
+        // 13 styles of the Latin alphabet, each with 26 uppercase and 26 lowercase letters
+        '\u{1D400}'..='\u{1D6A3}' => match (c as u32 - 0x1D400) % 52 {
+            i @ 0..=25 => 'A' as u32 + i,
+            i => 'a' as u32 + i - 26,
+        },
+        // 5 styles of the digits
+        '\u{1D7CE}'..='\u{1D7FF}' => '0' as u32 + (c as u32 - 0x1D7CE) % 10,
+        // enclosed letters: parenthesized, circled uppercase and lowercase
+        '\u{249C}'..='\u{24B5}' => 'a' as u32 + c as u32 - 0x249C,
+        '\u{24B6}'..='\u{24E9}' => 'a' as u32 + (c as u32 - 0x24B6) % 26,
+        // parenthesized, squared, negative circled and negative squared
+        '\u{1F110}'..='\u{1F129}'
+        | '\u{1F130}'..='\u{1F149}'
+        | '\u{1F150}'..='\u{1F169}'
+        | '\u{1F170}'..='\u{1F189}' => 'a' as u32 + (c as u32 - 0x1F110) % 32,
+        '\u{1F12A}' => 's' as u32,
+        '\u{1F12B}' => 'c' as u32,
+        '\u{1F12C}' => 'r' as u32,
+        '\u{FF01}'..='\u{FF5E}' => c as u32 - 0xFEE0,
+        '\u{FF5F}' => 0x2985,
+        '\u{FF60}' => 0x2986,
+        '\u{FF61}' => 0x3002,
+        '\u{FF62}' => 0x300C,
+        '\u{FF63}' => 0x300D,
+        '\u{FF64}' => 0x3001,
+        '\u{FF65}' => 0x30FB,
+        '\u{FFE0}' => 0xA2,
+        '\u{FFE1}' => 0xA3,
+        '\u{FFE2}' => 0xAC,
+        '\u{FFE3}' => 0xAF,
+        '\u{FFE4}' => 0xA6,
+        '\u{FFE5}' => 0xA5,
+        '\u{FFE6}' => 0x20A9,
+        '\u{FFE8}' => 0x2502,
+        '\u{FFE9}'..='\u{FFEC}' => c as u32 - 0xFFE9 + 0x2190,
+        '\u{FFED}' => 0x25A0,
+        '\u{FFEE}' => 0x25CB,
+        _ => return c,
+    };
+    core::char::from_u32(code).unwrap_or(c)
+}
+
+/// returns `true` for combining diacritical marks, including the blocks Combining Diacritical
+/// Marks Extended and Supplement, Combining Diacritical Marks for Symbols, and Combining Half
+/// Marks. Other nonspacing marks, such as the vowel signs of Indic scripts, aren't included.
+#[inline]
+pub(crate) fn combining_diacritical(&c: &char) -> bool {
+    matches!(
+        c,
+        '\u{300}'..='\u{36F}'
+            | '\u{1AB0}'..='\u{1AFF}'
+            | '\u{1DC0}'..='\u{1DFF}'
+            | '\u{20D0}'..='\u{20FF}'
+            | '\u{FE20}'..='\u{FE2F}'
+    )
+}
+
+/// returns `true` for invisible formatting characters, which are ignored in lexical
+/// comparisons: the zero-width space, zero-width non-joiner and joiner, soft hyphen, word
+/// joiner, zero-width no-break space (which is also used as a byte order mark), and the
+/// bidirectional text controls (marks, embeddings, overrides and isolates)
+#[inline]
+fn invisible(c: char) -> bool {
+    matches!(
+        c,
+        '\u{AD}'
+            | '\u{61C}'
+            | '\u{200B}'..='\u{200F}'
+            | '\u{202A}'..='\u{202E}'
+            | '\u{2060}'
+            | '\u{2066}'..='\u{2069}'
+            | '\u{FEFF}'
+    )
+}
+
+/// An iterator over the characters of a string, converted to lowercase
+/// and transliterated to ASCII, if they're alphanumeric
+///
+/// This iterator can be created by calling `iterate_lexical()` or
+/// `iterate_lexical_only_alnum()`. It can be cloned, e.g. to look ahead, and stored in
+/// a struct.
+#[derive(Clone, Debug)]
+pub struct LexicalIter<'a> {
+    chars: Chars<'a>,
+    only_alnum: bool,
+    front: LexicalChar,
+    back: LexicalChar,
+}
+
+impl LexicalIter<'_> {
+    #[inline]
+    fn expand(&self, c: char) -> LexicalChar {
+        if self.only_alnum {
+            iterate_lexical_char_only_alnum(c)
+        } else {
+            iterate_lexical_char(c)
+        }
+    }
+}
+
+impl Iterator for LexicalIter<'_> {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        loop {
+            if let Some(c) = self.front.next() {
+                return Some(c);
+            }
+            match self.chars.next() {
+                Some(c) => self.front = self.expand(c),
+                None => return self.back.next(),
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.front.len() + self.back.len();
+        if self.chars.as_str().is_empty() {
+            (len, Some(len))
+        } else {
+            (len, None)
+        }
+    }
+}
+
+impl DoubleEndedIterator for LexicalIter<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<char> {
+        loop {
+            if let Some(c) = self.back.next_back() {
+                return Some(c);
+            }
+            match self.chars.next_back() {
+                Some(c) => self.back = self.expand(c),
+                None => return self.front.next_back(),
+            }
+        }
+    }
+}
+
+impl FusedIterator for LexicalIter<'_> {}
+
 /// Returns an iterator over the characters of a string, converted to lowercase
 /// and transliterated to ASCII, if they're alphanumeric
-pub fn iterate_lexical(s: &'_ str) -> impl DoubleEndedIterator<Item = char> + '_ {
-    s.chars().flat_map(iterate_lexical_char)
+pub fn iterate_lexical(s: &'_ str) -> LexicalIter<'_> {
+    LexicalIter {
+        chars: s.chars(),
+        only_alnum: false,
+        front: LexicalChar::empty(),
+        back: LexicalChar::empty(),
+    }
 }
 
 /// Returns an iterator over the characters of a string, converted to lowercase
 /// and transliterated to ASCII. Non-alphanumeric characters are skipped
-pub fn iterate_lexical_only_alnum(s: &'_ str) -> impl DoubleEndedIterator<Item = char> + '_ {
-    s.chars().flat_map(iterate_lexical_char_only_alnum)
+pub fn iterate_lexical_only_alnum(s: &'_ str) -> LexicalIter<'_> {
+    LexicalIter {
+        only_alnum: true,
+        ..iterate_lexical(s)
+    }
+}
+
+/// A string that is displayed converted to lowercase and transliterated to ASCII, if it's
+/// alphanumeric, without allocating
+///
+/// This is returned by `lexical_display()` and `lexical_only_alnum_display()`. It writes the
+/// characters of `iterate_lexical()` or `iterate_lexical_only_alnum()` to the formatter one
+/// by one, so formatting options such as the width are ignored. It can be compared with a
+/// `str`, which is convenient in tests.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::iter::lexical_display;
+///
+/// let key = lexical_display("Crème Brûlée");
+/// assert_eq!(format!("key: {}", key), "key: creme brulee");
+/// assert_eq!(key, "creme brulee");
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct LexicalDisplay<'a> {
+    s: &'a str,
+    only_alnum: bool,
+}
+
+impl<'a> LexicalDisplay<'a> {
+    fn chars(self) -> LexicalIter<'a> {
+        if self.only_alnum {
+            iterate_lexical_only_alnum(self.s)
+        } else {
+            iterate_lexical(self.s)
+        }
+    }
+}
+
+impl fmt::Display for LexicalDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use fmt::Write;
+
+        self.chars().try_for_each(|c| f.write_char(c))
+    }
+}
+
+impl PartialEq<str> for LexicalDisplay<'_> {
+    fn eq(&self, other: &str) -> bool {
+        self.chars().eq(other.chars())
+    }
+}
+
+impl PartialEq<&str> for LexicalDisplay<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        self.chars().eq(other.chars())
+    }
+}
+
+/// Returns a value that displays a string converted to lowercase and transliterated to
+/// ASCII, if it's alphanumeric, without allocating. See `LexicalDisplay`.
+pub fn lexical_display(s: &'_ str) -> LexicalDisplay<'_> {
+    LexicalDisplay {
+        s,
+        only_alnum: false,
+    }
+}
+
+/// Returns a value that displays a string converted to lowercase and transliterated to
+/// ASCII, with non-alphanumeric characters skipped, without allocating. See
+/// `LexicalDisplay`.
+pub fn lexical_only_alnum_display(s: &'_ str) -> LexicalDisplay<'_> {
+    LexicalDisplay {
+        s,
+        only_alnum: true,
+    }
+}
+
+/// Converts text that arrives in chunks to lowercase and transliterates it to ASCII, if it's
+/// alphanumeric, e.g. text received from the network or stored in a rope
+///
+/// The output is the same as `iterate_lexical()` (or `iterate_lexical_only_alnum()`)
+/// produces for the concatenation of all chunks. Every character is converted on its own, so
+/// nothing is buffered: A combining mark at the start of a chunk is skipped like any other
+/// combining mark, the output is complete after every push, and it doesn't need to be flushed
+/// at the end.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::iter::LexicalNormalizer;
+///
+/// let mut normalizer = LexicalNormalizer::new();
+/// let mut out = String::new();
+/// normalizer.push_str("Crème Bru", &mut out);
+/// normalizer.push_char('\u{302}', &mut out);
+/// normalizer.push_str("lée", &mut out);
+/// assert_eq!(out, "creme brulee");
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Default)]
+pub struct LexicalNormalizer {
+    only_alnum: bool,
+}
+
+#[cfg(feature = "alloc")]
+impl LexicalNormalizer {
+    /// Returns a normalizer that produces the output of `iterate_lexical()`
+    pub fn new() -> Self {
+        LexicalNormalizer { only_alnum: false }
+    }
+
+    /// Returns a normalizer that produces the output of `iterate_lexical_only_alnum()`, i.e.
+    /// skips non-alphanumeric characters
+    pub fn new_only_alnum() -> Self {
+        LexicalNormalizer { only_alnum: true }
+    }
+
+    /// Converts a character and appends the result to `out`
+    pub fn push_char(&mut self, c: char, out: &mut String) {
+        if self.only_alnum {
+            out.extend(iterate_lexical_char_only_alnum(c));
+        } else {
+            out.extend(iterate_lexical_char(c));
+        }
+    }
+
+    /// Converts a chunk of text and appends the result to `out`
+    pub fn push_str(&mut self, chunk: &str, out: &mut String) {
+        if self.only_alnum {
+            write_lexical_only_alnum(out, chunk);
+        } else {
+            write_lexical(out, chunk);
+        }
+    }
+}
+
+/// Returns a string converted to lowercase and transliterated to ASCII, if it's
+/// alphanumeric. This is the same as `iterate_lexical(s).collect::<String>()`.
+///
+/// The result consists of exactly the characters that `lexical_cmp` compares, so strings
+/// are equal according to `lexical_eq` if and only if they have the same result. Note that
+/// the results can't be compared with `str::cmp` to get the order of `lexical_cmp`, since
+/// non-alphanumeric characters sort before alphanumeric characters there, e.g. `'~' < 'a'`.
+/// Compare them with `lexical_char_cmp` character by character instead.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::iter::lexical_to_string;
+///
+/// assert_eq!(lexical_to_string("Crème Brûlée"), "creme brulee");
+/// assert_eq!(lexical_to_string("Straße ½"), "strasse 1/2");
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+pub fn lexical_to_string(s: &str) -> String {
+    let mut out = String::new();
+    write_lexical(&mut out, s);
+    out
+}
+
+/// Appends a string converted to lowercase and transliterated to ASCII, if it's
+/// alphanumeric, to `out`, like `lexical_to_string()`.
+///
+/// This reserves the length of `s`, which is usually enough, so it rarely reallocates more
+/// than once.
+#[cfg(feature = "alloc")]
+pub fn write_lexical(out: &mut String, s: &str) {
+    out.reserve(s.len());
+    out.extend(iterate_lexical(s));
+}
+
+/// Returns a string converted to lowercase and transliterated to ASCII, with
+/// non-alphanumeric characters skipped. This is the same as
+/// `iterate_lexical_only_alnum(s).collect::<String>()`.
+///
+/// The result consists of exactly the characters that `lexical_only_alnum_cmp` compares.
+/// Since it only contains alphanumeric characters, the results can be compared with
+/// `str::cmp` to get the order of `lexical_only_alnum_cmp`, except for its tie-break.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::iter::lexical_only_alnum_to_string;
+///
+/// assert_eq!(lexical_only_alnum_to_string("Crème Brûlée!"), "cremebrulee");
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+pub fn lexical_only_alnum_to_string(s: &str) -> String {
+    let mut out = String::new();
+    write_lexical_only_alnum(&mut out, s);
+    out
+}
+
+/// Appends a string converted to lowercase and transliterated to ASCII, with
+/// non-alphanumeric characters skipped, to `out`, like `lexical_only_alnum_to_string()`.
+#[cfg(feature = "alloc")]
+pub fn write_lexical_only_alnum(out: &mut String, s: &str) {
+    out.reserve(s.len());
+    out.extend(iterate_lexical_only_alnum(s));
+}
+
+/// Returns an iterator over the characters of a string, converted to lowercase
+/// and transliterated to ASCII, if they're alphanumeric, like `iterate_lexical()`.
+/// Every character is returned with the byte range of the character it originates from.
+///
+/// If a character is transliterated to several characters, they all have the same range,
+/// e.g. `ß` is converted to `s` and `s`. Characters that are skipped, such as combining
+/// diacritical marks, don't appear in the iterator.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::iter::iterate_lexical_indices;
+///
+/// let mut iter = iterate_lexical_indices("Aß");
+/// assert_eq!(iter.next(), Some((0..1, 'a')));
+/// assert_eq!(iter.next(), Some((1..3, 's')));
+/// assert_eq!(iter.next(), Some((1..3, 's')));
+/// assert_eq!(iter.next(), None);
+/// # }
+/// ```
+pub fn iterate_lexical_indices(
+    s: &'_ str,
+) -> impl DoubleEndedIterator<Item = (Range<usize>, char)> + Clone + '_ {
+    s.char_indices().flat_map(|(i, c)| {
+        let range = i..i + c.len_utf8();
+        iterate_lexical_char(c).map(move |l| (range.clone(), l))
+    })
 }
 
 #[test]
-#[cfg(feature = "std")]
+#[cfg(all(feature = "std", feature = "unicode"))]
 fn test_iteration() {
     fn it(s: &'static str) -> String {
         iterate_lexical(s).collect()
@@ -191,10 +833,515 @@ fn test_iteration() {
     assert_eq!(&it("北亰"), "beijing");
     assert_eq!(&it("ΣΣΣ"), "sss");
     assert_eq!(&it("à"), "a"); // 'a' with combining diacritical mark '\u{300}'
+
+    assert_eq!(
+        iterate_lexical("Æ ß½").rev().collect::<String>(),
+        "2/1ss ea"
+    );
 }
 
 #[test]
-#[cfg(feature = "std")]
+#[cfg(feature = "unicode")]
+fn test_nth() {
+    let chars = ['ß', '½', 'æ', 'Ⅻ', '⑫', 'a', 'Z', '-', '\u{301}', '🦄', '¹'];
+    for &c in &chars {
+        for &only_alnum in &[false, true] {
+            let make = || match only_alnum {
+                false => iterate_lexical_char(c),
+                true => iterate_lexical_char_only_alnum(c),
+            };
+            // the elements returned by a naive `next()` loop
+            let mut expected = [None; 5];
+            let mut iter = make();
+            for x in expected.iter_mut() {
+                *x = iter.next();
+            }
+
+            for n in 0..5 {
+                let mut iter = make();
+                assert_eq!(iter.nth(n), expected[n], "{:?}.nth({})", c, n);
+                // the iterator continues after the nth element
+                for &next in &expected[n + 1..] {
+                    assert_eq!(iter.next(), next, "{:?}.nth({})", c, n);
+                }
+                let rest = expected[n..].iter().flatten().copied();
+                assert!(make().skip(n).eq(rest), "{:?}.skip({})", c, n);
+            }
+        }
+    }
+
+    let mut iter = iterate_lexical_char('½');
+    assert_eq!(iter.nth(1), Some('/'));
+    assert_eq!(iter.next(), Some('2'));
+    assert_eq!(iter.next(), None);
+    let mut iter = iterate_lexical_char('a');
+    assert_eq!(iter.nth(1), None);
+    assert_eq!(iter.next(), None);
+    let mut iter = iterate_lexical_char('ß');
+    assert_eq!(iter.nth(2), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+#[cfg(feature = "unicode")]
+fn test_next_back() {
+    let expansions = [
+        (iterate_lexical_char('a'), "a"),
+        (iterate_lexical_char('-'), "-"),
+        (iterate_lexical_char('ß'), "ss"),
+        (iterate_lexical_char('⑫'), "12"),
+        (iterate_lexical_char('½'), "1/2"),
+        (iterate_lexical_char('…'), "..."),
+        (iterate_lexical_char_only_alnum('½'), "12"),
+        (iterate_lexical_char_only_alnum('-'), ""),
+        (iterate_lexical_char('\u{301}'), ""),
+    ];
+    for (iter, s) in &expansions {
+        assert!(iter.clone().rev().eq(s.chars().rev()), "{:?}", s);
+
+        // alternating between both ends
+        for &front_first in &[true, false] {
+            let mut iter = iter.clone();
+            let mut chars = s.chars();
+            let mut front = front_first;
+            loop {
+                let (next, expected) = match front {
+                    true => (iter.next(), chars.next()),
+                    false => (iter.next_back(), chars.next_back()),
+                };
+                assert_eq!(next, expected, "{:?}", s);
+                if next.is_none() {
+                    break;
+                }
+                front = !front;
+            }
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next_back(), None);
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "unicode")]
+fn test_lexical_iter() {
+    struct Cursor<'a> {
+        iter: LexicalIter<'a>,
+    }
+
+    let mut cursor = Cursor {
+        iter: iterate_lexical("Straße ½"),
+    };
+    assert!(cursor.iter.by_ref().take(5).eq("stras".chars()));
+    let lookahead = cursor.iter.clone();
+    assert!(lookahead.eq("se 1/2".chars()));
+    assert_eq!(cursor.iter.next(), Some('s'));
+    assert_eq!(cursor.iter.next_back(), Some('2'));
+    assert!(cursor.iter.clone().eq("e 1/".chars()));
+    assert!(cursor.iter.rev().eq("/1 e".chars()));
+
+    let iter = iterate_lexical_only_alnum("a-ß ½!");
+    assert!(iter.clone().eq("ass12".chars()));
+    assert!(iter.rev().eq("21ssa".chars()));
+
+    let mut iter = iterate_lexical("ß");
+    assert_eq!(iter.size_hint(), (0, None));
+    assert_eq!(iter.next(), Some('s'));
+    assert_eq!(iter.size_hint(), (1, Some(1)));
+    assert_eq!(iter.next(), Some('s'));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+#[cfg(feature = "unicode")]
+fn test_lexical_display() {
+    /// A writer with a fixed capacity, which doesn't allocate
+    struct Buffer {
+        bytes: [u8; 32],
+        len: usize,
+    }
+
+    impl fmt::Write for Buffer {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let end = self.len + s.len();
+            if end > self.bytes.len() {
+                return Err(fmt::Error);
+            }
+            self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    impl Buffer {
+        fn as_str(&self) -> &str {
+            core::str::from_utf8(&self.bytes[..self.len]).unwrap()
+        }
+    }
+
+    use fmt::Write;
+    let mut buf = Buffer {
+        bytes: [0; 32],
+        len: 0,
+    };
+    write!(buf, "[{}]", lexical_display("Straße ½ Æ")).unwrap();
+    assert_eq!(buf.as_str(), "[strasse 1/2 ae]");
+    buf.len = 0;
+    write!(buf, "{}", lexical_only_alnum_display("Straße ½ Æ!")).unwrap();
+    assert_eq!(buf.as_str(), "strasse12ae");
+    buf.len = 0;
+    write!(buf, "{:>20}", lexical_display("a\u{300}🦄")).unwrap();
+    assert_eq!(buf.as_str(), "a🦄");
+    // the writer's errors are propagated
+    buf.len = 30;
+    assert!(write!(buf, "{}", lexical_display("ßß")).is_err());
+
+    assert_eq!(lexical_display("Foo Bär"), "foo bar");
+    assert_eq!(lexical_display(""), "");
+    assert!(lexical_display("Foo") != "Foo");
+    assert!(lexical_display("foo") != "foo ");
+    assert!(lexical_only_alnum_display("f-o-o") == *"foo");
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "unicode"))]
+fn test_lexical_to_string() {
+    use crate::{lexical_char_cmp, lexical_cmp, lexical_eq, lexical_only_alnum_cmp};
+    use core::cmp::Ordering;
+
+    assert_eq!(lexical_to_string(""), "");
+    assert_eq!(lexical_to_string("Ω A æ b ö ß é"), "o a ae b o ss e");
+    assert_eq!(lexical_to_string("北亰"), "beijing");
+    assert_eq!(lexical_to_string("a\u{300}🦄"), "a🦄");
+    assert_eq!(lexical_only_alnum_to_string("3½/⅝ £"), "31258");
+
+    let mut out = String::from("key: ");
+    write_lexical(&mut out, "Äpfel");
+    write_lexical_only_alnum(&mut out, " & Birnen!");
+    assert_eq!(out, "key: apfelbirnen");
+
+    let strings = [
+        "", " ", "-", "~", "a", "A", "ä", "aa", "æ", "ae", "ss", "ß", "Straße", "STRASSE",
+        "strasse~", "½", "1/2", "12", "Foo", "fóò", "foo bar", "foo-bar", "foobar", "北亰",
+        "beijing", "🦄", "a\u{300}",
+    ];
+    for lhs in &strings {
+        for rhs in &strings {
+            let (l, r) = (lexical_to_string(lhs), lexical_to_string(rhs));
+            assert_eq!(l == r, lexical_eq(lhs, rhs), "{:?} {:?}", lhs, rhs);
+
+            // comparing the results character by character gives the order of `lexical_cmp`
+            let ordering = l
+                .chars()
+                .zip(r.chars())
+                .map(|(l, r)| lexical_char_cmp(l, r))
+                .find(|&o| o != Ordering::Equal)
+                .unwrap_or_else(|| l.len().cmp(&r.len()));
+            if ordering != Ordering::Equal {
+                assert_eq!(ordering, lexical_cmp(lhs, rhs), "{:?} {:?}", lhs, rhs);
+            }
+
+            let (l, r) = (
+                lexical_only_alnum_to_string(lhs),
+                lexical_only_alnum_to_string(rhs),
+            );
+            if l != r {
+                let ordering = lexical_only_alnum_cmp(lhs, rhs);
+                assert_eq!(l.cmp(&r), ordering, "{:?} {:?}", lhs, rhs);
+            }
+        }
+    }
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "unicode"))]
+fn test_lexical_iter_rev() {
+    let strings = [
+        "",
+        "ß",
+        "Straße",
+        "a\u{300}",
+        "\u{301}a\u{301}\u{302}",
+        "e\u{301}\u{200B}x",
+        "½⅝",
+        "x.tar.gz",
+        "Æsop…",
+        "🦄 北亰",
+        "a-b ⑫!",
+    ];
+    for s in &strings {
+        let forward: Vec<char> = iterate_lexical(s).collect();
+        assert!(
+            iterate_lexical(s).rev().eq(forward.into_iter().rev()),
+            "{:?}",
+            s
+        );
+        let forward: Vec<char> = iterate_lexical_only_alnum(s).collect();
+        assert!(
+            iterate_lexical_only_alnum(s)
+                .rev()
+                .eq(forward.into_iter().rev()),
+            "{:?}",
+            s
+        );
+    }
+
+    let mut iter = iterate_lexical("Maß");
+    assert_eq!(iter.next_back(), Some('s'));
+    assert_eq!(iter.next_back(), Some('s'));
+    assert_eq!(iter.next_back(), Some('a'));
+    assert_eq!(iter.next_back(), Some('m'));
+    assert_eq!(iter.next_back(), None);
+
+    // both ends meet in the middle of an expansion
+    let mut iter = iterate_lexical("½");
+    assert_eq!(iter.next(), Some('1'));
+    assert_eq!(iter.next_back(), Some('2'));
+    assert_eq!(iter.next(), Some('/'));
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "unicode"))]
+fn test_iterate_lexical_indices() {
+    fn it(s: &str) -> Vec<(Range<usize>, char)> {
+        iterate_lexical_indices(s).collect()
+    }
+
+    assert_eq!(
+        it("Straße"),
+        [
+            (0..1, 's'),
+            (1..2, 't'),
+            (2..3, 'r'),
+            (3..4, 'a'),
+            (4..6, 's'),
+            (4..6, 's'),
+            (6..7, 'e'),
+        ]
+    );
+    assert_eq!(it("a\u{300}"), [(0..1, 'a')]);
+    assert_eq!(it("a\u{300}b"), [(0..1, 'a'), (3..4, 'b')]);
+    assert_eq!(it("e\u{301}\u{200B}"), [(0..1, 'e')]);
+    assert_eq!(it("🦄!"), [(0..4, '🦄'), (4..5, '!')]);
+    assert_eq!(
+        it("x½"),
+        [(0..1, 'x'), (1..3, '1'), (1..3, '/'), (1..3, '2')]
+    );
+    assert_eq!(it(""), []);
+
+    // the characters are the same as in `iterate_lexical()`
+    for s in &["Straße", "Ω A æ b ö ß é", "3½/⅝ £ → € ®™", "北亰", "à"] {
+        assert!(iterate_lexical_indices(s)
+            .map(|(_, c)| c)
+            .eq(iterate_lexical(s)));
+        assert!(iterate_lexical_indices(s)
+            .rev()
+            .map(|(_, c)| c)
+            .eq(iterate_lexical(s).rev()));
+        for (range, _) in iterate_lexical_indices(s) {
+            assert_eq!(s[range].chars().count(), 1);
+        }
+    }
+}
+
+#[test]
+fn test_typographic_punctuation() {
+    let pairs = [
+        ("It\u{2019}s", "It's"),
+        ("\u{2018}quoted\u{2019}", "'quoted'"),
+        ("\u{201C}quoted\u{201D}", "\"quoted\""),
+        ("\u{201E}quoted\u{201C}", "\"quoted\""),
+        (
+            "a\u{2010}b\u{2011}c\u{2012}d\u{2013}e\u{2014}f\u{2015}g",
+            "a-b-c-d-e-f-g",
+        ),
+        ("\u{2212}5", "-5"),
+        ("wait\u{2026}", "wait..."),
+    ];
+    for &(typographic, ascii) in &pairs {
+        assert!(
+            iterate_lexical(typographic).eq(iterate_lexical(ascii)),
+            "{:?}",
+            typographic
+        );
+        assert!(iterate_lexical_only_alnum(typographic).eq(iterate_lexical_only_alnum(ascii)));
+    }
+    assert!(iterate_lexical_char_only_alnum('\u{2019}').eq([]));
+    assert!(iterate_lexical_char('\u{2026}').eq(['.', '.', '.']));
+    // other punctuation is unchanged
+    assert!(iterate_lexical_char('\u{AB}').eq(['\u{AB}']));
+    assert!(iterate_lexical_char('\u{3001}').eq(['\u{3001}']));
+
+    // curly and straight spellings are adjacent, and the straight one is less
+    use crate::{lexical_cmp, natural_lexical_cmp};
+    use core::cmp::Ordering;
+    let sorted = [
+        "It's",
+        "It\u{2019}s",
+        "It\u{2019}s a trap",
+        "It\u{2026}s",
+        "Its",
+    ];
+    for (i, lhs) in sorted.iter().enumerate() {
+        for (j, rhs) in sorted.iter().enumerate() {
+            assert_eq!(lexical_cmp(lhs, rhs), i.cmp(&j), "{:?} {:?}", lhs, rhs);
+        }
+    }
+    assert_eq!(
+        natural_lexical_cmp("Part\u{2013}2", "Part-10"),
+        Ordering::Less
+    );
+    assert_eq!(crate::cmp("It\u{2019}s", "Its"), Ordering::Greater);
+}
+
+#[test]
+#[cfg(feature = "unicode")]
+fn test_fullwidth_forms() {
+    let pairs = [
+        ("\u{FF08}draft\u{FF09}\u{FF01}\u{FF1F}", "(draft)!?"),
+        ("\u{FF21}\u{FF42}\u{FF43}\u{FF11}\u{FF12}\u{FF13}", "Abc123"),
+        (
+            "\u{FF5F}\u{FF61}\u{FF62}\u{FF63}\u{FF64}\u{FF65}",
+            "⦅。「」、・",
+        ),
+        (
+            "\u{FFE0}\u{FFE1}\u{FFE2}\u{FFE3}\u{FFE4}\u{FFE5}\u{FFE6}",
+            "¢£¬¯¦¥₩",
+        ),
+        (
+            "\u{FFE8}\u{FFE9}\u{FFEA}\u{FFEB}\u{FFEC}\u{FFED}\u{FFEE}",
+            "│←↑→↓■○",
+        ),
+        ("\u{FF76}\u{FF80}\u{FF76}\u{FF85}", "カタカナ"),
+    ];
+    for &(fullwidth, normal) in &pairs {
+        assert!(
+            iterate_lexical(fullwidth).eq(iterate_lexical(normal)),
+            "{:?}",
+            fullwidth
+        );
+        assert!(iterate_lexical_only_alnum(fullwidth).eq(iterate_lexical_only_alnum(normal)));
+    }
+    assert!(iterate_lexical_char('\u{FF3F}').eq(['_']));
+    assert!(iterate_lexical_char_only_alnum('\u{FF08}').eq([]));
+    assert!(iterate_lexical_char_only_alnum('\u{FF39}').eq(['y']));
+
+    // fullwidth and halfwidth spellings are adjacent, and the halfwidth one is less
+    use crate::natural_lexical_cmp;
+    let sorted = [
+        "(draft)",
+        "\u{FF08}draft\u{FF09}",
+        "-draft",
+        "draft",
+        "\u{FF44}raft 2",
+        "draft 10",
+    ];
+    for (i, lhs) in sorted.iter().enumerate() {
+        for (j, rhs) in sorted.iter().enumerate() {
+            assert_eq!(
+                natural_lexical_cmp(lhs, rhs),
+                i.cmp(&j),
+                "{:?} {:?}",
+                lhs,
+                rhs
+            );
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "unicode")]
+fn test_mathematical_alphanumerics() {
+    let styles = [
+        "\u{1D400}\u{1D41B}\u{1D41C}",
+        "\u{1D49C}\u{1D4B7}\u{1D4B8}",
+        "\u{1D538}\u{1D553}\u{1D554}",
+        "\u{1D5A0}\u{1D5BB}\u{1D5BC}",
+        "\u{1D670}\u{1D68B}\u{1D68C}",
+    ];
+    for style in &styles {
+        assert!(iterate_lexical(style).eq("abc".chars()), "{:?}", style);
+        assert!(iterate_lexical_only_alnum(style).eq("abc".chars()));
+    }
+
+    // all assigned letters and digits of the block
+    for code in (0x1D400..=0x1D6A3).chain(0x1D7CE..=0x1D7FF) {
+        let c = match core::char::from_u32(code) {
+            Some(c) if c.is_alphanumeric() => c,
+            _ => continue,
+        };
+        let plain = match code {
+            0x1D7CE..=0x1D7FF => (b'0' + ((code - 0x1D7CE) % 10) as u8) as char,
+            _ => (b'a' + ((code - 0x1D400) % 26) as u8) as char,
+        };
+        assert!(iterate_lexical_char(c).eq([plain]), "{:?}", c);
+        assert!(iterate_lexical_char_only_alnum(c).eq([plain]), "{:?}", c);
+    }
+
+    // Greek letters are transliterated like the plain letters
+    assert!(iterate_lexical("\u{1D6AF}\u{1D6C2}\u{1D7C9}").eq(iterate_lexical("Θαϖ")));
+
+    use crate::{natural_cmp, natural_lexical_cmp, natural_lexical_only_alnum_cmp};
+    use core::cmp::Ordering;
+    assert_eq!(
+        natural_lexical_cmp("\u{1D7D0}\u{1D7CE} things", "9 things"),
+        Ordering::Greater
+    );
+    assert_eq!(
+        natural_cmp("\u{1D7D0}\u{1D7CE} things", "9 things"),
+        Ordering::Greater
+    );
+    assert_eq!(
+        natural_lexical_only_alnum_cmp("\u{1D7D0}0 things", "\u{1D7EB} things"),
+        Ordering::Greater
+    );
+    assert_eq!(
+        natural_lexical_cmp("\u{1D400}\u{1D41B}\u{1D41C} 2", "abc 10"),
+        Ordering::Less
+    );
+}
+
+#[test]
+fn test_transliterate_backend() {
+    struct Toy;
+
+    impl Transliterate for Toy {
+        fn transliterate(&self, _: char) -> Mapped {
+            Mapped::Str("x")
+        }
+    }
+
+    let it = |s: &'static str| s.chars().flat_map(|c| iterate_lexical_char_with(c, &Toy));
+    assert!(it("Ω A æ-ß é").eq("x a x-x x".chars()));
+    assert!(it("北亰\u{301}").eq("xx".chars()));
+    let it = |s: &'static str| {
+        s.chars()
+            .flat_map(|c| iterate_lexical_char_only_alnum_with(c, &Toy))
+    };
+    assert!(it("Ω A æ-ß é").eq("xaxxx".chars()));
+
+    struct Other;
+
+    impl Transliterate for Other {
+        fn transliterate(&self, c: char) -> Mapped {
+            match c {
+                'ä' => Mapped::Skip,
+                'ö' => Mapped::Char('o'),
+                _ => Mapped::Str("ü"),
+            }
+        }
+    }
+    assert!(iterate_lexical_char_with('ä', &Other).eq([]));
+    assert!(iterate_lexical_char_with('ö', &Other).eq(['o']));
+    assert!(iterate_lexical_char_with('ß', &Other).eq(['ß']));
+    #[cfg(feature = "unicode")]
+    assert!(iterate_lexical_char_with('Ω', &AnyAscii).eq(iterate_lexical_char('Ω')));
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "unicode"))]
 fn test_iteration_only_alnum() {
     fn it(s: &'static str) -> String {
         iterate_lexical_only_alnum(s).collect()
@@ -202,10 +1349,187 @@ fn test_iteration_only_alnum() {
 
     assert_eq!(&it("Hello, world!"), "helloworld");
     assert_eq!(&it("Ω A æ b ö ß é"), "oaaebosse");
-    assert_eq!(&it("3½/⅝ £ → € ®™"), "31/25/8");
+    assert_eq!(&it("3½/⅝ £ → € ®™"), "31258");
     assert_eq!(&it("»@« 15% ¡¹!"), "151");
     assert_eq!(&it("🎉🦄☣"), "");
     assert_eq!(&it("北亰"), "beijing");
     assert_eq!(&it("ΣΣΣ"), "sss");
     assert_eq!(&it("à"), "a"); // 'a' with combining diacritical mark '\u{300}'
 }
+
+#[test]
+#[cfg(all(feature = "std", feature = "unicode"))]
+fn test_combining_marks() {
+    fn it(s: &str) -> String {
+        iterate_lexical(s).collect()
+    }
+    fn it_alnum(s: &str) -> String {
+        iterate_lexical_only_alnum(s).collect()
+    }
+
+    let marked = [
+        "s\u{1DC4}",
+        "s\u{1AB0}",
+        "s\u{1DE7}",
+        "s\u{20D7}",
+        "s\u{FE20}",
+        "s\u{345}",
+        "s\u{323}\u{302}",
+    ];
+    for s in &marked {
+        assert_eq!(&it(s), "s", "{:?}", s);
+        assert_eq!(&it_alnum(s), "s", "{:?}", s);
+    }
+    // other nonspacing marks are kept
+    assert_eq!(&it("\u{915}\u{94D}"), "k\u{94D}");
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_invisible_chars() {
+    fn it(s: &str) -> String {
+        iterate_lexical(s).collect()
+    }
+
+    let strings = [
+        "foo\u{200B}bar",
+        "foo\u{200C}bar",
+        "foo\u{200D}bar",
+        "foo\u{AD}bar",
+        "foo\u{2060}bar",
+        "\u{FEFF}foobar",
+    ];
+    for s in &strings {
+        assert_eq!(&it(s), "foobar", "{:?}", s);
+        assert_eq!(&iterate_lexical_only_alnum(s).collect::<String>(), "foobar");
+    }
+
+    // bidirectional text controls
+    let name = "\u{645}\u{644}\u{641}.txt";
+    let wrapped = [
+        "\u{202B}\u{645}\u{644}\u{641}\u{202C}.txt",
+        "\u{2067}\u{645}\u{644}\u{641}\u{2069}.txt",
+        "\u{200F}\u{645}\u{644}\u{641}\u{200E}.txt",
+        "\u{202E}\u{645}\u{644}\u{641}\u{61C}.txt",
+    ];
+    for s in &wrapped {
+        assert!(iterate_lexical(s).eq(iterate_lexical(name)), "{:?}", s);
+        assert!(iterate_lexical_only_alnum(s).eq(iterate_lexical_only_alnum(name)));
+    }
+
+    let mut sorted = ["foobas", "foo\u{200B}bar", "foo bar", "foobar", "fooba"];
+    sorted.sort_by(|l, r| crate::lexical_cmp(l, r));
+    assert_eq!(
+        sorted,
+        ["foo bar", "fooba", "foobar", "foo\u{200B}bar", "foobas"]
+    );
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "unicode"))]
+fn test_only_alnum_expansion() {
+    let c: LexicalChar = iterate_lexical_char_only_alnum('½');
+    assert_eq!(c.len(), 2);
+    assert_eq!(c.collect::<String>(), "12");
+    assert_eq!(
+        iterate_lexical_char_only_alnum('½')
+            .rev()
+            .collect::<String>(),
+        "21"
+    );
+    assert_eq!(iterate_lexical_char_only_alnum('⅝').nth(1), Some('8'));
+    assert_eq!(iterate_lexical_char('½').collect::<String>(), "1/2");
+
+    // the slash of the expansion is skipped like a literal slash
+    use crate::{lexical_only_alnum_cmp, lexical_only_alnum_eq};
+    use core::cmp::Ordering;
+    assert!(lexical_only_alnum_eq("x½y", "x12y"));
+    assert!(lexical_only_alnum_eq("x½y", "x1/2y"));
+    assert_eq!(lexical_only_alnum_cmp("x½z", "x12y"), Ordering::Greater);
+    assert_eq!(lexical_only_alnum_cmp("x½y", "x12z"), Ordering::Less);
+    assert_eq!(lexical_only_alnum_cmp("x½y", "x1-2z"), Ordering::Less);
+    assert_eq!(lexical_only_alnum_cmp("x1/2z", "x½y"), Ordering::Greater);
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "unicode"))]
+fn test_case_variants() {
+    fn it(s: &str) -> String {
+        iterate_lexical(s).collect()
+    }
+
+    let variants: &[&[&str]] = &[
+        &["ß", "ẞ", "ss", "SS", "Ss"],
+        &["ŉ", "ʼN", "\u{2bc}n"],
+        &["ǰ", "J̌"],
+        &["ﬓ", "ՄՆ", "մն"],
+        &["ﬀ", "FF", "ff"],
+        &["ﬁ", "FI", "fi"],
+        &["ﬆ", "ST", "st"],
+        &["Ǆ", "ǅ", "ǆ", "DŽ", "dž"],
+        &["Ǉ", "ǈ", "ǉ", "LJ", "lj"],
+        &["ΐ", "Ϊ́"],
+        &["İ", "i̇", "i"],
+        &["ſ", "S", "s"],
+        &["\u{212A}", "K", "k"],
+        &["\u{212B}", "Å", "å"],
+        &["\u{2126}", "Ω", "ω"],
+        &["µ", "Μ", "μ"],
+        &["ϐ", "Β", "β"],
+        &["ϕ", "Φ", "φ"],
+        &["Ɤ", "ɤ"],
+        &["Ƛ", "ƛ"],
+        &["Ⱟ", "ⱟ"],
+    ];
+    for group in variants {
+        let expected = it(group[0]);
+        for s in group.iter() {
+            assert_eq!(it(s), expected, "{:?} vs {:?}", s, group[0]);
+            let lower: String = s.to_lowercase();
+            let upper: String = s.to_uppercase();
+            assert_eq!(it(&lower), expected, "lowercase of {:?}", s);
+            assert_eq!(it(&upper), expected, "uppercase of {:?}", s);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn normalizer_chunks(
+            s in "[-aAäß\u{300}\u{301}½ⅫΩ北亰🦄 .…]{0,12}",
+            splits in proptest::collection::vec(0..40usize, 0..6),
+        ) {
+            // split the string at char boundaries
+            let mut bounds: Vec<usize> = splits
+                .iter()
+                .map(|&i| s.char_indices().nth(i).map_or(s.len(), |(i, _)| i))
+                .collect();
+            bounds.push(0);
+            bounds.push(s.len());
+            bounds.sort_unstable();
+
+            for &only_alnum in &[false, true] {
+                let (mut normalizer, expected) = match only_alnum {
+                    false => (LexicalNormalizer::new(), lexical_to_string(&s)),
+                    true => (LexicalNormalizer::new_only_alnum(), lexical_only_alnum_to_string(&s)),
+                };
+                let mut out = String::new();
+                for w in bounds.windows(2) {
+                    normalizer.push_str(&s[w[0]..w[1]], &mut out);
+                }
+                prop_assert_eq!(&out, &expected);
+
+                let mut out = String::new();
+                for c in s.chars() {
+                    normalizer.push_char(c, &mut out);
+                }
+                prop_assert_eq!(&out, &expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,86 @@
+//! Folding of Japanese kana, used with `Collator::fold_kana`.
+
+/// The hiragana, grouped by their vowel
+const VOWELS: [(char, &str); 5] = [
+    ('あ', "あかがさざただなはばぱまやらわ"),
+    ('い', "いきぎしじちぢにひびぴみりゐ"),
+    ('う', "うくぐすずつづぬふぶぷむゆるゔ"),
+    ('え', "えけげせぜてでねへべぺめれゑ"),
+    ('お', "おこごそぞとどのほぼぽもよろを"),
+];
+
+/// The full-size hiragana of the small katakana from U+31F0 to U+31FF
+const SMALL_KATAKANA: &str = "くしすとぬはひふへほむらりるれろ";
+
+/// The prolonged sound mark `ー`
+const PROLONGED_SOUND_MARK: char = '\u{30FC}';
+
+/// Folds a kana to its full-size hiragana, e.g. `カ` and `ゃ` to `か` and `や`. The prolonged
+/// sound mark `ー` is folded to the vowel of the kana before it (`prev`), e.g. to `あ` after
+/// `カ`. Other characters are returned unchanged.
+pub(crate) fn fold_kana(c: char, prev: impl Iterator<Item = char>) -> char {
+    if c == PROLONGED_SOUND_MARK {
+        return prev
+            .filter(|&p| p != PROLONGED_SOUND_MARK)
+            .map(hiragana)
+            .next()
+            .and_then(|p| match p {
+                'ん' => Some(p),
+                _ => VOWELS.iter().find(|(_, row)| row.contains(p)).map(|v| v.0),
+            })
+            .unwrap_or(c);
+    }
+    hiragana(c)
+}
+
+/// Folds a kana to its full-size hiragana
+fn hiragana(c: char) -> char {
+    let c = match c {
+        '\u{30A1}'..='\u{30F6}' | '\u{30FD}'..='\u{30FE}' => {
+            core::char::from_u32(c as u32 - 0x60).unwrap_or(c)
+        }
+        '\u{31F0}'..='\u{31FF}' => {
+            let i = c as usize - 0x31F0;
+            SMALL_KATAKANA.chars().nth(i).unwrap_or(c)
+        }
+        _ => c,
+    };
+    match c {
+        'ぁ' | 'ぃ' | 'ぅ' | 'ぇ' | 'ぉ' | 'っ' | 'ゃ' | 'ゅ' | 'ょ' | 'ゎ' => {
+            core::char::from_u32(c as u32 + 1).unwrap_or(c)
+        }
+        'ゕ' => 'か',
+        'ゖ' => 'け',
+        _ => c,
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fold_kana() {
+        let fold = |s: &str| {
+            s.char_indices()
+                .map(|(i, c)| fold_kana(c, s[..i].chars().rev()))
+                .collect::<Vec<char>>()
+        };
+        assert_eq!(fold("カタカナ"), ['か', 'た', 'か', 'な']);
+        assert_eq!(
+            fold("ァィゥェォッャュョヮヵヶ"),
+            ['あ', 'い', 'う', 'え', 'お', 'つ', 'や', 'ゆ', 'よ', 'わ', 'か', 'け']
+        );
+        assert_eq!(fold("ぁっゃゎゕゖ"), ['あ', 'つ', 'や', 'わ', 'か', 'け']);
+        assert_eq!(fold("ㇰㇱㇿ"), ['く', 'し', 'ろ']);
+        assert_eq!(fold("ヽヾ"), ['ゝ', 'ゞ']);
+        assert_eq!(
+            fold("カーキーグーセーゾーー"),
+            ['か', 'あ', 'き', 'い', 'ぐ', 'う', 'せ', 'え', 'ぞ', 'お', 'お']
+        );
+        assert_eq!(fold("キャー"), ['き', 'や', 'あ']);
+        assert_eq!(fold("ンー"), ['ん', 'ん']);
+        assert_eq!(fold("ーaー"), ['ー', 'a', 'ー']);
+        assert_eq!(fold("ヷ漢"), ['ヷ', '漢']);
+    }
+}
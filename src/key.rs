@@ -0,0 +1,70 @@
+//! A wrapper to use strings as keys that are equal if they are lexically equal.
+
+use crate::cmp::{lexical_eq, lexical_hash};
+use core::hash::{Hash, Hasher};
+
+/// A string that is compared with `lexical_eq` and hashed with `lexical_hash`, so it can be
+/// used in a `HashMap` or `HashSet` for lookups that ignore case and diacritics
+///
+/// Two keys are equal if their strings are equal after transliteration, e.g. `"Foo"` and
+/// `"fóò"`. The string can be any type that implements `AsRef<str>`, e.g. `&str` or
+/// `String`.
+///
+/// With the optional `serde` feature, a key is serialized as its string. To store a
+/// precomputed key that can be compared without the string, use `SortKey`.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::LexicalKey;
+/// use std::collections::HashSet;
+///
+/// let mut set = HashSet::new();
+/// set.insert(LexicalKey("Crème brûlée"));
+/// assert!(set.contains(&LexicalKey("creme brulee")));
+/// assert!(!set.insert(LexicalKey("CRÈME BRÛLÉE")));
+/// assert_eq!(set.len(), 1);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct LexicalKey<S>(pub S);
+
+impl<S: AsRef<str>, T: AsRef<str>> PartialEq<LexicalKey<T>> for LexicalKey<S> {
+    fn eq(&self, other: &LexicalKey<T>) -> bool {
+        lexical_eq(self.0.as_ref(), other.0.as_ref())
+    }
+}
+
+impl<S: AsRef<str>> Eq for LexicalKey<S> {}
+
+impl<S: AsRef<str>> Hash for LexicalKey<S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        lexical_hash(self.0.as_ref(), state);
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_lexical_key() {
+        assert_eq!(LexicalKey("Foo"), LexicalKey("fóò"));
+        assert_eq!(LexicalKey("Foo"), LexicalKey(String::from("FOO")));
+        assert_ne!(LexicalKey("Foo"), LexicalKey("Foo "));
+
+        let mut map = HashMap::new();
+        map.insert(LexicalKey(String::from("Zürich")), 1);
+        map.insert(LexicalKey(String::from("Genève")), 2);
+        *map.entry(LexicalKey(String::from("zurich"))).or_insert(0) += 10;
+        assert_eq!(map.len(), 2);
+        assert_eq!(map[&LexicalKey(String::from("ZURICH"))], 11);
+        assert_eq!(map.get(&LexicalKey(String::from("geneve"))), Some(&2));
+        assert_eq!(map.get(&LexicalKey(String::from("Genf"))), None);
+    }
+}
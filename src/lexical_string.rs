@@ -0,0 +1,365 @@
+//! String types that are ordered by `lexical_cmp`.
+
+use crate::cmp::lexical_cmp;
+use alloc::borrow::{Borrow, ToOwned};
+use alloc::string::{String, ToString};
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+
+/// A string slice that is ordered by `lexical_cmp`. This is the borrowed form of
+/// `LexicalString`, like `str` is the borrowed form of `String`.
+///
+/// It is needed to look up keys in a `BTreeMap<LexicalString, _>` or `BTreeSet<LexicalString>`
+/// without allocating: The map can't be searched with a `&str`, since `str` is ordered
+/// differently. A `&LexicalStr` is created with `LexicalStr::new`.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::{LexicalStr, LexicalString};
+/// use std::collections::BTreeMap;
+///
+/// let mut map = BTreeMap::new();
+/// map.insert(LexicalString::from("Zebra"), 1);
+/// map.insert(LexicalString::from("Äpfel"), 2);
+///
+/// assert_eq!(map.get(LexicalStr::new("Äpfel")), Some(&2));
+/// assert_eq!(map.get(LexicalStr::new("apfel")), None);
+/// assert!(LexicalStr::new("Äpfel") < LexicalStr::new("apple"));
+/// # }
+/// ```
+#[repr(transparent)]
+pub struct LexicalStr(str);
+
+impl LexicalStr {
+    /// Wraps a string slice
+    pub fn new(s: &str) -> &LexicalStr {
+        // SAFETY: `LexicalStr` is a `#[repr(transparent)]` wrapper around `str`, so the pointer
+        // casts are valid and the lifetime of the reference is preserved
+        unsafe { &*(s as *const str as *const LexicalStr) }
+    }
+
+    /// Returns the wrapped string slice
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<'a> From<&'a str> for &'a LexicalStr {
+    fn from(s: &'a str) -> Self {
+        LexicalStr::new(s)
+    }
+}
+
+impl Deref for LexicalStr {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for LexicalStr {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Strings are equal if they are identical, since `lexical_cmp` only returns `Equal` for
+/// identical strings
+impl PartialEq for LexicalStr {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for LexicalStr {}
+
+impl PartialOrd for LexicalStr {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for LexicalStr {
+    fn cmp(&self, other: &Self) -> Ordering {
+        lexical_cmp(&self.0, &other.0)
+    }
+}
+
+impl Hash for LexicalStr {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl fmt::Debug for LexicalStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Display for LexicalStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl ToOwned for LexicalStr {
+    type Owned = LexicalString;
+
+    fn to_owned(&self) -> LexicalString {
+        LexicalString(self.0.to_string())
+    }
+}
+
+/// A string that is ordered by `lexical_cmp`, so it can be used as a key in a `BTreeMap` or
+/// `BTreeSet` that iterates in lexical order
+///
+/// The order is a total order that is consistent with `Eq` and `Hash`: Because of the
+/// tie-break of `lexical_cmp`, strings are only equal if they are identical. So unlike
+/// `LexicalKey`, `"Foo"` and `"fóò"` are different keys, but they are next to each other. To
+/// look up a key with a string slice, wrap it in a `LexicalStr`.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::LexicalString;
+/// use std::collections::BTreeSet;
+///
+/// let mut set = BTreeSet::new();
+/// set.insert(LexicalString::from("Zebra"));
+/// set.insert(LexicalString::from("Äpfel"));
+/// set.insert(LexicalString::from("apple"));
+///
+/// let sorted: Vec<&str> = set.iter().map(|s| s.as_str()).collect();
+/// assert_eq!(sorted, ["Äpfel", "apple", "Zebra"]);
+/// # }
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct LexicalString(String);
+
+impl LexicalString {
+    /// Returns the wrapped string as a string slice
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the wrapped string as a `LexicalStr`
+    pub fn as_lexical_str(&self) -> &LexicalStr {
+        LexicalStr::new(&self.0)
+    }
+
+    /// Returns the wrapped string
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl From<String> for LexicalString {
+    fn from(s: String) -> Self {
+        LexicalString(s)
+    }
+}
+
+impl From<&str> for LexicalString {
+    fn from(s: &str) -> Self {
+        LexicalString(s.to_string())
+    }
+}
+
+impl From<LexicalString> for String {
+    fn from(s: LexicalString) -> Self {
+        s.0
+    }
+}
+
+impl Deref for LexicalString {
+    type Target = LexicalStr;
+
+    fn deref(&self) -> &LexicalStr {
+        self.as_lexical_str()
+    }
+}
+
+impl Borrow<LexicalStr> for LexicalString {
+    fn borrow(&self) -> &LexicalStr {
+        self.as_lexical_str()
+    }
+}
+
+impl AsRef<str> for LexicalString {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialOrd for LexicalString {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for LexicalString {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_lexical_str().cmp(other.as_lexical_str())
+    }
+}
+
+impl fmt::Debug for LexicalString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Display for LexicalString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+/// Serializes the string like a `str`
+#[cfg(feature = "serde")]
+impl serde::Serialize for LexicalStr {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+/// Serializes the string like a `String`
+#[cfg(feature = "serde")]
+impl serde::Serialize for LexicalString {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+/// Deserializes the string like a `String`
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for LexicalString {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <String as serde::Deserialize>::deserialize(deserializer).map(LexicalString)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use std::collections::{BTreeMap, BTreeSet};
+    use std::ops::Bound;
+
+    #[test]
+    fn test_lexical_string() {
+        let strings = [
+            "b", "T-5", "Äpfel", "apple", "", "Foo", "fóò", "FOO", "T-20", "ß",
+        ];
+        let set: BTreeSet<LexicalString> = strings.iter().map(|&s| s.into()).collect();
+        let mut sorted = strings;
+        sorted.sort_by(|a, b| lexical_cmp(a, b));
+        assert!(set
+            .iter()
+            .map(LexicalString::as_str)
+            .eq(sorted.iter().copied()));
+        assert_eq!(set.len(), strings.len());
+
+        // lookups with a borrowed key
+        assert!(set.contains(LexicalStr::new("fóò")));
+        assert!(!set.contains(LexicalStr::new("foo")));
+        let range: Vec<&str> = set
+            .range::<LexicalStr, _>((
+                Bound::Included(LexicalStr::new("f")),
+                Bound::Excluded(LexicalStr::new("g")),
+            ))
+            .map(|s| s.as_str())
+            .collect();
+        assert_eq!(range, ["FOO", "Foo", "fóò"]);
+
+        let mut map = BTreeMap::new();
+        map.insert(LexicalString::from(String::from("Zürich")), 1);
+        map.insert(LexicalString::from("Genève"), 2);
+        *map.entry(LexicalStr::new("Zürich").to_owned()).or_insert(0) += 10;
+        assert_eq!(map[LexicalStr::new("Zürich")], 11);
+        assert_eq!(map.get(<&LexicalStr>::from("Genève")), Some(&2));
+
+        let s = LexicalString::from("Äpfel");
+        assert_eq!(s.to_string(), "Äpfel");
+        assert_eq!(format!("{:?}", s), "\"Äpfel\"");
+        assert_eq!(format!("{}", s.as_lexical_str()), "Äpfel");
+        assert_eq!(s.len(), "Äpfel".len());
+        assert_eq!(String::from(s.clone()), s.into_string());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        use crate::{ByOrder, LexicalKey, NaturalLexical};
+
+        let s = LexicalString::from("Äpfel");
+        assert_eq!(serde_json::to_string(&s).unwrap(), "\"Äpfel\"");
+        assert_eq!(
+            serde_json::to_string(s.as_lexical_str()).unwrap(),
+            "\"Äpfel\""
+        );
+        assert_eq!(
+            serde_json::from_str::<LexicalString>("\"Äpfel\"").unwrap(),
+            s
+        );
+
+        let set: BTreeSet<LexicalString> =
+            serde_json::from_str(r#"["b", "Äpfel", "apple"]"#).unwrap();
+        assert_eq!(
+            serde_json::to_string(&set).unwrap(),
+            r#"["Äpfel","apple","b"]"#
+        );
+
+        let key: LexicalKey<String> = serde_json::from_str("\"Foo\"").unwrap();
+        assert_eq!(key, LexicalKey("fóò"));
+        assert_eq!(serde_json::to_string(&key).unwrap(), "\"Foo\"");
+
+        let set: BTreeSet<ByOrder<String, NaturalLexical>> =
+            serde_json::from_str(r#"["T-20", "t-5"]"#).unwrap();
+        assert_eq!(serde_json::to_string(&set).unwrap(), r#"["t-5","T-20"]"#);
+    }
+
+    mod proptests {
+        use super::super::*;
+        use proptest::prelude::*;
+        use std::collections::hash_map::DefaultHasher;
+
+        fn hash<T: Hash + ?Sized>(s: &T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            s.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        proptest! {
+            #[test]
+            fn lexical_string_laws(
+                a in "[-aAäÄbBßs 1]{0,5}",
+                b in "[-aAäÄbBßs 1]{0,5}",
+                c in "[-aAäÄbBßs 1]{0,5}",
+            ) {
+                let (a, b, c) = (LexicalString::from(a), LexicalString::from(b), LexicalString::from(c));
+
+                // a total order, consistent with `Eq`
+                prop_assert_eq!(a.cmp(&b), b.cmp(&a).reverse());
+                prop_assert_eq!(a.cmp(&b) == Ordering::Equal, a == b);
+                prop_assert_eq!(a.partial_cmp(&b), Some(a.cmp(&b)));
+                if a <= b && b <= c {
+                    prop_assert!(a <= c);
+                }
+
+                // `Hash` is consistent with `Eq`, also for the borrowed form
+                if a == b {
+                    prop_assert_eq!(hash(&a), hash(&b));
+                }
+                prop_assert_eq!(hash(&a), hash(a.as_lexical_str()));
+                prop_assert_eq!(a.as_lexical_str().cmp(b.as_lexical_str()), a.cmp(&b));
+            }
+        }
+    }
+}
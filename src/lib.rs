@@ -3,26 +3,62 @@
 //! character: `á` is treated as `a`, `ß` is treated as `ss`, etc.
 //!
 //! Lexical comparisons are case-insensitive. Alphanumeric characters are sorted after all other
-//! characters (punctuation, whitespace, special characters, emojis, ...).
+//! characters (punctuation, whitespace, special characters, emojis, ...). A `Collator` can sort
+//! the other characters last instead, with `Collator::symbols_last`.
 //!
 //! It is possible to enable **natural sorting**, which also handles ASCII numbers. For example,
 //! `50` is less than `100` with natural sorting turned on. It's also possible to skip
 //! characters that aren't alphanumeric, so e.g. `f-5` is next to `f5`.
 //!
-//! If different strings have the same ASCII representation (e.g. `"Foo"` and `"fóò"`), it
-//! falls back to the default method from the standard library, so sorting is deterministic.
+//! If different strings have the same ASCII representation (e.g. `"Foo"` and `"fóò"`), they
+//! are ordered by the script of their first letter: Latin, Greek, Cyrillic, Han, then all other
+//! scripts, so `"Anna" < "Άννα" < "Анна"`. Then the string that has an ASCII character at the
+//! first position where only one of them has a non-ASCII character is less. So `"ss" < "ß"`,
+//! and `"Sss" < "sß"`. If that doesn't decide it, it falls back to the default method from the
+//! standard library, so sorting is deterministic.
+//! Functions that skip non-alphanumeric characters first compare the strings with these
+//! characters included (e.g. `lexical_only_alnum_cmp` falls back to `lexical_cmp`), so each
+//! comparison function is a consistent total order.
+//!
+//! Strings that are empty after transliterating or skipping characters (e.g. `"-"` when
+//! skipping non-alphanumeric characters) sort before all other strings. Among themselves,
+//! they are ordered by their length, then with the default method from the standard library.
+//! This is the same in all eight comparison functions.
+//!
+//! The replacement character `�` (U+FFFD), which replaces invalid UTF-8 in
+//! `String::from_utf8_lossy` and `Path::to_string_lossy`, sorts after all other characters in
+//! lexical comparisons. So file names with invalid UTF-8 are sorted after all valid names with
+//! the same prefix. Functions that skip non-alphanumeric characters skip it as well, and the
+//! functions that don't sort lexicographically compare it by its code point. To compare byte
+//! strings without replacing invalid UTF-8, use `lexical_cmp_bytes` and the other functions
+//! ending in `_bytes`, or the `ByteSort` trait; they sort each invalid byte after all
+//! characters, including `�`.
 //!
 //! <table><tr><td>
 //! <b>NOTE</b>: This crate doesn't attempt to be correct for every locale, but it should work
-//! reasonably well for a wide range of locales, while providing excellent performance.
+//! reasonably well for a wide range of locales, while providing excellent performance. A few
+//! well-known tailorings, such as the German phone-book order, are available with
+//! `Collator::tailoring`.
 //! </td></tr></table>
 //!
 //! ## Usage
 //!
-//! To sort strings or paths, you can use the `StringSort` or `PathSort` trait:
+//! The easiest way to sort strings is `sort`, or `sort_natural` to order numbers by their value:
+//!
+//! ```rust
+//! # #[cfg(feature = "unicode")] {
+//! let mut strings = vec!["ß", "é", "100", "hello", "world", "50", ".", "B!"];
+//! lexical_sort::sort_natural(&mut strings);
+//!
+//! assert_eq!(&strings, &[".", "50", "100", "B!", "é", "hello", "ß", "world"]);
+//! # }
+//! ```
+//!
+//! Paths can be sorted with `sort_paths` and `sort_paths_natural`. To choose one of the other
+//! comparison functions, you can use the `StringSort` or `PathSort` trait:
 //!
 //! ```rust
-//! # #[cfg(feature = "std")] {
+//! # #[cfg(all(feature = "std", feature = "unicode"))] {
 //! use lexical_sort::{StringSort, natural_lexical_cmp};
 //!
 //! let mut strings = vec!["ß", "é", "100", "hello", "world", "50", ".", "B!"];
@@ -45,25 +81,294 @@
 //! | `natural_lexical_cmp`            | yes             | yes     |                              |
 //! | `natural_lexical_­only_alnum_cmp` | yes             | yes     | yes                          |
 //!
-//! Note that only the functions that sort lexicographically are case insensitive.
+//! Note that only the functions that sort lexicographically are case insensitive. To compare
+//! strings case-insensitively without transliterating them, use `casefold_cmp` or
+//! `natural_casefold_cmp`. To skip whitespace, but no other characters, use
+//! `lexical_ignore_whitespace_cmp` or `natural_lexical_ignore_whitespace_cmp`. To check
+//! whether two strings are equal apart from the tie-break (e.g. `"Foo"` and `"fóò"`), use the
+//! functions ending in `_eq`, such as `lexical_eq`. The functions ending in `_hash` hash
+//! strings consistently with them, and `LexicalKey` uses them to make strings usable as
+//! case-insensitive keys in a `HashMap`. To keep strings in lexical order in a `BTreeMap` or
+//! `BTreeSet`, use `LexicalString` keys, or `ByOrder` to choose another comparison function.
+//! The `LexicalOrd` trait provides methods such as `a.natural_lexical_lt(b)`; it can be
+//! imported with the other traits from the `prelude`.
+//!
+//! To choose a comparison function at runtime, you can use the `Compare` trait, which is
+//! implemented for unit structs corresponding to the eight functions (e.g. `NaturalLexical`).
+//! `SortMode` can be parsed from a string such as `"natural-lexical"`, e.g. from a
+//! command-line argument. For more options, such as replacing abbreviations like `St.` with `Saint`, use a `Collator`.
+//! To find out why two strings are ordered the way they are, use `explain_cmp`. To check that a
+//! list is sorted, use `verify_sorted`, which reports the first pair that is out of order.
+//! To remove strings that are equal up to case and diacritics after sorting, use
+//! `string_dedup` with `lexical_eq`.
+//! To sort the items of an iterator in an iterator chain, use the `LexicalSortedIterator` trait.
+//! To sort structs by a string field, use the `SortByStrKey` trait, or derive `Ord` with
+//! `#[derive(LexicalOrd)]` (this requires the `derive` feature). To sort in descending
+//! order or by several keys, combine comparison functions with the `combinators` module. To
+//! pass a comparison function to `slice::sort_by` or `Iterator::max_by`, e.g. for a
+//! `Vec<String>`, wrap it with `by`.
+//! To sort the lines of a text, like the `sort` command, use `sort_lines`. To find the least
+//! or greatest string without sorting, use `lexical_min` or `lexical_max`. To merge sorted
+//! sequences without sorting them again, use `merge_sorted` or `kmerge_sorted`. To sort a large
+//! slice without transliterating every string in each comparison, use `[_]::sort_by_cached_key`
+//! with a `SortKey`, or a `CachedCollator` to reuse the keys when the same strings are sorted
+//! repeatedly. `StringSort::string_sort_fast` sorts with `lexical_cmp`, but compares numeric
+//! prefix keys first, which is much faster for large slices. To store strings in a database so that they are ordered by a comparison
+//! function, use `collation_key`. With the optional `rayon` feature, the `ParStringSort` and
+//! `ParPathSort` traits sort large slices in parallel.
+//! To check whether a string starts with a prefix after folding, e.g. for search-as-you-type,
+//! use `lexical_starts_with`, or `lexical_find` to search for a substring. The `StringSearch`
+//! trait finds all strings with a prefix in a sorted slice, or a string with a binary search;
+//! `string_insert_sorted` keeps a `Vec` sorted. To sort version strings such as
+//! `1.10.0-rc.1`, use `version_cmp`, or `gnu_version_cmp` for the order of `ls -v`.
+//! To build a custom natural comparison, `cmp_digit_runs` compares numbers like the natural
+//! comparison functions.
+//!
+//! For ASCII strings, there's also `const_ascii_lexical_cmp`, a `const fn` that agrees with
+//! `lexical_cmp` and can be used to sort or check string literals at compile time, and
+//! `ascii_lexical_cmp` and `ascii_natural_lexical_cmp`, which are faster than the general
+//! functions for ASCII strings.
+//!
+//! Without the default `std` feature, this crate is `no_std`. The functions that allocate,
+//! e.g. `SortKey` and `StringSort::string_argsort`, require the `alloc` feature, and
+//! `PathSort` and the other items for paths require `std`.
+//!
+//! The default `unicode` feature enables the transliteration of non-ASCII characters. Without
+//! it, the `any_ascii` dependency isn't needed and the binary is smaller, but non-ASCII
+//! characters are only lowercased, so they're sorted after all ASCII characters. With the
+//! `stable` feature, the order only changes in semver-major releases, which increment
+//! `STABLE_ORDER_VERSION`.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod by_order;
+mod bytes;
+#[cfg(feature = "std")]
+mod cached;
+mod casefold;
+mod category;
 mod cmp;
+mod collator;
+pub mod combinators;
+mod compare;
+mod dedup;
+#[cfg(feature = "alloc")]
+mod deque;
+mod diff;
+mod digit;
+#[cfg(feature = "emoji-names")]
+mod emoji;
+#[cfg(feature = "alloc")]
+mod explain;
+mod hangul;
+mod index;
 pub mod iter;
+mod kana;
+mod key;
+#[cfg(feature = "alloc")]
+mod lexical_string;
+#[cfg(feature = "alloc")]
+mod lines;
+mod merge;
+mod minmax;
+mod option;
+mod ord;
+#[cfg(all(feature = "rayon", feature = "std"))]
+mod par;
+pub mod prelude;
+#[cfg(feature = "alloc")]
+mod rank;
+mod script;
+mod search;
+#[cfg(feature = "alloc")]
+mod sort_key;
+#[cfg(feature = "alloc")]
+mod sorted;
+mod tailoring;
+#[cfg(feature = "alloc")]
+mod verify;
+mod version;
 
+pub use by_order::ByOrder;
+#[cfg(feature = "std")]
+pub use by_order::ByPathOrder;
+pub use bytes::{
+    cmp_bytes, lexical_cmp_bytes, natural_cmp_bytes, natural_lexical_cmp_bytes, ByteSort,
+};
+#[cfg(feature = "std")]
+pub use cached::CachedCollator;
+pub use casefold::{casefold_cmp, natural_casefold_cmp};
 pub use cmp::{
-    cmp, lexical_cmp, lexical_only_alnum_cmp, natural_cmp, natural_lexical_cmp,
-    natural_lexical_only_alnum_cmp, natural_only_alnum_cmp, only_alnum_cmp,
+    ascii_lexical_cmp, ascii_natural_lexical_cmp, cmp, cmp_by_reading, cmp_digit_runs,
+    cmp_digit_runs_iter, column_label_cmp, const_ascii_lexical_cmp, is_sorted_ascii,
+    lexical_char_cmp, lexical_char_key, lexical_cmp, lexical_cmp_prefix, lexical_eq, lexical_hash,
+    lexical_ignore_whitespace_cmp, lexical_only_alnum_cmp, lexical_only_alnum_eq,
+    lexical_only_alnum_hash, multiline_cmp, natural_cmp, natural_cmp_prefix, natural_eq,
+    natural_hash, natural_lexical_cmp, natural_lexical_cmp_prefix, natural_lexical_eq,
+    natural_lexical_hash, natural_lexical_ignore_whitespace_cmp, natural_lexical_only_alnum_cmp,
+    natural_lexical_only_alnum_eq, natural_lexical_only_alnum_hash, natural_only_alnum_cmp,
+    natural_only_alnum_eq, natural_only_alnum_hash, only_alnum_cmp, only_alnum_eq, only_alnum_hash,
+    shortlex_lexical_cmp, shortlex_natural_lexical_cmp, wordwise_lexical_cmp,
+    wordwise_natural_lexical_cmp,
+};
+#[cfg(feature = "emoji-names")]
+pub use collator::EmojiOrder;
+#[cfg(feature = "unicode-normalization")]
+pub use collator::Normalization;
+pub use collator::{
+    cmp_with_position, common_lexical_prefix, common_lexical_prefix_of, lexical_cmp_at,
+    natural_lexical_cmp_at, AccentOrder, Collator, ControlPolicy, DecidedBy, LeadingZeros,
+    NulPolicy, Placement, TieBreak,
+};
+pub use combinators::by;
+pub use compare::{
+    Compare, Lexical, LexicalOnlyAlnum, Natural, NaturalLexical, NaturalLexicalOnlyAlnum,
+    NaturalOnlyAlnum, OnlyAlnum, ParseSortModeError, Plain, SortMode,
+};
+pub use dedup::count_lexical_duplicates;
+#[cfg(feature = "std")]
+pub use dedup::path_dedup;
+#[cfg(feature = "alloc")]
+pub use dedup::{string_dedup, string_dedup_by_key};
+pub use diff::{diff_sorted, sorted_difference, sorted_intersection, sorted_union, DiffItem};
+#[cfg(feature = "alloc")]
+pub use explain::{explain_cmp, Explanation, Reason};
+pub use index::{first_lexical_unit, IndexKey};
+pub use key::LexicalKey;
+#[cfg(feature = "derive")]
+pub use lexical_sort_derive::LexicalOrd;
+#[cfg(feature = "alloc")]
+pub use lexical_string::{LexicalStr, LexicalString};
+#[cfg(feature = "alloc")]
+pub use lines::{sort_lines, sort_lines_in_place, sort_unique_lines, sort_unique_lines_in_place};
+#[cfg(feature = "alloc")]
+pub use merge::{kmerge_sorted, KMergeSorted};
+pub use merge::{merge_sorted, MergeSorted};
+pub use minmax::{lexical_max, lexical_min, lexical_min_max};
+pub use option::{empty_last, none_first, none_last, OptionStringSort};
+pub use ord::LexicalOrd;
+#[cfg(all(feature = "rayon", feature = "std"))]
+pub use par::{ParPathSort, ParStringSort};
+#[cfg(feature = "alloc")]
+pub use rank::rank_strings;
+pub use script::Script;
+#[cfg(feature = "alloc")]
+pub use search::{autocomplete, prefix_successor};
+pub use search::{
+    lexical_contains, lexical_find, lexical_starts_with, lexical_strip_prefix, lexically_between,
+};
+#[cfg(feature = "alloc")]
+pub use sort_key::{collation_key, prefix_key, write_collation_key, SortKey, SortKeyError};
+#[cfg(feature = "alloc")]
+pub use sorted::LexicalSortedIterator;
+#[cfg(feature = "std")]
+pub use sorted::LexicalSortedPathIterator;
+pub use tailoring::Tailoring;
+#[cfg(feature = "alloc")]
+pub use verify::{
+    sortedness, verify_sorted, verify_sorted_exhaustive, SortViolation, Sortedness, ViolationKind,
 };
+pub use version::{gnu_version_cmp, version_cmp, version_lexical_cmp};
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use core::cmp::Ordering;
+use core::ops::Range;
 #[cfg(feature = "std")]
 use std::path::Path;
 
+/// The version of the order that is guaranteed by the `stable` feature
+///
+/// With the `stable` feature, the order produced by the comparison functions, `SortKey` and
+/// the `Collator` only changes in semver-major releases of this crate, and this number is
+/// incremented when it does. Store it along with sorted data or collation keys to detect
+/// whether they were produced with a different order, e.g. by a client with another version.
+#[cfg(feature = "stable")]
+pub const STABLE_ORDER_VERSION: u32 = 1;
+
+/// Sorts strings with `lexical_cmp`.
+///
+/// This is a shortcut for `slice.string_sort_unstable(lexical_cmp)`; use the `StringSort`
+/// trait to choose another comparison function.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// let mut strings = vec!["ß", "é", "100", "hello", "world", "50", ".", "B!"];
+/// lexical_sort::sort(&mut strings);
+///
+/// assert_eq!(&strings, &[".", "100", "50", "B!", "é", "hello", "ß", "world"]);
+/// # }
+/// ```
+pub fn sort<A: AsRef<str>>(slice: &mut [A]) {
+    slice.string_sort_unstable(lexical_cmp);
+}
+
+/// Sorts strings with `natural_lexical_cmp`, so numbers are ordered by their value.
+///
+/// This is a shortcut for `slice.string_sort_unstable(natural_lexical_cmp)`.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// let mut strings = vec!["ß", "é", "100", "hello", "world", "50", ".", "B!"];
+/// lexical_sort::sort_natural(&mut strings);
+///
+/// assert_eq!(&strings, &[".", "50", "100", "B!", "é", "hello", "ß", "world"]);
+/// # }
+/// ```
+pub fn sort_natural<A: AsRef<str>>(slice: &mut [A]) {
+    slice.string_sort_unstable(natural_lexical_cmp);
+}
+
+/// Sorts paths with `lexical_cmp`.
+///
+/// This is a shortcut for `slice.path_sort_unstable(lexical_cmp)`; use the `PathSort` trait
+/// to choose another comparison function.
+///
+/// ## Example
+///
+/// ```rust
+/// use std::path::PathBuf;
+///
+/// let mut paths: Vec<PathBuf> = vec!["img/10.png".into(), "Img/3.png".into(), "a.txt".into()];
+/// lexical_sort::sort_paths(&mut paths);
+///
+/// assert_eq!(paths, [PathBuf::from("a.txt"), "img/10.png".into(), "Img/3.png".into()]);
+/// ```
+#[cfg(feature = "std")]
+pub fn sort_paths<A: AsRef<Path>>(slice: &mut [A]) {
+    slice.path_sort_unstable(lexical_cmp);
+}
+
+/// Sorts paths with `natural_lexical_cmp`, so numbers are ordered by their value.
+///
+/// This is a shortcut for `slice.path_sort_unstable(natural_lexical_cmp)`.
+///
+/// ## Example
+///
+/// ```rust
+/// use std::path::PathBuf;
+///
+/// let mut paths: Vec<PathBuf> = vec!["img/10.png".into(), "Img/3.png".into(), "a.txt".into()];
+/// lexical_sort::sort_paths_natural(&mut paths);
+///
+/// assert_eq!(paths, [PathBuf::from("a.txt"), "Img/3.png".into(), "img/10.png".into()]);
+/// ```
+#[cfg(feature = "std")]
+pub fn sort_paths_natural<A: AsRef<Path>>(slice: &mut [A]) {
+    slice.path_sort_unstable(natural_lexical_cmp);
+}
+
 /// A trait to sort strings. This is a convenient wrapper for the standard library sort functions.
 ///
-/// This trait is implemented for all slices whose inner type implements `AsRef<str>`.
+/// This trait is implemented for all slices whose inner type implements `AsRef<str>`, and for
+/// `VecDeque`s, which are made contiguous before sorting.
 ///
 /// ## Example
 ///
@@ -94,6 +399,7 @@ pub trait StringSort {
     ///
     /// assert_eq!(slice, &["amet", "dolor", "ipsum", "Lorem", "sit"]);
     /// ```
+    #[cfg(feature = "alloc")]
     fn string_sort(&mut self, cmp: impl FnMut(&str, &str) -> Ordering);
 
     /// Sorts the items using the provided comparison function.
@@ -132,6 +438,7 @@ pub trait StringSort {
     ///
     /// assert_eq!(slice, &["Eeny", " meeny", " miny", " moe"]);
     /// ```
+    #[cfg(feature = "alloc")]
     fn string_sort_by<Cmp, Map>(&mut self, cmp: Cmp, map: Map)
     where
         Cmp: FnMut(&str, &str) -> Ordering,
@@ -160,9 +467,254 @@ pub trait StringSort {
     where
         Cmp: FnMut(&str, &str) -> Ordering,
         Map: FnMut(&str) -> &str;
+
+    /// Sorts the items using the provided comparison function and a key function that is
+    /// applied to each string once. Unlike `string_sort_by`, the key function can return an
+    /// owned string, e.g. a `String`.
+    ///
+    /// This is like `[_]::sort_by_cached_key()`: The keys are computed once per item and
+    /// stored in a temporary `Vec`, then the comparison function is applied to the keys.
+    ///
+    /// **This is a stable sort, which is often not required**.
+    /// You can use `string_sort_unstable_by_cached_key` instead.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::StringSort;
+    ///
+    /// let slice = &mut ["The Who", "Queen", "the Beatles"];
+    /// slice.string_sort_by_cached_key(lexical_sort::lexical_cmp, |s| {
+    ///     let s = s.to_lowercase();
+    ///     s.strip_prefix("the ").map(String::from).unwrap_or(s)
+    /// });
+    ///
+    /// assert_eq!(slice, &["the Beatles", "Queen", "The Who"]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn string_sort_by_cached_key<K, Cmp, Key>(&mut self, cmp: Cmp, key: Key)
+    where
+        K: AsRef<str>,
+        Cmp: FnMut(&str, &str) -> Ordering,
+        Key: FnMut(&str) -> K;
+
+    /// Sorts the items using the provided comparison function and a key function that is
+    /// applied to each string once, like `string_sort_by_cached_key`.
+    ///
+    /// This sort is unstable: The original order of equal strings is not preserved.
+    /// It is slightly more efficient than the stable alternative.
+    #[cfg(feature = "alloc")]
+    fn string_sort_unstable_by_cached_key<K, Cmp, Key>(&mut self, cmp: Cmp, key: Key)
+    where
+        K: AsRef<str>,
+        Cmp: FnMut(&str, &str) -> Ordering,
+        Key: FnMut(&str) -> K;
+
+    /// Sorts the items with `lexical_cmp`, like `string_sort_unstable(lexical_cmp)`, but faster
+    /// for large slices.
+    ///
+    /// The `prefix_key` of each string is computed once, so most comparisons only compare two
+    /// numbers; strings are only compared with `lexical_cmp` if their prefix keys are equal.
+    /// This allocates a `Vec` of one key and index per item.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::StringSort;
+    ///
+    /// let slice = &mut ["Lorem", "ipsum", "dolor", "sit", "amet"];
+    /// slice.string_sort_fast();
+    ///
+    /// assert_eq!(slice, &["amet", "dolor", "ipsum", "Lorem", "sit"]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn string_sort_fast(&mut self) {
+        self.string_sort_unstable(lexical_cmp);
+    }
+
+    /// Sorts the items using the provided comparator, which can be a `&dyn Compare`.
+    ///
+    /// **This is a stable sort, which is often not required**.
+    /// You can use `string_sort_unstable_with` instead.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::{Compare, NaturalLexical, StringSort};
+    ///
+    /// let comparator: &dyn Compare = &NaturalLexical;
+    /// let slice = &mut ["Lorem", "ipsum", "dolor", "sit", "amet"];
+    /// slice.string_sort_with(comparator);
+    ///
+    /// assert_eq!(slice, &["amet", "dolor", "ipsum", "Lorem", "sit"]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn string_sort_with<C: Compare + ?Sized>(&mut self, cmp: &C) {
+        self.string_sort(|lhs, rhs| cmp.compare(lhs, rhs));
+    }
+
+    /// Sorts the items using the provided comparator, which can be a `&dyn Compare`.
+    ///
+    /// This sort is unstable: The original order of equal strings is not preserved.
+    /// It is slightly more efficient than the stable alternative.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::{Compare, NaturalLexical, StringSort};
+    ///
+    /// let comparator: &dyn Compare = &NaturalLexical;
+    /// let slice = &mut ["The", "quick", "brown", "fox"];
+    /// slice.string_sort_unstable_with(comparator);
+    ///
+    /// assert_eq!(slice, &["brown", "fox", "quick", "The"]);
+    /// ```
+    fn string_sort_unstable_with<C: Compare + ?Sized>(&mut self, cmp: &C) {
+        self.string_sort_unstable(|lhs, rhs| cmp.compare(lhs, rhs));
+    }
+
+    /// Sorts the items with the given mode, but keeps strings that are equal after folding
+    /// (e.g. `"Foo"` and `"fóò"`) in their original order.
+    ///
+    /// This is a stable sort with a `Collator` using `TieBreak::InputOrder`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "unicode")] {
+    /// use lexical_sort::{SortMode, StringSort};
+    ///
+    /// let slice = &mut ["fóò", "T-20", "Foo", "T-5", "FOO"];
+    /// slice.string_sort_preserving(SortMode::NaturalLexical);
+    ///
+    /// assert_eq!(slice, &["fóò", "Foo", "FOO", "T-5", "T-20"]);
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn string_sort_preserving(&mut self, mode: SortMode) {
+        let collator = Collator::from(mode).ties(TieBreak::InputOrder);
+        self.string_sort(|lhs, rhs| collator.cmp(lhs, rhs));
+    }
+
+    /// Returns `true` if the items are sorted with the provided comparison function, i.e. no
+    /// item is greater than the next one. This stops at the first item that is out of order.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::StringSort;
+    ///
+    /// assert!(["T-5", "t-5", "T-20"].string_is_sorted(lexical_sort::natural_lexical_cmp));
+    /// assert!(!["T-5", "T-20", "t-5"].string_is_sorted(lexical_sort::natural_lexical_cmp));
+    /// ```
+    fn string_is_sorted(&self, cmp: impl FnMut(&str, &str) -> Ordering) -> bool {
+        self.string_first_unsorted(cmp).is_none()
+    }
+
+    /// Returns `true` if the items are sorted with the provided comparison function and
+    /// another function that is applied to each string before the comparison, like
+    /// `string_sort_by` sorts them.
+    fn string_is_sorted_by<Cmp, Map>(&self, cmp: Cmp, map: Map) -> bool
+    where
+        Cmp: FnMut(&str, &str) -> Ordering,
+        Map: FnMut(&str) -> &str;
+
+    /// Returns the index of the first item that is less than the previous item according to
+    /// the provided comparison function, or `None` if the items are sorted.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::StringSort;
+    ///
+    /// let slice = ["T-5", "T-20", "t-5", "T-100"];
+    /// assert_eq!(slice.string_first_unsorted(lexical_sort::natural_lexical_cmp), Some(2));
+    /// ```
+    fn string_first_unsorted(&self, cmp: impl FnMut(&str, &str) -> Ordering) -> Option<usize>;
+
+    /// Returns the permutation that sorts the items with the provided comparison function,
+    /// without reordering them: `indices[k]` is the index of the `k`-th smallest item.
+    ///
+    /// The strings aren't cloned; the indices are sorted by comparing the items they point to.
+    ///
+    /// **This is a stable sort, which is often not required**.
+    /// You can use `string_argsort_unstable` instead.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "unicode")] {
+    /// use lexical_sort::StringSort;
+    ///
+    /// let names = ["T-20", "t-5", "Äpfel", "T-100"];
+    /// let indices = names.string_argsort(lexical_sort::natural_lexical_cmp);
+    ///
+    /// assert_eq!(indices, [2, 1, 0, 3]);
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn string_argsort(&self, cmp: impl FnMut(&str, &str) -> Ordering) -> Vec<usize>;
+
+    /// Returns the permutation that sorts the items with the provided comparison function,
+    /// like `string_argsort`.
+    ///
+    /// This sort is unstable: The original order of equal strings is not preserved.
+    /// It is slightly more efficient than the stable alternative.
+    #[cfg(feature = "alloc")]
+    fn string_argsort_unstable(&self, cmp: impl FnMut(&str, &str) -> Ordering) -> Vec<usize> {
+        self.string_argsort(cmp)
+    }
+
+    /// Reorders the items with the provided comparison function, so that the item at index `n`
+    /// is the one that would be there if the slice was sorted. All items before it are less
+    /// than or equal to it, and all items after it are greater than or equal to it.
+    ///
+    /// This is much faster than sorting the whole slice, if you only need the `n` least items
+    /// and don't care about their order. It is unstable: The original order of equal strings
+    /// is not preserved.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `n >= self.len()`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::StringSort;
+    ///
+    /// let slice = &mut ["T-20", "b", "T-5", "a", "T-100"];
+    /// slice.string_select_nth_unstable(1, lexical_sort::natural_lexical_cmp);
+    ///
+    /// assert_eq!(slice[1], "b");
+    /// assert!(slice[2..].contains(&"T-5"));
+    /// ```
+    fn string_select_nth_unstable(&mut self, n: usize, cmp: impl FnMut(&str, &str) -> Ordering);
+
+    /// Returns the `k` least strings, sorted with the provided comparison function, without
+    /// reordering the items. If there are fewer than `k` items, all of them are returned.
+    ///
+    /// This keeps at most `k` strings in a heap, so it takes O(n log k) comparisons, which is
+    /// faster than sorting all items if `k` is small. The result is the same as the first `k`
+    /// items after a stable sort: Equal strings are returned in their original order.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "unicode")] {
+    /// use lexical_sort::StringSort;
+    ///
+    /// let names = ["T-20", "t-5", "Äpfel", "T-100", "apfel"];
+    /// let smallest = names.string_smallest_k(3, lexical_sort::natural_lexical_cmp);
+    ///
+    /// assert_eq!(smallest, ["apfel", "Äpfel", "t-5"]);
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn string_smallest_k(&self, k: usize, cmp: impl FnMut(&str, &str) -> Ordering) -> Vec<&str>;
 }
 
 impl<A: AsRef<str>> StringSort for [A] {
+    #[cfg(feature = "alloc")]
     fn string_sort(&mut self, mut cmp: impl FnMut(&str, &str) -> Ordering) {
         self.sort_by(|lhs, rhs| cmp(lhs.as_ref(), rhs.as_ref()));
     }
@@ -171,6 +723,7 @@ impl<A: AsRef<str>> StringSort for [A] {
         self.sort_unstable_by(|lhs, rhs| cmp(lhs.as_ref(), rhs.as_ref()));
     }
 
+    #[cfg(feature = "alloc")]
     fn string_sort_by<Cmp, Map>(&mut self, mut cmp: Cmp, mut map: Map)
     where
         Cmp: FnMut(&str, &str) -> Ordering,
@@ -186,12 +739,99 @@ impl<A: AsRef<str>> StringSort for [A] {
     {
         self.sort_unstable_by(|lhs, rhs| cmp(map(lhs.as_ref()), map(rhs.as_ref())));
     }
+
+    #[cfg(feature = "alloc")]
+    fn string_sort_by_cached_key<K, Cmp, Key>(&mut self, cmp: Cmp, mut key: Key)
+    where
+        K: AsRef<str>,
+        Cmp: FnMut(&str, &str) -> Ordering,
+        Key: FnMut(&str) -> K,
+    {
+        sort_by_cached_key(self, |s| key(s.as_ref()), cmp, true);
+    }
+
+    #[cfg(feature = "alloc")]
+    fn string_sort_unstable_by_cached_key<K, Cmp, Key>(&mut self, cmp: Cmp, mut key: Key)
+    where
+        K: AsRef<str>,
+        Cmp: FnMut(&str, &str) -> Ordering,
+        Key: FnMut(&str) -> K,
+    {
+        sort_by_cached_key(self, |s| key(s.as_ref()), cmp, false);
+    }
+
+    #[cfg(feature = "alloc")]
+    fn string_sort_fast(&mut self) {
+        let mut indices: Vec<(u64, usize)> = self
+            .iter()
+            .map(|s| prefix_key(s.as_ref()))
+            .zip(0..)
+            .collect();
+        indices.sort_unstable_by(|&(lhs_key, lhs), &(rhs_key, rhs)| {
+            lhs_key
+                .cmp(&rhs_key)
+                .then_with(|| lexical_cmp(self[lhs].as_ref(), self[rhs].as_ref()))
+        });
+        apply_permutation(self, &mut indices);
+    }
+
+    fn string_is_sorted_by<Cmp, Map>(&self, mut cmp: Cmp, mut map: Map) -> bool
+    where
+        Cmp: FnMut(&str, &str) -> Ordering,
+        Map: FnMut(&str) -> &str,
+    {
+        self.windows(2)
+            .all(|w| cmp(map(w[0].as_ref()), map(w[1].as_ref())) != Ordering::Greater)
+    }
+
+    fn string_first_unsorted(&self, mut cmp: impl FnMut(&str, &str) -> Ordering) -> Option<usize> {
+        self.windows(2)
+            .position(|w| cmp(w[0].as_ref(), w[1].as_ref()) == Ordering::Greater)
+            .map(|i| i + 1)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn string_argsort(&self, mut cmp: impl FnMut(&str, &str) -> Ordering) -> Vec<usize> {
+        let mut indices: Vec<usize> = (0..self.len()).collect();
+        indices.sort_by(|&i, &j| cmp(self[i].as_ref(), self[j].as_ref()));
+        indices
+    }
+
+    #[cfg(feature = "alloc")]
+    fn string_argsort_unstable(&self, mut cmp: impl FnMut(&str, &str) -> Ordering) -> Vec<usize> {
+        let mut indices: Vec<usize> = (0..self.len()).collect();
+        indices.sort_unstable_by(|&i, &j| cmp(self[i].as_ref(), self[j].as_ref()));
+        indices
+    }
+
+    fn string_select_nth_unstable(
+        &mut self,
+        n: usize,
+        mut cmp: impl FnMut(&str, &str) -> Ordering,
+    ) {
+        self.select_nth_unstable_by(n, |lhs, rhs| cmp(lhs.as_ref(), rhs.as_ref()));
+    }
+
+    #[cfg(feature = "alloc")]
+    fn string_smallest_k(
+        &self,
+        k: usize,
+        mut cmp: impl FnMut(&str, &str) -> Ordering,
+    ) -> Vec<&str> {
+        smallest_k(self.len(), k, |i, j| {
+            cmp(self[i].as_ref(), self[j].as_ref())
+        })
+        .into_iter()
+        .map(|i| self[i].as_ref())
+        .collect()
+    }
 }
 
 /// A trait to sort paths and OsStrings. This is a convenient wrapper for the standard library
 /// sort functions.
 ///
-/// This trait is implemented for all slices whose inner type implements `AsRef<Path>`.
+/// This trait is implemented for all slices whose inner type implements `AsRef<Path>`, and for
+/// `VecDeque`s.
 ///
 /// ## Example
 ///
@@ -300,22 +940,215 @@ pub trait PathSort {
     where
         Cmp: FnMut(&str, &str) -> Ordering,
         Map: FnMut(&str) -> &str;
-}
 
-#[cfg(feature = "std")]
-impl<A: AsRef<Path>> PathSort for [A] {
-    fn path_sort(&mut self, mut cmp: impl FnMut(&str, &str) -> Ordering) {
-        self.sort_by(|lhs, rhs| {
-            cmp(
-                &lhs.as_ref().to_string_lossy(),
-                &rhs.as_ref().to_string_lossy(),
-            )
-        });
-    }
+    /// Sorts the items using the provided comparison function and a key function that is
+    /// applied to each path once, after converting it to a string with `to_string_lossy()`.
+    /// The key function can return an owned string, e.g. a `String`.
+    ///
+    /// This is like `StringSort::string_sort_by_cached_key`.
+    ///
+    /// **This is a stable sort, which is often not required**.
+    /// You can use `path_sort_unstable_by_cached_key` instead.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "unicode")] {
+    /// # use std::path::Path;
+    /// # fn paths<'a>(s: &'a[&'a str]) -> Vec<&'a Path> { s.iter().map(Path::new).collect() }
+    /// use lexical_sort::PathSort;
+    ///
+    /// let mut vec: Vec<&Path> = paths(&["b/Zebra.txt", "a/Äpfel.rs", "c/apple.md"]);
+    /// vec.path_sort_by_cached_key(lexical_sort::lexical_cmp, |s| {
+    ///     s.rsplit('/').next().unwrap_or(s).to_lowercase()
+    /// });
+    ///
+    /// assert_eq!(vec, paths(&["a/Äpfel.rs", "c/apple.md", "b/Zebra.txt"]));
+    /// # }
+    /// ```
+    fn path_sort_by_cached_key<K, Cmp, Key>(&mut self, cmp: Cmp, key: Key)
+    where
+        K: AsRef<str>,
+        Cmp: FnMut(&str, &str) -> Ordering,
+        Key: FnMut(&str) -> K;
 
-    fn path_sort_unstable(&mut self, mut cmp: impl FnMut(&str, &str) -> Ordering) {
-        self.sort_unstable_by(|lhs, rhs| {
-            cmp(
+    /// Sorts the items using the provided comparison function and a key function that is
+    /// applied to each path once, like `path_sort_by_cached_key`.
+    ///
+    /// This sort is unstable: The original order of equal strings is not preserved.
+    /// It is slightly more efficient than the stable alternative.
+    fn path_sort_unstable_by_cached_key<K, Cmp, Key>(&mut self, cmp: Cmp, key: Key)
+    where
+        K: AsRef<str>,
+        Cmp: FnMut(&str, &str) -> Ordering,
+        Key: FnMut(&str) -> K;
+
+    /// Sorts the items using the provided comparator, which can be a `&dyn Compare`.
+    ///
+    /// **This is a stable sort, which is often not required**.
+    /// You can use `path_sort_unstable_with` instead.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use std::path::Path;
+    /// # fn paths<'a>(s: &'a[&'a str]) -> Vec<&'a Path> { s.iter().map(Path::new).collect() }
+    /// use lexical_sort::{Compare, NaturalLexical, PathSort};
+    ///
+    /// let comparator: &dyn Compare = &NaturalLexical;
+    /// let mut vec: Vec<&Path> = paths(&["Lorem", "ipsum", "dolor", "sit", "amet"]);
+    /// vec.path_sort_with(comparator);
+    ///
+    /// assert_eq!(vec, paths(&["amet", "dolor", "ipsum", "Lorem", "sit"]));
+    /// ```
+    fn path_sort_with<C: Compare + ?Sized>(&mut self, cmp: &C) {
+        self.path_sort(|lhs, rhs| cmp.compare(lhs, rhs));
+    }
+
+    /// Sorts the items using the provided comparator, which can be a `&dyn Compare`.
+    ///
+    /// This sort is unstable: The original order of equal strings is not preserved.
+    /// It is slightly more efficient than the stable alternative.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use std::path::Path;
+    /// # fn paths<'a>(s: &'a[&'a str]) -> Vec<&'a Path> { s.iter().map(Path::new).collect() }
+    /// use lexical_sort::{Compare, NaturalLexical, PathSort};
+    ///
+    /// let comparator: &dyn Compare = &NaturalLexical;
+    /// let mut vec: Vec<&Path> = paths(&["The", "quick", "brown", "fox"]);
+    /// vec.path_sort_unstable_with(comparator);
+    ///
+    /// assert_eq!(vec, paths(&["brown", "fox", "quick", "The"]));
+    /// ```
+    fn path_sort_unstable_with<C: Compare + ?Sized>(&mut self, cmp: &C) {
+        self.path_sort_unstable(|lhs, rhs| cmp.compare(lhs, rhs));
+    }
+
+    /// Returns `true` if the items are sorted with the provided comparison function, like
+    /// `path_sort` sorts them. This stops at the first item that is out of order.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use std::path::Path;
+    /// # fn paths<'a>(s: &'a[&'a str]) -> Vec<&'a Path> { s.iter().map(Path::new).collect() }
+    /// use lexical_sort::PathSort;
+    ///
+    /// let vec: Vec<&Path> = paths(&["img/2.png", "img/10.png", "Img/3.png"]);
+    /// assert!(!vec.path_is_sorted(lexical_sort::natural_lexical_cmp));
+    /// ```
+    fn path_is_sorted(&self, cmp: impl FnMut(&str, &str) -> Ordering) -> bool {
+        self.path_first_unsorted(cmp).is_none()
+    }
+
+    /// Returns `true` if the items are sorted with the provided comparison function and
+    /// another function that is applied to each string before the comparison, like
+    /// `path_sort_by` sorts them.
+    fn path_is_sorted_by<Cmp, Map>(&self, cmp: Cmp, map: Map) -> bool
+    where
+        Cmp: FnMut(&str, &str) -> Ordering,
+        Map: FnMut(&str) -> &str;
+
+    /// Returns the index of the first item that is less than the previous item according to
+    /// the provided comparison function, or `None` if the items are sorted.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use std::path::Path;
+    /// # fn paths<'a>(s: &'a[&'a str]) -> Vec<&'a Path> { s.iter().map(Path::new).collect() }
+    /// use lexical_sort::PathSort;
+    ///
+    /// let vec: Vec<&Path> = paths(&["img/2.png", "img/10.png", "Img/3.png"]);
+    /// assert_eq!(vec.path_first_unsorted(lexical_sort::natural_lexical_cmp), Some(2));
+    /// ```
+    fn path_first_unsorted(&self, cmp: impl FnMut(&str, &str) -> Ordering) -> Option<usize>;
+
+    /// Returns the permutation that sorts the items with the provided comparison function,
+    /// without reordering them: `indices[k]` is the index of the `k`-th smallest item.
+    ///
+    /// This is like `StringSort::string_argsort`. The paths are converted with
+    /// `to_string_lossy()` in each comparison.
+    ///
+    /// **This is a stable sort, which is often not required**.
+    /// You can use `path_argsort_unstable` instead.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use std::path::Path;
+    /// # fn paths<'a>(s: &'a[&'a str]) -> Vec<&'a Path> { s.iter().map(Path::new).collect() }
+    /// use lexical_sort::PathSort;
+    ///
+    /// let vec: Vec<&Path> = paths(&["img/10.png", "Img/3.png", "img/2.png"]);
+    /// assert_eq!(vec.path_argsort(lexical_sort::natural_lexical_cmp), [2, 1, 0]);
+    /// ```
+    fn path_argsort(&self, cmp: impl FnMut(&str, &str) -> Ordering) -> Vec<usize>;
+
+    /// Returns the permutation that sorts the items with the provided comparison function,
+    /// like `path_argsort`.
+    ///
+    /// This sort is unstable: The original order of equal strings is not preserved.
+    /// It is slightly more efficient than the stable alternative.
+    fn path_argsort_unstable(&self, cmp: impl FnMut(&str, &str) -> Ordering) -> Vec<usize> {
+        self.path_argsort(cmp)
+    }
+
+    /// Reorders the items with the provided comparison function, so that the item at index `n`
+    /// is the one that would be there if the slice was sorted, like
+    /// `StringSort::string_select_nth_unstable`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `n >= self.len()`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use std::path::Path;
+    /// # fn paths<'a>(s: &'a[&'a str]) -> Vec<&'a Path> { s.iter().map(Path::new).collect() }
+    /// use lexical_sort::PathSort;
+    ///
+    /// let mut vec: Vec<&Path> = paths(&["img/10.png", "Img/3.png", "img/2.png"]);
+    /// vec.path_select_nth_unstable(0, lexical_sort::natural_lexical_cmp);
+    /// assert_eq!(vec[0], Path::new("img/2.png"));
+    /// ```
+    fn path_select_nth_unstable(&mut self, n: usize, cmp: impl FnMut(&str, &str) -> Ordering);
+
+    /// Returns the `k` least paths, sorted with the provided comparison function, without
+    /// reordering the items, like `StringSort::string_smallest_k`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use std::path::Path;
+    /// # fn paths<'a>(s: &'a[&'a str]) -> Vec<&'a Path> { s.iter().map(Path::new).collect() }
+    /// use lexical_sort::PathSort;
+    ///
+    /// let vec: Vec<&Path> = paths(&["img/10.png", "Img/3.png", "img/2.png"]);
+    /// let smallest = vec.path_smallest_k(2, lexical_sort::natural_lexical_cmp);
+    /// assert_eq!(smallest, paths(&["img/2.png", "Img/3.png"]));
+    /// ```
+    fn path_smallest_k(&self, k: usize, cmp: impl FnMut(&str, &str) -> Ordering) -> Vec<&Path>;
+}
+
+#[cfg(feature = "std")]
+impl<A: AsRef<Path>> PathSort for [A] {
+    fn path_sort(&mut self, mut cmp: impl FnMut(&str, &str) -> Ordering) {
+        self.sort_by(|lhs, rhs| {
+            cmp(
+                &lhs.as_ref().to_string_lossy(),
+                &rhs.as_ref().to_string_lossy(),
+            )
+        });
+    }
+
+    fn path_sort_unstable(&mut self, mut cmp: impl FnMut(&str, &str) -> Ordering) {
+        self.sort_unstable_by(|lhs, rhs| {
+            cmp(
                 &lhs.as_ref().to_string_lossy(),
                 &rhs.as_ref().to_string_lossy(),
             )
@@ -347,18 +1180,537 @@ impl<A: AsRef<Path>> PathSort for [A] {
             )
         });
     }
+
+    fn path_sort_by_cached_key<K, Cmp, Key>(&mut self, cmp: Cmp, mut key: Key)
+    where
+        K: AsRef<str>,
+        Cmp: FnMut(&str, &str) -> Ordering,
+        Key: FnMut(&str) -> K,
+    {
+        sort_by_cached_key(self, |p| key(&p.as_ref().to_string_lossy()), cmp, true);
+    }
+
+    fn path_sort_unstable_by_cached_key<K, Cmp, Key>(&mut self, cmp: Cmp, mut key: Key)
+    where
+        K: AsRef<str>,
+        Cmp: FnMut(&str, &str) -> Ordering,
+        Key: FnMut(&str) -> K,
+    {
+        sort_by_cached_key(self, |p| key(&p.as_ref().to_string_lossy()), cmp, false);
+    }
+
+    fn path_is_sorted_by<Cmp, Map>(&self, mut cmp: Cmp, mut map: Map) -> bool
+    where
+        Cmp: FnMut(&str, &str) -> Ordering,
+        Map: FnMut(&str) -> &str,
+    {
+        self.windows(2).all(|w| {
+            cmp(
+                map(&w[0].as_ref().to_string_lossy()),
+                map(&w[1].as_ref().to_string_lossy()),
+            ) != Ordering::Greater
+        })
+    }
+
+    fn path_first_unsorted(&self, mut cmp: impl FnMut(&str, &str) -> Ordering) -> Option<usize> {
+        self.windows(2)
+            .position(|w| {
+                cmp(
+                    &w[0].as_ref().to_string_lossy(),
+                    &w[1].as_ref().to_string_lossy(),
+                ) == Ordering::Greater
+            })
+            .map(|i| i + 1)
+    }
+
+    fn path_argsort(&self, mut cmp: impl FnMut(&str, &str) -> Ordering) -> Vec<usize> {
+        let mut indices: Vec<usize> = (0..self.len()).collect();
+        indices.sort_by(|&i, &j| {
+            cmp(
+                &self[i].as_ref().to_string_lossy(),
+                &self[j].as_ref().to_string_lossy(),
+            )
+        });
+        indices
+    }
+
+    fn path_argsort_unstable(&self, mut cmp: impl FnMut(&str, &str) -> Ordering) -> Vec<usize> {
+        let mut indices: Vec<usize> = (0..self.len()).collect();
+        indices.sort_unstable_by(|&i, &j| {
+            cmp(
+                &self[i].as_ref().to_string_lossy(),
+                &self[j].as_ref().to_string_lossy(),
+            )
+        });
+        indices
+    }
+
+    fn path_select_nth_unstable(&mut self, n: usize, mut cmp: impl FnMut(&str, &str) -> Ordering) {
+        self.select_nth_unstable_by(n, |lhs, rhs| {
+            cmp(
+                &lhs.as_ref().to_string_lossy(),
+                &rhs.as_ref().to_string_lossy(),
+            )
+        });
+    }
+
+    fn path_smallest_k(&self, k: usize, mut cmp: impl FnMut(&str, &str) -> Ordering) -> Vec<&Path> {
+        smallest_k(self.len(), k, |i, j| {
+            cmp(
+                &self[i].as_ref().to_string_lossy(),
+                &self[j].as_ref().to_string_lossy(),
+            )
+        })
+        .into_iter()
+        .map(|i| self[i].as_ref())
+        .collect()
+    }
+}
+
+/// Returns the indices of the `k` least of `len` items in sorted order, which are the first `k`
+/// indices of a stable sort, using a max-heap of at most `k` indices. The items are compared by
+/// their indices.
+#[cfg(feature = "alloc")]
+pub(crate) fn smallest_k(
+    len: usize,
+    k: usize,
+    mut cmp: impl FnMut(usize, usize) -> Ordering,
+) -> Vec<usize> {
+    // equal items are ordered by their index, like in a stable sort
+    let mut cmp = |i: usize, j: usize| cmp(i, j).then(i.cmp(&j));
+    let mut heap: Vec<usize> = Vec::with_capacity(k.min(len));
+    if k == 0 {
+        return heap;
+    }
+
+    for i in 0..len {
+        let mut pos = if heap.len() < k {
+            heap.push(i);
+            heap.len() - 1
+        } else if cmp(i, heap[0]) == Ordering::Less {
+            heap[0] = i;
+            0
+        } else {
+            continue;
+        };
+
+        // sift up a new item, or sift down the replaced root
+        while pos > 0 && cmp(heap[(pos - 1) / 2], heap[pos]) == Ordering::Less {
+            heap.swap(pos, (pos - 1) / 2);
+            pos = (pos - 1) / 2;
+        }
+        loop {
+            let mut greatest = pos;
+            for child in (2 * pos + 1..heap.len()).take(2) {
+                if cmp(heap[greatest], heap[child]) == Ordering::Less {
+                    greatest = child;
+                }
+            }
+            if greatest == pos {
+                break;
+            }
+            heap.swap(pos, greatest);
+            pos = greatest;
+        }
+    }
+
+    heap.sort_unstable_by(|&i, &j| cmp(i, j));
+    heap
+}
+
+/// Sorts a slice by keys that are computed once per item, like `[_]::sort_by_cached_key()`,
+/// and compared with the given comparison function
+#[cfg(feature = "alloc")]
+fn sort_by_cached_key<T, K: AsRef<str>>(
+    slice: &mut [T],
+    key: impl FnMut(&T) -> K,
+    mut cmp: impl FnMut(&str, &str) -> Ordering,
+    stable: bool,
+) {
+    let mut indices: Vec<(K, usize)> = slice.iter().map(key).zip(0..).collect();
+    let mut cmp = |lhs: &(K, usize), rhs: &(K, usize)| cmp(lhs.0.as_ref(), rhs.0.as_ref());
+    if stable {
+        indices.sort_by(&mut cmp);
+    } else {
+        indices.sort_unstable_by(&mut cmp);
+    }
+    apply_permutation(slice, &mut indices);
+}
+
+/// Moves every item of a slice to the position of its index in the sorted `indices`
+#[cfg(feature = "alloc")]
+pub(crate) fn apply_permutation<T, K>(slice: &mut [T], indices: &mut [(K, usize)]) {
+    // items before `i` were already swapped away, so their new position is followed
+    for i in 0..slice.len() {
+        let mut index = indices[i].1;
+        while index < i {
+            index = indices[index].1;
+        }
+        indices[i].1 = index;
+        slice.swap(i, index);
+    }
+}
+
+/// A trait to sort entries that consist of a display string and an optional reading, such as
+/// Japanese names with their kana reading. See `cmp_by_reading` for how entries are compared.
+///
+/// This trait is implemented for all slices of `(display, Option<reading>)` pairs whose
+/// components implement `AsRef<str>`.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::{ReadingSort, lexical_cmp};
+///
+/// let slice = &mut [("田中", Some("たなか")), ("Smith", None), ("佐藤", Some("さとう"))];
+/// slice.sort_by_reading(lexical_cmp);
+///
+/// assert_eq!(slice, &[("佐藤", Some("さとう")), ("Smith", None), ("田中", Some("たなか"))]);
+/// # }
+/// ```
+pub trait ReadingSort {
+    /// Sorts the items by their reading using the provided comparison function.
+    ///
+    /// **This is a stable sort, which is often not required**.
+    /// You can use `sort_unstable_by_reading` instead.
+    #[cfg(feature = "alloc")]
+    fn sort_by_reading(&mut self, cmp: impl FnMut(&str, &str) -> Ordering);
+
+    /// Sorts the items by their reading using the provided comparison function.
+    ///
+    /// This sort is unstable: The original order of equal items is not preserved.
+    /// It is slightly more efficient than the stable alternative.
+    fn sort_unstable_by_reading(&mut self, cmp: impl FnMut(&str, &str) -> Ordering);
+}
+
+impl<D: AsRef<str>, R: AsRef<str>> ReadingSort for [(D, Option<R>)] {
+    #[cfg(feature = "alloc")]
+    fn sort_by_reading(&mut self, mut cmp: impl FnMut(&str, &str) -> Ordering) {
+        self.sort_by(|(d1, r1), (d2, r2)| {
+            cmp_by_reading(
+                (d1.as_ref(), r1.as_ref().map(AsRef::as_ref)),
+                (d2.as_ref(), r2.as_ref().map(AsRef::as_ref)),
+                &mut cmp,
+            )
+        });
+    }
+
+    fn sort_unstable_by_reading(&mut self, mut cmp: impl FnMut(&str, &str) -> Ordering) {
+        self.sort_unstable_by(|(d1, r1), (d2, r2)| {
+            cmp_by_reading(
+                (d1.as_ref(), r1.as_ref().map(AsRef::as_ref)),
+                (d2.as_ref(), r2.as_ref().map(AsRef::as_ref)),
+                &mut cmp,
+            )
+        });
+    }
+}
+
+/// A trait to sort items by a string key, e.g. structs by their `name` field. This is like
+/// `StringSort`, but for slices of any type.
+///
+/// The key function of `sort_by_str_key` and `sort_unstable_by_str_key` borrows the key from
+/// the item, so it can return a field, or a part of it such as `item.name.trim_start()`, but
+/// it can't return a string that is created in the function, since it would be dropped too
+/// early. For computed keys, e.g. `format!("{} {}", first, last)`, use
+/// `sort_by_cached_str_key`, which computes each key once and keeps it until the slice is
+/// sorted.
+///
+/// This trait is implemented for all slices.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::{natural_lexical_cmp, SortByStrKey};
+///
+/// struct File {
+///     name: String,
+///     size: u64,
+/// }
+///
+/// let mut files = vec![
+///     File { name: "T-20.txt".into(), size: 3 },
+///     File { name: "t-5.txt".into(), size: 5 },
+///     File { name: "Äpfel.txt".into(), size: 8 },
+/// ];
+/// files.sort_by_str_key(natural_lexical_cmp, |file| &file.name);
+///
+/// let names: Vec<&str> = files.iter().map(|file| file.name.as_str()).collect();
+/// assert_eq!(names, ["Äpfel.txt", "t-5.txt", "T-20.txt"]);
+/// # }
+/// ```
+pub trait SortByStrKey<T> {
+    /// Sorts the items using the provided comparison function and a key function that borrows
+    /// a string from each item.
+    ///
+    /// **This is a stable sort, which is often not required**.
+    /// You can use `sort_unstable_by_str_key` instead.
+    #[cfg(feature = "alloc")]
+    fn sort_by_str_key<Cmp, Key>(&mut self, cmp: Cmp, key: Key)
+    where
+        Cmp: FnMut(&str, &str) -> Ordering,
+        Key: FnMut(&T) -> &str;
+
+    /// Sorts the items using the provided comparison function and a key function that borrows
+    /// a string from each item.
+    ///
+    /// This sort is unstable: The original order of equal strings is not preserved.
+    /// It is slightly more efficient than the stable alternative.
+    fn sort_unstable_by_str_key<Cmp, Key>(&mut self, cmp: Cmp, key: Key)
+    where
+        Cmp: FnMut(&str, &str) -> Ordering,
+        Key: FnMut(&T) -> &str;
+
+    /// Sorts the items using the provided comparison function and a key function that is
+    /// called once for each item, like `StringSort::string_sort_by_cached_key`.
+    ///
+    /// The key can be any type that implements `AsRef<str>`, so it can be owned, e.g. a
+    /// `String` that is created by the key function.
+    ///
+    /// **This is a stable sort, which is often not required**.
+    /// You can use `sort_unstable_by_cached_str_key` instead.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::{lexical_cmp, SortByStrKey};
+    ///
+    /// let mut people = [("Zoë", "Adams"), ("Ben", "Müller"), ("Anna", "Müller")];
+    /// people.sort_by_cached_str_key(lexical_cmp, |(first, last)| format!("{} {}", last, first));
+    ///
+    /// assert_eq!(people, [("Zoë", "Adams"), ("Anna", "Müller"), ("Ben", "Müller")]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn sort_by_cached_str_key<K, Cmp, Key>(&mut self, cmp: Cmp, key: Key)
+    where
+        K: AsRef<str>,
+        Cmp: FnMut(&str, &str) -> Ordering,
+        Key: FnMut(&T) -> K;
+
+    /// Sorts the items using the provided comparison function and a key function that is
+    /// called once for each item, like `sort_by_cached_str_key`.
+    ///
+    /// This sort is unstable: The original order of equal strings is not preserved.
+    /// It is slightly more efficient than the stable alternative.
+    #[cfg(feature = "alloc")]
+    fn sort_unstable_by_cached_str_key<K, Cmp, Key>(&mut self, cmp: Cmp, key: Key)
+    where
+        K: AsRef<str>,
+        Cmp: FnMut(&str, &str) -> Ordering,
+        Key: FnMut(&T) -> K;
+}
+
+impl<T> SortByStrKey<T> for [T] {
+    #[cfg(feature = "alloc")]
+    fn sort_by_str_key<Cmp, Key>(&mut self, mut cmp: Cmp, mut key: Key)
+    where
+        Cmp: FnMut(&str, &str) -> Ordering,
+        Key: FnMut(&T) -> &str,
+    {
+        self.sort_by(|lhs, rhs| cmp(key(lhs), key(rhs)));
+    }
+
+    fn sort_unstable_by_str_key<Cmp, Key>(&mut self, mut cmp: Cmp, mut key: Key)
+    where
+        Cmp: FnMut(&str, &str) -> Ordering,
+        Key: FnMut(&T) -> &str,
+    {
+        self.sort_unstable_by(|lhs, rhs| cmp(key(lhs), key(rhs)));
+    }
+
+    #[cfg(feature = "alloc")]
+    fn sort_by_cached_str_key<K, Cmp, Key>(&mut self, cmp: Cmp, key: Key)
+    where
+        K: AsRef<str>,
+        Cmp: FnMut(&str, &str) -> Ordering,
+        Key: FnMut(&T) -> K,
+    {
+        sort_by_cached_key(self, key, cmp, true);
+    }
+
+    #[cfg(feature = "alloc")]
+    fn sort_unstable_by_cached_str_key<K, Cmp, Key>(&mut self, cmp: Cmp, key: Key)
+    where
+        K: AsRef<str>,
+        Cmp: FnMut(&str, &str) -> Ordering,
+        Key: FnMut(&T) -> K,
+    {
+        sort_by_cached_key(self, key, cmp, false);
+    }
+}
+
+/// A trait to search slices of strings that are sorted with a comparison function of this
+/// crate.
+///
+/// This trait is implemented for all slices whose inner type implements `AsRef<str>`.
+pub trait StringSearch {
+    /// Returns the range of strings that start with `prefix` after folding them with the given
+    /// mode, e.g. for autocompletion. The slice must be sorted with the same mode. If no string
+    /// matches, the range is empty and starts where strings with the prefix would be inserted.
+    ///
+    /// This uses a binary search, so it takes O(log n) comparisons. The strings in the range
+    /// are the ones for which `lexical_starts_with` returns `true`, except in natural modes:
+    /// There, a number at the end of the prefix must match a whole number, so `"x1"` matches
+    /// `"x1"` and `"x1.txt"`, but not `"x10"`, which isn't next to them in the sorted slice.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "unicode")] {
+    /// use lexical_sort::{SortMode, StringSearch};
+    ///
+    /// let slice = ["Über", "über", "Uberall", "überhaupt", "Ufer", "Ukraine"];
+    /// assert_eq!(slice.prefix_range("uber", SortMode::Lexical), 0..4);
+    /// assert_eq!(slice.prefix_range("uf", SortMode::Lexical), 4..5);
+    /// assert_eq!(slice.prefix_range("ug", SortMode::Lexical), 5..5);
+    /// # }
+    /// ```
+    fn prefix_range(&self, prefix: &str, mode: SortMode) -> Range<usize>;
+
+    /// Searches a string with a binary search. The slice must be sorted with the same
+    /// comparison function.
+    ///
+    /// Returns `Ok` with the index of the string if it is found, otherwise `Err` with the index
+    /// where it would be inserted to keep the slice sorted. If the slice contains several
+    /// strings that are equal to `needle` according to the comparison function, the index of
+    /// the first of them is returned, unlike `[_]::binary_search_by()`.
+    ///
+    /// In debug builds, this panics if the strings around the returned index aren't sorted.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::{natural_lexical_cmp, StringSearch};
+    ///
+    /// let slice = ["T-5", "t-5", "T-20", "T-100"];
+    /// assert_eq!(slice.string_binary_search("T-20", natural_lexical_cmp), Ok(2));
+    /// assert_eq!(slice.string_binary_search("T-50", natural_lexical_cmp), Err(3));
+    /// ```
+    fn string_binary_search(
+        &self,
+        needle: &str,
+        cmp: impl FnMut(&str, &str) -> Ordering,
+    ) -> Result<usize, usize>;
+
+    /// Returns the index of the first string for which the predicate returns `false`, like
+    /// `[_]::partition_point()`. The slice must be partitioned by the predicate, i.e. it must
+    /// return `true` for all strings before the returned index, and `false` for all others.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "unicode")] {
+    /// use lexical_sort::{lexical_cmp, StringSearch};
+    /// use std::cmp::Ordering;
+    ///
+    /// let slice = ["apple", "Äpfel", "Birne", "Zitrone"];
+    /// let index = slice.string_partition_point(|s| lexical_cmp(s, "b") == Ordering::Less);
+    /// assert_eq!(index, 2);
+    /// # }
+    /// ```
+    fn string_partition_point(&self, pred: impl FnMut(&str) -> bool) -> usize;
+}
+
+/// Inserts a string into a `Vec` that is sorted with the given comparison function, so it
+/// stays sorted, and returns the index of the inserted string.
+///
+/// If the `Vec` contains strings that are equal to `item` according to the comparison
+/// function, `item` is inserted after them, so inserting strings one by one orders them like
+/// a stable sort. This uses a binary search, but inserting is O(n), because the following
+/// strings are moved.
+///
+/// In debug builds, this panics if the strings around the insertion point aren't sorted.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::{natural_lexical_cmp, string_insert_sorted};
+///
+/// let mut vec = vec!["T-5", "T-20"];
+/// assert_eq!(string_insert_sorted(&mut vec, "t-10", natural_lexical_cmp), 1);
+/// assert_eq!(string_insert_sorted(&mut vec, "T-100", natural_lexical_cmp), 3);
+/// assert_eq!(vec, ["T-5", "t-10", "T-20", "T-100"]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn string_insert_sorted<S: AsRef<str>>(
+    vec: &mut Vec<S>,
+    item: S,
+    mut cmp: impl FnMut(&str, &str) -> Ordering,
+) -> usize {
+    let index = vec.partition_point(|s| cmp(s.as_ref(), item.as_ref()) != Ordering::Greater);
+    debug_assert_sorted_at(vec, index, &mut cmp);
+    vec.insert(index, item);
+    index
+}
+
+/// Checks that the two strings before and after `index` are sorted, which is required for the
+/// result of a binary search to be meaningful
+#[inline]
+fn debug_assert_sorted_at<A: AsRef<str>>(
+    slice: &[A],
+    index: usize,
+    cmp: &mut impl FnMut(&str, &str) -> Ordering,
+) {
+    if cfg!(debug_assertions) {
+        let window = &slice[index.saturating_sub(2)..(index + 2).min(slice.len())];
+        for pair in window.windows(2) {
+            let (lhs, rhs) = (pair[0].as_ref(), pair[1].as_ref());
+            assert!(
+                cmp(lhs, rhs) != Ordering::Greater,
+                "the slice isn't sorted with the comparison function: {:?} > {:?}",
+                lhs,
+                rhs,
+            );
+        }
+    }
+}
+
+impl<A: AsRef<str>> StringSearch for [A] {
+    fn prefix_range(&self, prefix: &str, mode: SortMode) -> Range<usize> {
+        let collator = Collator::from(mode);
+        let start =
+            self.partition_point(|s| collator.cmp_prefix(s.as_ref(), prefix) == Ordering::Less);
+        let len = self[start..]
+            .partition_point(|s| collator.cmp_prefix(s.as_ref(), prefix) == Ordering::Equal);
+        start..start + len
+    }
+
+    fn string_binary_search(
+        &self,
+        needle: &str,
+        mut cmp: impl FnMut(&str, &str) -> Ordering,
+    ) -> Result<usize, usize> {
+        let index = self.partition_point(|s| cmp(s.as_ref(), needle) == Ordering::Less);
+        debug_assert_sorted_at(self, index, &mut cmp);
+        match self.get(index) {
+            Some(s) if cmp(s.as_ref(), needle) == Ordering::Equal => Ok(index),
+            _ => Err(index),
+        }
+    }
+
+    fn string_partition_point(&self, mut pred: impl FnMut(&str) -> bool) -> usize {
+        self.partition_point(|s| pred(s.as_ref()))
+    }
 }
 
 #[test]
+#[cfg(feature = "unicode")]
 fn test_sort() {
     macro_rules! assert_lexically_sorted {
-        ($T:ident, $array:expr, natural = $natural:expr) => {{
-            let mut sorted = $array.clone();
-            if $natural {
-                sorted.$T(natural_lexical_cmp);
+        ($T:ident, $first_unsorted:ident, $array:expr, natural = $natural:expr) => {{
+            let cmp = if $natural {
+                natural_lexical_cmp
             } else {
-                sorted.$T(lexical_cmp);
-            }
+                lexical_cmp
+            };
+            assert_eq!($array.$first_unsorted(cmp), None);
+
+            let mut sorted = $array.clone();
+            sorted.reverse();
+            assert_eq!(sorted.$first_unsorted(cmp), Some(1));
+            sorted.$T(cmp);
 
             assert_eq!($array, sorted);
         }};
@@ -371,15 +1723,712 @@ fn test_sort() {
         "-", "-$", "-a", "50", "100", "a", "ä", "aa", "áa", "AB", "Ab", "ab", "AE", "ae", "æ", "af",
     ];
 
-    assert_lexically_sorted!(string_sort, strings, natural = false);
-    assert_lexically_sorted!(string_sort, strings_nat, natural = true);
+    assert_lexically_sorted!(string_sort, string_first_unsorted, strings, natural = false);
+    assert_lexically_sorted!(
+        string_sort,
+        string_first_unsorted,
+        strings_nat,
+        natural = true
+    );
 
     #[cfg(feature = "std")]
     {
-        let paths: Vec<&Path> = strings.iter().map(|s| Path::new(s)).collect();
-        let paths_nat: Vec<&Path> = strings_nat.iter().map(|s| Path::new(s)).collect();
+        let paths: Vec<&Path> = strings.iter().map(Path::new).collect();
+        let paths_nat: Vec<&Path> = strings_nat.iter().map(Path::new).collect();
 
-        assert_lexically_sorted!(path_sort, paths, natural = false);
-        assert_lexically_sorted!(path_sort, paths_nat, natural = true);
+        assert_lexically_sorted!(path_sort, path_first_unsorted, paths, natural = false);
+        assert_lexically_sorted!(path_sort, path_first_unsorted, paths_nat, natural = true);
+    }
+}
+
+#[test]
+fn test_convenience_functions() {
+    const STRINGS: [&str; 10] = [
+        "T-20", "b", "t-5", "", "Äpfel", "T-100", "apfel", "-", "ß", "fóò",
+    ];
+
+    let (mut actual, mut expected) = (STRINGS, STRINGS);
+    sort(&mut actual);
+    expected.string_sort_unstable(lexical_cmp);
+    assert_eq!(actual, expected);
+
+    let (mut actual, mut expected) = (STRINGS, STRINGS);
+    sort_natural(&mut actual);
+    expected.string_sort_unstable(natural_lexical_cmp);
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_sort_fast() {
+    // many strings have the same prefix key, so they are compared with `lexical_cmp`
+    const STRINGS: [&str; 14] = [
+        "long string 2",
+        "Long string 10",
+        "long string 1",
+        "long stringß",
+        "long strings",
+        "long-string",
+        "",
+        "-",
+        "T-20",
+        "t-5",
+        "Äpfel",
+        "apfel",
+        "a🎉b",
+        "a🎉a",
+    ];
+    let mut sorted = STRINGS;
+    sorted.string_sort_fast();
+    let mut expected = STRINGS;
+    expected.string_sort_unstable(lexical_cmp);
+    assert_eq!(sorted, expected);
+
+    let mut empty: [&str; 0] = [];
+    empty.string_sort_fast();
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_path_convenience_functions() {
+    use std::path::PathBuf;
+
+    let paths: Vec<PathBuf> = ["img/10.png", "Img/3.png", "img/ä.png", "a.txt", "img/2"]
+        .iter()
+        .map(PathBuf::from)
+        .collect();
+
+    let (mut actual, mut expected) = (paths.clone(), paths.clone());
+    sort_paths(&mut actual);
+    expected.path_sort_unstable(lexical_cmp);
+    assert_eq!(actual, expected);
+
+    let (mut actual, mut expected) = (paths.clone(), paths);
+    sort_paths_natural(&mut actual);
+    expected.path_sort_unstable(natural_lexical_cmp);
+    assert_eq!(actual, expected);
+}
+
+#[test]
+#[cfg(feature = "unicode")]
+fn test_sort_preserving() {
+    let strings = [
+        "fóò", "b", "Foo", "_x", "ä", "FOO", "A", "a", "x-1", "X1", "foo",
+    ];
+
+    let mut sorted = strings;
+    sorted.string_sort_preserving(SortMode::Lexical);
+    assert_eq!(
+        sorted,
+        ["_x", "ä", "A", "a", "b", "fóò", "Foo", "FOO", "foo", "x-1", "X1"]
+    );
+
+    // fold-equal groups are in input order, everything else is unchanged
+    let mut reversed = strings;
+    reversed.reverse();
+    reversed.string_sort_preserving(SortMode::Lexical);
+    assert_eq!(
+        reversed,
+        ["_x", "a", "A", "ä", "b", "foo", "FOO", "Foo", "fóò", "x-1", "X1"]
+    );
+
+    let mut sorted = strings;
+    sorted.string_sort_preserving(SortMode::LexicalOnlyAlnum);
+    assert_eq!(
+        sorted,
+        ["ä", "A", "a", "b", "fóò", "Foo", "FOO", "foo", "_x", "x-1", "X1"]
+    );
+
+    let mut sorted = strings;
+    sorted.string_sort(lexical_cmp);
+    assert_eq!(
+        sorted,
+        ["_x", "A", "a", "ä", "b", "FOO", "Foo", "foo", "fóò", "x-1", "X1"]
+    );
+
+    // in every mode, strings that are equal after folding keep their relative order
+    let modes = [
+        SortMode::Plain,
+        SortMode::OnlyAlnum,
+        SortMode::Lexical,
+        SortMode::LexicalOnlyAlnum,
+        SortMode::Natural,
+        SortMode::NaturalOnlyAlnum,
+        SortMode::NaturalLexical,
+        SortMode::NaturalLexicalOnlyAlnum,
+    ];
+    let mut reversed = strings;
+    reversed.reverse();
+    for &mode in &modes {
+        let collator = Collator::from(mode).ties(TieBreak::InputOrder);
+        for input in &[strings, reversed] {
+            let mut sorted = *input;
+            sorted.string_sort_preserving(mode);
+            for (i, lhs) in sorted.iter().enumerate() {
+                for rhs in &sorted[i + 1..] {
+                    assert_ne!(collator.cmp(lhs, rhs), Ordering::Greater, "{:?}", mode);
+                    if collator.cmp(lhs, rhs) == Ordering::Equal {
+                        let position = |s| input.iter().position(|x| x == s);
+                        assert!(position(lhs) < position(rhs), "{:?}", mode);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "unicode")]
+fn test_sort_by_reading() {
+    let mut entries = [
+        ("Zeta", None),
+        ("田中", Some("たなか")),
+        ("Alpha", None),
+        ("佐藤", Some("さとう")),
+        ("Beta", Some("alpha")),
+        ("鈴木", None),
+    ];
+    entries.sort_by_reading(lexical_cmp);
+
+    assert_eq!(
+        entries,
+        [
+            ("Alpha", None),
+            ("Beta", Some("alpha")),
+            ("鈴木", None), // transliterated to "lingmu"
+            ("佐藤", Some("さとう")),
+            ("田中", Some("たなか")),
+            ("Zeta", None),
+        ]
+    );
+}
+
+#[test]
+#[cfg(feature = "unicode")]
+fn test_prefix_range() {
+    let mut strings = [
+        "",
+        "-",
+        "über",
+        "Überschrift",
+        "uber",
+        "Uberall",
+        "ubér",
+        "ube",
+        "ufer",
+        "u",
+        "U-Bahn",
+        "Straße",
+        "strasse",
+        "STRASSE",
+        "strassen",
+        "Strasbourg",
+        "straß",
+        "ß",
+        "ss",
+        "s",
+        "sz",
+        "zz",
+        "Zürich",
+        "z",
+        "zzz",
+        "Ž",
+        "9",
+        "99",
+        "9a",
+        "x1",
+        "x10",
+        "x1a",
+        "x2",
+        "_",
+        "ÿ",
+    ];
+    let prefixes = [
+        "", "u", "ub", "über", "UBER", "uberall", "ubz", "s", "ss", "ß", "stras", "straß", "z",
+        "zz", "zzzz", "9", "x", "x1", "-", "_", "ÿ", "y", "a", "~",
+    ];
+    let modes = [
+        SortMode::Plain,
+        SortMode::OnlyAlnum,
+        SortMode::Lexical,
+        SortMode::LexicalOnlyAlnum,
+        SortMode::Natural,
+        SortMode::NaturalOnlyAlnum,
+        SortMode::NaturalLexical,
+        SortMode::NaturalLexicalOnlyAlnum,
+    ];
+
+    for &mode in &modes {
+        strings.string_sort_unstable(mode.function());
+        for prefix in &prefixes {
+            let range = strings.prefix_range(prefix, mode);
+            for (i, s) in strings.iter().enumerate() {
+                let matches = if mode.is_natural() {
+                    Collator::from(mode).cmp_prefix(s, prefix) == Ordering::Equal
+                } else {
+                    lexical_starts_with(s, prefix, mode)
+                };
+                assert_eq!(
+                    range.contains(&i),
+                    matches,
+                    "{:?}: {:?} in {:?} ({:?})",
+                    mode,
+                    s,
+                    range,
+                    prefix
+                );
+            }
+            if range.is_empty() {
+                // the range starts where the prefix would be inserted
+                let insert = strings.partition_point(|s| mode.function()(s, prefix).is_lt());
+                assert_eq!(range.start, insert, "{:?}: {:?}", mode, prefix);
+            }
+        }
+    }
+
+    strings.string_sort_unstable(lexical_cmp);
+    let range = strings.prefix_range("uber", SortMode::Lexical);
+    assert_eq!(
+        strings[range],
+        ["uber", "ubér", "über", "Uberall", "Überschrift"]
+    );
+
+    strings.string_sort_unstable(natural_lexical_cmp);
+    let range = strings.prefix_range("x1", SortMode::NaturalLexical);
+    assert_eq!(strings[range], ["x1", "x1a"]);
+    let range = strings.prefix_range("9", SortMode::NaturalLexical);
+    assert_eq!(strings[range], ["9", "9a"]);
+    assert!(lexical_starts_with("99", "9", SortMode::NaturalLexical));
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "unicode"))]
+fn test_prefix_range_owned() {
+    let mut strings: Vec<String> = [
+        "Ubahn",
+        "Überschrift",
+        "uber",
+        "Ubel",
+        "überm",
+        "Ubfz",
+        "ubf",
+    ]
+    .iter()
+    .map(|&s| s.to_string())
+    .collect();
+    strings.string_sort_unstable(lexical_cmp);
+    assert_eq!(
+        strings,
+        [
+            "Ubahn",
+            "Ubel",
+            "uber",
+            "überm",
+            "Überschrift",
+            "ubf",
+            "Ubfz"
+        ]
+    );
+
+    assert_eq!(strings.prefix_range("uber", SortMode::Lexical), 2..5);
+    assert_eq!(strings.prefix_range("ÜBE", SortMode::Lexical), 1..5);
+    assert_eq!(strings.prefix_range("ubf", SortMode::Lexical), 5..7);
+    // no match: the range is empty at the insertion point
+    assert_eq!(strings.prefix_range("ubc", SortMode::Lexical), 1..1);
+    assert_eq!(strings.prefix_range("ubez", SortMode::Lexical), 5..5);
+    assert_eq!(strings.prefix_range("uc", SortMode::Lexical), 7..7);
+    assert_eq!(strings.prefix_range("a", SortMode::Lexical), 0..0);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_sort_by_cached_key() {
+    use std::cell::Cell;
+
+    let strings = [
+        "The Who",
+        "Queen",
+        "the Beatles",
+        "ABBA",
+        "The The",
+        "a-ha",
+        "Ärzte",
+        "the",
+        "The Doors",
+        "queen",
+        "The Who",
+    ];
+    let key = |s: &str| {
+        let s = s.to_lowercase();
+        s.strip_prefix("the ").map(String::from).unwrap_or(s)
+    };
+
+    let mut expected = strings;
+    expected.sort_by(|lhs, rhs| natural_lexical_cmp(&key(lhs), &key(rhs)));
+
+    let calls = Cell::new(0);
+    let counting_key = |s: &str| {
+        calls.set(calls.get() + 1);
+        key(s)
+    };
+
+    let mut slice = strings;
+    slice.string_sort_by_cached_key(natural_lexical_cmp, counting_key);
+    assert_eq!(slice, expected);
+    assert_eq!(calls.get(), strings.len());
+
+    calls.set(0);
+    let mut slice = strings;
+    slice.string_sort_unstable_by_cached_key(natural_lexical_cmp, counting_key);
+    assert_eq!(slice, expected);
+    assert_eq!(calls.get(), strings.len());
+
+    let mut vec: Vec<String> = strings.iter().map(|&s| s.to_string()).collect();
+    vec.string_sort_by_cached_key(natural_lexical_cmp, |s| s.len().to_string());
+    assert_eq!(vec[..3], ["the", "ABBA", "a-ha"]);
+
+    let mut empty: [&str; 0] = [];
+    empty.string_sort_by_cached_key(lexical_cmp, counting_key);
+
+    calls.set(0);
+    let mut paths: Vec<&Path> = strings.iter().map(Path::new).collect();
+    paths.path_sort_by_cached_key(natural_lexical_cmp, counting_key);
+    assert!(paths.iter().map(|p| p.to_str().unwrap()).eq(expected));
+    assert_eq!(calls.get(), strings.len());
+    let mut paths: Vec<&Path> = strings.iter().map(Path::new).collect();
+    paths.path_sort_unstable_by_cached_key(natural_lexical_cmp, counting_key);
+    assert!(paths.iter().map(|p| p.to_str().unwrap()).eq(expected));
+}
+
+#[test]
+#[cfg(feature = "unicode")]
+fn test_binary_search() {
+    let empty: [&str; 0] = [];
+    assert_eq!(empty.string_binary_search("a", lexical_cmp), Err(0));
+    assert_eq!(empty.string_partition_point(|_| true), 0);
+
+    let slice = ["", "-", "a", "Ä", "b", "T-5", "t-5", "T-20", "T-100"];
+    for (i, s) in slice.iter().enumerate() {
+        assert_eq!(slice.string_binary_search(s, natural_lexical_cmp), Ok(i));
+    }
+    assert_eq!(slice.string_binary_search("ä", natural_lexical_cmp), Err(4));
+    assert_eq!(
+        slice.string_binary_search("T-10", natural_lexical_cmp),
+        Err(7)
+    );
+    assert_eq!(
+        slice.string_binary_search("zz", natural_lexical_cmp),
+        Err(9)
+    );
+    let index = slice.string_partition_point(|s| natural_lexical_cmp(s, "t") == Ordering::Less);
+    assert_eq!(index, 5);
+
+    // duplicates: the first equal string is found
+    let case_insensitive = |lhs: &str, rhs: &str| lhs.to_lowercase().cmp(&rhs.to_lowercase());
+    let slice = ["a", "B", "b", "B", "c"];
+    assert_eq!(slice.string_binary_search("b", case_insensitive), Ok(1));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_insert_sorted() {
+    let mut vec: Vec<String> = Vec::new();
+    for s in ["T-20", "t-5", "T-100", "T-5", "a", "T-20"].iter() {
+        let index = string_insert_sorted(&mut vec, s.to_string(), natural_lexical_cmp);
+        assert_eq!(vec[index], *s);
+    }
+    assert_eq!(vec, ["a", "T-5", "t-5", "T-20", "T-20", "T-100"]);
+
+    // duplicates: the string is inserted after equal strings
+    let case_insensitive = |lhs: &str, rhs: &str| lhs.to_lowercase().cmp(&rhs.to_lowercase());
+    let mut vec = vec!["a", "B", "c"];
+    assert_eq!(string_insert_sorted(&mut vec, "b", case_insensitive), 2);
+    assert_eq!(string_insert_sorted(&mut vec, "A", case_insensitive), 1);
+    assert_eq!(vec, ["a", "A", "B", "b", "c"]);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "isn't sorted")]
+fn test_binary_search_unsorted() {
+    let slice = ["c", "a"];
+    let _ = slice.string_binary_search("b", lexical_cmp);
+}
+
+#[test]
+#[cfg(feature = "unicode")]
+fn test_is_sorted() {
+    let empty: [&str; 0] = [];
+    assert!(empty.string_is_sorted(lexical_cmp));
+    assert_eq!(empty.string_first_unsorted(lexical_cmp), None);
+    assert!(["a"].string_is_sorted(lexical_cmp));
+
+    let slice = ["a", "Ä", "B", "b", "b"];
+    assert!(slice.string_is_sorted(lexical_cmp));
+    assert!(!slice.string_is_sorted(cmp));
+    assert_eq!(slice.string_first_unsorted(cmp), Some(2));
+
+    let slice = [" a", "b", "  c"];
+    assert!(!slice.string_is_sorted(lexical_cmp));
+    assert!(slice.string_is_sorted_by(lexical_cmp, str::trim_start));
+
+    #[cfg(feature = "std")]
+    {
+        let paths: Vec<&Path> = slice.iter().map(Path::new).collect();
+        assert!(!paths.path_is_sorted(lexical_cmp));
+        assert_eq!(paths.path_first_unsorted(lexical_cmp), Some(2));
+        assert!(paths.path_is_sorted_by(lexical_cmp, str::trim_start));
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_argsort() {
+    fn is_permutation(indices: &[usize]) -> bool {
+        let mut seen = vec![false; indices.len()];
+        indices
+            .iter()
+            .all(|&i| i < seen.len() && !std::mem::replace(&mut seen[i], true))
+    }
+
+    let strings = [
+        "T-20", "b", "t-5", "", "Äpfel", "T-100", "apfel", "-", "T-5", "b", "ß", "ss",
+    ];
+    let case_insensitive = |lhs: &str, rhs: &str| lhs.to_lowercase().cmp(&rhs.to_lowercase());
+
+    let empty: [&str; 0] = [];
+    assert!(empty.string_argsort(lexical_cmp).is_empty());
+
+    for &cmp in [natural_lexical_cmp, lexical_cmp, cmp].iter() {
+        let mut sorted = strings;
+        sorted.string_sort(cmp);
+        let indices = strings.string_argsort(cmp);
+        assert!(is_permutation(&indices));
+        assert!(indices
+            .iter()
+            .map(|&i| strings[i])
+            .eq(sorted.iter().copied()));
+
+        let indices = strings.string_argsort_unstable(cmp);
+        assert!(is_permutation(&indices));
+        assert!(indices
+            .iter()
+            .map(|&i| strings[i])
+            .eq(sorted.iter().copied()));
+    }
+
+    // equal strings keep their order in the stable variant
+    let indices = strings.string_argsort(case_insensitive);
+    assert_eq!(indices[..3], [3, 7, 6]);
+    let indices = strings.string_argsort_unstable(case_insensitive);
+    assert!(is_permutation(&indices));
+    let sorted: Vec<&str> = indices.iter().map(|&i| strings[i]).collect();
+    assert!(sorted.string_is_sorted(case_insensitive));
+
+    let paths: Vec<&Path> = strings.iter().map(Path::new).collect();
+    let mut sorted = paths.clone();
+    sorted.path_sort(natural_lexical_cmp);
+    let indices = paths.path_argsort(natural_lexical_cmp);
+    assert!(is_permutation(&indices));
+    assert!(indices.iter().map(|&i| paths[i]).eq(sorted.iter().copied()));
+    let indices = paths.path_argsort_unstable(natural_lexical_cmp);
+    assert!(indices.iter().map(|&i| paths[i]).eq(sorted.iter().copied()));
+}
+
+#[test]
+fn test_select_nth_unstable() {
+    let strings = [
+        "T-20", "b", "t-5", "", "Äpfel", "T-100", "apfel", "-", "T-5", "b", "ß", "ss",
+    ];
+    let mut sorted = strings;
+    sorted.string_sort(natural_lexical_cmp);
+    for n in 0..strings.len() {
+        let mut slice = strings;
+        slice.string_select_nth_unstable(n, natural_lexical_cmp);
+        assert_eq!(slice[n], sorted[n]);
+        assert!(slice[..n]
+            .iter()
+            .all(|s| natural_lexical_cmp(s, slice[n]) != Ordering::Greater));
+        assert!(slice[n + 1..]
+            .iter()
+            .all(|s| natural_lexical_cmp(s, slice[n]) != Ordering::Less));
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_select_nth_unstable_out_of_bounds() {
+    ["a", "b"].string_select_nth_unstable(2, lexical_cmp);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_smallest_k() {
+    let strings = [
+        "T-20", "b", "t-5", "", "Äpfel", "T-100", "apfel", "-", "T-5", "b", "ß", "ss",
+    ];
+    let case_insensitive = |lhs: &str, rhs: &str| lhs.to_lowercase().cmp(&rhs.to_lowercase());
+
+    let empty: [&str; 0] = [];
+    assert!(empty.string_smallest_k(3, lexical_cmp).is_empty());
+    assert!(strings.string_smallest_k(0, lexical_cmp).is_empty());
+
+    for &cmp in [natural_lexical_cmp, lexical_cmp, cmp, case_insensitive].iter() {
+        let mut sorted = strings;
+        sorted.string_sort(cmp);
+        for k in 0..strings.len() + 2 {
+            let expected = &sorted[..k.min(sorted.len())];
+            assert_eq!(strings.string_smallest_k(k, cmp), expected);
+        }
+    }
+
+    // equal strings are returned in their original order
+    let strings = ["B", "a", "b", "A", "c"];
+    assert_eq!(
+        strings.string_smallest_k(3, case_insensitive),
+        ["a", "A", "B"]
+    );
+
+    let paths: Vec<&Path> = strings.iter().map(Path::new).collect();
+    assert_eq!(
+        paths.path_smallest_k(3, case_insensitive),
+        [Path::new("a"), Path::new("A"), Path::new("B")]
+    );
+    let mut paths = paths;
+    paths.path_select_nth_unstable(4, case_insensitive);
+    assert_eq!(paths[4], Path::new("c"));
+}
+
+#[test]
+fn test_sort_by_str_key() {
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Person {
+        first: &'static str,
+        last: &'static str,
+        id: u32,
+    }
+    let person = |first, last, id| Person { first, last, id };
+    let people = [
+        person("Zoë", " Müller", 1),
+        person("anna", "Adams", 2),
+        person("Ben", "müller", 3),
+        person("Anna", "Adams", 4),
+        person("Ben", "Muller", 5),
+    ];
+    let ids = |people: &[Person]| -> [u32; 5] {
+        let mut ids = [0; 5];
+        for (id, person) in ids.iter_mut().zip(people) {
+            *id = person.id;
+        }
+        ids
+    };
+
+    let mut sorted = people;
+    sorted.sort_by_str_key(lexical_cmp, |p| p.first);
+    assert_eq!(ids(&sorted), [4, 2, 3, 5, 1]);
+    sorted.sort_unstable_by_str_key(lexical_cmp, |p| p.first);
+    assert_eq!(ids(&sorted), [4, 2, 3, 5, 1]);
+
+    // the key can borrow a part of a field
+    let mut sorted = people;
+    sorted.sort_by_str_key(lexical_cmp, |p| p.last.trim_start());
+    assert_eq!(ids(&sorted), [2, 4, 5, 1, 3]);
+
+    #[cfg(feature = "std")]
+    {
+        // computed keys
+        let full_name = |p: &Person| format!("{} {}", p.last.trim_start(), p.first);
+        let mut sorted = people;
+        sorted.sort_by_cached_str_key(lexical_cmp, full_name);
+        assert_eq!(ids(&sorted), [4, 2, 5, 3, 1]);
+        let mut sorted = people;
+        sorted.sort_unstable_by_cached_str_key(lexical_cmp, full_name);
+        assert_eq!(ids(&sorted), [4, 2, 5, 3, 1]);
+
+        // equal keys keep their order in the stable variants
+        let case_insensitive = |lhs: &str, rhs: &str| lhs.to_lowercase().cmp(&rhs.to_lowercase());
+        let mut sorted = people;
+        sorted.sort_by_str_key(case_insensitive, |p| p.first);
+        assert_eq!(ids(&sorted), [2, 4, 3, 5, 1]);
+        let mut sorted = people;
+        sorted.sort_by_cached_str_key(case_insensitive, |p| p.last.trim_start().to_string());
+        assert_eq!(ids(&sorted), [2, 4, 5, 1, 3]);
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::VecDeque;
+
+    proptest! {
+        #[test]
+        fn smallest_k_matches_sort(
+            strings in proptest::collection::vec("[-aAbB1 0ä]{0,4}", 0..40),
+            k in 0..50usize,
+        ) {
+            let case_insensitive =
+                |lhs: &str, rhs: &str| lhs.to_lowercase().cmp(&rhs.to_lowercase());
+            for &cmp in [natural_lexical_cmp, lexical_cmp, case_insensitive].iter() {
+                let mut sorted = strings.clone();
+                sorted.string_sort(cmp);
+                sorted.truncate(k);
+                prop_assert_eq!(strings.string_smallest_k(k, cmp), sorted.clone());
+
+                let paths: Vec<&Path> = strings.iter().map(Path::new).collect();
+                let smallest: Vec<&Path> = sorted.iter().map(Path::new).collect();
+                prop_assert_eq!(paths.path_smallest_k(k, cmp), smallest);
+            }
+        }
+
+        #[test]
+        fn sort_fast_matches_sort(
+            strings in proptest::collection::vec("([-aAbB1 0ä\u{FFFD}]|\\PC){0,10}", 0..60),
+        ) {
+            let mut sorted = strings.clone();
+            sorted.string_sort_unstable(lexical_cmp);
+            let mut fast = strings.clone();
+            fast.string_sort_fast();
+            prop_assert_eq!(&fast, &sorted);
+
+            let mut deque: VecDeque<String> = strings.into_iter().collect();
+            deque.rotate_left(deque.len() / 2);
+            deque.string_sort_fast();
+            prop_assert!(deque.iter().eq(sorted.iter()));
+        }
+
+        #[test]
+        fn cmp_bytes_is_transitive(
+            strings in proptest::collection::vec(
+                proptest::collection::vec(
+                    proptest::sample::select(b"0 2aS-\xFF\xE2".to_vec()),
+                    0..5,
+                ),
+                0..12,
+            ),
+        ) {
+            for &mode in SortMode::ALL.iter() {
+                let cmp = |lhs: &Vec<u8>, rhs: &Vec<u8>| cmp_bytes(lhs, rhs, mode);
+                for a in &strings {
+                    for b in &strings {
+                        let ab = cmp(a, b);
+                        prop_assert_eq!(cmp(b, a), ab.reverse());
+                        for c in &strings {
+                            if ab != Ordering::Greater && cmp(b, c) != Ordering::Greater {
+                                prop_assert_ne!(
+                                    cmp(a, c),
+                                    Ordering::Greater,
+                                    "{} {:?} {:?} {:?}",
+                                    mode,
+                                    a,
+                                    b,
+                                    c
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        }
     }
 }
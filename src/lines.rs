@@ -0,0 +1,170 @@
+//! Sorting of the lines of a text.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// Sorts the lines of a text with the given comparison function, and returns the sorted text.
+///
+/// The text is split into lines at `\n`. A line that ends with `\r\n` keeps its line ending,
+/// so texts with Windows line endings, or with mixed line endings, are sorted correctly. The
+/// line endings are not compared. Empty lines are sorted like other lines, so they usually
+/// come first.
+///
+/// If the text ends with a newline, so does the sorted text; otherwise, the newline after the
+/// last line is removed. This is a stable sort, so equal lines keep their order.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::{natural_lexical_cmp, sort_lines};
+///
+/// let text = "T-20\nb\nT-5\nA\n";
+/// assert_eq!(sort_lines(text, natural_lexical_cmp), "A\nb\nT-5\nT-20\n");
+/// ```
+pub fn sort_lines(text: &str, cmp: impl FnMut(&str, &str) -> Ordering) -> String {
+    sort_lines_with(text, cmp, false)
+}
+
+/// Sorts the lines of a text in place, like `sort_lines`.
+pub fn sort_lines_in_place(text: &mut String, cmp: impl FnMut(&str, &str) -> Ordering) {
+    *text = sort_lines_with(text, cmp, false);
+}
+
+/// Sorts the lines of a text like `sort_lines`, and removes duplicate lines, like `sort -u`.
+///
+/// Lines are duplicates if the comparison function returns `Ordering::Equal`; only the first
+/// of them is kept. Note that the comparison functions of this crate only consider identical
+/// strings equal. To remove lines that are equal after folding, use a `Collator` with
+/// `TieBreak::InputOrder`.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::{lexical_cmp, sort_unique_lines, Collator, TieBreak};
+///
+/// let text = "b\r\nFoo\r\nb\r\nfoo\r\n";
+/// assert_eq!(sort_unique_lines(text, lexical_cmp), "b\r\nFoo\r\nfoo\r\n");
+///
+/// let collator = Collator::new().ties(TieBreak::InputOrder);
+/// let cmp = |lhs: &str, rhs: &str| collator.cmp(lhs, rhs);
+/// assert_eq!(sort_unique_lines(text, cmp), "b\r\nFoo\r\n");
+/// ```
+pub fn sort_unique_lines(text: &str, cmp: impl FnMut(&str, &str) -> Ordering) -> String {
+    sort_lines_with(text, cmp, true)
+}
+
+/// Sorts the lines of a text in place and removes duplicate lines, like `sort_unique_lines`.
+pub fn sort_unique_lines_in_place(text: &mut String, cmp: impl FnMut(&str, &str) -> Ordering) {
+    *text = sort_lines_with(text, cmp, true);
+}
+
+fn sort_lines_with(
+    text: &str,
+    mut cmp: impl FnMut(&str, &str) -> Ordering,
+    unique: bool,
+) -> String {
+    // every line is split into its content and its line ending
+    let mut lines: Vec<(&str, &str)> = text
+        .split_inclusive('\n')
+        .map(|line| {
+            let content = line.strip_suffix('\n').unwrap_or(line);
+            let content = match content.strip_suffix('\r') {
+                Some(c) if content.len() < line.len() => c,
+                _ => content,
+            };
+            (content, &line[content.len()..])
+        })
+        .collect();
+
+    // the last line gets a line ending while sorting, which is removed again afterwards
+    let trailing_newline = text.ends_with('\n');
+    if let Some(n) = lines.len().checked_sub(1) {
+        if !trailing_newline {
+            lines[n].1 = match n {
+                0 => "\n",
+                _ => lines[n - 1].1,
+            };
+        }
+    }
+
+    lines.sort_by(|lhs, rhs| cmp(lhs.0, rhs.0));
+    if unique {
+        lines.dedup_by(|rhs, lhs| cmp(lhs.0, rhs.0) == Ordering::Equal);
+    }
+
+    let mut sorted = String::with_capacity(text.len() + 2);
+    for (content, ending) in &lines {
+        sorted.push_str(content);
+        sorted.push_str(ending);
+    }
+    if !trailing_newline {
+        if let Some((_, ending)) = lines.last() {
+            sorted.truncate(sorted.len() - ending.len());
+        }
+    }
+    sorted
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{cmp, lexical_cmp, natural_lexical_cmp};
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_sort_lines() {
+        assert_eq!(sort_lines("", lexical_cmp), "");
+        assert_eq!(sort_lines("\n", lexical_cmp), "\n");
+        assert_eq!(sort_lines("b\na", lexical_cmp), "a\nb");
+        assert_eq!(sort_lines("b\na\n", lexical_cmp), "a\nb\n");
+        assert_eq!(
+            sort_lines("T-20\nT-5\nt-10\n", natural_lexical_cmp),
+            "T-5\nt-10\nT-20\n"
+        );
+
+        // CRLF
+        assert_eq!(sort_lines("b\r\na\r\n", lexical_cmp), "a\r\nb\r\n");
+        assert_eq!(sort_lines("b\r\na", lexical_cmp), "a\r\nb");
+        assert_eq!(sort_lines("b\r\na\n", lexical_cmp), "a\nb\r\n");
+        assert_eq!(sort_lines("c\nb\r\na", lexical_cmp), "a\r\nb\r\nc");
+        // a lone "\r" is not a line ending
+        assert_eq!(sort_lines("b\ra\na", lexical_cmp), "a\nb\ra");
+
+        // empty lines
+        assert_eq!(sort_lines("b\n\na\n", lexical_cmp), "\na\nb\n");
+        assert_eq!(sort_lines("b\n\n", lexical_cmp), "\nb\n");
+        assert_eq!(sort_lines("b\r\n\r\n", lexical_cmp), "\r\nb\r\n");
+        assert_eq!(sort_lines("b\n", lexical_cmp), "b\n");
+
+        // stability
+        let case_insensitive = |lhs: &str, rhs: &str| lhs.to_lowercase().cmp(&rhs.to_lowercase());
+        assert_eq!(
+            sort_lines("B\nb\na\nA\nb", case_insensitive),
+            "a\nA\nB\nb\nb"
+        );
+
+        let mut text = String::from("ß\nss\nst\nsr\n");
+        sort_lines_in_place(&mut text, lexical_cmp);
+        assert_eq!(text, "sr\nss\nß\nst\n");
+        sort_lines_in_place(&mut text, cmp);
+        assert_eq!(text, "sr\nss\nst\nß\n");
+    }
+
+    #[test]
+    fn test_sort_unique_lines() {
+        assert_eq!(sort_unique_lines("", lexical_cmp), "");
+        assert_eq!(sort_unique_lines("\n\n\n", lexical_cmp), "\n");
+        assert_eq!(sort_unique_lines("b\na\nb\na", lexical_cmp), "a\nb");
+        assert_eq!(sort_unique_lines("a\r\nb\na\n", lexical_cmp), "a\r\nb\n");
+        assert_eq!(sort_unique_lines("a\nb\na\r\n", lexical_cmp), "a\nb\n");
+
+        // the first of the equal lines is kept
+        let case_insensitive = |lhs: &str, rhs: &str| lhs.to_lowercase().cmp(&rhs.to_lowercase());
+        assert_eq!(sort_unique_lines("B\nb\na\nA\nb", case_insensitive), "a\nB");
+
+        let mut text = String::from("x\r\nx\r\n\r\n");
+        sort_unique_lines_in_place(&mut text, lexical_cmp);
+        assert_eq!(text, "\r\nx\r\n");
+    }
+}
@@ -0,0 +1,335 @@
+//! Lazy merging of sorted sequences of strings.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::iter::{FusedIterator, Peekable};
+
+/// Merges two iterators that are sorted with the same comparison function into one sorted
+/// iterator, without collecting or sorting them
+///
+/// The items can be of any type that implements `AsRef<str>`, e.g. `&str` or `String`, but
+/// both iterators must have the same item type. If two strings are equal according to the
+/// comparison function, the one from `a` comes first, so the merge is stable. Duplicates are
+/// preserved. If an iterator isn't sorted, the result isn't sorted either, but it contains
+/// all items.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::{merge_sorted, natural_lexical_cmp};
+///
+/// let a = ["Äpfel", "T-5", "T-100"];
+/// let b = ["apple", "T-20", "T-100"];
+/// let merged: Vec<_> = merge_sorted(a, b, natural_lexical_cmp).collect();
+/// assert_eq!(merged, ["Äpfel", "apple", "T-5", "T-20", "T-100", "T-100"]);
+/// # }
+/// ```
+pub fn merge_sorted<A, B, F>(a: A, b: B, cmp: F) -> MergeSorted<A::IntoIter, B::IntoIter, F>
+where
+    A: IntoIterator,
+    B: IntoIterator<Item = A::Item>,
+    A::Item: AsRef<str>,
+    F: FnMut(&str, &str) -> Ordering,
+{
+    MergeSorted {
+        a: a.into_iter().peekable(),
+        b: b.into_iter().peekable(),
+        cmp,
+    }
+}
+
+/// Iterator returned by `merge_sorted`
+pub struct MergeSorted<A: Iterator, B: Iterator, F> {
+    a: Peekable<A>,
+    b: Peekable<B>,
+    cmp: F,
+}
+
+impl<A, B, F> Iterator for MergeSorted<A, B, F>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+    A::Item: AsRef<str>,
+    F: FnMut(&str, &str) -> Ordering,
+{
+    type Item = A::Item;
+
+    fn next(&mut self) -> Option<A::Item> {
+        let take_b = match (self.a.peek(), self.b.peek()) {
+            (Some(a), Some(b)) => (self.cmp)(b.as_ref(), a.as_ref()) == Ordering::Less,
+            (a, _) => a.is_none(),
+        };
+        if take_b {
+            self.b.next()
+        } else {
+            self.a.next()
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (min1, max1) = self.a.size_hint();
+        let (min2, max2) = self.b.size_hint();
+        let max = match (max1, max2) {
+            (Some(max1), Some(max2)) => max1.checked_add(max2),
+            _ => None,
+        };
+        (min1.saturating_add(min2), max)
+    }
+}
+
+impl<A, B, F> FusedIterator for MergeSorted<A, B, F>
+where
+    A: FusedIterator,
+    B: FusedIterator<Item = A::Item>,
+    A::Item: AsRef<str>,
+    F: FnMut(&str, &str) -> Ordering,
+{
+}
+
+/// Merges any number of iterators that are sorted with the same comparison function into one
+/// sorted iterator, without collecting or sorting them
+///
+/// This is like `merge_sorted`, but for many iterators: It keeps the next item of every
+/// iterator in a binary heap, so every item takes O(log k) comparisons, where k is the number
+/// of iterators. If strings from different iterators are equal according to the comparison
+/// function, the one from the earlier iterator comes first, so the merge is stable.
+/// Duplicates are preserved.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::{kmerge_sorted, lexical_cmp};
+///
+/// let shards = vec![
+///     vec!["Bob", "dave"],
+///     vec!["alice", "Carol", "Erin"],
+///     vec![],
+///     vec!["Álvaro", "zoe"],
+/// ];
+/// let merged: Vec<_> = kmerge_sorted(shards, lexical_cmp).collect();
+/// assert_eq!(merged, ["alice", "Álvaro", "Bob", "Carol", "dave", "Erin", "zoe"]);
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+pub fn kmerge_sorted<I, F>(iters: I, cmp: F) -> KMergeSorted<<I::Item as IntoIterator>::IntoIter, F>
+where
+    I: IntoIterator,
+    I::Item: IntoIterator,
+    <I::Item as IntoIterator>::Item: AsRef<str>,
+    F: FnMut(&str, &str) -> Ordering,
+{
+    let mut merge = KMergeSorted {
+        heap: Vec::new(),
+        cmp,
+    };
+    for (index, iter) in iters.into_iter().enumerate() {
+        let mut iter = iter.into_iter();
+        if let Some(item) = iter.next() {
+            merge.heap.push(Head { item, index, iter });
+            merge.sift_up(merge.heap.len() - 1);
+        }
+    }
+    merge
+}
+
+/// Iterator returned by `kmerge_sorted`
+#[cfg(feature = "alloc")]
+pub struct KMergeSorted<I: Iterator, F> {
+    heap: Vec<Head<I>>,
+    cmp: F,
+}
+
+/// The next item of an iterator in `KMergeSorted`, and the index of the iterator
+#[cfg(feature = "alloc")]
+struct Head<I: Iterator> {
+    item: I::Item,
+    index: usize,
+    iter: I,
+}
+
+#[cfg(feature = "alloc")]
+impl<I, F> KMergeSorted<I, F>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+    F: FnMut(&str, &str) -> Ordering,
+{
+    /// Returns `true` if the head at `i` comes before the head at `j`
+    fn less(&mut self, i: usize, j: usize) -> bool {
+        let (lhs, rhs) = (&self.heap[i], &self.heap[j]);
+        (self.cmp)(lhs.item.as_ref(), rhs.item.as_ref())
+            .then(lhs.index.cmp(&rhs.index))
+            .is_lt()
+    }
+
+    fn sift_up(&mut self, mut i: usize) {
+        while i > 0 {
+            let parent = (i - 1) / 2;
+            if !self.less(i, parent) {
+                break;
+            }
+            self.heap.swap(i, parent);
+            i = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut i: usize) {
+        loop {
+            let (left, right) = (2 * i + 1, 2 * i + 2);
+            let mut least = i;
+            if left < self.heap.len() && self.less(left, least) {
+                least = left;
+            }
+            if right < self.heap.len() && self.less(right, least) {
+                least = right;
+            }
+            if least == i {
+                break;
+            }
+            self.heap.swap(i, least);
+            i = least;
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I, F> Iterator for KMergeSorted<I, F>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+    F: FnMut(&str, &str) -> Ordering,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let head = self.heap.first_mut()?;
+        let item = match head.iter.next() {
+            Some(next) => core::mem::replace(&mut head.item, next),
+            None => self.heap.swap_remove(0).item,
+        };
+        if !self.heap.is_empty() {
+            self.sift_down(0);
+        }
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.heap.iter().fold((0, Some(0)), |(min, max), head| {
+            let (head_min, head_max) = head.iter.size_hint();
+            let max = match (max, head_max) {
+                (Some(max), Some(head_max)) => {
+                    max.checked_add(head_max).and_then(|max| max.checked_add(1))
+                }
+                _ => None,
+            };
+            (min.saturating_add(head_min).saturating_add(1), max)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexical_cmp;
+
+    #[test]
+    fn test_merge_sorted() {
+        let empty: [&str; 0] = [];
+        assert_eq!(merge_sorted(empty, empty, lexical_cmp).next(), None);
+        let mut merge = merge_sorted(["a", "c"], empty, lexical_cmp);
+        assert_eq!(merge.size_hint(), (2, Some(2)));
+        assert_eq!(merge.next(), Some("a"));
+        assert_eq!(merge.next(), Some("c"));
+        assert_eq!(merge.next(), None);
+
+        // ties prefer the first iterator, and duplicates are preserved
+        let case_insensitive = |lhs: &str, rhs: &str| {
+            let lhs = lhs.bytes().map(|b| b.to_ascii_lowercase());
+            lhs.cmp(rhs.bytes().map(|b| b.to_ascii_lowercase()))
+        };
+        let a = ["a", "B", "b", "c"];
+        let b = ["A", "b", "C", "d"];
+        let mut merge = merge_sorted(a, b, case_insensitive);
+        for &expected in ["a", "A", "B", "b", "b", "c", "C", "d"].iter() {
+            assert_eq!(merge.next(), Some(expected));
+        }
+        assert_eq!(merge.next(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_merge_shards() {
+        use crate::natural_lexical_cmp;
+
+        let corpus = [
+            "dT@ŁeΩØä.µ#",
+            "ŋ +GÐNSCEṇZæB+łßc",
+            "Øí mÞt@Bwi",
+            "+9Üyđg",
+            "ZAgL!Gé",
+            "T-60575",
+            "T-49",
+            "zvħü¼Ø!iµ¼.",
+            "ŁøŒSBN9",
+            "(̣¥AŒvŁy40",
+            "æ#áBV(AŁFAdQ. 8",
+            "T-5",
+            "t-5",
+            "#½IṣÞY²̣",
+            "9f1kẞIz7æ3€ßeŁ",
+            "ŧywΩEc",
+            "!½j.œ³d⅞qÖDVÜðQNŒ",
+            "Äþ+RF ",
+            "T-5",
+            "¼µírT²ałDKnwB.T8P",
+            "€ŋØáEIJt @éµ",
+            "³3ömrn@H2jóĸ#R",
+            "Ŋđ⅞y$VṚ)ĦE",
+            "T-3012138",
+            "Ω9äülq6H",
+            "Ω9äülq.o",
+            "pŊcóh~öJL*fæL",
+            "pŊcóh~öJL*Ł",
+            "",
+            "T-160",
+            "T-2",
+        ];
+        let case_insensitive = |lhs: &str, rhs: &str| lhs.to_lowercase().cmp(&rhs.to_lowercase());
+        let comparators: [fn(&str, &str) -> Ordering; 3] =
+            [lexical_cmp, natural_lexical_cmp, case_insensitive];
+
+        for &cmp in comparators.iter() {
+            let mut shards = vec![Vec::new(); 4];
+            for (i, s) in corpus.iter().enumerate() {
+                shards[i * i % 4].push(s.to_string());
+            }
+            for shard in &mut shards {
+                shard.sort_by(|l, r| cmp(l, r));
+            }
+
+            // a stable sort of the concatenation keeps equal strings in the order of the shards
+            let mut expected: Vec<String> = shards.concat();
+            expected.sort_by(|l, r| cmp(l, r));
+
+            let merged = kmerge_sorted(shards.clone(), cmp);
+            assert_eq!(merged.size_hint(), (corpus.len(), Some(corpus.len())));
+            assert_eq!(merged.collect::<Vec<_>>(), expected);
+
+            let mut expected: Vec<&String> = shards[0].iter().chain(&shards[1]).collect();
+            expected.sort_by(|l, r| cmp(l, r));
+            let merged: Vec<&String> = merge_sorted(&shards[0], &shards[1], cmp).collect();
+            assert_eq!(merged, expected);
+        }
+
+        let no_shards: Vec<Vec<&str>> = Vec::new();
+        assert_eq!(kmerge_sorted(no_shards, lexical_cmp).next(), None);
+        let empty_shards: Vec<Vec<&str>> = vec![vec![], vec![]];
+        assert_eq!(
+            kmerge_sorted(empty_shards, lexical_cmp).size_hint(),
+            (0, Some(0))
+        );
+    }
+}
@@ -0,0 +1,149 @@
+//! The least and greatest strings of an iterator.
+
+use core::cmp::Ordering;
+
+/// Returns the least string of an iterator, according to the given comparison function, or
+/// `None` if the iterator is empty.
+///
+/// The items can be of any type that implements `AsRef<str>`, e.g. `&str` or `String`. If
+/// several strings are equally least, the first of them is returned, like in
+/// `Iterator::min_by`.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::{lexical_min, natural_lexical_cmp};
+///
+/// let files = ["T-20", "t-5", "T-100"];
+/// assert_eq!(lexical_min(files.iter(), natural_lexical_cmp), Some(&"t-5"));
+/// assert_eq!(lexical_min(Vec::<String>::new(), natural_lexical_cmp), None);
+/// ```
+pub fn lexical_min<I>(iter: I, mut cmp: impl FnMut(&str, &str) -> Ordering) -> Option<I::Item>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    iter.into_iter().fold(None, |min, item| match min {
+        Some(min) if cmp(item.as_ref(), min.as_ref()) != Ordering::Less => Some(min),
+        _ => Some(item),
+    })
+}
+
+/// Returns the greatest string of an iterator, according to the given comparison function, or
+/// `None` if the iterator is empty.
+///
+/// If several strings are equally greatest, the first of them is returned, like in
+/// `lexical_min`. Note that this differs from `Iterator::max_by`, which returns the last one.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::{lexical_max, natural_lexical_cmp};
+///
+/// let files = ["T-20", "t-5", "T-100"];
+/// assert_eq!(lexical_max(files.iter(), natural_lexical_cmp), Some(&"T-100"));
+/// ```
+pub fn lexical_max<I>(iter: I, mut cmp: impl FnMut(&str, &str) -> Ordering) -> Option<I::Item>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    iter.into_iter().fold(None, |max, item| match max {
+        Some(max) if cmp(item.as_ref(), max.as_ref()) != Ordering::Greater => Some(max),
+        _ => Some(item),
+    })
+}
+
+/// Returns the least and the greatest string of an iterator in one pass, according to the
+/// given comparison function, or `None` if the iterator is empty.
+///
+/// If several strings are equally least or greatest, the first of them is returned, like in
+/// `lexical_min` and `lexical_max`. If the iterator has only one item, it is both the least and
+/// the greatest, so it is cloned.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::{lexical_cmp, lexical_min_max};
+///
+/// let words = ["Banana", "apple", "cherry", "Apple"];
+/// assert_eq!(lexical_min_max(words, lexical_cmp), Some(("Apple", "cherry")));
+/// assert_eq!(lexical_min_max(["kiwi"], lexical_cmp), Some(("kiwi", "kiwi")));
+/// ```
+pub fn lexical_min_max<I>(
+    iter: I,
+    mut cmp: impl FnMut(&str, &str) -> Ordering,
+) -> Option<(I::Item, I::Item)>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str> + Clone,
+{
+    let mut iter = iter.into_iter();
+    let first = iter.next()?;
+    let (min, max) = iter.fold((first.clone(), first), |(min, max), item| {
+        if cmp(item.as_ref(), min.as_ref()) == Ordering::Less {
+            (item, max)
+        } else if cmp(item.as_ref(), max.as_ref()) == Ordering::Greater {
+            (min, item)
+        } else {
+            (min, max)
+        }
+    });
+    Some((min, max))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{lexical_cmp, natural_lexical_cmp};
+
+    #[test]
+    fn test_min_max() {
+        let strings = ["b", "T-20", "a", "T-5", "T-100", "B"];
+        assert_eq!(lexical_min(strings, natural_lexical_cmp), Some("a"));
+        assert_eq!(lexical_max(strings, natural_lexical_cmp), Some("T-100"));
+        assert_eq!(
+            lexical_min_max(strings, natural_lexical_cmp),
+            Some(("a", "T-100"))
+        );
+        assert_eq!(lexical_max(strings, lexical_cmp), Some("T-5"));
+
+        // empty and single-element iterators
+        let empty: [&str; 0] = [];
+        assert_eq!(lexical_min(empty, lexical_cmp), None);
+        assert_eq!(lexical_max(empty, lexical_cmp), None);
+        assert_eq!(lexical_min_max(empty, lexical_cmp), None);
+        assert_eq!(lexical_min([""], lexical_cmp), Some(""));
+        assert_eq!(lexical_max(["x"], lexical_cmp), Some("x"));
+        assert_eq!(lexical_min_max(["x"], lexical_cmp), Some(("x", "x")));
+
+        // the first of equal strings wins
+        let case_insensitive = |lhs: &str, rhs: &str| {
+            let lhs = lhs.bytes().map(|b| b.to_ascii_lowercase());
+            lhs.cmp(rhs.bytes().map(|b| b.to_ascii_lowercase()))
+        };
+        let strings = ["b", "A", "B", "a", "b"];
+        assert_eq!(lexical_min(strings.iter(), case_insensitive), Some(&"A"));
+        assert_eq!(lexical_max(strings.iter(), case_insensitive), Some(&"b"));
+        let (min, max) = lexical_min_max(strings.iter(), case_insensitive).unwrap();
+        assert!(core::ptr::eq(min, &strings[1]));
+        assert!(core::ptr::eq(max, &strings[0]));
+        let (min, max) = lexical_min_max(strings.iter().rev(), case_insensitive).unwrap();
+        assert!(core::ptr::eq(min, &strings[3]));
+        assert!(core::ptr::eq(max, &strings[4]));
+        let max = lexical_max(strings.iter().rev(), case_insensitive).unwrap();
+        assert!(core::ptr::eq(max, &strings[4]));
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", feature = "unicode"))]
+    fn test_min_max_owned() {
+        let strings = vec![String::from("b"), String::from("Ä"), String::from("a")];
+        assert_eq!(lexical_min(&strings, lexical_cmp), Some(&strings[2]));
+        assert_eq!(lexical_max(&strings, lexical_cmp), Some(&strings[0]));
+        assert_eq!(
+            lexical_min_max(strings, lexical_cmp),
+            Some((String::from("a"), String::from("b")))
+        );
+    }
+}
@@ -0,0 +1,256 @@
+//! Sorting of optional strings, and comparators that sort missing values last.
+
+use crate::compare::Compare;
+use core::cmp::Ordering;
+
+/// A trait to sort optional strings. This is a convenient wrapper for the standard library
+/// sort functions, like `StringSort`.
+///
+/// This trait is implemented for all slices of `Option<A>` whose inner type implements
+/// `AsRef<str>`. `None` values sort after all strings, unless the `_none_first` methods are
+/// used. Among themselves, `None` values are equal, so their order is preserved by the stable
+/// sorts.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::OptionStringSort;
+///
+/// let slice = &mut [Some("Lorem"), None, Some("ipsum"), Some("dolor"), None];
+/// slice.string_sort(lexical_sort::lexical_cmp);
+/// assert_eq!(slice, &[Some("dolor"), Some("ipsum"), Some("Lorem"), None, None]);
+///
+/// slice.string_sort_none_first(lexical_sort::lexical_cmp);
+/// assert_eq!(slice, &[None, None, Some("dolor"), Some("ipsum"), Some("Lorem")]);
+/// ```
+pub trait OptionStringSort {
+    /// Sorts the items using the provided comparison function, with `None` last.
+    ///
+    /// **This is a stable sort, which is often not required**.
+    /// You can use `string_sort_unstable` instead.
+    #[cfg(feature = "alloc")]
+    fn string_sort(&mut self, cmp: impl FnMut(&str, &str) -> Ordering);
+
+    /// Sorts the items using the provided comparison function, with `None` last.
+    ///
+    /// This sort is unstable: The original order of equal strings is not preserved.
+    /// It is slightly more efficient than the stable alternative.
+    fn string_sort_unstable(&mut self, cmp: impl FnMut(&str, &str) -> Ordering);
+
+    /// Sorts the items using the provided comparison function, with `None` first.
+    ///
+    /// **This is a stable sort, which is often not required**.
+    /// You can use `string_sort_unstable_none_first` instead.
+    #[cfg(feature = "alloc")]
+    fn string_sort_none_first(&mut self, cmp: impl FnMut(&str, &str) -> Ordering);
+
+    /// Sorts the items using the provided comparison function, with `None` first.
+    ///
+    /// This sort is unstable: The original order of equal strings is not preserved.
+    /// It is slightly more efficient than the stable alternative.
+    fn string_sort_unstable_none_first(&mut self, cmp: impl FnMut(&str, &str) -> Ordering);
+
+    /// Sorts the items using the provided comparator, which can be a `&dyn Compare`, with
+    /// `None` last.
+    ///
+    /// **This is a stable sort, which is often not required**.
+    /// You can use `string_sort_unstable_with` instead.
+    #[cfg(feature = "alloc")]
+    fn string_sort_with<C: Compare + ?Sized>(&mut self, cmp: &C);
+
+    /// Sorts the items using the provided comparator, which can be a `&dyn Compare`, with
+    /// `None` last.
+    ///
+    /// This sort is unstable: The original order of equal strings is not preserved.
+    /// It is slightly more efficient than the stable alternative.
+    fn string_sort_unstable_with<C: Compare + ?Sized>(&mut self, cmp: &C);
+}
+
+impl<A: AsRef<str>> OptionStringSort for [Option<A>] {
+    #[cfg(feature = "alloc")]
+    fn string_sort(&mut self, mut cmp: impl FnMut(&str, &str) -> Ordering) {
+        self.sort_by(|lhs, rhs| cmp_options(as_str(lhs), as_str(rhs), true, &mut cmp));
+    }
+
+    fn string_sort_unstable(&mut self, mut cmp: impl FnMut(&str, &str) -> Ordering) {
+        self.sort_unstable_by(|lhs, rhs| cmp_options(as_str(lhs), as_str(rhs), true, &mut cmp));
+    }
+
+    #[cfg(feature = "alloc")]
+    fn string_sort_none_first(&mut self, mut cmp: impl FnMut(&str, &str) -> Ordering) {
+        self.sort_by(|lhs, rhs| cmp_options(as_str(lhs), as_str(rhs), false, &mut cmp));
+    }
+
+    fn string_sort_unstable_none_first(&mut self, mut cmp: impl FnMut(&str, &str) -> Ordering) {
+        self.sort_unstable_by(|lhs, rhs| cmp_options(as_str(lhs), as_str(rhs), false, &mut cmp));
+    }
+
+    #[cfg(feature = "alloc")]
+    fn string_sort_with<C: Compare + ?Sized>(&mut self, cmp: &C) {
+        self.string_sort(|lhs, rhs| cmp.compare(lhs, rhs));
+    }
+
+    fn string_sort_unstable_with<C: Compare + ?Sized>(&mut self, cmp: &C) {
+        self.string_sort_unstable(|lhs, rhs| cmp.compare(lhs, rhs));
+    }
+}
+
+/// Returns a comparator for optional strings, which sorts `None` after all strings. Strings
+/// are compared with the given comparison function.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::{natural_lexical_cmp, none_last};
+///
+/// let mut rows = [(1, Some("T-20")), (2, None), (3, Some("T-5"))];
+/// let cmp = none_last(natural_lexical_cmp);
+/// rows.sort_by(|lhs, rhs| cmp(lhs.1, rhs.1));
+/// assert_eq!(rows, [(3, Some("T-5")), (1, Some("T-20")), (2, None)]);
+/// ```
+pub fn none_last<F>(cmp: F) -> impl Fn(Option<&str>, Option<&str>) -> Ordering
+where
+    F: Fn(&str, &str) -> Ordering,
+{
+    move |lhs, rhs| cmp_options(lhs, rhs, true, &cmp)
+}
+
+/// Returns a comparator for optional strings, which sorts `None` before all strings. Strings
+/// are compared with the given comparison function.
+pub fn none_first<F>(cmp: F) -> impl Fn(Option<&str>, Option<&str>) -> Ordering
+where
+    F: Fn(&str, &str) -> Ordering,
+{
+    move |lhs, rhs| cmp_options(lhs, rhs, false, &cmp)
+}
+
+/// Returns a comparator that sorts the empty string after all other strings. Other strings
+/// are compared with the given comparison function.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::{empty_last, lexical_cmp, StringSort};
+///
+/// let slice = &mut ["b", "", "A", ""];
+/// slice.string_sort(empty_last(lexical_cmp));
+/// assert_eq!(slice, &["A", "b", "", ""]);
+/// ```
+pub fn empty_last<F>(cmp: F) -> impl Fn(&str, &str) -> Ordering
+where
+    F: Fn(&str, &str) -> Ordering,
+{
+    move |lhs, rhs| match (lhs.is_empty(), rhs.is_empty()) {
+        (false, false) => cmp(lhs, rhs),
+        (l, r) => l.cmp(&r),
+    }
+}
+
+fn as_str<A: AsRef<str>>(s: &Option<A>) -> Option<&str> {
+    s.as_ref().map(AsRef::as_ref)
+}
+
+/// Compares optional strings, with `None` last if `none_last` is true, otherwise first
+fn cmp_options(
+    lhs: Option<&str>,
+    rhs: Option<&str>,
+    none_last: bool,
+    mut cmp: impl FnMut(&str, &str) -> Ordering,
+) -> Ordering {
+    match (lhs, rhs) {
+        (Some(lhs), Some(rhs)) => cmp(lhs, rhs),
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) if none_last => Ordering::Greater,
+        (None, Some(_)) => Ordering::Less,
+        (Some(_), None) if none_last => Ordering::Less,
+        (Some(_), None) => Ordering::Greater,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{lexical_cmp, natural_lexical_cmp, NaturalLexical, StringSort};
+
+    #[test]
+    fn test_option_string_sort() {
+        let unsorted = [
+            Some("T-20"),
+            None,
+            Some("b"),
+            Some(""),
+            None,
+            Some("T-5"),
+            Some("a"),
+        ];
+        let sorted = [
+            Some(""),
+            Some("a"),
+            Some("b"),
+            Some("T-5"),
+            Some("T-20"),
+            None,
+            None,
+        ];
+
+        let mut slice = unsorted;
+        slice.string_sort(natural_lexical_cmp);
+        assert_eq!(slice, sorted);
+        let mut slice = unsorted;
+        slice.string_sort_unstable(natural_lexical_cmp);
+        assert_eq!(slice, sorted);
+        let mut slice = unsorted;
+        slice.string_sort_with(&NaturalLexical);
+        assert_eq!(slice, sorted);
+        let mut slice = unsorted;
+        slice.string_sort_unstable_with(&NaturalLexical);
+        assert_eq!(slice, sorted);
+
+        let mut sorted = sorted;
+        sorted.rotate_right(2);
+        let mut slice = unsorted;
+        slice.string_sort_none_first(natural_lexical_cmp);
+        assert_eq!(slice, sorted);
+        let mut slice = unsorted;
+        slice.string_sort_unstable_none_first(natural_lexical_cmp);
+        assert_eq!(slice, sorted);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_option_string_sort_owned() {
+        let mut vec = vec![None, Some(String::from("b")), Some(String::from("A")), None];
+        vec.string_sort_unstable(lexical_cmp);
+        assert_eq!(vec, [Some("A".into()), Some("b".into()), None, None]);
+    }
+
+    #[test]
+    fn test_adapters() {
+        let cmp = none_last(lexical_cmp);
+        assert_eq!(cmp(Some("a"), Some("b")), Ordering::Less);
+        assert_eq!(cmp(Some("b"), Some("A")), Ordering::Greater);
+        assert_eq!(cmp(Some("z"), None), Ordering::Less);
+        assert_eq!(cmp(None, Some("")), Ordering::Greater);
+        assert_eq!(cmp(None, None), Ordering::Equal);
+
+        let cmp = none_first(lexical_cmp);
+        assert_eq!(cmp(Some("a"), Some("b")), Ordering::Less);
+        assert_eq!(cmp(Some(""), None), Ordering::Greater);
+        assert_eq!(cmp(None, Some("a")), Ordering::Less);
+        assert_eq!(cmp(None, None), Ordering::Equal);
+
+        let cmp = empty_last(lexical_cmp);
+        assert_eq!(cmp("a", "b"), Ordering::Less);
+        assert_eq!(cmp("", "z"), Ordering::Greater);
+        assert_eq!(cmp("z", ""), Ordering::Less);
+        assert_eq!(cmp("", ""), Ordering::Equal);
+        assert_eq!(cmp(" ", "a"), Ordering::Less);
+
+        let mut slice = ["", "T-20", "", "a", "T-5"];
+        slice.string_sort_unstable(empty_last(natural_lexical_cmp));
+        assert_eq!(slice, ["a", "T-5", "T-20", "", ""]);
+        let mut slice = ["", "T-20", "", "a", "T-5"];
+        slice.string_sort(empty_last(natural_lexical_cmp));
+        assert_eq!(slice, ["a", "T-5", "T-20", "", ""]);
+    }
+}
@@ -0,0 +1,165 @@
+//! Method syntax for the comparison functions.
+
+use crate::{lexical_cmp, lexical_eq, natural_lexical_cmp, natural_lexical_eq};
+use core::cmp::Ordering;
+
+/// An extension trait to compare strings with method syntax, e.g. `a.lexical_cmp(b)` instead
+/// of `lexical_cmp(a, b)`.
+///
+/// This trait is implemented for all types that implement `AsRef<str>`, e.g. `str`, `String`
+/// and `Cow<str>`. The methods delegate to the functions with the same name. The `_lt`, `_le`,
+/// `_gt` and `_ge` methods use the comparison function, so they consider the tie-break, but
+/// the `_eq` methods use the equality functions, which don't: `"Foo".lexical_eq("fóò")` is
+/// `true`, but so is `"Foo".lexical_lt("fóò")`.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::LexicalOrd;
+///
+/// let (a, b) = ("T-5", "t-20");
+/// if a.natural_lexical_lt(b) {
+///     println!("{} comes first", a);
+/// }
+///
+/// let files = ["T-20", "t-5", "T-100"];
+/// let last = files.iter().max_by(|a, b| a.natural_lexical_cmp(b));
+/// assert_eq!(last, Some(&"T-100"));
+///
+/// // orderings can be chained
+/// let people = [("Müller", "Zoë"), ("Adams", "Ben"), ("Müller", "Anna")];
+/// let last = people
+///     .iter()
+///     .max_by(|a, b| a.0.lexical_cmp(b.0).then_with(|| a.1.lexical_cmp(b.1)));
+/// assert_eq!(last, Some(&("Müller", "Zoë")));
+/// ```
+pub trait LexicalOrd: AsRef<str> {
+    /// Compares the strings like `lexical_cmp`
+    #[inline]
+    fn lexical_cmp(&self, other: &str) -> Ordering {
+        lexical_cmp(self.as_ref(), other)
+    }
+
+    /// Returns `true` if the strings are equal like `lexical_eq`, i.e. equal after
+    /// transliteration
+    #[inline]
+    fn lexical_eq(&self, other: &str) -> bool {
+        lexical_eq(self.as_ref(), other)
+    }
+
+    /// Returns `true` if this string is less than the other one according to `lexical_cmp`
+    #[inline]
+    fn lexical_lt(&self, other: &str) -> bool {
+        self.lexical_cmp(other) == Ordering::Less
+    }
+
+    /// Returns `true` if this string is less than or equal to the other one according to
+    /// `lexical_cmp`
+    #[inline]
+    fn lexical_le(&self, other: &str) -> bool {
+        self.lexical_cmp(other) != Ordering::Greater
+    }
+
+    /// Returns `true` if this string is greater than the other one according to `lexical_cmp`
+    #[inline]
+    fn lexical_gt(&self, other: &str) -> bool {
+        self.lexical_cmp(other) == Ordering::Greater
+    }
+
+    /// Returns `true` if this string is greater than or equal to the other one according to
+    /// `lexical_cmp`
+    #[inline]
+    fn lexical_ge(&self, other: &str) -> bool {
+        self.lexical_cmp(other) != Ordering::Less
+    }
+
+    /// Compares the strings like `natural_lexical_cmp`
+    #[inline]
+    fn natural_lexical_cmp(&self, other: &str) -> Ordering {
+        natural_lexical_cmp(self.as_ref(), other)
+    }
+
+    /// Returns `true` if the strings are equal like `natural_lexical_eq`, i.e. equal after
+    /// transliteration, with numbers compared by their value
+    #[inline]
+    fn natural_lexical_eq(&self, other: &str) -> bool {
+        natural_lexical_eq(self.as_ref(), other)
+    }
+
+    /// Returns `true` if this string is less than the other one according to
+    /// `natural_lexical_cmp`
+    #[inline]
+    fn natural_lexical_lt(&self, other: &str) -> bool {
+        self.natural_lexical_cmp(other) == Ordering::Less
+    }
+
+    /// Returns `true` if this string is less than or equal to the other one according to
+    /// `natural_lexical_cmp`
+    #[inline]
+    fn natural_lexical_le(&self, other: &str) -> bool {
+        self.natural_lexical_cmp(other) != Ordering::Greater
+    }
+
+    /// Returns `true` if this string is greater than the other one according to
+    /// `natural_lexical_cmp`
+    #[inline]
+    fn natural_lexical_gt(&self, other: &str) -> bool {
+        self.natural_lexical_cmp(other) == Ordering::Greater
+    }
+
+    /// Returns `true` if this string is greater than or equal to the other one according to
+    /// `natural_lexical_cmp`
+    #[inline]
+    fn natural_lexical_ge(&self, other: &str) -> bool {
+        self.natural_lexical_cmp(other) != Ordering::Less
+    }
+}
+
+impl<S: AsRef<str> + ?Sized> LexicalOrd for S {}
+
+#[cfg(test)]
+mod tests {
+    use super::LexicalOrd;
+    use core::cmp::Ordering;
+
+    #[test]
+    fn test_lexical_ord() {
+        let strings = [
+            "T-20", "b", "t-5", "", "Äpfel", "T-100", "apfel", "-", "T-5", "b", "ß", "ss", "Foo",
+            "fóò",
+        ];
+        for &a in strings.iter() {
+            for &b in strings.iter() {
+                let ordering = crate::lexical_cmp(a, b);
+                assert_eq!(a.lexical_cmp(b), ordering);
+                assert_eq!(a.lexical_eq(b), crate::lexical_eq(a, b));
+                assert_eq!(a.lexical_lt(b), ordering == Ordering::Less);
+                assert_eq!(a.lexical_le(b), ordering != Ordering::Greater);
+                assert_eq!(a.lexical_gt(b), ordering == Ordering::Greater);
+                assert_eq!(a.lexical_ge(b), ordering != Ordering::Less);
+
+                let ordering = crate::natural_lexical_cmp(a, b);
+                assert_eq!(a.natural_lexical_cmp(b), ordering);
+                assert_eq!(a.natural_lexical_eq(b), crate::natural_lexical_eq(a, b));
+                assert_eq!(a.natural_lexical_lt(b), ordering == Ordering::Less);
+                assert_eq!(a.natural_lexical_le(b), ordering != Ordering::Greater);
+                assert_eq!(a.natural_lexical_gt(b), ordering == Ordering::Greater);
+                assert_eq!(a.natural_lexical_ge(b), ordering != Ordering::Less);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", feature = "unicode"))]
+    fn test_lexical_ord_owned() {
+        use std::borrow::Cow;
+
+        let owned = String::from("fóò");
+        assert!(owned.lexical_eq("Foo"));
+        assert!(owned.lexical_gt("Foo"));
+        let cow: Cow<str> = Cow::Borrowed("T-5");
+        assert!(cow.natural_lexical_gt(&owned));
+        assert!(cow.natural_lexical_lt("T-20"));
+        assert!(Cow::<str>::Owned("T-20".into()).lexical_lt(&cow));
+    }
+}
@@ -0,0 +1,370 @@
+//! Parallel sorting with `rayon`.
+
+use crate::apply_permutation;
+use core::cmp::Ordering;
+use rayon::prelude::*;
+use std::path::Path;
+
+/// A trait to sort strings in parallel with `rayon`. This is like `StringSort`, but the
+/// functions must implement `Fn + Sync`, since they're called from multiple threads.
+///
+/// This trait is implemented for all slices whose inner type implements `AsRef<str> + Send`.
+/// It requires the `rayon` feature. Parallel sorting is only faster for large slices, e.g.
+/// with tens of thousands of strings.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::ParStringSort;
+///
+/// let mut vec: Vec<String> = (0..1000).rev().map(|i| format!("T-{}", i)).collect();
+/// vec.par_string_sort_unstable(lexical_sort::natural_lexical_cmp);
+///
+/// assert_eq!(vec[..3], ["T-0", "T-1", "T-2"]);
+/// ```
+pub trait ParStringSort {
+    /// Sorts the items in parallel using the provided comparison function.
+    ///
+    /// **This is a stable sort, which is often not required**.
+    /// You can use `par_string_sort_unstable` instead.
+    fn par_string_sort(&mut self, cmp: impl Fn(&str, &str) -> Ordering + Sync);
+
+    /// Sorts the items in parallel using the provided comparison function.
+    ///
+    /// This sort is unstable: The original order of equal strings is not preserved.
+    /// It is slightly more efficient than the stable alternative.
+    fn par_string_sort_unstable(&mut self, cmp: impl Fn(&str, &str) -> Ordering + Sync);
+
+    /// Sorts the items in parallel using the provided comparison function and another
+    /// function that is applied to each string before the comparison, like
+    /// `StringSort::string_sort_by`.
+    ///
+    /// **This is a stable sort, which is often not required**.
+    /// You can use `par_string_sort_unstable_by` instead.
+    fn par_string_sort_by<Cmp, Map>(&mut self, cmp: Cmp, map: Map)
+    where
+        Cmp: Fn(&str, &str) -> Ordering + Sync,
+        Map: Fn(&str) -> &str + Sync;
+
+    /// Sorts the items in parallel using the provided comparison function and another
+    /// function that is applied to each string before the comparison, like
+    /// `StringSort::string_sort_unstable_by`.
+    ///
+    /// This sort is unstable: The original order of equal strings is not preserved.
+    /// It is slightly more efficient than the stable alternative.
+    fn par_string_sort_unstable_by<Cmp, Map>(&mut self, cmp: Cmp, map: Map)
+    where
+        Cmp: Fn(&str, &str) -> Ordering + Sync,
+        Map: Fn(&str) -> &str + Sync;
+
+    /// Sorts the items in parallel using the provided comparison function and a key function
+    /// that is applied to each string once, like `StringSort::string_sort_by_cached_key`.
+    /// The keys are computed in parallel as well.
+    ///
+    /// **This is a stable sort, which is often not required**.
+    /// You can use `par_string_sort_unstable_by_cached_key` instead.
+    fn par_string_sort_by_cached_key<K, Cmp, Key>(&mut self, cmp: Cmp, key: Key)
+    where
+        K: AsRef<str> + Send,
+        Cmp: Fn(&str, &str) -> Ordering + Sync,
+        Key: Fn(&str) -> K + Sync;
+
+    /// Sorts the items in parallel using the provided comparison function and a key function
+    /// that is applied to each string once, like `par_string_sort_by_cached_key`.
+    ///
+    /// This sort is unstable: The original order of equal strings is not preserved.
+    /// It is slightly more efficient than the stable alternative.
+    fn par_string_sort_unstable_by_cached_key<K, Cmp, Key>(&mut self, cmp: Cmp, key: Key)
+    where
+        K: AsRef<str> + Send,
+        Cmp: Fn(&str, &str) -> Ordering + Sync,
+        Key: Fn(&str) -> K + Sync;
+}
+
+impl<A: AsRef<str> + Send> ParStringSort for [A] {
+    fn par_string_sort(&mut self, cmp: impl Fn(&str, &str) -> Ordering + Sync) {
+        self.par_sort_by(|lhs, rhs| cmp(lhs.as_ref(), rhs.as_ref()));
+    }
+
+    fn par_string_sort_unstable(&mut self, cmp: impl Fn(&str, &str) -> Ordering + Sync) {
+        self.par_sort_unstable_by(|lhs, rhs| cmp(lhs.as_ref(), rhs.as_ref()));
+    }
+
+    fn par_string_sort_by<Cmp, Map>(&mut self, cmp: Cmp, map: Map)
+    where
+        Cmp: Fn(&str, &str) -> Ordering + Sync,
+        Map: Fn(&str) -> &str + Sync,
+    {
+        self.par_sort_by(|lhs, rhs| cmp(map(lhs.as_ref()), map(rhs.as_ref())));
+    }
+
+    fn par_string_sort_unstable_by<Cmp, Map>(&mut self, cmp: Cmp, map: Map)
+    where
+        Cmp: Fn(&str, &str) -> Ordering + Sync,
+        Map: Fn(&str) -> &str + Sync,
+    {
+        self.par_sort_unstable_by(|lhs, rhs| cmp(map(lhs.as_ref()), map(rhs.as_ref())));
+    }
+
+    fn par_string_sort_by_cached_key<K, Cmp, Key>(&mut self, cmp: Cmp, key: Key)
+    where
+        K: AsRef<str> + Send,
+        Cmp: Fn(&str, &str) -> Ordering + Sync,
+        Key: Fn(&str) -> K + Sync,
+    {
+        par_sort_by_cached_key(self, |s| key(s.as_ref()), cmp, true);
+    }
+
+    fn par_string_sort_unstable_by_cached_key<K, Cmp, Key>(&mut self, cmp: Cmp, key: Key)
+    where
+        K: AsRef<str> + Send,
+        Cmp: Fn(&str, &str) -> Ordering + Sync,
+        Key: Fn(&str) -> K + Sync,
+    {
+        par_sort_by_cached_key(self, |s| key(s.as_ref()), cmp, false);
+    }
+}
+
+/// A trait to sort paths and OsStrings in parallel with `rayon`. This is like `PathSort`, but
+/// the functions must implement `Fn + Sync`, since they're called from multiple threads.
+///
+/// This trait is implemented for all slices whose inner type implements `AsRef<Path> + Send`.
+/// It requires the `rayon` feature.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::ParPathSort;
+/// use std::path::PathBuf;
+///
+/// let mut vec: Vec<PathBuf> = (0..1000).rev().map(|i| format!("img/{}.png", i).into()).collect();
+/// vec.par_path_sort(lexical_sort::natural_lexical_cmp);
+///
+/// assert_eq!(vec[..2], [PathBuf::from("img/0.png"), PathBuf::from("img/1.png")]);
+/// ```
+pub trait ParPathSort {
+    /// Sorts the items in parallel using the provided comparison function.
+    ///
+    /// **This is a stable sort, which is often not required**.
+    /// You can use `par_path_sort_unstable` instead.
+    fn par_path_sort(&mut self, cmp: impl Fn(&str, &str) -> Ordering + Sync);
+
+    /// Sorts the items in parallel using the provided comparison function.
+    ///
+    /// This sort is unstable: The original order of equal strings is not preserved.
+    /// It is slightly more efficient than the stable alternative.
+    fn par_path_sort_unstable(&mut self, cmp: impl Fn(&str, &str) -> Ordering + Sync);
+
+    /// Sorts the items in parallel using the provided comparison function and another
+    /// function that is applied to each string before the comparison, like
+    /// `PathSort::path_sort_by`.
+    ///
+    /// **This is a stable sort, which is often not required**.
+    /// You can use `par_path_sort_unstable_by` instead.
+    fn par_path_sort_by<Cmp, Map>(&mut self, cmp: Cmp, map: Map)
+    where
+        Cmp: Fn(&str, &str) -> Ordering + Sync,
+        Map: Fn(&str) -> &str + Sync;
+
+    /// Sorts the items in parallel using the provided comparison function and another
+    /// function that is applied to each string before the comparison, like
+    /// `PathSort::path_sort_unstable_by`.
+    ///
+    /// This sort is unstable: The original order of equal strings is not preserved.
+    /// It is slightly more efficient than the stable alternative.
+    fn par_path_sort_unstable_by<Cmp, Map>(&mut self, cmp: Cmp, map: Map)
+    where
+        Cmp: Fn(&str, &str) -> Ordering + Sync,
+        Map: Fn(&str) -> &str + Sync;
+
+    /// Sorts the items in parallel using the provided comparison function and a key function
+    /// that is applied to each path once, like `PathSort::path_sort_by_cached_key`.
+    /// The keys are computed in parallel as well.
+    ///
+    /// **This is a stable sort, which is often not required**.
+    /// You can use `par_path_sort_unstable_by_cached_key` instead.
+    fn par_path_sort_by_cached_key<K, Cmp, Key>(&mut self, cmp: Cmp, key: Key)
+    where
+        K: AsRef<str> + Send,
+        Cmp: Fn(&str, &str) -> Ordering + Sync,
+        Key: Fn(&str) -> K + Sync;
+
+    /// Sorts the items in parallel using the provided comparison function and a key function
+    /// that is applied to each path once, like `par_path_sort_by_cached_key`.
+    ///
+    /// This sort is unstable: The original order of equal strings is not preserved.
+    /// It is slightly more efficient than the stable alternative.
+    fn par_path_sort_unstable_by_cached_key<K, Cmp, Key>(&mut self, cmp: Cmp, key: Key)
+    where
+        K: AsRef<str> + Send,
+        Cmp: Fn(&str, &str) -> Ordering + Sync,
+        Key: Fn(&str) -> K + Sync;
+}
+
+impl<A: AsRef<Path> + Send> ParPathSort for [A] {
+    fn par_path_sort(&mut self, cmp: impl Fn(&str, &str) -> Ordering + Sync) {
+        self.par_sort_by(|lhs, rhs| {
+            cmp(
+                &lhs.as_ref().to_string_lossy(),
+                &rhs.as_ref().to_string_lossy(),
+            )
+        });
+    }
+
+    fn par_path_sort_unstable(&mut self, cmp: impl Fn(&str, &str) -> Ordering + Sync) {
+        self.par_sort_unstable_by(|lhs, rhs| {
+            cmp(
+                &lhs.as_ref().to_string_lossy(),
+                &rhs.as_ref().to_string_lossy(),
+            )
+        });
+    }
+
+    fn par_path_sort_by<Cmp, Map>(&mut self, cmp: Cmp, map: Map)
+    where
+        Cmp: Fn(&str, &str) -> Ordering + Sync,
+        Map: Fn(&str) -> &str + Sync,
+    {
+        self.par_sort_by(|lhs, rhs| {
+            cmp(
+                map(&lhs.as_ref().to_string_lossy()),
+                map(&rhs.as_ref().to_string_lossy()),
+            )
+        });
+    }
+
+    fn par_path_sort_unstable_by<Cmp, Map>(&mut self, cmp: Cmp, map: Map)
+    where
+        Cmp: Fn(&str, &str) -> Ordering + Sync,
+        Map: Fn(&str) -> &str + Sync,
+    {
+        self.par_sort_unstable_by(|lhs, rhs| {
+            cmp(
+                map(&lhs.as_ref().to_string_lossy()),
+                map(&rhs.as_ref().to_string_lossy()),
+            )
+        });
+    }
+
+    fn par_path_sort_by_cached_key<K, Cmp, Key>(&mut self, cmp: Cmp, key: Key)
+    where
+        K: AsRef<str> + Send,
+        Cmp: Fn(&str, &str) -> Ordering + Sync,
+        Key: Fn(&str) -> K + Sync,
+    {
+        par_sort_by_cached_key(self, |p| key(&p.as_ref().to_string_lossy()), cmp, true);
+    }
+
+    fn par_path_sort_unstable_by_cached_key<K, Cmp, Key>(&mut self, cmp: Cmp, key: Key)
+    where
+        K: AsRef<str> + Send,
+        Cmp: Fn(&str, &str) -> Ordering + Sync,
+        Key: Fn(&str) -> K + Sync,
+    {
+        par_sort_by_cached_key(self, |p| key(&p.as_ref().to_string_lossy()), cmp, false);
+    }
+}
+
+/// Sorts a slice in parallel by keys that are computed once per item, like
+/// `sort_by_cached_key`
+fn par_sort_by_cached_key<T: Send, K: AsRef<str> + Send>(
+    slice: &mut [T],
+    key: impl Fn(&T) -> K + Sync,
+    cmp: impl Fn(&str, &str) -> Ordering + Sync,
+    stable: bool,
+) {
+    let mut indices: Vec<(K, usize)> = slice
+        .par_iter_mut()
+        .enumerate()
+        .map(|(i, item)| (key(item), i))
+        .collect();
+    let cmp = |lhs: &(K, usize), rhs: &(K, usize)| cmp(lhs.0.as_ref(), rhs.0.as_ref());
+    if stable {
+        indices.par_sort_by(cmp);
+    } else {
+        indices.par_sort_unstable_by(cmp);
+    }
+    apply_permutation(slice, &mut indices);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{natural_lexical_cmp, PathSort, StringSort};
+
+    #[test]
+    fn test_par_sort() {
+        // enough strings that rayon splits the slice
+        let strings: Vec<String> = (0..5000_u32)
+            .map(|i| {
+                let i = i.wrapping_mul(2_654_435_761) % 10_007;
+                let prefix = ["T-", "t-", "Ä", "a", " x"][i as usize % 5];
+                format!("{}{}", prefix, i % 1000)
+            })
+            .collect();
+        let key = |s: &str| s.trim().to_lowercase();
+
+        let mut expected = strings.clone();
+        expected.string_sort(natural_lexical_cmp);
+        let mut vec = strings.clone();
+        vec.par_string_sort(natural_lexical_cmp);
+        assert_eq!(vec, expected);
+        let mut vec = strings.clone();
+        vec.par_string_sort_unstable(natural_lexical_cmp);
+        assert_eq!(vec, expected);
+
+        let mut expected = strings.clone();
+        expected.string_sort_by(natural_lexical_cmp, str::trim);
+        let mut vec = strings.clone();
+        vec.par_string_sort_by(natural_lexical_cmp, str::trim);
+        assert_eq!(vec, expected);
+        let mut vec = strings.clone();
+        vec.par_string_sort_unstable_by(natural_lexical_cmp, str::trim);
+        assert!(vec
+            .windows(2)
+            .all(|w| natural_lexical_cmp(w[0].trim(), w[1].trim()) != Ordering::Greater));
+
+        let mut expected = strings.clone();
+        expected.string_sort_by_cached_key(natural_lexical_cmp, key);
+        let mut vec = strings.clone();
+        vec.par_string_sort_by_cached_key(natural_lexical_cmp, key);
+        assert_eq!(vec, expected);
+        let mut vec = strings.clone();
+        vec.par_string_sort_unstable_by_cached_key(natural_lexical_cmp, key);
+        assert!(vec
+            .windows(2)
+            .all(|w| natural_lexical_cmp(&key(&w[0]), &key(&w[1])) != Ordering::Greater));
+
+        let paths: Vec<&Path> = strings.iter().map(Path::new).collect();
+        let mut expected = paths.clone();
+        expected.path_sort(natural_lexical_cmp);
+        let mut vec = paths.clone();
+        vec.par_path_sort(natural_lexical_cmp);
+        assert_eq!(vec, expected);
+        let mut vec = paths.clone();
+        vec.par_path_sort_unstable(natural_lexical_cmp);
+        assert_eq!(vec, expected);
+        let mut vec = paths.clone();
+        vec.par_path_sort_by(natural_lexical_cmp, str::trim_start);
+        let mut expected = paths.clone();
+        expected.path_sort_by(natural_lexical_cmp, str::trim_start);
+        assert_eq!(vec, expected);
+        let mut vec = paths.clone();
+        vec.par_path_sort_unstable_by(natural_lexical_cmp, str::trim_start);
+        assert!(vec.windows(2).all(|w| {
+            let (l, r) = (w[0].to_str().unwrap(), w[1].to_str().unwrap());
+            natural_lexical_cmp(l.trim_start(), r.trim_start()) != Ordering::Greater
+        }));
+        let mut expected = paths.clone();
+        expected.path_sort_by_cached_key(natural_lexical_cmp, key);
+        let mut vec = paths.clone();
+        vec.par_path_sort_by_cached_key(natural_lexical_cmp, key);
+        assert_eq!(vec, expected);
+        let mut vec = paths;
+        vec.par_path_sort_unstable_by_cached_key(natural_lexical_cmp, key);
+        assert!(vec.windows(2).all(|w| {
+            let (l, r) = (w[0].to_str().unwrap(), w[1].to_str().unwrap());
+            natural_lexical_cmp(&key(l), &key(r)) != Ordering::Greater
+        }));
+    }
+}
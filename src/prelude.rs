@@ -0,0 +1,21 @@
+//! The traits of this crate, for glob imports.
+//!
+//! The traits only add methods, so importing them doesn't shadow anything in scope:
+//!
+//! ```rust
+//! use lexical_sort::prelude::*;
+//!
+//! let mut names = vec!["T-20", "t-5", "Äpfel"];
+//! names.string_sort(lexical_sort::natural_lexical_cmp);
+//! assert!(names[0].natural_lexical_lt(names[1]));
+//! ```
+
+#[cfg(feature = "alloc")]
+pub use crate::LexicalSortedIterator;
+pub use crate::{
+    ByteSort, LexicalOrd, OptionStringSort, ReadingSort, SortByStrKey, StringSearch, StringSort,
+};
+#[cfg(feature = "std")]
+pub use crate::{LexicalSortedPathIterator, PathSort};
+#[cfg(all(feature = "rayon", feature = "std"))]
+pub use crate::{ParPathSort, ParStringSort};
@@ -0,0 +1,92 @@
+//! Dense ranks of strings in their sort order.
+
+use crate::collator::{Collator, TieBreak};
+use crate::compare::SortMode;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// Returns the 0-based dense rank of every string in the sort order of the given mode, in the
+/// order of the input slice. Strings that are equal share a rank, and the next distinct string
+/// has the next rank, so the ranks are `0, 1, 2, ...` without gaps.
+///
+/// `ties` decides which strings are equal: With `TieBreak::Bytes` (or a case-first tie-break),
+/// only identical strings are equal, like in the comparison functions of this crate. With
+/// `TieBreak::InputOrder`, strings that are equal after folding (e.g. `"Foo"` and `"fóò"`)
+/// are equal.
+///
+/// This sorts the indices of the strings, so it takes O(n log n) comparisons and allocates
+/// O(n) memory.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::{rank_strings, SortMode, TieBreak};
+///
+/// let strings = ["b", "Foo", "a", "fóò", "b"];
+/// assert_eq!(rank_strings(&strings, SortMode::Lexical, TieBreak::Bytes), [1, 2, 0, 3, 1]);
+/// assert_eq!(rank_strings(&strings, SortMode::Lexical, TieBreak::InputOrder), [1, 2, 0, 2, 1]);
+/// # }
+/// ```
+pub fn rank_strings<A: AsRef<str>>(strings: &[A], mode: SortMode, ties: TieBreak) -> Vec<usize> {
+    let collator = Collator::from(mode).ties(ties);
+    let cmp = |i: usize, j: usize| collator.cmp(strings[i].as_ref(), strings[j].as_ref());
+
+    let mut indices: Vec<usize> = (0..strings.len()).collect();
+    indices.sort_unstable_by(|&i, &j| cmp(i, j));
+
+    let mut ranks = vec![0; strings.len()];
+    let mut rank = 0;
+    for (k, w) in indices.windows(2).enumerate() {
+        if cmp(w[0], w[1]) != Ordering::Equal {
+            rank += 1;
+        }
+        ranks[indices[k + 1]] = rank;
+    }
+    ranks
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_rank_strings() {
+        let rank = |strings: &[&str], ties| rank_strings(strings, SortMode::NaturalLexical, ties);
+
+        assert!(rank(&[], TieBreak::Bytes).is_empty());
+        assert_eq!(rank(&["x"], TieBreak::Bytes), [0]);
+        assert_eq!(rank(&["T-20", "T-5", "T-100"], TieBreak::Bytes), [1, 0, 2]);
+
+        // duplicates
+        assert_eq!(
+            rank(&["b", "a", "b", "a", "c"], TieBreak::Bytes),
+            [1, 0, 1, 0, 2]
+        );
+        assert_eq!(rank(&["b", "b", "b"], TieBreak::InputOrder), [0, 0, 0]);
+
+        // strings that are equal after folding
+        let strings = ["Foo", "foo", "fóò", "bar", "FOO", "Bar", "baz"];
+        assert_eq!(rank(&strings, TieBreak::Bytes), [4, 5, 6, 1, 3, 0, 2]);
+        assert_eq!(rank(&strings, TieBreak::InputOrder), [2, 2, 2, 0, 2, 0, 1]);
+
+        // the ranks don't depend on the input order
+        let strings = [
+            "ä", "a", "A", "ß", "ss", "x10", "x9", "x09", "", "-", "Zoë", "zoe", "a", "ß",
+        ];
+        for &ties in &[TieBreak::Bytes, TieBreak::InputOrder] {
+            let expected = rank(&strings, ties);
+            let mut shuffled: Vec<(&str, usize)> = strings.iter().copied().zip(expected).collect();
+            for step in &[3, 5, 7, 11] {
+                for i in 0..shuffled.len() {
+                    let j = (i * step + 1) % shuffled.len();
+                    shuffled.swap(i, j);
+                }
+                let (input, expected): (Vec<&str>, Vec<usize>) = shuffled.iter().copied().unzip();
+                assert_eq!(rank(&input, ties), expected, "{:?}", input);
+            }
+        }
+    }
+}
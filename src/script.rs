@@ -0,0 +1,87 @@
+//! Scripts of characters, used to order strings that are equal after transliteration.
+
+/// A script (writing system), used to order strings that are equal after transliteration, or
+/// to group strings with `Collator::group_by_script`
+///
+/// Only a few scripts are distinguished; all other alphabetic characters belong to
+/// `Script::Other`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Script {
+    /// The Latin script, e.g. `A`, `ä` or `ß`
+    Latin,
+    /// The Greek script, e.g. `Α` or `ά`
+    Greek,
+    /// The Cyrillic script, e.g. `А` or `ж`
+    Cyrillic,
+    /// Han characters (Chinese characters, kanji and hanja), e.g. `北`
+    Han,
+    /// All other scripts
+    Other,
+}
+
+impl Script {
+    /// The default order of scripts: Latin, Greek, Cyrillic, Han, then all other scripts
+    pub const DEFAULT_ORDER: &'static [Script] = &[
+        Script::Latin,
+        Script::Greek,
+        Script::Cyrillic,
+        Script::Han,
+        Script::Other,
+    ];
+
+    /// Returns the script of a character, or `None` if it isn't alphabetic
+    pub fn of(c: char) -> Option<Script> {
+        if !c.is_alphabetic() {
+            return None;
+        }
+        Some(match c {
+            'A'..='Z'
+            | 'a'..='z'
+            | 'ª'
+            | 'º'
+            | '\u{C0}'..='\u{24F}'
+            | '\u{250}'..='\u{2AF}'
+            | '\u{1D00}'..='\u{1D25}'
+            | '\u{1E00}'..='\u{1EFF}'
+            | '\u{2C60}'..='\u{2C7F}'
+            | '\u{A720}'..='\u{A7FF}'
+            | '\u{AB30}'..='\u{AB64}'
+            | '\u{FB00}'..='\u{FB06}'
+            | '\u{FF21}'..='\u{FF3A}'
+            | '\u{FF41}'..='\u{FF5A}' => Script::Latin,
+            '\u{370}'..='\u{3FF}' | '\u{1F00}'..='\u{1FFF}' => Script::Greek,
+            '\u{400}'..='\u{52F}'
+            | '\u{1C80}'..='\u{1C8F}'
+            | '\u{2DE0}'..='\u{2DFF}'
+            | '\u{A640}'..='\u{A69F}' => Script::Cyrillic,
+            '\u{2E80}'..='\u{2FDF}'
+            | '\u{3005}'
+            | '\u{3007}'
+            | '\u{3021}'..='\u{3029}'
+            | '\u{3038}'..='\u{303B}'
+            | '\u{3400}'..='\u{4DBF}'
+            | '\u{4E00}'..='\u{9FFF}'
+            | '\u{F900}'..='\u{FAFF}'
+            | '\u{20000}'..='\u{3134F}' => Script::Han,
+            _ => Script::Other,
+        })
+    }
+}
+
+/// Returns whether a character is a Han character, hiragana or katakana
+pub(crate) fn is_cjk(c: char) -> bool {
+    Script::of(c) == Some(Script::Han)
+        || matches!(
+            c,
+            '\u{3041}'..='\u{30FF}' | '\u{31F0}'..='\u{31FF}' | '\u{FF66}'..='\u{FF9F}'
+        )
+}
+
+/// Returns the position of the script of the first alphabetic character in `order`.
+/// Scripts that aren't in `order` and strings without alphabetic characters come last.
+pub(crate) fn script_rank(mut chars: impl Iterator<Item = char>, order: &[Script]) -> usize {
+    chars
+        .find_map(Script::of)
+        .and_then(|script| order.iter().position(|&s| s == script))
+        .unwrap_or(order.len())
+}
@@ -0,0 +1,759 @@
+//! Matching of strings after folding them, e.g. for search-as-you-type.
+
+#[cfg(feature = "alloc")]
+use crate::cmp::char_rank;
+use crate::cmp::cmp_ascii_digits;
+use crate::collator::{Collator, Fold};
+use crate::compare::SortMode;
+#[cfg(feature = "alloc")]
+use alloc::collections::BinaryHeap;
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+use core::cmp::Ordering;
+use core::ops::{Bound, Range};
+
+impl Collator<'_> {
+    /// Returns `true` if `haystack` starts with `prefix` after folding both strings.
+    ///
+    /// The prefix may end in the middle of a transliteration, so `"s"` matches `"ß"` (which is
+    /// compared as `"ss"`), but `"sz"` doesn't.
+    pub fn starts_with(&self, haystack: &str, prefix: &str) -> bool {
+        self.strip_prefix(haystack, prefix).is_some()
+    }
+
+    /// If `haystack` starts with `prefix` after folding both strings, returns the byte offset
+    /// in `haystack` where the rest of the string starts.
+    ///
+    /// If the prefix ends in the middle of a transliteration, the offset is after the whole
+    /// transliterated character. Skipped characters after the prefix aren't included, so the
+    /// rest of the string may start with a skipped character.
+    pub fn strip_prefix(&self, haystack: &str, prefix: &str) -> Option<usize> {
+        let mut chars = self.fold(haystack);
+        let mut end = 0;
+        for p in self.fold(prefix) {
+            end = chars.next().filter(|h| h.c == p.c)?.end;
+        }
+        Some(end)
+    }
+
+    /// Returns the byte range of the first match of `needle` in `haystack`, after folding both
+    /// strings. See `lexical_find` for details.
+    pub fn find(&self, haystack: &str, needle: &str) -> Option<Range<usize>> {
+        if self.fold(needle).next().is_none() {
+            return Some(0..0);
+        }
+
+        let mut chars = self.fold(haystack);
+        loop {
+            let rest = chars.clone();
+            let start = chars.next()?;
+            if let Some(end) = self.match_at(rest, needle) {
+                return Some(start.index..end);
+            }
+        }
+    }
+
+    /// Returns `true` if `haystack` contains `needle` after folding both strings. See
+    /// `lexical_find` for details.
+    pub fn contains(&self, haystack: &str, needle: &str) -> bool {
+        self.find(haystack, needle).is_some()
+    }
+
+    /// Compares a string with the strings that start with `prefix` after folding. Returns
+    /// `Ordering::Equal` if the string starts with `prefix`, otherwise the ordering of the
+    /// string relative to all strings that start with `prefix`.
+    ///
+    /// In natural comparisons, a number at the end of the prefix must match a whole number.
+    pub(crate) fn cmp_prefix(&self, s: &str, prefix: &str) -> Ordering {
+        let mut iter1 = self.fold(s).peekable();
+        let mut iter2 = self.fold(prefix).peekable();
+        loop {
+            match (iter1.next(), iter2.next()) {
+                (Some(l), Some(r)) => {
+                    if self.natural && l.c.is_ascii_digit() && r.c.is_ascii_digit() {
+                        match cmp_ascii_digits(l, r, &mut iter1, &mut iter2) {
+                            (Ordering::Equal, _) => {}
+                            (ordering, _) => return ordering,
+                        }
+                    } else if l.c != r.c {
+                        return self.cmp_chars(l.c, r.c);
+                    }
+                }
+                (None, Some(_)) => return Ordering::Less,
+                (_, None) => return Ordering::Equal,
+            }
+        }
+    }
+
+    /// Returns `true` if `s` is within the bounds. See `lexically_between` for details.
+    pub fn between(&self, s: &str, lower: Bound<&str>, upper: Bound<&str>) -> bool {
+        let above = match lower {
+            Bound::Included(lower) => self.cmp(s, lower) != Ordering::Less,
+            Bound::Excluded(lower) => self.cmp(s, lower) == Ordering::Greater,
+            Bound::Unbounded => true,
+        };
+        above
+            && match upper {
+                Bound::Included(upper) => self.cmp(s, upper) != Ordering::Greater,
+                Bound::Excluded(upper) => self.cmp(s, upper) == Ordering::Less,
+                Bound::Unbounded => true,
+            }
+    }
+
+    /// Returns the smallest string after folding that is greater than all strings that start
+    /// with `prefix`. See `prefix_successor` for details.
+    ///
+    /// This only supports the options of `SortMode`, not e.g. `group_non_alnum`.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn prefix_successor(&self, prefix: &str) -> Option<String> {
+        let mut chars: Vec<char> = self.fold(prefix).map(|f| f.c).collect();
+
+        while let Some(c) = chars.pop() {
+            if self.natural && c.is_ascii_digit() {
+                // increment the number without its leading zeros, e.g. `19` to `20`, or `099`
+                // to `100`
+                chars.push(c);
+                let start = chars
+                    .iter()
+                    .rposition(|c| !c.is_ascii_digit())
+                    .map_or(0, |i| i + 1);
+                let zeros = chars[start..chars.len() - 1]
+                    .iter()
+                    .take_while(|&&d| d == '0')
+                    .count();
+                chars.drain(start..start + zeros);
+                let number = &mut chars[start..];
+                match number.iter().rposition(|&d| d != '9') {
+                    Some(i) => {
+                        number[i] = (number[i] as u8 + 1) as char;
+                        number[i + 1..].iter_mut().for_each(|d| *d = '0');
+                    }
+                    None => {
+                        number.iter_mut().for_each(|d| *d = '0');
+                        chars.insert(start, '1');
+                    }
+                }
+                return Some(chars.into_iter().collect());
+            }
+
+            // after a number, a digit would make the number greater instead
+            let after_number =
+                self.natural && matches!(chars.last(), Some(d) if d.is_ascii_digit());
+            if let Some(next) = self.next_char(c, after_number) {
+                chars.push(next);
+                return Some(chars.into_iter().collect());
+            }
+        }
+        None
+    }
+
+    /// Returns the smallest character that is greater than `c` and isn't changed by folding
+    #[cfg(feature = "alloc")]
+    fn next_char(&self, c: char, skip_digits: bool) -> Option<char> {
+        let rank = |d: char| if self.lexical { char_rank(d) } else { 0 };
+        let valid = |d: char, r: u8| {
+            rank(d) == r && !(skip_digits && d.is_ascii_digit()) && self.folds_to_itself(d)
+        };
+        let after = |start: u32, r: u8| {
+            (start..=char::MAX as u32)
+                .filter_map(char::from_u32)
+                .find(|&d| valid(d, r))
+        };
+
+        after(c as u32 + 1, rank(c)).or_else(|| (rank(c) + 1..=2).find_map(|r| after(0, r)))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn folds_to_itself(&self, c: char) -> bool {
+        let mut buf = [0; 4];
+        let mut fold = self.fold(c.encode_utf8(&mut buf));
+        matches!((fold.next(), fold.next()), (Some(f), None) if f.c == c)
+    }
+
+    /// Returns the end of the character where the match ends, if the non-empty `needle`
+    /// matches at the start of `chars`
+    fn match_at(&self, mut chars: Fold<'_>, needle: &str) -> Option<usize> {
+        let mut end = None;
+        for n in self.fold(needle) {
+            end = Some(chars.next().filter(|h| h.c == n.c)?.end);
+        }
+        end
+    }
+}
+
+/// Returns `true` if `haystack` starts with `prefix` after folding both strings with the given
+/// mode, e.g. `"Müller"` starts with `"mu"` in lexical modes.
+///
+/// The prefix may end in the middle of a transliteration, so `"s"` matches `"ß"` (which is
+/// compared as `"ss"`), but `"sz"` doesn't. To ignore non-alphanumeric characters, use a mode
+/// that skips them, such as `SortMode::LexicalOnlyAlnum`. Numbers are compared character by
+/// character, so `"1"` matches `"12"` even in natural modes.
+///
+/// This is equivalent to `Collator::from(mode).starts_with(haystack, prefix)`.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::{lexical_starts_with, SortMode};
+///
+/// assert!(lexical_starts_with("Müller", "mu", SortMode::Lexical));
+/// assert!(lexical_starts_with("Straße", "strass", SortMode::Lexical));
+/// assert!(!lexical_starts_with("Straße", "strasz", SortMode::Lexical));
+/// assert!(lexical_starts_with("O'Brien", "ob", SortMode::LexicalOnlyAlnum));
+/// # }
+/// ```
+pub fn lexical_starts_with(haystack: &str, prefix: &str, mode: SortMode) -> bool {
+    Collator::from(mode).starts_with(haystack, prefix)
+}
+
+/// If `haystack` starts with `prefix` after folding both strings with the given mode, returns
+/// the byte offset in `haystack` where the rest of the string starts.
+///
+/// If the prefix ends in the middle of a transliteration, the offset is after the whole
+/// transliterated character. The offset is also the length of the part of `haystack` that the
+/// prefix matched, e.g. to highlight it in autocomplete suggestions.
+///
+/// This is equivalent to `Collator::from(mode).strip_prefix(haystack, prefix)`.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::{lexical_strip_prefix, SortMode};
+///
+/// let haystack = "Ärztekammer";
+/// let offset = lexical_strip_prefix(haystack, "ARZTE", SortMode::Lexical).unwrap();
+/// assert_eq!(&haystack[offset..], "kammer");
+/// assert_eq!(lexical_strip_prefix(haystack, "aerzte", SortMode::Lexical), None);
+/// # }
+/// ```
+pub fn lexical_strip_prefix(haystack: &str, prefix: &str, mode: SortMode) -> Option<usize> {
+    Collator::from(mode).strip_prefix(haystack, prefix)
+}
+
+/// Returns the byte range of the first match of `needle` in `haystack`, after folding both
+/// strings with the given mode, e.g. `"uber"` is found in `"Die Über-Uhr"` in lexical modes.
+///
+/// The match may start or end in the middle of a transliteration, like in `lexical_starts_with`.
+/// Then the range covers the whole character of the original haystack: If `ß` is compared as
+/// `ss`, `"ass"` is found in `"Straße"` as `"aß"`, and `"s"` is found in `"ß"`. Skipped
+/// characters are never part of the range at its start or end. If the needle is empty after
+/// folding, the result is `Some(0..0)`.
+///
+/// This is equivalent to `Collator::from(mode).find(haystack, needle)`. It doesn't allocate
+/// memory, but takes O(n·m) time in the worst case.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::{lexical_find, SortMode};
+///
+/// let haystack = "Die Über-Uhr";
+/// let range = lexical_find(haystack, "uber", SortMode::Lexical).unwrap();
+/// assert_eq!(&haystack[range], "Über");
+///
+/// let range = lexical_find(haystack, "ruhr", SortMode::LexicalOnlyAlnum).unwrap();
+/// assert_eq!(&haystack[range], "r-Uhr");
+///
+/// let haystack = "Straße";
+/// let range = lexical_find(haystack, "ass", SortMode::Lexical).unwrap();
+/// assert_eq!(&haystack[range], "aß");
+/// # }
+/// ```
+pub fn lexical_find(haystack: &str, needle: &str, mode: SortMode) -> Option<Range<usize>> {
+    Collator::from(mode).find(haystack, needle)
+}
+
+/// Returns `true` if `haystack` contains `needle` after folding both strings with the given
+/// mode. See `lexical_find` for details.
+///
+/// This is equivalent to `Collator::from(mode).contains(haystack, needle)`.
+pub fn lexical_contains(haystack: &str, needle: &str, mode: SortMode) -> bool {
+    Collator::from(mode).contains(haystack, needle)
+}
+
+/// Returns `true` if `s` is within the bounds, when compared with the given mode. This is
+/// useful for keyset pagination, e.g. to select the strings after a cursor.
+///
+/// This is equivalent to `Collator::from(mode).between(s, lower, upper)`. To compare the
+/// strings after folding, without tie-breaks, use a `Collator` with `TieBreak::InputOrder`.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::{lexically_between, SortMode};
+/// use std::ops::Bound::{Excluded, Included, Unbounded};
+///
+/// assert!(lexically_between("Müller", Excluded("Meier"), Unbounded, SortMode::Lexical));
+/// assert!(lexically_between("T-5", Included("T-5"), Excluded("T-20"), SortMode::Natural));
+/// assert!(!lexically_between("T-5", Included("T-5"), Excluded("T-20"), SortMode::Plain));
+/// ```
+pub fn lexically_between(s: &str, lower: Bound<&str>, upper: Bound<&str>, mode: SortMode) -> bool {
+    Collator::from(mode).between(s, lower, upper)
+}
+
+/// Returns the smallest string after folding with the given mode that is greater than all
+/// strings that start with `prefix`, or `None` if there's no such string (e.g. if the prefix
+/// is empty after folding).
+///
+/// This is used to express the strings that start with a prefix as a half-open range: After
+/// folding, they are the strings that are at least `prefix` and less than the successor. The
+/// comparison must ignore tie-breaks, so `"Mu"` is in the range of `"mu"`. This can be done
+/// with a `Collator` with `TieBreak::InputOrder`.
+///
+/// In natural modes, a number at the end of the prefix must match a whole number, so the
+/// successor of `"x1"` is `"x2"`, and `"x10"` isn't in its range. Leading zeros are ignored,
+/// so the successor of `"x09"` is `"x10"`.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::{prefix_successor, Collator, SortMode, TieBreak};
+/// use std::ops::Bound::{Excluded, Included};
+///
+/// let successor = prefix_successor("Mü", SortMode::Lexical).unwrap();
+/// assert_eq!(successor, "mv");
+/// assert_eq!(prefix_successor("az", SortMode::Lexical).unwrap(), "a\u{5bd}");
+/// assert_eq!(prefix_successor("x19", SortMode::NaturalLexical).unwrap(), "x20");
+///
+/// let collator = Collator::new().ties(TieBreak::InputOrder);
+/// let in_range = |s| collator.between(s, Included("Mü"), Excluded(&successor));
+/// assert!(in_range("MU") && in_range("Mueller") && in_range("müller"));
+/// assert!(!in_range("Mt") && !in_range("mv"));
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+pub fn prefix_successor(prefix: &str, mode: SortMode) -> Option<String> {
+    Collator::from(mode).prefix_successor(prefix)
+}
+
+/// Returns the indices of the candidates that start with `query` after folding, like
+/// `lexical_starts_with`, sorted with the given mode and truncated to `limit`. If the query
+/// is empty, these are the first `limit` candidates in sorted order.
+///
+/// The candidates don't have to be sorted. Candidates that are equal are ordered by their
+/// index. This keeps only `limit` candidates in a heap, so it takes O(n log limit)
+/// comparisons and allocates O(limit) memory.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::{autocomplete, SortMode};
+///
+/// let candidates = ["Straßburg", "Stuttgart", "strasse", "Straubing", "Stralsund", "Bonn"];
+/// assert_eq!(autocomplete(&candidates, "stras", SortMode::Lexical, 10), [0, 2]);
+/// assert_eq!(autocomplete(&candidates, "STR", SortMode::Lexical, 3), [4, 0, 2]);
+/// assert_eq!(autocomplete(&candidates, "", SortMode::Lexical, 2), [5, 4]);
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+pub fn autocomplete<A: AsRef<str>>(
+    candidates: &[A],
+    query: &str,
+    mode: SortMode,
+    limit: usize,
+) -> Vec<usize> {
+    /// A candidate in the heap, which is a max-heap, so the greatest candidate is removed first
+    struct Candidate<'a> {
+        s: &'a str,
+        index: usize,
+        collator: &'a Collator<'a>,
+    }
+
+    impl Ord for Candidate<'_> {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.collator
+                .cmp(self.s, other.s)
+                .then(self.index.cmp(&other.index))
+        }
+    }
+
+    impl PartialOrd for Candidate<'_> {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl PartialEq for Candidate<'_> {
+        fn eq(&self, other: &Self) -> bool {
+            self.cmp(other) == Ordering::Equal
+        }
+    }
+
+    impl Eq for Candidate<'_> {}
+
+    if limit == 0 {
+        return Vec::new();
+    }
+    let collator = Collator::from(mode);
+    let mut heap = BinaryHeap::with_capacity(limit + 1);
+    for (index, s) in candidates.iter().enumerate() {
+        let s = s.as_ref();
+        if !collator.starts_with(s, query) {
+            continue;
+        }
+        let candidate = Candidate {
+            s,
+            index,
+            collator: &collator,
+        };
+        if heap.len() < limit {
+            heap.push(candidate);
+        } else if matches!(heap.peek(), Some(max) if candidate < *max) {
+            heap.pop();
+            heap.push(candidate);
+        }
+    }
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|c| c.index)
+        .collect()
+}
+
+#[cfg(all(test, feature = "unicode"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_starts_with() {
+        let starts_with =
+            |haystack, prefix| lexical_starts_with(haystack, prefix, SortMode::Lexical);
+
+        // expansions
+        assert!(starts_with("ßa", "s"));
+        assert!(starts_with("ßa", "ss"));
+        assert!(starts_with("ßa", "ssa"));
+        assert!(starts_with("ßa", "ß"));
+        assert!(!starts_with("ßa", "sz"));
+        assert!(!starts_with("s", "ß"));
+        assert!(starts_with("ss", "ß"));
+        assert!(starts_with("Æon", "ae"));
+        assert!(starts_with("aeon", "æ"));
+
+        // case and accents
+        assert!(starts_with("Müller", "mu"));
+        assert!(starts_with("Müller", "MÜ"));
+        assert!(starts_with("muller", "Mü"));
+        assert!(!lexical_starts_with("Müller", "mu", SortMode::Plain));
+        assert!(lexical_starts_with("Müller", "Mü", SortMode::Plain));
+
+        // skipped characters
+        assert!(!starts_with("O'Brien", "ob"));
+        assert!(lexical_starts_with(
+            "O'Brien",
+            "ob",
+            SortMode::LexicalOnlyAlnum
+        ));
+        assert!(lexical_starts_with(
+            "O'Brien",
+            "o-b",
+            SortMode::LexicalOnlyAlnum
+        ));
+        assert!(lexical_starts_with(
+            "x-12",
+            "x1",
+            SortMode::NaturalOnlyAlnum
+        ));
+        assert!(lexical_starts_with("F-5", "f5", SortMode::LexicalOnlyAlnum));
+
+        // numbers are compared character by character, also in natural modes
+        assert!(starts_with("10 files", "1"));
+        assert!(lexical_starts_with(
+            "10 files",
+            "1",
+            SortMode::NaturalLexical
+        ));
+        assert!(!lexical_starts_with(
+            "10 files",
+            "01",
+            SortMode::NaturalLexical
+        ));
+
+        // empty strings
+        assert!(starts_with("Müller", ""));
+        assert!(starts_with("", ""));
+        assert!(!starts_with("", "a"));
+        assert!(lexical_starts_with("abc", "--", SortMode::LexicalOnlyAlnum));
+    }
+
+    #[test]
+    fn test_strip_prefix() {
+        let strip = |haystack, prefix| lexical_strip_prefix(haystack, prefix, SortMode::Lexical);
+
+        assert_eq!(strip("Müller", "mu"), Some(3));
+        assert_eq!(strip("Müller", ""), Some(0));
+        assert_eq!(strip("Müller", "muller"), Some(7));
+        assert_eq!(strip("Müller", "mullers"), None);
+        assert_eq!(strip("ßa", "s"), Some(2));
+        assert_eq!(strip("ßa", "ss"), Some(2));
+        assert_eq!(strip("ßa", "ssa"), Some(3));
+        assert_eq!(strip("Überraschung", "uber"), Some(5));
+        assert_eq!(strip("STRASSE", "str"), Some(3));
+        assert_eq!(strip("Straße", "strass"), Some(6));
+
+        let strip =
+            |haystack, prefix| lexical_strip_prefix(haystack, prefix, SortMode::LexicalOnlyAlnum);
+        assert_eq!(strip("--a--b", "a"), Some(3));
+        assert_eq!(strip("--a--b", "-"), Some(0));
+        assert_eq!(strip("--a--b", "ab"), Some(6));
+        assert_eq!(strip("F-5 Tiger", "f5"), Some(3));
+        assert_eq!(strip("F-5 Tiger", "f"), Some(1));
+
+        let collator = Collator::new().token_replacements(&[("St", "Saint")]);
+        assert_eq!(collator.strip_prefix("St. Mary", "saint"), Some(3));
+        assert_eq!(collator.strip_prefix("St. Mary", "sai"), Some(3));
+        assert_eq!(collator.strip_prefix("St. Mary", "st"), Some(3));
+        assert_eq!(collator.strip_prefix("St. Mary", "ste"), None);
+    }
+
+    #[test]
+    fn test_find() {
+        let find = |haystack, needle| lexical_find(haystack, needle, SortMode::Lexical);
+
+        assert_eq!(find("Hauptstraße 5", "strasse"), Some(5..12));
+        assert_eq!(find("Hauptstraße 5", "STRAẞE"), Some(5..12));
+        assert_eq!(find("Hauptstraße 5", "straß"), Some(5..11));
+        assert_eq!(find("Über", "uber"), Some(0..5));
+        assert_eq!(find("Die Über-Uhr", "u"), Some(4..6));
+        assert_eq!(find("Die Über-Uhr", "r-u"), Some(8..11));
+        assert_eq!(find("banana", "ana"), Some(1..4));
+        assert_eq!(find("Æsop", "aes"), Some(0..3));
+        assert_eq!(find("abc", ""), Some(0..0));
+
+        // matches that start or end inside an expansion cover the whole character
+        assert_eq!(find("ß", "s"), Some(0..2));
+        assert_eq!(find("aßa", "sa"), Some(1..4));
+        assert_eq!(find("aßa", "as"), Some(0..3));
+        assert_eq!(find("Straße", "asse"), Some(3..7));
+        assert_eq!(find("Straße", "ass"), Some(3..6));
+        assert_eq!(find("Straße", "se"), Some(4..7));
+        assert_eq!(find("ßssß", "ss"), Some(0..2));
+        assert_eq!(find("sßs", "ss"), Some(0..3));
+        assert_eq!(find("Æsop", "es"), Some(0..3));
+        assert_eq!(find("Æsop", "eso"), Some(0..4));
+
+        // overlapping candidates
+        assert_eq!(find("aab", "ab"), Some(1..3));
+        assert_eq!(find("ababc", "abc"), Some(2..5));
+        assert_eq!(find("xßßt", "sst"), Some(3..6));
+        assert_eq!(find("xßßt", "ssst"), Some(1..6));
+
+        // negative cases
+        assert_eq!(find("Hauptstraße", "strasze"), None);
+        assert_eq!(find("", "a"), None);
+        assert_eq!(find("Über", "Überall"), None);
+        assert_eq!(lexical_find("Über", "uber", SortMode::Plain), None);
+        assert_eq!(lexical_find("Über", "Üb", SortMode::Plain), Some(0..3));
+
+        // skipped characters
+        let find = |haystack, needle| lexical_find(haystack, needle, SortMode::LexicalOnlyAlnum);
+        assert_eq!(find("--a--b--", "ab"), Some(2..6));
+        assert_eq!(find("--a--b--", "-"), Some(0..0));
+        assert_eq!(find("x-y-z", "y z"), Some(2..5));
+
+        assert!(lexical_contains(
+            "Die Über-Uhr",
+            "uberuhr",
+            SortMode::LexicalOnlyAlnum
+        ));
+        assert!(!lexical_contains(
+            "Die Über-Uhr",
+            "uberuhr",
+            SortMode::Lexical
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_prefix_successor() {
+        let successor = |prefix, mode| prefix_successor(prefix, mode);
+
+        assert_eq!(successor("mü", SortMode::Lexical).as_deref(), Some("mv"));
+        assert_eq!(successor("ß", SortMode::Lexical).as_deref(), Some("st"));
+        assert_eq!(successor("a9", SortMode::Lexical).as_deref(), Some("aa"));
+        assert_eq!(successor("a-", SortMode::Lexical).as_deref(), Some("a."));
+        assert_eq!(successor("a/", SortMode::Lexical).as_deref(), Some("a:"));
+        assert_eq!(
+            successor("az", SortMode::Lexical).as_deref(),
+            Some("a\u{5bd}")
+        );
+        assert_eq!(
+            successor("a\u{FFFD}", SortMode::Lexical).as_deref(),
+            Some("b")
+        );
+        assert_eq!(
+            successor("a-", SortMode::LexicalOnlyAlnum).as_deref(),
+            Some("b")
+        );
+        assert_eq!(successor("Mü", SortMode::Plain).as_deref(), Some("Mý"));
+        assert_eq!(successor("a9", SortMode::Plain).as_deref(), Some("a:"));
+        assert_eq!(successor("x9", SortMode::Natural).as_deref(), Some("x10"));
+        assert_eq!(
+            successor("x099", SortMode::Natural).as_deref(),
+            Some("x100")
+        );
+        assert_eq!(successor("x00", SortMode::Natural).as_deref(), Some("x1"));
+        assert_eq!(
+            successor("x199", SortMode::NaturalLexical).as_deref(),
+            Some("x200")
+        );
+        assert_eq!(
+            successor("1\u{10FFFF}", SortMode::NaturalLexical).as_deref(),
+            Some("1a")
+        );
+        assert_eq!(
+            successor("1\u{FFFD}", SortMode::NaturalLexical).as_deref(),
+            Some("2")
+        );
+        assert_eq!(successor("", SortMode::Lexical), None);
+        assert_eq!(successor("--", SortMode::LexicalOnlyAlnum), None);
+        assert_eq!(successor("\u{FFFD}", SortMode::Lexical), None);
+        assert_eq!(successor("\u{10FFFF}", SortMode::Plain), None);
+
+        let mut strings = [
+            "",
+            "-",
+            "Mu",
+            "MU",
+            "mü",
+            "Müller",
+            "Mueller",
+            "Mt",
+            "mv",
+            "Mv",
+            "M-v",
+            "m\u{FFFD}",
+            "ß",
+            "ss",
+            "sS",
+            "st",
+            "Straße",
+            "Strasse",
+            "s",
+            "sr",
+            "x1",
+            "x1a",
+            "x10",
+            "x2",
+            "x9",
+            "x00",
+            "x01",
+            "az",
+            "aZ",
+            "a\u{5bd}",
+            "a\u{5bd}b",
+            "b",
+            "a9",
+            "aa",
+            "a:",
+            "a/",
+            "~",
+        ];
+        let prefixes = [
+            "mu", "Mü", "m", "ss", "ß", "s", "a", "az", "a9", "x1", "x9", "x", "-", "m-", "", "~",
+        ];
+        let modes = [
+            SortMode::Plain,
+            SortMode::OnlyAlnum,
+            SortMode::Lexical,
+            SortMode::LexicalOnlyAlnum,
+            SortMode::Natural,
+            SortMode::NaturalOnlyAlnum,
+            SortMode::NaturalLexical,
+            SortMode::NaturalLexicalOnlyAlnum,
+        ];
+        for &mode in &modes {
+            let collator = Collator::from(mode).ties(crate::TieBreak::InputOrder);
+            strings.sort_unstable_by(|l, r| mode.function()(l, r));
+
+            for prefix in &prefixes {
+                let successor = prefix_successor(prefix, mode);
+                let upper = successor
+                    .as_deref()
+                    .map_or(Bound::Unbounded, Bound::Excluded);
+                for s in &strings {
+                    let expected = if mode.is_natural() {
+                        collator.cmp_prefix(s, prefix) == Ordering::Equal
+                    } else {
+                        lexical_starts_with(s, prefix, mode)
+                    };
+                    assert_eq!(
+                        collator.between(s, Bound::Included(prefix), upper),
+                        expected,
+                        "{:?}: {:?} with prefix {:?} and successor {:?}",
+                        mode,
+                        s,
+                        prefix,
+                        successor
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_autocomplete() {
+        let candidates = [
+            "Müller",
+            "Mueller",
+            "muller",
+            "Mull",
+            "Meier",
+            "ß-Bahn",
+            "ss",
+            "Ssangyong",
+            "Sz",
+            "s",
+            "Straße",
+            "Mü",
+            "Mühle",
+            "MÜLLER",
+        ];
+        let complete = |query, limit| autocomplete(&candidates, query, SortMode::Lexical, limit);
+        let names = |indices: Vec<usize>| -> Vec<&str> {
+            indices.into_iter().map(|i| candidates[i]).collect()
+        };
+
+        // accented candidates
+        assert_eq!(
+            names(complete("mü", 10)),
+            ["Mü", "Mueller", "Mühle", "Mull", "muller", "MÜLLER", "Müller"]
+        );
+        assert_eq!(names(complete("mue", 10)), ["Mueller"]);
+
+        // expansions
+        assert_eq!(names(complete("ss", 10)), ["ss", "ß-Bahn", "Ssangyong"]);
+        assert_eq!(names(complete("ß", 10)), ["ss", "ß-Bahn", "Ssangyong"]);
+        assert_eq!(names(complete("s", 3)), ["s", "ss", "ß-Bahn"]);
+
+        // the limit is smaller than the number of matches
+        assert_eq!(names(complete("m", 2)), ["Meier", "Mü"]);
+        assert!(complete("m", 0).is_empty());
+        assert_eq!(names(complete("", 3)), ["Meier", "Mü", "Mueller"]);
+        assert_eq!(complete("", 100).len(), candidates.len());
+        assert!(complete("x", 10).is_empty());
+
+        // equal candidates are ordered by their index
+        let candidates = ["b", "a", "b", "a"];
+        let complete = |mode| autocomplete(&candidates, "", mode, 3);
+        assert_eq!(complete(SortMode::Lexical), [1, 3, 0]);
+
+        // the result agrees with filtering and sorting
+        let candidates = ["x-10", "X9", "x9", "x-9", "x", "ẋ1", "xa", "x10"];
+        for &mode in &[SortMode::NaturalLexicalOnlyAlnum, SortMode::Plain] {
+            for limit in 0..10 {
+                let mut expected: Vec<usize> = (0..candidates.len())
+                    .filter(|&i| lexical_starts_with(candidates[i], "x", mode))
+                    .collect();
+                expected.sort_by(|&i, &j| mode.function()(candidates[i], candidates[j]));
+                expected.truncate(limit);
+                assert_eq!(autocomplete(&candidates, "x", mode, limit), expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,953 @@
+//! Precomputed keys that are compared like the comparison functions.
+
+use crate::cmp::{char_rank, Natural, NaturalChars};
+use crate::compare::SortMode;
+use crate::iter::{iterate_lexical, iterate_lexical_char, iterate_lexical_only_alnum};
+use crate::script::{script_rank, Script};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::Peekable;
+
+/// The number of bytes of a key that are stored without allocating
+const INLINE: usize = 46;
+
+/// The least common multiple of the denominators of all vulgar fractions, so the fractional
+/// part of every number is a whole multiple of `1 / DENOMINATOR`
+const DENOMINATOR: u32 = 2520;
+
+/// A sort key of a string, which is precomputed once, so comparing two keys is as fast as
+/// comparing two byte slices
+///
+/// Comparing two keys is consistent with the comparison function of the `SortMode` the keys
+/// were created with, including its tie-break: `SortKey::new(a, mode).cmp(&SortKey::new(b,
+/// mode))` is always the same as `mode.compare(a, b)`. Keys created with different modes
+/// shouldn't be compared with each other.
+///
+/// This is useful for sorting large slices, where every string would otherwise be
+/// transliterated in each of the `O(n log n)` comparisons. Use `[_]::sort_by_cached_key()`,
+/// which creates one key per item. The key is usually about four times as long as the string;
+/// keys of short ASCII strings are stored inline without allocating.
+///
+/// The first byte of a key is the version of its format, so keys that are stale after an
+/// update of this crate are detected: A key can be stored with `as_bytes` and restored with
+/// `from_bytes`, which fails for keys with another format version. With the optional `serde`
+/// feature, keys are serialized as a byte string in the same way. A key is formatted as
+/// hexadecimal digits with `Display`.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::SortKey;
+///
+/// let mut files = vec!["T-20", "t-5", "Ägypten", "T-100", "agypten"];
+/// files.sort_by_cached_key(|s| SortKey::natural_lexical(s));
+///
+/// assert_eq!(files, ["agypten", "Ägypten", "t-5", "T-20", "T-100"]);
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct SortKey(Buf);
+
+impl SortKey {
+    /// Creates the key of a string, which is compared like `mode.compare()` compares the
+    /// strings
+    pub fn new(s: &str, mode: SortMode) -> Self {
+        let mut buf = Buf::Inline(0, [0; INLINE]);
+        buf.push(FORMAT_VERSION);
+        push_key(&mut buf, s, mode);
+        if let Buf::Heap(vec) = &mut buf {
+            vec.shrink_to_fit();
+        }
+        SortKey(buf)
+    }
+
+    /// Creates the key of a string, which is compared like `lexical_cmp`
+    pub fn lexical(s: &str) -> Self {
+        SortKey::new(s, SortMode::Lexical)
+    }
+
+    /// Creates the key of a string, which is compared like `lexical_only_alnum_cmp`
+    pub fn lexical_only_alnum(s: &str) -> Self {
+        SortKey::new(s, SortMode::LexicalOnlyAlnum)
+    }
+
+    /// Creates the key of a string, which is compared like `natural_lexical_cmp`
+    pub fn natural_lexical(s: &str) -> Self {
+        SortKey::new(s, SortMode::NaturalLexical)
+    }
+
+    /// Creates the key of a string, which is compared like `natural_lexical_only_alnum_cmp`
+    pub fn natural_lexical_only_alnum(s: &str) -> Self {
+        SortKey::new(s, SortMode::NaturalLexicalOnlyAlnum)
+    }
+
+    /// Returns the bytes of the key, which are compared with `[u8]::cmp`
+    ///
+    /// The bytes can be stored and turned into a key again with `SortKey::from_bytes`. The
+    /// first byte is the version of the format of the key, which is followed by the collation
+    /// key of the string (see `collation_key`).
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+
+    /// Creates a key from the bytes returned by `SortKey::as_bytes`
+    ///
+    /// This fails if the bytes are too short to be a key, or if the key has another format
+    /// version, e.g. because it was created by a version of this crate with a different order.
+    /// Such keys must be recomputed from the strings. Note that other invalid bytes can't
+    /// always be detected; they are compared in an unspecified order.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::{SortKey, SortKeyError};
+    ///
+    /// let key = SortKey::natural_lexical("T-20");
+    /// let bytes = key.as_bytes().to_vec();
+    /// assert_eq!(SortKey::from_bytes(&bytes), Ok(key));
+    /// assert!(SortKey::from_bytes(&bytes).unwrap() > SortKey::natural_lexical("t-5"));
+    ///
+    /// assert_eq!(SortKey::from_bytes(b"garbage"), Err(SortKeyError::Version(b'g')));
+    /// assert_eq!(SortKey::from_bytes(&bytes[..1]), Err(SortKeyError::Truncated));
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SortKeyError> {
+        match bytes.first() {
+            None => return Err(SortKeyError::Truncated),
+            Some(&version) if version != FORMAT_VERSION => {
+                return Err(SortKeyError::Version(version))
+            }
+            // the compared characters are followed by a zero byte
+            Some(_) if !bytes[1..].contains(&0) => return Err(SortKeyError::Truncated),
+            Some(_) => {}
+        }
+        let mut buf = Buf::Inline(0, [0; INLINE]);
+        buf.extend(bytes);
+        if let Buf::Heap(vec) = &mut buf {
+            vec.shrink_to_fit();
+        }
+        Ok(SortKey(buf))
+    }
+}
+
+/// The error returned when `SortKey::from_bytes` gets bytes that aren't a valid key
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SortKeyError {
+    /// The bytes are too short to be a key
+    Truncated,
+    /// The key has another format version, which is the first byte
+    Version(u8),
+}
+
+impl fmt::Display for SortKeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SortKeyError::Truncated => f.write_str("truncated sort key"),
+            SortKeyError::Version(version) => write!(
+                f,
+                "stale sort key of format version {}, expected version {}",
+                version, FORMAT_VERSION
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SortKeyError {}
+
+impl PartialEq for SortKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl Eq for SortKey {}
+
+impl PartialOrd for SortKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SortKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_bytes().cmp(other.as_bytes())
+    }
+}
+
+impl Hash for SortKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_bytes().hash(state);
+    }
+}
+
+impl fmt::Debug for SortKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SortKey").field(&self.as_bytes()).finish()
+    }
+}
+
+/// Formats the bytes of the key as lowercase hexadecimal digits, for debugging
+impl fmt::Display for SortKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_bytes()
+            .iter()
+            .try_for_each(|b| write!(f, "{:02x}", b))
+    }
+}
+
+/// Returns a collation key of a string, a byte string whose byte-wise order is the order of
+/// the comparison function of the `SortMode`
+///
+/// This is the same as `SortKey::new(s, mode).as_bytes()` without the format version in its
+/// first byte, but the key can be stored, e.g. as the key in a key-value database such as
+/// sled or LMDB, so range scans return the strings in the order of the comparison function. `collation_key(a, mode).cmp(&collation_key(b,
+/// mode))` is always the same as `mode.compare(a, b)`, and distinct strings never have the
+/// same key.
+///
+/// ## Format
+///
+/// The key consists of these parts:
+///
+/// 1. The compared characters, e.g. the transliterated characters in lexical modes. Each
+///    character is a marker byte that isn't zero, followed by its UTF-8 bytes. The marker is
+///    the rank of the character, so e.g. punctuation sorts before letters in lexical modes.
+///    In natural modes, a number is the marker and `0`, followed by the number of digits and
+///    the digits of its integer part without leading zeros, and two bytes for the fractional
+///    part of vulgar fractions such as `½`, so `2` sorts before `10`.
+/// 2. A zero byte, so a string sorts before all strings that start with it.
+/// 3. The tie-break of the comparison function, e.g. the numbers of leading zeros of the
+///    numbers in natural modes, followed by the string itself.
+///
+/// The key isn't self-delimiting, so a key followed by other bytes doesn't sort like the key.
+/// When a collation key is part of a compound key, it should be the last part.
+///
+/// The format only changes in a new minor version of this crate (e.g. from 0.3 to 0.4),
+/// which also changes how the comparison functions order strings. Stored keys must be
+/// recomputed after such an update.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::{collation_key, SortMode};
+///
+/// let key = |s| collation_key(s, SortMode::NaturalLexical);
+/// assert!(key("T-2") < key("t-10"));
+/// assert!(key("T-10") < key("T-010"));
+/// assert!(key("Zürich") < key("zurich-2"));
+/// # }
+/// ```
+pub fn collation_key(s: &str, mode: SortMode) -> Vec<u8> {
+    let mut key = Vec::with_capacity(4 * s.len() + 2);
+    push_key(&mut key, s, mode);
+    key
+}
+
+/// Appends the collation key of a string to a `Vec`, like `collation_key`
+///
+/// This is useful to reuse a buffer, or to append the key to a prefix, e.g. of a compound
+/// key in a database.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::{collation_key, write_collation_key, SortMode};
+///
+/// let mut buf = b"users/".to_vec();
+/// write_collation_key(&mut buf, "Ægir", SortMode::Lexical);
+///
+/// assert_eq!(buf[6..], collation_key("Ægir", SortMode::Lexical)[..]);
+/// ```
+pub fn write_collation_key(buf: &mut Vec<u8>, s: &str, mode: SortMode) {
+    push_key(buf, s, mode);
+}
+
+/// Returns a number that is ordered like the first 8 characters of a string in `lexical_cmp`
+///
+/// If the prefix key of `a` is less than the prefix key of `b`, then `lexical_cmp(a, b)`
+/// returns `Ordering::Less`. If the keys are equal, the strings must be compared with
+/// `lexical_cmp`. Since comparing two numbers is much faster than comparing two strings, this
+/// can speed up sorting large slices, where most comparisons are decided by the first few
+/// characters. `StringSort::string_sort_fast` does this.
+///
+/// Every transliterated character is encoded in one byte, which contains its rank
+/// (non-alphanumeric characters come first) and the character itself if it's ASCII. Of other
+/// characters, only the rank is encoded, so the following characters are ignored.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::prefix_key;
+///
+/// assert!(prefix_key("Apfel") < prefix_key("banana"));
+/// assert!(prefix_key("Äpfel") < prefix_key("apple"));
+/// assert!(prefix_key("-5") < prefix_key("5"));
+///
+/// // the strings are equal in the first 8 characters
+/// assert_eq!(prefix_key("long string 1"), prefix_key("long string 2"));
+/// # }
+/// ```
+pub fn prefix_key(s: &str) -> u64 {
+    let mut bytes = [0; 8];
+    for (i, c) in iterate_lexical(s).take(8).enumerate() {
+        match prefix_byte(c) {
+            Ok(byte) => bytes[i] = byte,
+            Err(byte) => {
+                bytes[i] = byte;
+                // greater than all characters, but only compared with other keys that have
+                // the same inexact byte at the same position
+                bytes[i + 1..].iter_mut().for_each(|b| *b = u8::MAX);
+                break;
+            }
+        }
+    }
+    u64::from_be_bytes(bytes)
+}
+
+/// Returns the byte of a character in a prefix key, ordered like `ret_ordering`. If it's an
+/// error, the byte only encodes the rank of the character.
+fn prefix_byte(c: char) -> Result<u8, u8> {
+    // 0 is for the end of the string, and 255 for the rest of an inexact key
+    match char_rank(c) {
+        0 if c.is_ascii() => Ok(1 + c as u8), // 1..=128
+        0 => Err(129),
+        1 if c.is_ascii() => Ok(130 + (c as u8 - b'0')), // 130..=204
+        1 => Err(205),
+        _ => Ok(206), // only U+FFFD has this rank
+    }
+}
+
+/// A byte buffer that is stored inline until it gets too long
+#[derive(Clone)]
+enum Buf {
+    Inline(u8, [u8; INLINE]),
+    Heap(Vec<u8>),
+}
+
+impl Buf {
+    fn as_bytes(&self) -> &[u8] {
+        match self {
+            Buf::Inline(len, bytes) => &bytes[..usize::from(*len)],
+            Buf::Heap(vec) => vec,
+        }
+    }
+}
+
+/// A buffer that a key is appended to
+trait Sink {
+    fn len(&self) -> usize;
+
+    fn extend(&mut self, slice: &[u8]);
+
+    fn push(&mut self, byte: u8) {
+        self.extend(&[byte]);
+    }
+}
+
+impl Sink for Vec<u8> {
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn extend(&mut self, slice: &[u8]) {
+        self.extend_from_slice(slice);
+    }
+}
+
+impl Sink for Buf {
+    fn len(&self) -> usize {
+        self.as_bytes().len()
+    }
+
+    fn extend(&mut self, slice: &[u8]) {
+        match self {
+            Buf::Inline(len, bytes) => {
+                let start = usize::from(*len);
+                match bytes.get_mut(start..start + slice.len()) {
+                    Some(dest) => {
+                        dest.copy_from_slice(slice);
+                        *len += slice.len() as u8;
+                    }
+                    None => {
+                        let mut vec = Vec::with_capacity(2 * (start + slice.len()));
+                        vec.extend_from_slice(&bytes[..start]);
+                        vec.extend_from_slice(slice);
+                        *self = Buf::Heap(vec);
+                    }
+                }
+            }
+            Buf::Heap(vec) => vec.extend_from_slice(slice),
+        }
+    }
+}
+
+/// Appends the key of a string. It consists of the compared characters, followed by a zero
+/// byte, followed by the tie-break of the comparison function.
+///
+/// Every character is encoded as a marker byte and its UTF-8 bytes. The marker is the rank of
+/// the character in lexical comparisons, so non-alphanumeric characters sort first; it is
+/// never zero, so a string sorts before all strings that it is a prefix of.
+fn push_key(buf: &mut impl Sink, s: &str, mode: SortMode) {
+    let start = buf.len();
+    let ranked = mode.is_lexical() && !mode.is_only_alnum();
+    if mode.is_natural() {
+        push_natural(buf, s, mode, false);
+    } else if mode.is_lexical() {
+        let iter = match mode.is_only_alnum() {
+            true => iterate_lexical_only_alnum(s),
+            false => iterate_lexical(s),
+        };
+        iter.for_each(|c| push_char(buf, c, ranked));
+    } else {
+        let only_alnum = mode.is_only_alnum();
+        s.chars()
+            .filter(|c| !only_alnum || c.is_alphanumeric())
+            .for_each(|c| push_char(buf, c, ranked));
+    }
+    let blank = buf.len() == start;
+    buf.push(0);
+
+    match mode {
+        SortMode::Plain => {}
+        SortMode::Natural => {
+            push_natural(buf, s, mode, true);
+            buf.extend(s.as_bytes());
+        }
+        _ if blank => {
+            push_len(buf, s.chars().count());
+            buf.extend(s.as_bytes());
+        }
+        SortMode::OnlyAlnum => buf.extend(s.as_bytes()),
+        SortMode::Lexical => push_variants(buf, s),
+        SortMode::NaturalLexical => {
+            push_natural(buf, s, mode, true);
+            push_variants(buf, s);
+        }
+        SortMode::LexicalOnlyAlnum => push_key(buf, s, SortMode::Lexical),
+        SortMode::NaturalOnlyAlnum => push_key(buf, s, SortMode::Natural),
+        SortMode::NaturalLexicalOnlyAlnum => push_key(buf, s, SortMode::NaturalLexical),
+    }
+}
+
+/// Appends a character with its marker byte
+fn push_char(buf: &mut impl Sink, c: char, ranked: bool) {
+    buf.push(if ranked { char_rank(c) + 1 } else { 1 });
+    buf.extend(c.encode_utf8(&mut [0; 4]).as_bytes());
+}
+
+/// Appends the characters of a string in a natural comparison, or only the numbers of leading
+/// zeros of its numbers if `zeros` is true.
+///
+/// A number is encoded like the digit `0`, followed by its integer part without leading zeros
+/// and its fractional part, so it sorts like a digit compared to other characters, and by its
+/// value compared to other numbers.
+fn push_natural(buf: &mut impl Sink, s: &str, mode: SortMode, zeros: bool) {
+    let natural = Natural {
+        s,
+        lexical: mode.is_lexical(),
+        only_alnum: mode.is_only_alnum(),
+        ignore_whitespace: false,
+    };
+    let ranked = mode.is_lexical() && !mode.is_only_alnum();
+    let mut iter = natural.chars_from(0);
+
+    while let Some(first) = iter.next() {
+        if !first.c.is_ascii_digit() {
+            if !zeros {
+                push_char(buf, first.c, ranked);
+            }
+            continue;
+        }
+        let next_digit = |iter: &mut Peekable<NaturalChars<'_>>| {
+            iter.next_if(|c| c.kind == first.kind && c.c.is_ascii_digit())
+                .map(|c| c.c as u8)
+        };
+
+        if let Some((n, d)) = first.kind.single_value() {
+            if zeros {
+                push_len(buf, 0);
+            } else {
+                push_char(buf, '0', ranked);
+                push_decimal(buf, n / d);
+                let fraction = (n % d) * (DENOMINATOR / d);
+                buf.extend(&(fraction as u16).to_be_bytes());
+            }
+            continue;
+        }
+
+        let mut digit = first.c as u8;
+        let mut leading_zeros = 0;
+        while digit == b'0' {
+            match next_digit(&mut iter) {
+                Some(d) => {
+                    digit = d;
+                    leading_zeros += 1;
+                }
+                None => break,
+            }
+        }
+        if zeros {
+            push_len(buf, leading_zeros);
+            while next_digit(&mut iter).is_some() {}
+        } else {
+            let rest = iter
+                .clone()
+                .take_while(|c| c.kind == first.kind && c.c.is_ascii_digit());
+            push_char(buf, '0', ranked);
+            push_len(buf, 1 + rest.count());
+            buf.push(digit);
+            while let Some(d) = next_digit(&mut iter) {
+                buf.push(d);
+            }
+            buf.extend(&[0, 0]);
+        }
+    }
+}
+
+/// Appends a number of decimal digits, like a run of ASCII digits
+fn push_decimal(buf: &mut impl Sink, mut value: u32) {
+    let mut digits = [0; 10];
+    let mut start = digits.len();
+    loop {
+        start -= 1;
+        digits[start] = b'0' + (value % 10) as u8;
+        value /= 10;
+        if value == 0 {
+            break;
+        }
+    }
+    push_len(buf, digits.len() - start);
+    buf.extend(&digits[start..]);
+}
+
+/// Appends a length, so that greater lengths sort after smaller ones: The number of bytes,
+/// followed by the big-endian bytes without leading zeros
+fn push_len(buf: &mut impl Sink, len: usize) {
+    let bytes = (len as u64).to_be_bytes();
+    let skip = (len as u64).leading_zeros() as usize / 8;
+    buf.push((bytes.len() - skip) as u8);
+    buf.extend(&bytes[skip..]);
+}
+
+/// Appends the tie-break of strings that are equal after transliteration, like
+/// `cmp_variants`: The script rank, whether each transliterated character is from a
+/// non-ASCII character, and the string itself
+fn push_variants(buf: &mut impl Sink, s: &str) {
+    let rank = script_rank(s.chars(), Script::DEFAULT_ORDER);
+    buf.push(u8::try_from(rank).unwrap_or(u8::MAX));
+    for c in s.chars() {
+        let variant = if c.is_ascii() { 1 } else { 2 };
+        iterate_lexical_char(c).for_each(|_| buf.push(variant));
+    }
+    buf.push(0);
+    buf.extend(s.as_bytes());
+}
+
+/// The version of the format of sort keys, which is the first byte of each key. It must be
+/// incremented whenever the format changes.
+const FORMAT_VERSION: u8 = 1;
+
+/// Serializes the key as a byte string, which starts with the version of its format
+#[cfg(feature = "serde")]
+impl serde::Serialize for SortKey {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.as_bytes())
+    }
+}
+
+/// Deserializes a key written by the `Serialize` implementation. Like `SortKey::from_bytes`,
+/// this fails if the key was serialized by a version of this crate that uses another format,
+/// so stale keys are detected; they must be recomputed from the strings.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SortKey {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::{Error, SeqAccess, Visitor};
+
+        struct KeyVisitor;
+
+        impl<'de> Visitor<'de> for KeyVisitor {
+            type Value = SortKey;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("the bytes of a sort key")
+            }
+
+            fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<SortKey, E> {
+                SortKey::from_bytes(v).map_err(E::custom)
+            }
+
+            // formats such as JSON serialize bytes as a sequence of numbers
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<SortKey, A::Error> {
+                let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+                while let Some(byte) = seq.next_element()? {
+                    bytes.push(byte);
+                }
+                SortKey::from_bytes(&bytes).map_err(A::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_bytes(KeyVisitor)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::Compare;
+
+    const MODES: [SortMode; 8] = [
+        SortMode::Plain,
+        SortMode::OnlyAlnum,
+        SortMode::Lexical,
+        SortMode::LexicalOnlyAlnum,
+        SortMode::Natural,
+        SortMode::NaturalOnlyAlnum,
+        SortMode::NaturalLexical,
+        SortMode::NaturalLexicalOnlyAlnum,
+    ];
+
+    #[test]
+    fn test_sort_key() {
+        let strings = [
+            "",
+            " ",
+            "-",
+            "--",
+            "\t",
+            "\0",
+            "a",
+            "A",
+            "ä",
+            "Ä",
+            "aa",
+            "ß",
+            "ss",
+            "Sss",
+            "sß",
+            "a-b",
+            "ab",
+            "a b",
+            "Anna",
+            "Άννα",
+            "Анна",
+            "T-5",
+            "t-5",
+            "T-05",
+            "T-005",
+            "T-20",
+            "T-100",
+            "0",
+            "00",
+            "000",
+            "7",
+            "07",
+            "x1¹",
+            "x11",
+            "x½",
+            "x0",
+            "x1",
+            "x01",
+            "Ⅻ",
+            "12",
+            "xii",
+            "⑩",
+            "10",
+            "↉",
+            "x\u{300}",
+            "x",
+            "\u{FFFD}",
+            "a\u{FFFD}",
+            "１２",
+            "٣",
+            "3",
+            "¾",
+            "1¾",
+            "⅞",
+            "99999999999999999999",
+            "1e9",
+            "日本",
+            "z",
+        ];
+        for mode in MODES.iter() {
+            for lhs in strings.iter() {
+                for rhs in strings.iter() {
+                    assert_eq!(
+                        SortKey::new(lhs, *mode).cmp(&SortKey::new(rhs, *mode)),
+                        mode.compare(lhs, rhs),
+                        "{:?}: {:?} vs {:?}",
+                        mode,
+                        lhs,
+                        rhs,
+                    );
+                }
+            }
+        }
+
+        let mut strings = strings;
+        strings.sort_by_cached_key(|s| SortKey::natural_lexical(s));
+        assert!(strings
+            .windows(2)
+            .all(|w| crate::natural_lexical_cmp(w[0], w[1]) == Ordering::Less));
+    }
+
+    #[test]
+    fn test_collation_key() {
+        let mut buf = Vec::new();
+        for mode in MODES.iter() {
+            for s in ["", "a", "T-05", "Ⅻ½", "Ægir"].iter() {
+                let key = collation_key(s, *mode);
+                assert_eq!(key, SortKey::new(s, *mode).as_bytes()[1..]);
+
+                buf.clear();
+                buf.push(42);
+                write_collation_key(&mut buf, s, *mode);
+                assert_eq!(buf[1..], key[..]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sort_key_inline() {
+        let key = SortKey::lexical("Hello");
+        assert!(matches!(key.0, Buf::Inline(..)));
+        assert_eq!(key, SortKey::lexical("Hello"));
+        assert_ne!(key, SortKey::lexical("hello"));
+        assert_ne!(key.as_bytes(), SortKey::natural_lexical("hello").as_bytes());
+
+        let long = "Hello, world! ".repeat(10);
+        let key = SortKey::lexical(&long);
+        assert!(matches!(key.0, Buf::Heap(_)));
+        assert!(key > SortKey::lexical("Hello"));
+        assert_eq!(key.clone(), key);
+    }
+
+    #[test]
+    fn test_prefix_key() {
+        let strings = [
+            "",
+            "\u{300}",
+            " ",
+            "-",
+            "-5",
+            "0",
+            "5",
+            "A",
+            "a",
+            "aa",
+            "a-",
+            "ab",
+            "z",
+            "Z",
+            "\u{FFFD}",
+            "a\u{FFFD}",
+            "🎉",
+            "🎉a",
+            "🎉b",
+            "a🎉",
+            "a🎉b",
+            "12345678",
+            "12345678a",
+            "123456789",
+            "ꙮa",
+            "ꙮb",
+            "Äpfel",
+            "apple",
+            "abcdefghi",
+        ];
+        for &a in strings.iter() {
+            for &b in strings.iter() {
+                let ordering = prefix_key(a).cmp(&prefix_key(b));
+                if ordering != Ordering::Equal {
+                    assert_eq!(ordering, crate::lexical_cmp(a, b), "{:?} {:?}", a, b);
+                }
+            }
+        }
+        assert_eq!(prefix_key(""), 0);
+        assert_eq!(prefix_key("\u{300}"), 0);
+        assert_eq!(prefix_key("12345678"), prefix_key("123456789"));
+        assert_eq!(prefix_key("a🎉a"), prefix_key("a🎉b"));
+        assert!(prefix_key("a") < prefix_key("aa"));
+        assert!(prefix_key("z") < prefix_key("\u{FFFD}"));
+        assert!(prefix_key("a🎉") < prefix_key("aa"));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let long = "Hello, world! ".repeat(10);
+        for s in &["", "Hello", "T-5", &long] {
+            let key = SortKey::natural_lexical(s);
+            let json = serde_json::to_string(&key).unwrap();
+            assert!(json.starts_with("[1,"));
+            let deserialized: SortKey = serde_json::from_str(&json).unwrap();
+            assert_eq!(deserialized, key);
+            assert_eq!(
+                matches!(deserialized.0, Buf::Inline(..)),
+                matches!(key.0, Buf::Inline(..))
+            );
+
+            // a binary format, which serializes the key as a byte string
+            let bin = bincode::serialize(&key).unwrap();
+            assert_eq!(bin[8..], *key.as_bytes());
+            assert_eq!(bincode::deserialize::<SortKey>(&bin).unwrap(), key);
+        }
+
+        let stale = serde_json::from_str::<SortKey>("[0,104,0,104]").unwrap_err();
+        assert!(stale
+            .to_string()
+            .starts_with("stale sort key of format version 0, expected version 1"));
+        assert!(serde_json::from_str::<SortKey>("[1]").is_err());
+        assert!(serde_json::from_str::<SortKey>("[]").is_err());
+        assert!(serde_json::from_str::<SortKey>("[1,256,0]").is_err());
+        assert!(serde_json::from_str::<SortKey>("\"hello\"").is_err());
+
+        let stale = bincode::serialize(&serde_bytes(&[2, 104, 0, 104])).unwrap();
+        let error = bincode::deserialize::<SortKey>(&stale).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("stale sort key of format version 2"));
+        let truncated = bincode::serialize(&serde_bytes(&[1, 104])).unwrap();
+        let error = bincode::deserialize::<SortKey>(&truncated).unwrap_err();
+        assert!(error.to_string().contains("truncated sort key"));
+        assert!(bincode::deserialize::<SortKey>(b"garbage").is_err());
+        assert!(bincode::deserialize::<SortKey>(&[]).is_err());
+
+        // deserialized keys are compared like fresh keys
+        let strings = ["", "a", "A", "ä", "T-5", "t-20", "T-05", "ß", "ss", &long];
+        for &mode in MODES.iter() {
+            let keys: Vec<SortKey> = strings
+                .iter()
+                .map(|s| {
+                    let bin = bincode::serialize(&SortKey::new(s, mode)).unwrap();
+                    bincode::deserialize(&bin).unwrap()
+                })
+                .collect();
+            for (i, a) in strings.iter().enumerate() {
+                for (j, b) in strings.iter().enumerate() {
+                    let fresh = SortKey::new(a, mode).cmp(&SortKey::new(b, mode));
+                    assert_eq!(keys[i].cmp(&keys[j]), fresh, "{:?} {:?} {:?}", mode, a, b);
+                }
+            }
+        }
+    }
+
+    /// Returns a value that is serialized as a byte string
+    #[cfg(feature = "serde")]
+    fn serde_bytes(bytes: &[u8]) -> impl serde::Serialize + '_ {
+        struct Bytes<'a>(&'a [u8]);
+
+        impl serde::Serialize for Bytes<'_> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_bytes(self.0)
+            }
+        }
+
+        Bytes(bytes)
+    }
+
+    #[test]
+    fn test_from_bytes() {
+        let long = "Hello, world! ".repeat(10);
+        let strings = ["", "a", "A", "ä", "T-5", "t-20", "T-05", "ß", "ss", &long];
+        for &mode in MODES.iter() {
+            for a in &strings {
+                let key = SortKey::new(a, mode);
+                assert_eq!(key.as_bytes()[0], FORMAT_VERSION);
+                let copy = SortKey::from_bytes(key.as_bytes()).unwrap();
+                assert_eq!(copy, key);
+                assert_eq!(
+                    matches!(copy.0, Buf::Inline(..)),
+                    matches!(key.0, Buf::Inline(..))
+                );
+                for b in &strings {
+                    let other = SortKey::from_bytes(SortKey::new(b, mode).as_bytes()).unwrap();
+                    assert_eq!(copy.cmp(&other), mode.compare(a, b), "{:?} {:?}", a, b);
+                }
+            }
+        }
+
+        assert_eq!(SortKey::from_bytes(&[]), Err(SortKeyError::Truncated));
+        assert_eq!(SortKey::from_bytes(&[1]), Err(SortKeyError::Truncated));
+        assert_eq!(
+            SortKey::from_bytes(&[1, 2, 104]),
+            Err(SortKeyError::Truncated)
+        );
+        assert_eq!(SortKey::from_bytes(&[0, 0]), Err(SortKeyError::Version(0)));
+        assert_eq!(
+            SortKey::from_bytes(b"garbage"),
+            Err(SortKeyError::Version(b'g'))
+        );
+        assert_eq!(
+            SortKeyError::Version(2).to_string(),
+            "stale sort key of format version 2, expected version 1"
+        );
+        assert!(SortKey::from_bytes(&[1, 0]).is_ok());
+    }
+
+    #[test]
+    fn test_display() {
+        let key = SortKey::from_bytes(&[1, 0, 0xab, 0xff]).unwrap();
+        assert_eq!(key.to_string(), "0100abff");
+        let key = SortKey::lexical("Hello");
+        let hex: String = key
+            .as_bytes()
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect();
+        assert_eq!(key.to_string(), hex);
+        assert!(key.to_string().starts_with("01"));
+    }
+
+    mod proptests {
+        use super::MODES;
+        use crate::{
+            collation_key, lexical_cmp, natural_lexical_cmp, prefix_key, Compare, SortKey, SortMode,
+        };
+        use core::cmp::Ordering;
+        use proptest::prelude::*;
+
+        fn natural_lexical_key(s: &str) -> Vec<u8> {
+            collation_key(s, SortMode::NaturalLexical)
+        }
+
+        proptest! {
+            #[test]
+            fn sort_key_consistent(
+                a in "([0-9]{1,25}|[-sSaäß\u{300}½¹Ⅻ\u{FFFD}٣ !])*",
+                b in "([0-9]{1,25}|[-sSaäß\u{300}½¹Ⅻ\u{FFFD}٣ !])*",
+            ) {
+                for mode in MODES.iter() {
+                    let ordering = SortKey::new(&a, *mode).cmp(&SortKey::new(&b, *mode));
+                    prop_assert_eq!(ordering, mode.compare(&a, &b), "{:?}", mode);
+                }
+            }
+
+            #[test]
+            fn prefix_key_consistent(
+                a in "([-sSaäß\u{300}\u{FFFD}٣ !~]|\\PC){0,12}",
+                b in "([-sSaäß\u{300}\u{FFFD}٣ !~]|\\PC){0,12}",
+            ) {
+                let ordering = prefix_key(&a).cmp(&prefix_key(&b));
+                if ordering != Ordering::Equal {
+                    prop_assert_eq!(ordering, lexical_cmp(&a, &b));
+                }
+            }
+
+            #[test]
+            fn collation_key_consistent(
+                a in "([0-9]{1,20}|[-sSaäß ½Ⅻ¹]|\\PC){0,12}",
+                b in "([0-9]{1,20}|[-sSaäß ½Ⅻ¹]|\\PC){0,12}",
+            ) {
+                let (key1, key2) = (natural_lexical_key(&a), natural_lexical_key(&b));
+                prop_assert_eq!(key1.cmp(&key2), natural_lexical_cmp(&a, &b));
+                let sort_key = SortKey::natural_lexical(&a);
+                prop_assert_eq!(&key1[..], &sort_key.as_bytes()[1..]);
+            }
+        }
+    }
+}
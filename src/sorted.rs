@@ -0,0 +1,231 @@
+//! Sorting of iterators.
+
+#[cfg(feature = "std")]
+use crate::PathSort;
+use crate::{lexical_cmp, StringSort};
+use alloc::vec::{self, Vec};
+use core::cmp::Ordering;
+#[cfg(feature = "std")]
+use std::path::Path;
+
+/// An extension trait to sort the items of an iterator in the middle of an iterator chain.
+///
+/// This trait is implemented for all iterators whose items implement `AsRef<str>`. The methods
+/// are adapters like `Iterator::filter`, but they aren't lazy: They collect _all_ items into a
+/// `Vec` and sort it when they are called, and return an iterator over the sorted `Vec`. So
+/// the iterator must be finite, and the memory required is proportional to its length. If you
+/// only need the first few items, `StringSort::string_smallest_k` is faster.
+///
+/// If you want to sort file paths or OsStrings, use `LexicalSortedPathIterator` instead.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::{natural_lexical_cmp, LexicalSortedIterator};
+///
+/// let files = ["T-20", "t-5", "Äpfel", "T-100", "apple.txt"];
+/// let sorted: Vec<_> = files
+///     .iter()
+///     .filter(|s| !s.ends_with(".txt"))
+///     .sorted_by_cmp(natural_lexical_cmp)
+///     .take(3)
+///     .collect();
+///
+/// assert_eq!(sorted, [&"Äpfel", &"t-5", &"T-20"]);
+/// # }
+/// ```
+pub trait LexicalSortedIterator: Iterator {
+    /// Collects and sorts the items with `lexical_cmp`, and returns an iterator over them.
+    ///
+    /// This is a stable sort, so equal strings keep their order.
+    fn sorted_lexically(self) -> vec::IntoIter<Self::Item>;
+
+    /// Collects and sorts the items with the provided comparison function, and returns an
+    /// iterator over them.
+    ///
+    /// **This is a stable sort, which is often not required**.
+    /// You can use `sorted_unstable_by_cmp` instead.
+    fn sorted_by_cmp(self, cmp: impl FnMut(&str, &str) -> Ordering) -> vec::IntoIter<Self::Item>;
+
+    /// Collects and sorts the items with the provided comparison function, and returns an
+    /// iterator over them.
+    ///
+    /// This sort is unstable: The original order of equal strings is not preserved.
+    /// It is slightly more efficient than the stable alternative.
+    fn sorted_unstable_by_cmp(
+        self,
+        cmp: impl FnMut(&str, &str) -> Ordering,
+    ) -> vec::IntoIter<Self::Item>;
+}
+
+impl<I> LexicalSortedIterator for I
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+    fn sorted_lexically(self) -> vec::IntoIter<I::Item> {
+        self.sorted_by_cmp(lexical_cmp)
+    }
+
+    fn sorted_by_cmp(self, cmp: impl FnMut(&str, &str) -> Ordering) -> vec::IntoIter<I::Item> {
+        let mut vec: Vec<I::Item> = self.collect();
+        vec.string_sort(cmp);
+        vec.into_iter()
+    }
+
+    fn sorted_unstable_by_cmp(
+        self,
+        cmp: impl FnMut(&str, &str) -> Ordering,
+    ) -> vec::IntoIter<I::Item> {
+        let mut vec: Vec<I::Item> = self.collect();
+        vec.string_sort_unstable(cmp);
+        vec.into_iter()
+    }
+}
+
+/// An extension trait to sort paths in the middle of an iterator chain, like
+/// `LexicalSortedIterator`.
+///
+/// This trait is implemented for all iterators whose items implement `AsRef<Path>`. Like
+/// `LexicalSortedIterator`, its methods collect _all_ items into a `Vec` when they are called.
+/// The paths are converted with `to_string_lossy()` in each comparison, like in `PathSort`.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "unicode")] {
+/// use lexical_sort::{natural_lexical_cmp, LexicalSortedPathIterator};
+/// use std::path::PathBuf;
+///
+/// # let dir = std::env::temp_dir().join("lexical-sort-doctest-sorted");
+/// # let _ = std::fs::remove_dir_all(&dir);
+/// # std::fs::create_dir_all(&dir)?;
+/// # for name in &["T-20", "t-5", "Äpfel"] {
+/// #     std::fs::write(dir.join(name), "")?;
+/// # }
+/// let paths: Vec<PathBuf> = std::fs::read_dir(&dir)?
+///     .map(|entry| entry.map(|entry| entry.path()))
+///     .collect::<Result<Vec<_>, _>>()?
+///     .into_iter()
+///     .path_sorted_by_cmp(natural_lexical_cmp)
+///     .collect();
+///
+/// assert_eq!(paths, [dir.join("Äpfel"), dir.join("t-5"), dir.join("T-20")]);
+/// # std::fs::remove_dir_all(&dir)?;
+/// # }
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[cfg(feature = "std")]
+pub trait LexicalSortedPathIterator: Iterator {
+    /// Collects and sorts the paths with `lexical_cmp`, and returns an iterator over them.
+    ///
+    /// This is a stable sort, so equal paths keep their order.
+    fn path_sorted_lexically(self) -> vec::IntoIter<Self::Item>;
+
+    /// Collects and sorts the paths with the provided comparison function, and returns an
+    /// iterator over them.
+    ///
+    /// **This is a stable sort, which is often not required**.
+    /// You can use `path_sorted_unstable_by_cmp` instead.
+    fn path_sorted_by_cmp(
+        self,
+        cmp: impl FnMut(&str, &str) -> Ordering,
+    ) -> vec::IntoIter<Self::Item>;
+
+    /// Collects and sorts the paths with the provided comparison function, and returns an
+    /// iterator over them.
+    ///
+    /// This sort is unstable: The original order of equal paths is not preserved.
+    /// It is slightly more efficient than the stable alternative.
+    fn path_sorted_unstable_by_cmp(
+        self,
+        cmp: impl FnMut(&str, &str) -> Ordering,
+    ) -> vec::IntoIter<Self::Item>;
+}
+
+#[cfg(feature = "std")]
+impl<I> LexicalSortedPathIterator for I
+where
+    I: Iterator,
+    I::Item: AsRef<Path>,
+{
+    fn path_sorted_lexically(self) -> vec::IntoIter<I::Item> {
+        self.path_sorted_by_cmp(lexical_cmp)
+    }
+
+    fn path_sorted_by_cmp(self, cmp: impl FnMut(&str, &str) -> Ordering) -> vec::IntoIter<I::Item> {
+        let mut vec: Vec<I::Item> = self.collect();
+        vec.path_sort(cmp);
+        vec.into_iter()
+    }
+
+    fn path_sorted_unstable_by_cmp(
+        self,
+        cmp: impl FnMut(&str, &str) -> Ordering,
+    ) -> vec::IntoIter<I::Item> {
+        let mut vec: Vec<I::Item> = self.collect();
+        vec.path_sort_unstable(cmp);
+        vec.into_iter()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{cmp, natural_lexical_cmp};
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_sorted_iterator() {
+        let strings = [
+            "T-20", "b", "t-5", "", "Äpfel", "T-100", "apfel", "-", "T-5", "b", "ß", "ss",
+        ];
+
+        for &cmp in [natural_lexical_cmp, lexical_cmp, cmp].iter() {
+            let mut sorted = strings;
+            sorted.string_sort(cmp);
+            assert!(strings.iter().sorted_by_cmp(cmp).eq(sorted.iter()));
+            assert!(strings.iter().sorted_unstable_by_cmp(cmp).eq(sorted.iter()));
+
+            let owned = strings.iter().map(|s| s.to_string());
+            assert!(owned.sorted_by_cmp(cmp).eq(sorted.iter().copied()));
+
+            let paths = strings.iter().map(PathBuf::from);
+            assert!(paths
+                .path_sorted_unstable_by_cmp(cmp)
+                .eq(sorted.iter().map(PathBuf::from)));
+        }
+
+        let mut sorted = strings;
+        sorted.string_sort(lexical_cmp);
+        assert!(strings.iter().sorted_lexically().eq(sorted.iter()));
+        let paths = strings.iter().map(Path::new);
+        assert!(paths
+            .path_sorted_lexically()
+            .eq(sorted.iter().map(Path::new)));
+
+        // mid-chain, with equal strings in their original order
+        let case_insensitive = |lhs: &str, rhs: &str| lhs.to_lowercase().cmp(&rhs.to_lowercase());
+        let words = ["B", "c", "a", "b", "A", "d", "C"];
+        let chained: Vec<&str> = words
+            .iter()
+            .copied()
+            .filter(|s| *s != "c")
+            .sorted_by_cmp(case_insensitive)
+            .take(5)
+            .collect();
+        assert_eq!(chained, ["a", "A", "B", "b", "C"]);
+        let chained: Vec<&Path> = words
+            .iter()
+            .map(Path::new)
+            .path_sorted_by_cmp(case_insensitive)
+            .skip(1)
+            .step_by(2)
+            .collect();
+        assert_eq!(chained, [Path::new("A"), Path::new("b"), Path::new("C")]);
+
+        let empty: [&str; 0] = [];
+        assert_eq!(empty.iter().sorted_lexically().next(), None);
+    }
+}
@@ -0,0 +1,240 @@
+//! Language-specific tailorings of the lexical order.
+
+/// A tailoring of the lexical order for a language, used with `Collator::tailoring`
+///
+/// A tailoring consists of two tables, which only apply in lexical comparisons:
+///
+/// - Expansions: Characters that are compared like a string instead of their
+///   transliteration, e.g. `('ä', "ae")` in German phone books.
+/// - Letters: Characters that are separate letters, sorted right after another letter, e.g.
+///   `('ñ', 'n')` in Spanish, so `"nz" < "ña" < "o"`. Letters that follow the same letter are
+///   sorted in the order of the table.
+///
+/// Characters are matched in lowercase, so the tables should only contain lowercase
+/// characters. The letters are compared after transliteration, so they should follow a
+/// lowercase ASCII letter. Expansions can contain separate letters, e.g. `('æ', "ä")` in
+/// Swedish. Characters that are decomposed, e.g. `n` followed by a combining tilde, aren't
+/// tailored.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::{Collator, StringSort, Tailoring};
+///
+/// // Swedish, but `w` is sorted like `v`
+/// let tailoring = Tailoring::new(&[('w', "v")], Tailoring::SWEDISH.letters());
+///
+/// let slice = &mut ["Öberg", "Wall", "Ahlin", "Åberg", "Vik", "Zetterberg", "Ärlig"];
+/// slice.string_sort_with(&Collator::new().tailoring(tailoring));
+/// assert_eq!(slice, &["Ahlin", "Wall", "Vik", "Zetterberg", "Åberg", "Ärlig", "Öberg"]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Tailoring<'a> {
+    expansions: &'a [(char, &'a str)],
+    letters: &'a [(char, char)],
+}
+
+impl<'a> Tailoring<'a> {
+    /// Creates a tailoring with the given expansions and letters
+    pub const fn new(expansions: &'a [(char, &'a str)], letters: &'a [(char, char)]) -> Self {
+        Tailoring {
+            expansions,
+            letters,
+        }
+    }
+
+    /// Returns the characters that are compared like a string
+    pub const fn expansions(&self) -> &'a [(char, &'a str)] {
+        self.expansions
+    }
+
+    /// Returns the characters that are separate letters, and the letters they follow
+    pub const fn letters(&self) -> &'a [(char, char)] {
+        self.letters
+    }
+
+    /// Returns the string that a character is compared like, if it is expanded
+    pub(crate) fn expansion(&self, c: char) -> Option<&'a str> {
+        let c = lowercase(c);
+        self.expansions
+            .iter()
+            .find(|&&(e, _)| e == c)
+            .map(|&(_, expansion)| expansion)
+    }
+
+    /// Returns the character in lowercase, if it is a separate letter
+    pub(crate) fn letter(&self, c: char) -> Option<char> {
+        let c = lowercase(c);
+        self.letters.iter().find(|&&(l, _)| l == c).map(|&(l, _)| l)
+    }
+
+    /// Returns the letter that a character is sorted after, and its position among the
+    /// letters that follow the same letter. Other characters are returned with position 0.
+    pub(crate) fn letter_key(&self, c: char) -> (char, usize) {
+        match self.letters.iter().position(|&(l, _)| l == c) {
+            Some(i) => {
+                let base = self.letters[i].1;
+                let position = self.letters[..=i].iter().filter(|l| l.1 == base).count();
+                (base, position)
+            }
+            None => (c, 0),
+        }
+    }
+}
+
+impl Tailoring<'static> {
+    /// German dictionary order (DIN 5007-1): Umlauts are compared like the letters without
+    /// the diaeresis, so `"Müller"` is next to `"Muller"`, and `ß` like `ss`. This is the same
+    /// as no tailoring.
+    pub const GERMAN_DICTIONARY: Tailoring<'static> = Tailoring::new(&[], &[]);
+
+    /// German phone-book order (DIN 5007-2): Umlauts are compared like the vowel followed by
+    /// `e`, so `"Mueller"` is next to `"Müller"`, and `ß` like `ss`
+    pub const GERMAN_PHONEBOOK: Tailoring<'static> =
+        Tailoring::new(&[('ä', "ae"), ('ö', "oe"), ('ü', "ue")], &[]);
+
+    /// Spanish order: `ñ` is a separate letter after `n`. Other accents are ignored unless the
+    /// strings are equal otherwise.
+    pub const SPANISH: Tailoring<'static> = Tailoring::new(&[], &[('ñ', 'n')]);
+
+    /// Swedish order: `å`, `ä` and `ö` are separate letters after `z`, in this order. The
+    /// Danish and Norwegian letters `æ` and `ø` are compared like `ä` and `ö`.
+    pub const SWEDISH: Tailoring<'static> = Tailoring::new(
+        &[('æ', "ä"), ('ø', "ö")],
+        &[('å', 'z'), ('ä', 'z'), ('ö', 'z')],
+    );
+
+    /// Danish order: `æ`, `ø` and `å` are separate letters after `z`, in this order. The
+    /// Swedish and German letters `ä` and `ö` are compared like `æ` and `ø`.
+    pub const DANISH: Tailoring<'static> = Tailoring::new(
+        &[('ä', "æ"), ('ö', "ø")],
+        &[('æ', 'z'), ('ø', 'z'), ('å', 'z')],
+    );
+
+    /// Norwegian order, which is the same as the Danish order
+    pub const NORWEGIAN: Tailoring<'static> = Tailoring::DANISH;
+
+    /// Returns the tailoring for a locale, given as a BCP 47 language tag such as `"sv-SE"`,
+    /// or a POSIX locale name such as `"sv_SE.UTF-8"`. The language is matched ignoring case.
+    ///
+    /// German uses the dictionary order, unless the tag requests the phone-book collation
+    /// (`"de-DE-u-co-phonebk"` or `"de_DE@collation=phonebook"`). Finnish uses the Swedish
+    /// order. Returns `None` for other languages, e.g. `"en-US"` or `"tr-TR"`, and for tags
+    /// that aren't well-formed, e.g. `"C"` or `""`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use lexical_sort::Tailoring;
+    ///
+    /// assert_eq!(Tailoring::from_locale("sv-SE"), Some(Tailoring::SWEDISH));
+    /// assert_eq!(Tailoring::from_locale("nb_NO.UTF-8"), Some(Tailoring::NORWEGIAN));
+    /// assert_eq!(Tailoring::from_locale("en-US"), None);
+    /// ```
+    pub fn from_locale(tag: &str) -> Option<Self> {
+        let end = tag.find(&['-', '_', '.', '@'][..]).unwrap_or(tag.len());
+        let mut language = [0; 3];
+        match &tag.as_bytes()[..end] {
+            l if (2..=3).contains(&l.len()) && l.iter().all(u8::is_ascii_alphabetic) => {
+                language[..l.len()].copy_from_slice(l);
+            }
+            _ => return None,
+        }
+        language.make_ascii_lowercase();
+        let phonebook = contains_ignore_case(&tag[end..], "-u-co-phonebk")
+            || contains_ignore_case(&tag[end..], "@collation=phonebook");
+
+        Some(match &language[..end] {
+            b"de" if phonebook => Tailoring::GERMAN_PHONEBOOK,
+            b"de" => Tailoring::GERMAN_DICTIONARY,
+            b"es" => Tailoring::SPANISH,
+            b"sv" | b"fi" => Tailoring::SWEDISH,
+            b"da" => Tailoring::DANISH,
+            b"nb" | b"nn" | b"no" => Tailoring::NORWEGIAN,
+            _ => return None,
+        })
+    }
+}
+
+/// Returns whether `s` contains the ASCII string `needle`, ignoring case
+fn contains_ignore_case(s: &str, needle: &str) -> bool {
+    s.as_bytes()
+        .windows(needle.len())
+        .any(|w| w.eq_ignore_ascii_case(needle.as_bytes()))
+}
+
+/// Returns the lowercase form of a character, if it is a single character
+fn lowercase(c: char) -> char {
+    let mut lower = c.to_lowercase();
+    match (lower.next(), lower.next()) {
+        (Some(l), None) => l,
+        _ => c,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_locale() {
+        let german = Some(Tailoring::GERMAN_DICTIONARY);
+        assert_eq!(Tailoring::from_locale("de"), german);
+        assert_eq!(Tailoring::from_locale("de-DE"), german);
+        assert_eq!(Tailoring::from_locale("de_AT.UTF-8"), german);
+        assert_eq!(Tailoring::from_locale("DE-ch"), german);
+
+        let phonebook = Some(Tailoring::GERMAN_PHONEBOOK);
+        assert_eq!(Tailoring::from_locale("de-DE-u-co-phonebk"), phonebook);
+        assert_eq!(Tailoring::from_locale("de-u-co-PHONEBK"), phonebook);
+        assert_eq!(
+            Tailoring::from_locale("de_DE@collation=phonebook"),
+            phonebook
+        );
+        assert_eq!(Tailoring::from_locale("en-US-u-co-phonebk"), None);
+
+        assert_eq!(Tailoring::from_locale("es-MX"), Some(Tailoring::SPANISH));
+        assert_eq!(Tailoring::from_locale("sv-SE"), Some(Tailoring::SWEDISH));
+        assert_eq!(
+            Tailoring::from_locale("sv_FI.UTF-8@euro"),
+            Some(Tailoring::SWEDISH)
+        );
+        assert_eq!(Tailoring::from_locale("fi-FI"), Some(Tailoring::SWEDISH));
+        assert_eq!(Tailoring::from_locale("da_DK"), Some(Tailoring::DANISH));
+        assert_eq!(Tailoring::from_locale("nb-NO"), Some(Tailoring::NORWEGIAN));
+        assert_eq!(Tailoring::from_locale("nn"), Some(Tailoring::NORWEGIAN));
+        assert_eq!(Tailoring::from_locale("no_NO"), Some(Tailoring::NORWEGIAN));
+
+        // unknown and malformed tags
+        for tag in &[
+            "en-US",
+            "tr-TR",
+            "C",
+            "POSIX",
+            "C.UTF-8",
+            "",
+            "-",
+            "_sv",
+            "sve",
+            "s",
+            "sv1",
+            "de\u{e4}",
+            "@de",
+            "\u{1F600}",
+            "d\u{e9}-DE",
+        ] {
+            assert_eq!(Tailoring::from_locale(tag), None, "{:?}", tag);
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "sys-locale", feature = "std"))]
+    fn test_from_system_locale() {
+        use crate::Collator;
+        use core::cmp::Ordering;
+
+        let collator = Collator::from_system_locale();
+        assert_eq!(collator.cmp("a", "b"), Ordering::Less);
+        assert_eq!(collator.cmp("B", "a"), Ordering::Greater);
+    }
+}
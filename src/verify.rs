@@ -0,0 +1,417 @@
+//! Checks that a slice of strings is sorted, with a report of the first offending pair, and
+//! measures how sorted it is.
+
+use alloc::string::{String, ToString};
+use core::cmp::Ordering;
+use core::fmt;
+
+/// The first pair of strings that violates the sort order, returned by `verify_sorted` and
+/// `verify_sorted_exhaustive`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SortViolation {
+    /// The index of the left string
+    pub index: usize,
+    /// The left string
+    pub lhs: String,
+    /// The right string
+    pub rhs: String,
+    /// What is wrong with the pair
+    pub kind: ViolationKind,
+}
+
+/// The kind of a `SortViolation`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ViolationKind {
+    /// The string at `index` is greater than the string at `index + 1`
+    OutOfOrder,
+    /// The comparison function is inconsistent: The string at `index` is greater than the
+    /// string at `rhs_index`, although all adjacent strings between them are in order, or
+    /// comparing the strings in reverse order doesn't return the reverse result.
+    Inconsistent {
+        /// The index of the right string
+        rhs_index: usize,
+    },
+}
+
+impl fmt::Display for SortViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ViolationKind::OutOfOrder => write!(
+                f,
+                "{:?} at index {} is greater than the next string {:?}",
+                self.lhs, self.index, self.rhs
+            ),
+            ViolationKind::Inconsistent { rhs_index } => write!(
+                f,
+                "inconsistent comparison of {:?} at index {} and {:?} at index {}",
+                self.lhs, self.index, self.rhs, rhs_index
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SortViolation {}
+
+impl SortViolation {
+    fn new(strings: &[impl AsRef<str>], index: usize, rhs_index: usize) -> Self {
+        SortViolation {
+            index,
+            lhs: strings[index].as_ref().to_string(),
+            rhs: strings[rhs_index].as_ref().to_string(),
+            kind: if rhs_index == index + 1 {
+                ViolationKind::OutOfOrder
+            } else {
+                ViolationKind::Inconsistent { rhs_index }
+            },
+        }
+    }
+}
+
+/// Checks that the strings are sorted according to the comparison function. If they aren't,
+/// the first adjacent pair that is out of order is returned.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::{natural_lexical_cmp, verify_sorted, ViolationKind};
+///
+/// assert!(verify_sorted(&["T-5", "t-10", "T-20"], natural_lexical_cmp).is_ok());
+///
+/// let violation = verify_sorted(&["T-5", "T-20", "t-10"], natural_lexical_cmp).unwrap_err();
+/// assert_eq!(violation.index, 1);
+/// assert_eq!((&*violation.lhs, &*violation.rhs), ("T-20", "t-10"));
+/// assert_eq!(violation.kind, ViolationKind::OutOfOrder);
+/// ```
+pub fn verify_sorted<A: AsRef<str>>(
+    strings: &[A],
+    mut cmp: impl FnMut(&str, &str) -> Ordering,
+) -> Result<(), SortViolation> {
+    match strings
+        .windows(2)
+        .position(|w| cmp(w[0].as_ref(), w[1].as_ref()) == Ordering::Greater)
+    {
+        Some(index) => Err(SortViolation::new(strings, index, index + 1)),
+        None => Ok(()),
+    }
+}
+
+/// Checks that the strings are sorted like `verify_sorted`, and also that the comparison
+/// function is consistent on them: Every string must be less than or equal to every
+/// later string, and comparing two strings in reverse order must return the reverse result.
+///
+/// This compares every pair of strings, so it takes O(n²) time. It is meant for tests.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::{verify_sorted, verify_sorted_exhaustive, ViolationKind};
+/// use std::cmp::Ordering;
+///
+/// // An inconsistent comparison function: rock < paper < scissors < rock
+/// fn rock_paper_scissors(lhs: &str, rhs: &str) -> Ordering {
+///     match (lhs, rhs) {
+///         ("rock", "scissors") => Ordering::Greater,
+///         ("scissors", "rock") => Ordering::Less,
+///         _ => lhs.len().cmp(&rhs.len()).then_with(|| lhs.cmp(rhs)),
+///     }
+/// }
+///
+/// let strings = ["rock", "paper", "scissors"];
+/// assert!(verify_sorted(&strings, rock_paper_scissors).is_ok());
+///
+/// let violation = verify_sorted_exhaustive(&strings, rock_paper_scissors).unwrap_err();
+/// assert_eq!((violation.index, violation.kind), (0, ViolationKind::Inconsistent { rhs_index: 2 }));
+/// ```
+pub fn verify_sorted_exhaustive<A: AsRef<str>>(
+    strings: &[A],
+    mut cmp: impl FnMut(&str, &str) -> Ordering,
+) -> Result<(), SortViolation> {
+    verify_sorted(strings, &mut cmp)?;
+
+    for (i, lhs) in strings.iter().enumerate() {
+        let lhs = lhs.as_ref();
+        if cmp(lhs, lhs) != Ordering::Equal {
+            return Err(SortViolation::new(strings, i, i));
+        }
+        for (j, rhs) in strings.iter().enumerate().skip(i + 1) {
+            let rhs = rhs.as_ref();
+            let ordering = cmp(lhs, rhs);
+            if ordering == Ordering::Greater || cmp(rhs, lhs) != ordering.reverse() {
+                let mut violation = SortViolation::new(strings, i, j);
+                violation.kind = ViolationKind::Inconsistent { rhs_index: j };
+                return Err(violation);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// How sorted a slice is, returned by `sortedness`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Sortedness {
+    /// The length of the longest run of strings that are sorted
+    pub longest_run: usize,
+    /// The number of maximal runs of strings that are sorted. This is 1 if the slice is sorted,
+    /// and 0 if it is empty.
+    pub runs: usize,
+    /// The number of pairs of strings that are in the wrong order. This is exact for slices
+    /// with up to 1000 strings, and estimated from a sample of pairs otherwise.
+    pub inversions_estimate: u64,
+}
+
+/// Slices up to this length have an exact inversion count
+const EXACT_INVERSIONS_LIMIT: usize = 1000;
+
+/// The number of pairs that are compared to estimate the inversion count of longer slices
+const INVERSION_SAMPLES: u64 = 1 << 16;
+
+/// Measures how sorted the strings are according to the comparison function, e.g. to decide
+/// whether to sort them again.
+///
+/// The runs are counted in a single pass. The inversions (pairs of strings that are in the
+/// wrong order) are counted exactly by comparing every pair if there are at most 1000 strings.
+/// Otherwise, 65536 pairs are compared, and the count is extrapolated from them. The pairs are
+/// chosen pseudo-randomly, but deterministically, so the result is always the same.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::{natural_lexical_cmp, sortedness, Sortedness};
+///
+/// let strings = ["T-1", "T-2", "T-10", "T-5", "T-20"];
+/// assert_eq!(
+///     sortedness(&strings, natural_lexical_cmp),
+///     Sortedness { longest_run: 3, runs: 2, inversions_estimate: 1 },
+/// );
+/// ```
+pub fn sortedness<A: AsRef<str>>(
+    strings: &[A],
+    mut cmp: impl FnMut(&str, &str) -> Ordering,
+) -> Sortedness {
+    let mut cmp = |i: usize, j: usize| cmp(strings[i].as_ref(), strings[j].as_ref());
+    let n = strings.len();
+
+    let (mut longest_run, mut runs, mut run) = (0, 0, 0);
+    for i in 0..n {
+        if i == 0 || cmp(i - 1, i) == Ordering::Greater {
+            runs += 1;
+            run = 0;
+        }
+        run += 1;
+        longest_run = longest_run.max(run);
+    }
+
+    let inversions_estimate = if runs <= 1 {
+        0
+    } else if n <= EXACT_INVERSIONS_LIMIT {
+        let mut inversions = 0;
+        for i in 0..n {
+            for j in i + 1..n {
+                if cmp(i, j) == Ordering::Greater {
+                    inversions += 1;
+                }
+            }
+        }
+        inversions
+    } else {
+        // xorshift64, with a fixed seed
+        let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+        let mut random = |bound: usize| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state % bound as u64) as usize
+        };
+
+        let mut inversions = 0;
+        let mut samples = 0;
+        while samples < INVERSION_SAMPLES {
+            let (i, j) = (random(n), random(n));
+            if i == j {
+                continue;
+            }
+            samples += 1;
+            if cmp(i.min(j), i.max(j)) == Ordering::Greater {
+                inversions += 1;
+            }
+        }
+        let pairs = n as u64 * (n as u64 - 1) / 2;
+        // rounds to the nearest integer; `f64::round` isn't available without `std`
+        (inversions as f64 / samples as f64 * pairs as f64 + 0.5) as u64
+    };
+
+    Sortedness {
+        longest_run,
+        runs,
+        inversions_estimate,
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{lexical_cmp, natural_lexical_cmp, StringSort};
+
+    #[test]
+    fn test_verify_sorted() {
+        let mut fixture = vec!["T-100", "b", "T-5", "ä", "_c", "T-20", "ss", "ß"];
+        fixture.string_sort_unstable(natural_lexical_cmp);
+        assert_eq!(verify_sorted(&fixture, natural_lexical_cmp), Ok(()));
+        assert_eq!(
+            verify_sorted_exhaustive(&fixture, natural_lexical_cmp),
+            Ok(())
+        );
+        assert_eq!(verify_sorted(&[] as &[&str], lexical_cmp), Ok(()));
+        assert_eq!(verify_sorted(&["x"], lexical_cmp), Ok(()));
+
+        // sorted naturally, but not lexically
+        let violation = verify_sorted(&fixture, lexical_cmp).unwrap_err();
+        assert_eq!(violation.kind, ViolationKind::OutOfOrder);
+        assert_eq!(
+            (violation.lhs.as_str(), violation.rhs.as_str()),
+            ("T-5", "T-20")
+        );
+        assert_eq!(fixture[violation.index], "T-5");
+
+        // a corrupted cache: two entries swapped
+        let mut corrupted = fixture.clone();
+        corrupted.swap(2, 6);
+        let violation = verify_sorted(&corrupted, natural_lexical_cmp).unwrap_err();
+        assert_eq!(violation.index, 2);
+        assert_eq!(violation.lhs, fixture[6]);
+        assert_eq!(
+            violation.to_string(),
+            format!(
+                "{:?} at index 2 is greater than the next string {:?}",
+                fixture[6], fixture[3]
+            )
+        );
+
+        // the first violation is reported
+        let violation = verify_sorted(&["a", "c", "b", "a"], lexical_cmp).unwrap_err();
+        assert_eq!(violation.index, 1);
+    }
+
+    #[test]
+    fn test_verify_sorted_exhaustive() {
+        // rock < paper < scissors < rock
+        fn intransitive(lhs: &str, rhs: &str) -> Ordering {
+            match (lhs, rhs) {
+                ("rock", "scissors") => Ordering::Greater,
+                ("scissors", "rock") => Ordering::Less,
+                _ => lhs.len().cmp(&rhs.len()).then_with(|| lhs.cmp(rhs)),
+            }
+        }
+        let strings = ["rock", "paper", "scissors", "scissors!"];
+        assert_eq!(verify_sorted(&strings, intransitive), Ok(()));
+        let violation = verify_sorted_exhaustive(&strings, intransitive).unwrap_err();
+        assert_eq!(violation.index, 0);
+        assert_eq!(violation.kind, ViolationKind::Inconsistent { rhs_index: 2 });
+        assert_eq!(
+            violation.to_string(),
+            r#"inconsistent comparison of "rock" at index 0 and "scissors" at index 2"#
+        );
+
+        // "a" is less than everything else, and everything else is compared normally
+        fn asymmetric(lhs: &str, rhs: &str) -> Ordering {
+            if lhs == "a" && rhs != "a" {
+                Ordering::Less
+            } else {
+                lhs.cmp(rhs)
+            }
+        }
+        let strings = ["0", "a"];
+        assert_eq!(verify_sorted(&strings, asymmetric), Ok(()));
+        let violation = verify_sorted_exhaustive(&strings, asymmetric).unwrap_err();
+        assert_eq!(violation.kind, ViolationKind::Inconsistent { rhs_index: 1 });
+
+        // a string isn't equal to itself
+        let violation = verify_sorted_exhaustive(&["a"], |_, _| Ordering::Less).unwrap_err();
+        assert_eq!(violation.kind, ViolationKind::Inconsistent { rhs_index: 0 });
+
+        // an out-of-order pair is reported like in `verify_sorted`
+        let violation = verify_sorted_exhaustive(&["b", "a"], lexical_cmp).unwrap_err();
+        assert_eq!(violation.kind, ViolationKind::OutOfOrder);
+    }
+
+    #[test]
+    fn test_sortedness() {
+        let measure = |strings: &[String]| sortedness(strings, natural_lexical_cmp);
+        let names = |range: core::ops::Range<usize>| -> Vec<String> {
+            range.map(|i| format!("file-{}", i)).collect()
+        };
+
+        assert_eq!(
+            measure(&[]),
+            Sortedness {
+                longest_run: 0,
+                runs: 0,
+                inversions_estimate: 0
+            }
+        );
+
+        for &n in &[1, 10, 1000, 5000] {
+            let sorted = names(0..n);
+            assert_eq!(
+                measure(&sorted),
+                Sortedness {
+                    longest_run: n,
+                    runs: 1,
+                    inversions_estimate: 0
+                }
+            );
+
+            let mut reversed = sorted;
+            reversed.reverse();
+            assert_eq!(
+                measure(&reversed),
+                Sortedness {
+                    longest_run: 1,
+                    runs: n,
+                    inversions_estimate: (n * (n - 1) / 2) as u64,
+                }
+            );
+        }
+
+        // duplicates aren't inversions
+        let strings = ["a", "a", "b", "a", "a"].map(String::from);
+        assert_eq!(
+            measure(&strings),
+            Sortedness {
+                longest_run: 3,
+                runs: 2,
+                inversions_estimate: 2
+            }
+        );
+
+        // two sorted halves, swapped: every pair across the halves is an inversion
+        for &n in &[100, 4000] {
+            let mut strings = names(n / 2..n);
+            strings.extend(names(0..n / 2));
+            let sortedness = measure(&strings);
+            assert_eq!((sortedness.longest_run, sortedness.runs), (n / 2, 2));
+
+            let exact = (n / 2 * n / 2) as f64;
+            let estimate = sortedness.inversions_estimate as f64;
+            assert!(
+                (estimate - exact).abs() <= exact * 0.02,
+                "{} vs {}",
+                estimate,
+                exact
+            );
+            if n <= EXACT_INVERSIONS_LIMIT {
+                assert_eq!(estimate, exact);
+            }
+        }
+
+        // a sorted slice with one element moved to the front
+        let mut strings = names(0..2000);
+        let last = strings.pop().unwrap();
+        strings.insert(0, last);
+        let sortedness = measure(&strings);
+        assert_eq!((sortedness.longest_run, sortedness.runs), (1999, 2));
+        let estimate = sortedness.inversions_estimate as f64;
+        assert!((estimate - 1999.0).abs() <= 1999.0 * 0.5, "{}", estimate);
+    }
+}
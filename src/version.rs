@@ -0,0 +1,570 @@
+//! Comparisons of version strings, e.g. `foo-1.2.10` or `1.10.0-rc.1`.
+
+use crate::cmp::{cmp_ascii_digits, lexical_cmp};
+use crate::collator::{Collator, LeadingZeros, TieBreak};
+use core::cmp::Ordering;
+
+/// Compares version strings, e.g. `"foo-1.2.9" < "foo-1.2.10"` and
+/// `"1.10.0-rc.1" < "1.10.0"`
+///
+/// The strings are split into segments at `.` and `-`, and the segments are compared one by
+/// one:
+///
+/// - Numeric segments are compared numerically. A `v` before the digits is ignored, so
+///   `"v1.2"` and `"1.2"` are equal versions.
+/// - Other segments are compared naturally like `natural_cmp`, so `"rc2" < "rc10"`. They are
+///   greater than numeric segments.
+/// - A segment after a `-` that follows a numeric segment and starts with a letter is a
+///   pre-release, like in semver: It is less than all other segments, so
+///   `"1.0.0-alpha" < "1.0.0-alpha.2" < "1.0.0-beta" < "1.0.0" < "1.0.0.1"`.
+/// - Missing trailing segments are treated as zero, so `"1.2"` and `"1.2.0"` are equal
+///   versions.
+///
+/// Equal versions are compared with `str::cmp`, so only identical strings are equal, and
+/// `"1.2" < "1.2.0"`.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::{version_cmp, StringSort};
+///
+/// let slice = &mut ["1.10.0", "1.10.0-rc.1", "1.9.3", "1.10.0-beta", "v1.2"];
+/// slice.string_sort_unstable(version_cmp);
+/// assert_eq!(slice, &["v1.2", "1.9.3", "1.10.0-beta", "1.10.0-rc.1", "1.10.0"]);
+/// ```
+pub fn version_cmp(s1: &str, s2: &str) -> Ordering {
+    cmp_versions(s1, s2, &SEGMENTS).then_with(|| s1.cmp(s2))
+}
+
+/// Compares version strings like `version_cmp`, but segments that aren't numeric are
+/// compared naturally and lexicographically like `natural_lexical_cmp`
+///
+/// For example, `"foo-1.2.9" < "Foo-1.2.10"` and `"1.0.0-Beta" < "1.0.0-RC.1"`.
+///
+/// Equal versions are compared with `lexical_cmp`, so only identical strings are equal.
+pub fn version_lexical_cmp(s1: &str, s2: &str) -> Ordering {
+    cmp_versions(s1, s2, &LEXICAL_SEGMENTS).then_with(|| lexical_cmp(s1, s2))
+}
+
+/// Compares file names like GNU `ls -v` and `sort -V`, i.e. with the `filevercmp` algorithm
+/// of gnulib, followed by `str::cmp`
+///
+/// This differs from the other comparison functions of this crate:
+///
+/// - `""` sorts first, then `"."`, then `".."`, then other names that start with `.` (hidden
+///   files), then all other names.
+/// - The file suffix, i.e. the longest suffix that matches the regular expression
+///   `(\.[A-Za-z~][A-Za-z0-9~]*)*$`, such as `.tar.gz`, is only compared if the names are
+///   equal without it. The first character is never part of the suffix, so the name of a
+///   hidden file such as `.bashrc` has no suffix, and `".0" < ".zz"`. This is the behavior of
+///   current gnulib; coreutils 9.1 still considered `.zz` a suffix, and sorted it first.
+/// - Runs of digits are compared numerically, leading zeros are ignored.
+/// - Other bytes are compared one by one: `~` sorts before everything, even the end of the
+///   string, so `"a~" < "a"`. ASCII letters sort before all other bytes, and all other bytes
+///   are compared by their value. This is case sensitive, and non-ASCII characters aren't
+///   transliterated.
+///
+/// Names that are equal in these steps, such as `"a0"` and `"a00"`, are compared with
+/// `str::cmp`, like `ls -v` does.
+///
+/// ## Example
+///
+/// ```rust
+/// use lexical_sort::{gnu_version_cmp, StringSort};
+///
+/// let slice = &mut ["a.b", "b", "a.b~", ".hidden", "a10", "a9", "a"];
+/// slice.string_sort_unstable(gnu_version_cmp);
+/// assert_eq!(slice, &[".hidden", "a", "a.b~", "a.b", "a9", "a10", "b"]);
+/// ```
+pub fn gnu_version_cmp(s1: &str, s2: &str) -> Ordering {
+    filevercmp(s1.as_bytes(), s2.as_bytes()).then_with(|| s1.cmp(s2))
+}
+
+/// The `filevercmp` function of gnulib
+fn filevercmp(a: &[u8], b: &[u8]) -> Ordering {
+    // empty names sort first, then ".", then "..", then other names starting with "."
+    for special in &[&b""[..], b".", b".."] {
+        match (a == *special, b == *special) {
+            (true, true) => return Ordering::Equal,
+            (true, false) => return Ordering::Less,
+            (false, true) => return Ordering::Greater,
+            (false, false) => {}
+        }
+    }
+    match (a[0] == b'.', b[0] == b'.') {
+        (true, false) => return Ordering::Less,
+        (false, true) => return Ordering::Greater,
+        _ => {}
+    }
+
+    // compare the names without their suffixes first
+    let (a_prefix, b_prefix) = (file_prefix_len(a), file_prefix_len(b));
+    match verrevcmp(&a[..a_prefix], &b[..b_prefix]) {
+        Ordering::Equal if a_prefix < a.len() || b_prefix < b.len() => verrevcmp(a, b),
+        ordering => ordering,
+    }
+}
+
+/// Returns the length of the name without its suffix, which is the longest suffix matching
+/// `(\.[A-Za-z~][A-Za-z0-9~]*)*$`, but doesn't contain the first byte
+fn file_prefix_len(s: &[u8]) -> usize {
+    let is_suffix_start = |i: usize| {
+        s[i] == b'.' && matches!(s.get(i + 1), Some(c) if c.is_ascii_alphabetic() || *c == b'~')
+    };
+    let mut i = s.len().min(1);
+    loop {
+        let prefix_len = i;
+        while i < s.len() && is_suffix_start(i) {
+            i += 2;
+            while i < s.len() && (s[i].is_ascii_alphanumeric() || s[i] == b'~') {
+                i += 1;
+            }
+        }
+        if i >= s.len() {
+            return prefix_len;
+        }
+        i += 1;
+    }
+}
+
+/// Returns the weight of the byte at the given position in `filevercmp`, which is `None` for
+/// digits. The end of the string sorts before everything except `~`.
+fn byte_order(s: &[u8], pos: usize) -> Option<i32> {
+    match s.get(pos) {
+        None => Some(-1),
+        Some(c) if c.is_ascii_digit() => None,
+        Some(&c) if c.is_ascii_alphabetic() => Some(i32::from(c)),
+        Some(b'~') => Some(-2),
+        Some(&c) => Some(i32::from(c) + 256),
+    }
+}
+
+/// The `verrevcmp` function of dpkg, as modified in gnulib
+fn verrevcmp(s1: &[u8], s2: &[u8]) -> Ordering {
+    let digit = |s: &[u8], pos: usize| matches!(s.get(pos), Some(c) if c.is_ascii_digit());
+    let (mut pos1, mut pos2) = (0, 0);
+    while pos1 < s1.len() || pos2 < s2.len() {
+        // compare the bytes before the next digits
+        while (pos1 < s1.len() && !digit(s1, pos1)) || (pos2 < s2.len() && !digit(s2, pos2)) {
+            let order1 = byte_order(s1, pos1).unwrap_or(0);
+            let order2 = byte_order(s2, pos2).unwrap_or(0);
+            if order1 != order2 {
+                return order1.cmp(&order2);
+            }
+            pos1 += 1;
+            pos2 += 1;
+        }
+
+        // compare the numbers
+        while s1.get(pos1) == Some(&b'0') {
+            pos1 += 1;
+        }
+        while s2.get(pos2) == Some(&b'0') {
+            pos2 += 1;
+        }
+        let mut first_diff = Ordering::Equal;
+        while digit(s1, pos1) && digit(s2, pos2) {
+            first_diff = first_diff.then(s1[pos1].cmp(&s2[pos2]));
+            pos1 += 1;
+            pos2 += 1;
+        }
+        if digit(s1, pos1) {
+            return Ordering::Greater;
+        }
+        if digit(s2, pos2) {
+            return Ordering::Less;
+        }
+        if first_diff != Ordering::Equal {
+            return first_diff;
+        }
+    }
+    Ordering::Equal
+}
+
+/// Compares segments that aren't numeric in `version_cmp`. Segments that differ only in case
+/// or leading zeros are equal, so the following segments are compared before the tie-break.
+const SEGMENTS: Collator<'static> = LEXICAL_SEGMENTS.lexical(false);
+
+/// Compares segments that aren't numeric in `version_lexical_cmp`
+const LEXICAL_SEGMENTS: Collator<'static> = Collator::new()
+    .natural(true)
+    .leading_zeros(LeadingZeros::Ignore)
+    .ties(TieBreak::InputOrder);
+
+/// Compares the segments of two version strings, with the given collator for segments that
+/// aren't numeric
+fn cmp_versions(s1: &str, s2: &str, collator: &Collator<'_>) -> Ordering {
+    let mut segments1 = Segments::new(s1);
+    let mut segments2 = Segments::new(s2);
+    loop {
+        let ordering = match (segments1.next(), segments2.next()) {
+            (None, None) => return Ordering::Equal,
+            // a missing segment is treated as zero
+            (lhs, rhs) => lhs.unwrap_or(ZERO).cmp(rhs.unwrap_or(ZERO), collator),
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+}
+
+/// A segment of a version string
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Segment<'a> {
+    /// A pre-release, e.g. `rc` in `1.0.0-rc.1`
+    PreRelease(&'a str),
+    /// A number without the `v` prefix
+    Number(&'a str),
+    /// Any other segment
+    Other(&'a str),
+}
+
+const ZERO: Segment<'static> = Segment::Number("0");
+
+impl Segment<'_> {
+    /// Pre-releases are less than numbers, which are less than other segments
+    fn rank(self) -> u8 {
+        match self {
+            Segment::PreRelease(_) => 0,
+            Segment::Number(_) => 1,
+            Segment::Other(_) => 2,
+        }
+    }
+
+    fn cmp(self, other: Segment<'_>, collator: &Collator<'_>) -> Ordering {
+        match (self, other) {
+            (Segment::Number(lhs), Segment::Number(rhs)) => {
+                let mut iter1 = lhs.chars().peekable();
+                let mut iter2 = rhs.chars().peekable();
+                match (iter1.next(), iter2.next()) {
+                    (Some(l), Some(r)) => cmp_ascii_digits(l, r, &mut iter1, &mut iter2).0,
+                    _ => lhs.len().cmp(&rhs.len()),
+                }
+            }
+            (Segment::PreRelease(lhs), Segment::PreRelease(rhs))
+            | (Segment::Other(lhs), Segment::Other(rhs)) => collator.cmp(lhs, rhs),
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+}
+
+/// Iterator over the segments of a version string
+struct Segments<'a> {
+    rest: Option<&'a str>,
+    /// Whether the previous segment is followed by a `-`
+    after_hyphen: bool,
+    /// Whether the previous segment is numeric
+    after_number: bool,
+}
+
+impl<'a> Segments<'a> {
+    fn new(s: &'a str) -> Self {
+        Segments {
+            rest: Some(s),
+            after_hyphen: false,
+            after_number: false,
+        }
+    }
+}
+
+impl<'a> Iterator for Segments<'a> {
+    type Item = Segment<'a>;
+
+    fn next(&mut self) -> Option<Segment<'a>> {
+        let s = self.rest?;
+        let (segment, separator) = match s.find(&['.', '-'][..]) {
+            Some(i) => {
+                self.rest = Some(&s[i + 1..]);
+                (&s[..i], Some(s.as_bytes()[i]))
+            }
+            None => {
+                self.rest = None;
+                (s, None)
+            }
+        };
+
+        let is_number = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+        let result = if is_number(segment) {
+            Segment::Number(segment)
+        } else if segment.starts_with(&['v', 'V'][..]) && is_number(&segment[1..]) {
+            Segment::Number(&segment[1..])
+        } else if self.after_hyphen
+            && self.after_number
+            && segment.starts_with(|c: char| c.is_alphabetic())
+        {
+            Segment::PreRelease(segment)
+        } else {
+            Segment::Other(segment)
+        };
+
+        self.after_hyphen = separator == Some(b'-');
+        self.after_number = matches!(result, Segment::Number(_) | Segment::PreRelease(_));
+        Some(result)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segments() {
+        use Segment::{Number, Other, PreRelease};
+
+        let segments = |s| Segments::new(s).collect::<Vec<_>>();
+        assert_eq!(segments("1.2.10"), [Number("1"), Number("2"), Number("10")]);
+        assert_eq!(segments("v1.2"), [Number("1"), Number("2")]);
+        assert_eq!(
+            segments("foo-1.2.10"),
+            [Other("foo"), Number("1"), Number("2"), Number("10")]
+        );
+        assert_eq!(
+            segments("1.0.0-alpha.2"),
+            [
+                Number("1"),
+                Number("0"),
+                Number("0"),
+                PreRelease("alpha"),
+                Number("2")
+            ]
+        );
+        assert_eq!(
+            segments("1.0.0-rc-1"),
+            [
+                Number("1"),
+                Number("0"),
+                Number("0"),
+                PreRelease("rc"),
+                Number("1")
+            ]
+        );
+        assert_eq!(segments("foo-bar"), [Other("foo"), Other("bar")]);
+        assert_eq!(segments(""), [Other("")]);
+        assert_eq!(segments("1."), [Number("1"), Other("")]);
+    }
+
+    #[test]
+    fn test_version_cmp() {
+        let ordered = |lhs: &str, rhs: &str| {
+            assert_eq!(version_cmp(lhs, rhs), Ordering::Less, "{} < {}", lhs, rhs);
+            assert_eq!(
+                version_cmp(rhs, lhs),
+                Ordering::Greater,
+                "{} > {}",
+                rhs,
+                lhs
+            );
+        };
+
+        ordered("foo-1.2.9", "foo-1.2.10");
+        ordered("1.9", "1.10");
+        ordered("1.2.10", "1.10.0");
+        ordered("foo-1.10", "foobar-1.2");
+
+        // pre-releases
+        ordered("1.10.0-rc.1", "1.10.0");
+        ordered("1.0.0-alpha", "1.0.0-alpha.2");
+        ordered("1.0.0-alpha.2", "1.0.0-alpha.10");
+        ordered("1.0.0-alpha.10", "1.0.0-beta");
+        ordered("1.0.0-beta", "1.0.0-rc.1");
+        ordered("1.0.0-rc.1", "1.0.0");
+        ordered("1.0.0", "1.0.0.1");
+        ordered("1.0.0-rc2", "1.0.0-rc10");
+        ordered("0.9.9", "1.0.0-alpha");
+
+        // missing segments are zero
+        assert_eq!(cmp_versions("1.2", "1.2.0", &SEGMENTS), Ordering::Equal);
+        assert_eq!(cmp_versions("1.2", "1.2.0.0", &SEGMENTS), Ordering::Equal);
+        ordered("1.2", "1.2.0");
+        ordered("1.2.0-rc", "1.2");
+        ordered("1.2", "1.2.1");
+
+        // `v` prefixes
+        assert_eq!(
+            cmp_versions("v1.2.10", "1.2.10", &SEGMENTS),
+            Ordering::Equal
+        );
+        ordered("v1.2.9", "1.2.10");
+        ordered("1.2.9", "v1.2.10");
+        ordered("foo-v1.9", "foo-v1.10");
+
+        // mixed segments are compared naturally, and after numbers
+        ordered("1.0.2", "1.0.a");
+        ordered("1.0.x2", "1.0.x10");
+
+        // the order is consistent
+        let strings = [
+            "",
+            "0",
+            "1",
+            "1.0",
+            "1.0.0",
+            "v1",
+            "1.0.0-alpha",
+            "1.0.0-alpha.1",
+            "1.0.0-alpha.beta",
+            "1.0.0-beta",
+            "1.0.0-beta.2",
+            "1.0.0-beta.11",
+            "1.0.0-rc.1",
+            "1.0.1",
+            "1.0.a",
+            "1.0-rc",
+            "1.2.10",
+            "1.2.9",
+            "foo",
+            "foo-1",
+            "foo-bar",
+            "1..2",
+            "1.-2",
+            "a.b",
+            "A.b",
+        ];
+        for &cmp in &[version_cmp, version_lexical_cmp] {
+            let mut sorted = strings.to_vec();
+            sorted.sort_by(|l, r| cmp(l, r));
+            for (i, lhs) in sorted.iter().enumerate() {
+                for (j, rhs) in sorted.iter().enumerate() {
+                    assert_eq!(cmp(lhs, rhs), i.cmp(&j), "{} {}", lhs, rhs);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_gnu_version_cmp() {
+        // the examples of `tests/test-filevercmp.c` of gnulib, in the same order
+        let examples = [
+            "",
+            ".",
+            "..",
+            ".0",
+            ".9",
+            ".A",
+            ".Z",
+            ".a~",
+            ".a",
+            ".b~",
+            ".b",
+            ".z",
+            ".zz~",
+            ".zz",
+            ".zz.~1~",
+            ".zz.0",
+            ".\u{1}",
+            ".\u{1}.txt",
+            ".\u{1}x",
+            ".\u{1}x\u{1}",
+            ".\u{1}.0",
+            "0",
+            "9",
+            "A",
+            "Z",
+            "a~",
+            "a",
+            "a.b~",
+            "a.b",
+            "a.bc~",
+            "a.bc",
+            "a+",
+            "a.",
+            "a..a",
+            "a.+",
+            "b~",
+            "b",
+            "gcc-c++-10.fc9.tar.gz",
+            "gcc-c++-10.fc9.tar.gz.~1~",
+            "gcc-c++-10.fc9.tar.gz.~2~",
+            "gcc-c++-10.8.12-0.7rc2.fc9.tar.bz2",
+            "gcc-c++-10.8.12-0.7rc2.fc9.tar.bz2.~1~",
+            "glibc-2-0.1.beta1.fc10.rpm",
+            "glibc-common-5-0.2.beta2.fc9.ebuild",
+            "glibc-common-5-0.2b.deb",
+            "glibc-common-11b.ebuild",
+            "glibc-common-11-0.6rc2.ebuild",
+            "libstdc++-0.5.8.11-0.7rc2.fc10.tar.gz",
+            "libstdc++-4a.fc8.tar.gz",
+            "libstdc++-4.10.4.20040204svn.rpm",
+            "libstdc++-devel-3.fc8.ebuild",
+            "libstdc++-devel-3a.fc9.tar.gz",
+            "libstdc++-devel-8.fc8.deb",
+            "libstdc++-devel-8.6.2-0.4b.fc8",
+            "nss_ldap-1-0.2b.fc9.tar.bz2",
+            "nss_ldap-1-0.6rc2.fc8.tar.gz",
+            "nss_ldap-1.0-0.1a.tar.gz",
+            "nss_ldap-10beta1.fc8.tar.gz",
+            "nss_ldap-10.11.8.6.20040204cvs.fc10.ebuild",
+            "z",
+            "zz~",
+            "zz",
+            "zz.~1~",
+            "zz.0",
+            "zz.0.txt",
+            "\u{1}",
+            "\u{1}.txt",
+            "\u{1}x",
+            "\u{1}x\u{1}",
+            "\u{1}.0",
+            "#\u{1}.b#",
+            "#.b#",
+        ];
+        for (i, lhs) in examples.iter().enumerate() {
+            for (j, rhs) in examples.iter().enumerate() {
+                let expected = i.cmp(&j);
+                assert_eq!(
+                    filevercmp(lhs.as_bytes(), rhs.as_bytes()),
+                    expected,
+                    "{:?} {:?}",
+                    lhs,
+                    rhs
+                );
+                assert_eq!(gnu_version_cmp(lhs, rhs), expected, "{:?} {:?}", lhs, rhs);
+            }
+        }
+
+        // the names of the same test that `filevercmp` considers equal are ordered by `str::cmp`
+        let zeros = "a\u{1}c-00000000000000000000000000000000000000000000000000000027.txt";
+        let hidden_zeros = format!(".{}", zeros);
+        let classes: &[&[&str]] = &[
+            &["a", "a0", "a0000"],
+            &["a\u{1}c-27.txt", "a\u{1}c-027.txt", zeros],
+            &[".a\u{1}c-27.txt", ".a\u{1}c-027.txt", &hidden_zeros],
+            &["a\u{1}c-", "a\u{1}c-0", "a\u{1}c-00"],
+            &[".a\u{1}c-", ".a\u{1}c-0", ".a\u{1}c-00"],
+            &["a\u{1}c-0.txt", "a\u{1}c-00.txt"],
+            &[".a\u{1}c-1\u{1}.txt", ".a\u{1}c-001\u{1}.txt"],
+        ];
+        for class in classes {
+            for lhs in class.iter() {
+                for rhs in class.iter() {
+                    let (l, r) = (lhs.as_bytes(), rhs.as_bytes());
+                    assert_eq!(filevercmp(l, r), Ordering::Equal, "{:?} {:?}", lhs, rhs);
+                    assert_eq!(gnu_version_cmp(lhs, rhs), lhs.cmp(rhs));
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_version_lexical_cmp() {
+        assert_eq!(
+            version_lexical_cmp("foo-1.2.9", "Foo-1.2.10"),
+            Ordering::Less
+        );
+        assert_eq!(version_cmp("foo-1.2.9", "Foo-1.2.10"), Ordering::Greater);
+        assert_eq!(
+            version_lexical_cmp("1.0.0-Beta", "1.0.0-RC.1"),
+            Ordering::Less
+        );
+        assert_eq!(version_lexical_cmp("1.0.0-RC.1", "1.0.0"), Ordering::Less);
+        assert_eq!(
+            version_lexical_cmp("Éclair-2.0", "eclair-10.0"),
+            Ordering::Less
+        );
+        assert_eq!(
+            version_lexical_cmp("V1.2", "v1.2"),
+            lexical_cmp("V1.2", "v1.2")
+        );
+    }
+}
@@ -0,0 +1,5 @@
+//! This crate is enabled by the `stable` feature of `lexical-sort`. It has no items; it only
+//! depends on an exact version of `any_ascii`, so Cargo can't select another version for the
+//! whole dependency graph, which could change the order of `lexical-sort`.
+
+#![no_std]
@@ -0,0 +1,57 @@
+//! Checks that the comparison functions agree with the equivalent `Collator`s on the strings
+//! of the benchmarks. The functions don't use a `Collator`, because it is several times
+//! slower, so this makes sure that the two implementations don't drift apart.
+
+#[path = "../benches/corpora/mod.rs"]
+mod corpora;
+
+use corpora::{generate_strings, ASCII_STRINGS, NUM_STRINGS, STRINGS};
+use lexical_sort::{Collator, SortMode};
+
+fn check_agreement<S: AsRef<str>>(corpus: &str, strings: &[S]) {
+    for &mode in SortMode::ALL.iter() {
+        let (collator, function) = (Collator::from(mode), mode.function());
+        for lhs in strings {
+            for rhs in strings {
+                let (lhs, rhs) = (lhs.as_ref(), rhs.as_ref());
+                assert_eq!(
+                    collator.cmp(lhs, rhs),
+                    function(lhs, rhs),
+                    "`{}` disagrees with its collator on {:?} and {:?} of the {} corpus",
+                    mode,
+                    lhs,
+                    rhs,
+                    corpus,
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn unicode_strings() {
+    check_agreement("Unicode", &STRINGS);
+}
+
+#[test]
+fn ascii_strings() {
+    check_agreement("ASCII", &ASCII_STRINGS);
+}
+
+#[test]
+fn strings_with_numbers() {
+    check_agreement("numbers", &NUM_STRINGS);
+}
+
+#[test]
+fn file_names() {
+    check_agreement("file names", &generate_strings(200));
+}
+
+// Like the strings with 1 MB numbers, but shorter, so the test is fast in debug builds
+#[test]
+fn long_numbers() {
+    let lhs = format!("file {}1.txt", "9".repeat(1 << 12));
+    let rhs = format!("file {}2.txt", "9".repeat(1 << 12));
+    check_agreement("long numbers", &[lhs, rhs]);
+}
@@ -0,0 +1,69 @@
+//! Checks that the order of a large list of strings doesn't change. With the `stable` feature,
+//! this order may only change in a semver-major release, which also increments
+//! `STABLE_ORDER_VERSION`.
+
+#![cfg(feature = "stable")]
+
+use lexical_sort::{SortMode, StringSort};
+
+const INPUT: &str = include_str!("stable_order/input.txt");
+
+fn check_order(mode: SortMode, golden: &str) {
+    let mut sorted: Vec<&str> = INPUT.lines().collect();
+    sorted.string_sort_unstable(mode.function());
+
+    let golden: Vec<&str> = golden.lines().collect();
+    assert_eq!(
+        sorted.len(),
+        golden.len(),
+        "the golden file of `{}` is outdated",
+        mode
+    );
+    if let Some(i) = (0..sorted.len()).find(|&i| sorted[i] != golden[i]) {
+        panic!(
+            "the `{}` order changed at line {} of the golden file: expected {:?}, found {:?}. \
+             If this is intended, it requires a semver-major release that increments \
+             `STABLE_ORDER_VERSION`",
+            mode,
+            i + 1,
+            golden[i],
+            sorted[i],
+        );
+    }
+}
+
+#[test]
+fn test_lexical_order() {
+    check_order(SortMode::Lexical, include_str!("stable_order/lexical.txt"));
+}
+
+#[test]
+fn test_lexical_only_alnum_order() {
+    check_order(
+        SortMode::LexicalOnlyAlnum,
+        include_str!("stable_order/lexical_only_alnum.txt"),
+    );
+}
+
+#[test]
+fn test_natural_lexical_order() {
+    check_order(
+        SortMode::NaturalLexical,
+        include_str!("stable_order/natural_lexical.txt"),
+    );
+}
+
+#[test]
+fn test_natural_lexical_only_alnum_order() {
+    check_order(
+        SortMode::NaturalLexicalOnlyAlnum,
+        include_str!("stable_order/natural_lexical_only_alnum.txt"),
+    );
+}
+
+#[test]
+#[cfg(feature = "stable")]
+fn test_stable_order_version() {
+    // the golden files contain version 1 of the order
+    assert_eq!(lexical_sort::STABLE_ORDER_VERSION, 1);
+}